@@ -0,0 +1,193 @@
+//! Bytecode disassembly and jump destination analysis.
+
+use crate::opcode::{Hardfork, OpCode};
+use alloy_primitives::{hex, U256};
+use core::fmt;
+
+/// A single disassembled instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Instruction<'a> {
+    /// The program counter of the instruction.
+    pub pc: usize,
+    /// The opcode of the instruction.
+    pub opcode: OpCode,
+    /// The immediate data of the instruction, i.e. the push data.
+    ///
+    /// This may be shorter than [`OpCode::immediate_size`] if the code ends within the push data,
+    /// see [`Instruction::is_truncated`].
+    pub immediate: &'a [u8],
+}
+
+impl Instruction<'_> {
+    /// Returns whether the immediate data is cut off by the end of the code.
+    pub const fn is_truncated(&self) -> bool {
+        self.immediate.len() < self.opcode.immediate_size()
+    }
+
+    /// Returns whether the opcode is defined in the given hardfork.
+    pub fn is_defined_at(&self, fork: Hardfork) -> bool {
+        self.opcode.is_defined_at(fork)
+    }
+
+    /// Returns the value pushed by a `PUSH` instruction.
+    ///
+    /// Truncated push data is right-padded with zeroes, as the EVM does.
+    pub fn push_value(&self) -> Option<U256> {
+        if !self.opcode.is_push() {
+            return None;
+        }
+        let size = self.opcode.immediate_size();
+        let mut word = [0u8; 32];
+        word[32 - size..32 - size + self.immediate.len()].copy_from_slice(self.immediate);
+        Some(U256::from_be_bytes(word))
+    }
+
+    /// Returns the program counter of the next instruction.
+    pub const fn next_pc(&self) -> usize {
+        self.pc + 1 + self.opcode.immediate_size()
+    }
+}
+
+impl fmt::Display for Instruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#06x}: {}", self.pc, self.opcode)?;
+        if !self.immediate.is_empty() {
+            write!(f, " 0x{}", hex::encode(self.immediate))?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the instructions of some bytecode.
+///
+/// Created by [`instructions`].
+#[derive(Clone, Debug)]
+pub struct Instructions<'a> {
+    code: &'a [u8],
+    pc: usize,
+}
+
+impl<'a> Iterator for Instructions<'a> {
+    type Item = Instruction<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let opcode = OpCode(*self.code.get(self.pc)?);
+        let start = (self.pc + 1).min(self.code.len());
+        let end = (start + opcode.immediate_size()).min(self.code.len());
+        let instruction = Instruction { pc: self.pc, opcode, immediate: &self.code[start..end] };
+        self.pc = instruction.next_pc();
+        Some(instruction)
+    }
+}
+
+impl core::iter::FusedIterator for Instructions<'_> {}
+
+/// Returns an iterator over the instructions of the given bytecode.
+pub const fn instructions(code: &[u8]) -> Instructions<'_> {
+    Instructions { code, pc: 0 }
+}
+
+/// Disassembles the given bytecode into a list of instructions.
+///
+/// Undefined opcodes are kept as-is; use [`Instruction::is_defined_at`] to check them against a
+/// hardfork.
+pub fn disassemble(code: &[u8]) -> Vec<Instruction<'_>> {
+    instructions(code).collect()
+}
+
+/// Formats the given bytecode as an instruction listing, one instruction per line.
+pub fn listing(code: &[u8]) -> String {
+    instructions(code).map(|instruction| format!("{instruction}\n")).collect()
+}
+
+/// The valid jump destinations of some bytecode.
+///
+/// A `JUMPDEST` byte is only a valid jump destination if it is not part of push data.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JumpDests {
+    valid: Vec<bool>,
+}
+
+impl JumpDests {
+    /// Analyzes the given bytecode.
+    pub fn analyze(code: &[u8]) -> Self {
+        let mut valid = vec![false; code.len()];
+        for instruction in instructions(code) {
+            if instruction.opcode == OpCode::JUMPDEST {
+                valid[instruction.pc] = true;
+            }
+        }
+        Self { valid }
+    }
+
+    /// Returns whether the given program counter is a valid jump destination.
+    pub fn is_valid(&self, pc: usize) -> bool {
+        self.valid.get(pc).copied().unwrap_or_default()
+    }
+
+    /// Returns an iterator over all valid jump destinations.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.valid.iter().enumerate().filter_map(|(pc, valid)| valid.then_some(pc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassemble_constructor_prologue() {
+        let code = hex!("6080604052348015600f57600080fd5b50");
+        let instructions = disassemble(&code);
+        let opcodes = instructions.iter().map(|i| i.opcode).collect::<Vec<_>>();
+        assert_eq!(
+            opcodes,
+            [
+                OpCode::PUSH1,
+                OpCode::PUSH1,
+                OpCode::MSTORE,
+                OpCode::CALLVALUE,
+                OpCode::DUP1,
+                OpCode::ISZERO,
+                OpCode::PUSH1,
+                OpCode::JUMPI,
+                OpCode::PUSH1,
+                OpCode::DUP1,
+                OpCode::REVERT,
+                OpCode::JUMPDEST,
+                OpCode::POP,
+            ]
+        );
+        assert_eq!(instructions[0].push_value(), Some(U256::from(0x80)));
+        assert_eq!(instructions[6].to_string(), "0x0008: PUSH1 0x0f");
+        assert_eq!(instructions[11].pc, 0x0f);
+
+        let jumpdests = JumpDests::analyze(&code);
+        assert!(jumpdests.is_valid(0x0f));
+        assert_eq!(jumpdests.iter().collect::<Vec<_>>(), [0x0f]);
+    }
+
+    #[test]
+    fn jumpdest_in_push_data() {
+        // PUSH2 0x5b5b JUMPDEST
+        let code = hex!("615b5b5b");
+        let jumpdests = JumpDests::analyze(&code);
+        assert!(!jumpdests.is_valid(1));
+        assert!(!jumpdests.is_valid(2));
+        assert!(jumpdests.is_valid(3));
+        assert!(!jumpdests.is_valid(4));
+    }
+
+    #[test]
+    fn truncated_push_and_unknown_opcodes() {
+        let code = hex!("0c5f63ffff");
+        let instructions = disassemble(&code);
+        assert_eq!(instructions.len(), 3);
+        assert!(!instructions[0].is_defined_at(Hardfork::Cancun));
+        assert!(!instructions[1].is_defined_at(Hardfork::London));
+        assert_eq!(instructions[1].push_value(), Some(U256::ZERO));
+        assert!(instructions[2].is_truncated());
+        assert_eq!(instructions[2].push_value(), Some(U256::from(0xffff0000u32)));
+        assert_eq!(listing(&code), "0x0000: UNKNOWN(0x0c)\n0x0001: PUSH0\n0x0002: PUSH4 0xffff\n");
+    }
+}
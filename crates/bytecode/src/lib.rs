@@ -0,0 +1,13 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/alloy.jpg",
+    html_favicon_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/favicon.ico"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+pub mod opcode;
+pub use opcode::{Hardfork, OpCode, OpCodeInfo};
+
+mod disasm;
+pub use disasm::{disassemble, instructions, listing, Instruction, Instructions, JumpDests};
@@ -0,0 +1,600 @@
+//! EVM opcodes.
+
+pub use alloy_eips::Hardfork;
+
+use core::fmt;
+
+/// Static information about an opcode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OpCodeInfo {
+    /// The mnemonic of the opcode.
+    pub name: &'static str,
+    /// The number of immediate bytes following the opcode, i.e. the push data size.
+    pub immediate_size: u8,
+    /// The hardfork that introduced the opcode.
+    pub introduced: Hardfork,
+}
+
+/// An EVM opcode.
+///
+/// Any byte is a valid [`OpCode`], but only defined opcodes have [`OpCodeInfo`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OpCode(pub u8);
+
+impl OpCode {
+    /// The `STOP` opcode.
+    pub const STOP: Self = Self(0x00);
+    /// The `ADD` opcode.
+    pub const ADD: Self = Self(0x01);
+    /// The `MUL` opcode.
+    pub const MUL: Self = Self(0x02);
+    /// The `SUB` opcode.
+    pub const SUB: Self = Self(0x03);
+    /// The `DIV` opcode.
+    pub const DIV: Self = Self(0x04);
+    /// The `SDIV` opcode.
+    pub const SDIV: Self = Self(0x05);
+    /// The `MOD` opcode.
+    pub const MOD: Self = Self(0x06);
+    /// The `SMOD` opcode.
+    pub const SMOD: Self = Self(0x07);
+    /// The `ADDMOD` opcode.
+    pub const ADDMOD: Self = Self(0x08);
+    /// The `MULMOD` opcode.
+    pub const MULMOD: Self = Self(0x09);
+    /// The `EXP` opcode.
+    pub const EXP: Self = Self(0x0a);
+    /// The `SIGNEXTEND` opcode.
+    pub const SIGNEXTEND: Self = Self(0x0b);
+    /// The `LT` opcode.
+    pub const LT: Self = Self(0x10);
+    /// The `GT` opcode.
+    pub const GT: Self = Self(0x11);
+    /// The `SLT` opcode.
+    pub const SLT: Self = Self(0x12);
+    /// The `SGT` opcode.
+    pub const SGT: Self = Self(0x13);
+    /// The `EQ` opcode.
+    pub const EQ: Self = Self(0x14);
+    /// The `ISZERO` opcode.
+    pub const ISZERO: Self = Self(0x15);
+    /// The `AND` opcode.
+    pub const AND: Self = Self(0x16);
+    /// The `OR` opcode.
+    pub const OR: Self = Self(0x17);
+    /// The `XOR` opcode.
+    pub const XOR: Self = Self(0x18);
+    /// The `NOT` opcode.
+    pub const NOT: Self = Self(0x19);
+    /// The `BYTE` opcode.
+    pub const BYTE: Self = Self(0x1a);
+    /// The `SHL` opcode.
+    pub const SHL: Self = Self(0x1b);
+    /// The `SHR` opcode.
+    pub const SHR: Self = Self(0x1c);
+    /// The `SAR` opcode.
+    pub const SAR: Self = Self(0x1d);
+    /// The `KECCAK256` opcode.
+    pub const KECCAK256: Self = Self(0x20);
+    /// The `ADDRESS` opcode.
+    pub const ADDRESS: Self = Self(0x30);
+    /// The `BALANCE` opcode.
+    pub const BALANCE: Self = Self(0x31);
+    /// The `ORIGIN` opcode.
+    pub const ORIGIN: Self = Self(0x32);
+    /// The `CALLER` opcode.
+    pub const CALLER: Self = Self(0x33);
+    /// The `CALLVALUE` opcode.
+    pub const CALLVALUE: Self = Self(0x34);
+    /// The `CALLDATALOAD` opcode.
+    pub const CALLDATALOAD: Self = Self(0x35);
+    /// The `CALLDATASIZE` opcode.
+    pub const CALLDATASIZE: Self = Self(0x36);
+    /// The `CALLDATACOPY` opcode.
+    pub const CALLDATACOPY: Self = Self(0x37);
+    /// The `CODESIZE` opcode.
+    pub const CODESIZE: Self = Self(0x38);
+    /// The `CODECOPY` opcode.
+    pub const CODECOPY: Self = Self(0x39);
+    /// The `GASPRICE` opcode.
+    pub const GASPRICE: Self = Self(0x3a);
+    /// The `EXTCODESIZE` opcode.
+    pub const EXTCODESIZE: Self = Self(0x3b);
+    /// The `EXTCODECOPY` opcode.
+    pub const EXTCODECOPY: Self = Self(0x3c);
+    /// The `RETURNDATASIZE` opcode.
+    pub const RETURNDATASIZE: Self = Self(0x3d);
+    /// The `RETURNDATACOPY` opcode.
+    pub const RETURNDATACOPY: Self = Self(0x3e);
+    /// The `EXTCODEHASH` opcode.
+    pub const EXTCODEHASH: Self = Self(0x3f);
+    /// The `BLOCKHASH` opcode.
+    pub const BLOCKHASH: Self = Self(0x40);
+    /// The `COINBASE` opcode.
+    pub const COINBASE: Self = Self(0x41);
+    /// The `TIMESTAMP` opcode.
+    pub const TIMESTAMP: Self = Self(0x42);
+    /// The `NUMBER` opcode.
+    pub const NUMBER: Self = Self(0x43);
+    /// The `PREVRANDAO` opcode.
+    pub const PREVRANDAO: Self = Self(0x44);
+    /// The `GASLIMIT` opcode.
+    pub const GASLIMIT: Self = Self(0x45);
+    /// The `CHAINID` opcode.
+    pub const CHAINID: Self = Self(0x46);
+    /// The `SELFBALANCE` opcode.
+    pub const SELFBALANCE: Self = Self(0x47);
+    /// The `BASEFEE` opcode.
+    pub const BASEFEE: Self = Self(0x48);
+    /// The `BLOBHASH` opcode.
+    pub const BLOBHASH: Self = Self(0x49);
+    /// The `BLOBBASEFEE` opcode.
+    pub const BLOBBASEFEE: Self = Self(0x4a);
+    /// The `POP` opcode.
+    pub const POP: Self = Self(0x50);
+    /// The `MLOAD` opcode.
+    pub const MLOAD: Self = Self(0x51);
+    /// The `MSTORE` opcode.
+    pub const MSTORE: Self = Self(0x52);
+    /// The `MSTORE8` opcode.
+    pub const MSTORE8: Self = Self(0x53);
+    /// The `SLOAD` opcode.
+    pub const SLOAD: Self = Self(0x54);
+    /// The `SSTORE` opcode.
+    pub const SSTORE: Self = Self(0x55);
+    /// The `JUMP` opcode.
+    pub const JUMP: Self = Self(0x56);
+    /// The `JUMPI` opcode.
+    pub const JUMPI: Self = Self(0x57);
+    /// The `PC` opcode.
+    pub const PC: Self = Self(0x58);
+    /// The `MSIZE` opcode.
+    pub const MSIZE: Self = Self(0x59);
+    /// The `GAS` opcode.
+    pub const GAS: Self = Self(0x5a);
+    /// The `JUMPDEST` opcode.
+    pub const JUMPDEST: Self = Self(0x5b);
+    /// The `TLOAD` opcode.
+    pub const TLOAD: Self = Self(0x5c);
+    /// The `TSTORE` opcode.
+    pub const TSTORE: Self = Self(0x5d);
+    /// The `MCOPY` opcode.
+    pub const MCOPY: Self = Self(0x5e);
+    /// The `PUSH0` opcode.
+    pub const PUSH0: Self = Self(0x5f);
+    /// The `PUSH1` opcode.
+    pub const PUSH1: Self = Self(0x60);
+    /// The `PUSH2` opcode.
+    pub const PUSH2: Self = Self(0x61);
+    /// The `PUSH3` opcode.
+    pub const PUSH3: Self = Self(0x62);
+    /// The `PUSH4` opcode.
+    pub const PUSH4: Self = Self(0x63);
+    /// The `PUSH5` opcode.
+    pub const PUSH5: Self = Self(0x64);
+    /// The `PUSH6` opcode.
+    pub const PUSH6: Self = Self(0x65);
+    /// The `PUSH7` opcode.
+    pub const PUSH7: Self = Self(0x66);
+    /// The `PUSH8` opcode.
+    pub const PUSH8: Self = Self(0x67);
+    /// The `PUSH9` opcode.
+    pub const PUSH9: Self = Self(0x68);
+    /// The `PUSH10` opcode.
+    pub const PUSH10: Self = Self(0x69);
+    /// The `PUSH11` opcode.
+    pub const PUSH11: Self = Self(0x6a);
+    /// The `PUSH12` opcode.
+    pub const PUSH12: Self = Self(0x6b);
+    /// The `PUSH13` opcode.
+    pub const PUSH13: Self = Self(0x6c);
+    /// The `PUSH14` opcode.
+    pub const PUSH14: Self = Self(0x6d);
+    /// The `PUSH15` opcode.
+    pub const PUSH15: Self = Self(0x6e);
+    /// The `PUSH16` opcode.
+    pub const PUSH16: Self = Self(0x6f);
+    /// The `PUSH17` opcode.
+    pub const PUSH17: Self = Self(0x70);
+    /// The `PUSH18` opcode.
+    pub const PUSH18: Self = Self(0x71);
+    /// The `PUSH19` opcode.
+    pub const PUSH19: Self = Self(0x72);
+    /// The `PUSH20` opcode.
+    pub const PUSH20: Self = Self(0x73);
+    /// The `PUSH21` opcode.
+    pub const PUSH21: Self = Self(0x74);
+    /// The `PUSH22` opcode.
+    pub const PUSH22: Self = Self(0x75);
+    /// The `PUSH23` opcode.
+    pub const PUSH23: Self = Self(0x76);
+    /// The `PUSH24` opcode.
+    pub const PUSH24: Self = Self(0x77);
+    /// The `PUSH25` opcode.
+    pub const PUSH25: Self = Self(0x78);
+    /// The `PUSH26` opcode.
+    pub const PUSH26: Self = Self(0x79);
+    /// The `PUSH27` opcode.
+    pub const PUSH27: Self = Self(0x7a);
+    /// The `PUSH28` opcode.
+    pub const PUSH28: Self = Self(0x7b);
+    /// The `PUSH29` opcode.
+    pub const PUSH29: Self = Self(0x7c);
+    /// The `PUSH30` opcode.
+    pub const PUSH30: Self = Self(0x7d);
+    /// The `PUSH31` opcode.
+    pub const PUSH31: Self = Self(0x7e);
+    /// The `PUSH32` opcode.
+    pub const PUSH32: Self = Self(0x7f);
+    /// The `DUP1` opcode.
+    pub const DUP1: Self = Self(0x80);
+    /// The `DUP2` opcode.
+    pub const DUP2: Self = Self(0x81);
+    /// The `DUP3` opcode.
+    pub const DUP3: Self = Self(0x82);
+    /// The `DUP4` opcode.
+    pub const DUP4: Self = Self(0x83);
+    /// The `DUP5` opcode.
+    pub const DUP5: Self = Self(0x84);
+    /// The `DUP6` opcode.
+    pub const DUP6: Self = Self(0x85);
+    /// The `DUP7` opcode.
+    pub const DUP7: Self = Self(0x86);
+    /// The `DUP8` opcode.
+    pub const DUP8: Self = Self(0x87);
+    /// The `DUP9` opcode.
+    pub const DUP9: Self = Self(0x88);
+    /// The `DUP10` opcode.
+    pub const DUP10: Self = Self(0x89);
+    /// The `DUP11` opcode.
+    pub const DUP11: Self = Self(0x8a);
+    /// The `DUP12` opcode.
+    pub const DUP12: Self = Self(0x8b);
+    /// The `DUP13` opcode.
+    pub const DUP13: Self = Self(0x8c);
+    /// The `DUP14` opcode.
+    pub const DUP14: Self = Self(0x8d);
+    /// The `DUP15` opcode.
+    pub const DUP15: Self = Self(0x8e);
+    /// The `DUP16` opcode.
+    pub const DUP16: Self = Self(0x8f);
+    /// The `SWAP1` opcode.
+    pub const SWAP1: Self = Self(0x90);
+    /// The `SWAP2` opcode.
+    pub const SWAP2: Self = Self(0x91);
+    /// The `SWAP3` opcode.
+    pub const SWAP3: Self = Self(0x92);
+    /// The `SWAP4` opcode.
+    pub const SWAP4: Self = Self(0x93);
+    /// The `SWAP5` opcode.
+    pub const SWAP5: Self = Self(0x94);
+    /// The `SWAP6` opcode.
+    pub const SWAP6: Self = Self(0x95);
+    /// The `SWAP7` opcode.
+    pub const SWAP7: Self = Self(0x96);
+    /// The `SWAP8` opcode.
+    pub const SWAP8: Self = Self(0x97);
+    /// The `SWAP9` opcode.
+    pub const SWAP9: Self = Self(0x98);
+    /// The `SWAP10` opcode.
+    pub const SWAP10: Self = Self(0x99);
+    /// The `SWAP11` opcode.
+    pub const SWAP11: Self = Self(0x9a);
+    /// The `SWAP12` opcode.
+    pub const SWAP12: Self = Self(0x9b);
+    /// The `SWAP13` opcode.
+    pub const SWAP13: Self = Self(0x9c);
+    /// The `SWAP14` opcode.
+    pub const SWAP14: Self = Self(0x9d);
+    /// The `SWAP15` opcode.
+    pub const SWAP15: Self = Self(0x9e);
+    /// The `SWAP16` opcode.
+    pub const SWAP16: Self = Self(0x9f);
+    /// The `LOG0` opcode.
+    pub const LOG0: Self = Self(0xa0);
+    /// The `LOG1` opcode.
+    pub const LOG1: Self = Self(0xa1);
+    /// The `LOG2` opcode.
+    pub const LOG2: Self = Self(0xa2);
+    /// The `LOG3` opcode.
+    pub const LOG3: Self = Self(0xa3);
+    /// The `LOG4` opcode.
+    pub const LOG4: Self = Self(0xa4);
+    /// The `CREATE` opcode.
+    pub const CREATE: Self = Self(0xf0);
+    /// The `CALL` opcode.
+    pub const CALL: Self = Self(0xf1);
+    /// The `CALLCODE` opcode.
+    pub const CALLCODE: Self = Self(0xf2);
+    /// The `RETURN` opcode.
+    pub const RETURN: Self = Self(0xf3);
+    /// The `DELEGATECALL` opcode.
+    pub const DELEGATECALL: Self = Self(0xf4);
+    /// The `CREATE2` opcode.
+    pub const CREATE2: Self = Self(0xf5);
+    /// The `STATICCALL` opcode.
+    pub const STATICCALL: Self = Self(0xfa);
+    /// The `REVERT` opcode.
+    pub const REVERT: Self = Self(0xfd);
+    /// The `INVALID` opcode.
+    pub const INVALID: Self = Self(0xfe);
+    /// The `SELFDESTRUCT` opcode.
+    pub const SELFDESTRUCT: Self = Self(0xff);
+
+    /// Creates a new opcode from a byte.
+    pub const fn new(byte: u8) -> Self {
+        Self(byte)
+    }
+
+    /// Returns the opcode byte.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the information of the opcode, if it is defined in any hardfork.
+    pub const fn info(self) -> Option<&'static OpCodeInfo> {
+        OPCODE_INFO[self.0 as usize].as_ref()
+    }
+
+    /// Returns the information of the opcode, if it is defined in the given hardfork.
+    pub fn info_at(self, fork: Hardfork) -> Option<&'static OpCodeInfo> {
+        self.info().filter(|info| info.introduced <= fork)
+    }
+
+    /// Returns whether the opcode is defined in the given hardfork.
+    pub fn is_defined_at(self, fork: Hardfork) -> bool {
+        self.info_at(fork).is_some()
+    }
+
+    /// Returns the mnemonic of the opcode, if it is defined in any hardfork.
+    pub const fn name(self) -> Option<&'static str> {
+        match self.info() {
+            Some(info) => Some(info.name),
+            None => None,
+        }
+    }
+
+    /// Returns the number of immediate bytes following the opcode.
+    pub const fn immediate_size(self) -> usize {
+        match self.info() {
+            Some(info) => info.immediate_size as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns whether the opcode is `PUSH0` to `PUSH32`.
+    pub const fn is_push(self) -> bool {
+        self.0 >= Self::PUSH0.0 && self.0 <= Self::PUSH32.0
+    }
+
+    /// Returns whether the opcode terminates execution of the current context.
+    pub const fn is_terminating(self) -> bool {
+        matches!(
+            self,
+            Self::STOP | Self::RETURN | Self::REVERT | Self::INVALID | Self::SELFDESTRUCT
+        )
+    }
+}
+
+impl From<u8> for OpCode {
+    fn from(byte: u8) -> Self {
+        Self(byte)
+    }
+}
+
+impl From<OpCode> for u8 {
+    fn from(opcode: OpCode) -> Self {
+        opcode.0
+    }
+}
+
+impl fmt::Debug for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "UNKNOWN(0x{:02x})", self.0),
+        }
+    }
+}
+
+/// The opcode table, indexed by opcode byte.
+static OPCODE_INFO: [Option<OpCodeInfo>; 256] = {
+    let mut table = [None; 256];
+    macro_rules! op {
+        ($code:literal, $name:literal, $fork:ident) => {
+            op!($code, $name, $fork, 0)
+        };
+        ($code:literal, $name:literal, $fork:ident, $immediate_size:literal) => {
+            table[$code] = Some(OpCodeInfo {
+                name: $name,
+                immediate_size: $immediate_size,
+                introduced: Hardfork::$fork,
+            });
+        };
+    }
+    op!(0x00, "STOP", Frontier);
+    op!(0x01, "ADD", Frontier);
+    op!(0x02, "MUL", Frontier);
+    op!(0x03, "SUB", Frontier);
+    op!(0x04, "DIV", Frontier);
+    op!(0x05, "SDIV", Frontier);
+    op!(0x06, "MOD", Frontier);
+    op!(0x07, "SMOD", Frontier);
+    op!(0x08, "ADDMOD", Frontier);
+    op!(0x09, "MULMOD", Frontier);
+    op!(0x0a, "EXP", Frontier);
+    op!(0x0b, "SIGNEXTEND", Frontier);
+    op!(0x10, "LT", Frontier);
+    op!(0x11, "GT", Frontier);
+    op!(0x12, "SLT", Frontier);
+    op!(0x13, "SGT", Frontier);
+    op!(0x14, "EQ", Frontier);
+    op!(0x15, "ISZERO", Frontier);
+    op!(0x16, "AND", Frontier);
+    op!(0x17, "OR", Frontier);
+    op!(0x18, "XOR", Frontier);
+    op!(0x19, "NOT", Frontier);
+    op!(0x1a, "BYTE", Frontier);
+    op!(0x1b, "SHL", Constantinople);
+    op!(0x1c, "SHR", Constantinople);
+    op!(0x1d, "SAR", Constantinople);
+    op!(0x20, "KECCAK256", Frontier);
+    op!(0x30, "ADDRESS", Frontier);
+    op!(0x31, "BALANCE", Frontier);
+    op!(0x32, "ORIGIN", Frontier);
+    op!(0x33, "CALLER", Frontier);
+    op!(0x34, "CALLVALUE", Frontier);
+    op!(0x35, "CALLDATALOAD", Frontier);
+    op!(0x36, "CALLDATASIZE", Frontier);
+    op!(0x37, "CALLDATACOPY", Frontier);
+    op!(0x38, "CODESIZE", Frontier);
+    op!(0x39, "CODECOPY", Frontier);
+    op!(0x3a, "GASPRICE", Frontier);
+    op!(0x3b, "EXTCODESIZE", Frontier);
+    op!(0x3c, "EXTCODECOPY", Frontier);
+    op!(0x3d, "RETURNDATASIZE", Byzantium);
+    op!(0x3e, "RETURNDATACOPY", Byzantium);
+    op!(0x3f, "EXTCODEHASH", Constantinople);
+    op!(0x40, "BLOCKHASH", Frontier);
+    op!(0x41, "COINBASE", Frontier);
+    op!(0x42, "TIMESTAMP", Frontier);
+    op!(0x43, "NUMBER", Frontier);
+    op!(0x44, "PREVRANDAO", Frontier);
+    op!(0x45, "GASLIMIT", Frontier);
+    op!(0x46, "CHAINID", Istanbul);
+    op!(0x47, "SELFBALANCE", Istanbul);
+    op!(0x48, "BASEFEE", London);
+    op!(0x49, "BLOBHASH", Cancun);
+    op!(0x4a, "BLOBBASEFEE", Cancun);
+    op!(0x50, "POP", Frontier);
+    op!(0x51, "MLOAD", Frontier);
+    op!(0x52, "MSTORE", Frontier);
+    op!(0x53, "MSTORE8", Frontier);
+    op!(0x54, "SLOAD", Frontier);
+    op!(0x55, "SSTORE", Frontier);
+    op!(0x56, "JUMP", Frontier);
+    op!(0x57, "JUMPI", Frontier);
+    op!(0x58, "PC", Frontier);
+    op!(0x59, "MSIZE", Frontier);
+    op!(0x5a, "GAS", Frontier);
+    op!(0x5b, "JUMPDEST", Frontier);
+    op!(0x5c, "TLOAD", Cancun);
+    op!(0x5d, "TSTORE", Cancun);
+    op!(0x5e, "MCOPY", Cancun);
+    op!(0x5f, "PUSH0", Shanghai);
+    op!(0x60, "PUSH1", Frontier, 1);
+    op!(0x61, "PUSH2", Frontier, 2);
+    op!(0x62, "PUSH3", Frontier, 3);
+    op!(0x63, "PUSH4", Frontier, 4);
+    op!(0x64, "PUSH5", Frontier, 5);
+    op!(0x65, "PUSH6", Frontier, 6);
+    op!(0x66, "PUSH7", Frontier, 7);
+    op!(0x67, "PUSH8", Frontier, 8);
+    op!(0x68, "PUSH9", Frontier, 9);
+    op!(0x69, "PUSH10", Frontier, 10);
+    op!(0x6a, "PUSH11", Frontier, 11);
+    op!(0x6b, "PUSH12", Frontier, 12);
+    op!(0x6c, "PUSH13", Frontier, 13);
+    op!(0x6d, "PUSH14", Frontier, 14);
+    op!(0x6e, "PUSH15", Frontier, 15);
+    op!(0x6f, "PUSH16", Frontier, 16);
+    op!(0x70, "PUSH17", Frontier, 17);
+    op!(0x71, "PUSH18", Frontier, 18);
+    op!(0x72, "PUSH19", Frontier, 19);
+    op!(0x73, "PUSH20", Frontier, 20);
+    op!(0x74, "PUSH21", Frontier, 21);
+    op!(0x75, "PUSH22", Frontier, 22);
+    op!(0x76, "PUSH23", Frontier, 23);
+    op!(0x77, "PUSH24", Frontier, 24);
+    op!(0x78, "PUSH25", Frontier, 25);
+    op!(0x79, "PUSH26", Frontier, 26);
+    op!(0x7a, "PUSH27", Frontier, 27);
+    op!(0x7b, "PUSH28", Frontier, 28);
+    op!(0x7c, "PUSH29", Frontier, 29);
+    op!(0x7d, "PUSH30", Frontier, 30);
+    op!(0x7e, "PUSH31", Frontier, 31);
+    op!(0x7f, "PUSH32", Frontier, 32);
+    op!(0x80, "DUP1", Frontier);
+    op!(0x81, "DUP2", Frontier);
+    op!(0x82, "DUP3", Frontier);
+    op!(0x83, "DUP4", Frontier);
+    op!(0x84, "DUP5", Frontier);
+    op!(0x85, "DUP6", Frontier);
+    op!(0x86, "DUP7", Frontier);
+    op!(0x87, "DUP8", Frontier);
+    op!(0x88, "DUP9", Frontier);
+    op!(0x89, "DUP10", Frontier);
+    op!(0x8a, "DUP11", Frontier);
+    op!(0x8b, "DUP12", Frontier);
+    op!(0x8c, "DUP13", Frontier);
+    op!(0x8d, "DUP14", Frontier);
+    op!(0x8e, "DUP15", Frontier);
+    op!(0x8f, "DUP16", Frontier);
+    op!(0x90, "SWAP1", Frontier);
+    op!(0x91, "SWAP2", Frontier);
+    op!(0x92, "SWAP3", Frontier);
+    op!(0x93, "SWAP4", Frontier);
+    op!(0x94, "SWAP5", Frontier);
+    op!(0x95, "SWAP6", Frontier);
+    op!(0x96, "SWAP7", Frontier);
+    op!(0x97, "SWAP8", Frontier);
+    op!(0x98, "SWAP9", Frontier);
+    op!(0x99, "SWAP10", Frontier);
+    op!(0x9a, "SWAP11", Frontier);
+    op!(0x9b, "SWAP12", Frontier);
+    op!(0x9c, "SWAP13", Frontier);
+    op!(0x9d, "SWAP14", Frontier);
+    op!(0x9e, "SWAP15", Frontier);
+    op!(0x9f, "SWAP16", Frontier);
+    op!(0xa0, "LOG0", Frontier);
+    op!(0xa1, "LOG1", Frontier);
+    op!(0xa2, "LOG2", Frontier);
+    op!(0xa3, "LOG3", Frontier);
+    op!(0xa4, "LOG4", Frontier);
+    op!(0xf0, "CREATE", Frontier);
+    op!(0xf1, "CALL", Frontier);
+    op!(0xf2, "CALLCODE", Frontier);
+    op!(0xf3, "RETURN", Frontier);
+    op!(0xf4, "DELEGATECALL", Homestead);
+    op!(0xf5, "CREATE2", Constantinople);
+    op!(0xfa, "STATICCALL", Byzantium);
+    op!(0xfd, "REVERT", Byzantium);
+    op!(0xfe, "INVALID", Frontier);
+    op!(0xff, "SELFDESTRUCT", Frontier);
+    table
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table() {
+        assert_eq!(OPCODE_INFO.iter().flatten().count(), 149);
+        for (byte, info) in OPCODE_INFO.iter().enumerate() {
+            if let Some(info) = info {
+                assert_eq!(OpCode(byte as u8).is_push(), info.name.starts_with("PUSH"));
+            }
+        }
+        assert_eq!(OpCode::PUSH1.immediate_size(), 1);
+        assert_eq!(OpCode::PUSH32.immediate_size(), 32);
+        assert_eq!(OpCode::PUSH0.immediate_size(), 0);
+        assert_eq!(OpCode::JUMPDEST.to_string(), "JUMPDEST");
+        assert_eq!(OpCode(0x0c).to_string(), "UNKNOWN(0x0c)");
+    }
+
+    #[test]
+    fn fork_aware() {
+        assert!(!OpCode::PUSH0.is_defined_at(Hardfork::London));
+        assert!(OpCode::PUSH0.is_defined_at(Hardfork::Shanghai));
+        assert!(!OpCode::DELEGATECALL.is_defined_at(Hardfork::Frontier));
+        assert!(OpCode::TSTORE.is_defined_at(Hardfork::default()));
+        assert!(OpCode::STOP.is_defined_at(Hardfork::Frontier));
+    }
+}
@@ -1,4 +1,4 @@
-use alloy_consensus::{Eip658Value, Receipt, ReceiptWithBloom, TxReceipt};
+use alloy_consensus::{Eip658Value, Receipt, ReceiptEnvelope, ReceiptWithBloom, TxReceipt, TxType};
 use alloy_eips::{
     eip2718::{Decodable2718, Eip2718Result, Encodable2718},
     Typed2718,
@@ -34,6 +34,58 @@ impl<T> AnyReceiptEnvelope<T> {
     pub const fn is_legacy(&self) -> bool {
         self.r#type == 0
     }
+
+    /// Returns whether the receipt type is known to Ethereum, i.e. whether this envelope can be
+    /// converted into a [`ReceiptEnvelope`].
+    pub fn is_ethereum(&self) -> bool {
+        TxType::try_from(self.r#type).is_ok()
+    }
+
+    /// Returns the inner receipt with bloom.
+    pub const fn as_receipt_with_bloom(&self) -> &ReceiptWithBloom<Receipt<T>> {
+        &self.inner
+    }
+
+    /// Consumes the envelope and returns the inner receipt with bloom.
+    pub fn into_receipt_with_bloom(self) -> ReceiptWithBloom<Receipt<T>> {
+        self.inner
+    }
+
+    /// Attempts to convert the envelope into an Ethereum [`ReceiptEnvelope`].
+    ///
+    /// Returns the envelope unchanged if its type is not known to Ethereum.
+    pub fn try_into_envelope(self) -> Result<ReceiptEnvelope<T>, Self> {
+        let Ok(ty) = TxType::try_from(self.r#type) else { return Err(self) };
+        Ok(match ty {
+            TxType::Legacy => ReceiptEnvelope::Legacy(self.inner),
+            TxType::Eip2930 => ReceiptEnvelope::Eip2930(self.inner),
+            TxType::Eip1559 => ReceiptEnvelope::Eip1559(self.inner),
+            TxType::Eip4844 => ReceiptEnvelope::Eip4844(self.inner),
+            TxType::Eip7702 => ReceiptEnvelope::Eip7702(self.inner),
+        })
+    }
+}
+
+impl<T> From<ReceiptEnvelope<T>> for AnyReceiptEnvelope<T> {
+    fn from(envelope: ReceiptEnvelope<T>) -> Self {
+        let r#type = envelope.tx_type() as u8;
+        let inner = match envelope {
+            ReceiptEnvelope::Legacy(receipt)
+            | ReceiptEnvelope::Eip2930(receipt)
+            | ReceiptEnvelope::Eip1559(receipt)
+            | ReceiptEnvelope::Eip4844(receipt)
+            | ReceiptEnvelope::Eip7702(receipt) => receipt,
+        };
+        Self { inner, r#type }
+    }
+}
+
+impl<T> TryFrom<AnyReceiptEnvelope<T>> for ReceiptEnvelope<T> {
+    type Error = AnyReceiptEnvelope<T>;
+
+    fn try_from(value: AnyReceiptEnvelope<T>) -> Result<Self, Self::Error> {
+        value.try_into_envelope()
+    }
 }
 
 impl<T: Encodable> AnyReceiptEnvelope<T> {
@@ -116,13 +168,13 @@ where
     }
 }
 
-impl Typed2718 for AnyReceiptEnvelope {
+impl<T> Typed2718 for AnyReceiptEnvelope<T> {
     fn ty(&self) -> u8 {
         self.r#type
     }
 }
 
-impl Encodable2718 for AnyReceiptEnvelope {
+impl<T: Encodable + Send + Sync> Encodable2718 for AnyReceiptEnvelope<T> {
     fn encode_2718_len(&self) -> usize {
         self.inner.length() + !self.is_legacy() as usize
     }
@@ -136,7 +188,7 @@ impl Encodable2718 for AnyReceiptEnvelope {
     }
 }
 
-impl Decodable2718 for AnyReceiptEnvelope {
+impl<T: Decodable> Decodable2718 for AnyReceiptEnvelope<T> {
     fn typed_decode(ty: u8, buf: &mut &[u8]) -> Eip2718Result<Self> {
         let receipt = Decodable::decode(buf)?;
         Ok(Self { inner: receipt, r#type: ty })
@@ -146,3 +198,79 @@ impl Decodable2718 for AnyReceiptEnvelope {
         Self::typed_decode(0, buf)
     }
 }
+
+impl<T: Encodable + Send + Sync> Encodable for AnyReceiptEnvelope<T> {
+    fn encode(&self, out: &mut dyn BufMut) {
+        self.network_encode(out)
+    }
+
+    fn length(&self) -> usize {
+        self.network_len()
+    }
+}
+
+impl<T: Decodable> Decodable for AnyReceiptEnvelope<T> {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Self::network_decode(buf)
+            .map_or_else(|_| Err(alloy_rlp::Error::Custom("Unexpected type")), Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, bytes, LogData};
+
+    fn receipt(ty: u8) -> AnyReceiptEnvelope {
+        let log = Log {
+            address: address!("0000000000000000000000000000000000000011"),
+            data: LogData::new_unchecked(
+                vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                bytes!("0100ff"),
+            ),
+        };
+        let receipt = Receipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 21_000,
+            logs: vec![log],
+        };
+        AnyReceiptEnvelope { inner: receipt.with_bloom(), r#type: ty }
+    }
+
+    #[test]
+    fn roundtrip_2718() {
+        // legacy, known typed and unknown typed receipts
+        for ty in [0, 2, 0x7e] {
+            let receipt = receipt(ty);
+            let encoded = receipt.encoded_2718();
+            assert_eq!(encoded.len(), receipt.encode_2718_len());
+            assert_eq!(encoded[0] == ty, ty != 0);
+            let decoded = AnyReceiptEnvelope::decode_2718(&mut encoded.as_slice()).unwrap();
+            assert_eq!(decoded, receipt);
+        }
+    }
+
+    #[test]
+    fn roundtrip_rlp() {
+        for ty in [0, 3, 0x7e] {
+            let receipt = receipt(ty);
+            let encoded = alloy_rlp::encode(&receipt);
+            assert_eq!(encoded.len(), receipt.length());
+            let decoded = AnyReceiptEnvelope::decode(&mut encoded.as_slice()).unwrap();
+            assert_eq!(decoded, receipt);
+        }
+    }
+
+    #[test]
+    fn ethereum_conversions() {
+        let any = receipt(2);
+        let envelope = ReceiptEnvelope::try_from(any.clone()).unwrap();
+        assert!(matches!(envelope, ReceiptEnvelope::Eip1559(_)));
+        assert_eq!(envelope.encoded_2718(), any.encoded_2718());
+        assert_eq!(AnyReceiptEnvelope::from(envelope), any);
+
+        let unknown = receipt(0x7e);
+        assert!(!unknown.is_ethereum());
+        assert_eq!(ReceiptEnvelope::try_from(unknown.clone()).unwrap_err(), unknown);
+    }
+}
@@ -0,0 +1,97 @@
+#![allow(missing_docs)]
+
+use alloy_consensus::{SignableTransaction, TxEip1559, TxEip4844, TxEnvelope, TxLegacy};
+use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+use alloy_primitives::{b256, hex, Address, Bytes, PrimitiveSignature as Signature, TxKind, U256};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn legacy() -> TxEnvelope {
+    let tx = TxLegacy {
+        chain_id: Some(1),
+        nonce: 0x18,
+        gas_price: 0xfa56ea00,
+        gas_limit: 119902,
+        to: TxKind::Call(hex!("06012c8cf97bead5deae237070f9587f8e7a266d").into()),
+        value: U256::from(0x1c6bf526340000u64),
+        input: hex!("f7d8c88300000000000000000000000000000000000000000000000000000000000cee6100000000000000000000000000000000000000000000000000000000000ac3e1").into(),
+    };
+    let sig = Signature::from_scalars_and_parity(
+        b256!("2a378831cf81d99a3f06a18ae1b6ca366817ab4d88a70053c41d7a8f0368e031"),
+        b256!("450d831a05b6e418724436c05c155e0a1b7b921015d0fbc2f667aed709ac4fb5"),
+        false,
+    );
+    tx.into_signed(sig).into()
+}
+
+fn eip1559() -> TxEnvelope {
+    let tx = TxEip1559 {
+        chain_id: 1,
+        nonce: 0x42,
+        gas_limit: 44386,
+        to: TxKind::Call(hex!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into()),
+        value: U256::ZERO,
+        input: hex!("a22cb4650000000000000000000000005eee75727d804a2b13038928d36f8b188945a57a0000000000000000000000000000000000000000000000000000000000000000").into(),
+        max_fee_per_gas: 0x4a817c800,
+        max_priority_fee_per_gas: 0x3b9aca00,
+        access_list: Default::default(),
+    };
+    let sig = Signature::from_scalars_and_parity(
+        b256!("840cfc572845f5786e702984c2a582528cad4b49b2a10b9db1be7fca90058565"),
+        b256!("25e7109ceb98168d95b09b18bbf6b685130e0562f233877d492b94eee0c5b6d1"),
+        false,
+    );
+    tx.into_signed(sig).into()
+}
+
+fn eip4844() -> TxEnvelope {
+    let tx = TxEip4844 {
+        chain_id: 1,
+        nonce: 7,
+        gas_limit: 21_000,
+        max_fee_per_gas: 0x4a817c800,
+        max_priority_fee_per_gas: 0x3b9aca00,
+        to: Address::repeat_byte(0x11),
+        value: U256::ZERO,
+        access_list: Default::default(),
+        blob_versioned_hashes: vec![b256!(
+            "015a4cab4911426699ed34483de6640cf55a568afc5c5edffdcbd8bcd4452f68"
+        )],
+        max_fee_per_blob_gas: 0x20000,
+        input: Bytes::new(),
+    };
+    tx.into_signed(Signature::test_signature()).into()
+}
+
+fn envelopes() -> [(&'static str, TxEnvelope); 3] {
+    [("legacy", legacy()), ("eip1559", eip1559()), ("eip4844", eip4844())]
+}
+
+fn encode(c: &mut Criterion) {
+    let mut g = c.benchmark_group("encode_2718");
+    for (name, tx) in envelopes() {
+        g.bench_function(name, |b| b.iter(|| black_box(&tx).encoded_2718()));
+    }
+    g.finish();
+}
+
+fn decode(c: &mut Criterion) {
+    let mut g = c.benchmark_group("decode_2718");
+    for (name, tx) in envelopes() {
+        let encoded = tx.encoded_2718();
+        g.bench_function(name, |b| {
+            b.iter(|| TxEnvelope::decode_2718(&mut black_box(encoded.as_slice())).unwrap())
+        });
+    }
+    g.finish();
+}
+
+fn recover(c: &mut Criterion) {
+    let mut g = c.benchmark_group("recover_signer");
+    for (name, tx) in [("legacy", legacy()), ("eip1559", eip1559())] {
+        g.bench_function(name, |b| b.iter(|| black_box(&tx).recover_signer().unwrap()));
+    }
+    g.finish();
+}
+
+criterion_group!(benches, encode, decode, recover);
+criterion_main!(benches);
@@ -6,6 +6,7 @@ use alloc::vec::Vec;
 use alloy_eips::{
     eip1559::{calc_next_block_base_fee, BaseFeeParams},
     eip1898::BlockWithParent,
+    eip7685::Requests,
     eip7840::BlobParams,
     merge::ALLOWED_FUTURE_BLOCK_TIME_SECONDS,
     BlockNumHash,
@@ -683,6 +684,16 @@ pub trait BlockHeader {
     fn is_nonce_zero(&self) -> bool {
         self.nonce().is_some_and(|nonce| nonce.is_zero())
     }
+
+    /// Checks if the given [EIP-7685] requests match the requests hash of the header.
+    ///
+    /// If the header has no requests hash, i.e. it is a pre-Prague header, then this returns
+    /// `false`.
+    ///
+    /// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+    fn is_requests_hash_valid(&self, requests: &Requests) -> bool {
+        self.requests_hash().is_some_and(|hash| hash == requests.requests_hash())
+    }
 }
 
 impl BlockHeader for Header {
@@ -1067,4 +1078,15 @@ mod tests {
         let decoded = Header::decode(&mut v.as_slice()).unwrap();
         assert_eq!(decoded, header);
     }
+
+    #[test]
+    fn requests_hash_validation() {
+        let requests = Requests::new(vec![alloy_primitives::bytes!("0001")]);
+        let mut header = Header::default();
+        assert!(!header.is_requests_hash_valid(&requests));
+
+        header.requests_hash = Some(requests.requests_hash());
+        assert!(header.is_requests_hash_valid(&requests));
+        assert!(!header.is_requests_hash_valid(&Requests::default()));
+    }
 }
@@ -3,6 +3,9 @@
 mod header;
 pub use header::{BlockHeader, Header};
 
+mod stats;
+pub use stats::BlockBodyStats;
+
 #[cfg(all(feature = "serde", feature = "serde-bincode-compat"))]
 pub(crate) use header::serde_bincode_compat;
 
@@ -0,0 +1,125 @@
+//! Block body statistics.
+
+use super::{Block, BlockBody};
+use crate::Transaction;
+use alloc::collections::BTreeMap;
+use alloy_rlp::{Encodable, Header as RlpHeader};
+
+/// Aggregated statistics of the transactions in a [`BlockBody`].
+///
+/// All values are computed in a single pass over the transactions, see [`BlockBody::stats`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockBodyStats {
+    /// The number of transactions.
+    pub transaction_count: usize,
+    /// The number of transactions per [EIP-2718] transaction type.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub transaction_types: BTreeMap<u8, usize>,
+    /// The total number of calldata bytes across all transactions.
+    pub calldata_bytes: usize,
+    /// The total number of blobs referenced by blob transactions.
+    pub blob_count: usize,
+    /// The total blob gas used by blob transactions.
+    pub blob_gas_used: u64,
+    /// The RLP encoded length of the list of transactions.
+    pub transactions_rlp_length: usize,
+    /// The RLP encoded length of the block body.
+    pub rlp_length: usize,
+}
+
+impl BlockBodyStats {
+    /// Returns the number of transactions of the given type.
+    pub fn count_of(&self, ty: u8) -> usize {
+        self.transaction_types.get(&ty).copied().unwrap_or_default()
+    }
+}
+
+impl<T: Transaction + Encodable> BlockBody<T> {
+    /// Computes the [`BlockBodyStats`] of this body.
+    ///
+    /// The RLP lengths are derived from the lengths of the individual transactions, so the body
+    /// is never encoded.
+    pub fn stats(&self) -> BlockBodyStats {
+        let mut stats = BlockBodyStats::default();
+        let mut transactions_payload_length = 0;
+        for tx in &self.transactions {
+            stats.transaction_count += 1;
+            *stats.transaction_types.entry(tx.ty()).or_default() += 1;
+            stats.calldata_bytes += tx.input().len();
+            if let Some(hashes) = tx.blob_versioned_hashes() {
+                stats.blob_count += hashes.len();
+            }
+            stats.blob_gas_used += tx.blob_gas_used().unwrap_or_default();
+            transactions_payload_length += tx.length();
+        }
+        stats.transactions_rlp_length = list_length(transactions_payload_length);
+
+        let body_payload_length = stats.transactions_rlp_length
+            + self.ommers.length()
+            + self.withdrawals.as_ref().map(Encodable::length).unwrap_or_default();
+        stats.rlp_length = list_length(body_payload_length);
+        stats
+    }
+}
+
+impl<T: Transaction + Encodable, H> Block<T, H> {
+    /// Computes the [`BlockBodyStats`] of this block's body.
+    ///
+    /// See [`BlockBody::stats`].
+    pub fn stats(&self) -> BlockBodyStats {
+        self.body.stats()
+    }
+}
+
+/// Returns the length of an RLP list with the given payload length.
+fn list_length(payload_length: usize) -> usize {
+    RlpHeader { list: true, payload_length }.length_with_payload()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Signed, TxEip1559, TxEip4844, TxEnvelope, TxLegacy};
+    use alloy_eips::{
+        eip2718::{EIP1559_TX_TYPE_ID, EIP4844_TX_TYPE_ID, LEGACY_TX_TYPE_ID},
+        eip4844::DATA_GAS_PER_BLOB,
+        eip4895::{Withdrawal, Withdrawals},
+    };
+    use alloy_primitives::{Bytes, PrimitiveSignature as Signature, B256};
+
+    #[test]
+    fn body_stats() {
+        let sig = Signature::test_signature();
+        let legacy = TxLegacy { input: Bytes::from(vec![1; 10]), ..Default::default() };
+        let eip1559 = TxEip1559 { input: Bytes::from(vec![2; 4]), ..Default::default() };
+        let eip4844 =
+            TxEip4844 { blob_versioned_hashes: vec![B256::ZERO; 3], ..Default::default() };
+        let transactions = vec![
+            TxEnvelope::Legacy(Signed::new_unchecked(legacy, sig, B256::ZERO)),
+            TxEnvelope::Eip1559(Signed::new_unchecked(eip1559.clone(), sig, B256::ZERO)),
+            TxEnvelope::Eip1559(Signed::new_unchecked(eip1559, sig, B256::ZERO)),
+            TxEnvelope::Eip4844(Signed::new_unchecked(eip4844.into(), sig, B256::ZERO)),
+        ];
+        let body = BlockBody {
+            transactions,
+            ommers: Vec::new(),
+            withdrawals: Some(Withdrawals::new(vec![Withdrawal::default()])),
+        };
+
+        let stats = body.stats();
+        assert_eq!(stats.transaction_count, 4);
+        assert_eq!(stats.count_of(LEGACY_TX_TYPE_ID), 1);
+        assert_eq!(stats.count_of(EIP1559_TX_TYPE_ID), 2);
+        assert_eq!(stats.count_of(EIP4844_TX_TYPE_ID), 1);
+        assert_eq!(stats.calldata_bytes, 18);
+        assert_eq!(stats.blob_count, 3);
+        assert_eq!(stats.blob_gas_used, 3 * DATA_GAS_PER_BLOB);
+        assert_eq!(stats.transactions_rlp_length, body.transactions.length());
+        assert_eq!(stats.rlp_length, body.length());
+        assert_eq!(stats.rlp_length, alloy_rlp::encode(&body).len());
+
+        let empty = BlockBody::<TxEnvelope>::default();
+        assert_eq!(empty.stats().rlp_length, empty.length());
+    }
+}
@@ -0,0 +1,686 @@
+//! Hand-written JSON encoding and decoding for hot-path consensus types.
+//!
+//! These bypass serde and produce the same output as the serde implementations, for users that
+//! ingest large amounts of headers or receipts and profile JSON handling as a bottleneck.
+//!
+//! Decoding ignores unknown fields, so the JSON-RPC representations, which contain additional
+//! fields such as the block hash, can be decoded as well.
+
+use crate::{Eip658Value, Header, Receipt};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{hex, Address, Bloom, Bytes, Log, LogData, B256, B64, U256};
+use core::fmt;
+
+/// Maximum nesting depth of skipped values.
+const MAX_DEPTH: usize = 128;
+
+/// Encodes a value as JSON without going through serde.
+pub trait JsonEncode {
+    /// Appends the JSON encoding of the value to `out`.
+    fn encode_json(&self, out: &mut String);
+
+    /// Returns the JSON encoding of the value.
+    fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.encode_json(&mut out);
+        out
+    }
+}
+
+/// Decodes a value from JSON without going through serde.
+pub trait JsonDecode: Sized {
+    /// Decodes a value from the parser, which is positioned at the start of the value.
+    #[doc(hidden)]
+    fn decode_from(parser: &mut Parser<'_>) -> Result<Self, JsonError>;
+
+    /// Decodes a value from a JSON string. Trailing characters other than whitespace are an
+    /// error.
+    fn decode_json(input: &str) -> Result<Self, JsonError> {
+        let mut parser = Parser::new(input);
+        let value = Self::decode_from(&mut parser)?;
+        parser.finish()?;
+        Ok(value)
+    }
+}
+
+/// An error decoding JSON.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonError {
+    /// The byte offset in the input at which the error occurred.
+    pub offset: usize,
+    /// The kind of the error.
+    pub kind: JsonErrorKind,
+}
+
+/// The kind of a [`JsonError`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsonErrorKind {
+    /// The input ended unexpectedly.
+    UnexpectedEof,
+    /// An unexpected character was encountered.
+    UnexpectedChar(char),
+    /// A string contains an escape sequence where a hex value was expected.
+    UnsupportedEscape,
+    /// Values are nested too deeply.
+    DepthLimit,
+    /// A value is not a valid hex string or quantity.
+    InvalidValue(&'static str),
+    /// A required field is missing.
+    MissingField(&'static str),
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            JsonErrorKind::UnexpectedEof => f.write_str("unexpected end of input")?,
+            JsonErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {c:?}")?,
+            JsonErrorKind::UnsupportedEscape => f.write_str("unsupported escape in hex string")?,
+            JsonErrorKind::DepthLimit => f.write_str("nesting depth limit exceeded")?,
+            JsonErrorKind::InvalidValue(field) => write!(f, "invalid value for `{field}`")?,
+            JsonErrorKind::MissingField(field) => write!(f, "missing field `{field}`")?,
+        }
+        write!(f, " at offset {}", self.offset)
+    }
+}
+
+impl core::error::Error for JsonError {}
+
+/// A minimal JSON pull parser.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    const fn new(input: &'a str) -> Self {
+        Self { input: input.as_bytes(), pos: 0 }
+    }
+
+    const fn error(&self, kind: JsonErrorKind) -> JsonError {
+        JsonError { offset: self.pos, kind }
+    }
+
+    fn peek(&mut self) -> Result<u8, JsonError> {
+        while let Some(&b) = self.input.get(self.pos) {
+            if !matches!(b, b' ' | b'\t' | b'\n' | b'\r') {
+                return Ok(b);
+            }
+            self.pos += 1;
+        }
+        Err(self.error(JsonErrorKind::UnexpectedEof))
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), JsonError> {
+        let b = self.peek()?;
+        if b != expected {
+            return Err(self.error(JsonErrorKind::UnexpectedChar(b as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Consumes `true`, `false` or `null`.
+    fn literal(&mut self, literal: &[u8]) -> Result<(), JsonError> {
+        if self.input[self.pos..].starts_with(literal) {
+            self.pos += literal.len();
+            Ok(())
+        } else if self.pos + literal.len() > self.input.len() {
+            Err(self.error(JsonErrorKind::UnexpectedEof))
+        } else {
+            Err(self.error(JsonErrorKind::UnexpectedChar(self.input[self.pos] as char)))
+        }
+    }
+
+    /// Consumes `null` if it is the next value.
+    fn null(&mut self) -> Result<bool, JsonError> {
+        if self.peek()? == b'n' {
+            self.literal(b"null")?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Parses a string without escape sequences.
+    fn str(&mut self) -> Result<&'a str, JsonError> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        loop {
+            match self.input.get(self.pos) {
+                Some(b'"') => break,
+                Some(b'\\') => return Err(self.error(JsonErrorKind::UnsupportedEscape)),
+                Some(_) => self.pos += 1,
+                None => return Err(self.error(JsonErrorKind::UnexpectedEof)),
+            }
+        }
+        self.pos += 1;
+        // The input is a `str` and the string is delimited by ASCII quotes.
+        Ok(core::str::from_utf8(&self.input[start..self.pos - 1]).expect("valid utf-8"))
+    }
+
+    /// Skips a string, including escape sequences.
+    fn skip_str(&mut self) -> Result<(), JsonError> {
+        self.expect(b'"')?;
+        loop {
+            match self.input.get(self.pos) {
+                Some(b'"') => break,
+                Some(b'\\') => self.pos += 2,
+                Some(_) => self.pos += 1,
+                None => return Err(self.error(JsonErrorKind::UnexpectedEof)),
+            }
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Skips a value of any type.
+    fn skip_value(&mut self, depth: usize) -> Result<(), JsonError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error(JsonErrorKind::DepthLimit));
+        }
+        match self.peek()? {
+            b'"' => self.skip_str(),
+            b'{' => self.object(|parser, _| parser.skip_value(depth + 1)),
+            b'[' => self.array(|parser| parser.skip_value(depth + 1)),
+            b't' => self.literal(b"true"),
+            b'f' => self.literal(b"false"),
+            b'n' => self.literal(b"null"),
+            b'-' | b'0'..=b'9' => {
+                self.number()?;
+                Ok(())
+            }
+            b => Err(self.error(JsonErrorKind::UnexpectedChar(b as char))),
+        }
+    }
+
+    /// Parses a number, returning its text.
+    fn number(&mut self) -> Result<&'a str, JsonError> {
+        self.peek()?;
+        let start = self.pos;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.input.get(self.pos) {
+            self.pos += 1;
+        }
+        Ok(core::str::from_utf8(&self.input[start..self.pos]).expect("valid utf-8"))
+    }
+
+    /// Parses an object, calling `f` with each key and the parser positioned at its value.
+    fn object(
+        &mut self,
+        mut f: impl FnMut(&mut Self, &'a str) -> Result<(), JsonError>,
+    ) -> Result<(), JsonError> {
+        self.expect(b'{')?;
+        if self.peek()? == b'}' {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            let key = self.str()?;
+            self.expect(b':')?;
+            f(self, key)?;
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b'}' => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                b => return Err(self.error(JsonErrorKind::UnexpectedChar(b as char))),
+            }
+        }
+    }
+
+    /// Parses an array, calling `f` with the parser positioned at each element.
+    fn array(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> Result<(), JsonError>,
+    ) -> Result<(), JsonError> {
+        self.expect(b'[')?;
+        if self.peek()? == b']' {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            f(self)?;
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b']' => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                b => return Err(self.error(JsonErrorKind::UnexpectedChar(b as char))),
+            }
+        }
+    }
+
+    /// Ensures only whitespace is left.
+    fn finish(&mut self) -> Result<(), JsonError> {
+        match self.peek() {
+            Ok(b) => Err(self.error(JsonErrorKind::UnexpectedChar(b as char))),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn hex<const N: usize>(&mut self, field: &'static str) -> Result<[u8; N], JsonError> {
+        let start = self.pos;
+        let s = self.str()?;
+        let mut out = [0; N];
+        hex::decode_to_slice(strip_0x(s), &mut out)
+            .map_err(|_| JsonError { offset: start, kind: JsonErrorKind::InvalidValue(field) })?;
+        Ok(out)
+    }
+
+    fn bytes(&mut self, field: &'static str) -> Result<Bytes, JsonError> {
+        let start = self.pos;
+        let s = self.str()?;
+        hex::decode(strip_0x(s))
+            .map(Into::into)
+            .map_err(|_| JsonError { offset: start, kind: JsonErrorKind::InvalidValue(field) })
+    }
+
+    /// Parses a hex quantity, or a decimal number.
+    fn u64(&mut self, field: &'static str) -> Result<u64, JsonError> {
+        let start = self.pos;
+        let invalid = |_| JsonError { offset: start, kind: JsonErrorKind::InvalidValue(field) };
+        if self.peek()? == b'"' {
+            let s = self.str()?;
+            let digits = s.strip_prefix("0x").ok_or(()).map_err(invalid)?;
+            u64::from_str_radix(digits, 16).map_err(|_| invalid(()))
+        } else {
+            self.number()?.parse().map_err(|_| invalid(()))
+        }
+    }
+
+    fn opt_u64(&mut self, field: &'static str) -> Result<Option<u64>, JsonError> {
+        if self.null()? {
+            return Ok(None);
+        }
+        self.u64(field).map(Some)
+    }
+
+    fn u256(&mut self, field: &'static str) -> Result<U256, JsonError> {
+        let start = self.pos;
+        let invalid = || JsonError { offset: start, kind: JsonErrorKind::InvalidValue(field) };
+        if self.peek()? == b'"' {
+            let digits = self.str()?.strip_prefix("0x").ok_or_else(invalid)?;
+            U256::from_str_radix(digits, 16).map_err(|_| invalid())
+        } else {
+            U256::from_str_radix(self.number()?, 10).map_err(|_| invalid())
+        }
+    }
+
+    fn opt_b256(&mut self, field: &'static str) -> Result<Option<B256>, JsonError> {
+        if self.null()? {
+            return Ok(None);
+        }
+        self.hex(field).map(|b| Some(B256::from(b)))
+    }
+}
+
+fn strip_0x(s: &str) -> &str {
+    s.strip_prefix("0x").unwrap_or(s)
+}
+
+fn missing(parser: &Parser<'_>, field: &'static str) -> JsonError {
+    parser.error(JsonErrorKind::MissingField(field))
+}
+
+/// Appends the `0x`-prefixed lowercase hex encoding of `bytes`.
+fn write_hex(out: &mut String, bytes: &[u8]) {
+    out.push_str("0x");
+    let mut buf = [0; 128];
+    for chunk in bytes.chunks(buf.len() / 2) {
+        let len = chunk.len() * 2;
+        hex::encode_to_slice(chunk, &mut buf[..len]).expect("buffer fits chunk");
+        // Hex digits are ASCII.
+        out.push_str(core::str::from_utf8(&buf[..len]).expect("valid utf-8"));
+    }
+}
+
+fn write_key(out: &mut String, first: &mut bool, key: &str) {
+    if !core::mem::take(first) {
+        out.push(',');
+    }
+    out.push('"');
+    out.push_str(key);
+    out.push_str("\":");
+}
+
+fn write_hex_field(out: &mut String, first: &mut bool, key: &str, bytes: &[u8]) {
+    write_key(out, first, key);
+    out.push('"');
+    write_hex(out, bytes);
+    out.push('"');
+}
+
+fn write_quantity_field(out: &mut String, first: &mut bool, key: &str, value: u64) {
+    use fmt::Write;
+    write_key(out, first, key);
+    let _ = write!(out, "\"0x{value:x}\"");
+}
+
+fn write_address_field(out: &mut String, first: &mut bool, key: &str, address: &Address) {
+    write_key(out, first, key);
+    out.push('"');
+    out.push_str(address.to_checksum_buffer(None).as_str());
+    out.push('"');
+}
+
+impl JsonEncode for Header {
+    fn encode_json(&self, out: &mut String) {
+        use fmt::Write;
+
+        let first = &mut true;
+        out.push('{');
+        write_hex_field(out, first, "parentHash", self.parent_hash.as_slice());
+        write_hex_field(out, first, "sha3Uncles", self.ommers_hash.as_slice());
+        write_address_field(out, first, "miner", &self.beneficiary);
+        write_hex_field(out, first, "stateRoot", self.state_root.as_slice());
+        write_hex_field(out, first, "transactionsRoot", self.transactions_root.as_slice());
+        write_hex_field(out, first, "receiptsRoot", self.receipts_root.as_slice());
+        write_hex_field(out, first, "logsBloom", self.logs_bloom.as_slice());
+        write_key(out, first, "difficulty");
+        let _ = write!(out, "\"{:#x}\"", self.difficulty);
+        write_quantity_field(out, first, "number", self.number);
+        write_quantity_field(out, first, "gasLimit", self.gas_limit);
+        write_quantity_field(out, first, "gasUsed", self.gas_used);
+        write_quantity_field(out, first, "timestamp", self.timestamp);
+        write_hex_field(out, first, "extraData", &self.extra_data);
+        write_hex_field(out, first, "mixHash", self.mix_hash.as_slice());
+        write_hex_field(out, first, "nonce", self.nonce.as_slice());
+        if let Some(base_fee) = self.base_fee_per_gas {
+            write_quantity_field(out, first, "baseFeePerGas", base_fee);
+        }
+        if let Some(root) = &self.withdrawals_root {
+            write_hex_field(out, first, "withdrawalsRoot", root.as_slice());
+        }
+        if let Some(blob_gas_used) = self.blob_gas_used {
+            write_quantity_field(out, first, "blobGasUsed", blob_gas_used);
+        }
+        if let Some(excess_blob_gas) = self.excess_blob_gas {
+            write_quantity_field(out, first, "excessBlobGas", excess_blob_gas);
+        }
+        if let Some(root) = &self.parent_beacon_block_root {
+            write_hex_field(out, first, "parentBeaconBlockRoot", root.as_slice());
+        }
+        if let Some(hash) = &self.requests_hash {
+            write_hex_field(out, first, "requestsHash", hash.as_slice());
+        }
+        out.push('}');
+    }
+}
+
+impl JsonDecode for Header {
+    fn decode_from(parser: &mut Parser<'_>) -> Result<Self, JsonError> {
+        let mut parent_hash = None;
+        let mut ommers_hash = None;
+        let mut beneficiary = None;
+        let mut state_root = None;
+        let mut transactions_root = None;
+        let mut receipts_root = None;
+        let mut logs_bloom = None;
+        let mut difficulty = None;
+        let mut number = None;
+        let mut gas_limit = None;
+        let mut gas_used = None;
+        let mut timestamp = None;
+        let mut extra_data = None;
+        let mut mix_hash = None;
+        let mut nonce = None;
+        let mut header = Self::default();
+
+        parser.object(|parser, key| {
+            match key {
+                "parentHash" => parent_hash = Some(B256::from(parser.hex("parentHash")?)),
+                "sha3Uncles" | "ommersHash" => {
+                    ommers_hash = Some(B256::from(parser.hex("sha3Uncles")?))
+                }
+                "miner" | "beneficiary" => beneficiary = Some(Address::from(parser.hex("miner")?)),
+                "stateRoot" => state_root = Some(B256::from(parser.hex("stateRoot")?)),
+                "transactionsRoot" => {
+                    transactions_root = Some(B256::from(parser.hex("transactionsRoot")?))
+                }
+                "receiptsRoot" => receipts_root = Some(B256::from(parser.hex("receiptsRoot")?)),
+                "logsBloom" => logs_bloom = Some(Bloom::from(parser.hex("logsBloom")?)),
+                "difficulty" => difficulty = Some(parser.u256("difficulty")?),
+                "number" => number = Some(parser.u64("number")?),
+                "gasLimit" => gas_limit = Some(parser.u64("gasLimit")?),
+                "gasUsed" => gas_used = Some(parser.u64("gasUsed")?),
+                "timestamp" => timestamp = Some(parser.u64("timestamp")?),
+                "extraData" => extra_data = Some(parser.bytes("extraData")?),
+                "mixHash" => mix_hash = Some(B256::from(parser.hex("mixHash")?)),
+                "nonce" => nonce = Some(B64::from(parser.hex("nonce")?)),
+                "baseFeePerGas" => header.base_fee_per_gas = parser.opt_u64("baseFeePerGas")?,
+                "withdrawalsRoot" => {
+                    header.withdrawals_root = parser.opt_b256("withdrawalsRoot")?
+                }
+                "blobGasUsed" => header.blob_gas_used = parser.opt_u64("blobGasUsed")?,
+                "excessBlobGas" => header.excess_blob_gas = parser.opt_u64("excessBlobGas")?,
+                "parentBeaconBlockRoot" => {
+                    header.parent_beacon_block_root = parser.opt_b256("parentBeaconBlockRoot")?
+                }
+                "requestsHash" => header.requests_hash = parser.opt_b256("requestsHash")?,
+                _ => parser.skip_value(0)?,
+            }
+            Ok(())
+        })?;
+
+        header.parent_hash = parent_hash.ok_or_else(|| missing(parser, "parentHash"))?;
+        header.ommers_hash = ommers_hash.ok_or_else(|| missing(parser, "sha3Uncles"))?;
+        header.beneficiary = beneficiary.ok_or_else(|| missing(parser, "miner"))?;
+        header.state_root = state_root.ok_or_else(|| missing(parser, "stateRoot"))?;
+        header.transactions_root =
+            transactions_root.ok_or_else(|| missing(parser, "transactionsRoot"))?;
+        header.receipts_root = receipts_root.ok_or_else(|| missing(parser, "receiptsRoot"))?;
+        header.logs_bloom = logs_bloom.ok_or_else(|| missing(parser, "logsBloom"))?;
+        header.difficulty = difficulty.ok_or_else(|| missing(parser, "difficulty"))?;
+        header.number = number.ok_or_else(|| missing(parser, "number"))?;
+        header.gas_limit = gas_limit.ok_or_else(|| missing(parser, "gasLimit"))?;
+        header.gas_used = gas_used.ok_or_else(|| missing(parser, "gasUsed"))?;
+        header.timestamp = timestamp.ok_or_else(|| missing(parser, "timestamp"))?;
+        header.extra_data = extra_data.ok_or_else(|| missing(parser, "extraData"))?;
+        header.mix_hash = mix_hash.ok_or_else(|| missing(parser, "mixHash"))?;
+        header.nonce = nonce.ok_or_else(|| missing(parser, "nonce"))?;
+        Ok(header)
+    }
+}
+
+impl JsonEncode for Log {
+    fn encode_json(&self, out: &mut String) {
+        let first = &mut true;
+        out.push('{');
+        write_address_field(out, first, "address", &self.address);
+        write_key(out, first, "topics");
+        out.push('[');
+        for (i, topic) in self.topics().iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push('"');
+            write_hex(out, topic.as_slice());
+            out.push('"');
+        }
+        out.push(']');
+        write_hex_field(out, first, "data", &self.data.data);
+        out.push('}');
+    }
+}
+
+impl JsonDecode for Log {
+    fn decode_from(parser: &mut Parser<'_>) -> Result<Self, JsonError> {
+        let mut address = None;
+        let mut topics = Vec::new();
+        let mut data = None;
+        parser.object(|parser, key| {
+            match key {
+                "address" => address = Some(Address::from(parser.hex("address")?)),
+                "topics" => parser.array(|parser| {
+                    topics.push(B256::from(parser.hex("topics")?));
+                    Ok(())
+                })?,
+                "data" => data = Some(parser.bytes("data")?),
+                _ => parser.skip_value(0)?,
+            }
+            Ok(())
+        })?;
+
+        let address = address.ok_or_else(|| missing(parser, "address"))?;
+        let data = data.ok_or_else(|| missing(parser, "data"))?;
+        let data = LogData::new(topics, data)
+            .ok_or_else(|| parser.error(JsonErrorKind::InvalidValue("topics")))?;
+        Ok(Self { address, data })
+    }
+}
+
+impl<T: JsonEncode> JsonEncode for Receipt<T> {
+    fn encode_json(&self, out: &mut String) {
+        let first = &mut true;
+        out.push('{');
+        match self.status {
+            Eip658Value::Eip658(status) => {
+                write_quantity_field(out, first, "status", u64::from(status))
+            }
+            Eip658Value::PostState(root) => write_hex_field(out, first, "root", root.as_slice()),
+        }
+        write_quantity_field(out, first, "cumulativeGasUsed", self.cumulative_gas_used);
+        write_key(out, first, "logs");
+        out.push('[');
+        for (i, log) in self.logs.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            log.encode_json(out);
+        }
+        out.push(']');
+        out.push('}');
+    }
+}
+
+impl<T: JsonDecode> JsonDecode for Receipt<T> {
+    fn decode_from(parser: &mut Parser<'_>) -> Result<Self, JsonError> {
+        let mut status = None;
+        let mut root = None;
+        let mut cumulative_gas_used = None;
+        let mut logs = None;
+        parser.object(|parser, key| {
+            match key {
+                "status" => match parser.u64("status")? {
+                    0 => status = Some(false),
+                    1 => status = Some(true),
+                    _ => return Err(parser.error(JsonErrorKind::InvalidValue("status"))),
+                },
+                "root" => root = parser.opt_b256("root")?,
+                "cumulativeGasUsed" => cumulative_gas_used = Some(parser.u64("cumulativeGasUsed")?),
+                "logs" => {
+                    let mut items = Vec::new();
+                    parser.array(|parser| {
+                        items.push(T::decode_from(parser)?);
+                        Ok(())
+                    })?;
+                    logs = Some(items);
+                }
+                _ => parser.skip_value(0)?,
+            }
+            Ok(())
+        })?;
+
+        // Like the serde implementation, prefer `status` if both are present.
+        let status = match (status, root) {
+            (Some(status), _) => Eip658Value::Eip658(status),
+            (None, Some(root)) => Eip658Value::PostState(root),
+            (None, None) => return Err(missing(parser, "status")),
+        };
+        Ok(Self {
+            status,
+            cumulative_gas_used: cumulative_gas_used
+                .ok_or_else(|| missing(parser, "cumulativeGasUsed"))?,
+            logs: logs.ok_or_else(|| missing(parser, "logs"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+    use alloy_primitives::{address, b256, bytes};
+
+    fn header() -> Header {
+        Header {
+            parent_hash: B256::repeat_byte(1),
+            beneficiary: address!("95222290dd7278aa3ddd389cc1e1d165cc4bafe5"),
+            difficulty: U256::from(0x2_0000_0000_u64),
+            number: 21_000_000,
+            gas_limit: 30_000_000,
+            gas_used: 12_345_678,
+            timestamp: 1_730_000_000,
+            extra_data: bytes!("6265617665726275696c642e6f7267"),
+            nonce: B64::with_last_byte(7),
+            base_fee_per_gas: Some(7),
+            withdrawals_root: Some(B256::repeat_byte(2)),
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(131_072),
+            parent_beacon_block_root: Some(B256::repeat_byte(3)),
+            ..Default::default()
+        }
+    }
+
+    fn receipt() -> Receipt {
+        Receipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 46_913,
+            logs: vec![Log::new_unchecked(
+                address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+                vec![b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")],
+                bytes!("0000000000000000000000000000000000000000000000000000000000000001"),
+            )],
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        for header in [Header::default(), header()] {
+            assert_eq!(Header::decode_json(&header.to_json()).unwrap(), header);
+        }
+        let receipt = receipt();
+        assert_eq!(Receipt::decode_json(&receipt.to_json()).unwrap(), receipt);
+        let receipt = Receipt::<Log> {
+            status: Eip658Value::PostState(B256::repeat_byte(9)),
+            ..Default::default()
+        };
+        assert_eq!(Receipt::decode_json(&receipt.to_json()).unwrap(), receipt);
+    }
+
+    #[test]
+    fn decodes_rpc_representation() {
+        let mut json = header().to_json();
+        json.pop();
+        json.push_str(r#","hash":"0x01","size":"0x2a","uncles":[],"withdrawals":[{"a":[1,{"b":"\"}"}]}],"totalDifficulty":null}"#);
+        assert_eq!(Header::decode_json(&json).unwrap(), header());
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let json = header().to_json();
+        let err = Header::decode_json(&json.replace("\"number\"", "\"other\"")).unwrap_err();
+        assert_eq!(err.kind, JsonErrorKind::MissingField("number"));
+        let err = Header::decode_json(&json.replace("\"0x1406f40\"", "\"1406f40\"")).unwrap_err();
+        assert_eq!(err.kind, JsonErrorKind::InvalidValue("number"));
+        let err = Header::decode_json(&json[..json.len() - 1]).unwrap_err();
+        assert_eq!(err.kind, JsonErrorKind::UnexpectedEof);
+        let err = Header::decode_json(&format!("{json} x")).unwrap_err();
+        assert_eq!(err.kind, JsonErrorKind::UnexpectedChar('x'));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn matches_serde() {
+        for header in [Header::default(), header()] {
+            let json = header.to_json();
+            assert_eq!(json, serde_json::to_string(&header).unwrap());
+            assert_eq!(serde_json::from_str::<Header>(&json).unwrap(), header);
+        }
+        let receipt = receipt();
+        assert_eq!(receipt.to_json(), serde_json::to_string(&receipt).unwrap());
+    }
+}
@@ -18,26 +18,38 @@ pub use alloy_trie::TrieAccount;
 pub use alloy_trie::TrieAccount as Account;
 
 mod block;
-pub use block::{Block, BlockBody, BlockHeader, Header};
+pub use block::{Block, BlockBody, BlockBodyStats, BlockHeader, Header};
 
 pub mod constants;
 pub use constants::{EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH};
 
+#[cfg(feature = "fast-json")]
+pub mod json;
+
 mod receipt;
 pub use receipt::{
-    Eip2718EncodableReceipt, Eip658Value, Receipt, ReceiptEnvelope, ReceiptWithBloom, Receipts,
-    RlpDecodableReceipt, RlpEncodableReceipt, TxReceipt,
+    BloomMismatch, Eip2718EncodableReceipt, Eip658Value, Receipt, ReceiptEnvelope,
+    ReceiptWithBloom, Receipts, RlpDecodableReceipt, RlpEncodableReceipt, TxReceipt,
 };
 
 pub mod conditional;
 pub mod proofs;
 
+pub mod sealed;
+#[cfg(feature = "std")]
+pub use sealed::LazySealed;
+pub use sealed::SealableExt;
+
+#[cfg(feature = "revm")]
+pub mod revm;
+
 pub mod transaction;
 #[cfg(feature = "kzg")]
 pub use transaction::BlobTransactionValidationError;
 pub use transaction::{
-    SignableTransaction, Transaction, TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant,
-    TxEip4844WithSidecar, TxEip7702, TxEnvelope, TxLegacy, TxType, TypedTransaction,
+    MaybeOpaque, OpaqueTxEnvelope, SignableTransaction, Transaction, TxEip1559, TxEip2930,
+    TxEip4844, TxEip4844Variant, TxEip4844WithSidecar, TxEip7702, TxEnvelope, TxLegacy, TxType,
+    TypedTransaction,
 };
 
 pub use alloy_eips::{
@@ -1,9 +1,9 @@
 //! Helper function for calculating Merkle proofs and hashes.
 
-use crate::EMPTY_OMMER_ROOT_HASH;
+use crate::{BloomMismatch, TxReceipt, EMPTY_OMMER_ROOT_HASH};
 use alloc::vec::Vec;
 use alloy_eips::{eip2718::Encodable2718, eip4895::Withdrawal};
-use alloy_primitives::{keccak256, B256};
+use alloy_primitives::{keccak256, Bloom, B256};
 use alloy_rlp::Encodable;
 
 #[doc(inline)]
@@ -53,14 +53,38 @@ where
     ordered_trie_root_with_encoder(receipts, |r, buf| r.encode_2718(buf))
 }
 
+/// Calculates the logs bloom of a block, the union of the blooms of its receipts.
+pub fn calculate_logs_bloom<T>(receipts: &[T]) -> Bloom
+where
+    T: TxReceipt,
+{
+    let mut bloom = Bloom::ZERO;
+    for receipt in receipts {
+        bloom.accrue_bloom(&receipt.bloom());
+    }
+    bloom
+}
+
+/// Verifies that the logs bloom of a block matches the blooms of its receipts.
+///
+/// The blooms of the receipts are taken as they are, see [`TxReceipt::bloom`]. When the receipts
+/// are not trusted either, also verify their blooms against their logs, e.g. with
+/// [`ReceiptWithBloom::verify_bloom`](crate::ReceiptWithBloom::verify_bloom).
+pub fn verify_logs_bloom<T>(logs_bloom: Bloom, receipts: &[T]) -> Result<(), BloomMismatch>
+where
+    T: TxReceipt,
+{
+    BloomMismatch::check(logs_bloom, calculate_logs_bloom(receipts))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        Eip2718EncodableReceipt, Eip658Value, Receipt, ReceiptWithBloom, RlpEncodableReceipt,
-        TxType, Typed2718,
+        Eip2718EncodableReceipt, Eip658Value, Receipt, ReceiptEnvelope, ReceiptWithBloom,
+        RlpEncodableReceipt, TxType, Typed2718,
     };
-    use alloy_primitives::{b256, bloom, Address, Log, LogData};
+    use alloy_primitives::{b256, bloom, Address, BloomInput, Log, LogData};
 
     struct TypedReceipt {
         ty: TxType,
@@ -143,4 +167,32 @@ mod tests {
         let root = calculate_receipt_root(&receipt);
         assert_eq!(root, b256!("fe70ae4a136d98944951b2123859698d59ad251a381abc9960fa81cae3d0d4a0"));
     }
+
+    #[test]
+    fn verify_blooms() {
+        let log =
+            |address| Log { address, data: LogData::new_unchecked(vec![], Default::default()) };
+        let receipt =
+            |logs| Receipt { status: Eip658Value::success(), cumulative_gas_used: 0, logs };
+        let first = receipt(vec![log(Address::with_last_byte(1))]).with_bloom();
+        let second = receipt(vec![log(Address::with_last_byte(2))]).with_bloom();
+        assert!(first.verify_bloom().is_ok());
+        assert_ne!(first.logs_bloom, second.logs_bloom);
+
+        let receipts = vec![first.clone(), second.clone()];
+        let logs_bloom = calculate_logs_bloom(&receipts);
+        assert!(logs_bloom.contains_input(BloomInput::Raw(Address::with_last_byte(1).as_slice())));
+        assert!(verify_logs_bloom(logs_bloom, &receipts).is_ok());
+        assert_eq!(
+            verify_logs_bloom(first.logs_bloom, &receipts),
+            Err(BloomMismatch { expected: first.logs_bloom, computed: logs_bloom })
+        );
+        assert_eq!(calculate_logs_bloom::<ReceiptWithBloom>(&[]), Bloom::ZERO);
+
+        let tampered = ReceiptWithBloom::new(second.receipt.clone(), first.logs_bloom);
+        assert_eq!(tampered.bloom_slow(), second.logs_bloom);
+        assert!(tampered.verify_bloom().is_err());
+        assert!(ReceiptEnvelope::Eip1559(tampered).verify_bloom().is_err());
+        assert!(ReceiptEnvelope::Legacy(second).verify_bloom().is_ok());
+    }
 }
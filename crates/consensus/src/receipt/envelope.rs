@@ -1,6 +1,6 @@
 use core::fmt;
 
-use crate::{Eip658Value, Receipt, ReceiptWithBloom, TxReceipt, TxType};
+use crate::{BloomMismatch, Eip658Value, Receipt, ReceiptWithBloom, TxReceipt, TxType};
 use alloy_eips::{
     eip2718::{
         Decodable2718, Eip2718Error, Eip2718Result, Encodable2718, EIP1559_TX_TYPE_ID,
@@ -114,6 +114,18 @@ impl<T> ReceiptEnvelope<T> {
     }
 }
 
+impl<T: AsRef<Log>> ReceiptEnvelope<T> {
+    /// Recalculates the bloom filter from the receipt logs, ignoring the receipt's bloom.
+    pub fn bloom_slow(&self) -> Bloom {
+        self.as_receipt().unwrap().bloom_slow()
+    }
+
+    /// Verifies that the receipt's bloom matches the receipt logs.
+    pub fn verify_bloom(&self) -> Result<(), BloomMismatch> {
+        BloomMismatch::check(*self.logs_bloom(), self.bloom_slow())
+    }
+}
+
 impl<T> TxReceipt for ReceiptEnvelope<T>
 where
     T: Clone + fmt::Debug + PartialEq + Eq + Send + Sync,
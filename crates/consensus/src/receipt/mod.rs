@@ -6,7 +6,7 @@ mod envelope;
 pub use envelope::ReceiptEnvelope;
 
 mod receipts;
-pub use receipts::{Receipt, ReceiptWithBloom, Receipts};
+pub use receipts::{BloomMismatch, Receipt, ReceiptWithBloom, Receipts};
 
 mod status;
 pub use status::Eip658Value;
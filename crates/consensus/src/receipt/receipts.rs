@@ -271,6 +271,53 @@ impl<R> ReceiptWithBloom<R> {
     }
 }
 
+impl<R> ReceiptWithBloom<R>
+where
+    R: TxReceipt,
+    R::Log: AsRef<Log>,
+{
+    /// Recalculates the bloom filter from the logs of the receipt, ignoring
+    /// [`logs_bloom`](Self::logs_bloom).
+    pub fn bloom_slow(&self) -> Bloom {
+        self.receipt.logs().iter().map(AsRef::as_ref).collect()
+    }
+
+    /// Verifies that [`logs_bloom`](Self::logs_bloom) matches the logs of the receipt.
+    ///
+    /// This detects corrupted or tampered receipts, e.g. when they were fetched from an untrusted
+    /// endpoint.
+    pub fn verify_bloom(&self) -> Result<(), BloomMismatch> {
+        BloomMismatch::check(self.logs_bloom, self.bloom_slow())
+    }
+}
+
+/// A bloom filter does not match the logs it was computed from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BloomMismatch {
+    /// The bloom filter that was supplied.
+    pub expected: Bloom,
+    /// The bloom filter computed from the logs.
+    pub computed: Bloom,
+}
+
+impl BloomMismatch {
+    /// Returns an error if the blooms are not equal.
+    pub(crate) fn check(expected: Bloom, computed: Bloom) -> Result<(), Self> {
+        if expected != computed {
+            return Err(Self { expected, computed });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BloomMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("logs bloom does not match the logs")
+    }
+}
+
+impl core::error::Error for BloomMismatch {}
+
 impl<R: RlpEncodableReceipt> Encodable for ReceiptWithBloom<R> {
     fn encode(&self, out: &mut dyn BufMut) {
         self.receipt.rlp_encode_with_bloom(&self.logs_bloom, out);
@@ -0,0 +1,187 @@
+//! Conversions between consensus types and [`revm_primitives`] types.
+//!
+//! These keep the mapping of every transaction type onto revm's environment in one place, so
+//! simulation code can execute alloy transactions without maintaining its own conversion layer.
+
+use crate::{transaction::Recovered, BlockHeader, Receipt, Transaction};
+use alloc::vec::Vec;
+use alloy_eips::eip7840::BlobParams;
+use alloy_primitives::{Address, Log, U256};
+use revm_primitives::{AuthorizationList, BlobExcessGasAndPrice, BlockEnv, ExecutionResult, TxEnv};
+
+/// Fills the [`TxEnv`] with the fields of the given transaction sent by `sender`.
+///
+/// Dynamic fee transactions set the gas price to their max fee per gas and the priority fee to
+/// their max priority fee per gas, as expected by revm.
+pub fn fill_tx_env<T: Transaction + ?Sized>(tx_env: &mut TxEnv, tx: &T, sender: Address) {
+    tx_env.caller = sender;
+    tx_env.gas_limit = tx.gas_limit();
+    tx_env.gas_price = U256::from(tx.max_fee_per_gas());
+    tx_env.gas_priority_fee = tx.max_priority_fee_per_gas().map(U256::from);
+    tx_env.transact_to = tx.kind();
+    tx_env.value = tx.value();
+    tx_env.data = tx.input().clone();
+    tx_env.nonce = Some(tx.nonce());
+    tx_env.chain_id = tx.chain_id();
+    tx_env.access_list = tx.access_list().map(|list| list.0.clone()).unwrap_or_default();
+    tx_env.blob_hashes = tx.blob_versioned_hashes().map(<[_]>::to_vec).unwrap_or_default();
+    tx_env.max_fee_per_blob_gas = tx.max_fee_per_blob_gas().map(U256::from);
+    tx_env.authorization_list =
+        tx.authorization_list().map(|list| AuthorizationList::Signed(list.to_vec()));
+}
+
+/// Returns a [`TxEnv`] for the given transaction sent by `sender`, see [`fill_tx_env`].
+pub fn tx_env<T: Transaction + ?Sized>(tx: &T, sender: Address) -> TxEnv {
+    let mut tx_env = TxEnv::default();
+    fill_tx_env(&mut tx_env, tx, sender);
+    tx_env
+}
+
+impl<T: Transaction> From<&Recovered<T>> for TxEnv {
+    fn from(tx: &Recovered<T>) -> Self {
+        tx_env(tx.tx(), tx.signer())
+    }
+}
+
+/// Fills the [`BlockEnv`] with the fields of the given header.
+///
+/// The blob gas price is derived from the header's excess blob gas with the given
+/// [`BlobParams`]. Post-merge headers, which have a zero difficulty, set the `prevrandao` value
+/// to the header's mix hash.
+pub fn fill_block_env<H: BlockHeader + ?Sized>(
+    block_env: &mut BlockEnv,
+    header: &H,
+    blob_params: BlobParams,
+) {
+    block_env.number = U256::from(header.number());
+    block_env.coinbase = header.beneficiary();
+    block_env.timestamp = U256::from(header.timestamp());
+    block_env.gas_limit = U256::from(header.gas_limit());
+    block_env.basefee = U256::from(header.base_fee_per_gas().unwrap_or_default());
+    block_env.difficulty = header.difficulty();
+    block_env.prevrandao = if header.difficulty().is_zero() { header.mix_hash() } else { None };
+    block_env.blob_excess_gas_and_price =
+        header.excess_blob_gas().map(|excess_blob_gas| BlobExcessGasAndPrice {
+            excess_blob_gas,
+            blob_gasprice: blob_params.calc_blob_fee(excess_blob_gas),
+        });
+}
+
+/// Returns a [`BlockEnv`] for the given header, see [`fill_block_env`].
+pub fn block_env<H: BlockHeader + ?Sized>(header: &H, blob_params: BlobParams) -> BlockEnv {
+    let mut block_env = BlockEnv::default();
+    fill_block_env(&mut block_env, header, blob_params);
+    block_env
+}
+
+impl Receipt<Log> {
+    /// Creates the receipt of a transaction from its [`ExecutionResult`].
+    ///
+    /// `cumulative_gas_used` is the gas used by the preceding transactions of the block, the gas
+    /// used by this transaction is added to it. Only successful executions have logs.
+    pub fn from_execution_result(result: ExecutionResult, cumulative_gas_used: u64) -> Self {
+        let status = result.is_success().into();
+        let cumulative_gas_used = cumulative_gas_used + result.gas_used();
+        let logs = match result {
+            ExecutionResult::Success { logs, .. } => logs,
+            _ => Vec::new(),
+        };
+        Self { status, cumulative_gas_used, logs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Header, TxEip1559, TxLegacy, TxReceipt};
+    use alloy_eips::eip2930::{AccessList, AccessListItem};
+    use alloy_primitives::{address, b256, Bytes, LogData, TxKind};
+    use revm_primitives::{Output, SuccessReason};
+
+    #[test]
+    fn converts_transactions() {
+        let sender = address!("00000000000000000000000000000000000000aa");
+        let to = address!("00000000000000000000000000000000000000bb");
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 2,
+            gas_limit: 21_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 3,
+            to: TxKind::Call(to),
+            value: U256::from(4),
+            access_list: AccessList(vec![AccessListItem { address: to, storage_keys: vec![] }]),
+            input: Bytes::from_static(&[1, 2]),
+        };
+
+        let env = tx_env(&tx, sender);
+        assert_eq!(env.caller, sender);
+        assert_eq!(env.gas_limit, 21_000);
+        assert_eq!(env.gas_price, U256::from(100));
+        assert_eq!(env.gas_priority_fee, Some(U256::from(3)));
+        assert_eq!(env.transact_to, TxKind::Call(to));
+        assert_eq!(env.value, U256::from(4));
+        assert_eq!(env.data, tx.input);
+        assert_eq!(env.nonce, Some(2));
+        assert_eq!(env.chain_id, Some(1));
+        assert_eq!(env.access_list, tx.access_list.0);
+        assert!(env.authorization_list.is_none());
+
+        let legacy = TxLegacy { gas_price: 7, ..Default::default() };
+        let env = TxEnv::from(&Recovered::new_unchecked(legacy, sender));
+        assert_eq!(env.gas_price, U256::from(7));
+        assert_eq!(env.gas_priority_fee, None);
+        assert_eq!(env.chain_id, None);
+    }
+
+    #[test]
+    fn converts_headers() {
+        let mix_hash = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+        let header = Header {
+            number: 10,
+            timestamp: 20,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(7),
+            mix_hash,
+            excess_blob_gas: Some(0),
+            ..Default::default()
+        };
+
+        let env = block_env(&header, BlobParams::cancun());
+        assert_eq!(env.number, U256::from(10));
+        assert_eq!(env.timestamp, U256::from(20));
+        assert_eq!(env.gas_limit, U256::from(30_000_000));
+        assert_eq!(env.basefee, U256::from(7));
+        assert_eq!(env.prevrandao, Some(mix_hash));
+        assert_eq!(
+            env.blob_excess_gas_and_price,
+            Some(BlobExcessGasAndPrice { excess_blob_gas: 0, blob_gasprice: 1 })
+        );
+
+        let header = Header { difficulty: U256::from(1), ..header };
+        assert_eq!(block_env(&header, BlobParams::cancun()).prevrandao, None);
+    }
+
+    #[test]
+    fn converts_execution_results() {
+        let log =
+            Log { address: Address::ZERO, data: LogData::new_unchecked(vec![], Bytes::new()) };
+        let success = ExecutionResult::Success {
+            reason: SuccessReason::Stop,
+            gas_used: 21_000,
+            gas_refunded: 0,
+            logs: vec![log.clone()],
+            output: Output::Call(Bytes::new()),
+        };
+        let receipt = Receipt::from_execution_result(success, 1_000);
+        assert!(receipt.status());
+        assert_eq!(receipt.cumulative_gas_used, 22_000);
+        assert_eq!(receipt.logs, vec![log]);
+
+        let revert = ExecutionResult::Revert { gas_used: 500, output: Bytes::new() };
+        let receipt = Receipt::from_execution_result(revert, 0);
+        assert!(!receipt.status());
+        assert_eq!(receipt.cumulative_gas_used, 500);
+        assert!(receipt.logs.is_empty());
+    }
+}
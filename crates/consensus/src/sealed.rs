@@ -0,0 +1,230 @@
+//! Helpers for working with [`Sealed`] values.
+
+use alloy_primitives::{Sealable, Sealed, B256};
+
+/// Extension methods for [`Sealable`] types.
+pub trait SealableExt: Sealable + Sized {
+    /// Seals the value with a known hash, without computing it.
+    ///
+    /// Use this instead of [`Sealable::seal_slow`] when the hash is already known, e.g. because it
+    /// was supplied by the node.
+    ///
+    /// WARNING: This method does not perform validation whether the hash is correct.
+    fn seal_with(self, hash: B256) -> Sealed<Self> {
+        Sealed::new_unchecked(self, hash)
+    }
+
+    /// Wraps the value in a [`LazySealed`], which computes the hash on first access.
+    #[cfg(feature = "std")]
+    fn seal_lazy(self) -> LazySealed<Self> {
+        LazySealed::new(self)
+    }
+}
+
+impl<T: Sealable> SealableExt for T {}
+
+/// A value whose hash is computed on first access and cached.
+///
+/// This is useful when values are sealed in a pipeline but only some of them end up needing the
+/// hash. Mutable access through [`inner_mut`](Self::inner_mut) clears the cached hash.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct LazySealed<T> {
+    inner: T,
+    hash: std::sync::OnceLock<B256>,
+}
+
+#[cfg(feature = "std")]
+impl<T: Sealable> LazySealed<T> {
+    /// Creates a new value without computing its hash.
+    pub const fn new(inner: T) -> Self {
+        Self { inner, hash: std::sync::OnceLock::new() }
+    }
+
+    /// Creates a new value with a known hash.
+    ///
+    /// WARNING: This method does not perform validation whether the hash is correct.
+    pub fn new_unchecked(inner: T, hash: B256) -> Self {
+        Self { inner, hash: hash.into() }
+    }
+
+    /// Returns the hash, computing it if necessary.
+    pub fn hash(&self) -> B256 {
+        *self.hash.get_or_init(|| self.inner.hash_slow())
+    }
+
+    /// Returns the hash if it has been computed or supplied already.
+    pub fn hash_if_known(&self) -> Option<B256> {
+        self.hash.get().copied()
+    }
+
+    /// Returns a reference to the inner value.
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner value, clearing the cached hash.
+    pub fn inner_mut(&mut self) -> &mut T {
+        self.hash.take();
+        &mut self.inner
+    }
+
+    /// Consumes the type and returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Converts into a [`Sealed`], computing the hash if necessary.
+    pub fn seal(self) -> Sealed<T> {
+        let hash = self.hash();
+        Sealed::new_unchecked(self.inner, hash)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> core::ops::Deref for LazySealed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: PartialEq> PartialEq for LazySealed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq> Eq for LazySealed<T> {}
+
+#[cfg(feature = "std")]
+impl<T: Sealable> From<Sealed<T>> for LazySealed<T> {
+    fn from(sealed: Sealed<T>) -> Self {
+        let (inner, hash) = sealed.into_parts();
+        Self::new_unchecked(inner, hash)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Sealable> From<LazySealed<T>> for Sealed<T> {
+    fn from(sealed: LazySealed<T>) -> Self {
+        sealed.seal()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+impl<T: Sealable + serde::Serialize> serde::Serialize for LazySealed<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_sealed::Helper { hash: Some(self.hash()), inner: &self.inner }.serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+impl<'de, T: Sealable + serde::Deserialize<'de>> serde::Deserialize<'de> for LazySealed<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serde_sealed::Helper { hash, inner } = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match hash {
+            Some(hash) => Self::new_unchecked(inner, hash),
+            None => Self::new(inner),
+        })
+    }
+}
+
+/// Serde implementation for [`Sealed`] that flattens the inner value and stores the seal in a
+/// `hash` field, like the JSON-RPC representations of headers.
+///
+/// When deserializing, the `hash` field is trusted and only computed if it is missing.
+///
+/// Use with `#[serde(with = "alloy_consensus::sealed::serde_sealed")]`.
+#[cfg(feature = "serde")]
+pub mod serde_sealed {
+    use alloy_primitives::{Sealable, Sealed, B256};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    pub(crate) struct Helper<T> {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) hash: Option<B256>,
+        #[serde(flatten)]
+        pub(crate) inner: T,
+    }
+
+    /// Serializes a [`Sealed`] value.
+    pub fn serialize<T, S>(sealed: &Sealed<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        Helper { hash: Some(sealed.seal()), inner: sealed.inner() }.serialize(serializer)
+    }
+
+    /// Deserializes a [`Sealed`] value, hashing it if the `hash` field is missing.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Sealed<T>, D::Error>
+    where
+        T: Sealable + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let Helper { hash, inner } = Helper::<T>::deserialize(deserializer)?;
+        Ok(match hash {
+            Some(hash) => Sealed::new_unchecked(inner, hash),
+            None => Sealed::new(inner),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Header;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn seals_lazily() {
+        let header = Header { number: 1, ..Default::default() };
+        let hash = header.hash_slow();
+
+        let mut lazy = header.clone().seal_lazy();
+        assert_eq!(lazy.hash_if_known(), None);
+        assert_eq!(lazy.hash(), hash);
+        assert_eq!(lazy.hash_if_known(), Some(hash));
+
+        lazy.inner_mut().number = 2;
+        assert_eq!(lazy.hash_if_known(), None);
+        assert_ne!(lazy.hash(), hash);
+
+        let sealed = header.clone().seal_with(B256::repeat_byte(1));
+        let lazy = LazySealed::from(sealed);
+        assert_eq!(lazy.hash_if_known(), Some(B256::repeat_byte(1)));
+        assert_eq!(Sealed::from(lazy).seal(), B256::repeat_byte(1));
+    }
+
+    #[cfg(all(feature = "std", feature = "serde"))]
+    #[test]
+    fn serde_preserves_hash() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "serde_sealed")]
+            header: Sealed<Header>,
+        }
+
+        let header = Header { number: 1, ..Default::default() };
+        let hash = B256::repeat_byte(1);
+        let json =
+            serde_json::to_value(Wrapper { header: header.clone().seal_with(hash) }).unwrap();
+        assert_eq!(json["header"]["hash"], serde_json::json!(hash));
+        assert_eq!(json["header"]["number"], "0x1");
+
+        let wrapper: Wrapper = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(wrapper.header.seal(), hash);
+        let lazy: LazySealed<Header> = serde_json::from_value(json["header"].clone()).unwrap();
+        assert_eq!(lazy.hash_if_known(), Some(hash));
+
+        let mut json = json;
+        json["header"].as_object_mut().unwrap().remove("hash");
+        let wrapper: Wrapper = serde_json::from_value(json).unwrap();
+        assert_eq!(wrapper.header.seal(), header.hash_slow());
+    }
+}
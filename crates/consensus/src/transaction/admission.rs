@@ -0,0 +1,370 @@
+//! Stateless transaction pool admission rules.
+
+use crate::Transaction;
+use alloc::vec::Vec;
+use alloy_eips::{eip4844::MAX_BLOBS_PER_BLOCK, intrinsic_gas::IntrinsicGasSchedule};
+use alloy_primitives::ChainId;
+use core::fmt;
+
+/// The maximum init code size, as defined in [EIP-3860].
+///
+/// [EIP-3860]: https://eips.ethereum.org/EIPS/eip-3860
+pub const MAX_INIT_CODE_SIZE: usize = 2 * 24576;
+
+/// The default maximum calldata size accepted by [`AdmissionRules`], matching the default
+/// maximum transaction size of common mempool implementations.
+pub const DEFAULT_MAX_CALLDATA_SIZE: usize = 128 * 1024;
+
+/// Configurable rules for admitting transactions into a mempool, that can be checked without
+/// access to chain state.
+///
+/// Checks that require state, such as nonce and balance checks, are out of scope.
+///
+/// # Examples
+///
+/// ```
+/// # use alloy_consensus::{transaction::AdmissionRules, TxEnvelope};
+/// # fn check(tx: &TxEnvelope) {
+/// let rules = AdmissionRules::default().with_chain_id(1).with_base_fee(7);
+/// for violation in rules.check(tx) {
+///     println!("rejected: {violation}");
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdmissionRules {
+    /// The expected chain id. If set, transactions for other chains are rejected.
+    pub chain_id: Option<ChainId>,
+    /// Whether to reject transactions without a chain id, i.e. pre-[EIP-155] legacy transactions.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub require_chain_id: bool,
+    /// The current base fee. If set, transactions whose fee cap is below it are rejected.
+    pub base_fee: Option<u64>,
+    /// The maximum init code size of contract creations, if any.
+    pub max_init_code_size: Option<usize>,
+    /// The maximum calldata size, if any.
+    pub max_calldata_size: Option<usize>,
+    /// The maximum number of blobs of a blob transaction, if any.
+    pub max_blob_count: Option<usize>,
+    /// Whether to reject transactions whose gas limit is below their intrinsic gas.
+    pub check_intrinsic_gas: bool,
+    /// The schedule used to calculate the intrinsic gas.
+    pub intrinsic_gas_schedule: IntrinsicGasSchedule,
+}
+
+impl Default for AdmissionRules {
+    fn default() -> Self {
+        Self {
+            chain_id: None,
+            require_chain_id: true,
+            base_fee: None,
+            max_init_code_size: Some(MAX_INIT_CODE_SIZE),
+            max_calldata_size: Some(DEFAULT_MAX_CALLDATA_SIZE),
+            max_blob_count: Some(MAX_BLOBS_PER_BLOCK),
+            check_intrinsic_gas: true,
+            intrinsic_gas_schedule: IntrinsicGasSchedule::prague(),
+        }
+    }
+}
+
+impl AdmissionRules {
+    /// Sets the expected chain id.
+    pub const fn with_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets whether transactions without a chain id are rejected.
+    pub const fn with_require_chain_id(mut self, require_chain_id: bool) -> Self {
+        self.require_chain_id = require_chain_id;
+        self
+    }
+
+    /// Sets the current base fee.
+    pub const fn with_base_fee(mut self, base_fee: u64) -> Self {
+        self.base_fee = Some(base_fee);
+        self
+    }
+
+    /// Sets the maximum init code size.
+    pub const fn with_max_init_code_size(mut self, max_init_code_size: Option<usize>) -> Self {
+        self.max_init_code_size = max_init_code_size;
+        self
+    }
+
+    /// Sets the maximum calldata size.
+    pub const fn with_max_calldata_size(mut self, max_calldata_size: Option<usize>) -> Self {
+        self.max_calldata_size = max_calldata_size;
+        self
+    }
+
+    /// Sets the maximum number of blobs of a blob transaction.
+    pub const fn with_max_blob_count(mut self, max_blob_count: Option<usize>) -> Self {
+        self.max_blob_count = max_blob_count;
+        self
+    }
+
+    /// Sets whether the intrinsic gas is checked.
+    pub const fn with_check_intrinsic_gas(mut self, check_intrinsic_gas: bool) -> Self {
+        self.check_intrinsic_gas = check_intrinsic_gas;
+        self
+    }
+
+    /// Sets the schedule used to calculate the intrinsic gas.
+    pub const fn with_intrinsic_gas_schedule(mut self, schedule: IntrinsicGasSchedule) -> Self {
+        self.intrinsic_gas_schedule = schedule;
+        self
+    }
+
+    /// Checks the transaction against the rules, returning all violations.
+    ///
+    /// An empty list means the transaction is admissible.
+    pub fn check<T: Transaction>(&self, tx: &T) -> Vec<AdmissionViolation> {
+        let mut violations = Vec::new();
+
+        match tx.chain_id() {
+            None if self.require_chain_id => violations.push(AdmissionViolation::MissingChainId),
+            Some(got) => {
+                if let Some(expected) = self.chain_id.filter(|expected| *expected != got) {
+                    violations.push(AdmissionViolation::ChainIdMismatch { expected, got });
+                }
+            }
+            None => {}
+        }
+
+        if self.check_intrinsic_gas {
+            let intrinsic_gas = tx.intrinsic_gas(&self.intrinsic_gas_schedule).min_gas_limit();
+            if tx.gas_limit() < intrinsic_gas {
+                violations.push(AdmissionViolation::IntrinsicGasTooLow {
+                    gas_limit: tx.gas_limit(),
+                    intrinsic_gas,
+                });
+            }
+        }
+
+        let size = tx.input().len();
+        if tx.is_create() {
+            if let Some(max) = self.max_init_code_size.filter(|max| size > *max) {
+                violations.push(AdmissionViolation::InitCodeTooLarge { size, max });
+            }
+        }
+        if let Some(max) = self.max_calldata_size.filter(|max| size > *max) {
+            violations.push(AdmissionViolation::CalldataTooLarge { size, max });
+        }
+
+        if let Some(max_priority_fee_per_gas) = tx.max_priority_fee_per_gas() {
+            let max_fee_per_gas = tx.max_fee_per_gas();
+            if max_priority_fee_per_gas > max_fee_per_gas {
+                violations.push(AdmissionViolation::TipAboveFeeCap {
+                    max_priority_fee_per_gas,
+                    max_fee_per_gas,
+                });
+            }
+        }
+        if let Some(base_fee) = self.base_fee {
+            let max_fee_per_gas = tx.max_fee_per_gas();
+            if max_fee_per_gas < base_fee as u128 {
+                violations
+                    .push(AdmissionViolation::FeeCapBelowBaseFee { max_fee_per_gas, base_fee });
+            }
+        }
+
+        if let Some(count) = tx.blob_versioned_hashes().map(<[_]>::len) {
+            if count == 0 {
+                violations.push(AdmissionViolation::NoBlobs);
+            } else if let Some(max) = self.max_blob_count.filter(|max| count > *max) {
+                violations.push(AdmissionViolation::TooManyBlobs { count, max });
+            }
+        }
+
+        violations
+    }
+
+    /// Checks the transaction against the rules, returning `Ok(())` if it is admissible and all
+    /// violations otherwise.
+    pub fn validate<T: Transaction>(&self, tx: &T) -> Result<(), Vec<AdmissionViolation>> {
+        let violations = self.check(tx);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// A violation of an [`AdmissionRules`] rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AdmissionViolation {
+    /// The transaction has no chain id.
+    MissingChainId,
+    /// The transaction is for another chain.
+    ChainIdMismatch {
+        /// The expected chain id.
+        expected: ChainId,
+        /// The chain id of the transaction.
+        got: ChainId,
+    },
+    /// The gas limit is below the intrinsic gas of the transaction, including the [EIP-7623]
+    /// calldata floor.
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    IntrinsicGasTooLow {
+        /// The gas limit of the transaction.
+        gas_limit: u64,
+        /// The intrinsic gas of the transaction.
+        intrinsic_gas: u64,
+    },
+    /// The init code of a contract creation exceeds the maximum size.
+    InitCodeTooLarge {
+        /// The size of the init code.
+        size: usize,
+        /// The maximum size.
+        max: usize,
+    },
+    /// The calldata exceeds the maximum size.
+    CalldataTooLarge {
+        /// The size of the calldata.
+        size: usize,
+        /// The maximum size.
+        max: usize,
+    },
+    /// The max priority fee per gas is above the max fee per gas.
+    TipAboveFeeCap {
+        /// The max priority fee per gas.
+        max_priority_fee_per_gas: u128,
+        /// The max fee per gas.
+        max_fee_per_gas: u128,
+    },
+    /// The max fee per gas is below the current base fee.
+    FeeCapBelowBaseFee {
+        /// The max fee per gas, or the gas price for legacy transactions.
+        max_fee_per_gas: u128,
+        /// The current base fee.
+        base_fee: u64,
+    },
+    /// A blob transaction has no blobs.
+    NoBlobs,
+    /// A blob transaction has more blobs than allowed.
+    TooManyBlobs {
+        /// The number of blobs.
+        count: usize,
+        /// The maximum number of blobs.
+        max: usize,
+    },
+}
+
+impl fmt::Display for AdmissionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingChainId => f.write_str("transaction has no chain id"),
+            Self::ChainIdMismatch { expected, got } => {
+                write!(f, "chain id mismatch: expected {expected}, got {got}")
+            }
+            Self::IntrinsicGasTooLow { gas_limit, intrinsic_gas } => {
+                write!(f, "gas limit {gas_limit} is below intrinsic gas {intrinsic_gas}")
+            }
+            Self::InitCodeTooLarge { size, max } => {
+                write!(f, "init code size {size} exceeds maximum {max}")
+            }
+            Self::CalldataTooLarge { size, max } => {
+                write!(f, "calldata size {size} exceeds maximum {max}")
+            }
+            Self::TipAboveFeeCap { max_priority_fee_per_gas, max_fee_per_gas } => write!(
+                f,
+                "max priority fee per gas {max_priority_fee_per_gas} is above max fee per gas {max_fee_per_gas}"
+            ),
+            Self::FeeCapBelowBaseFee { max_fee_per_gas, base_fee } => {
+                write!(f, "max fee per gas {max_fee_per_gas} is below base fee {base_fee}")
+            }
+            Self::NoBlobs => f.write_str("blob transaction has no blobs"),
+            Self::TooManyBlobs { count, max } => {
+                write!(f, "blob count {count} exceeds maximum {max}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for AdmissionViolation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TxEip1559, TxEip4844, TxLegacy};
+    use alloy_primitives::{Bytes, TxKind, B256};
+
+    #[test]
+    fn admissible() {
+        let tx = TxEip1559 {
+            chain_id: 1,
+            gas_limit: 21_000,
+            max_fee_per_gas: 10,
+            max_priority_fee_per_gas: 1,
+            to: TxKind::Call(Default::default()),
+            ..Default::default()
+        };
+        let rules = AdmissionRules::default().with_chain_id(1).with_base_fee(10);
+        assert_eq!(rules.validate(&tx), Ok(()));
+    }
+
+    #[test]
+    fn violations() {
+        let tx = TxLegacy {
+            chain_id: None,
+            gas_limit: 53_000,
+            gas_price: 5,
+            to: TxKind::Create,
+            input: Bytes::from(vec![1; MAX_INIT_CODE_SIZE + 1]),
+            ..Default::default()
+        };
+        let violations = AdmissionRules::default().with_base_fee(10).check(&tx);
+        assert_eq!(
+            violations,
+            vec![
+                AdmissionViolation::MissingChainId,
+                AdmissionViolation::IntrinsicGasTooLow {
+                    gas_limit: 53_000,
+                    // the EIP-7623 floor exceeds the execution cost
+                    intrinsic_gas: 21_000 + 40 * (MAX_INIT_CODE_SIZE as u64 + 1),
+                },
+                AdmissionViolation::InitCodeTooLarge {
+                    size: MAX_INIT_CODE_SIZE + 1,
+                    max: MAX_INIT_CODE_SIZE
+                },
+                AdmissionViolation::FeeCapBelowBaseFee { max_fee_per_gas: 5, base_fee: 10 },
+            ]
+        );
+        assert_eq!(
+            violations[2].to_string(),
+            format!(
+                "init code size {} exceeds maximum {MAX_INIT_CODE_SIZE}",
+                MAX_INIT_CODE_SIZE + 1
+            )
+        );
+    }
+
+    #[test]
+    fn blob_and_fee_violations() {
+        let tx = TxEip4844 {
+            chain_id: 5,
+            gas_limit: 21_000,
+            max_fee_per_gas: 1,
+            max_priority_fee_per_gas: 2,
+            blob_versioned_hashes: vec![B256::ZERO; 7],
+            ..Default::default()
+        };
+        let violations = AdmissionRules::default().with_chain_id(1).check(&tx);
+        assert_eq!(
+            violations,
+            vec![
+                AdmissionViolation::ChainIdMismatch { expected: 1, got: 5 },
+                AdmissionViolation::TipAboveFeeCap {
+                    max_priority_fee_per_gas: 2,
+                    max_fee_per_gas: 1
+                },
+                AdmissionViolation::TooManyBlobs { count: 7, max: 6 },
+            ]
+        );
+    }
+}
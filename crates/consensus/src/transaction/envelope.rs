@@ -401,7 +401,7 @@ impl Decodable for TxEnvelope {
 
 impl Decodable2718 for TxEnvelope {
     fn typed_decode(ty: u8, buf: &mut &[u8]) -> Eip2718Result<Self> {
-        match ty.try_into().map_err(|_| alloy_rlp::Error::Custom("unexpected tx type"))? {
+        match ty.try_into()? {
             TxType::Eip2930 => Ok(TxEip2930::rlp_decode_signed(buf)?.into()),
             TxType::Eip1559 => Ok(TxEip1559::rlp_decode_signed(buf)?.into()),
             TxType::Eip4844 => Ok(TxEip4844Variant::rlp_decode_signed(buf)?.into()),
@@ -2,10 +2,19 @@
 
 use crate::Signed;
 use alloc::vec::Vec;
-use alloy_eips::{eip2930::AccessList, eip7702::SignedAuthorization};
+use alloy_eips::{
+    eip2930::AccessList,
+    eip7702::SignedAuthorization,
+    intrinsic_gas::{IntrinsicGas, IntrinsicGasSchedule},
+};
 use alloy_primitives::{keccak256, Address, Bytes, ChainId, TxKind, B256, U256};
 use core::{any, fmt};
 
+mod admission;
+pub use admission::{
+    AdmissionRules, AdmissionViolation, DEFAULT_MAX_CALLDATA_SIZE, MAX_INIT_CODE_SIZE,
+};
+
 mod eip1559;
 pub use eip1559::TxEip1559;
 
@@ -32,6 +41,9 @@ pub use eip4844::{TxEip4844, TxEip4844Variant, TxEip4844WithSidecar};
 mod envelope;
 pub use envelope::{TxEnvelope, TxType};
 
+mod opaque;
+pub use opaque::{MaybeOpaque, OpaqueTxEnvelope};
+
 mod legacy;
 pub use legacy::{from_eip155_value, to_eip155_value, TxLegacy};
 
@@ -179,6 +191,17 @@ pub trait Transaction: Typed2718 + fmt::Debug + any::Any + Send + Sync + 'static
     ///
     /// Returns `None` if this transaction is not EIP-7702.
     fn authorization_list(&self) -> Option<&[SignedAuthorization]>;
+
+    /// Calculates the intrinsic gas of the transaction under the given [`IntrinsicGasSchedule`].
+    #[inline]
+    fn intrinsic_gas(&self, schedule: &IntrinsicGasSchedule) -> IntrinsicGas {
+        schedule.intrinsic_gas(
+            self.is_create(),
+            self.input(),
+            self.access_list(),
+            self.authorization_list().map_or(0, <[_]>::len),
+        )
+    }
 }
 
 /// A signable transaction.
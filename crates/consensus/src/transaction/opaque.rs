@@ -0,0 +1,242 @@
+use crate::TxEnvelope;
+use alloy_eips::{
+    eip2718::{Decodable2718, Eip2718Error, Eip2718Result, Encodable2718, LEGACY_TX_TYPE_ID},
+    Typed2718,
+};
+use alloy_primitives::{keccak256, Bytes, TxHash};
+use alloy_rlp::{BufMut, Header};
+
+/// A typed transaction of an unknown type, preserved as its type byte and raw payload.
+///
+/// The payload is not interpreted, so the transaction round-trips through [EIP-2718] decoding and
+/// encoding unchanged. This allows pipelines to process blocks containing transaction types they
+/// do not know yet, e.g. to recompute transaction roots or to forward transactions, see
+/// [`MaybeOpaque`].
+///
+/// The payload is assumed to be a single RLP item, as it is for all transaction types so far, so
+/// that the transaction can be decoded from a list of transactions. Legacy transactions are not
+/// typed, and cannot be opaque.
+///
+/// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OpaqueTxEnvelope {
+    ty: u8,
+    payload: Bytes,
+}
+
+impl OpaqueTxEnvelope {
+    /// Creates a new opaque transaction from its type byte and payload, i.e. the [EIP-2718]
+    /// encoding without the type byte.
+    ///
+    /// Returns `None` if the type byte is not a valid typed transaction type, i.e. `0` or greater
+    /// than `0x7f`.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub fn new(ty: u8, payload: Bytes) -> Option<Self> {
+        (ty != LEGACY_TX_TYPE_ID && ty <= 0x7f).then_some(Self { ty, payload })
+    }
+
+    /// Returns the payload of the transaction, i.e. the [EIP-2718] encoding without the type
+    /// byte.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub const fn payload(&self) -> &Bytes {
+        &self.payload
+    }
+
+    /// Returns the hash of the transaction, i.e. the hash of its [EIP-2718] encoding.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub fn tx_hash(&self) -> TxHash {
+        keccak256(self.encoded_2718())
+    }
+}
+
+impl Typed2718 for OpaqueTxEnvelope {
+    fn ty(&self) -> u8 {
+        self.ty
+    }
+}
+
+impl Decodable2718 for OpaqueTxEnvelope {
+    fn typed_decode(ty: u8, buf: &mut &[u8]) -> Eip2718Result<Self> {
+        if ty == LEGACY_TX_TYPE_ID {
+            return Err(Eip2718Error::UnexpectedType(ty));
+        }
+        let header = Header::decode(&mut &buf[..])?;
+        let len = header.length_with_payload();
+        if buf.len() < len {
+            return Err(alloy_rlp::Error::InputTooShort.into());
+        }
+        let (payload, rest) = buf.split_at(len);
+        *buf = rest;
+        Ok(Self { ty, payload: Bytes::copy_from_slice(payload) })
+    }
+
+    fn fallback_decode(_buf: &mut &[u8]) -> Eip2718Result<Self> {
+        Err(Eip2718Error::UnexpectedType(LEGACY_TX_TYPE_ID))
+    }
+}
+
+impl Encodable2718 for OpaqueTxEnvelope {
+    fn encode_2718_len(&self) -> usize {
+        1 + self.payload.len()
+    }
+
+    fn encode_2718(&self, out: &mut dyn BufMut) {
+        out.put_u8(self.ty);
+        out.put_slice(&self.payload);
+    }
+}
+
+/// A transaction envelope that falls back to an [`OpaqueTxEnvelope`] for unknown transaction
+/// types.
+///
+/// Decoding a transaction type that the inner envelope rejects with
+/// [`Eip2718Error::UnexpectedType`] yields an [`Opaque`](Self::Opaque) transaction instead of
+/// failing, which is encoded back unchanged. This makes pipelines forward compatible with
+/// transaction types introduced after they were built.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_consensus::{MaybeOpaque, TxEnvelope};
+/// use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+///
+/// // a transaction of a future type 0x7e
+/// let raw = [0x7e, 0xc3, 0x01, 0x02, 0x03];
+/// let tx = MaybeOpaque::<TxEnvelope>::decode_2718(&mut &raw[..]).unwrap();
+/// assert!(tx.is_opaque());
+/// assert_eq!(tx.encoded_2718(), raw);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MaybeOpaque<T = TxEnvelope> {
+    /// A transaction of a type known to the inner envelope.
+    Known(T),
+    /// A transaction of an unknown type.
+    Opaque(OpaqueTxEnvelope),
+}
+
+impl<T> MaybeOpaque<T> {
+    /// Returns true if the transaction is of an unknown type.
+    pub const fn is_opaque(&self) -> bool {
+        matches!(self, Self::Opaque(_))
+    }
+
+    /// Returns the transaction if its type is known.
+    pub const fn as_known(&self) -> Option<&T> {
+        match self {
+            Self::Known(tx) => Some(tx),
+            Self::Opaque(_) => None,
+        }
+    }
+
+    /// Returns the opaque transaction if its type is unknown.
+    pub const fn as_opaque(&self) -> Option<&OpaqueTxEnvelope> {
+        match self {
+            Self::Known(_) => None,
+            Self::Opaque(tx) => Some(tx),
+        }
+    }
+
+    /// Returns the transaction if its type is known.
+    pub fn into_known(self) -> Option<T> {
+        match self {
+            Self::Known(tx) => Some(tx),
+            Self::Opaque(_) => None,
+        }
+    }
+}
+
+impl<T> From<T> for MaybeOpaque<T> {
+    fn from(tx: T) -> Self {
+        Self::Known(tx)
+    }
+}
+
+impl<T: Typed2718> Typed2718 for MaybeOpaque<T> {
+    fn ty(&self) -> u8 {
+        match self {
+            Self::Known(tx) => tx.ty(),
+            Self::Opaque(tx) => tx.ty(),
+        }
+    }
+}
+
+impl<T: Decodable2718> Decodable2718 for MaybeOpaque<T> {
+    fn typed_decode(ty: u8, buf: &mut &[u8]) -> Eip2718Result<Self> {
+        let mut known = *buf;
+        match T::typed_decode(ty, &mut known) {
+            Ok(tx) => {
+                *buf = known;
+                Ok(Self::Known(tx))
+            }
+            Err(Eip2718Error::UnexpectedType(_)) => {
+                OpaqueTxEnvelope::typed_decode(ty, buf).map(Self::Opaque)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn fallback_decode(buf: &mut &[u8]) -> Eip2718Result<Self> {
+        T::fallback_decode(buf).map(Self::Known)
+    }
+}
+
+impl<T: Encodable2718> Encodable2718 for MaybeOpaque<T> {
+    fn encode_2718_len(&self) -> usize {
+        match self {
+            Self::Known(tx) => tx.encode_2718_len(),
+            Self::Opaque(tx) => tx.encode_2718_len(),
+        }
+    }
+
+    fn encode_2718(&self, out: &mut dyn BufMut) {
+        match self {
+            Self::Known(tx) => tx.encode_2718(out),
+            Self::Opaque(tx) => tx.encode_2718(out),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn opaque_roundtrip() {
+        // a type 0x05 transaction followed by another transaction in the buffer
+        let raw = hex!("05c3010203");
+        let buf = [&raw[..], &[0x05, 0xc0][..]].concat();
+        let mut slice = &buf[..];
+        let tx = MaybeOpaque::<TxEnvelope>::decode_2718(&mut slice).unwrap();
+        assert_eq!(slice, [0x05, 0xc0]);
+
+        let opaque = tx.as_opaque().unwrap();
+        assert_eq!(opaque.ty(), 5);
+        assert_eq!(opaque.payload()[..], hex!("c3010203"));
+        assert_eq!(tx.encoded_2718(), raw);
+        assert_eq!(opaque.tx_hash(), keccak256(raw));
+
+        // network encoding wraps the envelope in an RLP string
+        let mut network = Vec::new();
+        tx.network_encode(&mut network);
+        assert_eq!(MaybeOpaque::<TxEnvelope>::network_decode(&mut &network[..]).unwrap(), tx);
+    }
+
+    #[test]
+    fn known_types_are_decoded() {
+        // EIP-1559 transaction
+        let raw = hex!("02f86f0102843b9aca0085029e7822d68298f094d9e1459a7a482635700cbc20bbaf52d495ab9c9680841b55ba3ac080a0c199674fcb29f353693dd779c017823b954b3c69dffa3cd6b2a6ff7888798039a028ca912de909e7e6cdef9cdcaf24c54dd8c1032946dfa1d85c206b32a9064fe8");
+        let tx = MaybeOpaque::<TxEnvelope>::decode_2718(&mut &raw[..]).unwrap();
+        assert!(tx.as_known().unwrap().is_eip1559());
+        assert_eq!(tx.encoded_2718(), raw);
+
+        // legacy transactions are never opaque, and invalid known types are not hidden
+        assert!(OpaqueTxEnvelope::new(0, Bytes::new()).is_none());
+        assert!(MaybeOpaque::<TxEnvelope>::decode_2718(&mut &[0x00, 0xc0][..]).is_err());
+        assert!(MaybeOpaque::<TxEnvelope>::decode_2718(&mut &[0x02, 0xc0][..]).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+#![allow(missing_docs)]
+
+use alloy_contract::EventDecoderRegistry;
+use alloy_primitives::{Address, LogData, U256};
+use alloy_sol_types::{sol, SolEvent};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+sol! {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+    event Deposit(address indexed dst, uint256 wad);
+    event Withdrawal(address indexed src, uint256 wad);
+    event Sync(uint112 reserve0, uint112 reserve1);
+    event Swap(
+        address indexed sender,
+        uint256 amount0In,
+        uint256 amount1In,
+        uint256 amount0Out,
+        uint256 amount1Out,
+        address indexed to
+    );
+    event Mint(address indexed sender, uint256 amount0, uint256 amount1);
+    event Burn(address indexed sender, uint256 amount0, uint256 amount1, address indexed to);
+}
+
+#[allow(dead_code)]
+enum Decoded {
+    Transfer(Transfer),
+    Approval(Approval),
+    Deposit(Deposit),
+    Withdrawal(Withdrawal),
+    Sync(Sync),
+    Swap(Swap),
+    Mint(Mint),
+    Burn(Burn),
+}
+
+/// Tries every event type in turn, as done without a registry.
+fn decode_sequential(log: &LogData) -> Option<Decoded> {
+    macro_rules! try_decode {
+        ($($event:ident),*) => {$(
+            if let Ok(event) = $event::decode_raw_log(log.topics().iter().copied(), &log.data, false) {
+                return Some(Decoded::$event(event));
+            }
+        )*};
+    }
+    try_decode!(Transfer, Approval, Deposit, Withdrawal, Sync, Swap, Mint, Burn);
+    None
+}
+
+fn logs() -> Vec<LogData> {
+    let a = Address::repeat_byte(1);
+    let b = Address::repeat_byte(2);
+    let v = U256::from(1_000_000);
+    vec![
+        Transfer { from: a, to: b, value: v }.encode_log_data(),
+        Approval { owner: a, spender: b, value: v }.encode_log_data(),
+        Sync { reserve0: v.to(), reserve1: v.to() }.encode_log_data(),
+        Swap {
+            sender: a,
+            amount0In: v,
+            amount1In: U256::ZERO,
+            amount0Out: U256::ZERO,
+            amount1Out: v,
+            to: b,
+        }
+        .encode_log_data(),
+        Burn { sender: a, amount0: v, amount1: v, to: b }.encode_log_data(),
+    ]
+}
+
+fn event_registry(c: &mut Criterion) {
+    let registry = EventDecoderRegistry::new()
+        .with_event::<Transfer>(Decoded::Transfer)
+        .with_event::<Approval>(Decoded::Approval)
+        .with_event::<Deposit>(Decoded::Deposit)
+        .with_event::<Withdrawal>(Decoded::Withdrawal)
+        .with_event::<Sync>(Decoded::Sync)
+        .with_event::<Swap>(Decoded::Swap)
+        .with_event::<Mint>(Decoded::Mint)
+        .with_event::<Burn>(Decoded::Burn);
+    let logs = logs();
+
+    let mut g = c.benchmark_group("decode_logs");
+    g.bench_function("sequential", |b| {
+        b.iter(|| {
+            for log in &logs {
+                black_box(decode_sequential(black_box(log)));
+            }
+        })
+    });
+    g.bench_function("registry", |b| {
+        b.iter(|| {
+            for log in &logs {
+                black_box(registry.decode_log_data(black_box(log)));
+            }
+        })
+    });
+    g.finish();
+}
+
+criterion_group!(benches, event_registry);
+criterion_main!(benches);
@@ -0,0 +1,446 @@
+use crate::{ContractInstance, Error, Interface, RawCallBuilder, Result};
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_json_abi::JsonAbi;
+use alloy_network::Network;
+use alloy_primitives::{hex, Address, Bytes};
+use alloy_provider::Provider;
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::Path};
+
+/// Error when loading or linking a [`ContractArtifact`].
+#[derive(Debug, thiserror::Error)]
+pub enum ArtifactError {
+    /// The artifact could not be read.
+    #[error("failed to read artifact: {0}")]
+    Io(#[from] std::io::Error),
+    /// The artifact is not valid JSON, or is missing the ABI.
+    #[error("invalid artifact: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The bytecode is not valid hex.
+    #[error("invalid bytecode: {0}")]
+    InvalidBytecode(#[from] hex::FromHexError),
+    /// A link reference points outside of the bytecode.
+    #[error("link reference for `{0}` is out of bounds")]
+    InvalidLinkReference(String),
+    /// The artifact has no creation bytecode, e.g. because it is an interface or abstract
+    /// contract.
+    #[error("artifact has no bytecode")]
+    MissingBytecode,
+    /// The bytecode references libraries that have not been linked.
+    #[error("bytecode references unlinked libraries: {}", .0.join(", "))]
+    UnlinkedLibraries(Vec<String>),
+}
+
+/// A reference to a library address in a [`LinkableBytecode`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkReference {
+    /// The source file of the library.
+    pub file: String,
+    /// The name of the library.
+    pub library: String,
+    /// The byte offset of the address placeholder in the bytecode.
+    pub start: usize,
+    /// The length of the address placeholder, in bytes.
+    pub length: usize,
+}
+
+impl LinkReference {
+    /// Returns the fully qualified name of the library, e.g. `src/Math.sol:Math`.
+    pub fn qualified_name(&self) -> String {
+        format!("{}:{}", self.file, self.library)
+    }
+
+    /// Returns `true` if `name` is the name or the fully qualified name of the library.
+    fn matches(&self, name: &str) -> bool {
+        match name.split_once(':') {
+            Some((file, library)) => self.file == file && self.library == library,
+            None => self.library == name,
+        }
+    }
+}
+
+/// Bytecode that may contain placeholders for library addresses.
+///
+/// The placeholders are zeroed in [`bytes`](Self::bytes) until they are replaced with
+/// [`link`](Self::link).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LinkableBytecode {
+    bytes: Vec<u8>,
+    link_references: Vec<LinkReference>,
+}
+
+impl LinkableBytecode {
+    /// Parses hex encoded bytecode with placeholders at the given link references.
+    pub fn new(code: &str, link_references: Vec<LinkReference>) -> Result<Self, ArtifactError> {
+        let mut code = code.strip_prefix("0x").unwrap_or(code).to_string();
+        for reference in &link_references {
+            let range = reference.start * 2..(reference.start + reference.length) * 2;
+            if range.end > code.len()
+                || !code.is_char_boundary(range.start)
+                || !code.is_char_boundary(range.end)
+            {
+                return Err(ArtifactError::InvalidLinkReference(reference.qualified_name()));
+            }
+            code.replace_range(range, &"0".repeat(reference.length * 2));
+        }
+        Ok(Self { bytes: hex::decode(code)?, link_references })
+    }
+
+    /// Returns the bytecode, with zeroed placeholders for unlinked libraries.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns `true` if the bytecode is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the references to libraries that have not been linked yet.
+    pub fn link_references(&self) -> &[LinkReference] {
+        &self.link_references
+    }
+
+    /// Returns `true` if all libraries have been linked.
+    pub fn is_linked(&self) -> bool {
+        self.link_references.is_empty()
+    }
+
+    /// Returns the fully qualified names of the libraries that have not been linked yet.
+    pub fn unlinked_libraries(&self) -> Vec<String> {
+        let mut libraries =
+            self.link_references.iter().map(LinkReference::qualified_name).collect::<Vec<_>>();
+        libraries.sort();
+        libraries.dedup();
+        libraries
+    }
+
+    /// Replaces the placeholders of `library` with `address`.
+    ///
+    /// `library` is either the name of the library or its fully qualified name, e.g.
+    /// `src/Math.sol:Math`. Returns `true` if any placeholder was replaced.
+    pub fn link(&mut self, library: &str, address: Address) -> bool {
+        let len = self.link_references.len();
+        let bytes = &mut self.bytes;
+        self.link_references.retain(|reference| {
+            if !reference.matches(library) {
+                return true;
+            }
+            let length = reference.length.min(20);
+            bytes[reference.start..reference.start + length]
+                .copy_from_slice(&address.as_slice()[20 - length..]);
+            false
+        });
+        self.link_references.len() != len
+    }
+
+    /// Returns the bytecode if all libraries have been linked.
+    pub fn to_linked(&self) -> Result<Bytes, ArtifactError> {
+        if !self.is_linked() {
+            return Err(ArtifactError::UnlinkedLibraries(self.unlinked_libraries()));
+        }
+        Ok(Bytes::copy_from_slice(&self.bytes))
+    }
+}
+
+/// A compiled contract loaded from a Foundry or Hardhat artifact.
+///
+/// This is the runtime counterpart of the bytecode embedded by the `sol!` macro, for projects that
+/// ship their artifacts separately. Libraries are linked with [`link`](Self::link), after which
+/// [`factory`](Self::factory) returns a [`ContractFactory`] to deploy the contract.
+#[derive(Clone, Debug)]
+pub struct ContractArtifact {
+    abi: JsonAbi,
+    bytecode: LinkableBytecode,
+    deployed_bytecode: LinkableBytecode,
+}
+
+impl ContractArtifact {
+    /// Parses an artifact from JSON.
+    ///
+    /// Both the Foundry layout, where `bytecode` and `deployedBytecode` are objects with their own
+    /// `linkReferences`, and the Hardhat layout, where they are hex strings with top-level
+    /// `linkReferences` and `deployedLinkReferences`, are supported.
+    pub fn from_json(json: &str) -> Result<Self, ArtifactError> {
+        serde_json::from_str::<RawArtifact>(json)?.try_into()
+    }
+
+    /// Parses an artifact from JSON bytes, see [`from_json`](Self::from_json).
+    pub fn from_slice(json: &[u8]) -> Result<Self, ArtifactError> {
+        serde_json::from_slice::<RawArtifact>(json)?.try_into()
+    }
+
+    /// Reads and parses an artifact file, see [`from_json`](Self::from_json).
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ArtifactError> {
+        Self::from_slice(&std::fs::read(path)?)
+    }
+
+    /// Returns the ABI of the contract.
+    pub const fn abi(&self) -> &JsonAbi {
+        &self.abi
+    }
+
+    /// Returns the creation bytecode.
+    pub const fn bytecode(&self) -> &LinkableBytecode {
+        &self.bytecode
+    }
+
+    /// Returns the runtime bytecode.
+    pub const fn deployed_bytecode(&self) -> &LinkableBytecode {
+        &self.deployed_bytecode
+    }
+
+    /// Returns the fully qualified names of the libraries that have not been linked yet.
+    pub fn unlinked_libraries(&self) -> Vec<String> {
+        self.bytecode.unlinked_libraries()
+    }
+
+    /// Links `library` at `address` in both the creation and the runtime bytecode.
+    ///
+    /// See [`LinkableBytecode::link`].
+    pub fn link(mut self, library: &str, address: Address) -> Self {
+        self.bytecode.link(library, address);
+        self.deployed_bytecode.link(library, address);
+        self
+    }
+
+    /// Returns a factory to deploy the contract.
+    ///
+    /// Returns an error if the artifact has no bytecode or references unlinked libraries.
+    pub fn factory(&self) -> Result<ContractFactory, ArtifactError> {
+        if self.bytecode.is_empty() {
+            return Err(ArtifactError::MissingBytecode);
+        }
+        Ok(ContractFactory::new(self.abi.clone(), self.bytecode.to_linked()?))
+    }
+}
+
+/// Deploys a contract from its ABI and linked creation bytecode.
+#[derive(Clone, Debug)]
+pub struct ContractFactory {
+    interface: Interface,
+    bytecode: Bytes,
+}
+
+impl ContractFactory {
+    /// Creates a new factory from the ABI and the linked creation bytecode.
+    pub fn new(abi: JsonAbi, bytecode: Bytes) -> Self {
+        Self { interface: Interface::new(abi), bytecode }
+    }
+
+    /// Returns the interface of the contract.
+    pub const fn interface(&self) -> &Interface {
+        &self.interface
+    }
+
+    /// Returns the creation bytecode.
+    pub const fn bytecode(&self) -> &Bytes {
+        &self.bytecode
+    }
+
+    /// Returns the deploy code: the creation bytecode followed by the ABI encoded constructor
+    /// arguments.
+    pub fn deploy_code(&self, args: &[DynSolValue]) -> Result<Bytes> {
+        let args = match self.interface.abi().constructor() {
+            Some(constructor) => constructor.abi_encode_input(args)?,
+            None if args.is_empty() => Vec::new(),
+            None => return Err(Error::UnknownFunction("constructor".to_string())),
+        };
+        Ok([&self.bytecode[..], &args].concat().into())
+    }
+
+    /// Returns a builder for the deployment transaction with the given constructor arguments.
+    pub fn deploy_builder<P: Provider<N>, N: Network>(
+        &self,
+        provider: P,
+        args: &[DynSolValue],
+    ) -> Result<RawCallBuilder<(), P, N>> {
+        Ok(RawCallBuilder::new_raw_deploy(provider, self.deploy_code(args)?))
+    }
+
+    /// Deploys the contract with the given constructor arguments, returning an instance at the
+    /// deployed address once the transaction has been confirmed.
+    pub async fn deploy<P: Provider<N>, N: Network>(
+        &self,
+        provider: P,
+        args: &[DynSolValue],
+    ) -> Result<ContractInstance<P, N>> {
+        let address = self.deploy_builder(&provider, args)?.deploy().await?;
+        Ok(ContractInstance::new(address, provider, self.interface.clone()))
+    }
+}
+
+/// `file => library => offsets`, as emitted by solc.
+type RawLinkReferences = BTreeMap<String, BTreeMap<String, Vec<RawOffset>>>;
+
+#[derive(Deserialize)]
+struct RawOffset {
+    start: usize,
+    length: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawBytecode {
+    Hex(String),
+    Object {
+        object: String,
+        #[serde(default, rename = "linkReferences")]
+        link_references: RawLinkReferences,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawArtifact {
+    abi: JsonAbi,
+    #[serde(default)]
+    bytecode: Option<RawBytecode>,
+    #[serde(default)]
+    deployed_bytecode: Option<RawBytecode>,
+    #[serde(default)]
+    link_references: RawLinkReferences,
+    #[serde(default)]
+    deployed_link_references: RawLinkReferences,
+}
+
+impl TryFrom<RawArtifact> for ContractArtifact {
+    type Error = ArtifactError;
+
+    fn try_from(raw: RawArtifact) -> Result<Self, Self::Error> {
+        Ok(Self {
+            abi: raw.abi,
+            bytecode: parse_bytecode(raw.bytecode, raw.link_references)?,
+            deployed_bytecode: parse_bytecode(raw.deployed_bytecode, raw.deployed_link_references)?,
+        })
+    }
+}
+
+fn parse_bytecode(
+    bytecode: Option<RawBytecode>,
+    link_references: RawLinkReferences,
+) -> Result<LinkableBytecode, ArtifactError> {
+    let (code, link_references) = match bytecode {
+        Some(RawBytecode::Hex(code)) => (code, link_references),
+        Some(RawBytecode::Object { object, link_references }) => (object, link_references),
+        None => return Ok(LinkableBytecode::default()),
+    };
+    let link_references = link_references
+        .into_iter()
+        .flat_map(|(file, libraries)| {
+            libraries.into_iter().flat_map(move |(library, offsets)| {
+                let file = file.clone();
+                offsets.into_iter().map(move |offset| LinkReference {
+                    file: file.clone(),
+                    library: library.clone(),
+                    start: offset.start,
+                    length: offset.length,
+                })
+            })
+        })
+        .collect();
+    LinkableBytecode::new(&code, link_references)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::Ethereum;
+    use alloy_primitives::{address, U256};
+    use alloy_provider::ProviderBuilder;
+
+    const PLACEHOLDER: &str = "__$b2f4ad0ff1ed7e3a5d3bbc3d1c6bb1b8b6$__";
+
+    fn foundry_artifact() -> String {
+        format!(
+            r#"{{
+                "abi": [{{"type":"constructor","inputs":[{{"name":"x","type":"uint256"}}],"stateMutability":"nonpayable"}}],
+                "bytecode": {{
+                    "object": "0x600173{PLACEHOLDER}50",
+                    "sourceMap": "",
+                    "linkReferences": {{"src/Math.sol": {{"Math": [{{"start": 3, "length": 20}}]}}}}
+                }},
+                "deployedBytecode": {{"object": "0x73{PLACEHOLDER}", "linkReferences": {{"src/Math.sol": {{"Math": [{{"start": 1, "length": 20}}]}}}}}}
+            }}"#
+        )
+    }
+
+    #[test]
+    fn parses_foundry_artifacts() {
+        let artifact = ContractArtifact::from_json(&foundry_artifact()).unwrap();
+        assert!(artifact.abi().constructor().is_some());
+        assert_eq!(artifact.bytecode().bytes().len(), 24);
+        assert_eq!(artifact.unlinked_libraries(), ["src/Math.sol:Math"]);
+        assert!(matches!(
+            artifact.factory(),
+            Err(ArtifactError::UnlinkedLibraries(libraries)) if libraries == ["src/Math.sol:Math"]
+        ));
+
+        let math = address!("00000000000000000000000000000000000000aa");
+        let artifact = artifact.link("src/Other.sol:Math", math);
+        assert!(!artifact.bytecode().is_linked());
+        let artifact = artifact.link("Math", math);
+        assert!(artifact.bytecode().is_linked());
+        assert!(artifact.deployed_bytecode().is_linked());
+        assert_eq!(&artifact.bytecode().bytes()[3..23], math.as_slice());
+        assert_eq!(&artifact.deployed_bytecode().bytes()[1..], math.as_slice());
+
+        let factory = artifact.factory().unwrap();
+        let code = factory.deploy_code(&[DynSolValue::Uint(U256::from(1), 256)]).unwrap();
+        assert_eq!(code[..24], factory.bytecode()[..]);
+        assert_eq!(U256::from_be_slice(&code[24..]), U256::from(1));
+        assert!(factory.deploy_code(&[]).is_err());
+    }
+
+    #[test]
+    fn parses_hardhat_artifacts() {
+        let json = format!(
+            r#"{{
+                "_format": "hh-sol-artifact-1",
+                "contractName": "Counter",
+                "abi": [],
+                "bytecode": "0x73{PLACEHOLDER}",
+                "deployedBytecode": "0x",
+                "linkReferences": {{"contracts/Math.sol": {{"Math": [{{"start": 1, "length": 20}}]}}}},
+                "deployedLinkReferences": {{}}
+            }}"#
+        );
+        let artifact = ContractArtifact::from_json(&json).unwrap();
+        assert_eq!(artifact.unlinked_libraries(), ["contracts/Math.sol:Math"]);
+        assert!(artifact.deployed_bytecode().is_empty());
+
+        let artifact = artifact.link("contracts/Math.sol:Math", Address::repeat_byte(1));
+        assert_eq!(artifact.factory().unwrap().deploy_code(&[]).unwrap()[1..], [1; 20]);
+    }
+
+    #[test]
+    fn rejects_invalid_artifacts() {
+        let missing = r#"{"abi": []}"#;
+        let artifact = ContractArtifact::from_json(missing).unwrap();
+        assert!(matches!(artifact.factory(), Err(ArtifactError::MissingBytecode)));
+
+        let unreferenced = format!(r#"{{"abi": [], "bytecode": "0x{PLACEHOLDER}"}}"#);
+        assert!(matches!(
+            ContractArtifact::from_json(&unreferenced),
+            Err(ArtifactError::InvalidBytecode(_))
+        ));
+
+        let out_of_bounds = r#"{"abi": [], "bytecode": {"object": "0x00", "linkReferences": {"a.sol": {"A": [{"start": 0, "length": 20}]}}}}"#;
+        assert!(matches!(
+            ContractArtifact::from_json(out_of_bounds),
+            Err(ArtifactError::InvalidLinkReference(name)) if name == "a.sol:A"
+        ));
+    }
+
+    #[tokio::test]
+    async fn deploys_artifacts() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+
+        let json = r#"{"abi":[{"inputs":[],"name":"counter","outputs":[{"internalType":"uint256","name":"","type":"uint256"}],"stateMutability":"view","type":"function"},{"inputs":[],"name":"increment","outputs":[],"stateMutability":"nonpayable","type":"function"}],"bytecode":"0x6080806040523460135760b2908160188239f35b5f80fdfe60808060405260043610156011575f80fd5b5f3560e01c90816361bc221a146065575063d09de08a14602f575f80fd5b346061575f3660031901126061575f5460018101809111604d575f55005b634e487b7160e01b5f52601160045260245ffd5b5f80fd5b346061575f3660031901126061576020905f548152f3fea2646970667358221220d802267a5f574e54a87a63d0ff8d733fdb275e6e6c502831d9e14f957bbcd7a264736f6c634300081a0033"}"#;
+        let factory = ContractArtifact::from_json(json).unwrap().factory().unwrap();
+        let contract: ContractInstance<_, Ethereum> = factory.deploy(&provider, &[]).await.unwrap();
+
+        let counter = contract.function("counter", &[]).unwrap().call().await.unwrap();
+        assert_eq!(counter, [DynSolValue::Uint(U256::ZERO, 256)]);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{TokenStandard, TokenTransfer};
+use alloy_network_primitives::ReceiptResponse;
+use alloy_primitives::{map::HashMap, Address};
+use alloy_rpc_types_eth::Log;
+use alloy_rpc_types_tenderly::{
+    AssetChange, AssetChangeType, AssetInfo, AssetStandard, BalanceChange,
+};
+use alloy_rpc_types_trace::tree::{CallKind, CallTree};
+
+/// The asset and balance changes of a transaction, derived locally from its trace and logs.
+///
+/// Produces the same [`AssetChange`] and [`BalanceChange`] shapes as Tenderly simulations, so
+/// that consumers can handle both the same way. Information that is only available to an
+/// enriched provider, such as token metadata and US dollar values, is left unset.
+///
+/// Native transfers are derived from the values of successful calls, creations and
+/// selfdestructs in the call tree, skipping calls reverted by themselves or an ancestor. Token
+/// transfers are decoded from the logs as described in [`TokenTransfer::from_log`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use alloy_contract::AssetChanges;
+/// # use alloy_rpc_types_eth::TransactionReceipt;
+/// # use alloy_rpc_types_trace::{geth::CallFrame, tree::CallTree};
+/// # fn example(frame: CallFrame, receipt: TransactionReceipt) {
+/// let tree = CallTree::from_call_frame(frame);
+/// let changes = AssetChanges::from_receipt(&tree, &receipt);
+/// for change in &changes.asset_changes {
+///     println!("{:?} {:?} -> {:?}: {}", change.kind, change.from, change.to, change.raw_amount);
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetChanges {
+    /// The native transfers in call order, followed by the token transfers in log order.
+    pub asset_changes: Vec<AssetChange>,
+    /// The accounts involved in the asset changes, in order of first appearance.
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+impl AssetChanges {
+    /// Derives the asset changes from the call tree and the logs of a transaction.
+    pub fn new(tree: &CallTree, logs: &[Log]) -> Self {
+        let mut asset_changes = native_changes(tree);
+        asset_changes.extend(logs.iter().flat_map(TokenTransfer::from_log).map(token_change));
+
+        let mut indices = HashMap::<Address, usize>::default();
+        let mut balance_changes = Vec::<BalanceChange>::new();
+        for (i, change) in asset_changes.iter().enumerate() {
+            for address in [change.from, change.to].into_iter().flatten() {
+                let idx = *indices.entry(address).or_insert_with(|| {
+                    balance_changes.push(BalanceChange {
+                        address,
+                        dollar_value: None,
+                        transfers: Vec::new(),
+                    });
+                    balance_changes.len() - 1
+                });
+                if balance_changes[idx].transfers.last() != Some(&i) {
+                    balance_changes[idx].transfers.push(i);
+                }
+            }
+        }
+
+        Self { asset_changes, balance_changes }
+    }
+
+    /// Derives the asset changes from the call tree and the receipt of a transaction.
+    pub fn from_receipt<R: ReceiptResponse>(tree: &CallTree, receipt: &R) -> Self {
+        let logs = receipt
+            .logs()
+            .map(|log| Log { inner: log.clone(), ..Default::default() })
+            .collect::<Vec<_>>();
+        Self::new(tree, &logs)
+    }
+
+    /// Returns the asset changes involving the given account.
+    pub fn involving(&self, address: Address) -> impl Iterator<Item = &AssetChange> + '_ {
+        self.balance_changes
+            .iter()
+            .find(|change| change.address == address)
+            .into_iter()
+            .flat_map(|change| change.transfers.iter().map(|&i| &self.asset_changes[i]))
+    }
+}
+
+/// Returns the native transfers of the successful calls of the tree, in call order.
+fn native_changes(tree: &CallTree) -> Vec<AssetChange> {
+    // nodes are in pre-order, so parents are visited before their children
+    let mut reverted = vec![false; tree.len()];
+    let mut changes = Vec::new();
+    for node in tree.iter() {
+        reverted[node.idx] = node.error.is_some() || node.parent.is_some_and(|p| reverted[p]);
+        let transfers_value = matches!(
+            node.kind,
+            CallKind::Call
+                | CallKind::AuthCall
+                | CallKind::Create
+                | CallKind::Create2
+                | CallKind::EofCreate
+                | CallKind::Selfdestruct
+        );
+        if reverted[node.idx] || !transfers_value || node.value.is_zero() {
+            continue;
+        }
+        changes.push(AssetChange {
+            asset_info: AssetInfo::native(),
+            kind: AssetChangeType::Transfer,
+            from: Some(node.from),
+            to: node.to,
+            raw_amount: node.value,
+            amount: None,
+            token_id: None,
+            dollar_value: None,
+        });
+    }
+    changes
+}
+
+fn token_change(transfer: TokenTransfer) -> AssetChange {
+    let standard = match transfer.standard {
+        TokenStandard::Erc20 => AssetStandard::Erc20,
+        TokenStandard::Erc721 => AssetStandard::Erc721,
+        TokenStandard::Erc1155 => AssetStandard::Erc1155,
+    };
+    let kind = if transfer.is_mint() {
+        AssetChangeType::Mint
+    } else if transfer.is_burn() {
+        AssetChangeType::Burn
+    } else {
+        AssetChangeType::Transfer
+    };
+    AssetChange {
+        asset_info: AssetInfo::token(standard, transfer.contract),
+        kind,
+        from: (!transfer.is_mint()).then_some(transfer.from),
+        to: (!transfer.is_burn()).then_some(transfer.to),
+        raw_amount: transfer.amount,
+        amount: None,
+        token_id: transfer.token_id,
+        dollar_value: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+    use alloy_rpc_types_trace::geth::CallFrame;
+    use alloy_sol_types::{sol, SolEvent};
+
+    sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+    }
+
+    fn frame(from: Address, to: Address, value: u64, calls: Vec<CallFrame>) -> CallFrame {
+        CallFrame {
+            from,
+            to: Some(to),
+            value: Some(U256::from(value)),
+            typ: "CALL".to_string(),
+            calls,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn derives_changes() {
+        let (user, router, pool, token) = (
+            Address::with_last_byte(1),
+            Address::with_last_byte(2),
+            Address::with_last_byte(3),
+            Address::with_last_byte(4),
+        );
+        let reverted = CallFrame {
+            error: Some("execution reverted".to_string()),
+            ..frame(router, user, 7, vec![frame(user, pool, 8, vec![])])
+        };
+        let tree = CallTree::from_call_frame(frame(
+            user,
+            router,
+            100,
+            vec![
+                frame(router, pool, 100, vec![]),
+                reverted,
+                CallFrame { typ: "DELEGATECALL".to_string(), ..frame(router, pool, 100, vec![]) },
+            ],
+        ));
+        let log = |from, to, value: u64| Log {
+            inner: alloy_primitives::Log {
+                address: token,
+                data: Transfer { from, to, value: U256::from(value) }.encode_log_data(),
+            },
+            ..Default::default()
+        };
+        let logs = [log(pool, user, 50), log(Address::ZERO, pool, 1)];
+
+        let changes = AssetChanges::new(&tree, &logs);
+        let kinds = changes.asset_changes.iter().map(|change| change.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                AssetChangeType::Transfer,
+                AssetChangeType::Transfer,
+                AssetChangeType::Transfer,
+                AssetChangeType::Mint
+            ]
+        );
+        assert_eq!(changes.asset_changes[1].asset_info.standard, AssetStandard::NativeCurrency);
+        assert_eq!(changes.asset_changes[1].to, Some(pool));
+        assert_eq!(changes.asset_changes[2].asset_info.contract_address, Some(token));
+        assert_eq!(changes.asset_changes[2].raw_amount, U256::from(50));
+        assert_eq!(changes.asset_changes[3].from, None);
+
+        let addresses =
+            changes.balance_changes.iter().map(|change| change.address).collect::<Vec<_>>();
+        assert_eq!(addresses, [user, router, pool]);
+        assert_eq!(changes.balance_changes[2].transfers, [1, 2, 3]);
+        assert_eq!(changes.involving(user).count(), 2);
+        assert_eq!(changes.involving(token).count(), 0);
+    }
+}
@@ -1,11 +1,15 @@
-use crate::{CallDecoder, Error, EthCall, Result};
+use crate::{
+    CallDecoder, CallReplay, DecodedError, DeployBuilder, Error, ErrorDecoder, EthCall, Result,
+};
 use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
-use alloy_json_abi::Function;
+use alloy_json_abi::{Function, JsonAbi};
 use alloy_network::{Ethereum, Network, TransactionBuilder, TransactionBuilder4844};
 use alloy_network_primitives::ReceiptResponse;
 use alloy_primitives::{Address, Bytes, ChainId, TxKind, U256};
-use alloy_provider::{PendingTransactionBuilder, Provider};
-use alloy_rpc_types_eth::{state::StateOverride, AccessList, BlobTransactionSidecar, BlockId};
+use alloy_provider::{EthCallParams, PendingTransactionBuilder, Provider};
+use alloy_rpc_types_eth::{
+    state::StateOverride, AccessList, BlobTransactionSidecar, BlockId, BlockOverrides,
+};
 use alloy_sol_types::SolCall;
 use std::{
     future::{Future, IntoFuture},
@@ -46,7 +50,7 @@ pub type RawCallBuilder<T, P, N = Ethereum> = CallBuilder<T, P, (), N>;
 /// # Note
 ///
 /// This will set [state overrides](https://geth.ethereum.org/docs/rpc/ns-eth#3-object---state-override-set)
-/// for `eth_call`, but this is not supported by all clients.
+/// and block overrides for `eth_call`, but this is not supported by all clients.
 ///
 /// # Examples
 ///
@@ -127,10 +131,12 @@ pub struct CallBuilder<T, P, D, N: Network = Ethereum> {
     request: N::TransactionRequest,
     block: BlockId,
     state: Option<StateOverride>,
+    block_overrides: Option<BlockOverrides>,
     /// The provider.
     // NOTE: This is public due to usage in `sol!`, please avoid changing it.
     pub provider: P,
     decoder: D,
+    errors: ErrorDecoder,
     fake_transport: PhantomData<T>,
 }
 
@@ -170,8 +176,10 @@ impl<T, P: Provider<N>, N: Network> DynCallBuilder<T, P, N> {
             request: self.request,
             block: self.block,
             state: self.state,
+            block_overrides: self.block_overrides,
             provider: self.provider,
             decoder: (),
+            errors: self.errors,
             fake_transport: PhantomData,
         }
     }
@@ -194,8 +202,10 @@ impl<T, P: Provider<N>, C: SolCall, N: Network> SolCallBuilder<T, P, C, N> {
             request: self.request,
             block: self.block,
             state: self.state,
+            block_overrides: self.block_overrides,
             provider: self.provider,
             decoder: (),
+            errors: self.errors,
             fake_transport: PhantomData,
         }
     }
@@ -259,8 +269,10 @@ impl<T, P: Provider<N>, N: Network> RawCallBuilder<T, P, N> {
             request: self.request,
             block: self.block,
             state: self.state,
+            block_overrides: self.block_overrides,
             provider: self.provider,
             decoder: PhantomData::<C>,
+            errors: self.errors,
             fake_transport: PhantomData,
         }
     }
@@ -284,6 +296,21 @@ impl<T, P: Provider<N>, N: Network> RawCallBuilder<T, P, N> {
     pub fn new_raw_deploy(provider: P, input: Bytes) -> Self {
         Self::new_inner_deploy(provider, input, ())
     }
+
+    /// Converts the call builder of a deployment transaction into a [`DeployBuilder`], e.g. to
+    /// deploy the contract at a deterministic address with [`create2`](DeployBuilder::create2).
+    pub fn into_deploy_builder(self) -> DeployBuilder<P, N> {
+        DeployBuilder::new(CallBuilder {
+            request: self.request,
+            block: self.block,
+            state: self.state,
+            block_overrides: self.block_overrides,
+            provider: self.provider,
+            decoder: (),
+            errors: self.errors,
+            fake_transport: PhantomData,
+        })
+    }
 }
 
 impl<T, P: Provider<N>, D: CallDecoder, N: Network> CallBuilder<T, P, D, N> {
@@ -294,6 +321,8 @@ impl<T, P: Provider<N>, D: CallDecoder, N: Network> CallBuilder<T, P, D, N> {
             provider,
             block: BlockId::default(),
             state: None,
+            block_overrides: None,
+            errors: ErrorDecoder::new(),
             fake_transport: PhantomData,
         }
     }
@@ -305,6 +334,8 @@ impl<T, P: Provider<N>, D: CallDecoder, N: Network> CallBuilder<T, P, D, N> {
             provider,
             block: BlockId::default(),
             state: None,
+            block_overrides: None,
+            errors: ErrorDecoder::new(),
             fake_transport: PhantomData,
         }
     }
@@ -425,13 +456,64 @@ impl<T, P: Provider<N>, D: CallDecoder, N: Network> CallBuilder<T, P, D, N> {
         self
     }
 
+    /// Sets the block overrides, e.g. to execute the call with a different block number or
+    /// timestamp.
+    ///
+    /// Block overrides are only applied to `eth_call`, and not to gas estimation.
+    ///
+    /// # Note
+    ///
+    /// Not all client implementations will support this as a parameter to `eth_call`.
+    pub fn block_overrides(mut self, block_overrides: BlockOverrides) -> Self {
+        self.block_overrides = Some(block_overrides);
+        self
+    }
+
+    /// Registers the errors declared in another interface for decoding reverts, see
+    /// [`decode_revert`](Self::decode_revert).
+    ///
+    /// This is useful for custom errors raised by libraries or by nested calls into other
+    /// contracts, which are not declared in the ABI of the called contract.
+    pub fn error_interface(mut self, name: impl Into<String>, abi: &JsonAbi) -> Self {
+        self.errors.add_interface(name, abi);
+        self
+    }
+
+    /// Sets the decoder used for decoding reverts, see [`decode_revert`](Self::decode_revert).
+    pub fn error_decoder(mut self, errors: ErrorDecoder) -> Self {
+        self.errors = errors;
+        self
+    }
+
+    /// Returns the decoder used for decoding reverts.
+    pub const fn errors(&self) -> &ErrorDecoder {
+        &self.errors
+    }
+
+    /// Decodes the custom Solidity error returned by a reverted call or gas estimation with the
+    /// registered error interfaces.
+    ///
+    /// Returns the first matching error and the name of the interface declaring it, or `None` if
+    /// `error` is not a revert or no registered error matches its data.
+    pub fn decode_revert(&self, error: &Error) -> Option<DecodedError> {
+        self.errors.decode_error(error)
+    }
+
     /// Returns the underlying transaction's ABI-encoded data.
     pub fn calldata(&self) -> &Bytes {
         self.request.input().expect("set in the constructor")
     }
 
-    /// Returns the estimated gas cost for the underlying transaction to be executed
-    /// If [`state overrides`](Self::state) are set, they will be applied to the gas estimation.
+    /// Returns the estimated gas cost for the underlying transaction to be executed.
+    ///
+    /// The estimation is done against the state at the configured [`block`](Self::block), which
+    /// allows estimating gas at historical blocks. If [`state overrides`](Self::state) are set,
+    /// they will be applied to the gas estimation, e.g. to estimate a `transferFrom` as if an
+    /// approval had already been made.
+    ///
+    /// # Note
+    ///
+    /// Not all client implementations support state overrides for `eth_estimateGas`.
     pub async fn estimate_gas(&self) -> Result<u64> {
         let mut estimate = self.provider.estimate_gas(&self.request);
         if let Some(state) = &self.state {
@@ -441,7 +523,8 @@ impl<T, P: Provider<N>, D: CallDecoder, N: Network> CallBuilder<T, P, D, N> {
     }
 
     /// Queries the blockchain via an `eth_call` without submitting a transaction to the network.
-    /// If [`state overrides`](Self::state) are set, they will be applied to the call.
+    /// If [`state overrides`](Self::state) or [`block overrides`](Self::block_overrides) are set,
+    /// they will be applied to the call.
     ///
     /// Returns the decoded the output by using the provided decoder.
     /// If this is not desired, use [`call_raw`](Self::call_raw) to get the raw output data.
@@ -452,7 +535,8 @@ impl<T, P: Provider<N>, D: CallDecoder, N: Network> CallBuilder<T, P, D, N> {
     }
 
     /// Queries the blockchain via an `eth_call` without submitting a transaction to the network.
-    /// If [`state overrides`](Self::state) are set, they will be applied to the call.
+    /// If [`state overrides`](Self::state) or [`block overrides`](Self::block_overrides) are set,
+    /// they will be applied to the call.
     ///
     /// Does not decode the output of the call, returning the raw output data instead.
     ///
@@ -463,9 +547,46 @@ impl<T, P: Provider<N>, D: CallDecoder, N: Network> CallBuilder<T, P, D, N> {
             Some(state) => call.overrides(state),
             None => call,
         };
+        let call = match &self.block_overrides {
+            Some(block_overrides) => call.block_overrides(block_overrides),
+            None => call,
+        };
         call.into()
     }
 
+    /// Replays the call at every `step`th block from `from_block` to `to_block`, inclusive, e.g. to
+    /// build a time series of an on-chain value.
+    ///
+    /// The call is made with the configured [state](Self::state) and
+    /// [block overrides](Self::block_overrides), ignoring the
+    /// configured [block](Self::block). Returns a [`CallReplay`] to configure batching and
+    /// concurrency, which yields the block numbers and the results of the call at each block when
+    /// turned [into a stream](CallReplay::into_stream). Calls at blocks whose state was pruned by
+    /// the node fail with [`Error::StateUnavailable`].
+    ///
+    /// A `step` of `0` is treated as `1`.
+    pub const fn call_over_range(
+        &self,
+        from_block: u64,
+        to_block: u64,
+        step: u64,
+    ) -> CallReplay<'_, T, P, D, N> {
+        CallReplay::new(self, from_block, to_block, step)
+    }
+
+    /// Returns the `eth_call` parameters of the call at the given block.
+    pub(crate) fn call_params(&self, block: BlockId) -> EthCallParams<'_, N> {
+        let params = EthCallParams::new(&self.request).with_block(block);
+        let params = match &self.state {
+            Some(state) => params.with_overrides(state),
+            None => params,
+        };
+        match &self.block_overrides {
+            Some(block_overrides) => params.with_block_overrides(block_overrides),
+            None => params,
+        }
+    }
+
     /// Decodes the output of a contract function using the provided decoder.
     #[inline]
     pub fn decode_output(&self, data: Bytes, validate: bool) -> Result<D::CallOutput> {
@@ -481,7 +602,10 @@ impl<T, P: Provider<N>, D: CallDecoder, N: Network> CallBuilder<T, P, D, N> {
     /// For more fine-grained control over the deployment process, use [`send`](Self::send) instead.
     ///
     /// Note that the deployment address can be pre-calculated if the `from` address and `nonce` are
-    /// known using [`calculate_create_address`](Self::calculate_create_address).
+    /// known using [`calculate_create_address`](Self::calculate_create_address). To deploy the
+    /// contract at a deterministic address instead, see [`into_deploy_builder`].
+    ///
+    /// [`into_deploy_builder`]: RawCallBuilder::into_deploy_builder
     pub async fn deploy(&self) -> Result<Address> {
         if !self.request.kind().is_some_and(|to| to.is_create()) {
             return Err(Error::NotADeploymentTransaction);
@@ -515,8 +639,10 @@ impl<T, P: Clone, D, N: Network> CallBuilder<T, &P, D, N> {
             request: self.request,
             block: self.block,
             state: self.state,
+            block_overrides: self.block_overrides,
             provider: self.provider.clone(),
             decoder: self.decoder,
+            errors: self.errors,
             fake_transport: PhantomData,
         }
     }
@@ -559,6 +685,7 @@ impl<T, P, D: CallDecoder, N: Network> std::fmt::Debug for CallBuilder<T, P, D,
             .field("request", &self.request)
             .field("block", &self.block)
             .field("state", &self.state)
+            .field("block_overrides", &self.block_overrides)
             .field("decoder", &self.decoder.as_debug_field())
             .finish()
     }
@@ -752,6 +879,56 @@ mod tests {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn estimate_gas_at_block_with_state_override() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let counter_contract = Counter::deploy(provider.clone()).await.unwrap();
+
+        // Incrementing from zero writes a fresh storage slot.
+        let latest = counter_contract.increment().estimate_gas().await.unwrap();
+
+        // Before the deployment there is no code to execute.
+        let before_deploy =
+            counter_contract.increment().block(BlockId::number(0)).estimate_gas().await.unwrap();
+        assert!(before_deploy < latest);
+
+        // Pretend the counter was already incremented, making the write cheaper.
+        let mut state = StateOverride::default();
+        state.insert(
+            *counter_contract.address(),
+            alloy_rpc_types_eth::state::AccountOverride {
+                state_diff: Some(std::iter::once((B256::ZERO, B256::with_last_byte(1))).collect()),
+                ..Default::default()
+            },
+        );
+        let overridden = counter_contract.increment().state(state).estimate_gas().await.unwrap();
+        assert!(overridden < latest);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn call_over_range() {
+        use futures::StreamExt;
+
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let counter_contract = Counter::deploy(provider.clone()).await.unwrap();
+        let deployed = provider.get_block_number().await.unwrap();
+        for _ in 0..4 {
+            counter_contract.increment().send().await.unwrap().get_receipt().await.unwrap();
+        }
+
+        let call = counter_contract.counter();
+        for batch_size in [1, 2, 50] {
+            let results: Vec<_> = call
+                .call_over_range(deployed, deployed + 4, 2)
+                .batch_size(batch_size)
+                .into_stream()
+                .map(|(block, res)| (block, res.unwrap().counter))
+                .collect()
+                .await;
+            assert_eq!(results, [(deployed, 0), (deployed + 2, 2), (deployed + 4, 4)]);
+        }
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn deploy_and_call_with_priority() {
         let provider = ProviderBuilder::new().on_anvil_with_wallet();
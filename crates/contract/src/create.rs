@@ -0,0 +1,234 @@
+//! Contract address computation for `CREATE`, `CREATE2` and `CREATE3` deployments.
+//!
+//! With the `vanity` feature, [`SaltMiner`] searches salts for deterministic addresses matching a
+//! pattern in parallel.
+
+use alloy_primitives::{address, b256, bytes, keccak256, Address, Bytes, B256};
+
+/// The init code of the proxy deployed by the `CREATE3` pattern, as used by Solady and Solmate.
+///
+/// The proxy deploys the calldata it receives as init code with `CREATE`, so the address of the
+/// final contract only depends on the deployer of the proxy and the salt.
+pub static CREATE3_PROXY_INIT_CODE: Bytes = bytes!("67363d3d37363d34f03d5260086018f3");
+
+/// The keccak256 hash of [`CREATE3_PROXY_INIT_CODE`].
+pub const CREATE3_PROXY_INIT_CODE_HASH: B256 =
+    b256!("21c35dbe1b344a2488cf3321d6ce542f8e9f305544ff09e4993a62319a497c1f");
+
+/// The address of the [deterministic deployment proxy], which is deployed at the same address on
+/// most chains and on local development nodes.
+///
+/// The proxy deploys the init code following a 32 byte salt in its calldata with `CREATE2`, see
+/// [`DeployBuilder::create2`](crate::DeployBuilder::create2).
+///
+/// [deterministic deployment proxy]: https://github.com/Arachnid/deterministic-deployment-proxy
+pub const DETERMINISTIC_DEPLOYER: Address = address!("4e59b44847b379578588920ca78fbf26c0b4956c");
+
+/// Computes the address of a contract deployed by `sender` with `CREATE` at the given nonce.
+pub fn create_address(sender: Address, nonce: u64) -> Address {
+    sender.create(nonce)
+}
+
+/// Computes the address of a contract deployed by `deployer` with `CREATE2`, given the hash of
+/// its init code.
+pub fn create2_address(deployer: Address, salt: B256, init_code_hash: B256) -> Address {
+    deployer.create2(salt, init_code_hash)
+}
+
+/// Computes the address of a contract deployed by `deployer` with `CREATE2`, given its init code.
+pub fn create2_address_from_code(deployer: Address, salt: B256, init_code: &[u8]) -> Address {
+    create2_address(deployer, salt, keccak256(init_code))
+}
+
+/// Computes the address of a contract deployed by `deployer` with the `CREATE3` pattern.
+///
+/// The deployer creates a [proxy](CREATE3_PROXY_INIT_CODE) with `CREATE2` and the salt, which in
+/// turn deploys the contract with `CREATE` at nonce 1. The address is therefore independent of the
+/// contract's init code.
+///
+/// Factories that derive the `CREATE2` salt from the caller, e.g. by hashing it with the user
+/// salt, must be given the derived salt.
+pub fn create3_address(deployer: Address, salt: B256) -> Address {
+    create2_address(deployer, salt, CREATE3_PROXY_INIT_CODE_HASH).create(1)
+}
+
+#[cfg(feature = "vanity")]
+pub use vanity::SaltMiner;
+
+#[cfg(feature = "vanity")]
+mod vanity {
+    use super::{create2_address, create3_address};
+    use alloy_primitives::{Address, B256, U256};
+    use std::{
+        num::NonZeroUsize,
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Mutex,
+        },
+    };
+
+    #[derive(Clone, Copy, Debug)]
+    enum Scheme {
+        Create2 { init_code_hash: B256 },
+        Create3,
+    }
+
+    /// Searches for a salt that deploys a contract at an address matching a pattern.
+    ///
+    /// Salts are tried in parallel, counting up from the [start salt](Self::with_start_salt), so
+    /// the search is deterministic up to which of the threads finds a match first.
+    #[derive(Clone, Debug)]
+    pub struct SaltMiner {
+        deployer: Address,
+        scheme: Scheme,
+        start: B256,
+        threads: usize,
+        max_attempts: Option<u64>,
+    }
+
+    impl SaltMiner {
+        /// Creates a miner for a `CREATE2` deployment by `deployer` of the init code with the
+        /// given hash.
+        pub fn create2(deployer: Address, init_code_hash: B256) -> Self {
+            Self::new(deployer, Scheme::Create2 { init_code_hash })
+        }
+
+        /// Creates a miner for a `CREATE3` deployment by `deployer`.
+        ///
+        /// See [`create3_address`](super::create3_address).
+        pub fn create3(deployer: Address) -> Self {
+            Self::new(deployer, Scheme::Create3)
+        }
+
+        fn new(deployer: Address, scheme: Scheme) -> Self {
+            let threads = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+            Self { deployer, scheme, start: B256::ZERO, threads, max_attempts: None }
+        }
+
+        /// Sets the salt to start counting from. Defaults to zero.
+        ///
+        /// Factories that require the salt to start with the caller's address can be given a
+        /// start salt with that prefix, as only the low bytes are incremented in practice.
+        pub const fn with_start_salt(mut self, start: B256) -> Self {
+            self.start = start;
+            self
+        }
+
+        /// Sets the number of threads. Defaults to the available parallelism.
+        pub fn with_threads(mut self, threads: usize) -> Self {
+            self.threads = threads.max(1);
+            self
+        }
+
+        /// Sets the maximum number of salts to try. Unlimited by default.
+        pub const fn with_max_attempts(mut self, max_attempts: u64) -> Self {
+            self.max_attempts = Some(max_attempts);
+            self
+        }
+
+        /// Returns the address the contract is deployed at with the given salt.
+        pub fn address(&self, salt: B256) -> Address {
+            match self.scheme {
+                Scheme::Create2 { init_code_hash } => {
+                    create2_address(self.deployer, salt, init_code_hash)
+                }
+                Scheme::Create3 => create3_address(self.deployer, salt),
+            }
+        }
+
+        /// Searches for a salt whose address starts with the given bytes.
+        pub fn mine_prefix(&self, prefix: &[u8]) -> Option<(B256, Address)> {
+            self.mine(|address| address.starts_with(prefix))
+        }
+
+        /// Searches for a salt whose address matches the predicate, blocking until one is found
+        /// or the maximum number of attempts is reached.
+        ///
+        /// Returns the salt and the address.
+        pub fn mine<F>(&self, matches: F) -> Option<(B256, Address)>
+        where
+            F: Fn(&Address) -> bool + Sync,
+        {
+            let start = U256::from_be_bytes(self.start.0);
+            let max_attempts = self.max_attempts.unwrap_or(u64::MAX);
+            let next = AtomicU64::new(0);
+            let found = AtomicBool::new(false);
+            let result = Mutex::new(None);
+
+            std::thread::scope(|scope| {
+                for _ in 0..self.threads {
+                    scope.spawn(|| {
+                        // Claim salts in chunks to keep contention on the counter low.
+                        const CHUNK: u64 = 1024;
+                        while !found.load(Ordering::Relaxed) {
+                            let first = next.fetch_add(CHUNK, Ordering::Relaxed);
+                            if first >= max_attempts {
+                                return;
+                            }
+                            for i in first..(first + CHUNK).min(max_attempts) {
+                                let salt = B256::from(start.wrapping_add(U256::from(i)));
+                                let address = self.address(salt);
+                                if matches(&address) {
+                                    found.store(true, Ordering::Relaxed);
+                                    result.lock().unwrap().get_or_insert((salt, address));
+                                    return;
+                                }
+                            }
+                        }
+                    });
+                }
+            });
+
+            result.into_inner().unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex};
+
+    #[test]
+    fn create2_eip1014_vectors() {
+        // Examples from EIP-1014.
+        assert_eq!(
+            create2_address_from_code(Address::ZERO, B256::ZERO, &hex!("00")),
+            address!("4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38")
+        );
+        assert_eq!(
+            create2_address_from_code(
+                address!("00000000000000000000000000000000deadbeef"),
+                b256!("00000000000000000000000000000000000000000000000000000000cafebabe"),
+                &hex!("deadbeef"),
+            ),
+            address!("60f3f640a8508fC6a86d45DF051962668E1e8AC7")
+        );
+    }
+
+    #[test]
+    fn create3_proxy() {
+        assert_eq!(keccak256(&CREATE3_PROXY_INIT_CODE), CREATE3_PROXY_INIT_CODE_HASH);
+
+        let deployer = address!("00000000000000000000000000000000deadbeef");
+        let salt = B256::repeat_byte(1);
+        let proxy = create2_address_from_code(deployer, salt, &CREATE3_PROXY_INIT_CODE);
+        assert_eq!(create3_address(deployer, salt), create_address(proxy, 1));
+    }
+
+    #[cfg(feature = "vanity")]
+    #[test]
+    fn mines_salts() {
+        let deployer = address!("00000000000000000000000000000000deadbeef");
+        let miner = SaltMiner::create2(deployer, keccak256([0])).with_threads(4);
+        let (salt, address) = miner.mine_prefix(&[0]).unwrap();
+        assert_eq!(address[0], 0);
+        assert_eq!(create2_address(deployer, salt, keccak256([0])), address);
+
+        let (salt, address) = SaltMiner::create3(deployer).mine_prefix(&[0xab]).unwrap();
+        assert_eq!(create3_address(deployer, salt), address);
+
+        let miner = SaltMiner::create2(deployer, B256::ZERO).with_max_attempts(10);
+        assert_eq!(miner.mine_prefix(&[0; 8]), None);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{
+    create::{create2_address, DETERMINISTIC_DEPLOYER},
+    Error, RawCallBuilder, Result,
+};
+use alloy_network::{Ethereum, Network, TransactionBuilder};
+use alloy_network_primitives::ReceiptResponse;
+use alloy_primitives::{keccak256, Address, Bytes, B256};
+use alloy_provider::{PendingTransactionBuilder, Provider};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug)]
+struct Create2 {
+    deployer: Address,
+    salt: B256,
+}
+
+/// A builder for deploying a contract, either with `CREATE` from the sender of the transaction, or
+/// with `CREATE2` through a deterministic deployer.
+///
+/// Created from the call builder of a deployment transaction with
+/// [`into_deploy_builder`](RawCallBuilder::into_deploy_builder), e.g. the one returned by the
+/// `deploy_builder` method of [`sol!`][sol] contracts or by
+/// [`ContractFactory::deploy_builder`](crate::ContractFactory::deploy_builder). The transaction,
+/// e.g. its sender or gas limit, is configured on the call builder before converting it.
+///
+/// `CREATE2` deployments send the salt followed by the init code to the deployer, which by
+/// default is the [`DETERMINISTIC_DEPLOYER`] available on most chains. The address of the contract
+/// then only depends on the deployer, the salt and the init code, including the constructor
+/// arguments. Salts matching an address pattern can be searched for with
+/// [`init_code_hash`](Self::init_code_hash) and the `SaltMiner` of the `vanity` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn test<P: alloy_provider::Provider>(provider: P) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_primitives::{B256, U256};
+/// use alloy_sol_types::sol;
+///
+/// sol! {
+///     #[sol(rpc, bytecode = "6942")]
+///     contract Counter {
+///         constructor(uint256 start);
+///     }
+/// }
+///
+/// let deployment = Counter::deploy_builder(&provider, U256::from(1))
+///     .into_deploy_builder()
+///     .create2(B256::with_last_byte(1))
+///     .skip_if_deployed(true)
+///     .with_required_confirmations(2);
+///
+/// let address = deployment.address().unwrap();
+/// assert_eq!(deployment.deploy().await?, address);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [sol]: alloy_sol_types::sol
+#[derive(Clone)]
+#[must_use = "deploy builders do nothing unless you `.deploy` or `.send` them"]
+pub struct DeployBuilder<P, N: Network = Ethereum> {
+    call: RawCallBuilder<(), P, N>,
+    create2: Option<Create2>,
+    skip_if_deployed: bool,
+    required_confirmations: u64,
+    timeout: Option<Duration>,
+}
+
+impl<P, N: Network> DeployBuilder<P, N> {
+    pub(crate) const fn new(call: RawCallBuilder<(), P, N>) -> Self {
+        Self {
+            call,
+            create2: None,
+            skip_if_deployed: false,
+            required_confirmations: 1,
+            timeout: None,
+        }
+    }
+}
+
+impl<P: Provider<N>, N: Network> DeployBuilder<P, N> {
+    /// Deploys the contract with `CREATE2` through the [`DETERMINISTIC_DEPLOYER`], using the given
+    /// salt.
+    pub const fn create2(self, salt: B256) -> Self {
+        self.create2_with_deployer(DETERMINISTIC_DEPLOYER, salt)
+    }
+
+    /// Deploys the contract with `CREATE2` through the given deployer, using the given salt.
+    ///
+    /// The deployer must deploy the init code following the salt in its calldata, like the
+    /// [`DETERMINISTIC_DEPLOYER`].
+    pub const fn create2_with_deployer(mut self, deployer: Address, salt: B256) -> Self {
+        self.create2 = Some(Create2 { deployer, salt });
+        self
+    }
+
+    /// Deploys the contract with `CREATE` from the sender of the transaction. This is the default.
+    pub const fn create(mut self) -> Self {
+        self.create2 = None;
+        self
+    }
+
+    /// Returns the `CREATE2` deployer, or `None` if the contract is deployed with `CREATE`.
+    pub fn deployer(&self) -> Option<Address> {
+        self.create2.map(|create2| create2.deployer)
+    }
+
+    /// Returns the `CREATE2` salt, or `None` if the contract is deployed with `CREATE`.
+    pub fn salt(&self) -> Option<B256> {
+        self.create2.map(|create2| create2.salt)
+    }
+
+    /// Sets whether to return the address of an already deployed contract instead of sending the
+    /// transaction, which would fail, if there is code at the `CREATE2` address. Defaults to
+    /// `false`.
+    ///
+    /// This makes deterministic deployments idempotent. Has no effect for `CREATE` deployments.
+    pub const fn skip_if_deployed(mut self, skip: bool) -> Self {
+        self.skip_if_deployed = skip;
+        self
+    }
+
+    /// Sets the number of confirmations to wait for before the contract is considered deployed.
+    /// Defaults to 1.
+    pub const fn with_required_confirmations(mut self, confirmations: u64) -> Self {
+        self.required_confirmations = confirmations;
+        self
+    }
+
+    /// Sets the maximum time to wait for the deployment transaction to be confirmed. Unlimited by
+    /// default.
+    pub const fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the init code of the contract, i.e. its creation bytecode followed by the ABI
+    /// encoded constructor arguments.
+    pub fn init_code(&self) -> &Bytes {
+        self.call.calldata()
+    }
+
+    /// Returns the keccak256 hash of the [init code](Self::init_code).
+    pub fn init_code_hash(&self) -> B256 {
+        keccak256(self.init_code())
+    }
+
+    /// Returns the address the contract will be deployed at.
+    ///
+    /// `CREATE` addresses can only be computed if the `from` and `nonce` fields of the transaction
+    /// are set, see
+    /// [`CallBuilder::calculate_create_address`](crate::CallBuilder::calculate_create_address).
+    pub fn address(&self) -> Option<Address> {
+        match self.create2 {
+            Some(Create2 { deployer, salt }) => {
+                Some(create2_address(deployer, salt, self.init_code_hash()))
+            }
+            None => self.call.calculate_create_address(),
+        }
+    }
+
+    /// Returns the deployment transaction.
+    ///
+    /// `CREATE2` deployments are calls to the deployer with the salt followed by the init code as
+    /// input.
+    ///
+    /// Returns an error if the call builder is not a deployment transaction.
+    pub fn transaction_request(&self) -> Result<N::TransactionRequest> {
+        let mut request = self.call.as_ref().clone();
+        if !request.kind().is_some_and(|to| to.is_create()) {
+            return Err(Error::NotADeploymentTransaction);
+        }
+        if let Some(Create2 { deployer, salt }) = self.create2 {
+            let input = [salt.as_slice(), &self.init_code()[..]].concat();
+            request.set_to(deployer);
+            request.set_input(input);
+        }
+        Ok(request)
+    }
+
+    /// Broadcasts the deployment transaction to the network.
+    ///
+    /// Returns a builder for the pending transaction, configured with the
+    /// [required confirmations](Self::with_required_confirmations) and
+    /// [timeout](Self::with_timeout) of this builder.
+    pub async fn send(&self) -> Result<PendingTransactionBuilder<N>> {
+        let request = self.transaction_request()?;
+        let pending_tx = self.call.provider.send_transaction(request).await?;
+        Ok(pending_tx
+            .with_required_confirmations(self.required_confirmations)
+            .with_timeout(self.timeout))
+    }
+
+    /// Deploys the contract, returning its address after the deployment transaction has been
+    /// confirmed.
+    ///
+    /// Returns an error if there is no code at the `CREATE2` address after the deployment, or if
+    /// the contract address is not found in the receipt of a `CREATE` deployment.
+    pub async fn deploy(&self) -> Result<Address> {
+        let Some(Create2 { deployer, salt }) = self.create2 else {
+            let receipt = self.send().await?.get_receipt().await?;
+            return receipt.contract_address().ok_or(Error::ContractNotDeployed);
+        };
+
+        let address = create2_address(deployer, salt, self.init_code_hash());
+        if self.skip_if_deployed && self.has_code(address).await? {
+            return Ok(address);
+        }
+        self.send().await?.get_receipt().await?;
+        if !self.has_code(address).await? {
+            return Err(Error::CodeNotDeployed(address));
+        }
+        Ok(address)
+    }
+
+    async fn has_code(&self, address: Address) -> Result<bool> {
+        Ok(!self.call.provider.get_code_at(address).await?.is_empty())
+    }
+}
+
+impl<P, N: Network> std::fmt::Debug for DeployBuilder<P, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeployBuilder")
+            .field("call", &self.call)
+            .field("create2", &self.create2)
+            .field("skip_if_deployed", &self.skip_if_deployed)
+            .field("required_confirmations", &self.required_confirmations)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create::create2_address_from_code;
+    use alloy_primitives::bytes;
+    use alloy_provider::{ProviderBuilder, WalletProvider};
+
+    // Deploys a contract whose code is a single `STOP` instruction.
+    static INIT_CODE: Bytes = bytes!("6001600c60003960016000f300");
+
+    fn deploy_call<P: Provider>(provider: P) -> RawCallBuilder<(), P> {
+        RawCallBuilder::new_raw_deploy(provider, INIT_CODE.clone())
+    }
+
+    #[test]
+    fn create2_transaction() {
+        let provider = ProviderBuilder::new().on_anvil();
+        let salt = B256::repeat_byte(0x11);
+        let deployment = deploy_call(&provider).into_deploy_builder();
+        assert_eq!(deployment.salt(), None);
+        assert_eq!(deployment.address(), None);
+
+        let deployment = deployment.create2(salt);
+        assert_eq!(deployment.deployer(), Some(DETERMINISTIC_DEPLOYER));
+        assert_eq!(
+            deployment.address(),
+            Some(create2_address_from_code(DETERMINISTIC_DEPLOYER, salt, &INIT_CODE))
+        );
+
+        let request = deployment.transaction_request().unwrap();
+        assert_eq!(request.to, Some(DETERMINISTIC_DEPLOYER.into()));
+        assert_eq!(request.input.input().unwrap()[..], [&salt[..], &INIT_CODE[..]].concat());
+
+        let call = deploy_call(&provider).to(DETERMINISTIC_DEPLOYER);
+        assert!(matches!(
+            call.into_deploy_builder().create2(salt).transaction_request(),
+            Err(Error::NotADeploymentTransaction)
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn deploy_create2() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let salt = B256::repeat_byte(0x22);
+        let deployment = deploy_call(&provider).into_deploy_builder().create2(salt);
+        let expected = deployment.address().unwrap();
+
+        assert_eq!(deployment.deploy().await.unwrap(), expected);
+        assert_eq!(provider.get_code_at(expected).await.unwrap()[..], [0x00]);
+
+        // redeploying at the same address is skipped if requested, and fails otherwise
+        let block = provider.get_block_number().await.unwrap();
+        let deployment = deployment.skip_if_deployed(true);
+        assert_eq!(deployment.deploy().await.unwrap(), expected);
+        assert_eq!(provider.get_block_number().await.unwrap(), block);
+        assert!(deployment.skip_if_deployed(false).deploy().await.is_err());
+
+        // `CREATE` deployments are unaffected
+        let from = provider.default_signer_address();
+        let nonce = provider.get_transaction_count(from).await.unwrap();
+        let deployment = deploy_call(&provider).from(from).nonce(nonce).into_deploy_builder();
+        let expected = from.create(nonce);
+        assert_eq!(deployment.address(), Some(expected));
+        assert_eq!(deployment.deploy().await.unwrap(), expected);
+    }
+}
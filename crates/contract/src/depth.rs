@@ -0,0 +1,123 @@
+//! Nesting depth limits for ABI types and values.
+//!
+//! Decoding ABI data recurses once per level of nesting of the decoded types, so an ABI received
+//! from an untrusted source can make the decoder overflow the stack. The functions in this module
+//! measure nesting depths iteratively, allowing callers to reject such inputs before decoding or
+//! walking them. [`Interface`](crate::Interface) checks the depth of function parameters against
+//! [`DEFAULT_MAX_DEPTH`] by default.
+
+use crate::{Error, Result};
+use alloy_dyn_abi::DynSolValue;
+use alloy_json_abi::Param;
+
+/// The default maximum nesting depth of ABI types and values.
+///
+/// Each array and tuple adds one level of nesting, so `uint256` has a depth of 0 and
+/// `(uint256,bytes)[]` has a depth of 2.
+pub const DEFAULT_MAX_DEPTH: usize = 32;
+
+/// Returns the nesting depth of the given parameters, i.e. the maximum depth of their types.
+pub fn params_depth(params: &[Param]) -> usize {
+    let mut max = 0;
+    let mut stack = params.iter().map(|param| (param, 0)).collect::<Vec<_>>();
+    while let Some((param, parent_depth)) = stack.pop() {
+        let arrays = param.ty.matches('[').count();
+        let tuple = usize::from(param.ty.starts_with("tuple"));
+        let depth = parent_depth + arrays + tuple;
+        max = max.max(depth);
+        stack.extend(param.components.iter().map(|component| (component, depth)));
+    }
+    max
+}
+
+/// Returns the nesting depth of the given value.
+pub fn value_depth(value: &DynSolValue) -> usize {
+    let mut max = 0;
+    let mut stack = vec![(value, 0)];
+    while let Some((value, depth)) = stack.pop() {
+        max = max.max(depth);
+        let children =
+            value.as_tuple().or_else(|| value.as_array()).or_else(|| value.as_fixed_array());
+        if let Some(children) = children {
+            max = max.max(depth + 1);
+            stack.extend(children.iter().map(|child| (child, depth + 1)));
+        }
+    }
+    max
+}
+
+/// Returns an error if the nesting depth of the parameters exceeds `limit`.
+pub fn check_params_depth(params: &[Param], limit: usize) -> Result<()> {
+    check(params_depth(params), limit)
+}
+
+/// Returns an error if the nesting depth of the value exceeds `limit`.
+pub fn check_value_depth(value: &DynSolValue, limit: usize) -> Result<()> {
+    check(value_depth(value), limit)
+}
+
+const fn check(depth: usize, limit: usize) -> Result<()> {
+    if depth > limit {
+        return Err(Error::DepthLimitExceeded { depth, limit });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    fn param(ty: &str, components: Vec<Param>) -> Param {
+        Param { ty: ty.to_string(), name: String::new(), components, internal_type: None }
+    }
+
+    #[test]
+    fn measures_params() {
+        assert_eq!(params_depth(&[]), 0);
+        assert_eq!(params_depth(&[param("uint256", vec![])]), 0);
+        assert_eq!(params_depth(&[param("uint256[][2]", vec![])]), 2);
+        let inner = param("tuple[]", vec![param("bytes", vec![]), param("uint8[]", vec![])]);
+        let outer = param("tuple", vec![param("address", vec![]), inner]);
+        assert_eq!(params_depth(&[param("bool", vec![]), outer.clone()]), 4);
+
+        assert!(check_params_depth(&[outer.clone()], 4).is_ok());
+        assert!(matches!(
+            check_params_depth(&[outer], 3),
+            Err(Error::DepthLimitExceeded { depth: 4, limit: 3 })
+        ));
+    }
+
+    #[test]
+    fn interface_rejects_deep_functions() {
+        let function = alloy_json_abi::Function::parse("function f(uint256[][][] a)").unwrap();
+        let mut abi = alloy_json_abi::JsonAbi::default();
+        abi.functions.insert(function.name.clone(), vec![function]);
+        let interface = crate::Interface::new(abi);
+        let data = DynSolValue::Array(vec![]).abi_encode_params();
+        assert!(interface.decode_input("f", &data, true).is_ok());
+        assert!(matches!(
+            interface.with_max_depth(2).decode_input("f", &data, true),
+            Err(Error::DepthLimitExceeded { depth: 3, limit: 2 })
+        ));
+    }
+
+    #[test]
+    fn measures_values() {
+        let uint = DynSolValue::Uint(U256::from(1), 256);
+        assert_eq!(value_depth(&uint), 0);
+        assert_eq!(value_depth(&DynSolValue::Tuple(vec![])), 1);
+
+        let mut value = uint;
+        for _ in 0..10_000 {
+            value = DynSolValue::Array(vec![value]);
+        }
+        assert_eq!(value_depth(&value), 10_000);
+        assert!(check_value_depth(&value, DEFAULT_MAX_DEPTH).is_err());
+
+        // drop iteratively to not overflow the stack in the test itself
+        while let DynSolValue::Array(mut values) = value {
+            value = values.pop().unwrap();
+        }
+    }
+}
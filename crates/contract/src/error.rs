@@ -1,6 +1,7 @@
 use alloy_dyn_abi::Error as AbiError;
-use alloy_primitives::Selector;
+use alloy_primitives::{Address, Bytes, Selector};
 use alloy_provider::PendingTransactionError;
+use alloy_sol_types::SolInterface;
 use alloy_transport::TransportError;
 use thiserror::Error;
 
@@ -22,9 +23,31 @@ pub enum Error {
     /// `contractAddress` was not found in the deployment transaction’s receipt.
     #[error("missing `contractAddress` from deployment transaction receipt")]
     ContractNotDeployed,
+    /// No code was found at the address of a deterministic deployment after the deployment
+    /// transaction was confirmed.
+    #[error("no contract code found at {0} after deployment")]
+    CodeNotDeployed(Address),
     /// The contract returned no data.
     #[error("contract call to `{0}` returned no data (\"0x\"); the called address might not be a contract")]
     ZeroData(String, #[source] AbiError),
+    /// An ABI type or value is nested deeper than allowed.
+    #[error("nesting depth {depth} exceeds the limit of {limit}")]
+    DepthLimitExceeded {
+        /// The nesting depth of the type or value.
+        depth: usize,
+        /// The maximum allowed nesting depth.
+        limit: usize,
+    },
+    /// The node does not have the state of the block a call was made at, usually because it is
+    /// not an archive node and the state was pruned.
+    #[error("state of block {block} is unavailable; an archive node may be required")]
+    StateUnavailable {
+        /// The block the call was made at.
+        block: u64,
+        /// The error returned by the node.
+        #[source]
+        source: TransportError,
+    },
     /// An error occurred ABI encoding or decoding.
     #[error(transparent)]
     AbiError(#[from] AbiError),
@@ -52,4 +75,111 @@ impl Error {
         }
         Self::AbiError(error)
     }
+
+    /// Returns the revert data of the error, if it is an RPC error returned for a reverted call.
+    ///
+    /// Custom errors can be decoded from the revert data with [`as_decoded_error`], or with an
+    /// [`ErrorDecoder`](crate::ErrorDecoder) if only their ABI is known.
+    ///
+    /// [`as_decoded_error`]: Self::as_decoded_error
+    pub fn as_revert_data(&self) -> Option<Bytes> {
+        match self {
+            Self::TransportError(error) => error.as_error_resp()?.as_revert_data(),
+            _ => None,
+        }
+    }
+
+    /// Decodes the revert data of the error into the given set of custom errors, e.g. the
+    /// `{Contract}Errors` enum generated by the `sol!` macro.
+    ///
+    /// Returns `None` if the error is not a revert, or if the revert data does not match any of
+    /// the custom errors.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn test<P: alloy_provider::Provider>(provider: P) -> Result<(), Box<dyn std::error::Error>> {
+    /// use alloy_primitives::{Address, U256};
+    /// use alloy_sol_types::sol;
+    ///
+    /// sol! {
+    ///     #[sol(rpc)]
+    ///     contract Vault {
+    ///         error InsufficientBalance(uint256 available, uint256 required);
+    ///         function withdraw(uint256 amount) external;
+    ///     }
+    /// }
+    ///
+    /// let vault = Vault::new(Address::ZERO, &provider);
+    /// if let Err(err) = vault.withdraw(U256::from(1)).call().await {
+    ///     if let Some(Vault::VaultErrors::InsufficientBalance(e)) =
+    ///         err.as_decoded_error::<Vault::VaultErrors>()
+    ///     {
+    ///         println!("only {} available", e.available);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_decoded_error<E: SolInterface>(&self) -> Option<E> {
+        self.as_revert_data().and_then(|data| E::abi_decode(&data, true).ok())
+    }
+
+    /// Converts the error into the given set of custom errors, see [`as_decoded_error`].
+    ///
+    /// Returns the original error if it could not be decoded.
+    ///
+    /// [`as_decoded_error`]: Self::as_decoded_error
+    pub fn try_into_decoded_error<E: SolInterface>(self) -> Result<E, Self> {
+        self.as_decoded_error().ok_or(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use alloy_primitives::U256;
+    use alloy_sol_types::{sol, SolError};
+
+    sol! {
+        #![sol(all_derives)]
+
+        contract Vault {
+            error InsufficientBalance(uint256 available, uint256 required);
+            error Paused();
+        }
+    }
+
+    fn revert(message: &str, data: &[u8]) -> Error {
+        let data = serde_json::value::to_raw_value(&Bytes::copy_from_slice(data)).unwrap();
+        Error::TransportError(TransportError::ErrorResp(ErrorPayload {
+            code: 3,
+            message: message.to_string().into(),
+            data: Some(data),
+        }))
+    }
+
+    #[test]
+    fn decodes_custom_errors() {
+        let error =
+            Vault::InsufficientBalance { available: U256::from(1), required: U256::from(2) };
+        let err = revert("execution reverted", &error.abi_encode());
+        match err.as_decoded_error::<Vault::VaultErrors>() {
+            Some(Vault::VaultErrors::InsufficientBalance(e)) => assert_eq!(e, error),
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(matches!(
+            err.try_into_decoded_error::<Vault::VaultErrors>(),
+            Ok(Vault::VaultErrors::InsufficientBalance(_))
+        ));
+
+        // unknown selectors and non-revert errors are returned as is
+        let err = revert("execution reverted", &[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(err.as_decoded_error::<Vault::VaultErrors>(), None);
+        assert!(err.try_into_decoded_error::<Vault::VaultErrors>().is_err());
+        let err = revert("rate limited", &Vault::Paused {}.abi_encode());
+        assert_eq!(err.as_decoded_error::<Vault::VaultErrors>(), None);
+        assert_eq!(Error::ContractNotDeployed.as_decoded_error::<Vault::VaultErrors>(), None);
+    }
 }
@@ -4,10 +4,10 @@ use alloy_primitives::{Address, LogData, B256};
 use alloy_provider::{FilterPollerBuilder, Network, Provider};
 use alloy_rpc_types_eth::{BlockNumberOrTag, Filter, FilterBlockOption, Log, Topic, ValueOrArray};
 use alloy_sol_types::SolEvent;
-use alloy_transport::TransportResult;
-use futures::Stream;
+use alloy_transport::{TransportError, TransportResult};
+use futures::{future, stream, Stream};
 use futures_util::StreamExt;
-use std::{fmt, marker::PhantomData};
+use std::{fmt, marker::PhantomData, time::Duration};
 
 /// Helper for managing the event filter before querying or streaming its logs
 #[must_use = "event filters do nothing unless you `query`, `watch`, or `stream` them"]
@@ -83,6 +83,14 @@ impl<T, P: Provider<N>, E: SolEvent, N: Network> Event<T, P, E, N> {
         Ok(sub.into())
     }
 
+    /// Streams the events that match the filter from the given block on, first paging through the
+    /// historical logs and then switching to a live poller.
+    ///
+    /// Returns an [`EventBackfill`] to configure the stream, see its documentation for details.
+    pub const fn stream_with_backfill(&self, from_block: u64) -> EventBackfill<'_, T, P, E, N> {
+        EventBackfill::new(self, from_block)
+    }
+
     /// Sets the inner filter object
     ///
     /// See [`Filter::select`].
@@ -170,6 +178,156 @@ impl<T, P: Clone, E, N> Event<T, &P, E, N> {
     }
 }
 
+/// A stream of events that backfills the historical logs before following new logs, see
+/// [`Event::stream_with_backfill`].
+///
+/// A live filter is installed with `eth_newFilter` first. Then, the logs from the start block to
+/// the latest block are fetched with `eth_getLogs`, in chunks of [`block_span`](Self::block_span)
+/// blocks to respect the range limits of providers. Finally, the stream switches to polling the
+/// live filter. Logs of blocks covered by both the backfill and the live filter are only yielded
+/// once, so that events are yielded in order and without gaps or duplicates.
+///
+/// The block range of the event filter is ignored. Logs removed by reorgs are skipped. The stream
+/// ends after the first error.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_contract::Event;
+/// use alloy_primitives::address;
+/// use alloy_rpc_types_eth::Filter;
+/// use alloy_sol_types::{sol, SolEvent};
+/// use futures::StreamExt;
+///
+/// sol! {
+///     event Transfer(address indexed from, address indexed to, uint256 value);
+/// }
+///
+/// let filter = Filter::new()
+///     .address(address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"))
+///     .event_signature(Transfer::SIGNATURE_HASH);
+/// let event: Event<(), _, Transfer> = Event::new(&provider, filter);
+/// let stream = event.stream_with_backfill(20_000_000).block_span(1_000).into_stream();
+/// futures::pin_mut!(stream);
+/// while let Some(res) = stream.next().await {
+///     let (transfer, log) = res?;
+///     println!("{} -> {}: {} in {:?}", transfer.from, transfer.to, transfer.value, log.block_number);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+#[must_use = "event backfills do nothing unless turned into a stream"]
+pub struct EventBackfill<'a, T, P, E, N> {
+    event: &'a Event<T, P, E, N>,
+    from_block: u64,
+    block_span: u64,
+    poll_interval: Option<Duration>,
+}
+
+impl<'a, T, P, E, N> EventBackfill<'a, T, P, E, N>
+where
+    P: Provider<N>,
+    E: SolEvent,
+    N: Network,
+{
+    /// The default number of blocks covered by each `eth_getLogs` request.
+    pub const DEFAULT_BLOCK_SPAN: u64 = 2_000;
+
+    const fn new(event: &'a Event<T, P, E, N>, from_block: u64) -> Self {
+        Self { event, from_block, block_span: Self::DEFAULT_BLOCK_SPAN, poll_interval: None }
+    }
+
+    /// Sets the number of blocks covered by each `eth_getLogs` request of the backfill. A span of
+    /// `0` is treated as `1`.
+    pub const fn block_span(mut self, block_span: u64) -> Self {
+        self.block_span = block_span;
+        self
+    }
+
+    /// Sets the interval at which the live filter is polled. Defaults to the poll interval of the
+    /// client.
+    pub const fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = Some(poll_interval);
+        self
+    }
+
+    /// Returns a stream of the decoded events and raw logs.
+    ///
+    /// Note that this stream will not return `None` until the provider is dropped, or an error
+    /// occurs.
+    pub fn into_stream(self) -> impl Stream<Item = Result<(E, Log), Error>> + 'a {
+        let Self { event, from_block, block_span, poll_interval } = self;
+        let span = block_span.max(1);
+        let setup = async move {
+            // The live filter is installed before the latest block is read, so that no block is
+            // missed between the backfill and the live logs.
+            let live = event.filter.clone().select(FilterBlockOption::default());
+            let mut poller = event.provider.watch_logs(&live).await?;
+            if let Some(poll_interval) = poll_interval {
+                poller.set_poll_interval(poll_interval);
+            }
+            let latest = event.provider.get_block_number().await?;
+            Ok::<_, TransportError>((poller.into_stream(), latest))
+        };
+
+        stream::once(setup)
+            .flat_map(move |res| match res {
+                Ok((live, latest)) => block_ranges(from_block, latest, span)
+                    .then(move |(start, end)| {
+                        let filter = event.filter.clone().from_block(start).to_block(end);
+                        async move { event.provider.get_logs(&filter).await }
+                    })
+                    .chain(live.map(Ok))
+                    .left_stream(),
+                Err(err) => stream::once(future::ready(Err(err))).right_stream(),
+            })
+            .scan((None, false), |(last, failed), res| {
+                if *failed {
+                    return future::ready(None);
+                }
+                let items: Vec<Result<(E, Log), Error>> = match res {
+                    Ok(logs) => logs
+                        .into_iter()
+                        .filter(|log| advance(last, log))
+                        .map(|log| Ok((decode_log(&log)?, log)))
+                        .collect(),
+                    Err(err) => {
+                        *failed = true;
+                        vec![Err(Error::TransportError(err))]
+                    }
+                };
+                future::ready(Some(stream::iter(items)))
+            })
+            .flatten()
+    }
+}
+
+/// Returns the inclusive block ranges of at most `span` blocks covering `from..=to`.
+fn block_ranges(from: u64, to: u64, span: u64) -> stream::Iter<impl Iterator<Item = (u64, u64)>> {
+    let step = usize::try_from(span).unwrap_or(usize::MAX);
+    stream::iter(
+        (from..=to).step_by(step).map(move |start| (start, start.saturating_add(span - 1).min(to))),
+    )
+}
+
+/// Returns `true` if the log is positioned after the last yielded log, updating the last position.
+/// Logs without a position, e.g. removed logs, are skipped.
+fn advance(last: &mut Option<(u64, u64)>, log: &Log) -> bool {
+    let (Some(block_number), Some(log_index), false) =
+        (log.block_number, log.log_index, log.removed)
+    else {
+        return false;
+    };
+    let position = Some((block_number, log_index));
+    if position <= *last {
+        return false;
+    }
+    *last = position;
+    true
+}
+
 /// An event poller.
 ///
 /// Polling configuration is available through the [`poller`](Self::poller) field.
@@ -521,4 +679,49 @@ mod tests {
             assert_eq!(all.len(), 0);
         }
     }
+
+    #[tokio::test]
+    async fn stream_with_backfill() {
+        let provider = alloy_provider::ProviderBuilder::new().on_anvil_with_wallet();
+        let contract = MyContract::deploy(&provider).await.unwrap();
+        for _ in 0..2 {
+            contract.doEmit().send().await.unwrap().get_receipt().await.unwrap();
+        }
+
+        let event = contract.MyEvent_filter();
+        let stream = event
+            .stream_with_backfill(0)
+            .block_span(1)
+            .poll_interval(std::time::Duration::from_millis(50))
+            .into_stream();
+        futures::pin_mut!(stream);
+
+        // backfilled events
+        for block in [2, 3] {
+            let (_, log) = stream.next().await.unwrap().unwrap();
+            assert_eq!(log.block_number, Some(block));
+        }
+
+        // live events
+        contract.doEmit().send().await.unwrap().get_receipt().await.unwrap();
+        let (decoded, log) = stream.next().await.unwrap().unwrap();
+        assert_eq!(decoded._0, 42);
+        assert_eq!(log.block_number, Some(4));
+    }
+
+    #[test]
+    fn advance_skips_overlap() {
+        let log = |block_number, log_index| Log {
+            block_number: Some(block_number),
+            log_index: Some(log_index),
+            ..Default::default()
+        };
+        let mut last = None;
+        assert!(advance(&mut last, &log(1, 1)));
+        assert!(!advance(&mut last, &log(1, 1)));
+        assert!(!advance(&mut last, &log(1, 0)));
+        assert!(advance(&mut last, &log(2, 0)));
+        assert!(!advance(&mut last, &Log { removed: true, ..log(3, 0) }));
+        assert!(!advance(&mut last, &Log::default()));
+    }
 }
@@ -0,0 +1,354 @@
+use crate::Result;
+use alloy_consensus::{BlockHeader, Transaction as _};
+use alloy_dyn_abi::{DecodedEvent, DynSolValue, EventExt, JsonAbiExt};
+use alloy_json_abi::{Event, Function, JsonAbi};
+use alloy_network::{FeeBreakdown, FeeComponent, FeeDimension};
+use alloy_network_primitives::{BlockResponse, ReceiptResponse};
+use alloy_primitives::{
+    map::{B256HashMap, SelectorHashMap},
+    Selector, TxHash, U256,
+};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::{BlockTransactionsKind, Log, Transaction, TransactionReceipt};
+#[cfg(feature = "trace")]
+use alloy_rpc_types_trace::geth::{CallConfig, CallFrame, GethDebugTracingOptions};
+
+/// Assembles an enriched view of a mined transaction, see [`TxInspection`].
+///
+/// Function inputs and logs are decoded with the registered ABIs and signatures, regardless of the
+/// address of the contract.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider, hash: alloy_primitives::TxHash) -> Result<(), alloy_contract::Error> {
+/// use alloy_contract::TxInspector;
+///
+/// let inspector = TxInspector::new(provider)
+///     .with_function_signature("transfer(address,uint256)")?
+///     .with_event_signature("Transfer(address indexed,address indexed,uint256)")?;
+///
+/// if let Some(inspection) = inspector.inspect(hash).await? {
+///     if let Some(call) = &inspection.call {
+///         println!("{}: {:?}", call.function.name, call.args);
+///     }
+///     println!("paid {} wei", inspection.fees.total_cost());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TxInspector<P> {
+    provider: P,
+    functions: SelectorHashMap<Vec<Function>>,
+    events: B256HashMap<Vec<Event>>,
+    #[cfg(feature = "trace")]
+    trace: bool,
+}
+
+impl<P: Provider> TxInspector<P> {
+    /// Creates a new inspector without any ABIs.
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider,
+            functions: Default::default(),
+            events: Default::default(),
+            #[cfg(feature = "trace")]
+            trace: false,
+        }
+    }
+
+    /// Registers the functions and events of the given ABI.
+    pub fn with_abi(mut self, abi: &JsonAbi) -> Self {
+        for function in abi.functions() {
+            self.add_function(function.clone());
+        }
+        for event in abi.events() {
+            self.add_event(event.clone());
+        }
+        self
+    }
+
+    /// Registers a function from its human-readable signature, e.g. one obtained from a 4byte
+    /// database.
+    pub fn with_function_signature(mut self, signature: &str) -> Result<Self> {
+        let function = Function::parse(signature).map_err(alloy_dyn_abi::Error::from)?;
+        self.add_function(function);
+        Ok(self)
+    }
+
+    /// Registers an event from its human-readable signature, e.g. one obtained from a 4byte
+    /// database.
+    pub fn with_event_signature(mut self, signature: &str) -> Result<Self> {
+        let event = Event::parse(signature).map_err(alloy_dyn_abi::Error::from)?;
+        self.add_event(event);
+        Ok(self)
+    }
+
+    /// Sets whether to fetch the call trace of the transaction with `debug_traceTransaction`.
+    ///
+    /// The trace is best effort: if the request fails for any reason, e.g. because the node does
+    /// not serve the `debug` namespace or cannot trace the transaction, [`TxInspection::trace`]
+    /// is `None`. Defaults to `false`.
+    #[cfg(feature = "trace")]
+    pub const fn with_trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    fn add_function(&mut self, function: Function) {
+        let functions = self.functions.entry(function.selector()).or_default();
+        if !functions.contains(&function) {
+            functions.push(function);
+        }
+    }
+
+    fn add_event(&mut self, event: Event) {
+        if event.anonymous {
+            return;
+        }
+        let events = self.events.entry(event.selector()).or_default();
+        if !events.contains(&event) {
+            events.push(event);
+        }
+    }
+
+    /// Assembles the enriched view of the transaction with the given hash.
+    ///
+    /// The transaction, receipt and trace are fetched concurrently. Returns `None` if the
+    /// transaction does not exist or is not mined yet.
+    pub async fn inspect(&self, hash: TxHash) -> Result<Option<TxInspection>> {
+        let fetch = futures::future::try_join(
+            self.provider.get_transaction_by_hash(hash),
+            self.provider.get_transaction_receipt(hash),
+        );
+        #[cfg(feature = "trace")]
+        let (fetched, trace) = futures::join!(fetch, self.trace(hash));
+        #[cfg(not(feature = "trace"))]
+        let fetched = fetch.await;
+        let (Some(transaction), Some(receipt)) = fetched? else {
+            return Ok(None);
+        };
+
+        let base_fee_per_gas = match receipt.block_hash {
+            Some(block_hash) => self
+                .provider
+                .get_block_by_hash(block_hash, BlockTransactionsKind::Hashes)
+                .await?
+                .and_then(|block| block.header().base_fee_per_gas()),
+            None => None,
+        };
+        let fees = FeeBreakdown::from_receipt(&receipt);
+
+        let call = self.decode_call(&transaction);
+        let logs = receipt
+            .inner
+            .logs()
+            .iter()
+            .map(|log| InspectedLog { decoded: self.decode_log(log), log: log.clone() })
+            .collect();
+
+        Ok(Some(TxInspection {
+            transaction,
+            receipt,
+            call,
+            logs,
+            #[cfg(feature = "trace")]
+            trace,
+            fees,
+            base_fee_per_gas,
+        }))
+    }
+
+    /// Fetches the call trace of the transaction if requested, mapping any failure to `None`.
+    #[cfg(feature = "trace")]
+    async fn trace(&self, hash: TxHash) -> Option<CallFrame> {
+        if !self.trace {
+            return None;
+        }
+        let options = GethDebugTracingOptions::call_tracer(CallConfig::default().with_log());
+        self.provider
+            .client()
+            .request::<_, CallFrame>("debug_traceTransaction", (hash, options))
+            .await
+            .ok()
+    }
+
+    /// Decodes the input of the transaction with the registered functions.
+    ///
+    /// Returns `None` for contract creations, and if no registered function matches.
+    pub fn decode_call(&self, transaction: &Transaction) -> Option<DecodedCall> {
+        if transaction.is_create() {
+            return None;
+        }
+        let input = transaction.input();
+        let (selector, data) = input.split_first_chunk::<4>()?;
+        self.functions.get(&Selector::from(*selector))?.iter().find_map(|function| {
+            let args = function.abi_decode_input(data, false).ok()?;
+            Some(DecodedCall { function: function.clone(), args })
+        })
+    }
+
+    /// Decodes the log with the registered events.
+    ///
+    /// Returns `None` if no registered event matches.
+    pub fn decode_log(&self, log: &Log) -> Option<DecodedLog> {
+        let selector = log.topics().first()?;
+        self.events.get(selector)?.iter().find_map(|event| {
+            let decoded = event.decode_log(log.data(), false).ok()?;
+            Some(DecodedLog { event: event.clone(), decoded })
+        })
+    }
+}
+
+/// An enriched view of a mined transaction, assembled by [`TxInspector`].
+#[derive(Clone, Debug)]
+pub struct TxInspection {
+    /// The transaction.
+    pub transaction: Transaction,
+    /// The receipt of the transaction.
+    pub receipt: TransactionReceipt,
+    /// The decoded input of the transaction, if a registered function matches.
+    pub call: Option<DecodedCall>,
+    /// The logs emitted by the transaction, decoded where a registered event matches.
+    pub logs: Vec<InspectedLog>,
+    /// The call trace of the transaction, if requested and available.
+    #[cfg(feature = "trace")]
+    pub trace: Option<CallFrame>,
+    /// The fees paid by the transaction.
+    pub fees: FeeBreakdown,
+    /// The base fee of the block, if it is post-London.
+    pub base_fee_per_gas: Option<u64>,
+}
+
+impl TxInspection {
+    /// Returns whether the transaction was successful.
+    pub fn is_success(&self) -> bool {
+        self.receipt.status()
+    }
+
+    /// Returns an iterator over the decoded logs.
+    pub fn decoded_logs(&self) -> impl Iterator<Item = &DecodedLog> {
+        self.logs.iter().filter_map(|log| log.decoded.as_ref())
+    }
+
+    /// Returns the part of the execution fee that was burnt, `gas_used * base_fee_per_gas`.
+    pub fn burnt_fee(&self) -> U256 {
+        U256::from(self.receipt.gas_used()) * U256::from(self.base_fee_per_gas.unwrap_or_default())
+    }
+
+    /// Returns the part of the execution fee paid to the block producer.
+    pub fn priority_fee(&self) -> U256 {
+        let execution = self.fees.get(FeeDimension::Execution).map(FeeComponent::cost);
+        execution.unwrap_or_default().saturating_sub(self.burnt_fee())
+    }
+}
+
+/// A decoded function call.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedCall {
+    /// The function that matched the input.
+    pub function: Function,
+    /// The decoded arguments.
+    pub args: Vec<DynSolValue>,
+}
+
+/// A log of an inspected transaction.
+#[derive(Clone, Debug)]
+pub struct InspectedLog {
+    /// The raw log.
+    pub log: Log,
+    /// The decoded log, if a registered event matches.
+    pub decoded: Option<DecodedLog>,
+}
+
+/// A decoded log.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedLog {
+    /// The event that matched the log.
+    pub event: Event,
+    /// The decoded indexed and non-indexed values.
+    pub decoded: DecodedEvent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Signed, TxEnvelope, TxLegacy};
+    use alloy_network::TransactionBuilder;
+    use alloy_primitives::{
+        address, Address, Bytes, LogData, PrimitiveSignature as Signature, B256,
+    };
+    use alloy_provider::{ProviderBuilder, WalletProvider};
+    use alloy_rpc_types_eth::TransactionRequest;
+    use alloy_sol_types::{sol, SolCall, SolEvent};
+
+    sol! {
+        function transfer(address to, uint256 amount);
+        event Transfer(address indexed from, address indexed to, uint256 value);
+    }
+
+    #[test]
+    fn decode_call_and_log() {
+        let provider = ProviderBuilder::new().on_http("http://localhost:8545".parse().unwrap());
+        let inspector = TxInspector::new(provider)
+            .with_function_signature("transfer(address,uint256)")
+            .unwrap()
+            .with_event_signature("Transfer(address indexed,address indexed,uint256)")
+            .unwrap();
+
+        let to = address!("00000000000000000000000000000000deadbeef");
+        let input = transferCall { to, amount: U256::from(7) }.abi_encode();
+        let legacy = TxLegacy { to: to.into(), input: Bytes::from(input), ..Default::default() };
+        let tx = Transaction {
+            inner: TxEnvelope::Legacy(Signed::new_unchecked(
+                legacy,
+                Signature::test_signature(),
+                B256::ZERO,
+            )),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+            from: Address::ZERO,
+        };
+        let call = inspector.decode_call(&tx).unwrap();
+        assert_eq!(call.function.name, "transfer");
+        assert_eq!(call.args, [DynSolValue::Address(to), DynSolValue::Uint(U256::from(7), 256)]);
+
+        let data: LogData =
+            Transfer { from: Address::ZERO, to, value: U256::from(7) }.encode_log_data();
+        let log = Log { inner: alloy_primitives::Log { address: to, data }, ..Default::default() };
+        let decoded = inspector.decode_log(&log).unwrap();
+        assert_eq!(decoded.event.name, "Transfer");
+        assert_eq!(decoded.decoded.body, [DynSolValue::Uint(U256::from(7), 256)]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn inspect_transfer() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let tx = TransactionRequest::default()
+            .with_from(provider.default_signer_address())
+            .with_to(Address::with_last_byte(1))
+            .with_value(U256::from(1));
+        let hash = provider.send_transaction(tx).await.unwrap().watch().await.unwrap();
+
+        let inspector = TxInspector::new(&provider);
+        let inspection = inspector.inspect(hash).await.unwrap().unwrap();
+        assert!(inspection.is_success());
+        assert!(inspection.base_fee_per_gas.is_some());
+        assert!(inspection.fees.get(FeeDimension::Blob).is_none());
+        assert_eq!(
+            inspection.fees.total_cost(),
+            inspection.burnt_fee() + inspection.priority_fee()
+        );
+        assert!(inspector.inspect(B256::ZERO).await.unwrap().is_none());
+
+        #[cfg(feature = "trace")]
+        {
+            assert!(inspection.trace.is_none());
+            let inspection = inspector.with_trace(true).inspect(hash).await.unwrap().unwrap();
+            assert!(inspection.trace.is_some());
+        }
+    }
+}
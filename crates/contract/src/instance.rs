@@ -1,4 +1,4 @@
-use crate::{CallBuilder, Event, Interface, Result};
+use crate::{CallBuilder, DecodedError, Error, ErrorDecoder, Event, Interface, Result};
 use alloy_dyn_abi::DynSolValue;
 use alloy_json_abi::{Function, JsonAbi};
 use alloy_network::{Ethereum, Network};
@@ -17,6 +17,7 @@ pub struct ContractInstance<P, N = Ethereum> {
     address: Address,
     provider: P,
     interface: Interface,
+    errors: ErrorDecoder,
     network: PhantomData<N>,
 }
 
@@ -24,7 +25,7 @@ impl<P, N> ContractInstance<P, N> {
     /// Creates a new contract from the provided address, provider, and interface.
     #[inline]
     pub const fn new(address: Address, provider: P, interface: Interface) -> Self {
-        Self { address, provider, interface, network: PhantomData }
+        Self { address, provider, interface, errors: ErrorDecoder::new(), network: PhantomData }
     }
 
     /// Returns a reference to the contract's address.
@@ -52,6 +53,47 @@ impl<P, N> ContractInstance<P, N> {
         self.interface.abi()
     }
 
+    /// Sets the contract's interface, e.g. after the implementation behind a proxy changed.
+    #[inline]
+    pub fn set_interface(&mut self, interface: Interface) {
+        self.interface = interface;
+    }
+
+    /// Registers the errors declared in another interface for decoding reverts, e.g. errors of
+    /// libraries or of contracts called by this contract.
+    ///
+    /// Call builders created by this instance decode reverts with the errors of the contract's
+    /// own ABI first, followed by the registered interfaces in registration order. See
+    /// [`CallBuilder::decode_revert`].
+    #[inline]
+    pub fn with_error_interface(mut self, name: impl Into<String>, abi: &JsonAbi) -> Self {
+        self.add_error_interface(name, abi);
+        self
+    }
+
+    /// Registers the errors declared in another interface for decoding reverts.
+    ///
+    /// See [`with_error_interface`](Self::with_error_interface).
+    #[inline]
+    pub fn add_error_interface(&mut self, name: impl Into<String>, abi: &JsonAbi) {
+        self.errors.add_interface(name, abi);
+    }
+
+    /// Returns the decoder for the errors of the contract's ABI and of all registered error
+    /// interfaces.
+    pub fn error_decoder(&self) -> ErrorDecoder {
+        let mut decoder = ErrorDecoder::from_abi(self.abi());
+        decoder.extend(&self.errors);
+        decoder
+    }
+
+    /// Decodes the custom Solidity error returned by a reverted call to this contract.
+    ///
+    /// See [`error_decoder`](Self::error_decoder).
+    pub fn decode_revert(&self, error: &Error) -> Option<DecodedError> {
+        self.error_decoder().decode_error(error)
+    }
+
     /// Returns a reference to the contract's provider.
     #[inline]
     pub const fn provider(&self) -> &P {
@@ -67,6 +109,7 @@ impl<P: Clone, N> ContractInstance<&P, N> {
             address: self.address,
             provider: self.provider.clone(),
             interface: self.interface,
+            errors: self.errors,
             network: PhantomData,
         }
     }
@@ -84,7 +127,8 @@ impl<P: Provider<N>, N: Network> ContractInstance<P, N> {
         args: &[DynSolValue],
     ) -> Result<CallBuilder<(), &P, Function, N>> {
         let function = self.interface.get_from_name(name)?;
-        CallBuilder::new_dyn(&self.provider, &self.address, function, args)
+        Ok(CallBuilder::new_dyn(&self.provider, &self.address, function, args)?
+            .error_decoder(self.error_decoder()))
     }
 
     /// Returns a transaction builder for the provided function selector.
@@ -94,7 +138,8 @@ impl<P: Provider<N>, N: Network> ContractInstance<P, N> {
         args: &[DynSolValue],
     ) -> Result<CallBuilder<(), &P, Function, N>> {
         let function = self.interface.get_from_selector(selector)?;
-        CallBuilder::new_dyn(&self.provider, &self.address, function, args)
+        Ok(CallBuilder::new_dyn(&self.provider, &self.address, function, args)?
+            .error_decoder(self.error_decoder()))
     }
 
     /// Returns an [`Event`] builder with the provided filter.
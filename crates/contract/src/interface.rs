@@ -1,4 +1,7 @@
-use crate::{ContractInstance, Error, Result};
+use crate::{
+    depth::{check_params_depth, DEFAULT_MAX_DEPTH},
+    ContractInstance, Error, Result,
+};
 use alloy_dyn_abi::{DynSolValue, FunctionExt, JsonAbiExt};
 use alloy_json_abi::{Function, JsonAbi};
 use alloy_primitives::{
@@ -8,10 +11,14 @@ use alloy_primitives::{
 use std::collections::BTreeMap;
 
 /// A smart contract interface.
+///
+/// Functions whose parameters are nested deeper than [`DEFAULT_MAX_DEPTH`] are rejected when
+/// decoding, see [`Interface::with_max_depth`].
 #[derive(Clone, Debug)]
 pub struct Interface {
     abi: JsonAbi,
     functions: SelectorHashMap<(String, usize)>,
+    max_depth: usize,
 }
 
 // TODO: events/errors
@@ -19,7 +26,21 @@ impl Interface {
     /// Creates a new contract interface from the provided ABI.
     pub fn new(abi: JsonAbi) -> Self {
         let functions = create_mapping(&abi.functions, Function::selector);
-        Self { abi, functions }
+        Self { abi, functions, max_depth: DEFAULT_MAX_DEPTH }
+    }
+
+    /// Sets the maximum nesting depth of the parameters of decoded functions.
+    ///
+    /// Decoding a function with deeper parameters returns [`Error::DepthLimitExceeded`] instead
+    /// of risking a stack overflow.
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Returns the maximum nesting depth of the parameters of decoded functions.
+    pub const fn max_depth(&self) -> usize {
+        self.max_depth
     }
 
     /// Returns the ABI encoded data (including the selector) for the provided function and
@@ -55,7 +76,9 @@ impl Interface {
         data: &[u8],
         validate: bool,
     ) -> Result<Vec<DynSolValue>> {
-        self.get_from_name(name)?.abi_decode_input(data, validate).map_err(Into::into)
+        let function = self.get_from_name(name)?;
+        check_params_depth(&function.inputs, self.max_depth)?;
+        function.abi_decode_input(data, validate).map_err(Into::into)
     }
 
     /// Decode the provided ABI encoded bytes as the input of the provided function selector.
@@ -65,7 +88,9 @@ impl Interface {
         data: &[u8],
         validate: bool,
     ) -> Result<Vec<DynSolValue>> {
-        self.get_from_selector(selector)?.abi_decode_input(data, validate).map_err(Into::into)
+        let function = self.get_from_selector(selector)?;
+        check_params_depth(&function.inputs, self.max_depth)?;
+        function.abi_decode_input(data, validate).map_err(Into::into)
     }
 
     /// Decode the provided ABI encoded bytes as the output of the first function with the given
@@ -81,7 +106,9 @@ impl Interface {
         data: &[u8],
         validate: bool,
     ) -> Result<Vec<DynSolValue>> {
-        self.get_from_name(name)?.abi_decode_output(data, validate).map_err(Into::into)
+        let function = self.get_from_name(name)?;
+        check_params_depth(&function.outputs, self.max_depth)?;
+        function.abi_decode_output(data, validate).map_err(Into::into)
     }
 
     /// Decode the provided ABI encoded bytes as the output of the provided function selector.
@@ -91,7 +118,9 @@ impl Interface {
         data: &[u8],
         validate: bool,
     ) -> Result<Vec<DynSolValue>> {
-        self.get_from_selector(selector)?.abi_decode_output(data, validate).map_err(Into::into)
+        let function = self.get_from_selector(selector)?;
+        check_params_depth(&function.outputs, self.max_depth)?;
+        function.abi_decode_output(data, validate).map_err(Into::into)
     }
 
     /// Returns a reference to the contract's ABI.
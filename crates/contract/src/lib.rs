@@ -6,9 +6,27 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
-#[cfg(test)]
+// Allows the `sol!` bindings of this crate to refer to it by name.
 extern crate self as alloy_contract;
 
+mod artifact;
+pub use artifact::{
+    ArtifactError, ContractArtifact, ContractFactory, LinkReference, LinkableBytecode,
+};
+
+#[cfg(feature = "trace")]
+mod asset_changes;
+#[cfg(feature = "trace")]
+pub use asset_changes::AssetChanges;
+
+pub mod create;
+pub use create::{create2_address, create3_address, create_address};
+
+mod deploy;
+pub use deploy::DeployBuilder;
+
+pub mod depth;
+
 mod eth_call;
 pub use eth_call::{CallDecoder, EthCall};
 
@@ -16,7 +34,7 @@ mod error;
 pub use error::*;
 
 mod event;
-pub use event::{Event, EventPoller};
+pub use event::{Event, EventBackfill, EventPoller};
 
 #[cfg(feature = "pubsub")]
 pub use event::subscription::EventSubscription;
@@ -24,9 +42,30 @@ pub use event::subscription::EventSubscription;
 mod interface;
 pub use interface::*;
 
+mod inspector;
+pub use inspector::{DecodedCall, DecodedLog, InspectedLog, TxInspection, TxInspector};
+
+pub mod proxy;
+pub use proxy::{ProxyUpgrade, ProxyWatcher, UpgradeSource};
+
+mod registry;
+pub use registry::EventDecoderRegistry;
+
+mod replay;
+pub use replay::CallReplay;
+
+mod revert;
+pub use revert::{DecodedError, ErrorDecoder};
+
+mod transfers;
+pub use transfers::{TokenStandard, TokenTransfer, TokenTransfers};
+
 mod instance;
 pub use instance::*;
 
+pub mod multicall;
+pub use multicall::MULTICALL3_ADDRESS;
+
 mod call;
 pub use call::*;
 
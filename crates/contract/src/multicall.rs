@@ -0,0 +1,196 @@
+//! Batching of calls into a single `eth_call` through [Multicall3].
+//!
+//! [Multicall3]: https://github.com/mds1/multicall
+
+use crate::Result;
+use alloy_network::Network;
+use alloy_primitives::{address, Address, Bytes};
+use alloy_provider::Provider;
+use alloy_sol_types::SolCall;
+use std::future::IntoFuture;
+
+alloy_sol_types::sol! {
+    /// The batching method of Multicall3.
+    #[sol(rpc)]
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Call3Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calldata calls)
+            external
+            payable
+            returns (Call3Result[] memory returnData);
+    }
+}
+
+/// The address of [Multicall3](https://github.com/mds1/multicall), deployed at the same address
+/// on most chains.
+pub const MULTICALL3_ADDRESS: Address = address!("cA11bde05977b3631167028862bE2a173976CA11");
+
+/// The maximum number of calls aggregated into a single `eth_call`.
+const MAX_BATCH_SIZE: usize = 256;
+
+/// Executes the calls through Multicall3, returning the decoded return values.
+///
+/// Calls are allowed to fail individually, in which case `None` is returned in their place.
+pub async fn aggregate<P, N, C>(
+    provider: &P,
+    calls: impl IntoIterator<Item = (Address, C)>,
+) -> Result<Vec<Option<C::Return>>>
+where
+    P: Provider<N>,
+    N: Network,
+    C: SolCall,
+{
+    let calls = calls
+        .into_iter()
+        .map(|(target, call)| (target, call.abi_encode().into()))
+        .collect::<Vec<_>>();
+    Ok(aggregate_raw(provider, calls)
+        .await?
+        .into_iter()
+        .map(|data| data.and_then(|data| C::abi_decode_returns(&data, true).ok()))
+        .collect())
+}
+
+/// Executes the ABI-encoded calls through Multicall3, returning the data returned by each call.
+///
+/// Calls are allowed to fail individually, in which case `None` is returned in their place.
+/// Batches of more than 256 calls are split and executed concurrently.
+pub async fn aggregate_raw<P, N>(
+    provider: &P,
+    calls: impl IntoIterator<Item = (Address, Bytes)>,
+) -> Result<Vec<Option<Bytes>>>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    let calls = calls
+        .into_iter()
+        .map(|(target, data)| IMulticall3::Call3 { target, allowFailure: true, callData: data })
+        .collect::<Vec<_>>();
+    if calls.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let multicall = IMulticall3::new(MULTICALL3_ADDRESS, provider);
+    let batches = calls
+        .chunks(MAX_BATCH_SIZE)
+        .map(|batch| multicall.aggregate3(batch.to_vec()))
+        .collect::<Vec<_>>();
+    let results =
+        futures::future::try_join_all(batches.iter().map(|batch| batch.call().into_future()))
+            .await?;
+
+    Ok(results
+        .into_iter()
+        .flat_map(|result| result.returnData)
+        .map(|result| result.success.then_some(result.returnData))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::{RequestPacket, Response, ResponsePacket, ResponsePayload};
+    use alloy_primitives::{B256, U256};
+    use alloy_provider::ProviderBuilder;
+    use alloy_rpc_client::RpcClient;
+    use alloy_sol_types::{sol, SolValue};
+    use alloy_transport::{TransportError, TransportFut};
+    use serde_json::value::RawValue;
+    use std::task::{Context, Poll};
+
+    sol! {
+        function balanceOf(address owner) returns (uint256);
+    }
+
+    /// Returns the number encoded in an address, i.e. its index in the batch.
+    fn index(target: Address) -> U256 {
+        U256::from_be_slice(target.as_slice())
+    }
+
+    /// Calls to targets whose index is a multiple of three revert.
+    fn reverts(target: Address) -> bool {
+        index(target) % U256::from(3) == U256::ZERO
+    }
+
+    /// A transport that executes `aggregate3` calls, returning the index of each target.
+    #[derive(Clone, Debug)]
+    struct MockMulticall;
+
+    impl tower::Service<RequestPacket> for MockMulticall {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: RequestPacket) -> Self::Future {
+            let RequestPacket::Single(request) = request else { panic!("unexpected batch") };
+            assert_eq!(request.method(), "eth_call");
+            let params: serde_json::Value =
+                serde_json::from_str(request.params().unwrap().get()).unwrap();
+            let tx = &params[0];
+            assert_eq!(tx["to"], serde_json::json!(MULTICALL3_ADDRESS));
+            let input: Bytes =
+                serde_json::from_value(tx.get("input").unwrap_or(&tx["data"]).clone()).unwrap();
+
+            let calls = IMulticall3::aggregate3Call::abi_decode(&input, true).unwrap().calls;
+            assert!(calls.len() <= MAX_BATCH_SIZE);
+            let results = calls
+                .into_iter()
+                .map(|call| {
+                    assert!(call.allowFailure);
+                    let success = !reverts(call.target);
+                    let data = if success { index(call.target).abi_encode() } else { Vec::new() };
+                    IMulticall3::Call3Result { success, returnData: data.into() }
+                })
+                .collect::<Vec<_>>();
+            let output = Bytes::from(IMulticall3::aggregate3Call::abi_encode_returns(&(results,)));
+
+            let id = request.id().clone();
+            let result = RawValue::from_string(serde_json::to_string(&output).unwrap()).unwrap();
+            Box::pin(async move {
+                Ok(ResponsePacket::Single(Response {
+                    id,
+                    payload: ResponsePayload::Success(result),
+                }))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn aggregate_partial_failure() {
+        let provider = ProviderBuilder::new().on_client(RpcClient::new(MockMulticall, true));
+
+        // more calls than fit into a single batch, every third of which reverts
+        let targets =
+            (1..=300u64).map(|i| Address::from_word(B256::from(U256::from(i)))).collect::<Vec<_>>();
+        let calls = targets.iter().map(|target| (*target, balanceOfCall { owner: *target }));
+        let results = aggregate(&provider, calls).await.unwrap();
+
+        assert_eq!(results.len(), targets.len());
+        for (target, result) in targets.into_iter().zip(results) {
+            match result {
+                Some(balance) => {
+                    assert!(!reverts(target));
+                    assert_eq!(balance._0, index(target));
+                }
+                None => assert!(reverts(target)),
+            }
+        }
+
+        assert!(aggregate::<_, _, balanceOfCall>(&provider, []).await.unwrap().is_empty());
+    }
+}
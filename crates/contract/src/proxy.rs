@@ -0,0 +1,370 @@
+//! [EIP-1967](https://eips.ethereum.org/EIPS/eip-1967) proxy inspection and upgrade monitoring.
+
+use crate::{ContractInstance, Interface};
+use alloy_json_abi::JsonAbi;
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{b256, map::HashMap, Address, TxHash, B256, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::{BlockId, Filter};
+use alloy_sol_types::SolEvent;
+use alloy_transport::{TransportError, TransportResult};
+use futures::{future::BoxFuture, stream, Stream, StreamExt, TryStreamExt};
+use std::{fmt, future::Future, marker::PhantomData, sync::Arc};
+
+alloy_sol_types::sol! {
+    /// Emitted by [EIP-1967](https://eips.ethereum.org/EIPS/eip-1967) proxies when the
+    /// implementation changes.
+    #[derive(Debug, PartialEq, Eq)]
+    event Upgraded(address indexed implementation);
+}
+
+/// The [EIP-1967](https://eips.ethereum.org/EIPS/eip-1967) storage slot holding the address of
+/// the implementation: `keccak256("eip1967.proxy.implementation") - 1`.
+pub const IMPLEMENTATION_SLOT: B256 =
+    b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// The [EIP-1967](https://eips.ethereum.org/EIPS/eip-1967) storage slot holding the address of
+/// the admin: `keccak256("eip1967.proxy.admin") - 1`.
+pub const ADMIN_SLOT: B256 =
+    b256!("b53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103");
+
+/// The [EIP-1967](https://eips.ethereum.org/EIPS/eip-1967) storage slot holding the address of
+/// the beacon: `keccak256("eip1967.proxy.beacon") - 1`.
+pub const BEACON_SLOT: B256 =
+    b256!("a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50");
+
+type AbiResolver = Arc<dyn Fn(Address) -> BoxFuture<'static, Option<JsonAbi>> + Send + Sync>;
+
+/// How a [`ProxyUpgrade`] was detected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UpgradeSource {
+    /// An [`Upgraded`] event emitted by the proxy.
+    Event,
+    /// A change of the implementation storage slot, without a matching event.
+    StoragePoll,
+}
+
+/// A change of the implementation of a proxy, found by a [`ProxyWatcher`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProxyUpgrade {
+    /// The address of the proxy.
+    pub proxy: Address,
+    /// The previous implementation.
+    pub old_implementation: Address,
+    /// The new implementation.
+    pub new_implementation: Address,
+    /// The number of the block of the upgrade. For storage polling, this is the block at which
+    /// the change was observed.
+    pub block_number: u64,
+    /// The hash of the upgrade transaction, if known.
+    pub transaction_hash: Option<TxHash>,
+    /// How the upgrade was detected.
+    pub source: UpgradeSource,
+    /// The ABI of the new implementation, if an ABI resolver is set and found one.
+    pub abi: Option<JsonAbi>,
+}
+
+impl ProxyUpgrade {
+    /// Replaces the interface of a contract instance of the upgraded proxy with the ABI of the
+    /// new implementation.
+    ///
+    /// Returns `false`, leaving the instance untouched, if the instance is not at the proxy's
+    /// address or no ABI was resolved.
+    pub fn apply<P, N>(&self, instance: &mut ContractInstance<P, N>) -> bool {
+        match &self.abi {
+            Some(abi) if *instance.address() == self.proxy => {
+                instance.set_interface(Interface::new(abi.clone()));
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns the implementation address stored in the EIP-1967 slot of `proxy` at `block`.
+///
+/// Returns the zero address if the slot is unset, e.g. if the contract is not a proxy.
+pub async fn get_implementation<P, N>(
+    provider: &P,
+    proxy: Address,
+    block: BlockId,
+) -> TransportResult<Address>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    let slot = U256::from_be_bytes(IMPLEMENTATION_SLOT.0);
+    let value = provider.get_storage_at(proxy, slot).block_id(block).await?;
+    Ok(Address::from_word(value.into()))
+}
+
+/// Monitors a set of [EIP-1967](https://eips.ethereum.org/EIPS/eip-1967) proxies for changes of
+/// their implementation.
+///
+/// On every new block, the [`Upgraded`] events of the proxies are queried, and the
+/// implementation storage slot of every proxy is read to catch upgrades that did not emit an
+/// event. Either mechanism can be disabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_contract::ProxyWatcher;
+/// use alloy_primitives::address;
+/// use futures::TryStreamExt;
+///
+/// let proxy = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+/// let watcher: ProxyWatcher<_> = ProxyWatcher::new(&provider).proxy(proxy);
+/// let mut upgrades = std::pin::pin!(watcher.watch().await?);
+/// while let Some(upgrade) = upgrades.try_next().await? {
+///     println!("{} upgraded to {}", upgrade.proxy, upgrade.new_implementation);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ProxyWatcher<P, N = Ethereum> {
+    provider: P,
+    proxies: Vec<Address>,
+    events: bool,
+    storage_polling: bool,
+    abi_resolver: Option<AbiResolver>,
+    _network: PhantomData<fn() -> N>,
+}
+
+impl<P, N> fmt::Debug for ProxyWatcher<P, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProxyWatcher")
+            .field("proxies", &self.proxies)
+            .field("events", &self.events)
+            .field("storage_polling", &self.storage_polling)
+            .field("abi_resolver", &self.abi_resolver.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P: Provider<N>, N: Network> ProxyWatcher<P, N> {
+    /// Creates a new watcher without any proxies.
+    pub const fn new(provider: P) -> Self {
+        Self {
+            provider,
+            proxies: Vec::new(),
+            events: true,
+            storage_polling: true,
+            abi_resolver: None,
+            _network: PhantomData,
+        }
+    }
+
+    /// Adds a proxy to watch.
+    pub fn proxy(mut self, proxy: Address) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Adds several proxies to watch.
+    pub fn proxies(mut self, proxies: impl IntoIterator<Item = Address>) -> Self {
+        self.proxies.extend(proxies);
+        self
+    }
+
+    /// Sets whether [`Upgraded`] events are queried. Defaults to `true`.
+    pub const fn events(mut self, events: bool) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Sets whether the implementation storage slots are polled. Defaults to `true`.
+    pub const fn storage_polling(mut self, storage_polling: bool) -> Self {
+        self.storage_polling = storage_polling;
+        self
+    }
+
+    /// Sets a function to resolve the ABI of new implementations, e.g. from a block explorer or a
+    /// local registry.
+    ///
+    /// Resolved ABIs are attached to the emitted upgrades, and can be applied to contract
+    /// instances with [`ProxyUpgrade::apply`].
+    pub fn abi_resolver<F, Fut>(mut self, resolver: F) -> Self
+    where
+        F: Fn(Address) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<JsonAbi>> + Send + 'static,
+    {
+        self.abi_resolver = Some(Arc::new(move |address| Box::pin(resolver(address))));
+        self
+    }
+
+    /// Returns the current implementation of every watched proxy.
+    pub async fn implementations(
+        &self,
+        block: BlockId,
+    ) -> TransportResult<HashMap<Address, Address>> {
+        let mut implementations = HashMap::default();
+        for &proxy in &self.proxies {
+            implementations
+                .insert(proxy, get_implementation::<P, N>(&self.provider, proxy, block).await?);
+        }
+        Ok(implementations)
+    }
+
+    /// Starts watching, returning a stream of the upgrades after the current block.
+    ///
+    /// New blocks are polled with [`Provider::watch_blocks`], at the poll interval of the client.
+    pub async fn watch(
+        &self,
+    ) -> TransportResult<impl Stream<Item = TransportResult<ProxyUpgrade>> + '_> {
+        let start = self.provider.get_block_number().await?;
+        let implementations = self.implementations(start.into()).await?;
+        let blocks = self.provider.watch_blocks().await?.into_stream();
+
+        let state = (blocks, implementations, start);
+        Ok(stream::try_unfold(
+            state,
+            move |(mut blocks, mut implementations, mut last)| async move {
+                loop {
+                    if blocks.next().await.is_none() {
+                        return Ok::<_, TransportError>(None);
+                    }
+                    let latest = self.provider.get_block_number().await?;
+                    if latest <= last {
+                        continue;
+                    }
+                    let upgrades = self.check(&mut implementations, last + 1, latest).await?;
+                    last = latest;
+                    if !upgrades.is_empty() {
+                        return Ok(Some((upgrades, (blocks, implementations, last))));
+                    }
+                }
+            },
+        )
+        .map_ok(|upgrades| stream::iter(upgrades.into_iter().map(Ok)))
+        .try_flatten())
+    }
+
+    /// Returns the upgrades in the given block range, updating the known implementations.
+    async fn check(
+        &self,
+        implementations: &mut HashMap<Address, Address>,
+        from: u64,
+        to: u64,
+    ) -> TransportResult<Vec<ProxyUpgrade>> {
+        let mut upgrades = Vec::new();
+        let mut record = |proxy, new_implementation, block_number, transaction_hash, source| {
+            let old_implementation = implementations.insert(proxy, new_implementation);
+            if let Some(old_implementation) =
+                old_implementation.filter(|&old| old != new_implementation)
+            {
+                upgrades.push(ProxyUpgrade {
+                    proxy,
+                    old_implementation,
+                    new_implementation,
+                    block_number,
+                    transaction_hash,
+                    source,
+                    abi: None,
+                });
+            }
+        };
+
+        if self.events && !self.proxies.is_empty() {
+            let filter = Filter::new()
+                .from_block(from)
+                .to_block(to)
+                .address(self.proxies.clone())
+                .event_signature(Upgraded::SIGNATURE_HASH);
+            for log in self.provider.get_logs(&filter).await? {
+                let Ok(event) = log.log_decode::<Upgraded>() else { continue };
+                record(
+                    log.address(),
+                    event.inner.data.implementation,
+                    log.block_number.unwrap_or(to),
+                    log.transaction_hash,
+                    UpgradeSource::Event,
+                );
+            }
+        }
+
+        if self.storage_polling {
+            for &proxy in &self.proxies {
+                let implementation =
+                    get_implementation::<P, N>(&self.provider, proxy, to.into()).await?;
+                record(proxy, implementation, to, None, UpgradeSource::StoragePoll);
+            }
+        }
+
+        if let Some(resolver) = &self.abi_resolver {
+            for upgrade in &mut upgrades {
+                upgrade.abi = resolver(upgrade.new_implementation).await;
+            }
+        }
+        Ok(upgrades)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::TransactionBuilder;
+    use alloy_primitives::keccak256;
+    use alloy_provider::{ext::AnvilApi, ProviderBuilder};
+    use alloy_rpc_types_eth::TransactionRequest;
+
+    #[test]
+    fn slots() {
+        for (slot, name) in [
+            (IMPLEMENTATION_SLOT, "eip1967.proxy.implementation"),
+            (ADMIN_SLOT, "eip1967.proxy.admin"),
+            (BEACON_SLOT, "eip1967.proxy.beacon"),
+        ] {
+            let expected = U256::from_be_bytes(keccak256(name).0) - U256::from(1);
+            assert_eq!(slot, B256::from(expected), "{name}");
+        }
+    }
+
+    /// Code that stores `implementation` in the implementation slot and emits `Upgraded`.
+    fn upgrade_code(implementation: Address) -> Vec<u8> {
+        let word = implementation.into_word();
+        let mut code = vec![0x7f];
+        code.extend_from_slice(word.as_slice());
+        code.push(0x7f);
+        code.extend_from_slice(IMPLEMENTATION_SLOT.as_slice());
+        code.push(0x55); // SSTORE
+        code.push(0x7f);
+        code.extend_from_slice(word.as_slice());
+        code.push(0x7f);
+        code.extend_from_slice(Upgraded::SIGNATURE_HASH.as_slice());
+        code.extend_from_slice(&[0x60, 0x00, 0x60, 0x00, 0xa2, 0x00]); // LOG2, STOP
+        code
+    }
+
+    #[tokio::test]
+    async fn watch_upgrades() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let proxy = Address::repeat_byte(0x11);
+        let [a, b, c] = [0xaa, 0xbb, 0xcc].map(Address::repeat_byte);
+        let slot = U256::from_be_bytes(IMPLEMENTATION_SLOT.0);
+
+        provider.anvil_set_code(proxy, upgrade_code(b).into()).await.unwrap();
+        provider.anvil_set_storage_at(proxy, slot, a.into_word()).await.unwrap();
+
+        let watcher: ProxyWatcher<_> = ProxyWatcher::new(&provider).proxy(proxy).abi_resolver(
+            move |implementation| async move { (implementation == b).then(JsonAbi::default) },
+        );
+        let mut upgrades = std::pin::pin!(watcher.watch().await.unwrap());
+
+        let tx = TransactionRequest::default().with_to(proxy);
+        let receipt = provider.send_transaction(tx).await.unwrap().get_receipt().await.unwrap();
+        let upgrade = upgrades.try_next().await.unwrap().unwrap();
+        assert_eq!(upgrade.source, UpgradeSource::Event);
+        assert_eq!((upgrade.old_implementation, upgrade.new_implementation), (a, b));
+        assert_eq!(upgrade.transaction_hash, Some(receipt.transaction_hash));
+        assert_eq!(upgrade.abi, Some(JsonAbi::default()));
+
+        provider.anvil_set_storage_at(proxy, slot, c.into_word()).await.unwrap();
+        provider.anvil_mine(Some(1), None).await.unwrap();
+        let upgrade = upgrades.try_next().await.unwrap().unwrap();
+        assert_eq!(upgrade.source, UpgradeSource::StoragePoll);
+        assert_eq!((upgrade.old_implementation, upgrade.new_implementation), (b, c));
+        assert_eq!(upgrade.abi, None);
+    }
+}
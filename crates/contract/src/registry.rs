@@ -0,0 +1,284 @@
+use alloy_primitives::{map::B256HashMap, LogData, B256};
+use alloy_rpc_types_eth::Log;
+use alloy_sol_types::{SolEvent, SolEventInterface};
+use std::fmt;
+
+type DecodeFn<T> = Box<dyn Fn(&LogData, bool) -> alloy_sol_types::Result<T> + Send + Sync>;
+
+/// A registry of log decoders for many event types, keyed by their `topic0` selector.
+///
+/// Decoding a log requires a single hash map lookup, instead of trying every event type in turn.
+/// All selectors are computed once, when registering the event types.
+///
+/// Every registered event is mapped into a common output type `T`, usually an enum over all
+/// events of interest.
+///
+/// Anonymous events do not have a selector and cannot be registered.
+///
+/// # Examples
+///
+/// ```no_run
+/// use alloy_contract::EventDecoderRegistry;
+/// use alloy_sol_types::sol;
+///
+/// sol! {
+///     event Transfer(address indexed from, address indexed to, uint256 value);
+///     event Approval(address indexed owner, address indexed spender, uint256 value);
+/// }
+///
+/// enum TokenEvent {
+///     Transfer(Transfer),
+///     Approval(Approval),
+/// }
+///
+/// let registry = EventDecoderRegistry::new()
+///     .with_event::<Transfer>(TokenEvent::Transfer)
+///     .with_event::<Approval>(TokenEvent::Approval);
+/// # let logs: Vec<alloy_rpc_types_eth::Log> = vec![];
+/// for event in registry.decode_logs(&logs) {
+///     match event {
+///         Ok(TokenEvent::Transfer(transfer)) => println!("{} -> {}", transfer.from, transfer.to),
+///         Ok(TokenEvent::Approval(approval)) => println!("{}", approval.owner),
+///         Err(err) => eprintln!("invalid log: {err}"),
+///     }
+/// }
+/// ```
+pub struct EventDecoderRegistry<T> {
+    decoders: B256HashMap<Vec<DecodeFn<T>>>,
+    validate: bool,
+}
+
+impl<T> Default for EventDecoderRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for EventDecoderRegistry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventDecoderRegistry")
+            .field("selectors", &self.decoders.keys().collect::<Vec<_>>())
+            .field("validate", &self.validate)
+            .finish()
+    }
+}
+
+impl<T> EventDecoderRegistry<T> {
+    /// Creates a new, empty registry.
+    pub fn new() -> Self {
+        Self { decoders: Default::default(), validate: false }
+    }
+
+    /// Sets whether decoded values are validated, see [`SolEvent::decode_raw_log`].
+    ///
+    /// Defaults to `false`.
+    pub const fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Registers an event type, mapping decoded events with `map`.
+    ///
+    /// Events sharing a selector with an already registered event, e.g. ERC-20 and ERC-721
+    /// `Transfer` events which only differ in indexing, are tried in registration order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event is anonymous.
+    pub fn register_event<E>(&mut self, map: impl Fn(E) -> T + Send + Sync + 'static) -> &mut Self
+    where
+        E: SolEvent,
+    {
+        assert!(!E::ANONYMOUS, "anonymous event `{}` has no selector", E::SIGNATURE);
+        self.insert(
+            E::SIGNATURE_HASH,
+            Box::new(move |log, validate| {
+                E::decode_raw_log(log.topics().iter().copied(), &log.data, validate).map(&map)
+            }),
+        );
+        self
+    }
+
+    /// Registers an event type, mapping decoded events with `map`.
+    ///
+    /// See [`register_event`](Self::register_event).
+    pub fn with_event<E>(mut self, map: impl Fn(E) -> T + Send + Sync + 'static) -> Self
+    where
+        E: SolEvent,
+    {
+        self.register_event(map);
+        self
+    }
+
+    /// Registers the events with the given selectors of a `sol!` generated events enum, e.g.
+    /// `IERC20Events`, mapping decoded events with `map`.
+    ///
+    /// The selectors are the [`SIGNATURE_HASH`](SolEvent::SIGNATURE_HASH)es of the events, e.g.
+    /// `[Transfer::SIGNATURE_HASH, Approval::SIGNATURE_HASH]`.
+    pub fn register_interface<I>(
+        &mut self,
+        selectors: impl IntoIterator<Item = B256>,
+        map: impl Fn(I) -> T + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        I: SolEventInterface + 'static,
+    {
+        let map = std::sync::Arc::new(map);
+        for selector in selectors {
+            let map = map.clone();
+            self.insert(
+                selector,
+                Box::new(move |log, validate| {
+                    I::decode_raw_log(log.topics(), &log.data, validate).map(&*map)
+                }),
+            );
+        }
+        self
+    }
+
+    /// Registers the events with the given selectors of a `sol!` generated events enum, mapping
+    /// decoded events with `map`.
+    ///
+    /// See [`register_interface`](Self::register_interface).
+    pub fn with_interface<I>(
+        mut self,
+        selectors: impl IntoIterator<Item = B256>,
+        map: impl Fn(I) -> T + Send + Sync + 'static,
+    ) -> Self
+    where
+        I: SolEventInterface + 'static,
+    {
+        self.register_interface(selectors, map);
+        self
+    }
+
+    fn insert(&mut self, selector: B256, decoder: DecodeFn<T>) {
+        self.decoders.entry(selector).or_default().push(decoder);
+    }
+
+    /// Returns whether a decoder is registered for the given selector.
+    pub fn contains(&self, selector: &B256) -> bool {
+        self.decoders.contains_key(selector)
+    }
+
+    /// Returns the number of registered selectors.
+    pub fn len(&self) -> usize {
+        self.decoders.len()
+    }
+
+    /// Returns whether no decoders are registered.
+    pub fn is_empty(&self) -> bool {
+        self.decoders.is_empty()
+    }
+
+    /// Decodes the given log data.
+    ///
+    /// Returns `None` if no decoder is registered for the log's `topic0`. If several decoders are
+    /// registered for the selector, the first successful result is returned, or the last error.
+    pub fn decode_log_data(&self, log: &LogData) -> Option<alloy_sol_types::Result<T>> {
+        let decoders = self.decoders.get(log.topics().first()?)?;
+        let mut result = None;
+        for decoder in decoders {
+            match decoder(log, self.validate) {
+                Ok(decoded) => return Some(Ok(decoded)),
+                Err(err) => result = Some(Err(err)),
+            }
+        }
+        result
+    }
+
+    /// Decodes the given RPC log.
+    ///
+    /// See [`decode_log_data`](Self::decode_log_data).
+    pub fn decode_log(&self, log: &Log) -> Option<alloy_sol_types::Result<T>> {
+        self.decode_log_data(log.data())
+    }
+
+    /// Decodes the given logs, skipping logs without a registered decoder.
+    pub fn decode_logs<'a, I>(
+        &'a self,
+        logs: I,
+    ) -> impl Iterator<Item = alloy_sol_types::Result<T>> + 'a
+    where
+        I: IntoIterator<Item = &'a Log>,
+        I::IntoIter: 'a,
+    {
+        logs.into_iter().filter_map(|log| self.decode_log(log))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, U256};
+    use alloy_sol_types::sol;
+
+    sol! {
+        #[derive(Debug, PartialEq)]
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        #[derive(Debug, PartialEq)]
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+        #[derive(Debug, PartialEq)]
+        event Paused(address account);
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Decoded {
+        Transfer(Transfer),
+        Approval(Approval),
+    }
+
+    fn log(event: &impl SolEvent) -> Log {
+        Log {
+            inner: alloy_primitives::Log { address: Address::ZERO, data: event.encode_log_data() },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn decode_registered_events() {
+        let registry = EventDecoderRegistry::new()
+            .with_event::<Transfer>(Decoded::Transfer)
+            .with_event::<Approval>(Decoded::Approval);
+        assert_eq!(registry.len(), 2);
+        assert!(registry.contains(&Transfer::SIGNATURE_HASH));
+
+        let transfer = Transfer {
+            from: Address::repeat_byte(1),
+            to: Address::repeat_byte(2),
+            value: U256::from(3),
+        };
+        let approval = Approval {
+            owner: Address::repeat_byte(4),
+            spender: Address::repeat_byte(5),
+            value: U256::from(6),
+        };
+        let logs = [log(&transfer), log(&Paused { account: Address::ZERO }), log(&approval)];
+
+        let decoded = registry.decode_logs(&logs).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded, [Decoded::Transfer(transfer), Decoded::Approval(approval)]);
+        assert!(registry.decode_log(&logs[1]).is_none());
+    }
+
+    #[test]
+    fn shared_selector_fallback() {
+        mod erc721 {
+            alloy_sol_types::sol! {
+                #[derive(Debug, PartialEq)]
+                event Transfer(address indexed from, address indexed to, uint256 indexed id);
+            }
+        }
+
+        let registry = EventDecoderRegistry::new()
+            .with_event::<Transfer>(|t| t.value)
+            .with_event::<erc721::Transfer>(|t| t.id);
+        assert_eq!(registry.len(), 1);
+
+        let nft = erc721::Transfer {
+            from: Address::repeat_byte(1),
+            to: Address::repeat_byte(2),
+            id: U256::from(42),
+        };
+        assert_eq!(registry.decode_log(&log(&nft)).unwrap().unwrap(), U256::from(42));
+    }
+}
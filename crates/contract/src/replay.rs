@@ -0,0 +1,202 @@
+use crate::{CallBuilder, CallDecoder, Error, Result};
+use alloy_network::Network;
+use alloy_primitives::Bytes;
+use alloy_provider::Provider;
+use alloy_rpc_client::BatchRequest;
+use alloy_rpc_types_eth::BlockId;
+use alloy_transport::{TransportError, TransportResult};
+use futures::{future::join_all, stream, Stream, StreamExt};
+
+/// Substrings of the error messages returned by nodes that do not have the state of a block,
+/// usually because they are not archive nodes and the state was pruned.
+const STATE_UNAVAILABLE_MESSAGES: &[&str] = &[
+    "missing trie node",
+    "header not found",
+    "historical state",
+    "state histories",
+    "pruned",
+    "state is not available",
+    "distance to target block exceeds maximum",
+];
+
+/// Replays a contract call at every `step`th block of a range, see
+/// [`CallBuilder::call_over_range`].
+///
+/// The calls are sent in JSON-RPC batches of [`batch_size`](Self::batch_size) calls, with up to
+/// [`concurrency`](Self::concurrency) batches in flight. If a batch fails, e.g. because the node
+/// does not support batches, its calls are retried individually.
+///
+/// Calls at blocks whose state the node does not have fail with [`Error::StateUnavailable`],
+/// which usually means that an archive node is required.
+#[must_use = "call replays do nothing unless turned into a stream"]
+pub struct CallReplay<'a, T, P, D, N: Network> {
+    call: &'a CallBuilder<T, P, D, N>,
+    from_block: u64,
+    to_block: u64,
+    step: u64,
+    batch_size: usize,
+    concurrency: usize,
+}
+
+impl<'a, T, P, D, N> CallReplay<'a, T, P, D, N>
+where
+    P: Provider<N>,
+    D: CallDecoder,
+    N: Network,
+{
+    /// The default number of calls sent in a batch.
+    pub const DEFAULT_BATCH_SIZE: usize = 50;
+
+    /// The default number of batches in flight.
+    pub const DEFAULT_CONCURRENCY: usize = 4;
+
+    pub(crate) const fn new(
+        call: &'a CallBuilder<T, P, D, N>,
+        from_block: u64,
+        to_block: u64,
+        step: u64,
+    ) -> Self {
+        Self {
+            call,
+            from_block,
+            to_block,
+            step,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            concurrency: Self::DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sets the number of calls sent in a batch. A batch size of `1` sends each call in its own
+    /// request.
+    pub const fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Sets the number of batches in flight.
+    pub const fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Returns the blocks the call is replayed at, in ascending order.
+    pub fn blocks(&self) -> impl Iterator<Item = u64> {
+        let step = usize::try_from(self.step).unwrap_or(usize::MAX).max(1);
+        (self.from_block..=self.to_block).step_by(step)
+    }
+
+    /// Returns a stream of the block numbers and the results of the call at each block, in
+    /// ascending order of the blocks.
+    pub fn into_stream(self) -> impl Stream<Item = (u64, Result<D::CallOutput>)> + 'a {
+        let call = self.call;
+        let blocks: Vec<_> = self.blocks().collect();
+        let batches: Vec<_> = blocks.chunks(self.batch_size.max(1)).map(<[u64]>::to_vec).collect();
+        stream::iter(batches)
+            .map(move |blocks| call_batch(call, blocks))
+            .buffered(self.concurrency.max(1))
+            .flat_map(stream::iter)
+    }
+}
+
+impl<T, P, D: CallDecoder, N: Network> std::fmt::Debug for CallReplay<'_, T, P, D, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallReplay")
+            .field("call", self.call)
+            .field("from_block", &self.from_block)
+            .field("to_block", &self.to_block)
+            .field("step", &self.step)
+            .field("batch_size", &self.batch_size)
+            .field("concurrency", &self.concurrency)
+            .finish()
+    }
+}
+
+/// Calls at each of the blocks, in a single batch if there are multiple blocks.
+async fn call_batch<T, P, D, N>(
+    call: &CallBuilder<T, P, D, N>,
+    blocks: Vec<u64>,
+) -> Vec<(u64, Result<D::CallOutput>)>
+where
+    P: Provider<N>,
+    D: CallDecoder,
+    N: Network,
+{
+    let decode = |block, res: Result<Bytes>| {
+        let res = res
+            .map_err(|err| state_unavailable(block, err))
+            .and_then(|data| call.decode_output(data, true));
+        (block, res)
+    };
+
+    if blocks.len() > 1 {
+        let mut batch = BatchRequest::new(call.provider.client());
+        let waiters = blocks
+            .iter()
+            .map(|&block| {
+                batch.add_call::<_, Bytes>("eth_call", &call.call_params(BlockId::number(block)))
+            })
+            .collect::<TransportResult<Vec<_>>>();
+        if let Ok(waiters) = waiters {
+            if batch.send().await.is_ok() {
+                let results = join_all(waiters).await;
+                return blocks
+                    .into_iter()
+                    .zip(results)
+                    .map(|(block, res)| decode(block, res.map_err(Into::into)))
+                    .collect();
+            }
+        }
+    }
+
+    join_all(blocks.into_iter().map(|block| async move {
+        decode(block, call.call_raw().block(BlockId::number(block)).await)
+    }))
+    .await
+}
+
+/// Converts errors caused by missing historical state into [`Error::StateUnavailable`].
+fn state_unavailable(block: u64, err: Error) -> Error {
+    match err {
+        Error::TransportError(source) if is_state_unavailable(&source) => {
+            Error::StateUnavailable { block, source }
+        }
+        err => err,
+    }
+}
+
+/// Returns `true` if the error was returned by a node that does not have the state of the block.
+fn is_state_unavailable(err: &TransportError) -> bool {
+    err.as_error_resp().is_some_and(|payload| {
+        let message = payload.message.to_lowercase();
+        STATE_UNAVAILABLE_MESSAGES.iter().any(|pattern| message.contains(pattern))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+
+    #[test]
+    fn detects_state_unavailable() {
+        let err = |message: &'static str| {
+            Error::TransportError(TransportError::ErrorResp(ErrorPayload {
+                code: -32000,
+                message: message.into(),
+                data: None,
+            }))
+        };
+        assert!(matches!(
+            state_unavailable(1, err("missing trie node 1a2b (path )")),
+            Error::StateUnavailable { block: 1, .. }
+        ));
+        assert!(matches!(
+            state_unavailable(2, err("History has been pruned for this block")),
+            Error::StateUnavailable { block: 2, .. }
+        ));
+        assert!(matches!(
+            state_unavailable(3, err("execution reverted")),
+            Error::TransportError(_)
+        ));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    depth::{check_params_depth, DEFAULT_MAX_DEPTH},
+    Error,
+};
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_json_abi::{Error as AbiError, JsonAbi};
+use alloy_primitives::Selector;
+
+/// A custom Solidity error decoded from revert data by an [`ErrorDecoder`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedError {
+    /// The name of the interface declaring the error, `None` if it is declared in the ABI of the
+    /// called contract itself.
+    pub interface: Option<String>,
+    /// The ABI of the error.
+    pub error: AbiError,
+    /// The decoded arguments of the error.
+    pub args: Vec<DynSolValue>,
+}
+
+impl DecodedError {
+    /// Returns the name of the error.
+    pub fn name(&self) -> &str {
+        &self.error.name
+    }
+
+    /// Returns the selector of the error.
+    pub fn selector(&self) -> Selector {
+        self.error.selector()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ErrorSource {
+    name: Option<String>,
+    errors: Vec<(Selector, AbiError)>,
+}
+
+/// Decodes custom Solidity errors from revert data using the errors of multiple ABIs.
+///
+/// Reverts are often raised by code that is not declared in the ABI of the called contract, e.g.
+/// by libraries or by nested calls into other contracts. Registering the interfaces declaring
+/// these errors allows decoding them anyway.
+///
+/// Interfaces are tried in registration order, and the first error whose selector matches and
+/// whose arguments decode successfully is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn test<P: alloy_provider::Provider>(provider: P, abi: alloy_json_abi::JsonAbi, erc20: alloy_json_abi::JsonAbi) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_contract::Interface;
+/// use alloy_primitives::Address;
+///
+/// let contract = Interface::new(abi)
+///     .connect(Address::ZERO, &provider)
+///     .with_error_interface("IERC20Errors", &erc20);
+///
+/// let call = contract.function("swap", &[])?;
+/// if let Err(err) = call.call().await {
+///     if let Some(decoded) = call.decode_revert(&err) {
+///         println!("{:?}::{}({:?})", decoded.interface, decoded.name(), decoded.args);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ErrorDecoder {
+    sources: Vec<ErrorSource>,
+}
+
+impl ErrorDecoder {
+    /// Creates a new decoder without any errors.
+    pub const fn new() -> Self {
+        Self { sources: Vec::new() }
+    }
+
+    /// Creates a new decoder with the errors declared in the ABI of the called contract.
+    pub fn from_abi(abi: &JsonAbi) -> Self {
+        let mut decoder = Self::new();
+        decoder.push(None, abi.errors().cloned());
+        decoder
+    }
+
+    /// Registers the errors declared in the ABI of another interface.
+    pub fn with_interface(mut self, name: impl Into<String>, abi: &JsonAbi) -> Self {
+        self.add_interface(name, abi);
+        self
+    }
+
+    /// Registers the errors declared in the ABI of another interface.
+    pub fn add_interface(&mut self, name: impl Into<String>, abi: &JsonAbi) {
+        self.add_errors(name, abi.errors().cloned());
+    }
+
+    /// Registers the given errors under the name of the interface declaring them.
+    pub fn add_errors(
+        &mut self,
+        name: impl Into<String>,
+        errors: impl IntoIterator<Item = AbiError>,
+    ) {
+        self.push(Some(name.into()), errors);
+    }
+
+    /// Registers all interfaces of `other` after the interfaces of this decoder.
+    pub fn extend(&mut self, other: &Self) {
+        self.sources.extend(other.sources.iter().cloned());
+    }
+
+    /// Returns `true` if no errors are registered.
+    pub fn is_empty(&self) -> bool {
+        self.sources.iter().all(|source| source.errors.is_empty())
+    }
+
+    /// Decodes the given revert data, returning the first matching error.
+    ///
+    /// The parameters are validated, so that garbage data does not match an error with the same
+    /// selector. Errors whose parameters are nested deeper than [`DEFAULT_MAX_DEPTH`] are skipped.
+    pub fn decode(&self, data: &[u8]) -> Option<DecodedError> {
+        let (selector, params) = data.split_first_chunk::<4>()?;
+        let selector = Selector::from(*selector);
+        self.sources.iter().find_map(|source| {
+            source.errors.iter().filter(|(s, _)| *s == selector).find_map(|(_, error)| {
+                check_params_depth(&error.inputs, DEFAULT_MAX_DEPTH).ok()?;
+                let args = error.abi_decode_input(params, true).ok()?;
+                Some(DecodedError { interface: source.name.clone(), error: error.clone(), args })
+            })
+        })
+    }
+
+    /// Decodes the revert data of the given error, see [`Error::as_revert_data`].
+    pub fn decode_error(&self, error: &Error) -> Option<DecodedError> {
+        self.decode(&error.as_revert_data()?)
+    }
+
+    fn push(&mut self, name: Option<String>, errors: impl IntoIterator<Item = AbiError>) {
+        let errors = errors.into_iter().map(|error| (error.selector(), error)).collect();
+        self.sources.push(ErrorSource { name, errors });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{hex, Address, U256};
+
+    fn abi(errors: &[&str]) -> JsonAbi {
+        let mut abi = JsonAbi::default();
+        for error in errors {
+            let error = AbiError::parse(error).unwrap();
+            abi.errors.entry(error.name.clone()).or_default().push(error);
+        }
+        abi
+    }
+
+    fn revert(error: &str, args: &[DynSolValue]) -> Vec<u8> {
+        // the encoded input is prefixed with the selector
+        AbiError::parse(error).unwrap().abi_encode_input(args).unwrap()
+    }
+
+    #[test]
+    fn decodes_from_registered_interfaces() {
+        let decoder = ErrorDecoder::from_abi(&abi(&["error Unauthorized()"]))
+            .with_interface("Math", &abi(&["error Overflow(uint256 value)"]))
+            .with_interface(
+                "IERC20Errors",
+                &abi(&["error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed)"]),
+            );
+        assert!(!decoder.is_empty());
+
+        let own = decoder.decode(&revert("error Unauthorized()", &[])).unwrap();
+        assert_eq!(own.interface, None);
+        assert_eq!(own.name(), "Unauthorized");
+
+        let args = [Address::ZERO.into(), U256::from(1).into(), U256::from(2).into()];
+        let data = revert(
+            "error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed)",
+            &args,
+        );
+        let decoded = decoder.decode(&data).unwrap();
+        assert_eq!(decoded.interface.as_deref(), Some("IERC20Errors"));
+        assert_eq!(decoded.name(), "ERC20InsufficientBalance");
+        assert_eq!(decoded.args, args);
+
+        assert_eq!(decoder.decode(&revert("error Other()", &[])), None);
+        assert_eq!(decoder.decode(&hex!("1234")), None);
+        assert!(ErrorDecoder::new().is_empty());
+    }
+
+    #[test]
+    fn returns_first_match() {
+        let error = "error Overflow(uint256 value)";
+        let decoder = ErrorDecoder::new()
+            .with_interface("A", &abi(&[error]))
+            .with_interface("B", &abi(&[error]));
+        let data = revert(error, &[U256::from(1).into()]);
+        assert_eq!(decoder.decode(&data).unwrap().interface.as_deref(), Some("A"));
+
+        // a matching selector with truncated arguments is not decoded
+        assert_eq!(decoder.decode(&data[..20]), None);
+    }
+}
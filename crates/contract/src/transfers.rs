@@ -0,0 +1,342 @@
+use alloy_network::Network;
+use alloy_primitives::{Address, TxHash, B256, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::{Filter, Log};
+use alloy_sol_types::SolEvent;
+use alloy_transport::TransportResult;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use std::{marker::PhantomData, ops::RangeInclusive};
+
+mod erc20 {
+    alloy_sol_types::sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+    }
+}
+
+mod erc721 {
+    alloy_sol_types::sol! {
+        event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+    }
+}
+
+mod erc1155 {
+    alloy_sol_types::sol! {
+        event TransferSingle(
+            address indexed operator,
+            address indexed from,
+            address indexed to,
+            uint256 id,
+            uint256 value
+        );
+        event TransferBatch(
+            address indexed operator,
+            address indexed from,
+            address indexed to,
+            uint256[] ids,
+            uint256[] values
+        );
+    }
+}
+
+/// The token standard of a [`TokenTransfer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenStandard {
+    /// [ERC-20](https://eips.ethereum.org/EIPS/eip-20) fungible tokens.
+    Erc20,
+    /// [ERC-721](https://eips.ethereum.org/EIPS/eip-721) non-fungible tokens.
+    Erc721,
+    /// [ERC-1155](https://eips.ethereum.org/EIPS/eip-1155) multi tokens.
+    Erc1155,
+}
+
+/// A token transfer, normalized across token standards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TokenTransfer {
+    /// The token standard.
+    pub standard: TokenStandard,
+    /// The address of the token contract.
+    pub contract: Address,
+    /// The sender, or the zero address for mints.
+    pub from: Address,
+    /// The recipient, or the zero address for burns.
+    pub to: Address,
+    /// The transferred token, `None` for ERC-20 transfers.
+    pub token_id: Option<U256>,
+    /// The transferred amount; always one for ERC-721 transfers.
+    pub amount: U256,
+    /// The address that initiated an ERC-1155 transfer on behalf of the sender.
+    pub operator: Option<Address>,
+    /// The number of the block of the log, if mined.
+    pub block_number: Option<u64>,
+    /// The hash of the transaction of the log, if mined.
+    pub transaction_hash: Option<TxHash>,
+    /// The index of the log in the block, if mined.
+    pub log_index: Option<u64>,
+}
+
+impl TokenTransfer {
+    /// The `topic0` of all supported transfer events.
+    pub const SIGNATURES: [B256; 3] = [
+        erc20::Transfer::SIGNATURE_HASH,
+        erc1155::TransferSingle::SIGNATURE_HASH,
+        erc1155::TransferBatch::SIGNATURE_HASH,
+    ];
+
+    /// Decodes the token transfers of a log.
+    ///
+    /// ERC-20 and ERC-721 `Transfer` events share a selector and are told apart by their number
+    /// of indexed topics. An ERC-1155 `TransferBatch` event yields one transfer per token.
+    ///
+    /// Returns an empty list if the log is not a well-formed transfer event.
+    pub fn from_log(log: &Log) -> Vec<Self> {
+        let topics = log.topics();
+        let data = &log.data().data;
+        let transfer = |standard, from, to, token_id, amount, operator| Self {
+            standard,
+            contract: log.address(),
+            from,
+            to,
+            token_id,
+            amount,
+            operator,
+            block_number: log.block_number,
+            transaction_hash: log.transaction_hash,
+            log_index: log.log_index,
+        };
+
+        let Some(&selector) = topics.first() else { return Vec::new() };
+        let topics = topics.iter().copied();
+        if selector == erc20::Transfer::SIGNATURE_HASH {
+            if let Ok(ev) = erc20::Transfer::decode_raw_log(topics.clone(), data, false) {
+                return vec![transfer(TokenStandard::Erc20, ev.from, ev.to, None, ev.value, None)];
+            }
+            if let Ok(ev) = erc721::Transfer::decode_raw_log(topics, data, false) {
+                let id = Some(ev.tokenId);
+                return vec![transfer(
+                    TokenStandard::Erc721,
+                    ev.from,
+                    ev.to,
+                    id,
+                    U256::from(1),
+                    None,
+                )];
+            }
+        } else if selector == erc1155::TransferSingle::SIGNATURE_HASH {
+            if let Ok(ev) = erc1155::TransferSingle::decode_raw_log(topics, data, false) {
+                let operator = Some(ev.operator);
+                return vec![transfer(
+                    TokenStandard::Erc1155,
+                    ev.from,
+                    ev.to,
+                    Some(ev.id),
+                    ev.value,
+                    operator,
+                )];
+            }
+        } else if selector == erc1155::TransferBatch::SIGNATURE_HASH {
+            if let Ok(ev) = erc1155::TransferBatch::decode_raw_log(topics, data, false) {
+                if ev.ids.len() == ev.values.len() {
+                    let operator = Some(ev.operator);
+                    return ev
+                        .ids
+                        .into_iter()
+                        .zip(ev.values)
+                        .map(|(id, value)| {
+                            transfer(
+                                TokenStandard::Erc1155,
+                                ev.from,
+                                ev.to,
+                                Some(id),
+                                value,
+                                operator,
+                            )
+                        })
+                        .collect();
+                }
+            }
+        }
+        Vec::new()
+    }
+
+    /// Returns whether the transfer mints tokens, i.e. is sent from the zero address.
+    pub fn is_mint(&self) -> bool {
+        self.from.is_zero()
+    }
+
+    /// Returns whether the transfer burns tokens, i.e. is sent to the zero address.
+    pub fn is_burn(&self) -> bool {
+        self.to.is_zero()
+    }
+
+    /// Returns whether the given address sends or receives tokens in the transfer.
+    pub fn involves(&self, address: Address) -> bool {
+        self.from == address || self.to == address
+    }
+}
+
+/// Streams the [`TokenTransfer`]s over a block range.
+///
+/// Logs are fetched with `eth_getLogs` in chunks of [`block_span`](Self::block_span) blocks.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_contract::TokenTransfers;
+/// use alloy_primitives::address;
+/// use futures::TryStreamExt;
+///
+/// let usdc = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+/// let transfers: TokenTransfers<_> = TokenTransfers::new(&provider).contract(usdc);
+/// let mut stream = std::pin::pin!(transfers.stream(21_000_000..=21_000_100));
+/// while let Some(transfer) = stream.try_next().await? {
+///     println!("{} -> {}: {}", transfer.from, transfer.to, transfer.amount);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TokenTransfers<P, N = alloy_network::Ethereum> {
+    provider: P,
+    contracts: Vec<Address>,
+    block_span: u64,
+    _network: PhantomData<fn() -> N>,
+}
+
+impl<P: Provider<N>, N: Network> TokenTransfers<P, N> {
+    /// The default number of blocks per `eth_getLogs` request.
+    pub const DEFAULT_BLOCK_SPAN: u64 = 1_000;
+
+    /// Creates a new token transfer stream builder over all token contracts.
+    pub const fn new(provider: P) -> Self {
+        Self {
+            provider,
+            contracts: Vec::new(),
+            block_span: Self::DEFAULT_BLOCK_SPAN,
+            _network: PhantomData,
+        }
+    }
+
+    /// Only includes transfers of the given token contract.
+    ///
+    /// May be called multiple times to include several contracts.
+    pub fn contract(mut self, contract: Address) -> Self {
+        self.contracts.push(contract);
+        self
+    }
+
+    /// Sets the number of blocks per `eth_getLogs` request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_span` is zero.
+    pub const fn block_span(mut self, block_span: u64) -> Self {
+        assert!(block_span > 0, "block span must be non-zero");
+        self.block_span = block_span;
+        self
+    }
+
+    /// Returns a stream of the token transfers in the given block range, in log order.
+    pub fn stream(
+        &self,
+        blocks: RangeInclusive<u64>,
+    ) -> impl Stream<Item = TransportResult<TokenTransfer>> + '_ {
+        let (start, end) = blocks.into_inner();
+        let span = self.block_span;
+        let chunks = (start <= end)
+            .then(|| {
+                (0..=(end - start) / span).map(move |i| {
+                    let from = start + i * span;
+                    (from, from.saturating_add(span - 1).min(end))
+                })
+            })
+            .into_iter()
+            .flatten();
+
+        stream::iter(chunks)
+            .then(move |(from, to)| {
+                let mut filter = Filter::new()
+                    .from_block(from)
+                    .to_block(to)
+                    .event_signature(TokenTransfer::SIGNATURES.to_vec());
+                if !self.contracts.is_empty() {
+                    filter = filter.address(self.contracts.clone());
+                }
+                async move { self.provider.get_logs(&filter).await }
+            })
+            .map_ok(|logs| {
+                stream::iter(
+                    logs.iter().flat_map(TokenTransfer::from_log).map(Ok).collect::<Vec<_>>(),
+                )
+            })
+            .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::LogData;
+
+    fn log(data: LogData) -> Log {
+        Log {
+            inner: alloy_primitives::Log { address: Address::repeat_byte(0xcc), data },
+            block_number: Some(1),
+            log_index: Some(2),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn erc20_and_erc721() {
+        let a = Address::repeat_byte(1);
+        let erc20 =
+            log(erc20::Transfer { from: a, to: Address::ZERO, value: U256::from(5) }
+                .encode_log_data());
+        let [burn] = TokenTransfer::from_log(&erc20)[..] else { panic!() };
+        assert_eq!(burn.standard, TokenStandard::Erc20);
+        assert_eq!(burn.contract, Address::repeat_byte(0xcc));
+        assert_eq!(burn.amount, U256::from(5));
+        assert_eq!(burn.token_id, None);
+        assert_eq!(burn.log_index, Some(2));
+        assert!(burn.is_burn() && !burn.is_mint());
+
+        let erc721 = log(erc721::Transfer { from: Address::ZERO, to: a, tokenId: U256::from(9) }
+            .encode_log_data());
+        let [mint] = TokenTransfer::from_log(&erc721)[..] else { panic!() };
+        assert_eq!(mint.standard, TokenStandard::Erc721);
+        assert_eq!(mint.token_id, Some(U256::from(9)));
+        assert_eq!(mint.amount, U256::from(1));
+        assert!(mint.is_mint() && mint.involves(a));
+    }
+
+    #[test]
+    fn erc1155_batch() {
+        let batch = erc1155::TransferBatch {
+            operator: Address::repeat_byte(3),
+            from: Address::repeat_byte(1),
+            to: Address::repeat_byte(2),
+            ids: vec![U256::from(1), U256::from(2)],
+            values: vec![U256::from(10), U256::from(20)],
+        };
+        let transfers = TokenTransfer::from_log(&log(batch.encode_log_data()));
+        assert_eq!(transfers.len(), 2);
+        assert!(transfers
+            .iter()
+            .all(|t| t.standard == TokenStandard::Erc1155
+                && t.operator == Some(Address::repeat_byte(3))));
+        assert_eq!(transfers[1].token_id, Some(U256::from(2)));
+        assert_eq!(transfers[1].amount, U256::from(20));
+
+        let mismatched = erc1155::TransferBatch { values: vec![U256::from(10)], ..batch };
+        assert!(TokenTransfer::from_log(&log(mismatched.encode_log_data())).is_empty());
+    }
+
+    #[test]
+    fn unrelated_logs() {
+        assert!(TokenTransfer::from_log(&log(LogData::default())).is_empty());
+        let malformed =
+            LogData::new_unchecked(vec![erc20::Transfer::SIGNATURE_HASH], vec![].into());
+        assert!(TokenTransfer::from_log(&log(malformed)).is_empty());
+    }
+}
@@ -0,0 +1,197 @@
+//! Chainlink price feed bindings and staleness checks.
+
+use crate::{
+    multicall::{aggregate, decode},
+    Error, Result,
+};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{aliases::U80, Address, I256, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::SolCall;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+alloy_sol_types::sol! {
+    /// The read methods of a Chainlink aggregator, or of its proxy.
+    #[sol(rpc)]
+    interface AggregatorV3Interface {
+        function decimals() external view returns (uint8);
+        function description() external view returns (string memory);
+        function version() external view returns (uint256);
+        function latestRoundData()
+            external
+            view
+            returns (
+                uint80 roundId,
+                int256 answer,
+                uint256 startedAt,
+                uint256 updatedAt,
+                uint80 answeredInRound
+            );
+    }
+}
+
+/// The data of a price feed round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoundData {
+    /// The id of the round.
+    pub round_id: U80,
+    /// The answer of the round, scaled by the decimals of the feed.
+    pub answer: I256,
+    /// The timestamp at which the round started.
+    pub started_at: u64,
+    /// The timestamp at which the answer was last updated.
+    pub updated_at: u64,
+    /// The id of the round in which the answer was computed.
+    pub answered_in_round: U80,
+}
+
+impl RoundData {
+    /// Validates the round at the unix timestamp `now`, returning the answer.
+    ///
+    /// Rejects rounds with a non-positive answer, rounds that were never completed or whose
+    /// answer was carried over from an earlier round, and answers older than `max_age`. An
+    /// `updated_at` in the future of `now` is treated as fresh.
+    pub fn validate(&self, now: u64, max_age: Duration) -> Result<U256> {
+        if self.answer <= I256::ZERO {
+            return Err(Error::InvalidAnswer(self.answer));
+        }
+        if self.updated_at == 0 || self.answered_in_round < self.round_id {
+            return Err(Error::IncompleteRound {
+                round_id: self.round_id,
+                answered_in_round: self.answered_in_round,
+            });
+        }
+        let age = now.saturating_sub(self.updated_at);
+        if age > max_age.as_secs() {
+            return Err(Error::StalePrice { age, max_age: max_age.as_secs() });
+        }
+        Ok(self.answer.into_raw())
+    }
+}
+
+/// A validated price feed answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Price {
+    /// The answer, scaled by `10**decimals`.
+    pub answer: U256,
+    /// The number of decimals of the answer.
+    pub decimals: u8,
+    /// The timestamp at which the answer was last updated.
+    pub updated_at: u64,
+}
+
+impl Price {
+    /// Returns the answer as a float.
+    ///
+    /// The result is approximate and intended for display.
+    pub fn to_f64(&self) -> f64 {
+        let answer: f64 = self.answer.to_string().parse().unwrap_or(f64::NAN);
+        answer / 10f64.powi(self.decimals.into())
+    }
+}
+
+/// A Chainlink price feed.
+#[derive(Clone, Debug)]
+pub struct ChainlinkFeed<P, N = Ethereum> {
+    feed: AggregatorV3Interface::AggregatorV3InterfaceInstance<(), P, N>,
+}
+
+impl<P: Provider<N>, N: Network> ChainlinkFeed<P, N> {
+    /// Creates a new feed at the given address, usually the address of the feed's proxy.
+    pub const fn new(address: Address, provider: P) -> Self {
+        Self { feed: AggregatorV3Interface::new(address, provider) }
+    }
+
+    /// Returns the address of the feed.
+    pub const fn address(&self) -> &Address {
+        self.feed.address()
+    }
+
+    /// Returns the number of decimals of the answers.
+    pub async fn decimals(&self) -> Result<u8> {
+        Ok(self.feed.decimals().call().await?._0)
+    }
+
+    /// Returns the description of the feed, e.g. `ETH / USD`.
+    pub async fn description(&self) -> Result<String> {
+        Ok(self.feed.description().call().await?._0)
+    }
+
+    /// Returns the data of the latest round, without validating it.
+    pub async fn latest_round_data(&self) -> Result<RoundData> {
+        Ok(self.feed.latestRoundData().call().await?.into())
+    }
+
+    /// Returns the latest answer, validated against the system clock.
+    ///
+    /// See [`RoundData::validate`]. The round and the decimals are read in a single call through
+    /// Multicall3.
+    pub async fn latest_price(&self, max_age: Duration) -> Result<Price> {
+        let address = *self.address();
+        let [round, decimals] = aggregate(
+            self.feed.provider(),
+            [
+                (address, AggregatorV3Interface::latestRoundDataCall {}.abi_encode()),
+                (address, AggregatorV3Interface::decimalsCall {}.abi_encode()),
+            ],
+        )
+        .await?;
+        let round = RoundData::from(decode::<AggregatorV3Interface::latestRoundDataCall>(&round)?);
+        let decimals = decode::<AggregatorV3Interface::decimalsCall>(&decimals)?._0;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let answer = round.validate(now, max_age)?;
+        Ok(Price { answer, decimals, updated_at: round.updated_at })
+    }
+}
+
+impl From<AggregatorV3Interface::latestRoundDataReturn> for RoundData {
+    fn from(round: AggregatorV3Interface::latestRoundDataReturn) -> Self {
+        Self {
+            round_id: round.roundId,
+            answer: round.answer,
+            started_at: round.startedAt.saturating_to(),
+            updated_at: round.updatedAt.saturating_to(),
+            answered_in_round: round.answeredInRound,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round() -> RoundData {
+        RoundData {
+            round_id: U80::from(7),
+            answer: I256::try_from(250_000_000_000i64).unwrap(),
+            started_at: 1_000,
+            updated_at: 1_000,
+            answered_in_round: U80::from(7),
+        }
+    }
+
+    #[test]
+    fn validate_round() {
+        let hour = Duration::from_secs(3600);
+        assert_eq!(round().validate(1_000 + 3600, hour).unwrap(), U256::from(250_000_000_000u64));
+        // clock skew
+        assert!(round().validate(0, hour).is_ok());
+
+        assert!(matches!(
+            round().validate(1_000 + 3601, hour),
+            Err(Error::StalePrice { age: 3601, max_age: 3600 })
+        ));
+        let negative = RoundData { answer: I256::MINUS_ONE, ..round() };
+        assert!(matches!(negative.validate(1_000, hour), Err(Error::InvalidAnswer(_))));
+        let carried = RoundData { answered_in_round: U80::from(6), ..round() };
+        assert!(matches!(carried.validate(1_000, hour), Err(Error::IncompleteRound { .. })));
+        let pending = RoundData { updated_at: 0, ..round() };
+        assert!(matches!(pending.validate(1_000, hour), Err(Error::IncompleteRound { .. })));
+    }
+
+    #[test]
+    fn price_to_f64() {
+        let price = Price { answer: U256::from(250_012_345_678u64), decimals: 8, updated_at: 0 };
+        assert_eq!(price.to_f64(), 2500.12345678);
+    }
+}
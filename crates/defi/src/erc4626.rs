@@ -0,0 +1,393 @@
+//! [ERC-4626](https://eips.ethereum.org/EIPS/eip-4626) tokenized vault bindings, share
+//! conversion math and preview checks.
+
+use crate::{
+    math::{mul_div, mul_div_rounding_up},
+    multicall::{aggregate, decode},
+    Error, Result,
+};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::SolCall;
+
+alloy_sol_types::sol! {
+    /// The ERC-4626 tokenized vault interface, without the ERC-20 methods other than
+    /// `totalSupply` and `decimals`.
+    #[sol(rpc)]
+    interface IERC4626 {
+        function asset() external view returns (address assetTokenAddress);
+        function totalAssets() external view returns (uint256 totalManagedAssets);
+        function totalSupply() external view returns (uint256);
+        function decimals() external view returns (uint8);
+
+        function convertToShares(uint256 assets) external view returns (uint256 shares);
+        function convertToAssets(uint256 shares) external view returns (uint256 assets);
+
+        function maxDeposit(address receiver) external view returns (uint256 maxAssets);
+        function previewDeposit(uint256 assets) external view returns (uint256 shares);
+        function deposit(uint256 assets, address receiver) external returns (uint256 shares);
+
+        function maxMint(address receiver) external view returns (uint256 maxShares);
+        function previewMint(uint256 shares) external view returns (uint256 assets);
+        function mint(uint256 shares, address receiver) external returns (uint256 assets);
+
+        function maxWithdraw(address owner) external view returns (uint256 maxAssets);
+        function previewWithdraw(uint256 assets) external view returns (uint256 shares);
+        function withdraw(uint256 assets, address receiver, address owner)
+            external
+            returns (uint256 shares);
+
+        function maxRedeem(address owner) external view returns (uint256 maxShares);
+        function previewRedeem(uint256 shares) external view returns (uint256 assets);
+        function redeem(uint256 shares, address receiver, address owner)
+            external
+            returns (uint256 assets);
+    }
+}
+
+/// The rounding direction of a share or asset conversion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+}
+
+/// A vault operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VaultOperation {
+    /// Deposit an exact amount of assets for shares.
+    Deposit,
+    /// Mint an exact amount of shares for assets.
+    Mint,
+    /// Withdraw an exact amount of assets for shares.
+    Withdraw,
+    /// Redeem an exact amount of shares for assets.
+    Redeem,
+}
+
+impl VaultOperation {
+    /// Returns whether the input amount of the operation is denominated in assets, rather than
+    /// shares.
+    pub const fn takes_assets(&self) -> bool {
+        matches!(self, Self::Deposit | Self::Withdraw)
+    }
+
+    /// Returns the rounding direction of the preview of the operation.
+    ///
+    /// Previews always round in favor of the vault: down for the shares received on deposit and
+    /// the assets received on redeem, up for the assets paid on mint and the shares paid on
+    /// withdraw.
+    pub const fn preview_rounding(&self) -> Rounding {
+        match self {
+            Self::Deposit | Self::Redeem => Rounding::Down,
+            Self::Mint | Self::Withdraw => Rounding::Up,
+        }
+    }
+}
+
+/// The totals of a vault, used to convert between shares and assets locally.
+///
+/// Conversions follow the OpenZeppelin implementation, which offsets the total supply by
+/// `10**decimals_offset` virtual shares and the total assets by one virtual asset to mitigate
+/// inflation attacks. Vaults with a different conversion or with fees must be queried on-chain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VaultState {
+    /// The total amount of assets managed by the vault.
+    pub total_assets: U256,
+    /// The total supply of shares.
+    pub total_supply: U256,
+    /// The decimals offset between shares and assets.
+    pub decimals_offset: u8,
+}
+
+impl VaultState {
+    /// Sets the decimals offset between shares and assets.
+    pub const fn with_decimals_offset(mut self, decimals_offset: u8) -> Self {
+        self.decimals_offset = decimals_offset;
+        self
+    }
+
+    /// Converts an amount of assets to shares.
+    pub fn convert_to_shares(&self, assets: U256, rounding: Rounding) -> Result<U256> {
+        let (supply, total_assets) = self.virtual_totals()?;
+        div(assets, supply, total_assets, rounding)
+    }
+
+    /// Converts an amount of shares to assets.
+    pub fn convert_to_assets(&self, shares: U256, rounding: Rounding) -> Result<U256> {
+        let (supply, total_assets) = self.virtual_totals()?;
+        div(shares, total_assets, supply, rounding)
+    }
+
+    /// Returns the local preview of an operation on `amount`, rounded in favor of the vault.
+    pub fn preview(&self, operation: VaultOperation, amount: U256) -> Result<U256> {
+        let rounding = operation.preview_rounding();
+        if operation.takes_assets() {
+            self.convert_to_shares(amount, rounding)
+        } else {
+            self.convert_to_assets(amount, rounding)
+        }
+    }
+
+    fn virtual_totals(&self) -> Result<(U256, U256)> {
+        let virtual_shares =
+            U256::from(10).checked_pow(U256::from(self.decimals_offset)).ok_or(Error::Overflow)?;
+        let supply = self.total_supply.checked_add(virtual_shares).ok_or(Error::Overflow)?;
+        let total_assets = self.total_assets.checked_add(U256::from(1)).ok_or(Error::Overflow)?;
+        Ok((supply, total_assets))
+    }
+}
+
+fn div(a: U256, b: U256, denominator: U256, rounding: Rounding) -> Result<U256> {
+    match rounding {
+        Rounding::Down => mul_div(a, b, denominator),
+        Rounding::Up => mul_div_rounding_up(a, b, denominator),
+    }
+}
+
+/// An on-chain preview of a vault operation, checked against the limits and the conversion
+/// rate of the vault.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Preview {
+    /// The previewed operation.
+    pub operation: VaultOperation,
+    /// The input amount, in assets for deposits and withdrawals, in shares otherwise.
+    pub amount: U256,
+    /// The output of the preview method, including fees.
+    pub preview: U256,
+    /// The output of the conversion method, excluding fees.
+    pub converted: U256,
+    /// The maximum input amount accepted for the account.
+    pub max: U256,
+}
+
+impl Preview {
+    /// Returns the difference between the preview and the conversion, i.e. the fees and
+    /// rounding charged by the vault.
+    pub fn cost(&self) -> U256 {
+        self.preview.abs_diff(self.converted)
+    }
+}
+
+/// An ERC-4626 vault.
+#[derive(Clone, Debug)]
+pub struct Erc4626Vault<P, N = Ethereum> {
+    vault: IERC4626::IERC4626Instance<(), P, N>,
+}
+
+impl<P: Provider<N>, N: Network> Erc4626Vault<P, N> {
+    /// Creates a new vault at the given address.
+    pub const fn new(address: Address, provider: P) -> Self {
+        Self { vault: IERC4626::new(address, provider) }
+    }
+
+    /// Returns the address of the vault.
+    pub const fn address(&self) -> &Address {
+        self.vault.address()
+    }
+
+    /// Returns the underlying contract binding, e.g. to send deposits.
+    pub const fn contract(&self) -> &IERC4626::IERC4626Instance<(), P, N> {
+        &self.vault
+    }
+
+    /// Returns the address of the underlying asset token.
+    pub async fn asset(&self) -> Result<Address> {
+        Ok(self.vault.asset().call().await?.assetTokenAddress)
+    }
+
+    /// Returns the total assets and total supply of the vault.
+    ///
+    /// Both values are read in a single call through Multicall3. The decimals offset is left at
+    /// zero, see [`VaultState::with_decimals_offset`].
+    pub async fn state(&self) -> Result<VaultState> {
+        let address = *self.address();
+        let [total_assets, total_supply] = aggregate(
+            self.vault.provider(),
+            [
+                (address, IERC4626::totalAssetsCall {}.abi_encode()),
+                (address, IERC4626::totalSupplyCall {}.abi_encode()),
+            ],
+        )
+        .await?;
+        Ok(VaultState {
+            total_assets: decode::<IERC4626::totalAssetsCall>(&total_assets)?.totalManagedAssets,
+            total_supply: decode::<IERC4626::totalSupplyCall>(&total_supply)?._0,
+            decimals_offset: 0,
+        })
+    }
+
+    /// Returns the maximum input amount of an operation for `account`, the receiver for deposits
+    /// and mints and the owner for withdrawals and redemptions.
+    pub async fn max(&self, operation: VaultOperation, account: Address) -> Result<U256> {
+        Ok(match operation {
+            VaultOperation::Deposit => self.vault.maxDeposit(account).call().await?.maxAssets,
+            VaultOperation::Mint => self.vault.maxMint(account).call().await?.maxShares,
+            VaultOperation::Withdraw => self.vault.maxWithdraw(account).call().await?.maxAssets,
+            VaultOperation::Redeem => self.vault.maxRedeem(account).call().await?.maxShares,
+        })
+    }
+
+    /// Clamps the input amount of an operation to its maximum for `account`.
+    pub async fn clamp(
+        &self,
+        operation: VaultOperation,
+        amount: U256,
+        account: Address,
+    ) -> Result<U256> {
+        Ok(amount.min(self.max(operation, account).await?))
+    }
+
+    /// Previews an operation on `amount` for `account`, and checks the preview for consistency.
+    ///
+    /// The maximum, the preview and the conversion are read in a single call through Multicall3,
+    /// so that they are consistent with each other.
+    ///
+    /// Fails with [`Error::ExceedsMax`] if `amount` exceeds the maximum for the account, and
+    /// with [`Error::InconsistentPreview`] if the preview is more favorable to the caller than
+    /// the fee-less conversion, which the standard forbids.
+    pub async fn preview(
+        &self,
+        operation: VaultOperation,
+        amount: U256,
+        account: Address,
+    ) -> Result<Preview> {
+        let address = *self.address();
+        let [max, preview, converted] = aggregate(
+            self.vault.provider(),
+            [
+                (address, max_call(operation, account)),
+                (address, preview_call(operation, amount)),
+                (address, convert_call(operation, amount)),
+            ],
+        )
+        .await?;
+        let (max, preview, converted) =
+            (decode_amount(&max)?, decode_amount(&preview)?, decode_amount(&converted)?);
+        if amount > max {
+            return Err(Error::ExceedsMax { amount, max });
+        }
+        let consistent = match operation.preview_rounding() {
+            Rounding::Down => preview <= converted,
+            Rounding::Up => preview >= converted,
+        };
+        if !consistent {
+            return Err(Error::InconsistentPreview { preview, converted });
+        }
+        Ok(Preview { operation, amount, preview, converted, max })
+    }
+
+    /// Converts the input amount of an operation with the fee-less conversion method of the
+    /// vault, i.e. `convertToShares` for deposits and withdrawals, `convertToAssets` otherwise.
+    pub async fn convert(&self, operation: VaultOperation, amount: U256) -> Result<U256> {
+        Ok(if operation.takes_assets() {
+            self.vault.convertToShares(amount).call().await?.shares
+        } else {
+            self.vault.convertToAssets(amount).call().await?.assets
+        })
+    }
+
+    /// Calls the preview method of an operation, without any checks.
+    pub async fn preview_raw(&self, operation: VaultOperation, amount: U256) -> Result<U256> {
+        Ok(match operation {
+            VaultOperation::Deposit => self.vault.previewDeposit(amount).call().await?.shares,
+            VaultOperation::Mint => self.vault.previewMint(amount).call().await?.assets,
+            VaultOperation::Withdraw => self.vault.previewWithdraw(amount).call().await?.shares,
+            VaultOperation::Redeem => self.vault.previewRedeem(amount).call().await?.assets,
+        })
+    }
+}
+
+/// Encodes the call of the `max` method of an operation.
+fn max_call(operation: VaultOperation, account: Address) -> Vec<u8> {
+    match operation {
+        VaultOperation::Deposit => IERC4626::maxDepositCall { receiver: account }.abi_encode(),
+        VaultOperation::Mint => IERC4626::maxMintCall { receiver: account }.abi_encode(),
+        VaultOperation::Withdraw => IERC4626::maxWithdrawCall { owner: account }.abi_encode(),
+        VaultOperation::Redeem => IERC4626::maxRedeemCall { owner: account }.abi_encode(),
+    }
+}
+
+/// Encodes the call of the `preview` method of an operation.
+fn preview_call(operation: VaultOperation, amount: U256) -> Vec<u8> {
+    match operation {
+        VaultOperation::Deposit => IERC4626::previewDepositCall { assets: amount }.abi_encode(),
+        VaultOperation::Mint => IERC4626::previewMintCall { shares: amount }.abi_encode(),
+        VaultOperation::Withdraw => IERC4626::previewWithdrawCall { assets: amount }.abi_encode(),
+        VaultOperation::Redeem => IERC4626::previewRedeemCall { shares: amount }.abi_encode(),
+    }
+}
+
+/// Encodes the call of the fee-less conversion method of an operation.
+fn convert_call(operation: VaultOperation, amount: U256) -> Vec<u8> {
+    if operation.takes_assets() {
+        IERC4626::convertToSharesCall { assets: amount }.abi_encode()
+    } else {
+        IERC4626::convertToAssetsCall { shares: amount }.abi_encode()
+    }
+}
+
+/// Decodes the amount returned by the `max`, `preview` and conversion methods, which all return a
+/// single `uint256`.
+fn decode_amount(data: &[u8]) -> Result<U256> {
+    decode::<IERC4626::totalSupplyCall>(data).map(|ret| ret._0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> VaultState {
+        VaultState {
+            total_assets: U256::from(1_000),
+            total_supply: U256::from(500),
+            decimals_offset: 0,
+        }
+    }
+
+    #[test]
+    fn previews_round_in_favor_of_vault() {
+        let state = state();
+        let amount = U256::from(100);
+        assert_eq!(state.preview(VaultOperation::Deposit, amount).unwrap(), U256::from(50));
+        assert_eq!(state.preview(VaultOperation::Withdraw, amount).unwrap(), U256::from(51));
+        let shares = U256::from(50);
+        assert_eq!(state.preview(VaultOperation::Mint, shares).unwrap(), U256::from(100));
+        assert_eq!(state.preview(VaultOperation::Redeem, shares).unwrap(), U256::from(99));
+    }
+
+    #[test]
+    fn empty_vault() {
+        let empty = VaultState::default();
+        let amount = U256::from(1_000);
+        assert_eq!(empty.convert_to_shares(amount, Rounding::Down).unwrap(), amount);
+        let offset = empty.with_decimals_offset(3);
+        assert_eq!(
+            offset.convert_to_shares(amount, Rounding::Down).unwrap(),
+            U256::from(1_000_000)
+        );
+        assert_eq!(
+            offset.convert_to_assets(U256::from(1_000_000), Rounding::Down).unwrap(),
+            amount
+        );
+        assert!(matches!(
+            empty.with_decimals_offset(100).convert_to_shares(amount, Rounding::Down),
+            Err(Error::Overflow)
+        ));
+    }
+
+    #[test]
+    fn preview_cost() {
+        let preview = Preview {
+            operation: VaultOperation::Withdraw,
+            amount: U256::from(100),
+            preview: U256::from(52),
+            converted: U256::from(50),
+            max: U256::MAX,
+        };
+        assert_eq!(preview.cost(), U256::from(2));
+    }
+}
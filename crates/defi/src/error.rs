@@ -0,0 +1,66 @@
+use alloy_primitives::{aliases::U80, Address, I256, U256};
+use thiserror::Error;
+
+/// DeFi helper result type.
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Error when reading from or quoting against DeFi protocols.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// An error occurred calling a contract.
+    #[error(transparent)]
+    Contract(#[from] alloy_contract::Error),
+    /// A call of a batch of reads reverted.
+    #[error("call to {0} reverted")]
+    CallFailed(Address),
+    /// The fee of a swap is not below 100%.
+    #[error("invalid fee of {0} basis points")]
+    InvalidFee(u16),
+    /// The input amount of a swap is zero.
+    #[error("insufficient input amount")]
+    InsufficientInputAmount,
+    /// The output amount of a swap is zero.
+    #[error("insufficient output amount")]
+    InsufficientOutputAmount,
+    /// The pool does not hold enough liquidity for the swap.
+    #[error("insufficient liquidity")]
+    InsufficientLiquidity,
+    /// An intermediate or final value does not fit its type.
+    #[error("arithmetic overflow")]
+    Overflow,
+    /// The amount of a vault operation exceeds its maximum.
+    #[error("amount {amount} exceeds the maximum of {max}")]
+    ExceedsMax {
+        /// The requested amount.
+        amount: U256,
+        /// The maximum amount.
+        max: U256,
+    },
+    /// A vault preview is more favorable to the caller than the fee-less conversion.
+    #[error("inconsistent vault preview: previewed {preview}, converted {converted}")]
+    InconsistentPreview {
+        /// The output of the preview method.
+        preview: U256,
+        /// The output of the conversion method.
+        converted: U256,
+    },
+    /// The price feed reported a non-positive answer.
+    #[error("invalid price feed answer: {0}")]
+    InvalidAnswer(I256),
+    /// The price feed round was not completed.
+    #[error("incomplete price feed round {round_id}, answered in round {answered_in_round}")]
+    IncompleteRound {
+        /// The id of the round.
+        round_id: U80,
+        /// The id of the round in which the answer was computed.
+        answered_in_round: U80,
+    },
+    /// The price feed answer is older than the accepted maximum age.
+    #[error("stale price feed answer: updated {age}s ago, max age is {max_age}s")]
+    StalePrice {
+        /// The age of the answer in seconds.
+        age: u64,
+        /// The maximum accepted age in seconds.
+        max_age: u64,
+    },
+}
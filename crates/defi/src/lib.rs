@@ -0,0 +1,18 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/alloy.jpg",
+    html_favicon_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/favicon.ico"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+pub mod chainlink;
+pub mod erc4626;
+pub mod math;
+pub mod uniswap_v2;
+pub mod uniswap_v3;
+
+mod error;
+pub use error::{Error, Result};
+
+mod multicall;
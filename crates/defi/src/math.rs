@@ -0,0 +1,209 @@
+//! Full precision fixed point arithmetic.
+//!
+//! All operations are checked and return [`Error::Overflow`] if a result does not fit in a
+//! [`U256`] or a denominator is zero.
+
+use crate::{Error, Result};
+use alloy_primitives::{ruint::UintTryFrom, U256, U512};
+
+/// The number of basis points in one.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// The direction in which to round the result of a division.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards zero.
+    #[default]
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round to the nearest value, with ties rounded up.
+    Nearest,
+}
+
+/// Returns `a * b / denominator`, rounded down, with a full precision intermediate product.
+pub fn mul_div(a: U256, b: U256, denominator: U256) -> Result<U256> {
+    mul_div_rounded(a, b, denominator, Rounding::Down)
+}
+
+/// Returns `a * b / denominator`, rounded up, with a full precision intermediate product.
+pub fn mul_div_rounding_up(a: U256, b: U256, denominator: U256) -> Result<U256> {
+    mul_div_rounded(a, b, denominator, Rounding::Up)
+}
+
+/// Returns `a * b / denominator`, rounded in the given direction, with a full precision
+/// intermediate product.
+pub fn mul_div_rounded(a: U256, b: U256, denominator: U256, rounding: Rounding) -> Result<U256> {
+    let (quotient, remainder) = mul_div_rem(a, b, denominator)?;
+    let round_up = match rounding {
+        Rounding::Down => false,
+        Rounding::Up => !remainder.is_zero(),
+        Rounding::Nearest => remainder >= U512::from(denominator) - remainder,
+    };
+    narrow(if round_up { quotient + U512::from(1) } else { quotient })
+}
+
+/// Returns `bps` basis points of `amount`.
+pub fn apply_bps(amount: U256, bps: u32, rounding: Rounding) -> Result<U256> {
+    mul_div_rounded(amount, U256::from(bps), U256::from(BPS_DENOMINATOR), rounding)
+}
+
+/// Returns `amount` increased by `bps` basis points, e.g. to add a fee.
+pub fn add_bps(amount: U256, bps: u32, rounding: Rounding) -> Result<U256> {
+    let bps = BPS_DENOMINATOR.checked_add(bps).ok_or(Error::Overflow)?;
+    apply_bps(amount, bps, rounding)
+}
+
+/// Returns `amount` decreased by `bps` basis points, e.g. to apply a slippage tolerance.
+///
+/// Fails if `bps` exceeds [`BPS_DENOMINATOR`].
+pub fn sub_bps(amount: U256, bps: u32, rounding: Rounding) -> Result<U256> {
+    let bps = BPS_DENOMINATOR.checked_sub(bps).ok_or(Error::Overflow)?;
+    apply_bps(amount, bps, rounding)
+}
+
+/// Returns the ratio of `numerator` to `denominator` in basis points.
+pub fn to_bps(numerator: U256, denominator: U256, rounding: Rounding) -> Result<U256> {
+    mul_div_rounded(numerator, U256::from(BPS_DENOMINATOR), denominator, rounding)
+}
+
+/// Returns the square root of `value`, rounded down.
+pub fn sqrt(value: U256) -> U256 {
+    value.root(2)
+}
+
+/// Returns the `Q64.96` square root price of the ratio `amount1 / amount0`, rounded down.
+///
+/// This is the square root price of a pool holding `amount0` of `token0` and `amount1` of
+/// `token1`.
+pub fn encode_sqrt_ratio_x96(amount1: U256, amount0: U256) -> Result<U256> {
+    if amount0.is_zero() {
+        return Err(Error::Overflow);
+    }
+    // sqrt(amount1 / amount0) * 2^96 = sqrt(amount1 * 2^192 / amount0)
+    let ratio_x192: U512 = (U512::from(amount1) << 192) / U512::from(amount0);
+    narrow(ratio_x192.root(2))
+}
+
+/// Converts `amount` of one token into the other at the given `Q64.96` square root price of
+/// `token0` in `token1`.
+///
+/// If `zero_for_one` is `true`, `amount` is an amount of `token0` and the result an amount of
+/// `token1`, and vice versa.
+pub fn quote_at_sqrt_price_x96(
+    amount: U256,
+    sqrt_price_x96: U256,
+    zero_for_one: bool,
+    rounding: Rounding,
+) -> Result<U256> {
+    // the squared price has 192 fractional bits, which is scaled down to 128 bits if the
+    // square would not fit in 256 bits
+    let (ratio, one) = if sqrt_price_x96 <= U256::from(u128::MAX) {
+        (sqrt_price_x96 * sqrt_price_x96, U256::from(1) << 192)
+    } else {
+        (mul_div(sqrt_price_x96, sqrt_price_x96, U256::from(1) << 64)?, U256::from(1) << 128)
+    };
+    if zero_for_one {
+        mul_div_rounded(amount, ratio, one, rounding)
+    } else {
+        mul_div_rounded(amount, one, ratio, rounding)
+    }
+}
+
+fn mul_div_rem(a: U256, b: U256, denominator: U256) -> Result<(U512, U512)> {
+    if denominator.is_zero() {
+        return Err(Error::Overflow);
+    }
+    let product: U512 = a.widening_mul(b);
+    Ok(product.div_rem(U512::from(denominator)))
+}
+
+fn narrow(value: U512) -> Result<U256> {
+    U256::uint_try_from(value).map_err(|_| Error::Overflow)
+}
+
+pub(crate) fn div_rounding_up(a: U256, b: U256) -> U256 {
+    let (quotient, remainder) = a.div_rem(b);
+    if remainder.is_zero() {
+        quotient
+    } else {
+        quotient + U256::from(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uniswap_v3::Q96;
+
+    #[test]
+    fn mul_div_full_precision() {
+        let max = U256::MAX;
+        assert_eq!(mul_div(max, max, max).unwrap(), max);
+        assert_eq!(
+            mul_div_rounding_up(U256::from(5), U256::from(3), U256::from(2)).unwrap(),
+            U256::from(8)
+        );
+        assert!(matches!(mul_div(max, max, U256::from(1)), Err(Error::Overflow)));
+        assert!(matches!(mul_div(max, max, U256::ZERO), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn rounding_modes() {
+        let div = |a: u64, d: u64, rounding| {
+            mul_div_rounded(U256::from(a), U256::from(1), U256::from(d), rounding).unwrap()
+        };
+        assert_eq!(div(5, 4, Rounding::Down), U256::from(1));
+        assert_eq!(div(5, 4, Rounding::Up), U256::from(2));
+        assert_eq!(div(5, 4, Rounding::Nearest), U256::from(1));
+        assert_eq!(div(6, 4, Rounding::Nearest), U256::from(2));
+        assert_eq!(div(7, 4, Rounding::Nearest), U256::from(2));
+        assert_eq!(div(8, 4, Rounding::Up), U256::from(2));
+    }
+
+    #[test]
+    fn basis_points() {
+        let amount = U256::from(1_000);
+        assert_eq!(apply_bps(amount, 30, Rounding::Down).unwrap(), U256::from(3));
+        assert_eq!(apply_bps(U256::from(999), 30, Rounding::Up).unwrap(), U256::from(3));
+        assert_eq!(add_bps(amount, 30, Rounding::Down).unwrap(), U256::from(1_003));
+        assert_eq!(sub_bps(amount, 50, Rounding::Down).unwrap(), U256::from(995));
+        assert!(matches!(sub_bps(amount, 10_001, Rounding::Down), Err(Error::Overflow)));
+        assert_eq!(
+            to_bps(U256::from(1), U256::from(3), Rounding::Nearest).unwrap(),
+            U256::from(3_333)
+        );
+        assert!(matches!(apply_bps(U256::MAX, 20_000, Rounding::Down), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn sqrt_prices() {
+        assert_eq!(sqrt(U256::MAX), U256::from(u128::MAX));
+        assert_eq!(sqrt(U256::from(15)), U256::from(3));
+
+        assert_eq!(encode_sqrt_ratio_x96(U256::from(1), U256::from(1)).unwrap(), Q96);
+        assert_eq!(
+            encode_sqrt_ratio_x96(U256::from(100), U256::from(1)).unwrap(),
+            Q96 * U256::from(10)
+        );
+        assert_eq!(encode_sqrt_ratio_x96(U256::from(1), U256::from(4)).unwrap(), Q96 >> 1);
+        assert!(encode_sqrt_ratio_x96(U256::from(1), U256::ZERO).is_err());
+
+        let amount = U256::from(1_000);
+        let price_four = Q96 * U256::from(2);
+        assert_eq!(quote_at_sqrt_price_x96(amount, Q96, true, Rounding::Down).unwrap(), amount);
+        assert_eq!(
+            quote_at_sqrt_price_x96(amount, price_four, true, Rounding::Down).unwrap(),
+            U256::from(4_000)
+        );
+        assert_eq!(
+            quote_at_sqrt_price_x96(U256::from(4_000), price_four, false, Rounding::Down).unwrap(),
+            amount
+        );
+        assert_eq!(
+            quote_at_sqrt_price_x96(U256::from(1), U256::from(1) << 130, true, Rounding::Down)
+                .unwrap(),
+            U256::from(1) << 68
+        );
+    }
+}
@@ -0,0 +1,37 @@
+//! Batching of reads that span several calls through Multicall3.
+
+use crate::{Error, Result};
+use alloy_contract::multicall::aggregate_raw;
+use alloy_network::Network;
+use alloy_primitives::{Address, Bytes};
+use alloy_provider::Provider;
+use alloy_sol_types::SolCall;
+
+/// Executes the calls in a single `eth_call` through Multicall3, so that all values are read at
+/// the same block, returning the data returned by each call.
+///
+/// Fails with [`Error::CallFailed`] if any of the calls reverts.
+pub(crate) async fn aggregate<P, N, const M: usize>(
+    provider: &P,
+    calls: [(Address, Vec<u8>); M],
+) -> Result<[Bytes; M]>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    let targets = calls.each_ref().map(|(target, _)| *target);
+    let mut results = aggregate_raw(provider, calls.map(|(target, data)| (target, data.into())))
+        .await?
+        .into_iter();
+    let mut returns: [Bytes; M] = std::array::from_fn(|_| Bytes::new());
+    for (ret, target) in returns.iter_mut().zip(targets) {
+        *ret = results.next().flatten().ok_or(Error::CallFailed(target))?;
+    }
+    Ok(returns)
+}
+
+/// Decodes the data returned by a call.
+pub(crate) fn decode<C: SolCall>(data: &[u8]) -> Result<C::Return> {
+    C::abi_decode_returns(data, true)
+        .map_err(|err| alloy_contract::Error::AbiError(err.into()).into())
+}
@@ -0,0 +1,265 @@
+//! Uniswap v2 pair bindings and constant product quoting math.
+//!
+//! The math matches `UniswapV2Library` and applies to forks with a different swap fee through
+//! the `_with_fee` variants.
+
+use crate::{
+    multicall::{aggregate, decode},
+    Error, Result,
+};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::SolCall;
+
+alloy_sol_types::sol! {
+    /// The read methods of a Uniswap v2 pair.
+    #[sol(rpc)]
+    interface IUniswapV2Pair {
+        function token0() external view returns (address);
+        function token1() external view returns (address);
+        function getReserves()
+            external
+            view
+            returns (uint112 reserve0, uint112 reserve1, uint32 blockTimestampLast);
+    }
+}
+
+/// The Uniswap v2 swap fee, in basis points.
+pub const FEE_BPS: u16 = 30;
+
+const BPS: u64 = 10_000;
+
+/// Returns the maximum output amount of a swap of `amount_in`, with the Uniswap v2 fee.
+pub fn get_amount_out(amount_in: U256, reserve_in: U256, reserve_out: U256) -> Result<U256> {
+    get_amount_out_with_fee(amount_in, reserve_in, reserve_out, FEE_BPS)
+}
+
+/// Returns the maximum output amount of a swap of `amount_in`, with a fee of `fee_bps` basis
+/// points.
+///
+/// Fails with [`Error::InvalidFee`] if the fee is 100% or more.
+pub fn get_amount_out_with_fee(
+    amount_in: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    fee_bps: u16,
+) -> Result<U256> {
+    if amount_in.is_zero() {
+        return Err(Error::InsufficientInputAmount);
+    }
+    if reserve_in.is_zero() || reserve_out.is_zero() {
+        return Err(Error::InsufficientLiquidity);
+    }
+    let amount_in_with_fee = amount_in.checked_mul(fee_factor(fee_bps)?).ok_or(Error::Overflow)?;
+    let numerator = amount_in_with_fee.checked_mul(reserve_out).ok_or(Error::Overflow)?;
+    let denominator = reserve_in
+        .checked_mul(U256::from(BPS))
+        .and_then(|reserve| reserve.checked_add(amount_in_with_fee))
+        .ok_or(Error::Overflow)?;
+    Ok(numerator / denominator)
+}
+
+/// Returns the minimum input amount of a swap for `amount_out`, with the Uniswap v2 fee.
+pub fn get_amount_in(amount_out: U256, reserve_in: U256, reserve_out: U256) -> Result<U256> {
+    get_amount_in_with_fee(amount_out, reserve_in, reserve_out, FEE_BPS)
+}
+
+/// Returns the minimum input amount of a swap for `amount_out`, with a fee of `fee_bps` basis
+/// points.
+///
+/// Fails with [`Error::InvalidFee`] if the fee is 100% or more.
+pub fn get_amount_in_with_fee(
+    amount_out: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    fee_bps: u16,
+) -> Result<U256> {
+    if amount_out.is_zero() {
+        return Err(Error::InsufficientOutputAmount);
+    }
+    if reserve_in.is_zero() || amount_out >= reserve_out {
+        return Err(Error::InsufficientLiquidity);
+    }
+    let numerator = reserve_in
+        .checked_mul(amount_out)
+        .and_then(|n| n.checked_mul(U256::from(BPS)))
+        .ok_or(Error::Overflow)?;
+    let denominator =
+        (reserve_out - amount_out).checked_mul(fee_factor(fee_bps)?).ok_or(Error::Overflow)?;
+    Ok(numerator / denominator + U256::from(1))
+}
+
+/// Returns the output amounts of a swap of `amount_in` along a path of pairs.
+///
+/// Each element of `reserves` holds the input and output reserves of a hop.
+pub fn get_amounts_out(amount_in: U256, reserves: &[(U256, U256)]) -> Result<Vec<U256>> {
+    let mut amounts = Vec::with_capacity(reserves.len() + 1);
+    amounts.push(amount_in);
+    for &(reserve_in, reserve_out) in reserves {
+        let amount = get_amount_out(*amounts.last().unwrap(), reserve_in, reserve_out)?;
+        amounts.push(amount);
+    }
+    Ok(amounts)
+}
+
+/// Returns the share of the input amount left after the fee, in basis points.
+fn fee_factor(fee_bps: u16) -> Result<U256> {
+    BPS.checked_sub(u64::from(fee_bps))
+        .filter(|factor| *factor != 0)
+        .map(U256::from)
+        .ok_or(Error::InvalidFee(fee_bps))
+}
+
+/// The reserves of a Uniswap v2 pair.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Reserves {
+    /// The reserve of `token0`.
+    pub reserve0: U256,
+    /// The reserve of `token1`.
+    pub reserve1: U256,
+    /// The timestamp of the last block in which the reserves changed, modulo `2**32`.
+    pub block_timestamp_last: u32,
+}
+
+impl Reserves {
+    /// Returns the input and output reserves of a swap in the given direction.
+    pub const fn oriented(&self, zero_for_one: bool) -> (U256, U256) {
+        if zero_for_one {
+            (self.reserve0, self.reserve1)
+        } else {
+            (self.reserve1, self.reserve0)
+        }
+    }
+}
+
+/// A Uniswap v2 pair.
+#[derive(Clone, Debug)]
+pub struct UniswapV2Pair<P, N = Ethereum> {
+    pair: IUniswapV2Pair::IUniswapV2PairInstance<(), P, N>,
+}
+
+impl<P: Provider<N>, N: Network> UniswapV2Pair<P, N> {
+    /// Creates a new pair at the given address.
+    pub const fn new(address: Address, provider: P) -> Self {
+        Self { pair: IUniswapV2Pair::new(address, provider) }
+    }
+
+    /// Returns the address of the pair.
+    pub const fn address(&self) -> &Address {
+        self.pair.address()
+    }
+
+    /// Returns the addresses of `token0` and `token1`.
+    ///
+    /// Both tokens are read in a single call through Multicall3.
+    pub async fn tokens(&self) -> Result<(Address, Address)> {
+        let address = *self.address();
+        let [token0, token1] = aggregate(
+            self.pair.provider(),
+            [
+                (address, IUniswapV2Pair::token0Call {}.abi_encode()),
+                (address, IUniswapV2Pair::token1Call {}.abi_encode()),
+            ],
+        )
+        .await?;
+        Ok((
+            decode::<IUniswapV2Pair::token0Call>(&token0)?._0,
+            decode::<IUniswapV2Pair::token1Call>(&token1)?._0,
+        ))
+    }
+
+    /// Returns the current reserves.
+    pub async fn reserves(&self) -> Result<Reserves> {
+        let reserves = self.pair.getReserves().call().await?;
+        Ok(Reserves {
+            reserve0: U256::from(reserves.reserve0),
+            reserve1: U256::from(reserves.reserve1),
+            block_timestamp_last: reserves.blockTimestampLast,
+        })
+    }
+
+    /// Returns the output amount of a swap of `amount_in` against the current reserves.
+    ///
+    /// `zero_for_one` swaps `token0` for `token1`.
+    pub async fn quote_exact_input(&self, amount_in: U256, zero_for_one: bool) -> Result<U256> {
+        let (reserve_in, reserve_out) = self.reserves().await?.oriented(zero_for_one);
+        get_amount_out(amount_in, reserve_in, reserve_out)
+    }
+
+    /// Returns the input amount of a swap for `amount_out` against the current reserves.
+    ///
+    /// `zero_for_one` swaps `token0` for `token1`.
+    pub async fn quote_exact_output(&self, amount_out: U256, zero_for_one: bool) -> Result<U256> {
+        let (reserve_in, reserve_out) = self.reserves().await?.oriented(zero_for_one);
+        get_amount_in(amount_out, reserve_in, reserve_out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amount_out() {
+        let reserve = U256::from(1_000_000_000u64);
+        // 1% of the reserve, minus the fee and the price impact
+        let out = get_amount_out(U256::from(10_000_000u64), reserve, reserve).unwrap();
+        assert_eq!(out, U256::from(9_871_580u64));
+        let no_fee = get_amount_out_with_fee(U256::from(10_000_000u64), reserve, reserve, 0);
+        assert_eq!(no_fee.unwrap(), U256::from(9_900_990u64));
+
+        assert!(matches!(
+            get_amount_out(U256::ZERO, reserve, reserve),
+            Err(Error::InsufficientInputAmount)
+        ));
+        assert!(matches!(
+            get_amount_out(U256::from(1), U256::ZERO, reserve),
+            Err(Error::InsufficientLiquidity)
+        ));
+        assert!(matches!(get_amount_out(U256::MAX, reserve, reserve), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn amount_in_round_trips() {
+        let (reserve_in, reserve_out) = (U256::from(5_000_000u64), U256::from(20_000_000u64));
+        let amount_out = U256::from(123_456u64);
+        let amount_in = get_amount_in(amount_out, reserve_in, reserve_out).unwrap();
+        assert!(get_amount_out(amount_in, reserve_in, reserve_out).unwrap() >= amount_out);
+        let short = amount_in - U256::from(1);
+        assert!(get_amount_out(short, reserve_in, reserve_out).unwrap() < amount_out);
+
+        assert!(matches!(
+            get_amount_in(reserve_out, reserve_in, reserve_out),
+            Err(Error::InsufficientLiquidity)
+        ));
+    }
+
+    #[test]
+    fn invalid_fee() {
+        let reserve = U256::from(1_000_000u64);
+        for fee_bps in [10_000, u16::MAX] {
+            assert!(matches!(
+                get_amount_in_with_fee(U256::from(1_000u64), reserve, reserve, fee_bps),
+                Err(Error::InvalidFee(fee)) if fee == fee_bps
+            ));
+            assert!(matches!(
+                get_amount_out_with_fee(U256::from(1_000u64), reserve, reserve, fee_bps),
+                Err(Error::InvalidFee(fee)) if fee == fee_bps
+            ));
+        }
+        assert!(get_amount_in_with_fee(U256::from(1_000u64), reserve, reserve, 9_999).is_ok());
+    }
+
+    #[test]
+    fn amounts_out() {
+        let reserves = [
+            (U256::from(1_000_000u64), U256::from(2_000_000u64)),
+            (U256::from(3_000_000u64), U256::from(1_000_000u64)),
+        ];
+        let amounts = get_amounts_out(U256::from(1_000u64), &reserves).unwrap();
+        assert_eq!(amounts.len(), 3);
+        assert_eq!(amounts[1], U256::from(1_992u64));
+        assert_eq!(amounts[2], get_amount_out(amounts[1], reserves[1].0, reserves[1].1).unwrap());
+    }
+}
@@ -0,0 +1,406 @@
+//! Uniswap v3 pool bindings and concentrated liquidity quoting math.
+//!
+//! The math matches `SqrtPriceMath` and `SwapMath` of the v3 core contracts, including their
+//! rounding, and operates on `Q64.96` square root prices.
+
+use crate::{
+    math::{div_rounding_up, mul_div, mul_div_rounding_up},
+    multicall::{aggregate, decode},
+    Error, Result,
+};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::SolCall;
+
+alloy_sol_types::sol! {
+    /// The read methods of a Uniswap v3 pool.
+    #[sol(rpc)]
+    interface IUniswapV3Pool {
+        function token0() external view returns (address);
+        function token1() external view returns (address);
+        function fee() external view returns (uint24);
+        function tickSpacing() external view returns (int24);
+        function liquidity() external view returns (uint128);
+        function slot0()
+            external
+            view
+            returns (
+                uint160 sqrtPriceX96,
+                int24 tick,
+                uint16 observationIndex,
+                uint16 observationCardinality,
+                uint16 observationCardinalityNext,
+                uint8 feeProtocol,
+                bool unlocked
+            );
+    }
+}
+
+/// The number of fractional bits of a `Q64.96` fixed point number.
+pub const RESOLUTION: usize = 96;
+
+/// `2**96`, i.e. one in `Q64.96`.
+pub const Q96: U256 = U256::from_limbs([0, 1 << 32, 0, 0]);
+
+/// The denominator of fees expressed in hundredths of a basis point.
+pub const FEE_DENOMINATOR: u32 = 1_000_000;
+
+/// The largest value of a `uint160`, the type of square root prices.
+const MAX_U160: U256 = U256::from_limbs([u64::MAX, u64::MAX, u32::MAX as u64, 0]);
+
+/// Returns the amount of `token0` between two square root prices for the given liquidity.
+pub fn get_amount0_delta(
+    sqrt_price_a_x96: U256,
+    sqrt_price_b_x96: U256,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<U256> {
+    let (lower, upper) = sorted(sqrt_price_a_x96, sqrt_price_b_x96);
+    if lower.is_zero() {
+        return Err(Error::InsufficientLiquidity);
+    }
+    let numerator1 = U256::from(liquidity) << RESOLUTION;
+    let numerator2 = upper - lower;
+    if round_up {
+        Ok(div_rounding_up(mul_div_rounding_up(numerator1, numerator2, upper)?, lower))
+    } else {
+        Ok(mul_div(numerator1, numerator2, upper)? / lower)
+    }
+}
+
+/// Returns the amount of `token1` between two square root prices for the given liquidity.
+pub fn get_amount1_delta(
+    sqrt_price_a_x96: U256,
+    sqrt_price_b_x96: U256,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<U256> {
+    let (lower, upper) = sorted(sqrt_price_a_x96, sqrt_price_b_x96);
+    let liquidity = U256::from(liquidity);
+    if round_up {
+        mul_div_rounding_up(liquidity, upper - lower, Q96)
+    } else {
+        mul_div(liquidity, upper - lower, Q96)
+    }
+}
+
+fn sorted(a: U256, b: U256) -> (U256, U256) {
+    if a > b {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+/// Returns the square root price after adding `amount_in` of the input token to the pool.
+///
+/// `zero_for_one` swaps `token0` for `token1`, which moves the price down.
+pub fn get_next_sqrt_price_from_input(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount_in: U256,
+    zero_for_one: bool,
+) -> Result<U256> {
+    if sqrt_price_x96.is_zero() || liquidity == 0 {
+        return Err(Error::InsufficientLiquidity);
+    }
+    if zero_for_one {
+        next_sqrt_price_from_amount0(sqrt_price_x96, liquidity, amount_in)
+    } else {
+        next_sqrt_price_from_amount1(sqrt_price_x96, liquidity, amount_in)
+    }
+}
+
+/// Adds `amount` of `token0`, rounding the price up.
+fn next_sqrt_price_from_amount0(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount: U256,
+) -> Result<U256> {
+    if amount.is_zero() {
+        return Ok(sqrt_price_x96);
+    }
+    let numerator1 = U256::from(liquidity) << RESOLUTION;
+    if let Some(denominator) =
+        amount.checked_mul(sqrt_price_x96).and_then(|product| numerator1.checked_add(product))
+    {
+        return mul_div_rounding_up(numerator1, sqrt_price_x96, denominator);
+    }
+    let denominator = (numerator1 / sqrt_price_x96).checked_add(amount).ok_or(Error::Overflow)?;
+    Ok(div_rounding_up(numerator1, denominator))
+}
+
+/// Adds `amount` of `token1`, rounding the price down.
+fn next_sqrt_price_from_amount1(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount: U256,
+) -> Result<U256> {
+    let quotient = mul_div(amount, Q96, U256::from(liquidity))?;
+    let next = sqrt_price_x96.checked_add(quotient).ok_or(Error::Overflow)?;
+    if next > MAX_U160 {
+        return Err(Error::Overflow);
+    }
+    Ok(next)
+}
+
+/// The result of a swap within a single tick range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapStep {
+    /// The square root price after the swap.
+    pub sqrt_price_next_x96: U256,
+    /// The input amount, including the fee.
+    pub amount_in: U256,
+    /// The output amount.
+    pub amount_out: U256,
+    /// The fee amount, in the input token.
+    pub fee_amount: U256,
+}
+
+/// Quotes a swap of exactly `amount_in`, fee included, against the active liquidity.
+///
+/// `fee` is expressed in hundredths of a basis point, e.g. `3000` for 0.3%.
+///
+/// The quote assumes that the swap does not cross an initialized tick, i.e. that the liquidity
+/// stays constant. This holds for swaps that are small relative to the liquidity around the
+/// current price; larger swaps must be quoted with the tick data of the pool, or on-chain.
+pub fn quote_exact_input_single_tick(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount_in: U256,
+    fee: u32,
+    zero_for_one: bool,
+) -> Result<SwapStep> {
+    if amount_in.is_zero() {
+        return Err(Error::InsufficientInputAmount);
+    }
+    if fee >= FEE_DENOMINATOR {
+        return Err(Error::Overflow);
+    }
+    let amount_in_less_fee =
+        mul_div(amount_in, U256::from(FEE_DENOMINATOR - fee), U256::from(FEE_DENOMINATOR))?;
+    let next = get_next_sqrt_price_from_input(
+        sqrt_price_x96,
+        liquidity,
+        amount_in_less_fee,
+        zero_for_one,
+    )?;
+    let (amount_in_used, amount_out) = if zero_for_one {
+        (
+            get_amount0_delta(next, sqrt_price_x96, liquidity, true)?,
+            get_amount1_delta(next, sqrt_price_x96, liquidity, false)?,
+        )
+    } else {
+        (
+            get_amount1_delta(sqrt_price_x96, next, liquidity, true)?,
+            get_amount0_delta(sqrt_price_x96, next, liquidity, false)?,
+        )
+    };
+    // the remainder of the rounding is kept by the pool as a fee, as in `SwapMath`
+    Ok(SwapStep {
+        sqrt_price_next_x96: next,
+        amount_in,
+        amount_out,
+        fee_amount: amount_in - amount_in_used.min(amount_in_less_fee),
+    })
+}
+
+/// Converts a `Q64.96` square root price to the price of `token0` in `token1`, adjusted for the
+/// token decimals.
+///
+/// The result is approximate and intended for display.
+pub fn sqrt_price_x96_to_price(sqrt_price_x96: U256, decimals0: u8, decimals1: u8) -> f64 {
+    let sqrt_price = u256_to_f64(sqrt_price_x96) / 2f64.powi(RESOLUTION as i32);
+    sqrt_price * sqrt_price * 10f64.powi(i32::from(decimals0) - i32::from(decimals1))
+}
+
+fn u256_to_f64(value: U256) -> f64 {
+    value.as_limbs().iter().rev().fold(0.0, |acc, &limb| acc * 2f64.powi(64) + limb as f64)
+}
+
+/// The current state of a Uniswap v3 pool, as used for quoting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolState {
+    /// The current `Q64.96` square root price.
+    pub sqrt_price_x96: U256,
+    /// The current tick.
+    pub tick: i32,
+    /// The active liquidity.
+    pub liquidity: u128,
+    /// The swap fee, in hundredths of a basis point.
+    pub fee: u32,
+}
+
+impl PoolState {
+    /// Quotes a swap of exactly `amount_in` against this state.
+    ///
+    /// See [`quote_exact_input_single_tick`].
+    pub fn quote_exact_input(&self, amount_in: U256, zero_for_one: bool) -> Result<SwapStep> {
+        quote_exact_input_single_tick(
+            self.sqrt_price_x96,
+            self.liquidity,
+            amount_in,
+            self.fee,
+            zero_for_one,
+        )
+    }
+}
+
+/// A Uniswap v3 pool.
+#[derive(Clone, Debug)]
+pub struct UniswapV3Pool<P, N = Ethereum> {
+    pool: IUniswapV3Pool::IUniswapV3PoolInstance<(), P, N>,
+}
+
+impl<P: Provider<N>, N: Network> UniswapV3Pool<P, N> {
+    /// Creates a new pool at the given address.
+    pub const fn new(address: Address, provider: P) -> Self {
+        Self { pool: IUniswapV3Pool::new(address, provider) }
+    }
+
+    /// Returns the address of the pool.
+    pub const fn address(&self) -> &Address {
+        self.pool.address()
+    }
+
+    /// Returns the addresses of `token0` and `token1`.
+    ///
+    /// Both tokens are read in a single call through Multicall3.
+    pub async fn tokens(&self) -> Result<(Address, Address)> {
+        let address = *self.address();
+        let [token0, token1] = aggregate(
+            self.pool.provider(),
+            [
+                (address, IUniswapV3Pool::token0Call {}.abi_encode()),
+                (address, IUniswapV3Pool::token1Call {}.abi_encode()),
+            ],
+        )
+        .await?;
+        Ok((
+            decode::<IUniswapV3Pool::token0Call>(&token0)?._0,
+            decode::<IUniswapV3Pool::token1Call>(&token1)?._0,
+        ))
+    }
+
+    /// Returns the current price, tick, active liquidity and fee of the pool.
+    ///
+    /// The values are read in a single call through Multicall3 at the latest block.
+    pub async fn state(&self) -> Result<PoolState> {
+        let address = *self.address();
+        let [slot0, liquidity, fee] = aggregate(
+            self.pool.provider(),
+            [
+                (address, IUniswapV3Pool::slot0Call {}.abi_encode()),
+                (address, IUniswapV3Pool::liquidityCall {}.abi_encode()),
+                (address, IUniswapV3Pool::feeCall {}.abi_encode()),
+            ],
+        )
+        .await?;
+        let slot0 = decode::<IUniswapV3Pool::slot0Call>(&slot0)?;
+        Ok(PoolState {
+            sqrt_price_x96: U256::from(slot0.sqrtPriceX96),
+            tick: slot0.tick.as_i32(),
+            liquidity: decode::<IUniswapV3Pool::liquidityCall>(&liquidity)?._0,
+            fee: decode::<IUniswapV3Pool::feeCall>(&fee)?._0.to(),
+        })
+    }
+
+    /// Quotes a swap of exactly `amount_in` against the current state of the pool.
+    ///
+    /// See [`quote_exact_input_single_tick`].
+    pub async fn quote_exact_input(&self, amount_in: U256, zero_for_one: bool) -> Result<SwapStep> {
+        self.state().await?.quote_exact_input(amount_in, zero_for_one)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // encodePriceSqrt(1, 1) and encodePriceSqrt(121, 100)
+    const PRICE_1: U256 = Q96;
+    const PRICE_121_100: U256 = U256::from_limbs([0x9999_9999_9999_9999, 0x1_1999_9999, 0, 0]);
+
+    fn u(s: &str) -> U256 {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Q96, U256::from(1) << 96);
+        assert_eq!(MAX_U160, (U256::from(1) << 160) - U256::from(1));
+        assert_eq!(PRICE_121_100, u("87150978765690771352898345369"));
+    }
+
+    #[test]
+    fn amount_deltas() {
+        let liquidity = 10u128.pow(18);
+        assert_eq!(
+            get_amount0_delta(PRICE_1, PRICE_121_100, liquidity, true).unwrap(),
+            u("90909090909090910")
+        );
+        assert_eq!(
+            get_amount0_delta(PRICE_121_100, PRICE_1, liquidity, false).unwrap(),
+            u("90909090909090909")
+        );
+        assert_eq!(
+            get_amount1_delta(PRICE_1, PRICE_121_100, liquidity, true).unwrap(),
+            u("100000000000000000")
+        );
+        assert_eq!(
+            get_amount1_delta(PRICE_1, PRICE_121_100, liquidity, false).unwrap(),
+            u("99999999999999999")
+        );
+        assert_eq!(get_amount0_delta(PRICE_1, PRICE_1, liquidity, true).unwrap(), U256::ZERO);
+    }
+
+    #[test]
+    fn next_sqrt_price() {
+        let liquidity = 10u128.pow(18);
+        let amount = U256::from(10u64.pow(17));
+        assert_eq!(
+            get_next_sqrt_price_from_input(PRICE_1, liquidity, amount, false).unwrap(),
+            PRICE_121_100
+        );
+        assert_eq!(
+            get_next_sqrt_price_from_input(PRICE_1, liquidity, amount, true).unwrap(),
+            u("72025602285694852357767227579")
+        );
+        assert_eq!(
+            get_next_sqrt_price_from_input(PRICE_1, liquidity, U256::ZERO, true).unwrap(),
+            PRICE_1
+        );
+        assert!(matches!(
+            get_next_sqrt_price_from_input(PRICE_1, 0, amount, true),
+            Err(Error::InsufficientLiquidity)
+        ));
+        assert!(matches!(
+            get_next_sqrt_price_from_input(MAX_U160, 1, U256::from(1) << 96, false),
+            Err(Error::Overflow)
+        ));
+    }
+
+    #[test]
+    fn quote_single_tick() {
+        let state =
+            PoolState { sqrt_price_x96: PRICE_1, tick: 0, liquidity: 10u128.pow(18), fee: 3000 };
+        let amount_in = U256::from(10u64.pow(15));
+        let step = state.quote_exact_input(amount_in, true).unwrap();
+        assert_eq!(step.fee_amount, U256::from(3 * 10u64.pow(12)));
+        assert_eq!(step.sqrt_price_next_x96, u("79149250711305166342700278159"));
+        assert_eq!(step.amount_out, U256::from(996_006_981_039_903u64));
+
+        // the price is one, so the swap is symmetric up to the price movement direction
+        let reverse = state.quote_exact_input(amount_in, false).unwrap();
+        assert_eq!(reverse.sqrt_price_next_x96, u("79307152992291059138124713654"));
+        assert_eq!(reverse.amount_out, step.amount_out);
+    }
+
+    #[test]
+    fn price() {
+        assert_eq!(sqrt_price_x96_to_price(PRICE_1, 18, 18), 1.0);
+        assert!((sqrt_price_x96_to_price(PRICE_121_100, 18, 18) - 1.21).abs() < 1e-12);
+        assert!((sqrt_price_x96_to_price(PRICE_1, 18, 6) - 1e12).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,297 @@
+//! Well-known addresses of system contracts and precompiles, per chain and hardfork.
+//!
+//! [`ChainConstants`] collects the addresses defined across the EIP modules of this crate, so they
+//! can be queried by chain id and hardfork instead of being hardcoded by every consumer.
+
+pub use crate::hardfork::Hardfork;
+
+use crate::{eip2935, eip4788, eip6110, eip7002, eip7251};
+use alloc::vec::Vec;
+use alloy_primitives::{address, Address, ChainId};
+
+/// The chain id of Ethereum mainnet.
+pub const MAINNET_CHAIN_ID: ChainId = 1;
+
+/// The chain id of the Sepolia testnet.
+pub const SEPOLIA_CHAIN_ID: ChainId = 11_155_111;
+
+/// The chain id of the Holesky testnet.
+pub const HOLESKY_CHAIN_ID: ChainId = 17_000;
+
+/// Sepolia deposit contract address.
+pub const SEPOLIA_DEPOSIT_CONTRACT_ADDRESS: Address =
+    address!("7f02C3E3c98b133055B8B348B2Ac625669Ed295D");
+
+/// Holesky deposit contract address.
+pub const HOLESKY_DEPOSIT_CONTRACT_ADDRESS: Address =
+    address!("4242424242424242424242424242424242424242");
+
+/// The gas schedule of a [`Precompile`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PrecompileGas {
+    /// A fixed cost per call.
+    Fixed(u64),
+    /// A base cost plus a cost per 32-byte word of input.
+    Linear {
+        /// The base cost.
+        base: u64,
+        /// The cost per word of input.
+        per_word: u64,
+    },
+    /// A base cost plus a cost per pair of points of input.
+    Pairing {
+        /// The base cost.
+        base: u64,
+        /// The cost per pair.
+        per_pair: u64,
+        /// The size of a pair in the input, in bytes.
+        pair_size: usize,
+    },
+    /// A cost that depends on the contents of the input, e.g. the exponent length of `modexp`.
+    Dynamic,
+}
+
+impl PrecompileGas {
+    /// Returns the cost of a call with the given input, or `None` for [`Dynamic`](Self::Dynamic)
+    /// schedules.
+    pub const fn cost(&self, input: &[u8]) -> Option<u64> {
+        match *self {
+            Self::Fixed(cost) => Some(cost),
+            Self::Linear { base, per_word } => {
+                Some(base + (input.len() as u64).div_ceil(32) * per_word)
+            }
+            Self::Pairing { base, per_pair, pair_size } => {
+                let Some(pairs) = input.len().checked_div(pair_size) else { return None };
+                Some(base + pairs as u64 * per_pair)
+            }
+            Self::Dynamic => None,
+        }
+    }
+}
+
+/// A precompiled contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Precompile {
+    /// The name of the precompile, e.g. `ecrecover`.
+    pub name: &'static str,
+    /// The address of the precompile.
+    pub address: Address,
+    /// The gas schedule of the precompile.
+    pub gas: PrecompileGas,
+}
+
+impl Precompile {
+    const fn new(name: &'static str, address: u8, gas: PrecompileGas) -> Self {
+        Self { name, address: Address::with_last_byte(address), gas }
+    }
+}
+
+/// The addresses of the system contracts of a chain.
+///
+/// Contracts that are not active at the hardfork, or whose address is not known for the chain,
+/// are `None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SystemContracts {
+    /// The [EIP-4788](https://eips.ethereum.org/EIPS/eip-4788) beacon roots contract.
+    pub beacon_roots: Option<Address>,
+    /// The [EIP-2935](https://eips.ethereum.org/EIPS/eip-2935) history storage contract.
+    pub history_storage: Option<Address>,
+    /// The beacon chain deposit contract.
+    pub deposit_contract: Option<Address>,
+    /// The [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002) withdrawal requests contract.
+    pub withdrawal_requests: Option<Address>,
+    /// The [EIP-7251](https://eips.ethereum.org/EIPS/eip-7251) consolidation requests contract.
+    pub consolidation_requests: Option<Address>,
+}
+
+/// The well-known addresses of a chain at a hardfork.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChainConstants {
+    chain_id: ChainId,
+    hardfork: Hardfork,
+}
+
+impl ChainConstants {
+    /// Creates the constants of the given chain at the given hardfork.
+    pub const fn new(chain_id: ChainId, hardfork: Hardfork) -> Self {
+        Self { chain_id, hardfork }
+    }
+
+    /// Returns the constants of Ethereum mainnet at the given hardfork.
+    pub const fn mainnet(hardfork: Hardfork) -> Self {
+        Self::new(MAINNET_CHAIN_ID, hardfork)
+    }
+
+    /// Returns the chain id.
+    pub const fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    /// Returns the hardfork.
+    pub const fn hardfork(&self) -> Hardfork {
+        self.hardfork
+    }
+
+    /// Returns the address of the beacon chain deposit contract, if known for the chain.
+    pub const fn deposit_contract(&self) -> Option<Address> {
+        match self.chain_id {
+            MAINNET_CHAIN_ID => Some(eip6110::MAINNET_DEPOSIT_CONTRACT_ADDRESS),
+            SEPOLIA_CHAIN_ID => Some(SEPOLIA_DEPOSIT_CONTRACT_ADDRESS),
+            HOLESKY_CHAIN_ID => Some(HOLESKY_DEPOSIT_CONTRACT_ADDRESS),
+            _ => None,
+        }
+    }
+
+    /// Returns the addresses of the system contracts.
+    ///
+    /// The EIP-4788, EIP-2935, EIP-7002 and EIP-7251 contracts are deployed at the same address
+    /// on every chain that activates them.
+    pub const fn system_contracts(&self) -> SystemContracts {
+        let cancun = self.hardfork as u8 >= Hardfork::Cancun as u8;
+        let prague = self.hardfork as u8 >= Hardfork::Prague as u8;
+        SystemContracts {
+            beacon_roots: if cancun { Some(eip4788::BEACON_ROOTS_ADDRESS) } else { None },
+            history_storage: if prague { Some(eip2935::HISTORY_STORAGE_ADDRESS) } else { None },
+            deposit_contract: self.deposit_contract(),
+            withdrawal_requests: if prague {
+                Some(eip7002::WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS)
+            } else {
+                None
+            },
+            consolidation_requests: if prague {
+                Some(eip7251::CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS)
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Returns the precompiles active at the hardfork, ordered by address.
+    pub fn precompiles(&self) -> Vec<Precompile> {
+        let fork = self.hardfork;
+        let mut precompiles = vec![
+            Precompile::new("ecrecover", 0x01, PrecompileGas::Fixed(3_000)),
+            Precompile::new("sha256", 0x02, PrecompileGas::Linear { base: 60, per_word: 12 }),
+            Precompile::new("ripemd160", 0x03, PrecompileGas::Linear { base: 600, per_word: 120 }),
+            Precompile::new("identity", 0x04, PrecompileGas::Linear { base: 15, per_word: 3 }),
+        ];
+        if fork >= Hardfork::Byzantium {
+            // EIP-1108 reduced the alt_bn128 costs in Istanbul
+            let (add, mul, pairing_base, per_pair) = if fork >= Hardfork::Istanbul {
+                (150, 6_000, 45_000, 34_000)
+            } else {
+                (500, 40_000, 100_000, 80_000)
+            };
+            precompiles.extend([
+                Precompile::new("modexp", 0x05, PrecompileGas::Dynamic),
+                Precompile::new("ecadd", 0x06, PrecompileGas::Fixed(add)),
+                Precompile::new("ecmul", 0x07, PrecompileGas::Fixed(mul)),
+                Precompile::new(
+                    "ecpairing",
+                    0x08,
+                    PrecompileGas::Pairing { base: pairing_base, per_pair, pair_size: 192 },
+                ),
+            ]);
+        }
+        if fork >= Hardfork::Istanbul {
+            // one gas per round, read from the input
+            precompiles.push(Precompile::new("blake2f", 0x09, PrecompileGas::Dynamic));
+        }
+        if fork >= Hardfork::Cancun {
+            precompiles.push(Precompile::new(
+                "kzg_point_evaluation",
+                0x0a,
+                PrecompileGas::Fixed(50_000),
+            ));
+        }
+        if fork >= Hardfork::Prague {
+            precompiles.extend([
+                Precompile::new("bls12_g1add", 0x0b, PrecompileGas::Fixed(375)),
+                Precompile::new("bls12_g1msm", 0x0c, PrecompileGas::Dynamic),
+                Precompile::new("bls12_g2add", 0x0d, PrecompileGas::Fixed(600)),
+                Precompile::new("bls12_g2msm", 0x0e, PrecompileGas::Dynamic),
+                Precompile::new(
+                    "bls12_pairing_check",
+                    0x0f,
+                    PrecompileGas::Pairing { base: 37_700, per_pair: 32_600, pair_size: 384 },
+                ),
+                Precompile::new("bls12_map_fp_to_g1", 0x10, PrecompileGas::Fixed(5_500)),
+                Precompile::new("bls12_map_fp2_to_g2", 0x11, PrecompileGas::Fixed(23_800)),
+            ]);
+        }
+        precompiles
+    }
+
+    /// Returns the precompile at `address`, if it is active at the hardfork.
+    pub fn precompile(&self, address: Address) -> Option<Precompile> {
+        self.precompiles().into_iter().find(|precompile| precompile.address == address)
+    }
+
+    /// Returns `true` if `address` is a precompile at the hardfork.
+    pub fn is_precompile(&self, address: Address) -> bool {
+        self.precompile(address).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_contracts_by_fork() {
+        let cancun = ChainConstants::mainnet(Hardfork::Cancun).system_contracts();
+        assert_eq!(cancun.beacon_roots, Some(eip4788::BEACON_ROOTS_ADDRESS));
+        assert_eq!(cancun.deposit_contract, Some(eip6110::MAINNET_DEPOSIT_CONTRACT_ADDRESS));
+        assert_eq!(cancun.history_storage, None);
+        assert_eq!(cancun.withdrawal_requests, None);
+
+        let prague = ChainConstants::new(SEPOLIA_CHAIN_ID, Hardfork::Prague).system_contracts();
+        assert_eq!(prague.deposit_contract, Some(SEPOLIA_DEPOSIT_CONTRACT_ADDRESS));
+        assert_eq!(prague.history_storage, Some(eip2935::HISTORY_STORAGE_ADDRESS));
+        assert_eq!(
+            prague.consolidation_requests,
+            Some(eip7251::CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS)
+        );
+
+        let unknown = ChainConstants::new(1337, Hardfork::Frontier).system_contracts();
+        assert_eq!(unknown, SystemContracts::default());
+    }
+
+    #[test]
+    fn precompiles_by_fork() {
+        let count = |fork| ChainConstants::mainnet(fork).precompiles().len();
+        assert_eq!(count(Hardfork::Frontier), 4);
+        assert_eq!(count(Hardfork::Byzantium), 8);
+        assert_eq!(count(Hardfork::Istanbul), 9);
+        assert_eq!(count(Hardfork::Cancun), 10);
+        assert_eq!(count(Hardfork::Prague), 17);
+
+        let prague = ChainConstants::mainnet(Hardfork::Prague);
+        for (i, precompile) in prague.precompiles().iter().enumerate() {
+            assert_eq!(precompile.address, Address::with_last_byte(i as u8 + 1));
+        }
+        assert!(prague.is_precompile(Address::with_last_byte(0x11)));
+        assert!(!prague.is_precompile(Address::with_last_byte(0x12)));
+        assert!(
+            !ChainConstants::mainnet(Hardfork::Cancun).is_precompile(Address::with_last_byte(0x0b))
+        );
+    }
+
+    #[test]
+    fn precompile_gas() {
+        let byzantium = ChainConstants::mainnet(Hardfork::Byzantium);
+        let istanbul = ChainConstants::mainnet(Hardfork::Istanbul);
+        let ecmul = Address::with_last_byte(0x07);
+        assert_eq!(byzantium.precompile(ecmul).unwrap().gas.cost(&[]), Some(40_000));
+        assert_eq!(istanbul.precompile(ecmul).unwrap().gas.cost(&[]), Some(6_000));
+
+        let pairing = istanbul.precompile(Address::with_last_byte(0x08)).unwrap();
+        assert_eq!(pairing.gas.cost(&[0; 384]), Some(45_000 + 2 * 34_000));
+
+        let sha256 = istanbul.precompile(Address::with_last_byte(0x02)).unwrap();
+        assert_eq!(sha256.gas.cost(&[0; 33]), Some(60 + 2 * 12));
+
+        let modexp = istanbul.precompile(Address::with_last_byte(0x05)).unwrap();
+        assert_eq!(modexp.gas.cost(&[]), None);
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! See also [EIP-6110](https://eips.ethereum.org/EIPS/eip-6110): Supply validator deposits on chain
 
+use crate::eip7685::TypedRequest;
+use alloc::vec::Vec;
 use alloy_primitives::{address, Address, FixedBytes, B256};
 
 /// Mainnet deposit contract address.
@@ -34,6 +36,30 @@ pub struct DepositRequest {
     pub index: u64,
 }
 
+impl TypedRequest for DepositRequest {
+    const REQUEST_TYPE: u8 = DEPOSIT_REQUEST_TYPE;
+    const ENCODED_LENGTH: usize = 48 + 32 + 8 + 96 + 8;
+
+    fn encode_request_data(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.pubkey.as_slice());
+        out.extend_from_slice(self.withdrawal_credentials.as_slice());
+        out.extend_from_slice(&self.amount.to_le_bytes());
+        out.extend_from_slice(self.signature.as_slice());
+        out.extend_from_slice(&self.index.to_le_bytes());
+    }
+
+    fn decode_request_data(data: &[u8]) -> Self {
+        assert_eq!(data.len(), Self::ENCODED_LENGTH);
+        Self {
+            pubkey: FixedBytes::from_slice(&data[..48]),
+            withdrawal_credentials: B256::from_slice(&data[48..80]),
+            amount: u64::from_le_bytes(data[80..88].try_into().unwrap()),
+            signature: FixedBytes::from_slice(&data[88..184]),
+            index: u64::from_le_bytes(data[184..].try_into().unwrap()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
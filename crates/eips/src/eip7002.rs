@@ -2,6 +2,8 @@
 //!
 //! See also [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002): Execution layer triggerable withdrawals
 
+use crate::eip7685::TypedRequest;
+use alloc::vec::Vec;
 use alloy_primitives::{address, bytes, Address, Bytes, FixedBytes};
 
 /// The caller to be used when calling the EIP-7002 withdrawal requests contract at the end of the
@@ -38,6 +40,26 @@ pub struct WithdrawalRequest {
     pub amount: u64,
 }
 
+impl TypedRequest for WithdrawalRequest {
+    const REQUEST_TYPE: u8 = WITHDRAWAL_REQUEST_TYPE;
+    const ENCODED_LENGTH: usize = 20 + 48 + 8;
+
+    fn encode_request_data(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.source_address.as_slice());
+        out.extend_from_slice(self.validator_pubkey.as_slice());
+        out.extend_from_slice(&self.amount.to_le_bytes());
+    }
+
+    fn decode_request_data(data: &[u8]) -> Self {
+        assert_eq!(data.len(), Self::ENCODED_LENGTH);
+        Self {
+            source_address: Address::from_slice(&data[..20]),
+            validator_pubkey: FixedBytes::from_slice(&data[20..68]),
+            amount: u64::from_le_bytes(data[68..].try_into().unwrap()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,6 +2,8 @@
 //!
 //! See also [EIP-7251](https://eips.ethereum.org/EIPS/eip-7251): Increase the MAX_EFFECTIVE_BALANCE
 
+use crate::eip7685::TypedRequest;
+use alloc::vec::Vec;
 use alloy_primitives::{address, bytes, Address, Bytes, FixedBytes};
 
 /// The address for the EIP-7251 consolidation requests contract.
@@ -31,6 +33,26 @@ pub struct ConsolidationRequest {
     pub target_pubkey: FixedBytes<48>,
 }
 
+impl TypedRequest for ConsolidationRequest {
+    const REQUEST_TYPE: u8 = CONSOLIDATION_REQUEST_TYPE;
+    const ENCODED_LENGTH: usize = 20 + 48 + 48;
+
+    fn encode_request_data(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.source_address.as_slice());
+        out.extend_from_slice(self.source_pubkey.as_slice());
+        out.extend_from_slice(self.target_pubkey.as_slice());
+    }
+
+    fn decode_request_data(data: &[u8]) -> Self {
+        assert_eq!(data.len(), Self::ENCODED_LENGTH);
+        Self {
+            source_address: Address::from_slice(&data[..20]),
+            source_pubkey: FixedBytes::from_slice(&data[20..68]),
+            target_pubkey: FixedBytes::from_slice(&data[68..]),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,6 +4,7 @@
 
 use alloc::vec::Vec;
 use alloy_primitives::{b256, Bytes, B256};
+use core::fmt;
 use derive_more::{Deref, DerefMut, From, IntoIterator};
 
 /// The empty requests hash.
@@ -110,8 +111,92 @@ impl Requests {
     pub fn extend(&mut self, other: Self) {
         self.0.extend(other.take());
     }
+
+    /// Returns the `request_data` of the request with the given `request_type`, if any.
+    pub fn request_data(&self, request_type: u8) -> Option<&[u8]> {
+        self.0.iter().find(|req| req.first() == Some(&request_type)).map(|req| &req[1..])
+    }
+
+    /// Adds the given typed requests into the container, encoded as a single request of type
+    /// [`TypedRequest::REQUEST_TYPE`].
+    ///
+    /// Empty lists are omitted.
+    pub fn push_typed_requests<R: TypedRequest>(&mut self, requests: &[R]) {
+        if requests.is_empty() {
+            return;
+        }
+        let mut request = Vec::with_capacity(1 + requests.len() * R::ENCODED_LENGTH);
+        request.push(R::REQUEST_TYPE);
+        for req in requests {
+            req.encode_request_data(&mut request);
+        }
+        self.0.push(request.into());
+    }
+
+    /// Decodes all requests of type [`TypedRequest::REQUEST_TYPE`] from the container.
+    ///
+    /// Returns an empty list if the container does not hold any requests of that type.
+    pub fn decode_typed_requests<R: TypedRequest>(&self) -> Result<Vec<R>, RequestDecodeError> {
+        let Some(data) = self.request_data(R::REQUEST_TYPE) else { return Ok(Vec::new()) };
+        if data.len() % R::ENCODED_LENGTH != 0 {
+            return Err(RequestDecodeError {
+                request_type: R::REQUEST_TYPE,
+                expected_length: R::ENCODED_LENGTH,
+                length: data.len(),
+            });
+        }
+        Ok(data.chunks_exact(R::ENCODED_LENGTH).map(R::decode_request_data).collect())
+    }
 }
 
+/// An [EIP-7685] request type with a fixed size `request_data` encoding.
+///
+/// The `request_data` of a request type is the concatenation of the encodings of the individual
+/// requests, as emitted by the system contracts or, for deposits, as parsed from the deposit
+/// contract logs. The encodings match the SSZ encodings of the respective types.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub trait TypedRequest: Sized {
+    /// The EIP-7685 `request_type` byte.
+    const REQUEST_TYPE: u8;
+
+    /// The length of a single encoded request.
+    const ENCODED_LENGTH: usize;
+
+    /// Appends the encoding of this request to `out`.
+    fn encode_request_data(&self, out: &mut Vec<u8>);
+
+    /// Decodes a single request from `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is not exactly [`ENCODED_LENGTH`](Self::ENCODED_LENGTH) bytes long.
+    fn decode_request_data(data: &[u8]) -> Self;
+}
+
+/// Error returned when the `request_data` of a [`TypedRequest`] type has an invalid length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequestDecodeError {
+    /// The request type that failed to decode.
+    pub request_type: u8,
+    /// The expected length of a single request.
+    pub expected_length: usize,
+    /// The length of the `request_data`.
+    pub length: usize,
+}
+
+impl fmt::Display for RequestDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid request data length {} for request type {}, expected a multiple of {}",
+            self.length, self.request_type, self.expected_length
+        )
+    }
+}
+
+impl core::error::Error for RequestDecodeError {}
+
 /// A list of requests or a precomputed requests hash.
 ///
 /// For testing purposes, the `Hash` variant stores a precomputed requests hash. This can be useful
@@ -187,6 +272,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn typed_requests_roundtrip() {
+        use crate::{
+            eip6110::DepositRequest, eip7002::WithdrawalRequest, eip7251::ConsolidationRequest,
+        };
+        use alloy_primitives::{Address, FixedBytes};
+
+        let deposits = vec![
+            DepositRequest {
+                pubkey: FixedBytes::repeat_byte(1),
+                withdrawal_credentials: B256::repeat_byte(2),
+                amount: 32_000_000_000,
+                signature: FixedBytes::repeat_byte(3),
+                index: 7,
+            };
+            2
+        ];
+        let withdrawals = vec![WithdrawalRequest {
+            source_address: Address::repeat_byte(4),
+            validator_pubkey: FixedBytes::repeat_byte(5),
+            amount: 1,
+        }];
+        let consolidations: Vec<ConsolidationRequest> = Vec::new();
+
+        let mut requests = Requests::default();
+        requests.push_typed_requests(&deposits);
+        requests.push_typed_requests(&withdrawals);
+        requests.push_typed_requests(&consolidations);
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests.request_data(0x00).unwrap().len(), 2 * 192);
+        // amounts are little endian
+        assert_eq!(requests.request_data(0x01).unwrap()[68..], [1, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(requests.decode_typed_requests::<DepositRequest>().unwrap(), deposits);
+        assert_eq!(requests.decode_typed_requests::<WithdrawalRequest>().unwrap(), withdrawals);
+        assert!(requests.decode_typed_requests::<ConsolidationRequest>().unwrap().is_empty());
+
+        let invalid = Requests::new(vec![Bytes::from(vec![0x01; 10])]);
+        assert_eq!(
+            invalid.decode_typed_requests::<WithdrawalRequest>().unwrap_err(),
+            RequestDecodeError { request_type: 0x01, expected_length: 76, length: 9 }
+        );
+    }
+
     #[test]
     #[cfg(feature = "sha2")]
     fn test_consistent_requests_hash() {
@@ -1,6 +1,6 @@
 //! Contains constants and utility functions for [EIP-7840](https://github.com/ethereum/EIPs/tree/master/EIPS/eip-7840.md)
 
-use crate::{eip4844, eip7691};
+use crate::{eip4844, eip7691, Hardfork};
 
 const DEFAULT_BLOB_FEE_GETTER: fn() -> u128 = || eip4844::BLOB_TX_MIN_BLOB_GASPRICE;
 const IS_DEFAULT_BLOB_FEE: fn(&u128) -> bool = |&x| x == eip4844::BLOB_TX_MIN_BLOB_GASPRICE;
@@ -50,6 +50,35 @@ impl BlobParams {
         }
     }
 
+    /// Returns the default [`BlobParams`] introduced by the given hardfork.
+    ///
+    /// Returns `None` for hardforks that did not change the blob parameters.
+    pub const fn for_fork(fork: Hardfork) -> Option<Self> {
+        match fork {
+            Hardfork::Cancun => Some(Self::cancun()),
+            Hardfork::Prague => Some(Self::prague()),
+            _ => None,
+        }
+    }
+
+    /// Returns the target blob gas per block.
+    #[inline]
+    pub const fn target_blob_gas_per_block(&self) -> u64 {
+        eip4844::DATA_GAS_PER_BLOB * self.target_blob_count
+    }
+
+    /// Returns the maximum blob gas per block.
+    #[inline]
+    pub const fn max_blob_gas_per_block(&self) -> u64 {
+        eip4844::DATA_GAS_PER_BLOB * self.max_blob_count
+    }
+
+    /// Returns whether the given blob count is within the per block limit.
+    #[inline]
+    pub const fn is_valid_blob_count(&self, blob_count: u64) -> bool {
+        blob_count <= self.max_blob_count
+    }
+
     /// Calculates the `excess_blob_gas` value for the next block based on the current block
     /// `excess_blob_gas` and `blob_gas_used`.
     #[inline]
@@ -58,8 +87,7 @@ impl BlobParams {
         excess_blob_gas: u64,
         blob_gas_used: u64,
     ) -> u64 {
-        (excess_blob_gas + blob_gas_used)
-            .saturating_sub(eip4844::DATA_GAS_PER_BLOB * self.target_blob_count)
+        (excess_blob_gas + blob_gas_used).saturating_sub(self.target_blob_gas_per_block())
     }
 
     /// Calculates the blob fee for block based on its `excess_blob_gas`.
@@ -68,3 +96,30 @@ impl BlobParams {
         eip4844::fake_exponential(self.min_blob_fee, excess_blob_gas as u128, self.update_fraction)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fork_params() {
+        assert_eq!(BlobParams::for_fork(Hardfork::Cancun), Some(BlobParams::cancun()));
+        assert_eq!(BlobParams::for_fork(Hardfork::Prague), Some(BlobParams::prague()));
+        assert_eq!(BlobParams::for_fork(Hardfork::Shanghai), None);
+
+        let prague = BlobParams::prague();
+        assert_eq!(prague.target_blob_gas_per_block(), 6 * eip4844::DATA_GAS_PER_BLOB);
+        assert_eq!(prague.max_blob_gas_per_block(), 9 * eip4844::DATA_GAS_PER_BLOB);
+        assert!(prague.is_valid_blob_count(9));
+        assert!(!BlobParams::cancun().is_valid_blob_count(7));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_blob_params() {
+        let params: BlobParams =
+            serde_json::from_str(r#"{"target":6,"max":9,"baseFeeUpdateFraction":5007716}"#)
+                .unwrap();
+        assert_eq!(params, BlobParams::prague());
+    }
+}
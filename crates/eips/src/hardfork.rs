@@ -0,0 +1,161 @@
+//! Ethereum hardforks.
+
+use core::{fmt, str::FromStr};
+
+/// An Ethereum mainnet hardfork, ordered by activation.
+///
+/// This is the hardfork used across the crates of this workspace, e.g. to select the
+/// [`BlobParams`](crate::eip7840::BlobParams), the
+/// [`IntrinsicGasSchedule`](crate::IntrinsicGasSchedule) or the
+/// [`ChainConstants`](crate::ChainConstants) of a chain.
+///
+/// The [`Display`](fmt::Display) and [`FromStr`] implementations use the camel case names of the
+/// hardforks as found in chain configs, e.g. `"spuriousDragon"` or `"cancun"`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Hardfork {
+    /// The Frontier hardfork, i.e. the genesis of mainnet.
+    Frontier,
+    /// The Homestead hardfork, which introduced `DELEGATECALL` and increased the cost of contract
+    /// creations.
+    Homestead,
+    /// The DAO hardfork.
+    Dao,
+    /// The Tangerine Whistle hardfork, which repriced IO-heavy opcodes ([EIP-150]).
+    ///
+    /// [EIP-150]: https://eips.ethereum.org/EIPS/eip-150
+    Tangerine,
+    /// The Spurious Dragon hardfork, which introduced replay protection ([EIP-155]).
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    SpuriousDragon,
+    /// The Byzantium hardfork, which introduced `RETURNDATASIZE`, `RETURNDATACOPY`, `STATICCALL`,
+    /// `REVERT` and the `modexp` and alt_bn128 precompiles.
+    Byzantium,
+    /// The Constantinople hardfork, which introduced the shift opcodes, `EXTCODEHASH` and
+    /// `CREATE2`.
+    Constantinople,
+    /// The Petersburg hardfork, which removed net gas metering from Constantinople.
+    Petersburg,
+    /// The Istanbul hardfork, which introduced `CHAINID`, `SELFBALANCE` and `blake2f`, and
+    /// reduced the cost of calldata and the alt_bn128 precompiles.
+    Istanbul,
+    /// The Muir Glacier hardfork, which delayed the difficulty bomb.
+    MuirGlacier,
+    /// The Berlin hardfork, which introduced access lists.
+    Berlin,
+    /// The London hardfork, which introduced the base fee and `BASEFEE`.
+    London,
+    /// The Arrow Glacier hardfork, which delayed the difficulty bomb.
+    ArrowGlacier,
+    /// The Gray Glacier hardfork, which delayed the difficulty bomb.
+    GrayGlacier,
+    /// The Paris hardfork, i.e. the merge.
+    Paris,
+    /// The Shanghai hardfork, which introduced withdrawals, `PUSH0` and the init code cost.
+    Shanghai,
+    /// The Cancun hardfork, which introduced blob transactions, transient storage, `MCOPY`, the
+    /// beacon roots contract and the point evaluation precompile.
+    Cancun,
+    /// The Prague hardfork, which introduced authorizations, the calldata floor cost, the history
+    /// storage and request contracts and the BLS12-381 precompiles.
+    #[default]
+    Prague,
+    /// The Osaka hardfork.
+    Osaka,
+}
+
+impl Hardfork {
+    /// All hardforks, ordered by activation.
+    pub const ALL: [Self; 19] = [
+        Self::Frontier,
+        Self::Homestead,
+        Self::Dao,
+        Self::Tangerine,
+        Self::SpuriousDragon,
+        Self::Byzantium,
+        Self::Constantinople,
+        Self::Petersburg,
+        Self::Istanbul,
+        Self::MuirGlacier,
+        Self::Berlin,
+        Self::London,
+        Self::ArrowGlacier,
+        Self::GrayGlacier,
+        Self::Paris,
+        Self::Shanghai,
+        Self::Cancun,
+        Self::Prague,
+        Self::Osaka,
+    ];
+
+    /// Returns the camel case name of the hardfork, e.g. `"spuriousDragon"`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Frontier => "frontier",
+            Self::Homestead => "homestead",
+            Self::Dao => "dao",
+            Self::Tangerine => "tangerine",
+            Self::SpuriousDragon => "spuriousDragon",
+            Self::Byzantium => "byzantium",
+            Self::Constantinople => "constantinople",
+            Self::Petersburg => "petersburg",
+            Self::Istanbul => "istanbul",
+            Self::MuirGlacier => "muirGlacier",
+            Self::Berlin => "berlin",
+            Self::London => "london",
+            Self::ArrowGlacier => "arrowGlacier",
+            Self::GrayGlacier => "grayGlacier",
+            Self::Paris => "paris",
+            Self::Shanghai => "shanghai",
+            Self::Cancun => "cancun",
+            Self::Prague => "prague",
+            Self::Osaka => "osaka",
+        }
+    }
+}
+
+impl fmt::Display for Hardfork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error thrown when parsing a [`Hardfork`] from an unknown name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseHardforkError(alloc::string::String);
+
+impl fmt::Display for ParseHardforkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown hardfork: {:?}", self.0)
+    }
+}
+
+impl core::error::Error for ParseHardforkError {}
+
+impl FromStr for Hardfork {
+    type Err = ParseHardforkError;
+
+    /// Parses the name of a hardfork, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|fork| fork.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseHardforkError(s.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_hardfork() {
+        for fork in Hardfork::ALL {
+            assert_eq!(fork.to_string().parse::<Hardfork>(), Ok(fork));
+        }
+        assert_eq!("SpuriousDragon".parse(), Ok(Hardfork::SpuriousDragon));
+        assert!("bedrock".parse::<Hardfork>().is_err());
+        assert!(Hardfork::ALL.windows(2).all(|forks| forks[0] < forks[1]));
+    }
+}
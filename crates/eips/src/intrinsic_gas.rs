@@ -0,0 +1,239 @@
+//! Intrinsic gas calculation across hardforks.
+//!
+//! The intrinsic gas of a transaction is the gas charged before any execution takes place. A
+//! transaction whose gas limit is below its intrinsic gas is invalid.
+
+use crate::{eip2930::AccessList, Hardfork};
+
+/// The costs that make up the intrinsic gas of a transaction, as of a given hardfork.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntrinsicGasSchedule {
+    /// The base cost of a transaction.
+    pub tx_gas: u64,
+    /// The base cost of a contract creation transaction.
+    pub tx_create_gas: u64,
+    /// The cost per zero byte of calldata.
+    pub zero_byte_gas: u64,
+    /// The cost per non-zero byte of calldata.
+    pub non_zero_byte_gas: u64,
+    /// The cost per address in the access list, as defined in [EIP-2930].
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    pub access_list_address_gas: u64,
+    /// The cost per storage key in the access list, as defined in [EIP-2930].
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    pub access_list_storage_key_gas: u64,
+    /// The cost per 32-byte word of init code, as defined in [EIP-3860].
+    ///
+    /// [EIP-3860]: https://eips.ethereum.org/EIPS/eip-3860
+    pub init_code_word_gas: u64,
+    /// The cost per authorization, as defined in [EIP-7702].
+    ///
+    /// [EIP-7702]: https://eips.ethereum.org/EIPS/eip-7702
+    pub authorization_gas: u64,
+    /// The floor cost per calldata token, as defined in [EIP-7623]. Zero before Prague.
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    pub floor_token_gas: u64,
+}
+
+impl IntrinsicGasSchedule {
+    /// Returns the schedule of the Frontier hardfork.
+    pub const fn frontier() -> Self {
+        Self {
+            tx_gas: 21_000,
+            tx_create_gas: 21_000,
+            zero_byte_gas: 4,
+            non_zero_byte_gas: 68,
+            access_list_address_gas: 0,
+            access_list_storage_key_gas: 0,
+            init_code_word_gas: 0,
+            authorization_gas: 0,
+            floor_token_gas: 0,
+        }
+    }
+
+    /// Returns the schedule of the Homestead hardfork, which increased the cost of contract
+    /// creations.
+    pub const fn homestead() -> Self {
+        Self { tx_create_gas: 53_000, ..Self::frontier() }
+    }
+
+    /// Returns the schedule of the Istanbul hardfork, which reduced the cost of non-zero calldata
+    /// bytes ([EIP-2028]).
+    ///
+    /// [EIP-2028]: https://eips.ethereum.org/EIPS/eip-2028
+    pub const fn istanbul() -> Self {
+        Self { non_zero_byte_gas: 16, ..Self::homestead() }
+    }
+
+    /// Returns the schedule of the Berlin hardfork, which introduced access lists.
+    pub const fn berlin() -> Self {
+        Self {
+            access_list_address_gas: 2_400,
+            access_list_storage_key_gas: 1_900,
+            ..Self::istanbul()
+        }
+    }
+
+    /// Returns the schedule of the Shanghai hardfork, which introduced the init code cost.
+    pub const fn shanghai() -> Self {
+        Self { init_code_word_gas: 2, ..Self::berlin() }
+    }
+
+    /// Returns the schedule of the Prague hardfork, which introduced authorizations and the
+    /// calldata floor cost.
+    pub const fn prague() -> Self {
+        Self { authorization_gas: 25_000, floor_token_gas: 10, ..Self::shanghai() }
+    }
+
+    /// Returns the schedule in effect at the given hardfork.
+    pub const fn for_fork(fork: Hardfork) -> Self {
+        match fork {
+            Hardfork::Frontier => Self::frontier(),
+            Hardfork::Homestead
+            | Hardfork::Dao
+            | Hardfork::Tangerine
+            | Hardfork::SpuriousDragon
+            | Hardfork::Byzantium
+            | Hardfork::Constantinople
+            | Hardfork::Petersburg => Self::homestead(),
+            Hardfork::Istanbul | Hardfork::MuirGlacier => Self::istanbul(),
+            Hardfork::Berlin
+            | Hardfork::London
+            | Hardfork::ArrowGlacier
+            | Hardfork::GrayGlacier
+            | Hardfork::Paris => Self::berlin(),
+            Hardfork::Shanghai | Hardfork::Cancun => Self::shanghai(),
+            Hardfork::Prague | Hardfork::Osaka => Self::prague(),
+        }
+    }
+
+    /// Calculates the intrinsic gas of a transaction with the given properties.
+    pub fn intrinsic_gas(
+        &self,
+        is_create: bool,
+        input: &[u8],
+        access_list: Option<&AccessList>,
+        authorization_count: usize,
+    ) -> IntrinsicGas {
+        let zero_bytes = input.iter().filter(|byte| **byte == 0).count() as u64;
+        let non_zero_bytes = input.len() as u64 - zero_bytes;
+
+        let mut initial_gas = if is_create { self.tx_create_gas } else { self.tx_gas };
+        initial_gas += zero_bytes * self.zero_byte_gas + non_zero_bytes * self.non_zero_byte_gas;
+        if is_create {
+            initial_gas += (input.len() as u64).div_ceil(32) * self.init_code_word_gas;
+        }
+        if let Some(access_list) = access_list {
+            initial_gas += access_list
+                .iter()
+                .map(|item| {
+                    self.access_list_address_gas
+                        + item.storage_keys.len() as u64 * self.access_list_storage_key_gas
+                })
+                .sum::<u64>();
+        }
+        initial_gas += authorization_count as u64 * self.authorization_gas;
+
+        // EIP-7623: a non-zero byte counts as four tokens
+        let tokens = zero_bytes + non_zero_bytes * 4;
+        let floor_gas =
+            if self.floor_token_gas == 0 { 0 } else { self.tx_gas + tokens * self.floor_token_gas };
+
+        IntrinsicGas { initial_gas, floor_gas }
+    }
+}
+
+impl Default for IntrinsicGasSchedule {
+    fn default() -> Self {
+        Self::prague()
+    }
+}
+
+/// The intrinsic gas of a transaction, see [`IntrinsicGasSchedule::intrinsic_gas`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntrinsicGas {
+    /// The gas charged before execution.
+    pub initial_gas: u64,
+    /// The minimum gas charged for the transaction, as defined in [EIP-7623]. Zero before Prague.
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    pub floor_gas: u64,
+}
+
+impl IntrinsicGas {
+    /// Returns the minimum gas limit of the transaction.
+    pub const fn min_gas_limit(&self) -> u64 {
+        if self.initial_gas > self.floor_gas {
+            self.initial_gas
+        } else {
+            self.floor_gas
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eip2930::AccessListItem;
+    use alloy_primitives::{Address, B256};
+
+    #[test]
+    fn transfer() {
+        for schedule in [
+            IntrinsicGasSchedule::frontier(),
+            IntrinsicGasSchedule::berlin(),
+            IntrinsicGasSchedule::prague(),
+        ] {
+            assert_eq!(schedule.intrinsic_gas(false, &[], None, 0).min_gas_limit(), 21_000);
+        }
+    }
+
+    #[test]
+    fn schedule_for_fork() {
+        for (fork, schedule) in [
+            (Hardfork::Frontier, IntrinsicGasSchedule::frontier()),
+            (Hardfork::Byzantium, IntrinsicGasSchedule::homestead()),
+            (Hardfork::London, IntrinsicGasSchedule::berlin()),
+            (Hardfork::Cancun, IntrinsicGasSchedule::shanghai()),
+            (Hardfork::default(), IntrinsicGasSchedule::default()),
+        ] {
+            assert_eq!(IntrinsicGasSchedule::for_fork(fork), schedule);
+        }
+    }
+
+    #[test]
+    fn calldata_across_forks() {
+        let input = [0, 0, 1, 2];
+        let gas = |schedule: IntrinsicGasSchedule| schedule.intrinsic_gas(false, &input, None, 0);
+        assert_eq!(gas(IntrinsicGasSchedule::homestead()).initial_gas, 21_000 + 8 + 136);
+        assert_eq!(gas(IntrinsicGasSchedule::istanbul()).initial_gas, 21_000 + 8 + 32);
+        assert_eq!(
+            gas(IntrinsicGasSchedule::prague()),
+            IntrinsicGas { initial_gas: 21_040, floor_gas: 21_000 + 10 * 10 }
+        );
+        assert_eq!(gas(IntrinsicGasSchedule::prague()).min_gas_limit(), 21_100);
+    }
+
+    #[test]
+    fn create_access_list_and_authorizations() {
+        let init_code = [1; 33];
+        assert_eq!(
+            IntrinsicGasSchedule::frontier().intrinsic_gas(true, &init_code, None, 0).initial_gas,
+            21_000 + 33 * 68
+        );
+        assert_eq!(
+            IntrinsicGasSchedule::shanghai().intrinsic_gas(true, &init_code, None, 0).initial_gas,
+            53_000 + 33 * 16 + 2 * 2
+        );
+
+        let access_list = AccessList(vec![AccessListItem {
+            address: Address::ZERO,
+            storage_keys: vec![B256::ZERO, B256::ZERO],
+        }]);
+        let gas = IntrinsicGasSchedule::prague().intrinsic_gas(false, &[], Some(&access_list), 2);
+        assert_eq!(gas.initial_gas, 21_000 + 2_400 + 2 * 1_900 + 2 * 25_000);
+    }
+}
@@ -50,3 +50,12 @@ pub mod eip7691;
 pub mod eip7702;
 
 pub mod eip7840;
+
+pub mod hardfork;
+pub use hardfork::Hardfork;
+
+pub mod chain_constants;
+pub use chain_constants::ChainConstants;
+
+pub mod intrinsic_gas;
+pub use intrinsic_gas::{IntrinsicGas, IntrinsicGasSchedule};
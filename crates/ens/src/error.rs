@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+/// ENS resolution result type.
+pub type Result<T, E = EnsError> = core::result::Result<T, E>;
+
+/// Error when resolving ENS names or addresses.
+#[derive(Debug, Error)]
+pub enum EnsError {
+    /// An error occurred calling the registry, a resolver or Multicall3.
+    #[error(transparent)]
+    Contract(#[from] alloy_contract::Error),
+    /// The name has no resolver set in the registry.
+    #[error("no resolver set for {0:?}")]
+    ResolverNotFound(String),
+    /// The resolver has no record for the name.
+    #[error("no record set for {0:?}")]
+    RecordNotFound(String),
+    /// A batched resolver call reverted or returned malformed data.
+    #[error("resolver call for {0:?} failed")]
+    CallFailed(String),
+}
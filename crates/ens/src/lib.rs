@@ -0,0 +1,94 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/alloy.jpg",
+    html_favicon_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/favicon.ico"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+use alloy_primitives::{address, hex, keccak256, Address, B256};
+
+mod error;
+pub use error::{EnsError, Result};
+
+pub use alloy_contract::MULTICALL3_ADDRESS;
+
+mod provider;
+pub use provider::ProviderEnsExt;
+
+mod records;
+pub use records::{evm_coin_type, RecordKey, Records, COIN_TYPE_BTC, COIN_TYPE_ETH, COIN_TYPE_SOL};
+
+alloy_sol_types::sol! {
+    /// The read methods of the ENS registry.
+    #[sol(rpc)]
+    interface EnsRegistry {
+        function resolver(bytes32 node) external view returns (address);
+    }
+
+    /// The read methods of an ENS resolver.
+    #[sol(rpc)]
+    interface EnsResolver {
+        function addr(bytes32 node) external view returns (address);
+        function name(bytes32 node) external view returns (string memory);
+        function text(bytes32 node, string calldata key) external view returns (string memory);
+    }
+
+    /// The multichain address method of ENS resolvers, see
+    /// [ENSIP-9](https://docs.ens.domains/ensip/9).
+    #[sol(rpc)]
+    interface IAddressResolver {
+        function addr(bytes32 node, uint256 coinType) external view returns (bytes memory);
+    }
+}
+
+/// The address of the ENS registry, deployed at the same address on mainnet and its testnets.
+pub const ENS_ADDRESS: Address = address!("00000000000C2E074eC69A0dFb2997BA6C7d2e1e");
+
+/// The domain under which the reverse records of addresses are stored.
+pub const ENS_REVERSE_REGISTRAR_DOMAIN: &str = "addr.reverse";
+
+/// Returns the [ENSIP-1](https://docs.ens.domains/ensip/1) hash of the name.
+///
+/// The name must already be normalized, see [ENSIP-15](https://docs.ens.domains/ensip/15).
+pub fn namehash(name: &str) -> B256 {
+    if name.is_empty() {
+        return B256::ZERO;
+    }
+    name.rsplit('.').fold(B256::ZERO, |node, label| {
+        keccak256([node.as_slice(), keccak256(label.as_bytes()).as_slice()].concat())
+    })
+}
+
+/// Returns the name of the reverse record of the address, e.g. `<address>.addr.reverse`.
+pub fn reverse_address(address: &Address) -> String {
+    format!("{}.{ENS_REVERSE_REGISTRAR_DOMAIN}", hex::encode(address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn hashes_names() {
+        assert_eq!(namehash(""), B256::ZERO);
+        assert_eq!(
+            namehash("eth"),
+            b256!("93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae")
+        );
+        assert_eq!(
+            namehash("foo.eth"),
+            b256!("de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f")
+        );
+    }
+
+    #[test]
+    fn reverse_names() {
+        let address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+        assert_eq!(
+            reverse_address(&address),
+            "d8da6bf26964af9d7eed9e03e53415d37aa96045.addr.reverse"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! The ENS provider extension.
+
+use crate::{
+    namehash, reverse_address, EnsError, EnsRegistry, EnsResolver, RecordKey, Records, Result,
+    ENS_ADDRESS,
+};
+use alloy_contract::multicall::{aggregate, aggregate_raw};
+use alloy_network::Network;
+use alloy_primitives::{Address, B256};
+use alloy_provider::Provider;
+use alloy_sol_types::SolCall;
+
+/// Extension trait resolving ENS names and addresses through a [`Provider`].
+///
+/// The single name methods issue their calls directly. The batched methods, such as
+/// [`resolve_names`](Self::resolve_names), aggregate the registry and resolver calls of all names
+/// through Multicall3, see [`MULTICALL3_ADDRESS`](crate::MULTICALL3_ADDRESS), resolving any number
+/// of names in two `eth_call`s. Their outer result fails if an `eth_call` fails, while each name
+/// fails individually if it cannot be resolved.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait ProviderEnsExt<N>: Send + Sync {
+    /// Returns the resolver of the name.
+    async fn get_resolver(&self, name: &str) -> Result<Address>;
+
+    /// Returns the address the name resolves to.
+    async fn resolve_name(&self, name: &str) -> Result<Address>;
+
+    /// Returns the primary name of the address, as set in its reverse record.
+    ///
+    /// The name is not verified to resolve back to the address.
+    async fn lookup_address(&self, address: &Address) -> Result<String>;
+
+    /// Returns the text record of the name with the given key, e.g. `url` or `avatar`.
+    async fn lookup_txt(&self, name: &str, key: &str) -> Result<String>;
+
+    /// Returns the text and multichain address records of the name with the given keys.
+    ///
+    /// All records are read from the resolver of the name in a single multicall. Records that are
+    /// not set are omitted, as are records the resolver fails to return, e.g. address records
+    /// of resolvers that do not support [ENSIP-9](https://docs.ens.domains/ensip/9).
+    async fn get_records(&self, name: &str, keys: &[RecordKey]) -> Result<Records>;
+
+    /// Returns the addresses the names resolve to, in the order of the names.
+    async fn resolve_names(&self, names: &[&str]) -> Result<Vec<Result<Address>>>;
+
+    /// Returns the primary names of the addresses, in the order of the addresses.
+    ///
+    /// The names are not verified to resolve back to the addresses.
+    async fn lookup_addresses(&self, addresses: &[Address]) -> Result<Vec<Result<String>>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<N, P> ProviderEnsExt<N> for P
+where
+    N: Network,
+    P: Provider<N>,
+{
+    async fn get_resolver(&self, name: &str) -> Result<Address> {
+        let registry = EnsRegistry::new(ENS_ADDRESS, self);
+        let resolver = registry.resolver(namehash(name)).call().await?._0;
+        if resolver.is_zero() {
+            return Err(EnsError::ResolverNotFound(name.to_string()));
+        }
+        Ok(resolver)
+    }
+
+    async fn resolve_name(&self, name: &str) -> Result<Address> {
+        let resolver = EnsResolver::new(self.get_resolver(name).await?, self);
+        let address = resolver.addr(namehash(name)).call().await?._0;
+        if address.is_zero() {
+            return Err(EnsError::RecordNotFound(name.to_string()));
+        }
+        Ok(address)
+    }
+
+    async fn lookup_address(&self, address: &Address) -> Result<String> {
+        let reverse = reverse_address(address);
+        let resolver = EnsResolver::new(self.get_resolver(&reverse).await?, self);
+        let name = resolver.name(namehash(&reverse)).call().await?._0;
+        if name.is_empty() {
+            return Err(EnsError::RecordNotFound(reverse));
+        }
+        Ok(name)
+    }
+
+    async fn lookup_txt(&self, name: &str, key: &str) -> Result<String> {
+        let resolver = EnsResolver::new(self.get_resolver(name).await?, self);
+        let text = resolver.text(namehash(name), key.to_string()).call().await?._0;
+        if text.is_empty() {
+            return Err(EnsError::RecordNotFound(name.to_string()));
+        }
+        Ok(text)
+    }
+
+    async fn get_records(&self, name: &str, keys: &[RecordKey]) -> Result<Records> {
+        let resolver = self.get_resolver(name).await?;
+        let node = namehash(name);
+        let calls = keys.iter().map(|key| (resolver, key.call_data(node))).collect::<Vec<_>>();
+        let mut records = Records::default();
+        for (key, data) in keys.iter().zip(aggregate_raw(self, calls).await?) {
+            if let Some(data) = data {
+                records.insert(key, &data);
+            }
+        }
+        Ok(records)
+    }
+
+    async fn resolve_names(&self, names: &[&str]) -> Result<Vec<Result<Address>>> {
+        let names = names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+        let results = resolve_batch(self, &names, |node| EnsResolver::addrCall { node }).await?;
+        Ok(names
+            .into_iter()
+            .zip(results)
+            .map(|(name, result)| {
+                let address = result?._0;
+                if address.is_zero() {
+                    return Err(EnsError::RecordNotFound(name));
+                }
+                Ok(address)
+            })
+            .collect())
+    }
+
+    async fn lookup_addresses(&self, addresses: &[Address]) -> Result<Vec<Result<String>>> {
+        let names = addresses.iter().map(reverse_address).collect::<Vec<_>>();
+        let results = resolve_batch(self, &names, |node| EnsResolver::nameCall { node }).await?;
+        Ok(names
+            .into_iter()
+            .zip(results)
+            .map(|(reverse, result)| {
+                let name = result?._0;
+                if name.is_empty() {
+                    return Err(EnsError::RecordNotFound(reverse));
+                }
+                Ok(name)
+            })
+            .collect())
+    }
+}
+
+/// Looks up the resolvers of the names in one multicall, then executes the resolver call built by
+/// `call` for each name with a resolver in a second multicall.
+async fn resolve_batch<P, N, C>(
+    provider: &P,
+    names: &[String],
+    call: impl Fn(B256) -> C,
+) -> Result<Vec<Result<C::Return>>>
+where
+    P: Provider<N>,
+    N: Network,
+    C: SolCall,
+{
+    let nodes = names.iter().map(|name| namehash(name)).collect::<Vec<_>>();
+    let calls = nodes
+        .iter()
+        .map(|&node| (ENS_ADDRESS, EnsRegistry::resolverCall { node }))
+        .collect::<Vec<_>>();
+    let resolvers = aggregate(provider, calls)
+        .await?
+        .into_iter()
+        .map(|resolver| resolver.map(|resolver| resolver._0).filter(|resolver| !resolver.is_zero()))
+        .collect::<Vec<_>>();
+
+    let calls = nodes
+        .iter()
+        .zip(&resolvers)
+        .filter_map(|(&node, resolver)| resolver.map(|resolver| (resolver, call(node))))
+        .collect::<Vec<_>>();
+    let mut returns = aggregate(provider, calls).await?.into_iter();
+
+    Ok(names
+        .iter()
+        .zip(resolvers)
+        .map(|(name, resolver)| {
+            if resolver.is_none() {
+                return Err(EnsError::ResolverNotFound(name.clone()));
+            }
+            returns.next().flatten().ok_or_else(|| EnsError::CallFailed(name.clone()))
+        })
+        .collect())
+}
@@ -0,0 +1,164 @@
+//! Text and multichain address records of ENS names.
+
+use crate::{EnsResolver, IAddressResolver};
+use alloy_primitives::{Address, Bytes, B256, U256};
+use alloy_sol_types::SolCall;
+use std::collections::BTreeMap;
+
+/// The [SLIP-44](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) coin type of
+/// Bitcoin.
+pub const COIN_TYPE_BTC: u64 = 0;
+
+/// The SLIP-44 coin type of Ether, which addresses are stored under by `addr(bytes32)`.
+pub const COIN_TYPE_ETH: u64 = 60;
+
+/// The SLIP-44 coin type of Solana.
+pub const COIN_TYPE_SOL: u64 = 501;
+
+/// Returns the coin type of an EVM chain, as defined by
+/// [ENSIP-11](https://docs.ens.domains/ensip/11).
+pub const fn evm_coin_type(chain_id: u64) -> u64 {
+    0x8000_0000 | chain_id
+}
+
+/// The key of an ENS record.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RecordKey {
+    /// A text record, e.g. `avatar`, `url` or `com.twitter`.
+    Text(String),
+    /// The address on the chain with the given coin type, as defined by
+    /// [ENSIP-9](https://docs.ens.domains/ensip/9).
+    Address(u64),
+}
+
+impl RecordKey {
+    /// Returns the key of the text record with the given key.
+    pub fn text(key: impl Into<String>) -> Self {
+        Self::Text(key.into())
+    }
+
+    /// Returns the key of the address record with the given coin type.
+    pub const fn address(coin_type: u64) -> Self {
+        Self::Address(coin_type)
+    }
+
+    /// Returns the calldata of the resolver call reading the record of the node.
+    pub(crate) fn call_data(&self, node: B256) -> Bytes {
+        match self {
+            Self::Text(key) => EnsResolver::textCall { node, key: key.clone() }.abi_encode(),
+            Self::Address(coin_type) => {
+                IAddressResolver::addrCall { node, coinType: U256::from(*coin_type) }.abi_encode()
+            }
+        }
+        .into()
+    }
+}
+
+impl From<&str> for RecordKey {
+    fn from(key: &str) -> Self {
+        Self::text(key)
+    }
+}
+
+impl From<String> for RecordKey {
+    fn from(key: String) -> Self {
+        Self::Text(key)
+    }
+}
+
+/// The records of an ENS name, as returned by
+/// [`ProviderEnsExt::get_records`](crate::ProviderEnsExt::get_records).
+///
+/// Records that are not set, or that the resolver failed to return, are not included.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Records {
+    /// The text records by key.
+    pub texts: BTreeMap<String, String>,
+    /// The addresses by coin type, in the binary encoding of their chain.
+    pub addresses: BTreeMap<u64, Bytes>,
+}
+
+impl Records {
+    /// Returns the text record with the given key.
+    pub fn text(&self, key: &str) -> Option<&str> {
+        self.texts.get(key).map(String::as_str)
+    }
+
+    /// Returns the address with the given coin type.
+    pub fn address(&self, coin_type: u64) -> Option<&Bytes> {
+        self.addresses.get(&coin_type)
+    }
+
+    /// Returns the Ethereum address, if it is set and well-formed.
+    pub fn eth_address(&self) -> Option<Address> {
+        self.address(COIN_TYPE_ETH)
+            .filter(|address| address.len() == Address::len_bytes())
+            .map(|address| Address::from_slice(address))
+    }
+
+    /// Returns `true` if no records are set.
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty() && self.addresses.is_empty()
+    }
+
+    /// Inserts the record from the data returned by the resolver call of
+    /// [`RecordKey::call_data`], ignoring malformed data and empty records.
+    pub(crate) fn insert(&mut self, key: &RecordKey, data: &[u8]) {
+        match key {
+            RecordKey::Text(key) => {
+                if let Ok(text) = EnsResolver::textCall::abi_decode_returns(data, true) {
+                    if !text._0.is_empty() {
+                        self.texts.insert(key.clone(), text._0);
+                    }
+                }
+            }
+            RecordKey::Address(coin_type) => {
+                if let Ok(address) = IAddressResolver::addrCall::abi_decode_returns(data, true) {
+                    if !address._0.is_empty() {
+                        self.addresses.insert(*coin_type, address._0);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex};
+
+    #[test]
+    fn inserts_records() {
+        let eth = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+        let btc = Bytes::from(hex!("76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac"));
+        let text = |text: &str| EnsResolver::textCall::abi_encode_returns(&(text.to_string(),));
+        let addr = |addr: &Bytes| IAddressResolver::addrCall::abi_encode_returns(&(addr.clone(),));
+
+        let mut records = Records::default();
+        records.insert(&"url".into(), &text("https://example.com"));
+        records.insert(&"avatar".into(), &text(""));
+        records.insert(&RecordKey::address(COIN_TYPE_ETH), &addr(&eth.to_vec().into()));
+        records.insert(&RecordKey::address(COIN_TYPE_BTC), &addr(&btc));
+        records.insert(&RecordKey::address(COIN_TYPE_SOL), &addr(&Bytes::new()));
+        records.insert(&RecordKey::address(evm_coin_type(10)), &[0xde, 0xad]);
+
+        assert_eq!(records.text("url"), Some("https://example.com"));
+        assert_eq!(records.text("avatar"), None);
+        assert_eq!(records.eth_address(), Some(eth));
+        assert_eq!(records.address(COIN_TYPE_BTC), Some(&btc));
+        assert_eq!(records.addresses.len(), 2);
+    }
+
+    #[test]
+    fn coin_types() {
+        assert_eq!(evm_coin_type(10), 2147483658);
+        assert_eq!(RecordKey::from("url"), RecordKey::Text("url".to_string()));
+        let node = B256::repeat_byte(1);
+        assert_eq!(RecordKey::text("url").call_data(node)[..4], EnsResolver::textCall::SELECTOR);
+        assert_eq!(
+            RecordKey::address(COIN_TYPE_BTC).call_data(node)[..4],
+            IAddressResolver::addrCall::SELECTOR
+        );
+    }
+}
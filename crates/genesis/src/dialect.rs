@@ -0,0 +1,838 @@
+//! Client-specific genesis file dialects.
+//!
+//! [`Genesis`] follows geth's genesis format, but other clients define their own:
+//! - besu extends geth's format with its own consensus and account keys;
+//! - nethermind uses parity-style chain spec files;
+//! - anvil dumps its state with `anvil --dump-state`.
+//!
+//! [`parse`] detects the dialect of a genesis file, converts it to a [`Genesis`] and reports the
+//! fields it ignored as [`GenesisWarning`]s, while [`to_dialect`] converts a [`Genesis`] back.
+//! Fields with a default value, such as `null`, `false`, zero or an empty object, are never
+//! reported.
+//!
+//! Chain configuration fields that are not part of [`Genesis`] are kept in
+//! [`ChainConfig::extra_fields`](crate::ChainConfig::extra_fields), so that e.g. besu's `ibft2`
+//! and `qbft` configurations round-trip unchanged.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_genesis::dialect::{self, GenesisDialect, GenesisWarning};
+//!
+//! let besu = serde_json::json!({
+//!     "config": { "chainId": 1337, "clique": { "blockperiodseconds": 5, "epochlength": 30000 } },
+//!     "gasLimit": "0x1c9c380",
+//!     "alloc": {
+//!         "0x627306090abab3a6e1400e9345bc60c78a8bef57": { "balance": "0x1", "comment": "faucet" }
+//!     }
+//! });
+//!
+//! let parsed = dialect::parse(besu).unwrap();
+//! assert_eq!(parsed.dialect, GenesisDialect::Besu);
+//! assert_eq!(parsed.genesis.config.clique.unwrap().period, Some(5));
+//! assert_eq!(
+//!     parsed.warnings,
+//!     [GenesisWarning::UnknownField(
+//!         "/alloc/0x627306090abab3a6e1400e9345bc60c78a8bef57/comment".into()
+//!     )]
+//! );
+//! ```
+
+use crate::Genesis;
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// The keys of a geth genesis account.
+const ACCOUNT_FIELDS: &[&str] = &["nonce", "balance", "code", "storage", "secretKey"];
+
+/// Chain configuration keys only used by besu, as JSON pointers.
+const BESU_CONFIG_KEYS: &[&str] = &[
+    "/config/ibft2",
+    "/config/qbft",
+    "/config/contractSizeLimit",
+    "/config/evmStackSize",
+    "/config/zeroBaseFee",
+    "/config/clique/blockperiodseconds",
+    "/config/clique/epochlength",
+    "/config/ethash/fixeddifficulty",
+];
+
+/// Nethermind transition parameters and the geth chain configuration keys they map to.
+const NETHERMIND_TRANSITIONS: &[(&str, &str)] = &[
+    ("eip150Transition", "eip150Block"),
+    ("eip155Transition", "eip155Block"),
+    ("eip161abcTransition", "eip158Block"),
+    ("eip140Transition", "byzantiumBlock"),
+    ("eip145Transition", "constantinopleBlock"),
+    ("eip1283DisableTransition", "petersburgBlock"),
+    ("eip1344Transition", "istanbulBlock"),
+    ("eip2929Transition", "berlinBlock"),
+    ("eip1559Transition", "londonBlock"),
+    ("eip3855TransitionTimestamp", "shanghaiTime"),
+    ("eip4844TransitionTimestamp", "cancunTime"),
+    ("eip7702TransitionTimestamp", "pragueTime"),
+];
+
+/// Nethermind genesis header fields and the geth genesis keys they map to.
+const NETHERMIND_HEADER: &[(&str, &str)] = &[
+    ("difficulty", "difficulty"),
+    ("author", "coinbase"),
+    ("timestamp", "timestamp"),
+    ("extraData", "extraData"),
+    ("gasLimit", "gasLimit"),
+    ("baseFeePerGas", "baseFeePerGas"),
+];
+
+/// Anvil block environment fields and the geth genesis keys they map to.
+const ANVIL_BLOCK: &[(&str, &str)] = &[
+    ("number", "number"),
+    ("coinbase", "coinbase"),
+    ("timestamp", "timestamp"),
+    ("gas_limit", "gasLimit"),
+    ("basefee", "baseFeePerGas"),
+    ("difficulty", "difficulty"),
+    ("prevrandao", "mixHash"),
+];
+
+/// A client-specific genesis file format.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GenesisDialect {
+    /// geth's genesis format, which [`Genesis`] follows.
+    #[default]
+    Geth,
+    /// besu's genesis format, extending geth's format with its own consensus and account keys.
+    Besu,
+    /// nethermind's chain spec format.
+    Nethermind,
+    /// anvil's state dump format.
+    Anvil,
+}
+
+impl GenesisDialect {
+    /// Detects the dialect of the given genesis file, defaulting to [`GenesisDialect::Geth`].
+    pub fn detect(value: &Value) -> Self {
+        let Some(object) = value.as_object() else { return Self::Geth };
+        let has = |key: &str| object.contains_key(key);
+
+        if has("accounts") && !has("alloc") {
+            if has("engine") || has("params") {
+                return Self::Nethermind;
+            }
+            if has("block") {
+                return Self::Anvil;
+            }
+        }
+
+        let besu_config = BESU_CONFIG_KEYS.iter().any(|key| value.pointer(key).is_some());
+        let besu_account = object
+            .get("alloc")
+            .and_then(Value::as_object)
+            .is_some_and(|alloc| alloc.values().any(|account| account.get("privateKey").is_some()));
+        if besu_config || besu_account {
+            Self::Besu
+        } else {
+            Self::Geth
+        }
+    }
+}
+
+impl fmt::Display for GenesisDialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Geth => "geth",
+            Self::Besu => "besu",
+            Self::Nethermind => "nethermind",
+            Self::Anvil => "anvil",
+        })
+    }
+}
+
+/// A field ignored when converting between genesis dialects.
+///
+/// Fields are identified by [JSON pointers] into the converted document: the parsed file when
+/// parsing, and the geth representation of the [`Genesis`] when converting to another dialect.
+///
+/// [JSON pointers]: https://datatracker.ietf.org/doc/html/rfc6901
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GenesisWarning {
+    /// A field that is not part of the dialect was ignored.
+    UnknownField(String),
+    /// A field of the dialect that cannot be represented in the target format was ignored.
+    UnsupportedField(String),
+}
+
+impl GenesisWarning {
+    /// Returns the JSON pointer of the ignored field.
+    pub fn pointer(&self) -> &str {
+        match self {
+            Self::UnknownField(pointer) | Self::UnsupportedField(pointer) => pointer,
+        }
+    }
+}
+
+impl fmt::Display for GenesisWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField(pointer) => write!(f, "ignored unknown field `{pointer}`"),
+            Self::UnsupportedField(pointer) => write!(f, "ignored unsupported field `{pointer}`"),
+        }
+    }
+}
+
+/// A genesis file parsed by [`parse`] or [`parse_as`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedGenesis {
+    /// The parsed genesis.
+    pub genesis: Genesis,
+    /// The dialect of the genesis file.
+    pub dialect: GenesisDialect,
+    /// The fields of the genesis file that were ignored.
+    pub warnings: Vec<GenesisWarning>,
+}
+
+/// Parses a genesis file of any dialect, see [`GenesisDialect::detect`].
+pub fn parse(value: Value) -> Result<ParsedGenesis, serde_json::Error> {
+    let dialect = GenesisDialect::detect(&value);
+    parse_as(value, dialect)
+}
+
+/// Parses a genesis file of the given dialect.
+pub fn parse_as(value: Value, dialect: GenesisDialect) -> Result<ParsedGenesis, serde_json::Error> {
+    let mut warnings = Vec::new();
+    let mut value = match dialect {
+        GenesisDialect::Geth => value,
+        GenesisDialect::Besu => besu_to_geth(value, &mut warnings),
+        GenesisDialect::Nethermind => nethermind_to_geth(value, &mut warnings)?,
+        GenesisDialect::Anvil => anvil_to_geth(value, &mut warnings),
+    };
+
+    let accounts = match dialect {
+        GenesisDialect::Geth | GenesisDialect::Besu => "/alloc",
+        GenesisDialect::Nethermind | GenesisDialect::Anvil => "/accounts",
+    };
+    if let Some(alloc) = value.get_mut("alloc").and_then(Value::as_object_mut) {
+        for (address, account) in alloc {
+            let Some(account) = account.as_object_mut() else { continue };
+            let prefix = pointer(accounts, address);
+            account.retain(|key, value| {
+                let known = ACCOUNT_FIELDS.contains(&key.as_str());
+                if !known && !is_default(value) {
+                    warnings.push(GenesisWarning::UnknownField(pointer(&prefix, key)));
+                }
+                known
+            });
+        }
+    }
+
+    let genesis = Genesis::deserialize(&value)?;
+
+    // nethermind and anvil files were rebuilt from known fields only
+    if matches!(dialect, GenesisDialect::Geth | GenesisDialect::Besu) {
+        let mut serialized = serde_json::to_value(&genesis)?;
+        // accounts were checked above, and their addresses may be formatted differently
+        for value in [&mut value, &mut serialized] {
+            if let Some(object) = value.as_object_mut() {
+                object.remove("alloc");
+            }
+        }
+        warnings.extend(
+            alloy_serde::strict::unknown_fields(&value, &serialized)
+                .into_iter()
+                .filter(|field| value.pointer(field).is_some_and(|value| !is_default(value)))
+                .map(GenesisWarning::UnknownField),
+        );
+    }
+
+    Ok(ParsedGenesis { genesis, dialect, warnings })
+}
+
+/// Converts a genesis to the given dialect, returning the fields that could not be converted.
+pub fn to_dialect(
+    genesis: &Genesis,
+    dialect: GenesisDialect,
+) -> Result<(Value, Vec<GenesisWarning>), serde_json::Error> {
+    let mut warnings = Vec::new();
+    let value = match dialect {
+        GenesisDialect::Geth => serde_json::to_value(genesis)?,
+        GenesisDialect::Besu => geth_to_besu(serde_json::to_value(genesis)?),
+        GenesisDialect::Nethermind => geth_to_nethermind(genesis, &mut warnings)?,
+        GenesisDialect::Anvil => geth_to_anvil(genesis, &mut warnings)?,
+    };
+    Ok((value, warnings))
+}
+
+fn besu_to_geth(mut value: Value, warnings: &mut Vec<GenesisWarning>) -> Value {
+    if let Some(clique) = value.pointer_mut("/config/clique").and_then(Value::as_object_mut) {
+        rename(clique, "blockperiodseconds", "period");
+        rename(clique, "epochlength", "epoch");
+    }
+    if let Some(ethash) = value.pointer_mut("/config/ethash").and_then(Value::as_object_mut) {
+        if ethash.remove("fixeddifficulty").is_some_and(|value| !is_default(&value)) {
+            warnings
+                .push(GenesisWarning::UnsupportedField("/config/ethash/fixeddifficulty".into()));
+        }
+    }
+    if let Some(alloc) = value.get_mut("alloc").and_then(Value::as_object_mut) {
+        for account in alloc.values_mut().filter_map(Value::as_object_mut) {
+            rename(account, "privateKey", "secretKey");
+        }
+    }
+    value
+}
+
+fn geth_to_besu(mut value: Value) -> Value {
+    if let Some(clique) = value.pointer_mut("/config/clique").and_then(Value::as_object_mut) {
+        rename(clique, "period", "blockperiodseconds");
+        rename(clique, "epoch", "epochlength");
+    }
+    if let Some(alloc) = value.get_mut("alloc").and_then(Value::as_object_mut) {
+        for account in alloc.values_mut().filter_map(Value::as_object_mut) {
+            rename(account, "secretKey", "privateKey");
+        }
+    }
+    value
+}
+
+fn nethermind_to_geth(
+    value: Value,
+    warnings: &mut Vec<GenesisWarning>,
+) -> Result<Value, serde_json::Error> {
+    let Value::Object(mut spec) = value else { return Ok(value) };
+    let mut geth = Map::new();
+    let mut config = Map::new();
+
+    if let Some(mut params) = take_object(&mut spec, "params") {
+        let chain_id = params.remove("chainID");
+        let network_id = params.remove("networkID");
+        if let Some(chain_id) = chain_id.or(network_id) {
+            config.insert("chainId".into(), quantity(&chain_id)?);
+        }
+        for (transition, key) in NETHERMIND_TRANSITIONS {
+            if let Some(block) = params.remove(*transition) {
+                config.insert((*key).into(), quantity(&block)?);
+            }
+        }
+        move_field(&mut params, &mut config, "terminalTotalDifficulty", "terminalTotalDifficulty");
+        unsupported(&params, "/params", warnings);
+    }
+
+    if let Some(engines) = take_object(&mut spec, "engine") {
+        for (name, engine) in engines {
+            let prefix = pointer("/engine", &name);
+            let Value::Object(mut engine) = engine else { continue };
+            let mut params = take_object(&mut engine, "params").unwrap_or_default();
+            match name.as_str() {
+                "Ethash" => {
+                    if let Some(block) = params.remove("homesteadTransition") {
+                        config.insert("homesteadBlock".into(), quantity(&block)?);
+                    }
+                    if let Some(block) = params.remove("daoHardforkTransition") {
+                        config.insert("daoForkBlock".into(), quantity(&block)?);
+                        config.insert("daoForkSupport".into(), true.into());
+                    }
+                    config.insert("ethash".into(), Map::new().into());
+                }
+                "clique" => {
+                    let mut clique = Map::new();
+                    for key in ["period", "epoch"] {
+                        if let Some(value) = params.remove(key) {
+                            clique.insert(key.into(), quantity(&value)?);
+                        }
+                    }
+                    config.insert("clique".into(), clique.into());
+                }
+                _ => {
+                    warnings.push(GenesisWarning::UnsupportedField(prefix));
+                    continue;
+                }
+            }
+            unsupported(&params, &format!("{prefix}/params"), warnings);
+            unsupported(&engine, &prefix, warnings);
+        }
+    }
+
+    if let Some(mut header) = take_object(&mut spec, "genesis") {
+        if let Some(mut seal) = take_object(&mut header, "seal") {
+            if let Some(mut ethereum) = take_object(&mut seal, "ethereum") {
+                move_field(&mut ethereum, &mut geth, "nonce", "nonce");
+                move_field(&mut ethereum, &mut geth, "mixHash", "mixHash");
+                unsupported(&ethereum, "/genesis/seal/ethereum", warnings);
+            }
+            unsupported(&seal, "/genesis/seal", warnings);
+        }
+        for (from, to) in NETHERMIND_HEADER {
+            move_field(&mut header, &mut geth, from, to);
+        }
+        unsupported(&header, "/genesis", warnings);
+    }
+
+    if let Some(mut accounts) = take_object(&mut spec, "accounts") {
+        accounts.retain(|address, account| {
+            let Some(account) = account.as_object_mut() else { return true };
+            if account.remove("builtin").is_some() {
+                warnings.push(GenesisWarning::UnsupportedField(format!(
+                    "{}/builtin",
+                    pointer("/accounts", address)
+                )));
+                // precompiles are usually declared without any state
+                if account.is_empty() {
+                    return false;
+                }
+            }
+            account.entry("balance").or_insert_with(|| "0x0".into());
+            true
+        });
+        geth.insert("alloc".into(), accounts.into());
+    }
+
+    unsupported(&spec, "", warnings);
+    geth.insert("config".into(), config.into());
+    Ok(geth.into())
+}
+
+fn geth_to_nethermind(
+    genesis: &Genesis,
+    warnings: &mut Vec<GenesisWarning>,
+) -> Result<Value, serde_json::Error> {
+    let mut geth = object(serde_json::to_value(genesis)?);
+    let mut config = take_object(&mut geth, "config").unwrap_or_default();
+    let mut spec = Map::new();
+
+    let mut params = Map::new();
+    if let Some(chain_id) = config.remove("chainId") {
+        params.insert("chainID".into(), hex(&chain_id));
+        params.insert("networkID".into(), hex(&chain_id));
+    }
+    for (transition, key) in NETHERMIND_TRANSITIONS {
+        if let Some(block) = config.remove(*key) {
+            params.insert((*transition).into(), hex(&block));
+        }
+    }
+    move_field(&mut config, &mut params, "terminalTotalDifficulty", "terminalTotalDifficulty");
+
+    let mut engine = Map::new();
+    if let Some(mut clique) = config.remove("clique").map(object) {
+        let mut params = Map::new();
+        for key in ["period", "epoch"] {
+            if let Some(value) = clique.remove(key) {
+                params.insert(key.into(), hex(&value));
+            }
+        }
+        engine.insert("clique".into(), Map::from_iter([("params".into(), params.into())]).into());
+    } else {
+        let mut params = Map::new();
+        if let Some(block) = config.remove("homesteadBlock") {
+            params.insert("homesteadTransition".into(), hex(&block));
+        }
+        if config.get("daoForkSupport") == Some(&Value::Bool(true)) {
+            if let Some(block) = config.remove("daoForkBlock") {
+                params.insert("daoHardforkTransition".into(), hex(&block));
+            }
+            config.remove("daoForkSupport");
+        }
+        config.remove("ethash");
+        engine.insert("Ethash".into(), Map::from_iter([("params".into(), params.into())]).into());
+    }
+
+    let mut header = Map::new();
+    let mut ethereum = Map::new();
+    move_field(&mut geth, &mut ethereum, "nonce", "nonce");
+    move_field(&mut geth, &mut ethereum, "mixHash", "mixHash");
+    header.insert("seal".into(), Map::from_iter([("ethereum".into(), ethereum.into())]).into());
+    for (to, from) in NETHERMIND_HEADER {
+        move_field(&mut geth, &mut header, from, to);
+    }
+
+    let mut accounts = take_object(&mut geth, "alloc").unwrap_or_default();
+    for (address, account) in &mut accounts {
+        if let Some(account) = account.as_object_mut() {
+            if account.remove("secretKey").is_some() {
+                warnings.push(GenesisWarning::UnsupportedField(format!(
+                    "{}/secretKey",
+                    pointer("/alloc", address)
+                )));
+            }
+        }
+    }
+
+    unsupported(&config, "/config", warnings);
+    unsupported(&geth, "", warnings);
+
+    spec.insert("engine".into(), engine.into());
+    spec.insert("params".into(), params.into());
+    spec.insert("genesis".into(), header.into());
+    spec.insert("accounts".into(), accounts.into());
+    Ok(spec.into())
+}
+
+fn anvil_to_geth(value: Value, warnings: &mut Vec<GenesisWarning>) -> Value {
+    let Value::Object(mut dump) = value else { return value };
+    let mut geth = Map::new();
+
+    if let Some(mut block) = take_object(&mut dump, "block") {
+        for (from, to) in ANVIL_BLOCK {
+            move_field(&mut block, &mut geth, from, to);
+        }
+        if let Some(mut blob) = take_object(&mut block, "blob_excess_gas_and_price") {
+            move_field(&mut blob, &mut geth, "excess_blob_gas", "excessBlobGas");
+            // the blob gas price is derived from the excess blob gas
+            blob.remove("blob_gasprice");
+            unsupported(&blob, "/block/blob_excess_gas_and_price", warnings);
+        }
+        unsupported(&block, "/block", warnings);
+    }
+
+    move_field(&mut dump, &mut geth, "accounts", "alloc");
+    unsupported(&dump, "", warnings);
+    geth.into()
+}
+
+fn geth_to_anvil(
+    genesis: &Genesis,
+    warnings: &mut Vec<GenesisWarning>,
+) -> Result<Value, serde_json::Error> {
+    let mut geth = object(serde_json::to_value(genesis)?);
+    let mut dump = Map::new();
+
+    let mut block = Map::new();
+    for (to, from) in ANVIL_BLOCK {
+        move_field(&mut geth, &mut block, from, to);
+    }
+    let number = block.entry("number").or_insert_with(|| "0x0".into()).clone();
+
+    let mut accounts = take_object(&mut geth, "alloc").unwrap_or_default();
+    for (address, account) in &mut accounts {
+        let Some(account) = account.as_object_mut() else { continue };
+        if account.remove("secretKey").is_some() {
+            warnings.push(GenesisWarning::UnsupportedField(format!(
+                "{}/secretKey",
+                pointer("/alloc", address)
+            )));
+        }
+        // anvil requires all account fields, and the nonce as a number
+        let nonce = account.remove("nonce").map(|nonce| quantity(&nonce)).transpose()?;
+        account.insert("nonce".into(), nonce.unwrap_or_else(|| 0.into()));
+        account.entry("code").or_insert_with(|| "0x".into());
+        account.entry("storage").or_insert_with(|| Map::new().into());
+    }
+
+    if let Some(config) = geth.remove("config") {
+        unsupported(&object(config), "/config", warnings);
+    }
+    unsupported(&geth, "", warnings);
+
+    dump.insert("block".into(), block.into());
+    dump.insert("accounts".into(), accounts.into());
+    dump.insert("best_block_number".into(), number);
+    Ok(dump.into())
+}
+
+/// Returns `true` if the value is `null`, `false`, zero, or empty.
+fn is_default(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Bool(value) => !value,
+        Value::Number(value) => value.as_u64() == Some(0),
+        Value::String(value) => {
+            value.strip_prefix("0x").unwrap_or(value).bytes().all(|byte| byte == b'0')
+        }
+        Value::Array(value) => value.is_empty(),
+        Value::Object(value) => value.is_empty(),
+    }
+}
+
+/// Reports every non-default field of `object` as unsupported.
+fn unsupported(object: &Map<String, Value>, prefix: &str, warnings: &mut Vec<GenesisWarning>) {
+    warnings.extend(
+        object
+            .iter()
+            .filter(|(_, value)| !is_default(value))
+            .map(|(key, _)| GenesisWarning::UnsupportedField(pointer(prefix, key))),
+    );
+}
+
+fn pointer(prefix: &str, key: &str) -> String {
+    format!("{prefix}/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+fn object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(object) => object,
+        _ => Map::new(),
+    }
+}
+
+fn take_object(object: &mut Map<String, Value>, key: &str) -> Option<Map<String, Value>> {
+    object.remove(key).map(self::object)
+}
+
+fn rename(object: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(value) = object.remove(from) {
+        object.insert(to.into(), value);
+    }
+}
+
+fn move_field(from: &mut Map<String, Value>, to: &mut Map<String, Value>, key: &str, as_key: &str) {
+    if let Some(value) = from.remove(key) {
+        to.insert(as_key.into(), value);
+    }
+}
+
+/// Converts a quantity, either a number or a hex string, to a number.
+fn quantity(value: &Value) -> Result<Value, serde_json::Error> {
+    alloy_serde::quantity::deserialize::<u64, _>(value).map(Into::into)
+}
+
+/// Converts a number to a hex quantity.
+fn hex(value: &Value) -> Value {
+    match value.as_u64() {
+        Some(value) => format!("{value:#x}").into(),
+        None => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, Bytes, B256, U256};
+    use serde_json::json;
+
+    #[test]
+    fn detects_dialects() {
+        let geth = json!({ "config": { "chainId": 1 }, "alloc": {} });
+        assert_eq!(GenesisDialect::detect(&geth), GenesisDialect::Geth);
+        let besu = json!({ "config": { "chainId": 1, "ethash": { "fixeddifficulty": 100 } } });
+        assert_eq!(GenesisDialect::detect(&besu), GenesisDialect::Besu);
+        let nethermind = json!({ "engine": {}, "params": {}, "accounts": {} });
+        assert_eq!(GenesisDialect::detect(&nethermind), GenesisDialect::Nethermind);
+        let anvil = json!({ "block": {}, "accounts": {} });
+        assert_eq!(GenesisDialect::detect(&anvil), GenesisDialect::Anvil);
+        assert_eq!(GenesisDialect::detect(&json!([])), GenesisDialect::Geth);
+    }
+
+    #[test]
+    fn geth_warns_unknown_fields() {
+        let geth = json!({
+            "config": { "chainId": 5, "ethash": { "custom": 1 }, "myChainField": 2 },
+            "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "gasUsed": "0x0",
+            "nodes": ["enode://abc"],
+            "alloc": { "0x0000000000000000000000000000000000000001": { "balance": "0x1" } }
+        });
+        let parsed = parse(geth).unwrap();
+        assert_eq!(parsed.dialect, GenesisDialect::Geth);
+        assert_eq!(parsed.genesis.config.extra_fields["myChainField"], 2);
+        assert_eq!(
+            parsed.warnings,
+            [
+                GenesisWarning::UnknownField("/config/ethash/custom".into()),
+                GenesisWarning::UnknownField("/nodes".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn besu_round_trip() {
+        let besu = json!({
+            "config": {
+                "chainId": 2018,
+                "londonBlock": 0,
+                "contractSizeLimit": 2147483647,
+                "ethash": { "fixeddifficulty": 100 },
+                "clique": { "blockperiodseconds": 2, "epochlength": 30000 },
+                "qbft": { "blockperiodseconds": 2, "requesttimeoutseconds": 4 }
+            },
+            "gasLimit": "0x1fffffffffffff",
+            "difficulty": "0x10000",
+            "alloc": {
+                "fe3b557e8fb62b89f4916b721be55ceb828dbd73": {
+                    "privateKey": "8f2a55949038a9610f50fb23b5883af3b4ecb3c3bb792cbcefbd1542c692be63",
+                    "comment": "private key and this comment are ignored",
+                    "balance": "0xad78ebc5ac6200000"
+                }
+            }
+        });
+        let parsed = parse(besu).unwrap();
+        assert_eq!(parsed.dialect, GenesisDialect::Besu);
+        assert_eq!(
+            parsed.warnings,
+            [
+                GenesisWarning::UnsupportedField("/config/ethash/fixeddifficulty".into()),
+                GenesisWarning::UnknownField(
+                    "/alloc/fe3b557e8fb62b89f4916b721be55ceb828dbd73/comment".into()
+                ),
+            ]
+        );
+
+        let genesis = &parsed.genesis;
+        assert_eq!(genesis.config.chain_id, 2018);
+        assert_eq!(genesis.config.clique.unwrap().period, Some(2));
+        assert_eq!(genesis.config.clique.unwrap().epoch, Some(30000));
+        assert_eq!(genesis.config.extra_fields["qbft"]["requesttimeoutseconds"], 4);
+        let account = &genesis.alloc[&address!("fe3b557e8fb62b89f4916b721be55ceb828dbd73")];
+        assert_eq!(
+            account.private_key,
+            Some(b256!("8f2a55949038a9610f50fb23b5883af3b4ecb3c3bb792cbcefbd1542c692be63"))
+        );
+
+        let (value, warnings) = to_dialect(genesis, GenesisDialect::Besu).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(
+            value["config"]["clique"],
+            json!({ "blockperiodseconds": 2, "epochlength": 30000 })
+        );
+        assert_eq!(value["config"]["contractSizeLimit"], 2147483647);
+        assert!(
+            value["alloc"]["0xfe3b557e8fb62b89f4916b721be55ceb828dbd73"]["privateKey"].is_string()
+        );
+
+        let reparsed = parse(value).unwrap();
+        assert_eq!(reparsed.dialect, GenesisDialect::Besu);
+        assert_eq!(&reparsed.genesis, genesis);
+    }
+
+    #[test]
+    fn nethermind_round_trip() {
+        let spec = json!({
+            "name": "Testnet",
+            "engine": { "Ethash": { "params": { "homesteadTransition": "0x0" } } },
+            "params": {
+                "chainID": "0x539",
+                "networkID": "0x539",
+                "eip150Transition": "0x0",
+                "eip155Transition": "0x0",
+                "eip1559Transition": "0x10",
+                "eip3855TransitionTimestamp": "0x64",
+                "terminalTotalDifficulty": "0x0",
+                "gasLimitBoundDivisor": "0x400"
+            },
+            "genesis": {
+                "seal": { "ethereum": { "nonce": "0x0000000000000042", "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000" } },
+                "difficulty": "0x400",
+                "author": "0x0000000000000000000000000000000000000000",
+                "timestamp": "0x0",
+                "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "extraData": "0x1234",
+                "gasLimit": "0x1388"
+            },
+            "accounts": {
+                "0x0000000000000000000000000000000000000001": {
+                    "builtin": { "name": "ecrecover", "pricing": { "linear": { "base": 3000, "word": 0 } } }
+                },
+                "0x0000000000000000000000000000000000000002": {
+                    "balance": "0x1",
+                    "builtin": { "name": "sha256", "pricing": { "linear": { "base": 60, "word": 12 } } }
+                },
+                "0x00000000000000000000000000000000000000aa": {
+                    "balance": "0x3e8",
+                    "nonce": "0x1",
+                    "code": "0x6000",
+                    "storage": { "0x01": "0x02" }
+                }
+            }
+        });
+        let parsed = parse(spec).unwrap();
+        assert_eq!(parsed.dialect, GenesisDialect::Nethermind);
+        assert_eq!(
+            parsed.warnings,
+            [
+                GenesisWarning::UnsupportedField("/params/gasLimitBoundDivisor".into()),
+                GenesisWarning::UnsupportedField(
+                    "/accounts/0x0000000000000000000000000000000000000001/builtin".into()
+                ),
+                GenesisWarning::UnsupportedField(
+                    "/accounts/0x0000000000000000000000000000000000000002/builtin".into()
+                ),
+                GenesisWarning::UnsupportedField("/name".into()),
+            ]
+        );
+
+        let genesis = &parsed.genesis;
+        assert_eq!(genesis.config.chain_id, 1337);
+        assert_eq!(genesis.config.homestead_block, Some(0));
+        assert_eq!(genesis.config.eip155_block, Some(0));
+        assert_eq!(genesis.config.london_block, Some(16));
+        assert_eq!(genesis.config.shanghai_time, Some(100));
+        assert_eq!(genesis.config.terminal_total_difficulty, Some(U256::ZERO));
+        assert!(genesis.config.ethash.is_some());
+        assert_eq!(genesis.nonce, 0x42);
+        assert_eq!(genesis.difficulty, U256::from(0x400));
+        assert_eq!(genesis.gas_limit, 0x1388);
+        assert_eq!(genesis.extra_data, Bytes::from_static(&[0x12, 0x34]));
+        assert_eq!(genesis.alloc.len(), 2);
+        let account = &genesis.alloc[&address!("00000000000000000000000000000000000000aa")];
+        assert_eq!(account.nonce, Some(1));
+        assert_eq!(account.storage.as_ref().unwrap().len(), 1);
+
+        let (value, warnings) = to_dialect(genesis, GenesisDialect::Nethermind).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(value["params"]["chainID"], "0x539");
+        assert_eq!(value["engine"]["Ethash"]["params"]["homesteadTransition"], "0x0");
+
+        let reparsed = parse(value).unwrap();
+        assert_eq!(reparsed.dialect, GenesisDialect::Nethermind);
+        assert!(reparsed.warnings.is_empty());
+        assert_eq!(&reparsed.genesis, genesis);
+    }
+
+    #[test]
+    fn anvil_round_trip() {
+        let dump = json!({
+            "block": {
+                "number": "0x5",
+                "coinbase": "0x0000000000000000000000000000000000000000",
+                "timestamp": "0x6553f100",
+                "gas_limit": "0x1c9c380",
+                "basefee": "0x3b9aca00",
+                "difficulty": "0x0",
+                "prevrandao": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "blob_excess_gas_and_price": { "excess_blob_gas": 0, "blob_gasprice": 1 }
+            },
+            "accounts": {
+                "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266": {
+                    "nonce": 1,
+                    "balance": "0x21e19e0c9bab2400000",
+                    "code": "0x",
+                    "storage": {}
+                }
+            },
+            "best_block_number": "0x5",
+            "blocks": [{ "header": {} }],
+            "transactions": []
+        });
+        let parsed = parse(dump).unwrap();
+        assert_eq!(parsed.dialect, GenesisDialect::Anvil);
+        assert_eq!(
+            parsed.warnings,
+            [
+                GenesisWarning::UnsupportedField("/best_block_number".into()),
+                GenesisWarning::UnsupportedField("/blocks".into()),
+            ]
+        );
+
+        let genesis = &parsed.genesis;
+        assert_eq!(genesis.number, Some(5));
+        assert_eq!(genesis.timestamp, 0x6553f100);
+        assert_eq!(genesis.gas_limit, 30_000_000);
+        assert_eq!(genesis.base_fee_per_gas, Some(1_000_000_000));
+        assert_eq!(genesis.excess_blob_gas, Some(0));
+        assert_eq!(genesis.mix_hash, B256::with_last_byte(1));
+        let account = &genesis.alloc[&address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266")];
+        assert_eq!(account.nonce, Some(1));
+        assert_eq!(account.balance, U256::from(10_000) * U256::from(10).pow(U256::from(18)));
+
+        let (value, warnings) = to_dialect(genesis, GenesisDialect::Anvil).unwrap();
+        assert_eq!(warnings, [GenesisWarning::UnsupportedField("/config/chainId".into())]);
+        assert_eq!(value["best_block_number"], "0x5");
+        assert_eq!(value["accounts"]["0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"]["nonce"], 1);
+
+        let mut reparsed = parse(value).unwrap();
+        assert_eq!(reparsed.dialect, GenesisDialect::Anvil);
+        // the excess blob gas is zero, which is not written
+        reparsed.genesis.excess_blob_gas = Some(0);
+        assert_eq!(&reparsed.genesis, genesis);
+    }
+}
@@ -11,8 +11,10 @@
 
 extern crate alloc;
 
+pub mod dialect;
+
 use alloc::{collections::BTreeMap, string::String};
-use alloy_eips::eip7840::BlobParams;
+use alloy_eips::{eip7840::BlobParams, Hardfork};
 use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
 use alloy_serde::{storage::deserialize_storage_map, ttd::deserialize_json_ttd_opt, OtherFields};
 use alloy_trie::{TrieAccount, EMPTY_ROOT_HASH, KECCAK_EMPTY};
@@ -576,6 +578,38 @@ impl ChainConfig {
             && self.is_active_at_timestamp(self.cancun_time, timestamp)
     }
 
+    /// Checks if the blockchain is active at or after the Prague fork block and the specified
+    /// timestamp.
+    pub fn is_prague_active_at_block_and_timestamp(&self, block: u64, timestamp: u64) -> bool {
+        self.is_london_active_at_block(block)
+            && self.is_active_at_timestamp(self.prague_time, timestamp)
+    }
+
+    /// Checks if the blockchain is active at or after the Osaka fork block and the specified
+    /// timestamp.
+    pub fn is_osaka_active_at_block_and_timestamp(&self, block: u64, timestamp: u64) -> bool {
+        self.is_london_active_at_block(block)
+            && self.is_active_at_timestamp(self.osaka_time, timestamp)
+    }
+
+    /// Returns the [`BlobParams`] of the latest blob-enabled hardfork that is active at the given
+    /// timestamp, or `None` if Cancun is not active.
+    ///
+    /// The parameters are taken from the [`blob_schedule`](Self::blob_schedule), falling back to
+    /// the default parameters of the hardfork if it has no entry.
+    pub fn blob_params_at_timestamp(&self, timestamp: u64) -> Option<BlobParams> {
+        [
+            (Hardfork::Osaka, self.osaka_time),
+            (Hardfork::Prague, self.prague_time),
+            (Hardfork::Cancun, self.cancun_time),
+        ]
+        .into_iter()
+        .filter(|(_, time)| self.is_active_at_timestamp(*time, timestamp))
+        .find_map(|(fork, _)| {
+            self.blob_schedule.get(fork.name()).copied().or_else(|| BlobParams::for_fork(fork))
+        })
+    }
+
     // Private function handling the comparison logic for block numbers
     fn is_active_at_block(&self, config_block: Option<u64>, block: u64) -> bool {
         config_block.is_some_and(|cb| cb <= block)
@@ -1741,4 +1775,33 @@ mod tests {
         // No code provided, so code hash should be KECCAK_EMPTY
         assert_eq!(trie_account.code_hash, KECCAK_EMPTY);
     }
+
+    #[test]
+    fn blob_schedule_lookup() {
+        let genesis = r#"
+        {
+          "config": {
+            "chainId": 1337,
+            "londonBlock": 0,
+            "cancunTime": 0,
+            "pragueTime": 100,
+            "osakaTime": 200,
+            "blobSchedule": {
+              "cancun": { "target": 3, "max": 6, "baseFeeUpdateFraction": 3338477 },
+              "osaka": { "target": 9, "max": 12, "baseFeeUpdateFraction": 5007716 }
+            }
+          },
+          "alloc": {}
+        }
+        "#;
+        let config = serde_json::from_str::<Genesis>(genesis).unwrap().config;
+
+        assert_eq!(config.blob_params_at_timestamp(0), Some(BlobParams::cancun()));
+        // falls back to the prague defaults without a schedule entry
+        assert_eq!(config.blob_params_at_timestamp(150), Some(BlobParams::prague()));
+        let osaka = config.blob_params_at_timestamp(200).unwrap();
+        assert_eq!((osaka.target_blob_count, osaka.max_blob_count), (9, 12));
+
+        assert_eq!(ChainConfig::default().blob_params_at_timestamp(u64::MAX), None);
+    }
 }
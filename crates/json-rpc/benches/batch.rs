@@ -0,0 +1,52 @@
+#![allow(missing_docs)]
+
+use alloy_json_rpc::{Id, Request, RequestPacket, ResponsePacket};
+use alloy_primitives::Address;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const SIZES: [u64; 3] = [1, 10, 100];
+
+fn requests(n: u64) -> Vec<Request<(Address, &'static str)>> {
+    (0..n)
+        .map(|i| {
+            let params = (Address::with_last_byte(i as u8), "latest");
+            Request::new("eth_getBalance", Id::Number(i), params)
+        })
+        .collect()
+}
+
+fn responses(n: u64) -> String {
+    let items = (0..n)
+        .map(|i| format!(r#"{{"jsonrpc":"2.0","id":{i},"result":"0x{:x}"}}"#, i * 1_000_000_007))
+        .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+}
+
+fn serialize_batch(c: &mut Criterion) {
+    let mut g = c.benchmark_group("serialize_batch");
+    for n in SIZES {
+        let batch = requests(n);
+        g.bench_with_input(BenchmarkId::from_parameter(n), &batch, |b, batch| {
+            b.iter(|| {
+                let requests =
+                    black_box(batch).iter().cloned().map(|req| req.serialize().unwrap()).collect();
+                RequestPacket::Batch(requests).serialize().unwrap()
+            })
+        });
+    }
+    g.finish();
+}
+
+fn deserialize_batch(c: &mut Criterion) {
+    let mut g = c.benchmark_group("deserialize_batch");
+    for n in SIZES {
+        let json = responses(n);
+        g.bench_with_input(BenchmarkId::from_parameter(n), &json, |b, json| {
+            b.iter(|| serde_json::from_str::<ResponsePacket>(black_box(json)).unwrap())
+        });
+    }
+    g.finish();
+}
+
+criterion_group!(benches, serialize_batch, deserialize_batch);
+criterion_main!(benches);
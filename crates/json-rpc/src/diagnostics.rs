@@ -0,0 +1,169 @@
+//! Reporting of failed deserializations of RPC responses and subscription items.
+//!
+//! When the `deser-diagnostics` feature is enabled, a hook can be installed with
+//! [`set_deser_failure_hook`]. It is invoked with a [`DeserFailure`] every time a typed
+//! deserialization performed by the alloy client stack fails, including subscription items that
+//! would otherwise be skipped silently.
+
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "deser-diagnostics")]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, RwLock,
+};
+
+/// The default maximum number of bytes of the raw payload captured in a [`DeserFailure`].
+pub const DEFAULT_DESER_CAPTURE_LIMIT: usize = 4096;
+
+/// The kind of payload that failed to deserialize.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeserContext {
+    /// The result of a request.
+    Response,
+    /// An item received from a subscription.
+    Subscription,
+}
+
+/// A report of a failed deserialization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeserFailure {
+    /// The kind of payload that failed to deserialize.
+    pub context: DeserContext,
+    /// The name of the type the payload was deserialized into.
+    pub type_name: &'static str,
+    /// The raw JSON payload, truncated to the capture limit.
+    pub raw: String,
+    /// The length of the full raw payload in bytes.
+    pub raw_len: usize,
+    /// The serde error message.
+    pub error: String,
+}
+
+impl DeserFailure {
+    /// Creates a new report, capturing at most `limit` bytes of the raw payload.
+    pub fn new<T: ?Sized>(
+        context: DeserContext,
+        raw: &str,
+        error: &serde_json::Error,
+        limit: usize,
+    ) -> Self {
+        let mut end = raw.len().min(limit);
+        while !raw.is_char_boundary(end) {
+            end -= 1;
+        }
+        Self {
+            context,
+            type_name: std::any::type_name::<T>(),
+            raw: raw[..end].to_owned(),
+            raw_len: raw.len(),
+            error: error.to_string(),
+        }
+    }
+
+    /// Returns `true` if the captured payload was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.raw.len() < self.raw_len
+    }
+}
+
+#[cfg(feature = "deser-diagnostics")]
+type Hook = Arc<dyn Fn(&DeserFailure) + Send + Sync>;
+
+#[cfg(feature = "deser-diagnostics")]
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+#[cfg(feature = "deser-diagnostics")]
+static CAPTURE_LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_DESER_CAPTURE_LIMIT);
+
+/// Installs a global hook that is called with a report of every failed deserialization.
+///
+/// Replaces any previously installed hook.
+///
+/// # Examples
+///
+/// ```
+/// alloy_json_rpc::set_deser_failure_hook(|failure| {
+///     eprintln!(
+///         "failed to deserialize {} ({}): {}",
+///         failure.type_name, failure.error, failure.raw
+///     );
+/// });
+/// ```
+#[cfg(feature = "deser-diagnostics")]
+pub fn set_deser_failure_hook(hook: impl Fn(&DeserFailure) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Removes the hook installed with [`set_deser_failure_hook`].
+#[cfg(feature = "deser-diagnostics")]
+pub fn clear_deser_failure_hook() {
+    HOOK.write().unwrap_or_else(|e| e.into_inner()).take();
+}
+
+/// Sets the maximum number of bytes of the raw payload captured in a [`DeserFailure`].
+///
+/// Defaults to [`DEFAULT_DESER_CAPTURE_LIMIT`].
+#[cfg(feature = "deser-diagnostics")]
+pub fn set_deser_capture_limit(limit: usize) {
+    CAPTURE_LIMIT.store(limit, Ordering::Relaxed);
+}
+
+/// Reports a failed deserialization of `raw` into `T` to the installed hook.
+///
+/// This is a no-op if no hook is installed or the `deser-diagnostics` feature is disabled.
+#[allow(unused_variables)]
+pub fn report_deser_failure<T: ?Sized>(context: DeserContext, raw: &str, err: &serde_json::Error) {
+    #[cfg(feature = "deser-diagnostics")]
+    {
+        let Some(hook) = HOOK.read().unwrap_or_else(|e| e.into_inner()).clone() else {
+            return;
+        };
+        let limit = CAPTURE_LIMIT.load(Ordering::Relaxed);
+        hook(&DeserFailure::new::<T>(context, raw, err, limit));
+    }
+}
+
+/// Deserializes `raw` into `T`, reporting failures with [`report_deser_failure`].
+pub fn deserialize_reported<T: DeserializeOwned>(
+    context: DeserContext,
+    raw: &str,
+) -> serde_json::Result<T> {
+    serde_json::from_str(raw).inspect_err(|err| report_deser_failure::<T>(context, raw, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_at_char_boundary() {
+        let err = serde_json::from_str::<u64>("\"é\"").unwrap_err();
+        let failure = DeserFailure::new::<u64>(DeserContext::Response, "\"é\"", &err, 2);
+        assert_eq!(failure.raw, "\"");
+        assert_eq!(failure.raw_len, 4);
+        assert!(failure.is_truncated());
+        assert_eq!(failure.type_name, "u64");
+    }
+
+    #[cfg(feature = "deser-diagnostics")]
+    #[test]
+    fn reports_to_hook() {
+        use std::sync::Mutex;
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let captured = reports.clone();
+        set_deser_failure_hook(move |failure| captured.lock().unwrap().push(failure.clone()));
+
+        assert_eq!(deserialize_reported::<u64>(DeserContext::Subscription, "1").unwrap(), 1);
+        assert!(deserialize_reported::<u64>(DeserContext::Subscription, "{\"a\":1}").is_err());
+        clear_deser_failure_hook();
+        assert!(deserialize_reported::<u64>(DeserContext::Subscription, "null").is_err());
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].context, DeserContext::Subscription);
+        assert_eq!(reports[0].raw, "{\"a\":1}");
+        assert!(!reports[0].is_truncated());
+    }
+}
@@ -82,6 +82,14 @@ use std::fmt::Debug;
 mod common;
 pub use common::Id;
 
+mod diagnostics;
+#[cfg(feature = "deser-diagnostics")]
+pub use diagnostics::{clear_deser_failure_hook, set_deser_capture_limit, set_deser_failure_hook};
+pub use diagnostics::{
+    deserialize_reported, report_deser_failure, DeserContext, DeserFailure,
+    DEFAULT_DESER_CAPTURE_LIMIT,
+};
+
 mod error;
 pub use error::RpcError;
 
@@ -91,6 +99,9 @@ pub use notification::{EthNotification, PubSubItem, SubId};
 mod packet;
 pub use packet::{BorrowedResponsePacket, RequestPacket, ResponsePacket};
 
+mod priority;
+pub use priority::RequestPriority;
+
 mod request;
 pub use request::{PartiallySerializedRequest, Request, RequestMeta, SerializedRequest};
 
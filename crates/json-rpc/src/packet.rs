@@ -1,4 +1,4 @@
-use crate::{ErrorPayload, Id, Response, SerializedRequest};
+use crate::{ErrorPayload, Id, RequestPriority, Response, SerializedRequest};
 use alloy_primitives::map::HashSet;
 use serde::{
     de::{self, Deserializer, MapAccess, SeqAccess, Visitor},
@@ -83,6 +83,14 @@ impl RequestPacket {
         self.len() == 0
     }
 
+    /// Returns the highest priority of the requests in the packet.
+    pub fn priority(&self) -> RequestPriority {
+        match self {
+            Self::Single(single) => single.priority(),
+            Self::Batch(batch) => batch.iter().map(|req| req.priority()).max().unwrap_or_default(),
+        }
+    }
+
     /// Push a request into the packet.
     pub fn push(&mut self, req: SerializedRequest) {
         match self {
@@ -0,0 +1,49 @@
+/// Methods that are [`RequestPriority::Critical`] unless a priority is set explicitly.
+const CRITICAL_METHODS: &[&str] = &["eth_sendRawTransaction", "eth_sendTransaction"];
+
+/// The priority class of a request.
+///
+/// Transport layers that queue or rate limit requests, such as the priority queue and retry
+/// layers of `alloy-transport`, serve higher priority requests first. This prevents bulk traffic,
+/// e.g. indexing or backfills, from starving latency-critical requests like transaction
+/// submission that share the same client.
+///
+/// Requests without an explicit priority are [`Normal`](Self::Normal), except for transaction
+/// submissions which are [`Critical`](Self::Critical).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RequestPriority {
+    /// Bulk traffic that may be delayed arbitrarily, e.g. backfills.
+    Background,
+    /// Regular traffic.
+    #[default]
+    Normal,
+    /// Latency-critical traffic, e.g. transaction submission.
+    Critical,
+}
+
+impl RequestPriority {
+    /// Returns the default priority of requests of the given method.
+    pub fn for_method(method: &str) -> Self {
+        if CRITICAL_METHODS.contains(&method) {
+            Self::Critical
+        } else {
+            Self::Normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordering() {
+        assert!(RequestPriority::Background < RequestPriority::Normal);
+        assert!(RequestPriority::Normal < RequestPriority::Critical);
+        assert_eq!(
+            RequestPriority::for_method("eth_sendRawTransaction"),
+            RequestPriority::Critical
+        );
+        assert_eq!(RequestPriority::for_method("eth_getLogs"), RequestPriority::Normal);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{common::Id, RpcBorrow, RpcSend};
+use crate::{common::Id, RequestPriority, RpcBorrow, RpcSend};
 use alloy_primitives::{keccak256, B256};
 use serde::{
     de::{DeserializeOwned, MapAccess},
@@ -17,12 +17,14 @@ pub struct RequestMeta {
     pub id: Id,
     /// Whether the request is a subscription, other than `eth_subscribe`.
     is_subscription: bool,
+    /// The explicitly set priority of the request.
+    priority: Option<RequestPriority>,
 }
 
 impl RequestMeta {
     /// Create a new `RequestMeta`.
     pub const fn new(method: Cow<'static, str>, id: Id) -> Self {
-        Self { method, id, is_subscription: false }
+        Self { method, id, is_subscription: false, priority: None }
     }
 
     /// Returns `true` if the request is a subscription.
@@ -41,6 +43,18 @@ impl RequestMeta {
     pub fn set_subscription_status(&mut self, sub: bool) {
         self.is_subscription = sub;
     }
+
+    /// Returns the priority of the request.
+    ///
+    /// Defaults to [`RequestPriority::for_method`] if no priority was set.
+    pub fn priority(&self) -> RequestPriority {
+        self.priority.unwrap_or_else(|| RequestPriority::for_method(&self.method))
+    }
+
+    /// Sets the priority of the request.
+    pub fn set_priority(&mut self, priority: RequestPriority) {
+        self.priority = Some(priority);
+    }
 }
 
 /// A JSON-RPC 2.0 request object.
@@ -83,6 +97,16 @@ impl<Params> Request<Params> {
         self.meta.set_subscription_status(sub);
     }
 
+    /// Returns the priority of the request.
+    pub fn priority(&self) -> RequestPriority {
+        self.meta.priority()
+    }
+
+    /// Sets the priority of the request.
+    pub fn set_priority(&mut self, priority: RequestPriority) {
+        self.meta.set_priority(priority);
+    }
+
     /// Change type of the request parameters.
     pub fn map_params<NewParams>(
         self,
@@ -333,6 +357,16 @@ impl SerializedRequest {
         self.meta.is_subscription()
     }
 
+    /// Returns the priority of the request.
+    pub fn priority(&self) -> RequestPriority {
+        self.meta.priority()
+    }
+
+    /// Sets the priority of the request.
+    pub fn set_priority(&mut self, priority: RequestPriority) {
+        self.meta.set_priority(priority);
+    }
+
     /// Returns the serialized request.
     pub const fn serialized(&self) -> &RawValue {
         &self.request
@@ -12,6 +12,7 @@ use std::{
     borrow::{Borrow, Cow},
     fmt,
     marker::PhantomData,
+    ops::RangeInclusive,
 };
 
 use crate::RpcSend;
@@ -81,6 +82,72 @@ impl<E> ErrorPayload<E> {
         Self { code: -32603, message, data: Some(data) }
     }
 
+    /// The range of codes reserved for implementation-defined server errors.
+    pub const SERVER_ERROR_CODES: RangeInclusive<i64> = -32099..=-32000;
+
+    /// Create a new error payload for a parse error with additional data.
+    pub const fn parse_error_with_obj(data: E) -> Self
+    where
+        E: RpcSend,
+    {
+        Self { code: -32700, message: Cow::Borrowed("Parse error"), data: Some(data) }
+    }
+
+    /// Create a new error payload for an invalid request with additional data.
+    pub const fn invalid_request_with_obj(data: E) -> Self
+    where
+        E: RpcSend,
+    {
+        Self { code: -32600, message: Cow::Borrowed("Invalid Request"), data: Some(data) }
+    }
+
+    /// Create a new error payload for an invalid params error with a custom message.
+    pub const fn invalid_params_message(message: Cow<'static, str>) -> Self {
+        Self { code: -32602, message, data: None }
+    }
+
+    /// Create a new error payload for an invalid params error with additional data.
+    pub const fn invalid_params_with_obj(data: E) -> Self
+    where
+        E: RpcSend,
+    {
+        Self { code: -32602, message: Cow::Borrowed("Invalid params"), data: Some(data) }
+    }
+
+    /// Create a new error payload for an implementation-defined server error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` is not in [`SERVER_ERROR_CODES`](Self::SERVER_ERROR_CODES).
+    pub fn server_error(code: i64, message: impl Into<Cow<'static, str>>) -> Self {
+        assert!(
+            Self::SERVER_ERROR_CODES.contains(&code),
+            "server error code {code} is outside of the reserved range -32099..=-32000"
+        );
+        Self { code, message: message.into(), data: None }
+    }
+
+    /// Attach data to the error payload.
+    pub fn with_data<T>(self, data: T) -> ErrorPayload<T>
+    where
+        T: RpcSend,
+    {
+        ErrorPayload { code: self.code, message: self.message, data: Some(data) }
+    }
+
+    /// Returns `true` if the error indicates that the server does not support
+    /// the requested method.
+    ///
+    /// Besides the standard `-32601` code, some nodes report disabled
+    /// namespaces with a custom code and a message like
+    /// `"the method ots_getApiLevel does not exist/is not available"`.
+    pub fn is_method_not_found(&self) -> bool {
+        self.code == -32601
+            || self.message.contains("does not exist/is not available")
+            || self.message.contains("method not found")
+            || self.message.contains("Method not found")
+    }
+
     /// Analyzes the [ErrorPayload] and decides if the request should be
     /// retried based on the error code or the message.
     pub fn is_retry_err(&self) -> bool {
@@ -125,6 +192,80 @@ impl<E> ErrorPayload<E> {
             }
         }
     }
+
+    /// Analyzes the [ErrorPayload] and decides if the error was caused by the
+    /// server's limit on the number of subscriptions per connection.
+    ///
+    /// Such errors are returned in response to `eth_subscribe` and indicate
+    /// that the subscription may succeed on a different connection. Only the
+    /// `-32005` "limit exceeded" code of [EIP-1474] and the generic `-32000`
+    /// server error code are considered, and the message must refer to
+    /// subscriptions.
+    ///
+    /// [EIP-1474]: https://eips.ethereum.org/EIPS/eip-1474
+    pub fn is_subscription_limit_err(&self) -> bool {
+        let msg = self.message.to_ascii_lowercase();
+
+        match self.code {
+            -32005 => msg.contains("subscription"),
+            // geth: `too many subscriptions`, erigon/nethermind variants
+            -32000 => {
+                msg.contains("too many subscriptions")
+                    // alchemy/infura: `subscription limit reached`, `exceeded subscription limit`
+                    || msg.contains("subscription limit")
+                    // quicknode: `max subscriptions per connection exceeded`
+                    || msg.contains("max subscriptions")
+                    || msg.contains("maximum number of subscriptions")
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ErrorPayload {
+    /// Create a new error payload for an invalid params error, hinting at the offending parameter.
+    ///
+    /// The data contains the parameter, by name or by its position in the params array, and the
+    /// reason it was rejected: `{"param": "fromBlock", "reason": "..."}`.
+    pub fn invalid_param(param: impl Serialize, reason: impl fmt::Display) -> Self {
+        #[derive(Serialize)]
+        struct InvalidParam<P> {
+            param: P,
+            reason: String,
+        }
+
+        let data = to_raw_value(&InvalidParam { param, reason: reason.to_string() }).ok();
+        Self { code: -32602, message: Cow::Borrowed("Invalid params"), data }
+    }
+
+    /// Create a sanitized internal error payload from an error.
+    ///
+    /// The error is not exposed to the client, so that internal details such as file paths or
+    /// connection strings do not leak. Use [`from_error_with_detail`](Self::from_error_with_detail)
+    /// in debug setups.
+    ///
+    /// Accepts any [`std::error::Error`], including `anyhow::Error` via `err.as_ref()`.
+    pub fn from_error(err: &(dyn std::error::Error + 'static)) -> Self {
+        tracing::debug!(%err, "converting error into internal error payload");
+        Self::internal_error()
+    }
+
+    /// Create an internal error payload from an error, exposing the error and its sources.
+    ///
+    /// The message is the error's display representation, and the data contains the messages of
+    /// its sources: `{"causes": ["...", ...]}`.
+    pub fn from_error_with_detail(err: &(dyn std::error::Error + 'static)) -> Self {
+        #[derive(Serialize)]
+        struct Detail {
+            causes: Vec<String>,
+        }
+
+        let causes = std::iter::successors(err.source(), |err| err.source())
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let data = if causes.is_empty() { None } else { to_raw_value(&Detail { causes }).ok() };
+        Self { code: -32603, message: Cow::Owned(err.to_string()), data }
+    }
 }
 
 impl<T> From<T> for ErrorPayload<T>
@@ -363,6 +504,50 @@ mod test {
     use super::BorrowedErrorPayload;
     use crate::ErrorPayload;
 
+    #[test]
+    fn standard_errors_with_data() {
+        let payload = ErrorPayload::invalid_param("fromBlock", "block range too large");
+        assert_eq!(payload.code, -32602);
+        assert_eq!(
+            payload.data.unwrap().get(),
+            r#"{"param":"fromBlock","reason":"block range too large"}"#
+        );
+
+        let payload = ErrorPayload::<()>::server_error(-32005, "limit exceeded").with_data(5u64);
+        assert_eq!((payload.code, payload.data), (-32005, Some(5)));
+
+        let payload = ErrorPayload::<()>::invalid_request_with_obj(());
+        assert_eq!(payload.code, -32600);
+    }
+
+    #[test]
+    #[should_panic = "outside of the reserved range"]
+    fn server_error_range() {
+        let _ = ErrorPayload::<()>::server_error(-32600, "not a server error");
+    }
+
+    #[test]
+    fn errors_from_std_errors() {
+        #[derive(Debug, thiserror::Error)]
+        #[error("failed to query database")]
+        struct Outer(#[source] std::io::Error);
+
+        let err = Outer(std::io::Error::other("connection to postgres://secret refused"));
+
+        let payload = ErrorPayload::from_error(&err);
+        assert_eq!(payload.code, -32603);
+        assert_eq!(payload.message, "Internal error");
+        assert!(payload.data.is_none());
+
+        let payload = ErrorPayload::from_error_with_detail(&err);
+        assert_eq!(payload.code, -32603);
+        assert_eq!(payload.message, "failed to query database");
+        assert_eq!(
+            payload.data.unwrap().get(),
+            r#"{"causes":["connection to postgres://secret refused"]}"#
+        );
+    }
+
     #[test]
     fn smooth_borrowing() {
         let json = r#"{ "code": -32000, "message": "b", "data": null }"#;
@@ -398,6 +583,43 @@ mod test {
         assert!(payload.data.is_none());
     }
 
+    #[test]
+    fn subscription_limit_error() {
+        let json = r#"{"code":-32000,"message":"Subscription limit reached for this connection"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert!(payload.is_subscription_limit_err());
+        assert!(!payload.is_retry_err());
+
+        let json = r#"{"code":-32000,"message":"too many subscriptions"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert!(payload.is_subscription_limit_err());
+
+        let json = r#"{"code":-32005,"message":"limit exceeded: 1000 subscriptions"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert!(payload.is_subscription_limit_err());
+
+        let json = r#"{"code":-32000,"message":"execution reverted"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert!(!payload.is_subscription_limit_err());
+
+        let json = r#"{"code":-32602,"message":"too many subscriptions"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert!(!payload.is_subscription_limit_err());
+    }
+
+    #[test]
+    fn method_not_found_error() {
+        assert!(ErrorPayload::<()>::method_not_found().is_method_not_found());
+
+        let json = r#"{"code":-32000,"message":"the method ots_getApiLevel does not exist/is not available"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert!(payload.is_method_not_found());
+
+        let json = r#"{"code":-32000,"message":"execution reverted"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert!(!payload.is_method_not_found());
+    }
+
     #[test]
     fn custom_error_decoding() {
         sol!(
@@ -1,7 +1,10 @@
 use crate::{ErrorPayload, RpcSend};
-use serde::{de::DeserializeOwned, Deserialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::value::{to_raw_value, RawValue};
-use std::borrow::{Borrow, Cow};
+use std::{
+    borrow::{Borrow, Cow},
+    fmt,
+};
 
 /// A JSON-RPC 2.0 response payload.
 ///
@@ -95,6 +98,44 @@ impl<Payload, ErrData> ResponsePayload<Payload, ErrData> {
         Self::Failure(ErrorPayload::internal_error_with_message_and_obj(message, data))
     }
 
+    /// Create a new error payload for a parse error with additional data.
+    pub const fn parse_error_with_obj(data: ErrData) -> Self
+    where
+        ErrData: RpcSend,
+    {
+        Self::Failure(ErrorPayload::parse_error_with_obj(data))
+    }
+
+    /// Create a new error payload for an invalid request with additional data.
+    pub const fn invalid_request_with_obj(data: ErrData) -> Self
+    where
+        ErrData: RpcSend,
+    {
+        Self::Failure(ErrorPayload::invalid_request_with_obj(data))
+    }
+
+    /// Create a new error payload for an invalid params error with a custom message.
+    pub const fn invalid_params_message(message: Cow<'static, str>) -> Self {
+        Self::Failure(ErrorPayload::invalid_params_message(message))
+    }
+
+    /// Create a new error payload for an invalid params error with additional data.
+    pub const fn invalid_params_with_obj(data: ErrData) -> Self
+    where
+        ErrData: RpcSend,
+    {
+        Self::Failure(ErrorPayload::invalid_params_with_obj(data))
+    }
+
+    /// Create a new error payload for an implementation-defined server error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` is not in [`ErrorPayload::SERVER_ERROR_CODES`].
+    pub fn server_error(code: i64, message: impl Into<Cow<'static, str>>) -> Self {
+        Self::Failure(ErrorPayload::server_error(code, message))
+    }
+
     /// Fallible conversion to the successful payload.
     pub const fn as_success(&self) -> Option<&Payload> {
         match self {
@@ -122,6 +163,29 @@ impl<Payload, ErrData> ResponsePayload<Payload, ErrData> {
     }
 }
 
+impl<Payload> ResponsePayload<Payload> {
+    /// Create a new error payload for an invalid params error, hinting at the offending parameter.
+    ///
+    /// See [`ErrorPayload::invalid_param`].
+    pub fn invalid_param(param: impl Serialize, reason: impl fmt::Display) -> Self {
+        Self::Failure(ErrorPayload::invalid_param(param, reason))
+    }
+
+    /// Create a sanitized internal error payload from an error.
+    ///
+    /// See [`ErrorPayload::from_error`].
+    pub fn from_error(err: &(dyn std::error::Error + 'static)) -> Self {
+        Self::Failure(ErrorPayload::from_error(err))
+    }
+
+    /// Create an internal error payload from an error, exposing the error and its sources.
+    ///
+    /// See [`ErrorPayload::from_error_with_detail`].
+    pub fn from_error_with_detail(err: &(dyn std::error::Error + 'static)) -> Self {
+        Self::Failure(ErrorPayload::from_error_with_detail(err))
+    }
+}
+
 impl<Payload, ErrData> ResponsePayload<Payload, ErrData>
 where
     Payload: RpcSend,
@@ -1,4 +1,4 @@
-use crate::{Response, ResponsePayload, RpcError, RpcRecv};
+use crate::{DeserContext, Response, ResponsePayload, RpcError, RpcRecv};
 use serde_json::value::RawValue;
 use std::borrow::Borrow;
 
@@ -61,7 +61,7 @@ where
     let json = result?;
     let json = json.borrow().get();
     trace!(ty=%std::any::type_name::<T>(), %json, "deserializing response");
-    serde_json::from_str(json)
+    crate::deserialize_reported(DeserContext::Response, json)
         .inspect(|response| trace!(?response, "deserialized response"))
         .inspect_err(|err| trace!(?err, "failed to deserialize response"))
         .map_err(|err| RpcError::deser_err(err, json))
@@ -0,0 +1,161 @@
+use crate::{
+    spec::{sync_committee_period_at_slot, ChainSpec},
+    LightClientStore, Result,
+};
+use alloy_primitives::B256;
+use alloy_rpc_types_beacon::light_client::{
+    LightClientBootstrap, LightClientFinalityUpdate, LightClientOptimisticUpdate,
+    LightClientResponse, LightClientUpdate,
+};
+use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use url::Url;
+
+/// The maximum number of updates the beacon API serves per request.
+pub const MAX_REQUEST_LIGHT_CLIENT_UPDATES: u64 = 128;
+
+/// A client of the light client endpoints of a beacon node.
+#[derive(Clone, Debug)]
+pub struct BeaconClient {
+    client: reqwest::Client,
+    url: Url,
+}
+
+impl BeaconClient {
+    /// Creates a new client for the beacon node at the given URL.
+    pub fn new(url: Url) -> Self {
+        Self::with_client(reqwest::Client::new(), url)
+    }
+
+    /// Creates a new client for the beacon node at the given URL using the given HTTP client.
+    pub const fn with_client(client: reqwest::Client, url: Url) -> Self {
+        Self { client, url }
+    }
+
+    /// Fetches the bootstrap of the block with the given root.
+    ///
+    /// GET `/eth/v1/beacon/light_client/bootstrap/{block_root}`
+    pub async fn bootstrap(&self, block_root: B256) -> Result<LightClientBootstrap> {
+        self.get::<LightClientResponse<_>>(
+            &format!("eth/v1/beacon/light_client/bootstrap/{block_root}"),
+            &[],
+        )
+        .await
+        .map(|response| response.data)
+    }
+
+    /// Fetches the best updates of `count` sync committee periods, starting at `start_period`.
+    ///
+    /// GET `/eth/v1/beacon/light_client/updates`
+    pub async fn updates(&self, start_period: u64, count: u64) -> Result<Vec<LightClientUpdate>> {
+        self.get::<Vec<LightClientResponse<_>>>(
+            "eth/v1/beacon/light_client/updates",
+            &[("start_period", start_period), ("count", count)],
+        )
+        .await
+        .map(|responses| responses.into_iter().map(|response| response.data).collect())
+    }
+
+    /// Fetches the latest finality update.
+    ///
+    /// GET `/eth/v1/beacon/light_client/finality_update`
+    pub async fn finality_update(&self) -> Result<LightClientFinalityUpdate> {
+        self.get::<LightClientResponse<_>>("eth/v1/beacon/light_client/finality_update", &[])
+            .await
+            .map(|response| response.data)
+    }
+
+    /// Fetches the latest optimistic update.
+    ///
+    /// GET `/eth/v1/beacon/light_client/optimistic_update`
+    pub async fn optimistic_update(&self) -> Result<LightClientOptimisticUpdate> {
+        self.get::<LightClientResponse<_>>("eth/v1/beacon/light_client/optimistic_update", &[])
+            .await
+            .map(|response| response.data)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: &[(&str, u64)]) -> Result<T> {
+        let url = format!("{}/{path}", self.url.as_str().trim_end_matches('/'));
+        let response = self.client.get(url).query(query).send().await?.error_for_status()?;
+        Ok(response.json().await?)
+    }
+}
+
+/// A light client following the beacon chain through a [`BeaconClient`].
+///
+/// The verified state is shared through [`store`](Self::store), e.g. with a
+/// [`VerifiedProvider`](crate::VerifiedProvider), and advanced with [`sync`](Self::sync).
+#[derive(Clone, Debug)]
+pub struct LightClient {
+    beacon: BeaconClient,
+    store: Arc<RwLock<LightClientStore>>,
+}
+
+impl LightClient {
+    /// Bootstraps a light client from the block with the trusted root, e.g. a recent finalized
+    /// checkpoint obtained out of band.
+    pub async fn bootstrap(
+        beacon: BeaconClient,
+        spec: ChainSpec,
+        trusted_block_root: B256,
+    ) -> Result<Self> {
+        let bootstrap = beacon.bootstrap(trusted_block_root).await?;
+        let store = LightClientStore::bootstrap(spec, trusted_block_root, bootstrap)?;
+        Ok(Self { beacon, store: Arc::new(RwLock::new(store)) })
+    }
+
+    /// Returns the beacon client.
+    pub const fn beacon(&self) -> &BeaconClient {
+        &self.beacon
+    }
+
+    /// Returns the shared verified state.
+    pub fn store(&self) -> Arc<RwLock<LightClientStore>> {
+        self.store.clone()
+    }
+
+    /// Advances the verified state to the latest finalized and attested headers.
+    ///
+    /// Updates of all sync committee periods since the last sync are fetched and verified first,
+    /// followed by the latest finality and optimistic updates.
+    pub async fn sync(&self) -> Result<()> {
+        loop {
+            let (period, current_period, has_next) = {
+                let store = self.store.read();
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+                let current_slot = store.spec().slot_at(now.as_secs());
+                (
+                    store.period(),
+                    sync_committee_period_at_slot(current_slot),
+                    store.has_next_sync_committee(),
+                )
+            };
+            if period >= current_period && has_next {
+                break;
+            }
+
+            let count =
+                (current_period.saturating_sub(period) + 1).min(MAX_REQUEST_LIGHT_CLIENT_UPDATES);
+            let updates = self.beacon.updates(period, count).await?;
+            for update in &updates {
+                self.store.write().process_update(update)?;
+            }
+
+            // the beacon node may not serve updates up to the current period yet
+            let store = self.store.read();
+            if store.period() == period && store.has_next_sync_committee() == has_next {
+                break;
+            }
+        }
+
+        let finality_update = self.beacon.finality_update().await?;
+        self.store.write().process_finality_update(&finality_update)?;
+        let optimistic_update = self.beacon.optimistic_update().await?;
+        self.store.write().process_optimistic_update(&optimistic_update)?;
+        Ok(())
+    }
+}
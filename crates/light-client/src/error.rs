@@ -0,0 +1,74 @@
+use crate::spec::SYNC_COMMITTEE_SIZE;
+use alloy_primitives::{Address, B256};
+use alloy_transport::TransportError;
+use thiserror::Error;
+
+/// Light client result type.
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Error when syncing the light client or verifying state against it.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A request to the beacon node failed.
+    #[error(transparent)]
+    Beacon(#[from] reqwest::Error),
+    /// A request to the execution node failed.
+    #[error(transparent)]
+    Transport(#[from] TransportError),
+    /// The bootstrap header does not match the trusted block root.
+    #[error("bootstrap header does not match the trusted block root {0}")]
+    UntrustedBootstrap(B256),
+    /// A merkle branch of an update does not verify.
+    #[error("invalid merkle branch of the {0}")]
+    InvalidMerkleBranch(&'static str),
+    /// The slots of an update are not ordered correctly.
+    #[error(
+        "invalid update slots: signature slot {signature_slot}, attested slot {attested_slot}, \
+         finalized slot {finalized_slot}"
+    )]
+    InvalidSlots {
+        /// The slot at which the sync aggregate was included.
+        signature_slot: u64,
+        /// The slot of the attested header.
+        attested_slot: u64,
+        /// The slot of the finalized header.
+        finalized_slot: u64,
+    },
+    /// The update does not belong to the current or next sync committee period of the store.
+    #[error("update for sync committee period {update} does not follow store period {store}")]
+    UnexpectedPeriod {
+        /// The sync committee period of the update.
+        update: u64,
+        /// The sync committee period of the store.
+        store: u64,
+    },
+    /// The update requires the sync committee of the next period, which is not known yet.
+    #[error("sync committee of the next period is unknown")]
+    UnknownNextSyncCommittee,
+    /// Less than two thirds of the sync committee signed the update.
+    #[error("insufficient sync committee participation: {participants} of {SYNC_COMMITTEE_SIZE}")]
+    InsufficientParticipation {
+        /// The number of participating committee members.
+        participants: usize,
+    },
+    /// A sync committee public key is invalid.
+    #[error("invalid sync committee public key")]
+    InvalidPublicKey,
+    /// The sync committee signature is invalid.
+    #[error("invalid sync committee signature")]
+    InvalidSignature,
+    /// The account proof does not verify against the verified state root.
+    #[error("invalid account proof for {0}")]
+    InvalidAccountProof(Address),
+    /// The storage proof does not verify against the verified storage root.
+    #[error("invalid storage proof for slot {slot} of {address}")]
+    InvalidStorageProof {
+        /// The account address.
+        address: Address,
+        /// The storage slot.
+        slot: B256,
+    },
+    /// The returned code does not match the verified code hash.
+    #[error("code of {0} does not match the verified code hash")]
+    CodeHashMismatch(Address),
+}
@@ -0,0 +1,22 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/alloy.jpg",
+    html_favicon_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/favicon.ico"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+pub mod spec;
+pub mod ssz;
+
+mod client;
+pub use client::{BeaconClient, LightClient, MAX_REQUEST_LIGHT_CLIENT_UPDATES};
+
+mod error;
+pub use error::{Error, Result};
+
+mod provider;
+pub use provider::{verify_account_proof, VerifiedProvider};
+
+mod store;
+pub use store::LightClientStore;
@@ -0,0 +1,216 @@
+use crate::{Error, LightClient, LightClientStore, Result};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::EIP1186AccountProofResponse;
+use alloy_trie::{proof::verify_proof, Nibbles, TrieAccount, EMPTY_ROOT_HASH, KECCAK_EMPTY};
+use parking_lot::RwLock;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A provider whose state reads are verified against a [`LightClientStore`].
+///
+/// Reads are served with `eth_getProof` at the execution block of the latest verified header, and
+/// the returned proofs are checked against its state root. By default the latest finalized header
+/// is used, see [`with_optimistic`](Self::with_optimistic).
+#[derive(Clone, Debug)]
+pub struct VerifiedProvider<P, N = Ethereum> {
+    provider: P,
+    store: Arc<RwLock<LightClientStore>>,
+    optimistic: bool,
+    _network: PhantomData<N>,
+}
+
+impl<P, N> VerifiedProvider<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new verified provider from an untrusted provider and the verified state.
+    pub const fn new(provider: P, store: Arc<RwLock<LightClientStore>>) -> Self {
+        Self { provider, store, optimistic: false, _network: PhantomData }
+    }
+
+    /// Creates a new verified provider from an untrusted provider and a light client.
+    pub fn from_light_client(provider: P, light_client: &LightClient) -> Self {
+        Self::new(provider, light_client.store())
+    }
+
+    /// Sets whether reads are verified against the latest attested header instead of the latest
+    /// finalized header.
+    pub const fn with_optimistic(mut self, optimistic: bool) -> Self {
+        self.optimistic = optimistic;
+        self
+    }
+
+    /// Returns the untrusted provider.
+    pub const fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Returns the number and state root of the execution block reads are verified against.
+    pub fn verified_block(&self) -> (u64, B256) {
+        let store = self.store.read();
+        let header = if self.optimistic {
+            store.optimistic_execution()
+        } else {
+            store.finalized_execution()
+        };
+        (header.block_number, header.state_root)
+    }
+
+    /// Returns the verified account of the given address.
+    pub async fn get_account(&self, address: Address) -> Result<TrieAccount> {
+        let (number, state_root) = self.verified_block();
+        let proof = self.provider.get_proof(address, vec![]).number(number).await?;
+        verify_account_proof(state_root, address, &proof)
+    }
+
+    /// Returns the verified balance of the given address.
+    pub async fn get_balance(&self, address: Address) -> Result<U256> {
+        self.get_account(address).await.map(|account| account.balance)
+    }
+
+    /// Returns the verified nonce of the given address.
+    pub async fn get_transaction_count(&self, address: Address) -> Result<u64> {
+        self.get_account(address).await.map(|account| account.nonce)
+    }
+
+    /// Returns the verified value of the storage slot of the given address.
+    pub async fn get_storage_at(&self, address: Address, slot: U256) -> Result<U256> {
+        let (number, state_root) = self.verified_block();
+        let slot = B256::from(slot);
+        let proof = self.provider.get_proof(address, vec![slot]).number(number).await?;
+        let account = verify_account_proof(state_root, address, &proof)?;
+
+        let storage_proof = proof
+            .storage_proof
+            .iter()
+            .find(|proof| proof.key.as_b256() == slot)
+            .ok_or(Error::InvalidStorageProof { address, slot })?;
+        let expected =
+            (!storage_proof.value.is_zero()).then(|| alloy_rlp::encode(&storage_proof.value));
+        verify_proof(
+            account.storage_root,
+            Nibbles::unpack(keccak256(slot)),
+            expected,
+            &storage_proof.proof,
+        )
+        .map_err(|_| Error::InvalidStorageProof { address, slot })?;
+        Ok(storage_proof.value)
+    }
+
+    /// Returns the verified code of the given address.
+    pub async fn get_code(&self, address: Address) -> Result<Bytes> {
+        let account = self.get_account(address).await?;
+        let (number, _) = self.verified_block();
+        let code = self.provider.get_code_at(address).number(number).await?;
+        if keccak256(&code) != account.code_hash {
+            return Err(Error::CodeHashMismatch(address));
+        }
+        Ok(code)
+    }
+}
+
+/// Verifies the account proof of `address` against the state root and returns the account.
+///
+/// Proofs of accounts that do not exist are verified as exclusion proofs.
+pub fn verify_account_proof(
+    state_root: B256,
+    address: Address,
+    proof: &EIP1186AccountProofResponse,
+) -> Result<TrieAccount> {
+    if proof.address != address {
+        return Err(Error::InvalidAccountProof(address));
+    }
+
+    let account = TrieAccount {
+        nonce: proof.nonce,
+        balance: proof.balance,
+        storage_root: if proof.storage_hash.is_zero() {
+            EMPTY_ROOT_HASH
+        } else {
+            proof.storage_hash
+        },
+        code_hash: if proof.code_hash.is_zero() { KECCAK_EMPTY } else { proof.code_hash },
+    };
+    let expected = (account != empty_account()).then(|| alloy_rlp::encode(&account));
+    verify_proof(state_root, Nibbles::unpack(keccak256(address)), expected, &proof.account_proof)
+        .map_err(|_| Error::InvalidAccountProof(address))?;
+    Ok(account)
+}
+
+const fn empty_account() -> TrieAccount {
+    TrieAccount {
+        nonce: 0,
+        balance: U256::ZERO,
+        storage_root: EMPTY_ROOT_HASH,
+        code_hash: KECCAK_EMPTY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use alloy_trie::{proof::ProofRetainer, HashBuilder};
+
+    fn account_proof(accounts: &[(Address, TrieAccount)], target: Address) -> (B256, Vec<Bytes>) {
+        let mut accounts = accounts
+            .iter()
+            .map(|(address, account)| (Nibbles::unpack(keccak256(address)), account))
+            .collect::<Vec<_>>();
+        accounts.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let target = Nibbles::unpack(keccak256(target));
+        let mut builder =
+            HashBuilder::default().with_proof_retainer(ProofRetainer::new(vec![target.clone()]));
+        for (key, account) in accounts {
+            builder.add_leaf(key, &alloy_rlp::encode(&account));
+        }
+        let root = builder.root();
+        let proof = builder
+            .take_proof_nodes()
+            .matching_nodes_sorted(&target)
+            .into_iter()
+            .map(|(_, node)| node)
+            .collect();
+        (root, proof)
+    }
+
+    #[test]
+    fn account_proofs() {
+        let alice = address!("00000000000000000000000000000000000a11ce");
+        let bob = address!("0000000000000000000000000000000000000b0b");
+        let account =
+            TrieAccount { nonce: 7, balance: U256::from(10u64.pow(18)), ..empty_account() };
+        let accounts = [(alice, account), (bob, TrieAccount { nonce: 1, ..empty_account() })];
+
+        let (state_root, account_proof) = account_proof(&accounts, alice);
+        let mut proof = EIP1186AccountProofResponse {
+            address: alice,
+            balance: account.balance,
+            code_hash: account.code_hash,
+            nonce: account.nonce,
+            storage_hash: account.storage_root,
+            account_proof,
+            storage_proof: vec![],
+        };
+        assert_eq!(verify_account_proof(state_root, alice, &proof).unwrap(), account);
+        assert!(verify_account_proof(state_root, bob, &proof).is_err());
+
+        proof.balance += U256::from(1);
+        assert!(verify_account_proof(state_root, alice, &proof).is_err());
+    }
+
+    #[test]
+    fn missing_account_proof() {
+        let alice = address!("00000000000000000000000000000000000a11ce");
+        let bob = address!("0000000000000000000000000000000000000b0b");
+        let accounts = [(alice, TrieAccount { nonce: 1, ..empty_account() })];
+
+        let (state_root, account_proof) = account_proof(&accounts, bob);
+        let proof =
+            EIP1186AccountProofResponse { address: bob, account_proof, ..Default::default() };
+        assert_eq!(verify_account_proof(state_root, bob, &proof).unwrap(), empty_account());
+    }
+}
@@ -0,0 +1,184 @@
+//! Beacon chain parameters required to verify light client updates.
+
+use crate::ssz::{bytes4_chunk, hash_pair};
+use alloy_primitives::{b256, FixedBytes, B256};
+
+/// The number of slots in an epoch.
+pub const SLOTS_PER_EPOCH: u64 = 32;
+
+/// The number of epochs in a sync committee period.
+pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
+
+/// The duration of a slot in seconds.
+pub const SECONDS_PER_SLOT: u64 = 12;
+
+/// The number of members of a sync committee.
+pub const SYNC_COMMITTEE_SIZE: usize = 512;
+
+/// The domain type of sync committee signatures.
+pub const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+
+/// Generalized index of the finalized checkpoint root in the beacon state before Electra.
+pub const FINALIZED_ROOT_GINDEX: u64 = 105;
+
+/// Generalized index of the current sync committee in the beacon state before Electra.
+pub const CURRENT_SYNC_COMMITTEE_GINDEX: u64 = 54;
+
+/// Generalized index of the next sync committee in the beacon state before Electra.
+pub const NEXT_SYNC_COMMITTEE_GINDEX: u64 = 55;
+
+/// Generalized index of the finalized checkpoint root in the beacon state since Electra.
+pub const FINALIZED_ROOT_GINDEX_ELECTRA: u64 = 169;
+
+/// Generalized index of the current sync committee in the beacon state since Electra.
+pub const CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 86;
+
+/// Generalized index of the next sync committee in the beacon state since Electra.
+pub const NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 87;
+
+/// Generalized index of the execution payload in the beacon block body.
+pub const EXECUTION_PAYLOAD_GINDEX: u64 = 25;
+
+/// Returns the epoch of the given slot.
+pub const fn epoch_at_slot(slot: u64) -> u64 {
+    slot / SLOTS_PER_EPOCH
+}
+
+/// Returns the sync committee period of the given slot.
+pub const fn sync_committee_period_at_slot(slot: u64) -> u64 {
+    epoch_at_slot(slot) / EPOCHS_PER_SYNC_COMMITTEE_PERIOD
+}
+
+/// A scheduled fork of the beacon chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fork {
+    /// The epoch at which the fork activates.
+    pub epoch: u64,
+    /// The fork version.
+    pub version: FixedBytes<4>,
+}
+
+/// The parameters of a beacon chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainSpec {
+    /// The genesis time in seconds since the unix epoch.
+    pub genesis_time: u64,
+    /// The root of the genesis validators.
+    pub genesis_validators_root: B256,
+    /// The forks of the chain, in activation order.
+    pub forks: Vec<Fork>,
+    /// The activation epoch of Electra, which changed the beacon state layout.
+    pub electra_epoch: u64,
+}
+
+impl ChainSpec {
+    /// Returns the parameters of Ethereum mainnet.
+    pub fn mainnet() -> Self {
+        let fork = |epoch, version: [u8; 4]| Fork { epoch, version: version.into() };
+        Self {
+            genesis_time: 1606824023,
+            genesis_validators_root: b256!(
+                "4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95"
+            ),
+            forks: vec![
+                fork(0, [0, 0, 0, 0]),
+                fork(74240, [1, 0, 0, 0]),
+                fork(144896, [2, 0, 0, 0]),
+                fork(194048, [3, 0, 0, 0]),
+                fork(269568, [4, 0, 0, 0]),
+                fork(364032, [5, 0, 0, 0]),
+            ],
+            electra_epoch: 364032,
+        }
+    }
+
+    /// Returns the fork version active at the given slot.
+    pub fn fork_version(&self, slot: u64) -> FixedBytes<4> {
+        let epoch = epoch_at_slot(slot);
+        self.forks
+            .iter()
+            .rev()
+            .find(|fork| fork.epoch <= epoch)
+            .map(|fork| fork.version)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the beacon state at the given slot uses the Electra layout.
+    pub const fn is_electra(&self, slot: u64) -> bool {
+        epoch_at_slot(slot) >= self.electra_epoch
+    }
+
+    /// Returns the generalized index of the finalized checkpoint root in the state at `slot`.
+    pub const fn finalized_root_gindex(&self, slot: u64) -> u64 {
+        if self.is_electra(slot) {
+            FINALIZED_ROOT_GINDEX_ELECTRA
+        } else {
+            FINALIZED_ROOT_GINDEX
+        }
+    }
+
+    /// Returns the generalized index of the current sync committee in the state at `slot`.
+    pub const fn current_sync_committee_gindex(&self, slot: u64) -> u64 {
+        if self.is_electra(slot) {
+            CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA
+        } else {
+            CURRENT_SYNC_COMMITTEE_GINDEX
+        }
+    }
+
+    /// Returns the generalized index of the next sync committee in the state at `slot`.
+    pub const fn next_sync_committee_gindex(&self, slot: u64) -> u64 {
+        if self.is_electra(slot) {
+            NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA
+        } else {
+            NEXT_SYNC_COMMITTEE_GINDEX
+        }
+    }
+
+    /// Returns the slot at the given unix timestamp.
+    pub const fn slot_at(&self, timestamp: u64) -> u64 {
+        timestamp.saturating_sub(self.genesis_time) / SECONDS_PER_SLOT
+    }
+
+    /// Returns the root that sync committee members sign for the block with the given root, when
+    /// the signature is included at `signature_slot`.
+    pub fn sync_committee_signing_root(&self, block_root: B256, signature_slot: u64) -> B256 {
+        let fork_version = self.fork_version(signature_slot.max(1) - 1);
+        let fork_data_root =
+            hash_pair(&bytes4_chunk(fork_version.0), &self.genesis_validators_root);
+        let mut domain = B256::ZERO;
+        domain[..4].copy_from_slice(&DOMAIN_SYNC_COMMITTEE);
+        domain[4..].copy_from_slice(&fork_data_root[..28]);
+        hash_pair(&block_root, &domain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mainnet_forks() {
+        let spec = ChainSpec::mainnet();
+        assert_eq!(spec.fork_version(0), FixedBytes([0, 0, 0, 0]));
+        assert_eq!(spec.fork_version(74240 * SLOTS_PER_EPOCH - 1), FixedBytes([0, 0, 0, 0]));
+        assert_eq!(spec.fork_version(74240 * SLOTS_PER_EPOCH), FixedBytes([1, 0, 0, 0]));
+        assert_eq!(spec.fork_version(u64::MAX), FixedBytes([5, 0, 0, 0]));
+
+        assert_eq!(spec.finalized_root_gindex(269568 * SLOTS_PER_EPOCH), FINALIZED_ROOT_GINDEX);
+        assert_eq!(
+            spec.finalized_root_gindex(364032 * SLOTS_PER_EPOCH),
+            FINALIZED_ROOT_GINDEX_ELECTRA
+        );
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(sync_committee_period_at_slot(8191), 0);
+        assert_eq!(sync_committee_period_at_slot(8192), 1);
+
+        let spec = ChainSpec::mainnet();
+        assert_eq!(spec.slot_at(spec.genesis_time - 1), 0);
+        assert_eq!(spec.slot_at(spec.genesis_time + 25), 2);
+    }
+}
@@ -0,0 +1,202 @@
+//! SSZ merkleization of the containers verified by the light client.
+//!
+//! Only the `hash_tree_root` of the few containers that appear in light client updates is
+//! implemented here, see also
+//! <https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md#merkleization>
+
+use alloy_primitives::{Address, B256, U256};
+use alloy_rpc_types_beacon::{
+    header::BeaconBlockHeader,
+    light_client::{LightClientExecutionHeader, SyncCommittee},
+    BlsPublicKey,
+};
+use sha2::{Digest, Sha256};
+
+/// Returns `sha256(left ++ right)`.
+pub fn hash_pair(left: &B256, right: &B256) -> B256 {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    B256::from_slice(&hasher.finalize())
+}
+
+/// Merkleizes the given chunks, padding them with zero chunks up to `limit` rounded to the next
+/// power of two.
+pub fn merkleize(chunks: &[B256], limit: usize) -> B256 {
+    let mut width = limit.max(chunks.len()).next_power_of_two();
+    let mut layer = chunks.to_vec();
+    let mut zero = B256::ZERO;
+    while width > 1 {
+        if layer.len() % 2 == 1 {
+            layer.push(zero);
+        }
+        layer = layer.chunks(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect();
+        zero = hash_pair(&zero, &zero);
+        width /= 2;
+    }
+    layer.first().copied().unwrap_or(zero)
+}
+
+/// Verifies that `leaf` is included in the tree with the given `root` at the generalized index
+/// `gindex`.
+pub fn is_valid_merkle_branch(leaf: B256, branch: &[B256], gindex: u64, root: B256) -> bool {
+    if gindex == 0 {
+        return false;
+    }
+    let depth = gindex.ilog2() as usize;
+    if branch.len() != depth {
+        return false;
+    }
+    let index = gindex - (1 << depth);
+    let computed = branch.iter().enumerate().fold(leaf, |node, (i, sibling)| {
+        if (index >> i) & 1 == 1 {
+            hash_pair(sibling, &node)
+        } else {
+            hash_pair(&node, sibling)
+        }
+    });
+    computed == root
+}
+
+/// Returns the `hash_tree_root` of a beacon block header.
+pub fn beacon_header_root(header: &BeaconBlockHeader) -> B256 {
+    merkleize(
+        &[
+            u64_chunk(header.slot),
+            u64_chunk(header.proposer_index),
+            header.parent_root,
+            header.state_root,
+            header.body_root,
+        ],
+        5,
+    )
+}
+
+/// Returns the `hash_tree_root` of an execution payload header.
+///
+/// Headers without the blob gas fields are merkleized with the Capella layout.
+pub fn execution_header_root(header: &LightClientExecutionHeader) -> B256 {
+    let mut fields = vec![
+        header.parent_hash,
+        address_chunk(header.fee_recipient),
+        header.state_root,
+        header.receipts_root,
+        merkleize(&pack(header.logs_bloom.as_slice()), 8),
+        header.prev_randao,
+        u64_chunk(header.block_number),
+        u64_chunk(header.gas_limit),
+        u64_chunk(header.gas_used),
+        u64_chunk(header.timestamp),
+        mix_in_length(merkleize(&pack(&header.extra_data), 1), header.extra_data.len()),
+        u256_chunk(header.base_fee_per_gas),
+        header.block_hash,
+        header.transactions_root,
+        header.withdrawals_root,
+    ];
+    if let (Some(blob_gas_used), Some(excess_blob_gas)) =
+        (header.blob_gas_used, header.excess_blob_gas)
+    {
+        fields.push(u64_chunk(blob_gas_used));
+        fields.push(u64_chunk(excess_blob_gas));
+    }
+    merkleize(&fields, fields.len())
+}
+
+/// Returns the `hash_tree_root` of a sync committee.
+pub fn sync_committee_root(committee: &SyncCommittee) -> B256 {
+    let pubkeys = committee.pubkeys.iter().map(pubkey_root).collect::<Vec<_>>();
+    hash_pair(&merkleize(&pubkeys, pubkeys.len()), &pubkey_root(&committee.aggregate_pubkey))
+}
+
+/// Returns the `hash_tree_root` of a 4 byte vector, e.g. a fork version or domain type.
+pub(crate) fn bytes4_chunk(bytes: [u8; 4]) -> B256 {
+    let mut chunk = B256::ZERO;
+    chunk[..4].copy_from_slice(&bytes);
+    chunk
+}
+
+fn pubkey_root(pubkey: &BlsPublicKey) -> B256 {
+    merkleize(&pack(pubkey.as_slice()), 2)
+}
+
+fn mix_in_length(root: B256, len: usize) -> B256 {
+    hash_pair(&root, &u64_chunk(len as u64))
+}
+
+fn pack(bytes: &[u8]) -> Vec<B256> {
+    bytes
+        .chunks(32)
+        .map(|bytes| {
+            let mut chunk = B256::ZERO;
+            chunk[..bytes.len()].copy_from_slice(bytes);
+            chunk
+        })
+        .collect()
+}
+
+fn u64_chunk(value: u64) -> B256 {
+    let mut chunk = B256::ZERO;
+    chunk[..8].copy_from_slice(&value.to_le_bytes());
+    chunk
+}
+
+fn u256_chunk(value: U256) -> B256 {
+    B256::from(value.to_le_bytes::<32>())
+}
+
+fn address_chunk(address: Address) -> B256 {
+    let mut chunk = B256::ZERO;
+    chunk[..20].copy_from_slice(address.as_slice());
+    chunk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn zero_hashes() {
+        assert_eq!(merkleize(&[], 1), B256::ZERO);
+        assert_eq!(
+            merkleize(&[], 2),
+            b256!("f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b")
+        );
+        assert_eq!(
+            merkleize(&[], 4),
+            b256!("db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71")
+        );
+        assert_eq!(beacon_header_root(&BeaconBlockHeader::default()), merkleize(&[], 8));
+    }
+
+    #[test]
+    fn merkle_branch() {
+        let leaves = (0..8u64).map(u64_chunk).collect::<Vec<_>>();
+        let root = merkleize(&leaves, 8);
+
+        // leaf 5 has the generalized index 8 + 5 = 13
+        let branch = [
+            leaves[4],
+            hash_pair(&leaves[6], &leaves[7]),
+            hash_pair(&hash_pair(&leaves[0], &leaves[1]), &hash_pair(&leaves[2], &leaves[3])),
+        ];
+        assert!(is_valid_merkle_branch(leaves[5], &branch, 13, root));
+        assert!(!is_valid_merkle_branch(leaves[4], &branch, 13, root));
+        assert!(!is_valid_merkle_branch(leaves[5], &branch, 12, root));
+        assert!(!is_valid_merkle_branch(leaves[5], &branch[..2], 13, root));
+    }
+
+    #[test]
+    fn execution_header_layout() {
+        let mut header = LightClientExecutionHeader::default();
+        let capella = execution_header_root(&header);
+
+        header.blob_gas_used = Some(0);
+        header.excess_blob_gas = Some(0);
+        let deneb = execution_header_root(&header);
+        assert_ne!(capella, deneb);
+
+        header.extra_data = vec![1u8; 32].into();
+        assert_ne!(execution_header_root(&header), deneb);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    spec::{
+        sync_committee_period_at_slot, ChainSpec, EXECUTION_PAYLOAD_GINDEX, SYNC_COMMITTEE_SIZE,
+    },
+    ssz::{beacon_header_root, execution_header_root, is_valid_merkle_branch, sync_committee_root},
+    Error, Result,
+};
+use alloy_primitives::B256;
+use alloy_rpc_types_beacon::{
+    constants::BLS_DST_SIG,
+    light_client::{
+        LightClientBootstrap, LightClientExecutionHeader, LightClientFinalityUpdate,
+        LightClientHeader, LightClientOptimisticUpdate, LightClientSyncAggregate,
+        LightClientUpdate, SyncCommittee,
+    },
+};
+use blst::{
+    min_pk::{PublicKey, Signature},
+    BLST_ERROR,
+};
+
+/// The verified state of a light client.
+///
+/// The store is initialized from a trusted beacon block root and advanced with updates signed by
+/// a supermajority of the sync committee, following the Altair light client sync protocol. Only
+/// finalized headers are used to rotate sync committees, optimistic updates merely advance
+/// [`optimistic_header`](Self::optimistic_header).
+#[derive(Clone, Debug)]
+pub struct LightClientStore {
+    spec: ChainSpec,
+    finalized_header: LightClientHeader,
+    optimistic_header: LightClientHeader,
+    current_sync_committee: SyncCommittee,
+    next_sync_committee: Option<SyncCommittee>,
+}
+
+impl LightClientStore {
+    /// Initializes the store from a bootstrap of the block with the trusted root.
+    pub fn bootstrap(
+        spec: ChainSpec,
+        trusted_block_root: B256,
+        bootstrap: LightClientBootstrap,
+    ) -> Result<Self> {
+        let LightClientBootstrap { header, current_sync_committee, current_sync_committee_branch } =
+            bootstrap;
+
+        if beacon_header_root(&header.beacon) != trusted_block_root {
+            return Err(Error::UntrustedBootstrap(trusted_block_root));
+        }
+        verify_execution_branch(&header)?;
+        if !is_valid_merkle_branch(
+            sync_committee_root(&current_sync_committee),
+            &current_sync_committee_branch,
+            spec.current_sync_committee_gindex(header.beacon.slot),
+            header.beacon.state_root,
+        ) {
+            return Err(Error::InvalidMerkleBranch("current sync committee"));
+        }
+
+        Ok(Self {
+            spec,
+            optimistic_header: header.clone(),
+            finalized_header: header,
+            current_sync_committee,
+            next_sync_committee: None,
+        })
+    }
+
+    /// Returns the chain parameters of the store.
+    pub const fn spec(&self) -> &ChainSpec {
+        &self.spec
+    }
+
+    /// Returns the latest verified finalized header.
+    pub const fn finalized_header(&self) -> &LightClientHeader {
+        &self.finalized_header
+    }
+
+    /// Returns the latest verified attested header.
+    pub const fn optimistic_header(&self) -> &LightClientHeader {
+        &self.optimistic_header
+    }
+
+    /// Returns the execution payload header of the latest verified finalized header.
+    pub const fn finalized_execution(&self) -> &LightClientExecutionHeader {
+        &self.finalized_header.execution
+    }
+
+    /// Returns the execution payload header of the latest verified attested header.
+    pub const fn optimistic_execution(&self) -> &LightClientExecutionHeader {
+        &self.optimistic_header.execution
+    }
+
+    /// Returns the sync committee period of the latest finalized header.
+    pub const fn period(&self) -> u64 {
+        sync_committee_period_at_slot(self.finalized_header.beacon.slot)
+    }
+
+    /// Returns whether the sync committee of the next period is known.
+    pub const fn has_next_sync_committee(&self) -> bool {
+        self.next_sync_committee.is_some()
+    }
+
+    /// Verifies and applies an update from `light_client/updates`.
+    pub fn process_update(&mut self, update: &LightClientUpdate) -> Result<()> {
+        self.process(
+            &update.attested_header,
+            Some((&update.finalized_header, update.finality_branch.as_slice())),
+            Some((&update.next_sync_committee, update.next_sync_committee_branch.as_slice())),
+            &update.sync_aggregate,
+            update.signature_slot,
+        )
+    }
+
+    /// Verifies and applies an update from `light_client/finality_update`.
+    pub fn process_finality_update(&mut self, update: &LightClientFinalityUpdate) -> Result<()> {
+        self.process(
+            &update.attested_header,
+            Some((&update.finalized_header, update.finality_branch.as_slice())),
+            None,
+            &update.sync_aggregate,
+            update.signature_slot,
+        )
+    }
+
+    /// Verifies and applies an update from `light_client/optimistic_update`.
+    pub fn process_optimistic_update(
+        &mut self,
+        update: &LightClientOptimisticUpdate,
+    ) -> Result<()> {
+        self.process(
+            &update.attested_header,
+            None,
+            None,
+            &update.sync_aggregate,
+            update.signature_slot,
+        )
+    }
+
+    fn process(
+        &mut self,
+        attested: &LightClientHeader,
+        finalized: Option<(&LightClientHeader, &[B256])>,
+        next_sync_committee: Option<(&SyncCommittee, &[B256])>,
+        sync_aggregate: &LightClientSyncAggregate,
+        signature_slot: u64,
+    ) -> Result<()> {
+        let attested_slot = attested.beacon.slot;
+        let finalized_slot = finalized.map(|(header, _)| header.beacon.slot).unwrap_or_default();
+        if signature_slot <= attested_slot || attested_slot < finalized_slot {
+            return Err(Error::InvalidSlots { signature_slot, attested_slot, finalized_slot });
+        }
+
+        let store_period = self.period();
+        let signature_period = sync_committee_period_at_slot(signature_slot);
+        let sync_committee = if signature_period == store_period {
+            &self.current_sync_committee
+        } else if signature_period == store_period + 1 {
+            self.next_sync_committee.as_ref().ok_or(Error::UnknownNextSyncCommittee)?
+        } else {
+            return Err(Error::UnexpectedPeriod { update: signature_period, store: store_period });
+        };
+
+        verify_execution_branch(attested)?;
+        if let Some((header, branch)) = finalized {
+            verify_execution_branch(header)?;
+            if !is_valid_merkle_branch(
+                beacon_header_root(&header.beacon),
+                branch,
+                self.spec.finalized_root_gindex(attested_slot),
+                attested.beacon.state_root,
+            ) {
+                return Err(Error::InvalidMerkleBranch("finalized header"));
+            }
+        }
+        if let Some((committee, branch)) = next_sync_committee {
+            if !is_valid_merkle_branch(
+                sync_committee_root(committee),
+                branch,
+                self.spec.next_sync_committee_gindex(attested_slot),
+                attested.beacon.state_root,
+            ) {
+                return Err(Error::InvalidMerkleBranch("next sync committee"));
+            }
+        }
+
+        let signing_root = self
+            .spec
+            .sync_committee_signing_root(beacon_header_root(&attested.beacon), signature_slot);
+        verify_sync_aggregate(sync_committee, sync_aggregate, signing_root)?;
+
+        let finalized = finalized
+            .map(|(header, _)| header)
+            .filter(|header| header.beacon.slot > self.finalized_header.beacon.slot);
+        let rotate = match finalized.map(|header| sync_committee_period_at_slot(header.beacon.slot))
+        {
+            Some(period) if period == store_period + 1 => true,
+            Some(period) if period != store_period => {
+                return Err(Error::UnexpectedPeriod { update: period, store: store_period });
+            }
+            _ => false,
+        };
+
+        if rotate {
+            self.current_sync_committee =
+                self.next_sync_committee.take().ok_or(Error::UnknownNextSyncCommittee)?;
+        }
+        if let Some(header) = finalized {
+            self.finalized_header = header.clone();
+        }
+        if attested_slot > self.optimistic_header.beacon.slot {
+            self.optimistic_header = attested.clone();
+        }
+
+        if let Some((committee, _)) = next_sync_committee {
+            if self.next_sync_committee.is_none()
+                && sync_committee_period_at_slot(attested_slot) == self.period()
+            {
+                self.next_sync_committee = Some(committee.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn verify_execution_branch(header: &LightClientHeader) -> Result<()> {
+    if is_valid_merkle_branch(
+        execution_header_root(&header.execution),
+        &header.execution_branch,
+        EXECUTION_PAYLOAD_GINDEX,
+        header.beacon.body_root,
+    ) {
+        Ok(())
+    } else {
+        Err(Error::InvalidMerkleBranch("execution payload header"))
+    }
+}
+
+/// Returns the indices of the committee members that participated in the sync aggregate.
+fn participants(bits: &[u8]) -> impl Iterator<Item = usize> + '_ {
+    (0..SYNC_COMMITTEE_SIZE.min(bits.len() * 8)).filter(|i| (bits[i / 8] >> (i % 8)) & 1 == 1)
+}
+
+fn verify_sync_aggregate(
+    committee: &SyncCommittee,
+    sync_aggregate: &LightClientSyncAggregate,
+    signing_root: B256,
+) -> Result<()> {
+    let participants = participants(&sync_aggregate.sync_committee_bits).collect::<Vec<_>>();
+    if participants.len() * 3 < SYNC_COMMITTEE_SIZE * 2 {
+        return Err(Error::InsufficientParticipation { participants: participants.len() });
+    }
+
+    let pubkeys = participants
+        .into_iter()
+        .map(|i| {
+            let pubkey = committee.pubkeys.get(i).ok_or(Error::InvalidPublicKey)?;
+            PublicKey::from_bytes(pubkey.as_slice()).map_err(|_| Error::InvalidPublicKey)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let pubkeys = pubkeys.iter().collect::<Vec<_>>();
+
+    let signature = Signature::from_bytes(sync_aggregate.sync_committee_signature.as_slice())
+        .map_err(|_| Error::InvalidSignature)?;
+    match signature.fast_aggregate_verify(true, signing_root.as_slice(), BLS_DST_SIG, &pubkeys) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(Error::InvalidSignature),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn participation_bits() {
+        let mut bits = vec![0u8; SYNC_COMMITTEE_SIZE / 8];
+        bits[0] = 0b1000_0001;
+        bits[63] = 0b1000_0000;
+        assert_eq!(participants(&bits).collect::<Vec<_>>(), vec![0, 7, 511]);
+        assert_eq!(participants(&[0xff; 128]).count(), SYNC_COMMITTEE_SIZE);
+    }
+
+    #[test]
+    fn insufficient_participation() {
+        let mut bits = vec![0xffu8; SYNC_COMMITTEE_SIZE / 8];
+        bits[..23].fill(0);
+        let sync_aggregate =
+            LightClientSyncAggregate { sync_committee_bits: bits.into(), ..Default::default() };
+        let err = verify_sync_aggregate(&SyncCommittee::default(), &sync_aggregate, B256::ZERO)
+            .unwrap_err();
+        assert!(matches!(err, Error::InsufficientParticipation { participants: 328 }));
+    }
+
+    #[test]
+    fn bootstrap_rejects_untrusted_root() {
+        let err = LightClientStore::bootstrap(
+            ChainSpec::mainnet(),
+            B256::repeat_byte(1),
+            LightClientBootstrap::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::UntrustedBootstrap(_)));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::BlockTransactions;
-use alloy_consensus::{BlockHeader, Transaction};
-use alloy_primitives::{Address, BlockHash, TxHash, B256};
+use alloy_consensus::{BlockHeader, Eip658Value, Transaction};
+use alloy_eips::eip4895::Withdrawals;
+use alloy_primitives::{Address, BlockHash, Bytes, Log, TxHash, B256};
 use alloy_serde::WithOtherFields;
 
 /// Receipt JSON-RPC response.
@@ -55,6 +56,40 @@ pub trait ReceiptResponse {
     ///
     /// EIP98 makes this field optional.
     fn state_root(&self) -> Option<B256>;
+
+    /// Returns the logs emitted by the transaction.
+    fn logs(&self) -> impl Iterator<Item = &Log>;
+
+    /// Returns the [EIP-658] status of the transaction, or the post-transaction state root for
+    /// receipts from before [EIP-658].
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    fn status_or_post_state(&self) -> Eip658Value {
+        self.state_root().map_or_else(|| Eip658Value::Eip658(self.status()), Eip658Value::PostState)
+    }
+
+    /// Returns the revert data of a failed transaction, if the node included it in the receipt.
+    ///
+    /// Standard receipts do not contain the revert data, but some clients include it in a
+    /// `revertReason` field.
+    fn revert_reason(&self) -> Option<Bytes> {
+        None
+    }
+
+    /// Returns the fee paid for the execution gas of the transaction, excluding blob gas.
+    fn execution_fee(&self) -> u128 {
+        (self.gas_used() as u128).saturating_mul(self.effective_gas_price())
+    }
+
+    /// Returns the fee paid for the blob gas of the eip-4844 transaction.
+    fn blob_fee(&self) -> Option<u128> {
+        Some((self.blob_gas_used()? as u128).saturating_mul(self.blob_gas_price()?))
+    }
+
+    /// Returns the total fee paid by the transaction, including the blob fee.
+    fn total_fee(&self) -> u128 {
+        self.execution_fee().saturating_add(self.blob_fee().unwrap_or_default())
+    }
 }
 
 /// Transaction JSON-RPC response. Aggregates transaction data with its block and signer context.
@@ -123,6 +158,15 @@ pub trait BlockResponse {
     /// Mutable reference to block transactions
     fn transactions_mut(&mut self) -> &mut BlockTransactions<Self::Transaction>;
 
+    /// Block withdrawals, if the block has any.
+    ///
+    /// Returns `None` for blocks before [EIP-4895], and for networks without withdrawals.
+    ///
+    /// [EIP-4895]: https://eips.ethereum.org/EIPS/eip-4895
+    fn withdrawals(&self) -> Option<&Withdrawals> {
+        None
+    }
+
     /// Returns the `other` field from `WithOtherFields` type.
     fn other_fields(&self) -> Option<&alloy_serde::OtherFields> {
         None
@@ -207,6 +251,21 @@ impl<T: ReceiptResponse> ReceiptResponse for WithOtherFields<T> {
     fn state_root(&self) -> Option<B256> {
         self.inner.state_root()
     }
+
+    fn logs(&self) -> impl Iterator<Item = &Log> {
+        self.inner.logs()
+    }
+
+    fn status_or_post_state(&self) -> Eip658Value {
+        self.inner.status_or_post_state()
+    }
+
+    fn revert_reason(&self) -> Option<Bytes> {
+        self.other
+            .get_deserialized::<Bytes>("revertReason")
+            .and_then(Result::ok)
+            .or_else(|| self.inner.revert_reason())
+    }
 }
 
 impl<T: BlockResponse> BlockResponse for WithOtherFields<T> {
@@ -225,6 +284,10 @@ impl<T: BlockResponse> BlockResponse for WithOtherFields<T> {
         self.inner.transactions_mut()
     }
 
+    fn withdrawals(&self) -> Option<&Withdrawals> {
+        self.inner.withdrawals()
+    }
+
     fn other_fields(&self) -> Option<&alloy_serde::OtherFields> {
         Some(&self.other)
     }
@@ -0,0 +1,256 @@
+//! Multi-dimensional fee model abstraction.
+
+use crate::{AnyNetwork, Ethereum, Network, ReceiptResponse, TransactionBuilder};
+use alloy_eips::eip4844::DATA_GAS_PER_BLOB;
+use alloy_primitives::U256;
+use alloy_rpc_types_eth::TransactionRequest;
+use core::fmt;
+
+/// A dimension of transaction fees.
+///
+/// Ethereum charges for execution gas and, for EIP-4844 transactions, blob gas. Other networks may
+/// charge for additional resources, e.g. the L1 data fee on OP-stack chains or L1 pricing on
+/// Arbitrum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FeeDimension {
+    /// Execution gas, priced by `gasPrice` or `maxFeePerGas`.
+    Execution,
+    /// EIP-4844 blob gas, priced by `maxFeePerBlobGas`.
+    Blob,
+    /// The cost of posting the transaction data to L1, as charged by rollups.
+    L1Data,
+    /// A network-specific dimension.
+    Other(&'static str),
+}
+
+impl fmt::Display for FeeDimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Execution => f.write_str("execution"),
+            Self::Blob => f.write_str("blob"),
+            Self::L1Data => f.write_str("l1-data"),
+            Self::Other(name) => f.write_str(name),
+        }
+    }
+}
+
+/// The fee charged for a single [`FeeDimension`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeComponent {
+    /// The fee dimension.
+    pub dimension: FeeDimension,
+    /// The number of units consumed, e.g. gas.
+    pub units: u128,
+    /// The (maximum) price paid per unit, in wei.
+    pub price_per_unit: u128,
+}
+
+impl FeeComponent {
+    /// Creates a new fee component.
+    pub const fn new(dimension: FeeDimension, units: u128, price_per_unit: u128) -> Self {
+        Self { dimension, units, price_per_unit }
+    }
+
+    /// Creates a fee component that is charged as a flat amount, in wei.
+    pub const fn flat(dimension: FeeDimension, cost: u128) -> Self {
+        Self { dimension, units: 1, price_per_unit: cost }
+    }
+
+    /// Returns the cost of this component, in wei.
+    pub fn cost(&self) -> U256 {
+        U256::from(self.units) * U256::from(self.price_per_unit)
+    }
+}
+
+/// A breakdown of the fees of a transaction across all [`FeeDimension`]s.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    components: Vec<FeeComponent>,
+}
+
+impl FeeBreakdown {
+    /// Creates an empty fee breakdown.
+    pub const fn new() -> Self {
+        Self { components: Vec::new() }
+    }
+
+    /// Returns the fees paid by a mined transaction, from its receipt.
+    ///
+    /// The execution component is priced at the effective gas price, and a blob component is added
+    /// for transactions that used blob gas. Network-specific dimensions are not included.
+    pub fn from_receipt<R: ReceiptResponse>(receipt: &R) -> Self {
+        let mut breakdown = Self::new().with_component(FeeComponent::new(
+            FeeDimension::Execution,
+            receipt.gas_used() as u128,
+            receipt.effective_gas_price(),
+        ));
+        if let (Some(units), Some(price)) = (receipt.blob_gas_used(), receipt.blob_gas_price()) {
+            breakdown.push(FeeComponent::new(FeeDimension::Blob, units as u128, price));
+        }
+        breakdown
+    }
+
+    /// Adds a component to the breakdown, replacing any existing component of the same dimension.
+    pub fn push(&mut self, component: FeeComponent) {
+        self.components.retain(|c| c.dimension != component.dimension);
+        self.components.push(component);
+    }
+
+    /// Adds a component to the breakdown, replacing any existing component of the same dimension.
+    pub fn with_component(mut self, component: FeeComponent) -> Self {
+        self.push(component);
+        self
+    }
+
+    /// Returns the component for the given dimension, if any.
+    pub fn get(&self, dimension: FeeDimension) -> Option<&FeeComponent> {
+        self.components.iter().find(|c| c.dimension == dimension)
+    }
+
+    /// Returns all components.
+    pub fn components(&self) -> &[FeeComponent] {
+        &self.components
+    }
+
+    /// Returns the total cost across all dimensions, in wei.
+    pub fn total_cost(&self) -> U256 {
+        self.components.iter().map(FeeComponent::cost).sum()
+    }
+}
+
+/// A network's fee model.
+///
+/// Networks implement this to express all the dimensions a transaction is charged for, so that
+/// fee estimation and transaction building can account for the total cost generically, instead of
+/// assuming Ethereum's execution gas only.
+///
+/// [`fee_breakdown`](Self::fee_breakdown) computes the components that are derivable from the
+/// request alone. Dimensions that depend on chain state, such as an L1 data fee read from an oracle
+/// contract, can be added to the returned [`FeeBreakdown`] by the caller.
+pub trait FeeModel: Network {
+    /// The dimensions this network charges for.
+    const DIMENSIONS: &'static [FeeDimension];
+
+    /// Returns the worst-case fee breakdown for the given request, based on its gas limit and
+    /// (maximum) fee fields. Dimensions whose fields are not set are omitted.
+    fn fee_breakdown(request: &Self::TransactionRequest) -> FeeBreakdown {
+        let mut breakdown = FeeBreakdown::new();
+        if let Some(component) = execution_component::<Self>(request) {
+            breakdown.push(component);
+        }
+        breakdown
+    }
+}
+
+/// Returns the execution component of the given request, using `maxFeePerGas` or `gasPrice` as
+/// the price.
+pub fn execution_component<N: Network>(request: &N::TransactionRequest) -> Option<FeeComponent> {
+    let gas_limit = request.gas_limit()?;
+    let price = request.max_fee_per_gas().or_else(|| request.gas_price())?;
+    Some(FeeComponent::new(FeeDimension::Execution, gas_limit as u128, price))
+}
+
+/// Returns the blob component of an Ethereum transaction request, if it carries blobs.
+fn blob_component(request: &TransactionRequest) -> Option<FeeComponent> {
+    let price = request.max_fee_per_blob_gas?;
+    let blobs = request
+        .blob_versioned_hashes
+        .as_ref()
+        .map(Vec::len)
+        .or_else(|| request.sidecar.as_ref().map(|sidecar| sidecar.blobs.len()))?;
+    Some(FeeComponent::new(FeeDimension::Blob, blobs as u128 * DATA_GAS_PER_BLOB as u128, price))
+}
+
+impl FeeModel for Ethereum {
+    const DIMENSIONS: &'static [FeeDimension] = &[FeeDimension::Execution, FeeDimension::Blob];
+
+    fn fee_breakdown(request: &Self::TransactionRequest) -> FeeBreakdown {
+        let mut breakdown = FeeBreakdown::new();
+        if let Some(component) = execution_component::<Self>(request) {
+            breakdown.push(component);
+        }
+        if let Some(component) = blob_component(request) {
+            breakdown.push(component);
+        }
+        breakdown
+    }
+}
+
+impl FeeModel for AnyNetwork {
+    const DIMENSIONS: &'static [FeeDimension] = &[FeeDimension::Execution, FeeDimension::Blob];
+
+    fn fee_breakdown(request: &Self::TransactionRequest) -> FeeBreakdown {
+        let mut breakdown = FeeBreakdown::new();
+        if let Some(component) = execution_component::<Self>(request) {
+            breakdown.push(component);
+        }
+        if let Some(component) = blob_component(request) {
+            breakdown.push(component);
+        }
+        breakdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::ReceiptEnvelope;
+    use alloy_primitives::{Address, B256};
+    use alloy_rpc_types_eth::TransactionReceipt;
+
+    #[test]
+    fn ethereum_breakdown() {
+        let request = TransactionRequest::default().gas_limit(21_000).max_fee_per_gas(10);
+        let breakdown = Ethereum::fee_breakdown(&request);
+        assert_eq!(breakdown.components().len(), 1);
+        assert_eq!(breakdown.total_cost(), U256::from(210_000));
+
+        let request = TransactionRequest {
+            max_fee_per_blob_gas: Some(2),
+            blob_versioned_hashes: Some(vec![B256::ZERO; 2]),
+            ..request
+        };
+        let breakdown = Ethereum::fee_breakdown(&request);
+        let blob = breakdown.get(FeeDimension::Blob).unwrap();
+        assert_eq!(blob.units, 2 * DATA_GAS_PER_BLOB as u128);
+        assert_eq!(breakdown.total_cost(), U256::from(210_000 + 4 * DATA_GAS_PER_BLOB));
+    }
+
+    #[test]
+    fn extra_dimensions() {
+        let request =
+            TransactionRequest { gas: Some(100), gas_price: Some(1), ..Default::default() };
+        let breakdown = Ethereum::fee_breakdown(&request)
+            .with_component(FeeComponent::flat(FeeDimension::L1Data, 1_000));
+        assert_eq!(breakdown.total_cost(), U256::from(1_100));
+        assert!(Ethereum::fee_breakdown(&TransactionRequest::default()).components().is_empty());
+    }
+
+    #[test]
+    fn receipt_breakdown() {
+        let receipt = TransactionReceipt {
+            inner: ReceiptEnvelope::Eip4844(Default::default()),
+            transaction_hash: B256::ZERO,
+            transaction_index: None,
+            block_hash: None,
+            block_number: None,
+            gas_used: 21_000,
+            effective_gas_price: 12,
+            blob_gas_used: Some(131_072),
+            blob_gas_price: Some(2),
+            from: Address::ZERO,
+            to: None,
+            contract_address: None,
+        };
+        let breakdown = FeeBreakdown::from_receipt(&receipt);
+        assert_eq!(breakdown.get(FeeDimension::Execution).unwrap().cost(), U256::from(252_000));
+        assert_eq!(breakdown.get(FeeDimension::Blob).unwrap().cost(), U256::from(262_144));
+        assert_eq!(breakdown.total_cost(), U256::from(252_000 + 262_144));
+
+        let receipt = TransactionReceipt { blob_gas_used: None, blob_gas_price: None, ..receipt };
+        let breakdown = FeeBreakdown::from_receipt(&receipt);
+        assert_eq!(breakdown.components().len(), 1);
+        assert_eq!(breakdown.total_cost(), U256::from(252_000));
+    }
+}
@@ -21,6 +21,9 @@ pub use transaction::{
 mod ethereum;
 pub use ethereum::{Ethereum, EthereumWallet};
 
+mod fees;
+pub use fees::{execution_component, FeeBreakdown, FeeComponent, FeeDimension, FeeModel};
+
 mod any;
 pub use any::{
     AnyHeader, AnyNetwork, AnyReceiptEnvelope, AnyRpcBlock, AnyRpcHeader, AnyRpcTransaction,
@@ -36,8 +39,6 @@ pub use alloy_network_primitives::{
 /// Captures type info for network-specific RPC requests/responses.
 ///
 /// Networks are only containers for types, so it is recommended to use ZSTs for their definition.
-// todo: block responses are ethereum only, so we need to include this in here too, or make `Block`
-// generic over tx/header type
 pub trait Network: Debug + Clone + Copy + Sized + Send + Sync + 'static {
     // -- Consensus types --
 
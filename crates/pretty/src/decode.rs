@@ -0,0 +1,239 @@
+use alloy_dyn_abi::{DynSolValue, EventExt, FunctionExt, JsonAbiExt};
+use alloy_json_abi::{Event, Function, JsonAbi, Param};
+use alloy_primitives::{hex, map::AddressHashMap, Address, LogData, Selector};
+
+/// A registry of the ABIs used to decode calldata, return data and logs.
+///
+/// The ABI registered for the called contract is tried first, followed by the global ABIs, e.g.
+/// token standards, in registration order.
+#[derive(Clone, Debug, Default)]
+pub struct Abis {
+    /// ABIs of specific contracts.
+    by_address: AddressHashMap<JsonAbi>,
+    /// ABIs tried for every contract.
+    global: Vec<JsonAbi>,
+}
+
+/// A decoded function call or event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Decoded {
+    /// The name of the function or event.
+    pub(crate) name: String,
+    /// The names and formatted values of the parameters.
+    pub(crate) params: Vec<(String, String)>,
+}
+
+impl Decoded {
+    fn new(name: &str, params: &[Param], values: &[DynSolValue]) -> Self {
+        let params = params
+            .iter()
+            .zip(values)
+            .enumerate()
+            .map(|(i, (param, value))| (param_name(i, &param.name), fmt_value(value)))
+            .collect();
+        Self { name: name.to_string(), params }
+    }
+
+    /// Formats the call on a single line, e.g. `transfer(0x.., 1)`.
+    pub(crate) fn inline(&self) -> String {
+        let values = self.params.iter().map(|(_, value)| value.as_str()).collect::<Vec<_>>();
+        format!("{}({})", self.name, values.join(", "))
+    }
+
+    /// Formats the call with a parameter per line.
+    pub(crate) fn multiline(&self) -> String {
+        let mut out = self.name.clone();
+        for (name, value) in &self.params {
+            out.push_str(&format!("\n  {name}: {value}"));
+        }
+        out
+    }
+}
+
+impl Abis {
+    /// Registers the ABI of the contract at `address`, replacing any previous one.
+    pub fn insert(&mut self, address: Address, abi: JsonAbi) {
+        self.by_address.insert(address, abi);
+    }
+
+    /// Registers an ABI used to decode calls to and logs of any contract.
+    pub fn push_global(&mut self, abi: JsonAbi) {
+        self.global.push(abi);
+    }
+
+    /// Decodes the calldata of a call to `to` with the first registered function that matches
+    /// its selector and arguments.
+    pub fn decode_call(
+        &self,
+        to: Option<Address>,
+        input: &[u8],
+    ) -> Option<(&Function, Vec<DynSolValue>)> {
+        let selector = Selector::try_from(input.get(..4)?).ok()?;
+        self.candidates(to)
+            .flat_map(JsonAbi::functions)
+            .filter(|function| function.selector() == selector)
+            .find_map(|function| {
+                Some((function, function.abi_decode_input(&input[4..], true).ok()?))
+            })
+    }
+
+    fn candidates(&self, address: Option<Address>) -> impl Iterator<Item = &JsonAbi> {
+        address.and_then(|address| self.by_address.get(&address)).into_iter().chain(&self.global)
+    }
+
+    fn function(&self, address: Option<Address>, selector: Selector) -> Option<&Function> {
+        self.candidates(address)
+            .flat_map(JsonAbi::functions)
+            .find(|function| function.selector() == selector)
+    }
+
+    fn event(&self, address: Address, log: &LogData) -> Option<&Event> {
+        let topic0 = *log.topics().first()?;
+        self.candidates(Some(address))
+            .flat_map(JsonAbi::events)
+            .find(|event| !event.anonymous && event.selector() == topic0)
+    }
+
+    /// Decodes the calldata of a call to `to`.
+    pub(crate) fn decode_input(&self, to: Option<Address>, input: &[u8]) -> Option<Decoded> {
+        let (function, values) = self.decode_call(to, input)?;
+        Some(Decoded::new(&function.name, &function.inputs, &values))
+    }
+
+    /// Decodes the return data of a call to `to` with the given calldata.
+    pub(crate) fn decode_output(
+        &self,
+        to: Option<Address>,
+        input: &[u8],
+        output: &[u8],
+    ) -> Option<Decoded> {
+        let selector = Selector::try_from(input.get(..4)?).ok()?;
+        let function = self.function(to, selector)?;
+        let values = function.abi_decode_output(output, true).ok()?;
+        Some(Decoded::new("", &function.outputs, &values))
+    }
+
+    /// Decodes a log emitted by `address`.
+    pub(crate) fn decode_log(&self, address: Address, log: &LogData) -> Option<Decoded> {
+        let event = self.event(address, log)?;
+        let decoded = event.decode_log(log, true).ok()?;
+        let (mut indexed, mut body) = (decoded.indexed.iter(), decoded.body.iter());
+        let values = event
+            .inputs
+            .iter()
+            .map(|input| if input.indexed { indexed.next() } else { body.next() })
+            .collect::<Option<Vec<_>>>()?;
+        let params = event
+            .inputs
+            .iter()
+            .zip(values)
+            .enumerate()
+            .map(|(i, (input, value))| (param_name(i, &input.name), fmt_value(value)))
+            .collect();
+        Some(Decoded { name: event.name.clone(), params })
+    }
+}
+
+/// Returns the name of a parameter, or its position if unnamed.
+fn param_name(index: usize, name: &str) -> String {
+    if name.is_empty() {
+        index.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Formats a decoded ABI value.
+pub(crate) fn fmt_value(value: &DynSolValue) -> String {
+    if let Some(address) = value.as_address() {
+        return address.to_checksum(None);
+    }
+    if let Some(value) = value.as_bool() {
+        return value.to_string();
+    }
+    if let Some((value, _)) = value.as_int() {
+        return value.to_string();
+    }
+    if let Some((value, _)) = value.as_uint() {
+        return value.to_string();
+    }
+    if let Some((word, size)) = value.as_fixed_bytes() {
+        return hex::encode_prefixed(&word[..size.min(word.len())]);
+    }
+    if let Some(bytes) = value.as_bytes() {
+        return hex::encode_prefixed(bytes);
+    }
+    if let Some(value) = value.as_str() {
+        return format!("{value:?}");
+    }
+    if let Some(values) = value.as_tuple() {
+        return format!("({})", fmt_values(values));
+    }
+    if let Some(values) = value.as_array().or_else(|| value.as_fixed_array()) {
+        return format!("[{}]", fmt_values(values));
+    }
+    format!("{value:?}")
+}
+
+fn fmt_values(values: &[DynSolValue]) -> String {
+    values.iter().map(fmt_value).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, U256};
+
+    const ERC20: &str = include_str!("../test_data/erc20.json");
+
+    fn abis() -> Abis {
+        let mut abis = Abis::default();
+        abis.push_global(serde_json::from_str(ERC20).unwrap());
+        abis
+    }
+
+    #[test]
+    fn decodes_calls() {
+        let abis = abis();
+        let to = address!("00000000000000000000000000000000000000aa");
+        let function = abis.global[0].function("transfer").unwrap()[0].clone();
+        let input = function
+            .abi_encode_input(&[DynSolValue::Address(to), DynSolValue::Uint(U256::from(5), 256)])
+            .unwrap();
+
+        let (function, args) = abis.decode_call(None, &input).unwrap();
+        assert_eq!(function.name, "transfer");
+        assert_eq!(args, [DynSolValue::Address(to), DynSolValue::Uint(U256::from(5), 256)]);
+
+        let decoded = abis.decode_input(None, &input).unwrap();
+        assert_eq!(decoded.inline(), format!("transfer({}, 5)", to.to_checksum(None)));
+        assert_eq!(
+            decoded.multiline(),
+            format!("transfer\n  to: {}\n  amount: 5", to.to_checksum(None))
+        );
+
+        let output = DynSolValue::Tuple(vec![DynSolValue::Bool(true)]).abi_encode_params();
+        assert_eq!(abis.decode_output(None, &input, &output).unwrap().inline(), "(true)");
+
+        assert!(abis.decode_input(None, &[0xde, 0xad, 0xbe, 0xef]).is_none());
+        assert!(abis.decode_input(None, &[0xde]).is_none());
+    }
+
+    #[test]
+    fn decodes_logs() {
+        let abis = abis();
+        let from = address!("00000000000000000000000000000000000000aa");
+        let to = address!("00000000000000000000000000000000000000bb");
+        let event = &abis.global[0].events["Transfer"][0];
+        let log = LogData::new_unchecked(
+            vec![event.selector(), from.into_word(), to.into_word()],
+            U256::from(7).to_be_bytes_vec().into(),
+        );
+
+        let decoded = abis.decode_log(Address::ZERO, &log).unwrap();
+        assert_eq!(
+            decoded.inline(),
+            format!("Transfer({}, {}, 7)", from.to_checksum(None), to.to_checksum(None))
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+/// Computes the structural difference between two values.
+///
+/// The values are compared through their serialized JSON representation, so the paths of the
+/// changes use the serialized field names. Values that fail to serialize are compared as `null`.
+pub fn diff<T: Serialize + ?Sized>(old: &T, new: &T) -> Diff {
+    let old = serde_json::to_value(old).unwrap_or_default();
+    let new = serde_json::to_value(new).unwrap_or_default();
+    let mut changes = Vec::new();
+    diff_values(String::new(), &old, &new, &mut changes);
+    Diff { changes }
+}
+
+fn diff_values(path: String, old: &Value, new: &Value, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let path = join(&path, key);
+                match new.get(key) {
+                    Some(new_value) => diff_values(path, old_value, new_value, changes),
+                    None => changes.push(Change::Removed { path, old: old_value.clone() }),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    changes.push(Change::Added { path: join(&path, key), new: new_value.clone() });
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for (i, (old, new)) in old.iter().zip(new).enumerate() {
+                diff_values(format!("{path}[{i}]"), old, new, changes);
+            }
+            for (i, old) in old.iter().enumerate().skip(new.len()) {
+                changes.push(Change::Removed { path: format!("{path}[{i}]"), old: old.clone() });
+            }
+            for (i, new) in new.iter().enumerate().skip(old.len()) {
+                changes.push(Change::Added { path: format!("{path}[{i}]"), new: new.clone() });
+            }
+        }
+        (old, new) if old != new => {
+            changes.push(Change::Modified { path, old: old.clone(), new: new.clone() })
+        }
+        _ => {}
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// The structural difference between two values, see [`diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diff {
+    changes: Vec<Change>,
+}
+
+impl Diff {
+    /// Returns the changes.
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Returns the number of changes.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the values are equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single change of a [`Diff`].
+///
+/// Paths are dot-separated object keys, with array indices in brackets, e.g.
+/// `transactions[0].hash`. The path of a change of the values themselves is empty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// A field or array element that is only present in the new value.
+    Added {
+        /// The path of the field.
+        path: String,
+        /// The new value.
+        new: Value,
+    },
+    /// A field or array element that is only present in the old value.
+    Removed {
+        /// The path of the field.
+        path: String,
+        /// The old value.
+        old: Value,
+    },
+    /// A field or array element that differs between the values.
+    Modified {
+        /// The path of the field.
+        path: String,
+        /// The old value.
+        old: Value,
+        /// The new value.
+        new: Value,
+    },
+}
+
+impl Change {
+    /// Returns the path of the changed field.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Modified { path, .. } => {
+                path
+            }
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { path, new } => write!(f, "+ {path}: {new}"),
+            Self::Removed { path, old } => write!(f, "- {path}: {old}"),
+            Self::Modified { path, old, new } => write!(f, "~ {path}: {old} -> {new}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn diffs_nested_values() {
+        let old = json!({ "a": 1, "b": { "c": [1, 2, 3] }, "d": "x" });
+        let new = json!({ "a": 1, "b": { "c": [1, 4] }, "e": null });
+
+        let diff = diff(&old, &new);
+        assert_eq!(
+            diff.changes(),
+            [
+                Change::Modified { path: "b.c[1]".into(), old: json!(2), new: json!(4) },
+                Change::Removed { path: "b.c[2]".into(), old: json!(3) },
+                Change::Removed { path: "d".into(), old: json!("x") },
+                Change::Added { path: "e".into(), new: json!(null) },
+            ]
+        );
+        assert_eq!(diff.to_string(), "~ b.c[1]: 2 -> 4\n- b.c[2]: 3\n- d: \"x\"\n+ e: null\n");
+    }
+
+    #[test]
+    fn equal_values() {
+        assert!(diff(&json!({ "a": [1] }), &json!({ "a": [1] })).is_empty());
+
+        let diff = diff(&1, &2);
+        assert_eq!(diff.changes()[0].path(), "");
+    }
+}
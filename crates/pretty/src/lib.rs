@@ -0,0 +1,21 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/alloy.jpg",
+    html_favicon_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/favicon.ico"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod decode;
+pub use decode::Abis;
+
+mod diff;
+pub use diff::{diff, Change, Diff};
+
+mod printer;
+pub use printer::Printer;
+
+mod style;
+pub use style::ColorChoice;
+
+mod table;
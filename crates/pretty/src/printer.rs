@@ -0,0 +1,376 @@
+use crate::{
+    decode::Abis,
+    diff::{Change, Diff},
+    style::{ColorChoice, Style},
+    table::Table,
+};
+use alloy_consensus::{BlockHeader, Transaction, TxReceipt};
+use alloy_json_abi::JsonAbi;
+use alloy_network_primitives::{BlockResponse, HeaderResponse, TransactionResponse};
+use alloy_primitives::{
+    hex,
+    utils::{format_ether, format_units},
+    Address, LogData, U256,
+};
+use alloy_rpc_types_eth::{Log, TransactionReceipt};
+use alloy_rpc_types_trace::geth::{CallFrame, CallLogFrame};
+use std::fmt::Write;
+
+/// Renders RPC types in a human-friendly format.
+///
+/// Blocks, transactions and receipts are rendered as tables of their fields, and call traces as
+/// trees. Calldata, return data and logs are decoded with the ABIs registered with
+/// [`with_abi`](Self::with_abi) and [`with_global_abi`](Self::with_global_abi), and shown as raw
+/// hex otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_pretty::{ColorChoice, Printer};
+/// use alloy_rpc_types_trace::geth::CallFrame;
+///
+/// let printer = Printer::new().with_color(ColorChoice::Never);
+/// let frame = CallFrame { typ: "CALL".into(), ..Default::default() };
+/// println!("{}", printer.call_trace(&frame));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Printer {
+    color: ColorChoice,
+    abis: Abis,
+}
+
+impl Printer {
+    /// Creates a new printer without any ABIs, coloring the output if stdout is a terminal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to color the output.
+    pub const fn with_color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Registers the ABI of the contract at `address`.
+    pub fn with_abi(mut self, address: Address, abi: JsonAbi) -> Self {
+        self.abis.insert(address, abi);
+        self
+    }
+
+    /// Registers an ABI that is tried for every contract, e.g. the ERC-20 ABI.
+    ///
+    /// ABIs registered for a specific address take precedence.
+    pub fn with_global_abi(mut self, abi: JsonAbi) -> Self {
+        self.abis.push_global(abi);
+        self
+    }
+
+    /// Renders a block.
+    pub fn block<B>(&self, block: &B) -> String
+    where
+        B: BlockResponse,
+        B::Header: HeaderResponse,
+    {
+        let header = block.header();
+        let mut table = Table::default();
+        table.row("hash", header.hash());
+        table.row("parentHash", header.parent_hash());
+        table.row("number", header.number());
+        table.row("timestamp", header.timestamp());
+        table.row("miner", header.beneficiary());
+        table.row("gasUsed", gas_used(header.gas_used(), header.gas_limit()));
+        table.row("gasLimit", header.gas_limit());
+        table.opt_row("baseFeePerGas", header.base_fee_per_gas().map(|fee| gwei(fee.into())));
+        table.opt_row("blobGasUsed", header.blob_gas_used());
+        table.opt_row("excessBlobGas", header.excess_blob_gas());
+        table.row("stateRoot", header.state_root());
+        table.row("transactionsRoot", header.transactions_root());
+        table.row("receiptsRoot", header.receipts_root());
+        table.opt_row("withdrawalsRoot", header.withdrawals_root());
+        table.opt_row("parentBeaconBlockRoot", header.parent_beacon_block_root());
+        table.row("extraData", header.extra_data());
+
+        let hashes = block.transactions().hashes().map(|hash| hash.to_string()).collect::<Vec<_>>();
+        table.row("transactions", if hashes.is_empty() { "[]".into() } else { hashes.join("\n") });
+        table.render(self.color.should_color())
+    }
+
+    /// Renders a transaction, decoding its calldata if the ABI of the recipient is known.
+    pub fn transaction<T: TransactionResponse>(&self, tx: &T) -> String {
+        let mut table = Table::default();
+        table.row("hash", tx.tx_hash());
+        table.row("type", tx.ty());
+        table.row("from", tx.from());
+        table.row("to", tx.to().map_or_else(|| "contract creation".into(), |to| to.to_string()));
+        table.row("value", ether(tx.value()));
+        table.row("nonce", tx.nonce());
+        table.row("gasLimit", tx.gas_limit());
+        match Transaction::gas_price(tx) {
+            Some(gas_price) => table.row("gasPrice", gwei(gas_price)),
+            None => {
+                table.row("maxFeePerGas", gwei(Transaction::max_fee_per_gas(tx)));
+                table.opt_row("maxPriorityFeePerGas", tx.max_priority_fee_per_gas().map(gwei));
+            }
+        }
+        table.opt_row("maxFeePerBlobGas", tx.max_fee_per_blob_gas().map(gwei));
+        table.opt_row("chainId", tx.chain_id());
+        table.opt_row("blockNumber", TransactionResponse::block_number(tx));
+        table.opt_row("blockHash", TransactionResponse::block_hash(tx));
+        table.opt_row("transactionIndex", TransactionResponse::transaction_index(tx));
+        table.row("input", tx.input());
+        table.opt_row(
+            "decodedInput",
+            self.abis.decode_input(tx.to(), tx.input()).map(|d| d.multiline()),
+        );
+        table.render(self.color.should_color())
+    }
+
+    /// Renders a receipt, decoding its logs if the ABIs of the emitters are known.
+    pub fn receipt<T: TxReceipt<Log = Log>>(&self, receipt: &TransactionReceipt<T>) -> String {
+        let color = self.color.should_color();
+        let status = if receipt.inner.status() {
+            Style::Green.paint(color, "success")
+        } else {
+            Style::Red.paint(color, "failed")
+        };
+
+        let mut table = Table::default();
+        table.row("transactionHash", receipt.transaction_hash);
+        table.row("status", status);
+        table.opt_row("blockNumber", receipt.block_number);
+        table.opt_row("blockHash", receipt.block_hash);
+        table.opt_row("transactionIndex", receipt.transaction_index);
+        table.row("from", receipt.from);
+        table.opt_row("to", receipt.to);
+        table.opt_row("contractAddress", receipt.contract_address);
+        table.row("gasUsed", receipt.gas_used);
+        table.row("cumulativeGasUsed", receipt.inner.cumulative_gas_used());
+        table.row("effectiveGasPrice", gwei(receipt.effective_gas_price));
+        table.opt_row("blobGasUsed", receipt.blob_gas_used);
+        table.opt_row("blobGasPrice", receipt.blob_gas_price.map(gwei));
+
+        let logs = receipt.inner.logs();
+        let logs = logs
+            .iter()
+            .enumerate()
+            .map(|(i, log)| {
+                let index = log.log_index.unwrap_or(i as u64);
+                format!("[{index}] {} {}", log.address(), self.log(log.address(), log.data()))
+            })
+            .collect::<Vec<_>>();
+        table.row("logs", if logs.is_empty() { "[]".into() } else { logs.join("\n") });
+        table.render(color)
+    }
+
+    /// Renders a call trace of the `callTracer` as a tree, decoding calls, return data and logs if
+    /// the ABIs of the involved contracts are known.
+    pub fn call_trace(&self, frame: &CallFrame) -> String {
+        let color = self.color.should_color();
+        let mut out = self.frame_header(frame, color);
+        out.push('\n');
+        self.write_frame_children(&mut out, frame, "", color);
+        out
+    }
+
+    /// Renders a [`Diff`], coloring additions, removals and modifications.
+    pub fn diff(&self, diff: &Diff) -> String {
+        let color = self.color.should_color();
+        let mut out = String::new();
+        for change in diff.changes() {
+            let style = match change {
+                Change::Added { .. } => Style::Green,
+                Change::Removed { .. } => Style::Red,
+                Change::Modified { .. } => Style::Yellow,
+            };
+            let _ = writeln!(out, "{}", style.paint(color, change));
+        }
+        out
+    }
+
+    fn frame_header(&self, frame: &CallFrame, color: bool) -> String {
+        let mut out =
+            format!("{} {}", Style::Dim.paint(color, format!("[{}]", frame.gas_used)), frame.typ);
+        if let Some(to) = frame.to {
+            let _ = write!(out, " {to}");
+        }
+        if !frame.typ.starts_with("CREATE") {
+            match self.abis.decode_input(frame.to, &frame.input) {
+                Some(decoded) => {
+                    let _ = write!(out, "::{}", decoded.inline());
+                }
+                None if !frame.input.is_empty() => {
+                    let _ = write!(out, "::{}", frame.input);
+                }
+                None => {}
+            }
+        }
+        if let Some(value) = frame.value.filter(|value| !value.is_zero()) {
+            let _ = write!(out, " {{value: {} ETH}}", ether(value));
+        }
+        out
+    }
+
+    fn write_frame_children(&self, out: &mut String, frame: &CallFrame, indent: &str, color: bool) {
+        // logs are placed before the subcall at their position, or after all subcalls
+        let mut children = Vec::with_capacity(frame.calls.len() + frame.logs.len() + 1);
+        let mut logs = frame.logs.iter().peekable();
+        for (i, call) in frame.calls.iter().enumerate() {
+            while let Some(log) = logs.next_if(|log| log.position.is_some_and(|p| p <= i as u64)) {
+                children.push(Child::Line(self.log_frame(frame, log, color)));
+            }
+            children.push(Child::Call(call));
+        }
+        children.extend(logs.map(|log| Child::Line(self.log_frame(frame, log, color))));
+        children.push(Child::Line(self.frame_result(frame, color)));
+
+        let last = children.len() - 1;
+        for (i, child) in children.into_iter().enumerate() {
+            let (connector, next_indent) =
+                if i == last { ("└─ ", "   ") } else { ("├─ ", "│  ") };
+            match child {
+                Child::Call(call) => {
+                    let _ = writeln!(out, "{indent}{connector}{}", self.frame_header(call, color));
+                    self.write_frame_children(out, call, &format!("{indent}{next_indent}"), color);
+                }
+                Child::Line(line) => {
+                    let _ = writeln!(out, "{indent}{connector}{line}");
+                }
+            }
+        }
+    }
+
+    fn log_frame(&self, frame: &CallFrame, log: &CallLogFrame, color: bool) -> String {
+        let data = LogData::new_unchecked(
+            log.topics.clone().unwrap_or_default(),
+            log.data.clone().unwrap_or_default(),
+        );
+        let address = log.address.or(frame.to).unwrap_or_default();
+        format!("{} {}", Style::Cyan.paint(color, "emit"), self.log(address, &data))
+    }
+
+    fn frame_result(&self, frame: &CallFrame, color: bool) -> String {
+        if let Some(error) = &frame.error {
+            let reason = frame.revert_reason.as_deref().map(|reason| format!(": {reason}"));
+            return Style::Red.paint(color, format!("← [{error}]{}", reason.unwrap_or_default()));
+        }
+        let output = frame.output.as_ref().map_or(&[][..], |output| &output[..]);
+        if frame.typ.starts_with("CREATE") {
+            return format!("← {} bytes of code", output.len());
+        }
+        match self.abis.decode_output(frame.to, &frame.input, output) {
+            Some(decoded) => format!("← {}", decoded.inline()),
+            None => format!("← {}", hex::encode_prefixed(output)),
+        }
+    }
+
+    fn log(&self, address: Address, data: &LogData) -> String {
+        match self.abis.decode_log(address, data) {
+            Some(decoded) => decoded.inline(),
+            None => {
+                let topics =
+                    data.topics().iter().map(|topic| topic.to_string()).collect::<Vec<_>>();
+                format!("topics: [{}], data: {}", topics.join(", "), data.data)
+            }
+        }
+    }
+}
+
+/// A child line of a call trace frame.
+enum Child<'a> {
+    Call(&'a CallFrame),
+    Line(String),
+}
+
+fn gas_used(gas_used: u64, gas_limit: u64) -> String {
+    if gas_limit == 0 {
+        return gas_used.to_string();
+    }
+    format!("{gas_used} ({:.2}%)", gas_used as f64 * 100.0 / gas_limit as f64)
+}
+
+fn ether(value: U256) -> String {
+    trim_decimals(format_ether(value))
+}
+
+fn gwei(value: u128) -> String {
+    let value = format_units(U256::from(value), "gwei").unwrap_or_default();
+    format!("{} gwei", trim_decimals(value))
+}
+
+/// Strips trailing zeros of the fractional part.
+fn trim_decimals(value: String) -> String {
+    if !value.contains('.') {
+        return value;
+    }
+    value.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+    use alloy_primitives::{address, Bytes};
+
+    const ERC20: &str = include_str!("../test_data/erc20.json");
+
+    #[test]
+    fn formats_units() {
+        assert_eq!(ether(U256::from(1_500_000_000_000_000_000u128)), "1.5");
+        assert_eq!(ether(U256::ZERO), "0");
+        assert_eq!(gwei(30_000_000_000), "30 gwei");
+        assert_eq!(gwei(1_234_567_890), "1.23456789 gwei");
+        assert_eq!(gas_used(15_000_000, 30_000_000), "15000000 (50.00%)");
+    }
+
+    #[test]
+    fn renders_call_trace() {
+        let abi: JsonAbi = serde_json::from_str(ERC20).unwrap();
+        let token = address!("00000000000000000000000000000000000000aa");
+        let to = address!("00000000000000000000000000000000000000bb");
+        let input = abi.function("transfer").unwrap()[0]
+            .abi_encode_input(&[DynSolValue::Address(to), DynSolValue::Uint(U256::from(7), 256)])
+            .unwrap();
+        let event = &abi.events["Transfer"][0];
+
+        let frame = CallFrame {
+            typ: "CALL".into(),
+            to: Some(token),
+            gas_used: U256::from(100),
+            input: input.into(),
+            output: Some(DynSolValue::Bool(true).abi_encode().into()),
+            calls: vec![CallFrame {
+                typ: "STATICCALL".into(),
+                to: Some(to),
+                gas_used: U256::from(10),
+                input: Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]),
+                error: Some("execution reverted".into()),
+                ..Default::default()
+            }],
+            logs: vec![CallLogFrame {
+                address: Some(token),
+                topics: Some(vec![event.selector(), Address::ZERO.into_word(), to.into_word()]),
+                data: Some(U256::from(7).to_be_bytes_vec().into()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let printer = Printer::new().with_color(ColorChoice::Never).with_abi(token, abi);
+        let expected = format!(
+            "[100] CALL {token}::transfer({to}, 7)\n\
+             ├─ [10] STATICCALL {to}::0xdeadbeef\n\
+             │  └─ ← [execution reverted]\n\
+             ├─ emit Transfer({}, {to}, 7)\n\
+             └─ ← (true)\n",
+            Address::ZERO
+        );
+        assert_eq!(printer.call_trace(&frame), expected);
+    }
+
+    #[test]
+    fn renders_diff() {
+        let printer = Printer::new().with_color(ColorChoice::Always);
+        let diff = crate::diff(&serde_json::json!({ "a": 1 }), &serde_json::json!({ "a": 2 }));
+        assert_eq!(printer.diff(&diff), "\x1b[33m~ a: 1 -> 2\x1b[0m\n");
+    }
+}
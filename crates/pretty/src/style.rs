@@ -0,0 +1,55 @@
+use std::{fmt, io::IsTerminal};
+
+/// Whether to color the output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorChoice {
+    /// Color the output if stdout is a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    /// Always color the output.
+    Always,
+    /// Never color the output.
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns `true` if the output should be colored.
+    pub fn should_color(self) -> bool {
+        match self {
+            Self::Auto => std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+/// The ANSI styles used in the output.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Style {
+    Red,
+    Green,
+    Yellow,
+    Cyan,
+    Dim,
+}
+
+impl Style {
+    const fn code(self) -> &'static str {
+        match self {
+            Self::Red => "31",
+            Self::Green => "32",
+            Self::Yellow => "33",
+            Self::Cyan => "36",
+            Self::Dim => "2",
+        }
+    }
+
+    /// Returns the styled text, or the plain text if `enabled` is `false`.
+    pub(crate) fn paint(self, enabled: bool, text: impl fmt::Display) -> String {
+        if enabled {
+            format!("\x1b[{}m{text}\x1b[0m", self.code())
+        } else {
+            text.to_string()
+        }
+    }
+}
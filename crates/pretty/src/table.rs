@@ -0,0 +1,63 @@
+use crate::style::Style;
+use std::fmt::{self, Write};
+
+/// A two column table of field names and values, rendered with aligned values.
+///
+/// Values spanning multiple lines are indented to the value column.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Table {
+    rows: Vec<(&'static str, String)>,
+}
+
+impl Table {
+    /// Adds a row.
+    pub(crate) fn row(&mut self, key: &'static str, value: impl fmt::Display) {
+        self.rows.push((key, value.to_string()));
+    }
+
+    /// Adds a row if the value is present.
+    pub(crate) fn opt_row(&mut self, key: &'static str, value: Option<impl fmt::Display>) {
+        if let Some(value) = value {
+            self.row(key, value);
+        }
+    }
+
+    /// Renders the table, coloring the field names if `color` is `true`.
+    pub(crate) fn render(&self, color: bool) -> String {
+        let width = self.rows.iter().map(|(key, _)| key.len()).max().unwrap_or_default() + 2;
+        let mut out = String::new();
+        for (key, value) in &self.rows {
+            let padding = " ".repeat(width - key.len());
+            let _ = write!(out, "{}{padding}", Style::Cyan.paint(color, key));
+            for (i, line) in value.lines().enumerate() {
+                if i > 0 {
+                    out.push_str(&" ".repeat(width));
+                }
+                out.push_str(line);
+                out.push('\n');
+            }
+            if value.is_empty() {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligns_values() {
+        let mut table = Table::default();
+        table.row("hash", "0x01");
+        table.opt_row("number", Some(1));
+        table.opt_row("skipped", None::<u64>);
+        table.row("input", "transfer(address,uint256)\n  to: 0x02");
+        assert_eq!(
+            table.render(false),
+            "hash    0x01\nnumber  1\ninput   transfer(address,uint256)\n          to: 0x02\n"
+        );
+    }
+}
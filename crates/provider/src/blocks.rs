@@ -7,6 +7,8 @@ use futures::{Stream, StreamExt};
 use lru::LruCache;
 use std::{marker::PhantomData, num::NonZeroUsize};
 
+#[cfg(feature = "pubsub")]
+use alloy_rpc_types_eth::pubsub::SubscriptionParams;
 #[cfg(feature = "pubsub")]
 use futures::{future::Either, FutureExt};
 
@@ -79,7 +81,7 @@ impl<N: Network> NewBlocks<N> {
             error!("pubsub_frontend returned None after being Some");
             return None;
         };
-        let id = match client.request("eth_subscribe", ("newHeads",)).await {
+        let id = match client.request("eth_subscribe", SubscriptionParams::NewHeads).await {
             Ok(id) => id,
             Err(err) => {
                 error!(%err, "failed to subscribe to newHeads");
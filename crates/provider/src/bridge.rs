@@ -0,0 +1,326 @@
+//! Correlating bridge transactions across chains.
+//!
+//! Most bridges emit an event with a message id when a message is sent on the source chain, and
+//! another event with the same id when it is relayed on the destination chain. A
+//! [`BridgeCorrelator`] matches these events across a source and a destination provider, producing
+//! a [`BridgeLink`] for each message sent by a source transaction.
+
+use crate::Provider;
+use alloy_network::{Ethereum, Network};
+use alloy_network_primitives::ReceiptResponse;
+use alloy_primitives::{map::HashMap, Address, Log, TxHash, B256};
+use alloy_rpc_types_eth::Filter;
+use alloy_transport::TransportResult;
+use std::marker::PhantomData;
+
+/// The location of the message id in a bridge event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MessageIdLocation {
+    /// The topic at the given index, e.g. `1` for the first indexed parameter.
+    Topic(usize),
+    /// The 32-byte word at the given index of the event data, e.g. `0` for the first
+    /// non-indexed parameter.
+    Data(usize),
+}
+
+/// An event emitted by a bridge contract, identifying a message by its id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BridgeEvent {
+    /// The event signature, i.e. the first topic of the event.
+    pub signature: B256,
+    /// The address of the emitting contract, or `None` to match events from any contract.
+    pub address: Option<Address>,
+    /// The location of the message id in the event.
+    pub message_id: MessageIdLocation,
+}
+
+impl BridgeEvent {
+    /// Creates a new bridge event with the given signature and message id location, emitted by
+    /// any contract.
+    pub const fn new(signature: B256, message_id: MessageIdLocation) -> Self {
+        Self { signature, address: None, message_id }
+    }
+
+    /// Only matches events emitted by the contract at the given address.
+    pub const fn with_address(mut self, address: Address) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Returns the message id of the log, or `None` if the log is not this event.
+    pub fn message_id(&self, log: &Log) -> Option<B256> {
+        if self.address.is_some_and(|address| address != log.address)
+            || log.topics().first() != Some(&self.signature)
+        {
+            return None;
+        }
+        match self.message_id {
+            MessageIdLocation::Topic(index) => log.topics().get(index).copied(),
+            MessageIdLocation::Data(index) => {
+                let start = index.checked_mul(32)?;
+                log.data.data.get(start..start.checked_add(32)?).map(B256::from_slice)
+            }
+        }
+    }
+
+    /// Returns a filter for this event, narrowed to the given message ids if they are indexed.
+    fn filter(&self, message_ids: &[B256]) -> Filter {
+        let mut filter = Filter::new().event_signature(self.signature);
+        if let Some(address) = self.address {
+            filter = filter.address(address);
+        }
+        if let MessageIdLocation::Topic(index @ 1..=3) = self.message_id {
+            filter.topics[index] = message_ids.to_vec().into();
+        }
+        filter
+    }
+}
+
+/// A transaction that emitted a bridge event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BridgeTransaction {
+    /// The hash of the transaction.
+    pub transaction_hash: TxHash,
+    /// The number of the block of the transaction.
+    pub block_number: u64,
+}
+
+/// A message sent by a source chain transaction, linked to the destination chain transaction
+/// that relayed it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BridgeLink {
+    /// The id of the message.
+    pub message_id: B256,
+    /// The source chain transaction that sent the message.
+    pub source: BridgeTransaction,
+    /// The destination chain transaction that relayed the message, or `None` if it was not found
+    /// within the lookup window.
+    pub destination: Option<BridgeTransaction>,
+}
+
+impl BridgeLink {
+    /// Returns `true` if the message was relayed on the destination chain.
+    pub const fn is_relayed(&self) -> bool {
+        self.destination.is_some()
+    }
+}
+
+/// Correlates source chain transactions with their destination chain effects, for bridges that
+/// identify messages by an id emitted in an event on both chains.
+///
+/// The destination chain is searched for the destination event over a lookup window of blocks,
+/// starting at a given block, e.g. the destination block at the time the source transaction was
+/// sent. The window is queried with `eth_getLogs` in chunks of
+/// [`with_block_span`](Self::with_block_span) blocks, and never extends past the latest block.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(
+/// #     l1: impl alloy_provider::Provider,
+/// #     l2: impl alloy_provider::Provider,
+/// #     tx_hash: alloy_primitives::TxHash,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_primitives::{address, keccak256};
+/// use alloy_provider::bridge::{BridgeCorrelator, BridgeEvent, MessageIdLocation};
+///
+/// // `MessageSent(bytes32 indexed id, ...)` on the source chain
+/// let sent =
+///     BridgeEvent::new(keccak256("MessageSent(bytes32,bytes)"), MessageIdLocation::Topic(1))
+///         .with_address(address!("25ace71c97B33Cc4729CF772ae268934F7ab5fA1"));
+/// // `MessageRelayed(bytes32 indexed id)` on the destination chain
+/// let relayed =
+///     BridgeEvent::new(keccak256("MessageRelayed(bytes32)"), MessageIdLocation::Topic(1));
+///
+/// let correlator = BridgeCorrelator::new(l1, l2, sent, relayed);
+/// // search the destination chain from the block at which the message was sent
+/// let from_block = 12_000_000;
+/// for link in correlator.correlate(tx_hash, from_block).await? {
+///     println!("{}: {:?}", link.message_id, link.destination);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BridgeCorrelator<S, D, SN = Ethereum, DN = Ethereum> {
+    source: S,
+    destination: D,
+    source_event: BridgeEvent,
+    destination_event: BridgeEvent,
+    lookup_window: u64,
+    block_span: u64,
+    _network: PhantomData<fn() -> (SN, DN)>,
+}
+
+impl<S, D, SN, DN> BridgeCorrelator<S, D, SN, DN>
+where
+    S: Provider<SN>,
+    D: Provider<DN>,
+    SN: Network,
+    DN: Network,
+{
+    /// The default number of destination blocks searched for a message.
+    pub const DEFAULT_LOOKUP_WINDOW: u64 = 10_000;
+
+    /// The default number of blocks covered by each `eth_getLogs` request.
+    pub const DEFAULT_BLOCK_SPAN: u64 = 2_000;
+
+    /// Creates a new correlator for messages sent with the source event on the source chain and
+    /// relayed with the destination event on the destination chain.
+    pub const fn new(
+        source: S,
+        destination: D,
+        source_event: BridgeEvent,
+        destination_event: BridgeEvent,
+    ) -> Self {
+        Self {
+            source,
+            destination,
+            source_event,
+            destination_event,
+            lookup_window: Self::DEFAULT_LOOKUP_WINDOW,
+            block_span: Self::DEFAULT_BLOCK_SPAN,
+            _network: PhantomData,
+        }
+    }
+
+    /// Sets the number of destination blocks searched for a message.
+    pub const fn with_lookup_window(mut self, lookup_window: u64) -> Self {
+        self.lookup_window = lookup_window;
+        self
+    }
+
+    /// Sets the number of blocks covered by each `eth_getLogs` request. A span of `0` is treated
+    /// as `1`.
+    pub const fn with_block_span(mut self, block_span: u64) -> Self {
+        self.block_span = block_span;
+        self
+    }
+
+    /// Returns the messages sent by the source chain transaction, in the order of their events,
+    /// without searching the destination chain.
+    ///
+    /// Fails with [`NotFound::Receipt`](crate::NotFound::Receipt) if the transaction is not
+    /// included in a block.
+    pub async fn source_messages(&self, tx_hash: TxHash) -> TransportResult<Vec<BridgeLink>> {
+        let receipt = self.source.require_transaction_receipt(tx_hash).await?;
+        let source = BridgeTransaction {
+            transaction_hash: tx_hash,
+            block_number: receipt.block_number().unwrap_or_default(),
+        };
+        Ok(receipt
+            .logs()
+            .filter_map(|log| self.source_event.message_id(log))
+            .map(|message_id| BridgeLink { message_id, source, destination: None })
+            .collect())
+    }
+
+    /// Returns the messages sent by the source chain transaction, linked to the destination chain
+    /// transactions that relayed them, searching the lookup window starting at the given
+    /// destination block.
+    pub async fn correlate(
+        &self,
+        tx_hash: TxHash,
+        from_block: u64,
+    ) -> TransportResult<Vec<BridgeLink>> {
+        let mut links = self.source_messages(tx_hash).await?;
+        let message_ids: Vec<_> = links.iter().map(|link| link.message_id).collect();
+        let relays = self.find_relays(&message_ids, from_block).await?;
+        for link in &mut links {
+            link.destination = relays.get(&link.message_id).copied();
+        }
+        Ok(links)
+    }
+
+    /// Searches the lookup window starting at the given destination block for the destination
+    /// events of the given message ids, returning the first relaying transaction of each id that
+    /// was found.
+    pub async fn find_relays(
+        &self,
+        message_ids: &[B256],
+        from_block: u64,
+    ) -> TransportResult<HashMap<B256, BridgeTransaction>> {
+        let mut relays = HashMap::default();
+        if message_ids.is_empty() {
+            return Ok(relays);
+        }
+
+        let latest = self.destination.get_block_number().await?;
+        let to_block = from_block.saturating_add(self.lookup_window.saturating_sub(1)).min(latest);
+        let filter = self.destination_event.filter(message_ids);
+        let span = self.block_span.max(1);
+
+        let mut start = from_block;
+        while start <= to_block && relays.len() < message_ids.len() {
+            let end = start.saturating_add(span - 1).min(to_block);
+            let logs =
+                self.destination.get_logs(&filter.clone().from_block(start).to_block(end)).await?;
+            for log in logs {
+                let Some(message_id) = self.destination_event.message_id(&log.inner) else {
+                    continue;
+                };
+                let (Some(transaction_hash), Some(block_number)) =
+                    (log.transaction_hash, log.block_number)
+                else {
+                    continue;
+                };
+                if message_ids.contains(&message_id) {
+                    relays
+                        .entry(message_id)
+                        .or_insert(BridgeTransaction { transaction_hash, block_number });
+                }
+            }
+            start = end.saturating_add(1);
+            if end == u64::MAX {
+                break;
+            }
+        }
+        Ok(relays)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, keccak256, LogData};
+
+    #[test]
+    fn message_id() {
+        let signature = keccak256("Relayed(bytes32,bytes32)");
+        let id = b256!("00000000000000000000000000000000000000000000000000000000000000aa");
+        let contract = address!("0000000000000000000000000000000000000001");
+        let log = Log {
+            address: contract,
+            data: LogData::new_unchecked(
+                vec![signature, id],
+                [B256::with_last_byte(0xbb), B256::with_last_byte(0xcc)].concat().into(),
+            ),
+        };
+
+        let topic = BridgeEvent::new(signature, MessageIdLocation::Topic(1));
+        assert_eq!(topic.message_id(&log), Some(id));
+        assert_eq!(topic.with_address(contract).message_id(&log), Some(id));
+        assert_eq!(topic.with_address(Address::ZERO).message_id(&log), None);
+
+        let data = BridgeEvent::new(signature, MessageIdLocation::Data(1));
+        assert_eq!(data.message_id(&log).unwrap()[31], 0xcc);
+        assert_eq!(BridgeEvent::new(signature, MessageIdLocation::Data(2)).message_id(&log), None);
+        assert_eq!(BridgeEvent::new(signature, MessageIdLocation::Topic(2)).message_id(&log), None);
+
+        let other = BridgeEvent::new(keccak256("Other()"), MessageIdLocation::Topic(1));
+        assert_eq!(other.message_id(&log), None);
+    }
+
+    #[test]
+    fn filter() {
+        let signature = keccak256("Relayed(bytes32)");
+        let ids = [B256::with_last_byte(1), B256::with_last_byte(2)];
+        let filter = BridgeEvent::new(signature, MessageIdLocation::Topic(1)).filter(&ids);
+        assert!(filter.topics[1].matches(&ids[0]) && filter.topics[1].matches(&ids[1]));
+        assert!(!filter.topics[1].matches(&B256::ZERO));
+
+        // ids in the data cannot be filtered
+        let filter = BridgeEvent::new(signature, MessageIdLocation::Data(0)).filter(&ids);
+        assert!(filter.topics[1].is_empty());
+    }
+}
@@ -0,0 +1,272 @@
+//! Batched broadcasting of raw transactions.
+
+use crate::Provider;
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{hex, TxHash};
+use alloy_rpc_client::BatchRequest;
+use alloy_transport::{TransportErrorKind, TransportResult};
+use std::{marker::PhantomData, ops::Range, time::Duration};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+
+/// The default maximum size of a batch in bytes, matching geth's default HTTP body limit of 5 MiB.
+pub const DEFAULT_MAX_BATCH_BYTES: usize = 5 * 1024 * 1024;
+
+/// The default maximum number of requests in a batch.
+pub const DEFAULT_MAX_BATCH_LEN: usize = 100;
+
+/// The size of an `eth_sendRawTransaction` request without the transaction hex and the id:
+/// `{"method":"eth_sendRawTransaction","params":["0x"],"id":,"jsonrpc":"2.0"}`.
+const REQUEST_OVERHEAD: usize = 73;
+
+/// The maximum number of digits of a request id, which is a `u64`.
+const MAX_ID_DIGITS: usize = 20;
+
+/// Broadcasts raw transactions in JSON-RPC batches.
+///
+/// Transactions are packed into batches in order, such that neither the serialized size nor the
+/// number of requests of a batch exceeds the configured limits. A transaction that exceeds the
+/// size limit on its own is sent in a batch of its own.
+///
+/// Batches are sent one after another, at most one per [interval](Self::with_interval), which
+/// allows staying within the rate limits of a provider. Transient failures can be retried by
+/// adding a [`RetryBackoffLayer`](alloy_transport::layers::RetryBackoffLayer) to the client.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider, txs: Vec<Vec<u8>>) {
+/// use alloy_provider::broadcast::RawTxBroadcaster;
+/// use std::time::Duration;
+///
+/// let broadcaster = RawTxBroadcaster::new(provider)
+///     .with_max_batch_len(50)
+///     .with_interval(Duration::from_millis(200));
+/// for result in broadcaster.broadcast(&txs).await {
+///     if let Err(err) = result {
+///         eprintln!("failed to rebroadcast transaction: {err}");
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RawTxBroadcaster<P, N = Ethereum> {
+    provider: P,
+    max_batch_bytes: usize,
+    max_batch_len: usize,
+    interval: Duration,
+    _pd: PhantomData<N>,
+}
+
+impl<P, N> RawTxBroadcaster<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new broadcaster with the default limits and no pacing.
+    pub const fn new(provider: P) -> Self {
+        Self {
+            provider,
+            max_batch_bytes: DEFAULT_MAX_BATCH_BYTES,
+            max_batch_len: DEFAULT_MAX_BATCH_LEN,
+            interval: Duration::ZERO,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Sets the maximum serialized size of a batch in bytes.
+    ///
+    /// Defaults to [`DEFAULT_MAX_BATCH_BYTES`].
+    pub const fn with_max_batch_bytes(mut self, max_batch_bytes: usize) -> Self {
+        self.max_batch_bytes = max_batch_bytes;
+        self
+    }
+
+    /// Sets the maximum number of requests in a batch. Values below 1 are treated as 1.
+    ///
+    /// Defaults to [`DEFAULT_MAX_BATCH_LEN`].
+    pub fn with_max_batch_len(mut self, max_batch_len: usize) -> Self {
+        self.max_batch_len = max_batch_len.max(1);
+        self
+    }
+
+    /// Sets the minimum delay between sending two batches. Defaults to zero.
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns a reference to the provider.
+    pub const fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Broadcasts the EIP-2718 encoded transactions.
+    ///
+    /// Returns the result of each transaction, in the order of the input. If a whole batch
+    /// fails, e.g. because the provider rejected its size, each of its transactions carries the
+    /// error of the batch.
+    pub async fn broadcast<I>(&self, txs: I) -> Vec<TransportResult<TxHash>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let txs: Vec<_> = txs.into_iter().collect();
+        let batches = self.batches(txs.iter().map(|tx| tx.as_ref().len()));
+        let mut results = Vec::with_capacity(txs.len());
+        for (i, range) in batches.into_iter().enumerate() {
+            if i > 0 && !self.interval.is_zero() {
+                sleep(self.interval).await;
+            }
+            trace!(len = range.len(), "broadcasting raw transaction batch");
+            results.extend(self.send_batch(&txs[range]).await);
+        }
+        results
+    }
+
+    async fn send_batch<T: AsRef<[u8]>>(&self, txs: &[T]) -> Vec<TransportResult<TxHash>> {
+        let mut batch = BatchRequest::new(self.provider.client());
+        let waiters: Vec<_> = txs
+            .iter()
+            .map(|tx| {
+                let params = (hex::encode_prefixed(tx),);
+                batch.add_call::<_, TxHash>("eth_sendRawTransaction", &params)
+            })
+            .collect();
+
+        if let Err(err) = batch.await {
+            debug!(%err, "raw transaction batch failed");
+            let err = err.to_string();
+            return waiters
+                .into_iter()
+                .map(|waiter| waiter.and_then(|_| Err(TransportErrorKind::custom_str(&err))))
+                .collect();
+        }
+
+        let mut results = Vec::with_capacity(waiters.len());
+        for waiter in waiters {
+            results.push(match waiter {
+                Ok(waiter) => waiter.await,
+                Err(err) => Err(err),
+            });
+        }
+        results
+    }
+
+    /// Splits transactions of the given encoded lengths into consecutive batches.
+    fn batches(&self, tx_lens: impl IntoIterator<Item = usize>) -> Vec<Range<usize>> {
+        let mut batches = Vec::new();
+        let mut start = 0;
+        // The enclosing brackets of the batch.
+        let mut size = 2;
+        let mut end = 0;
+        for tx_len in tx_lens {
+            let len = end - start;
+            // Requests after the first are separated by a comma.
+            let cost = request_size(tx_len) + usize::from(len > 0);
+            if len > 0 && (len >= self.max_batch_len || size + cost > self.max_batch_bytes) {
+                batches.push(start..end);
+                start = end;
+                size = 2 + request_size(tx_len);
+            } else {
+                size += cost;
+            }
+            end += 1;
+        }
+        if end > start {
+            batches.push(start..end);
+        }
+        batches
+    }
+}
+
+/// Returns an upper bound of the serialized size of an `eth_sendRawTransaction` request for a
+/// transaction of the given encoded length.
+///
+/// The bound is exact for requests whose id has the maximum number of digits.
+pub const fn request_size(tx_len: usize) -> usize {
+    REQUEST_OVERHEAD + MAX_ID_DIGITS + 2 * tx_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_json_rpc::{Id, Request};
+    use alloy_network::{EthereumWallet, TransactionBuilder};
+    use alloy_node_bindings::Anvil;
+    use alloy_primitives::U256;
+    use alloy_rpc_types_eth::TransactionRequest;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[test]
+    fn request_size_is_exact_for_max_id() {
+        let tx = [0xab; 37];
+        let request = Request::new(
+            "eth_sendRawTransaction",
+            Id::Number(u64::MAX),
+            (hex::encode_prefixed(tx),),
+        );
+        let serialized = request.serialize().unwrap();
+        assert_eq!(serialized.serialized().get().len(), request_size(tx.len()));
+    }
+
+    #[test]
+    fn splits_batches() {
+        let provider = ProviderBuilder::new().on_http("http://localhost:8545".parse().unwrap());
+        let broadcaster = RawTxBroadcaster::new(provider).with_max_batch_len(3);
+        assert_eq!(broadcaster.batches([10; 7]), vec![0..3, 3..6, 6..7]);
+        assert!(broadcaster.batches([]).is_empty());
+
+        // Two requests of 100 bytes fit, three don't.
+        let max = 2 + 2 * request_size(100) + 1;
+        let broadcaster = broadcaster.with_max_batch_len(10).with_max_batch_bytes(max);
+        assert_eq!(broadcaster.batches([100; 5]), vec![0..2, 2..4, 4..5]);
+
+        // Oversized transactions are sent on their own.
+        assert_eq!(broadcaster.batches([10, 1000, 10]), vec![0..1, 1..2, 2..3]);
+    }
+
+    #[tokio::test]
+    async fn broadcasts_with_partial_failures() {
+        let anvil = Anvil::new().spawn();
+        let signer: PrivateKeySigner = anvil.keys()[0].clone().into();
+        let wallet = EthereumWallet::from(signer);
+        let to = anvil.addresses()[1];
+
+        let mut txs = Vec::new();
+        for nonce in 0..3 {
+            let tx = TransactionRequest::default()
+                .with_to(to)
+                .with_value(U256::from(1))
+                .with_nonce(nonce)
+                .with_chain_id(anvil.chain_id())
+                .with_gas_limit(21_000)
+                .with_max_fee_per_gas(20_000_000_000)
+                .with_max_priority_fee_per_gas(1_000_000_000)
+                .build(&wallet)
+                .await
+                .unwrap();
+            txs.push(tx.encoded_2718());
+        }
+        // Not a valid transaction.
+        txs.insert(1, vec![0xde, 0xad]);
+
+        let provider = ProviderBuilder::new().on_http(anvil.endpoint_url());
+        let broadcaster = RawTxBroadcaster::new(provider)
+            .with_max_batch_len(2)
+            .with_interval(Duration::from_millis(10));
+        let results = broadcaster.broadcast(&txs).await;
+
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+        assert!(results[3].is_ok());
+    }
+}
@@ -2,8 +2,8 @@
 use crate::Provider;
 use alloy_json_rpc::RpcRecv;
 use alloy_network::Network;
-use alloy_primitives::{hex, Bytes, TxHash, B256};
-use alloy_rpc_types_debug::ExecutionWitness;
+use alloy_primitives::{hex, Address, BlockHash, Bytes, TxHash, B256};
+use alloy_rpc_types_debug::{ExecutionWitness, StatelessWitness, StorageRangeResult};
 use alloy_rpc_types_eth::{
     BadBlock, BlockId, BlockNumberOrTag, Bundle, StateContext, TransactionRequest,
 };
@@ -32,6 +32,24 @@ pub trait DebugApi<N>: Send + Sync {
     /// Returns an array of recent bad blocks that the client has seen on the network.
     async fn debug_get_bad_blocks(&self) -> TransportResult<Vec<BadBlock>>;
 
+    /// Returns a page of the storage of `address`, at the state after executing the transaction
+    /// at `tx_index` in the given block.
+    ///
+    /// Entries are ordered by hashed storage slot, starting at `start_key`. At most `max_results`
+    /// entries are returned; use [`StorageRangeResult::next_key`] to fetch the next page.
+    ///
+    /// # Note
+    ///
+    /// Not all nodes support this call.
+    async fn debug_storage_range_at(
+        &self,
+        block_hash: BlockHash,
+        tx_index: u64,
+        address: Address,
+        start_key: B256,
+        max_results: u64,
+    ) -> TransportResult<StorageRangeResult>;
+
     /// Returns the structured logs created during the execution of EVM between two blocks
     /// (excluding start) as a JSON object.
     async fn debug_trace_chain(
@@ -249,6 +267,22 @@ pub trait DebugApi<N>: Send + Sync {
         &self,
         block: BlockNumberOrTag,
     ) -> TransportResult<ExecutionWitness>;
+
+    /// The `debug_executionWitness` method of geth, which returns the execution witness of a block
+    /// as lists of trie nodes and codes together with the ancestor headers required for stateless
+    /// execution.
+    ///
+    /// The first argument is the block number or block hash.
+    ///
+    /// # Note
+    ///
+    /// Not all nodes support this call. Nodes returning the hash-keyed format of
+    /// [`debug_execution_witness`](DebugApi::debug_execution_witness) can not be decoded as
+    /// [`StatelessWitness`].
+    async fn debug_stateless_witness(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> TransportResult<StatelessWitness>;
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
@@ -278,6 +312,22 @@ where
         self.client().request_noparams("debug_getBadBlocks").await
     }
 
+    async fn debug_storage_range_at(
+        &self,
+        block_hash: BlockHash,
+        tx_index: u64,
+        address: Address,
+        start_key: B256,
+        max_results: u64,
+    ) -> TransportResult<StorageRangeResult> {
+        self.client()
+            .request(
+                "debug_storageRangeAt",
+                (block_hash, tx_index, address, start_key, max_results),
+            )
+            .await
+    }
+
     async fn debug_trace_chain(
         &self,
         start_exclusive: BlockNumberOrTag,
@@ -400,6 +450,13 @@ where
     ) -> TransportResult<ExecutionWitness> {
         self.client().request("debug_executionWitness", block).await
     }
+
+    async fn debug_stateless_witness(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> TransportResult<StatelessWitness> {
+        self.client().request("debug_executionWitness", block).await
+    }
 }
 
 #[cfg(test)]
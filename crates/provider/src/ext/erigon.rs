@@ -0,0 +1,128 @@
+//! This module extends the Ethereum JSON-RPC provider with the Erigon namespace's RPC methods.
+use crate::Provider;
+use alloy_network::Network;
+use alloy_network_primitives::BlockResponse;
+use alloy_rpc_types_eth::{BlockNumberOrTag, BlockTransactionsKind, Filter, Log};
+use alloy_rpc_types_trace::erigon::LatestLogsOptions;
+use alloy_transport::TransportResult;
+
+/// Erigon namespace rpc interface that gives access to several non-standard RPC methods.
+///
+/// The `*_with_fallback` methods use the standard `eth_` methods if the node does not serve the
+/// Erigon namespace.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait ErigonApi<N: Network>: Send + Sync {
+    /// Returns the header of the block with the given number, without fetching its body.
+    async fn erigon_get_header_by_number(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> TransportResult<Option<N::HeaderResponse>>;
+
+    /// Returns the header of the block with the given number, falling back to
+    /// `eth_getBlockByNumber` if the node does not serve the Erigon namespace.
+    async fn erigon_get_header_by_number_with_fallback(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> TransportResult<Option<N::HeaderResponse>>;
+
+    /// Returns the latest logs matching the filter, searching backwards from the latest block
+    /// until the limits of `options` are reached.
+    async fn erigon_get_latest_logs(
+        &self,
+        filter: &Filter,
+        options: LatestLogsOptions,
+    ) -> TransportResult<Vec<Log>>;
+
+    /// Returns the latest logs matching the filter, falling back to `eth_getLogs` if the node
+    /// does not serve the Erigon namespace.
+    ///
+    /// The fallback queries the block range of the filter and keeps the last
+    /// [`LatestLogsOptions::log_count`] logs; the other options are ignored.
+    async fn erigon_get_latest_logs_with_fallback(
+        &self,
+        filter: &Filter,
+        options: LatestLogsOptions,
+    ) -> TransportResult<Vec<Log>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<N, P> ErigonApi<N> for P
+where
+    N: Network,
+    P: Provider<N>,
+{
+    async fn erigon_get_header_by_number(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> TransportResult<Option<N::HeaderResponse>> {
+        self.client().request("erigon_getHeaderByNumber", (block,)).await
+    }
+
+    async fn erigon_get_header_by_number_with_fallback(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> TransportResult<Option<N::HeaderResponse>> {
+        match self.erigon_get_header_by_number(block).await {
+            Err(err) if super::is_method_not_found(&err) => Ok(self
+                .get_block_by_number(block, BlockTransactionsKind::Hashes)
+                .await?
+                .map(|block| block.header().clone())),
+            res => res,
+        }
+    }
+
+    async fn erigon_get_latest_logs(
+        &self,
+        filter: &Filter,
+        options: LatestLogsOptions,
+    ) -> TransportResult<Vec<Log>> {
+        self.client().request("erigon_getLatestLogs", (filter, options)).await
+    }
+
+    async fn erigon_get_latest_logs_with_fallback(
+        &self,
+        filter: &Filter,
+        options: LatestLogsOptions,
+    ) -> TransportResult<Vec<Log>> {
+        match self.erigon_get_latest_logs(filter, options).await {
+            Err(err) if super::is_method_not_found(&err) => {
+                let mut logs = self.get_logs(filter).await?;
+                if let Some(count) = options.log_count {
+                    let skip = logs.len().saturating_sub(count as usize);
+                    logs.drain(..skip);
+                }
+                Ok(logs)
+            }
+            res => res,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ProviderBuilder;
+
+    #[tokio::test]
+    async fn erigon_fallback_on_anvil() {
+        let provider = ProviderBuilder::new().on_anvil();
+
+        let header = provider
+            .erigon_get_header_by_number_with_fallback(BlockNumberOrTag::Earliest)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(header.number, 0);
+
+        let logs = provider
+            .erigon_get_latest_logs_with_fallback(
+                &Filter::new(),
+                LatestLogsOptions::default().with_log_count(1),
+            )
+            .await
+            .unwrap();
+        assert!(logs.is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+//! This module extends the Ethereum JSON-RPC provider with the legacy mining methods of the `eth`
+//! namespace and the `miner` namespace.
+use crate::Provider;
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, U128, U64};
+use alloy_transport::{TransportError, TransportResult};
+use std::collections::HashMap;
+
+/// The mining methods served by a node, see [`MinerApi::miner_capabilities`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MinerCapabilities {
+    /// Whether the node serves `eth_coinbase`.
+    pub coinbase: bool,
+    /// Whether the node serves `eth_mining`.
+    pub mining: bool,
+    /// Whether the node serves `eth_hashrate`.
+    pub hashrate: bool,
+    /// Whether the node exposes the `miner` namespace, `None` if the node does not serve
+    /// `rpc_modules`.
+    pub miner: Option<bool>,
+}
+
+/// Mining rpc interface, covering the legacy `eth_coinbase`, `eth_mining` and `eth_hashrate`
+/// methods and the administrative `miner` namespace.
+///
+/// These methods are mostly useful to automate private networks. Support varies between clients
+/// and versions, e.g. geth dropped `eth_hashrate` after the merge, so
+/// [`miner_capabilities`](Self::miner_capabilities) can be used to check which methods a node
+/// serves.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait MinerApi<N: Network = Ethereum>: Send + Sync {
+    /// Returns the address receiving the rewards of the blocks mined by the node.
+    async fn eth_coinbase(&self) -> TransportResult<Address>;
+
+    /// Returns whether the node is actively mining new blocks.
+    async fn eth_mining(&self) -> TransportResult<bool>;
+
+    /// Returns the number of hashes per second the node is mining with.
+    async fn eth_hashrate(&self) -> TransportResult<u64>;
+
+    /// Starts mining new blocks.
+    async fn miner_start(&self) -> TransportResult<()>;
+
+    /// Stops mining new blocks.
+    async fn miner_stop(&self) -> TransportResult<()>;
+
+    /// Sets the gas limit targeted by the blocks mined by the node.
+    ///
+    /// Returns `true` if the node accepted the new gas limit.
+    async fn miner_set_gas_limit(&self, gas_limit: u64) -> TransportResult<bool>;
+
+    /// Sets the minimum gas price of the transactions included in the blocks mined by the node.
+    ///
+    /// Returns `true` if the node accepted the new gas price.
+    async fn miner_set_gas_price(&self, gas_price: u128) -> TransportResult<bool>;
+
+    /// Sets the extra data of the blocks mined by the node.
+    ///
+    /// Returns `true` if the node accepted the new extra data.
+    async fn miner_set_extra(&self, extra: &str) -> TransportResult<bool>;
+
+    /// Sets the address receiving the rewards of the blocks mined by the node.
+    ///
+    /// Returns `true` if the node accepted the new address.
+    async fn miner_set_etherbase(&self, etherbase: Address) -> TransportResult<bool>;
+
+    /// Checks which mining methods the node serves.
+    ///
+    /// The `eth` methods are probed by calling them, and count as served unless the node responds
+    /// with a "method not found" error. The `miner` namespace is looked up in `rpc_modules`, as
+    /// it has no side-effect free method.
+    async fn miner_capabilities(&self) -> TransportResult<MinerCapabilities>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<N, P> MinerApi<N> for P
+where
+    N: Network,
+    P: Provider<N>,
+{
+    async fn eth_coinbase(&self) -> TransportResult<Address> {
+        self.client().request_noparams("eth_coinbase").await
+    }
+
+    async fn eth_mining(&self) -> TransportResult<bool> {
+        self.client().request_noparams("eth_mining").await
+    }
+
+    async fn eth_hashrate(&self) -> TransportResult<u64> {
+        self.client().request_noparams("eth_hashrate").map_resp(crate::utils::convert_u64).await
+    }
+
+    async fn miner_start(&self) -> TransportResult<()> {
+        self.client().request_noparams("miner_start").await
+    }
+
+    async fn miner_stop(&self) -> TransportResult<()> {
+        self.client().request_noparams("miner_stop").await
+    }
+
+    async fn miner_set_gas_limit(&self, gas_limit: u64) -> TransportResult<bool> {
+        self.client().request("miner_setGasLimit", (U64::from(gas_limit),)).await
+    }
+
+    async fn miner_set_gas_price(&self, gas_price: u128) -> TransportResult<bool> {
+        self.client().request("miner_setGasPrice", (U128::from(gas_price),)).await
+    }
+
+    async fn miner_set_extra(&self, extra: &str) -> TransportResult<bool> {
+        self.client().request("miner_setExtra", (extra,)).await
+    }
+
+    async fn miner_set_etherbase(&self, etherbase: Address) -> TransportResult<bool> {
+        self.client().request("miner_setEtherbase", (etherbase,)).await
+    }
+
+    async fn miner_capabilities(&self) -> TransportResult<MinerCapabilities> {
+        let miner =
+            match self.client().request_noparams::<HashMap<String, String>>("rpc_modules").await {
+                Ok(modules) => Some(modules.contains_key("miner")),
+                Err(err) if is_served(&err) => None,
+                Err(err) => return Err(err),
+            };
+        Ok(MinerCapabilities {
+            coinbase: served(self.eth_coinbase().await)?,
+            mining: served(self.eth_mining().await)?,
+            hashrate: served(self.eth_hashrate().await)?,
+            miner,
+        })
+    }
+}
+
+/// Returns whether the method was served, i.e. the node did not respond with "method not found".
+fn served<T>(res: TransportResult<T>) -> TransportResult<bool> {
+    match res {
+        Ok(_) => Ok(true),
+        Err(err) if is_served(&err) => Ok(!super::is_method_not_found(&err)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns whether the error is an error response of the node, as opposed to a transport error.
+fn is_served(err: &TransportError) -> bool {
+    err.as_error_resp().is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ext::test::async_ci_only, ProviderBuilder};
+    use alloy_node_bindings::{utils::run_with_tempdir, Geth};
+
+    #[tokio::test]
+    async fn miner_capabilities() {
+        async_ci_only(|| async move {
+            run_with_tempdir("geth-test-", |temp_dir| async move {
+                let geth = Geth::new().dev().disable_discovery().data_dir(temp_dir).spawn();
+                let provider = ProviderBuilder::new().on_http(geth.endpoint_url());
+
+                // geth serves `rpc_modules`, but dropped some of the legacy methods
+                let capabilities = provider.miner_capabilities().await.unwrap();
+                assert!(capabilities.miner.is_some());
+                if capabilities.hashrate {
+                    assert_eq!(provider.eth_hashrate().await.unwrap(), 0);
+                }
+            })
+            .await;
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn miner_set_gas_price() {
+        async_ci_only(|| async move {
+            run_with_tempdir("geth-test-", |temp_dir| async move {
+                let geth = Geth::new().dev().disable_discovery().data_dir(temp_dir).spawn();
+                let provider = ProviderBuilder::new().on_http(geth.endpoint_url());
+
+                if provider.miner_capabilities().await.unwrap().miner == Some(true) {
+                    assert!(provider.miner_set_gas_price(1_000_000_000).await.unwrap());
+                }
+            })
+            .await;
+        })
+        .await;
+    }
+}
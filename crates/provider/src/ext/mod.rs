@@ -20,11 +20,36 @@ mod debug;
 #[cfg(feature = "debug-api")]
 pub use debug::DebugApi;
 
+#[cfg(feature = "erigon-api")]
+mod erigon;
+#[cfg(feature = "erigon-api")]
+pub use erigon::ErigonApi;
+
+#[cfg(feature = "miner-api")]
+mod miner;
+#[cfg(feature = "miner-api")]
+pub use miner::{MinerApi, MinerCapabilities};
+
 #[cfg(feature = "net-api")]
 mod net;
 #[cfg(feature = "net-api")]
 pub use net::NetApi;
 
+#[cfg(feature = "otterscan-api")]
+mod otterscan;
+#[cfg(feature = "otterscan-api")]
+pub use otterscan::OtterscanApi;
+
+#[cfg(feature = "personal-api")]
+mod personal;
+#[cfg(feature = "personal-api")]
+pub use personal::PersonalApi;
+
+#[cfg(feature = "tenderly-api")]
+mod tenderly;
+#[cfg(feature = "tenderly-api")]
+pub use tenderly::TenderlyApi;
+
 #[cfg(feature = "trace-api")]
 mod trace;
 #[cfg(feature = "trace-api")]
@@ -45,6 +70,17 @@ mod erc4337;
 #[cfg(feature = "erc4337-api")]
 pub use erc4337::Erc4337Api;
 
+/// Returns whether the error indicates that the node does not serve the requested method.
+#[cfg(any(
+    feature = "erigon-api",
+    feature = "miner-api",
+    feature = "otterscan-api",
+    all(test, feature = "personal-api")
+))]
+pub(crate) fn is_method_not_found(err: &alloy_transport::TransportError) -> bool {
+    err.as_error_resp().is_some_and(|err| err.is_method_not_found())
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     #[allow(dead_code)] // dead only when all features off
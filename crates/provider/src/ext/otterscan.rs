@@ -0,0 +1,167 @@
+//! This module extends the Ethereum JSON-RPC provider with the Otterscan namespace's RPC methods.
+use crate::Provider;
+use alloy_network::Network;
+use alloy_primitives::{Address, BlockHash};
+use alloy_rpc_types_eth::{BlockNumberOrTag, BlockTransactionsKind};
+use alloy_rpc_types_trace::otterscan::{BlockDetails, TransactionsWithReceipts};
+use alloy_transport::TransportResult;
+
+/// Otterscan namespace rpc interface that gives access to explorer-oriented RPC methods.
+///
+/// These methods are served by Erigon and Reth and are considerably faster for explorer-style
+/// queries than combining standard `eth_` methods. Use [`ots_is_supported`] to detect support.
+///
+/// [`ots_is_supported`]: OtterscanApi::ots_is_supported
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait OtterscanApi<N: Network>: Send + Sync {
+    /// Returns the version of the Otterscan API implemented by the node.
+    async fn ots_get_api_level(&self) -> TransportResult<u64>;
+
+    /// Returns whether the node serves the Otterscan namespace.
+    ///
+    /// Errors other than an unknown method are returned as is.
+    async fn ots_is_supported(&self) -> TransportResult<bool>;
+
+    /// Returns the details of the block with the given number, including its issuance and total
+    /// fees.
+    async fn ots_get_block_details(
+        &self,
+        block_number: u64,
+    ) -> TransportResult<BlockDetails<N::HeaderResponse>>;
+
+    /// Returns the details of the block with the given hash, including its issuance and total
+    /// fees.
+    async fn ots_get_block_details_by_hash(
+        &self,
+        block_hash: BlockHash,
+    ) -> TransportResult<BlockDetails<N::HeaderResponse>>;
+
+    /// Returns the details of the block with the given number, falling back to
+    /// `eth_getBlockByNumber` if the node does not serve the Otterscan namespace.
+    ///
+    /// The fallback does not know the issuance and total fees of the block, which are left as
+    /// zero.
+    async fn ots_get_block_details_with_fallback(
+        &self,
+        block_number: u64,
+    ) -> TransportResult<Option<BlockDetails<N::HeaderResponse>>>
+    where
+        N::BlockResponse: Into<BlockDetails<N::HeaderResponse>>;
+
+    /// Returns a page of at most `page_size` transactions sent from or to `address`, before the
+    /// given block, in descending order.
+    ///
+    /// A `block_number` of zero starts at the latest block.
+    async fn ots_search_transactions_before(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> TransportResult<TransactionsWithReceipts<N::TransactionResponse>>;
+
+    /// Returns a page of at most `page_size` transactions sent from or to `address`, after the
+    /// given block, in descending order.
+    ///
+    /// A `block_number` of zero starts at the genesis block.
+    async fn ots_search_transactions_after(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> TransportResult<TransactionsWithReceipts<N::TransactionResponse>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<N, P> OtterscanApi<N> for P
+where
+    N: Network,
+    P: Provider<N>,
+{
+    async fn ots_get_api_level(&self) -> TransportResult<u64> {
+        self.client().request_noparams("ots_getApiLevel").await
+    }
+
+    async fn ots_is_supported(&self) -> TransportResult<bool> {
+        match self.ots_get_api_level().await {
+            Ok(_) => Ok(true),
+            Err(err) if super::is_method_not_found(&err) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn ots_get_block_details(
+        &self,
+        block_number: u64,
+    ) -> TransportResult<BlockDetails<N::HeaderResponse>> {
+        self.client().request("ots_getBlockDetails", (block_number,)).await
+    }
+
+    async fn ots_get_block_details_by_hash(
+        &self,
+        block_hash: BlockHash,
+    ) -> TransportResult<BlockDetails<N::HeaderResponse>> {
+        self.client().request("ots_getBlockDetailsByHash", (block_hash,)).await
+    }
+
+    async fn ots_get_block_details_with_fallback(
+        &self,
+        block_number: u64,
+    ) -> TransportResult<Option<BlockDetails<N::HeaderResponse>>>
+    where
+        N::BlockResponse: Into<BlockDetails<N::HeaderResponse>>,
+    {
+        match self.ots_get_block_details(block_number).await {
+            Ok(details) => Ok(Some(details)),
+            Err(err) if super::is_method_not_found(&err) => {
+                let block = self
+                    .get_block_by_number(
+                        BlockNumberOrTag::Number(block_number),
+                        BlockTransactionsKind::Hashes,
+                    )
+                    .await?;
+                Ok(block.map(Into::into))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn ots_search_transactions_before(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> TransportResult<TransactionsWithReceipts<N::TransactionResponse>> {
+        self.client()
+            .request("ots_searchTransactionsBefore", (address, block_number, page_size))
+            .await
+    }
+
+    async fn ots_search_transactions_after(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> TransportResult<TransactionsWithReceipts<N::TransactionResponse>> {
+        self.client()
+            .request("ots_searchTransactionsAfter", (address, block_number, page_size))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ProviderBuilder;
+
+    #[tokio::test]
+    async fn ots_fallback_on_anvil() {
+        let provider = ProviderBuilder::new().on_anvil();
+
+        // anvil implements the otterscan namespace
+        assert!(provider.ots_is_supported().await.unwrap());
+        let details = provider.ots_get_block_details_with_fallback(0).await.unwrap().unwrap();
+        assert_eq!(details.block.transaction_count, 0);
+    }
+}
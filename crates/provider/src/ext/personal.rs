@@ -0,0 +1,130 @@
+//! This module extends the Ethereum JSON-RPC provider with the Personal namespace's RPC methods.
+use crate::Provider;
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{hex, Address, B256};
+use alloy_transport::TransportResult;
+
+/// Personal namespace rpc interface that manages the accounts stored in the keystore of the node.
+///
+/// These methods are meant for development and test setups where the node manages accounts, e.g.
+/// to create and unlock accounts before sending transactions from them with
+/// [`Provider::send_transaction`]. Nodes usually disable this namespace by default, and geth
+/// removed it in recent versions.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait PersonalApi<N: Network = Ethereum>: Send + Sync {
+    /// Creates a new account in the keystore of the node, encrypted with the given password.
+    ///
+    /// Returns the address of the new account.
+    async fn personal_new_account(&self, password: &str) -> TransportResult<Address>;
+
+    /// Imports the given private key into the keystore of the node, encrypted with the given
+    /// password.
+    ///
+    /// Returns the address of the imported account.
+    async fn personal_import_raw_key(
+        &self,
+        private_key: &B256,
+        password: &str,
+    ) -> TransportResult<Address>;
+
+    /// Returns the addresses of all accounts in the keystore of the node.
+    async fn personal_list_accounts(&self) -> TransportResult<Vec<Address>>;
+
+    /// Unlocks the given account, allowing the node to sign with it.
+    ///
+    /// The account stays unlocked for `duration` seconds, or the default duration of the node if
+    /// `None`. A duration of `0` unlocks the account until the node exits.
+    ///
+    /// Returns `true` if the account was unlocked.
+    async fn personal_unlock_account(
+        &self,
+        address: Address,
+        password: &str,
+        duration: Option<u64>,
+    ) -> TransportResult<bool>;
+
+    /// Locks the given account, removing its private key from the memory of the node.
+    ///
+    /// Returns `true` if the account was locked.
+    async fn personal_lock_account(&self, address: Address) -> TransportResult<bool>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<N, P> PersonalApi<N> for P
+where
+    N: Network,
+    P: Provider<N>,
+{
+    async fn personal_new_account(&self, password: &str) -> TransportResult<Address> {
+        self.client().request("personal_newAccount", (password,)).await
+    }
+
+    async fn personal_import_raw_key(
+        &self,
+        private_key: &B256,
+        password: &str,
+    ) -> TransportResult<Address> {
+        // The key is expected without the `0x` prefix.
+        self.client().request("personal_importRawKey", (hex::encode(private_key), password)).await
+    }
+
+    async fn personal_list_accounts(&self) -> TransportResult<Vec<Address>> {
+        self.client().request_noparams("personal_listAccounts").await
+    }
+
+    async fn personal_unlock_account(
+        &self,
+        address: Address,
+        password: &str,
+        duration: Option<u64>,
+    ) -> TransportResult<bool> {
+        self.client().request("personal_unlockAccount", (address, password, duration)).await
+    }
+
+    async fn personal_lock_account(&self, address: Address) -> TransportResult<bool> {
+        self.client().request("personal_lockAccount", (address,)).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ext::test::async_ci_only, ProviderBuilder};
+    use alloy_node_bindings::{utils::run_with_tempdir, Geth};
+    use alloy_primitives::b256;
+
+    #[tokio::test]
+    async fn personal_accounts() {
+        async_ci_only(|| async move {
+            run_with_tempdir("geth-test-", |temp_dir| async move {
+                let geth = Geth::new().disable_discovery().data_dir(temp_dir).spawn();
+                let provider = ProviderBuilder::new().on_http(geth.endpoint_url());
+
+                // recent geth versions no longer serve the personal namespace
+                let accounts = match provider.personal_list_accounts().await {
+                    Ok(accounts) => accounts,
+                    Err(err) if crate::ext::is_method_not_found(&err) => return,
+                    Err(err) => panic!("{err}"),
+                };
+                assert!(accounts.is_empty());
+
+                let key = b256!("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80");
+                let imported = provider.personal_import_raw_key(&key, "password").await.unwrap();
+                let created = provider.personal_new_account("password").await.unwrap();
+                let accounts = provider.personal_list_accounts().await.unwrap();
+                assert_eq!(accounts.len(), 2);
+                assert!(accounts.contains(&imported) && accounts.contains(&created));
+
+                assert!(provider
+                    .personal_unlock_account(created, "password", Some(60))
+                    .await
+                    .unwrap());
+                assert!(provider.personal_lock_account(created).await.unwrap());
+            })
+            .await;
+        })
+        .await;
+    }
+}
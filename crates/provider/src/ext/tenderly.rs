@@ -0,0 +1,130 @@
+//! This module extends the Ethereum JSON-RPC provider with the Tenderly namespace's RPC methods.
+use crate::Provider;
+use alloy_network::Network;
+use alloy_primitives::{Bytes, TxHash};
+use alloy_rpc_types_eth::{BlockId, Transaction, TransactionRequest};
+use alloy_rpc_types_tenderly::{
+    BundleSimulationRequest, DecodedCall, GasEstimate, HistoricalStorageChange, SimulationRequest,
+    SimulationResult, StorageChangesRequest, TransactionsRangeRequest,
+};
+use alloy_transport::TransportResult;
+
+/// Tenderly namespace rpc interface that gives access to the simulation, decoding and
+/// historical query methods of [Tenderly nodes](https://docs.tenderly.co/node/rpc-reference).
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait TenderlyApi<N>: Send + Sync {
+    /// Simulates the transaction, returning its logs, call trace and asset, balance and state
+    /// changes.
+    ///
+    /// A [`SimulationRequest`] can be created from a [`TransactionRequest`] with `into()`.
+    async fn tenderly_simulate_transaction(
+        &self,
+        request: SimulationRequest,
+    ) -> TransportResult<SimulationResult>;
+
+    /// Simulates the transactions in order, each on top of the state left by the previous ones.
+    async fn tenderly_simulate_bundle(
+        &self,
+        request: BundleSimulationRequest,
+    ) -> TransportResult<Vec<SimulationResult>>;
+
+    /// Estimates the gas of the transaction on top of the given block.
+    async fn tenderly_estimate_gas(
+        &self,
+        tx: TransactionRequest,
+        block: BlockId,
+    ) -> TransportResult<GasEstimate>;
+
+    /// Estimates the gas of each transaction, executing them in order on top of the given block.
+    async fn tenderly_estimate_gas_bundle(
+        &self,
+        txs: Vec<TransactionRequest>,
+        block: BlockId,
+    ) -> TransportResult<Vec<GasEstimate>>;
+
+    /// Decodes the calldata of a function call using the ABIs known to Tenderly.
+    async fn tenderly_decode_input(&self, input: Bytes) -> TransportResult<DecodedCall>;
+
+    /// Decodes the revert data of a custom error using the ABIs known to Tenderly.
+    async fn tenderly_decode_error(&self, data: Bytes) -> TransportResult<DecodedCall>;
+
+    /// Traces a mined transaction, returning the same details as a simulation.
+    async fn tenderly_trace_transaction(&self, hash: TxHash) -> TransportResult<SimulationResult>;
+
+    /// Returns the transactions in a block range matching the filter.
+    async fn tenderly_get_transactions_range(
+        &self,
+        filter: TransactionsRangeRequest,
+    ) -> TransportResult<Vec<Transaction>>;
+
+    /// Returns the storage changes of an account in a block range.
+    async fn tenderly_get_storage_changes(
+        &self,
+        filter: StorageChangesRequest,
+    ) -> TransportResult<Vec<HistoricalStorageChange>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<N, P> TenderlyApi<N> for P
+where
+    N: Network,
+    P: Provider<N>,
+{
+    async fn tenderly_simulate_transaction(
+        &self,
+        request: SimulationRequest,
+    ) -> TransportResult<SimulationResult> {
+        self.client().request("tenderly_simulateTransaction", request).await
+    }
+
+    async fn tenderly_simulate_bundle(
+        &self,
+        request: BundleSimulationRequest,
+    ) -> TransportResult<Vec<SimulationResult>> {
+        self.client().request("tenderly_simulateBundle", request).await
+    }
+
+    async fn tenderly_estimate_gas(
+        &self,
+        tx: TransactionRequest,
+        block: BlockId,
+    ) -> TransportResult<GasEstimate> {
+        self.client().request("tenderly_estimateGas", (tx, block)).await
+    }
+
+    async fn tenderly_estimate_gas_bundle(
+        &self,
+        txs: Vec<TransactionRequest>,
+        block: BlockId,
+    ) -> TransportResult<Vec<GasEstimate>> {
+        self.client().request("tenderly_estimateGasBundle", (txs, block)).await
+    }
+
+    async fn tenderly_decode_input(&self, input: Bytes) -> TransportResult<DecodedCall> {
+        self.client().request("tenderly_decodeInput", (input,)).await
+    }
+
+    async fn tenderly_decode_error(&self, data: Bytes) -> TransportResult<DecodedCall> {
+        self.client().request("tenderly_decodeError", (data,)).await
+    }
+
+    async fn tenderly_trace_transaction(&self, hash: TxHash) -> TransportResult<SimulationResult> {
+        self.client().request("tenderly_traceTransaction", (hash,)).await
+    }
+
+    async fn tenderly_get_transactions_range(
+        &self,
+        filter: TransactionsRangeRequest,
+    ) -> TransportResult<Vec<Transaction>> {
+        self.client().request("tenderly_getTransactionsRange", (filter,)).await
+    }
+
+    async fn tenderly_get_storage_changes(
+        &self,
+        filter: StorageChangesRequest,
+    ) -> TransportResult<Vec<HistoricalStorageChange>> {
+        self.client().request("tenderly_getStorageChanges", (filter,)).await
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+    Provider,
+};
+use alloy_eips::eip2930::AccessList;
+use alloy_network::{Network, TransactionBuilder};
+use alloy_transport::TransportResult;
+
+/// The gas estimates of a transaction with and without the access list returned by
+/// `eth_createAccessList`.
+///
+/// Returned by [`Provider::estimate_access_list`]. Use [`AccessListEstimate::should_include`] to
+/// decide whether attaching the access list is worth it, and [`AccessListEstimate::apply`] to
+/// update a transaction request accordingly. The [`AccessListFiller`] uses the same logic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessListEstimate {
+    /// The access list generated by the node.
+    pub access_list: AccessList,
+    /// The estimated gas of the transaction including the access list.
+    pub gas_with_access_list: u64,
+    /// The estimated gas of the transaction without an access list.
+    pub gas_without_access_list: u64,
+}
+
+impl AccessListEstimate {
+    /// Returns the difference between the estimate without and with the access list.
+    ///
+    /// A positive value means the access list reduces the gas of the transaction.
+    pub const fn gas_delta(&self) -> i128 {
+        self.gas_without_access_list as i128 - self.gas_with_access_list as i128
+    }
+
+    /// Returns `true` if the access list is non-empty and saves at least `min_gas_saved` gas.
+    ///
+    /// An access list that saves no gas at all is never included.
+    pub fn should_include(&self, min_gas_saved: u64) -> bool {
+        !self.access_list.0.is_empty()
+            && self.gas_delta() > 0
+            && self.gas_delta() >= min_gas_saved as i128
+    }
+
+    /// Returns the gas limit matching the decision of [`should_include`](Self::should_include).
+    pub fn gas_limit(&self, min_gas_saved: u64) -> u64 {
+        if self.should_include(min_gas_saved) {
+            self.gas_with_access_list
+        } else {
+            self.gas_without_access_list
+        }
+    }
+
+    /// Sets the gas limit of the transaction request, and the access list if it should be
+    /// included.
+    ///
+    /// Returns `true` if the access list was set.
+    pub fn apply<N: Network>(self, tx: &mut N::TransactionRequest, min_gas_saved: u64) -> bool {
+        let include = self.should_include(min_gas_saved);
+        tx.set_gas_limit(self.gas_limit(min_gas_saved));
+        if include {
+            tx.set_access_list(self.access_list);
+        }
+        include
+    }
+}
+
+/// A [`TxFiller`] that attaches the access list generated by `eth_createAccessList` to
+/// transactions if it lowers their gas.
+///
+/// The filler estimates the gas of the transaction with and without the access list, see
+/// [`Provider::estimate_access_list`], and sets the gas limit to the estimate matching its
+/// decision. Transactions that already have an access list or a gas limit are not modified.
+///
+/// As the [`GasFiller`] overrides the gas limit, this filler must be joined after it so that the
+/// lower gas limit of transactions with an access list is kept.
+///
+/// [`GasFiller`]: crate::fillers::GasFiller
+///
+/// # Example
+///
+/// ```
+/// # use alloy_network::{NetworkWallet, EthereumWallet, Ethereum};
+/// # use alloy_rpc_types_eth::TransactionRequest;
+/// # use alloy_provider::{fillers::AccessListFiller, ProviderBuilder, RootProvider, Provider};
+/// # async fn test<W: NetworkWallet<Ethereum> + Clone>(url: url::Url, wallet: W) -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = ProviderBuilder::default()
+///     .with_gas_estimation()
+///     .filler(AccessListFiller::default())
+///     .wallet(wallet)
+///     .on_http(url);
+///
+/// provider.send_transaction(TransactionRequest::default()).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AccessListFiller {
+    min_gas_saved: u64,
+}
+
+impl AccessListFiller {
+    /// Creates a new filler that only attaches access lists saving at least `min_gas_saved` gas.
+    pub const fn new(min_gas_saved: u64) -> Self {
+        Self { min_gas_saved }
+    }
+
+    /// Returns the minimum amount of gas an access list must save to be attached.
+    pub const fn min_gas_saved(&self) -> u64 {
+        self.min_gas_saved
+    }
+}
+
+impl<N: Network> TxFiller<N> for AccessListFiller {
+    type Fillable = AccessListEstimate;
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        if tx.access_list().is_some() || tx.gas_limit().is_some() {
+            FillerControlFlow::Finished
+        } else {
+            FillerControlFlow::Ready
+        }
+    }
+
+    fn fill_sync(&self, _tx: &mut SendableTx<N>) {}
+
+    async fn prepare<P>(
+        &self,
+        provider: &P,
+        tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: Provider<N>,
+    {
+        provider.estimate_access_list(tx).await
+    }
+
+    async fn fill(
+        &self,
+        fillable: Self::Fillable,
+        mut tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        if let Some(builder) = tx.as_mut_builder() {
+            fillable.apply::<N>(builder, self.min_gas_saved);
+        }
+        Ok(tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip2930::AccessListItem;
+    use alloy_network::Ethereum;
+    use alloy_primitives::Address;
+    use alloy_rpc_types_eth::TransactionRequest;
+
+    fn estimate(with: u64, without: u64) -> AccessListEstimate {
+        AccessListEstimate {
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::ZERO,
+                storage_keys: vec![Default::default()],
+            }]),
+            gas_with_access_list: with,
+            gas_without_access_list: without,
+        }
+    }
+
+    #[test]
+    fn decision() {
+        assert_eq!(estimate(50_000, 52_000).gas_delta(), 2_000);
+        assert!(estimate(50_000, 52_000).should_include(0));
+        assert!(estimate(50_000, 52_000).should_include(2_000));
+        assert!(!estimate(50_000, 52_000).should_include(2_001));
+        assert!(!estimate(52_000, 52_000).should_include(0));
+        assert!(!estimate(53_000, 52_000).should_include(0));
+        assert_eq!(estimate(53_000, 52_000).gas_delta(), -1_000);
+
+        let empty = AccessListEstimate { access_list: AccessList::default(), ..estimate(1, 2) };
+        assert!(!empty.should_include(0));
+    }
+
+    #[test]
+    fn apply() {
+        let mut tx = TransactionRequest::default();
+        assert!(estimate(50_000, 52_000).apply::<Ethereum>(&mut tx, 0));
+        assert_eq!(tx.gas, Some(50_000));
+        assert!(tx.access_list.is_some());
+
+        let mut tx = TransactionRequest::default();
+        assert!(!estimate(53_000, 52_000).apply::<Ethereum>(&mut tx, 0));
+        assert_eq!(tx.gas, Some(52_000));
+        assert!(tx.access_list.is_none());
+    }
+}
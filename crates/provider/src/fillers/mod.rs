@@ -6,6 +6,9 @@
 //!
 //! [`Provider`]: crate::Provider
 
+mod access_list;
+pub use access_list::{AccessListEstimate, AccessListFiller};
+
 mod chain_id;
 pub use chain_id::ChainIdFiller;
 
@@ -0,0 +1,354 @@
+//! Parallel downloads of block header ranges.
+//!
+//! [`HeaderFetcher`] is the building block for historical backfills: it downloads a range of
+//! headers in batched `eth_getBlockByNumber` requests, several batches at a time, and yields the
+//! headers in order after verifying that each one links to its parent. Interrupted downloads can
+//! be resumed from a [`HeaderCheckpoint`].
+
+use crate::Provider;
+use alloy_eips::BlockNumberOrTag;
+use alloy_network::{Ethereum, Network};
+use alloy_network_primitives::HeaderResponse;
+use alloy_primitives::{BlockHash, BlockNumber};
+use alloy_rpc_client::BatchRequest;
+use alloy_transport::{
+    layers::{RateLimitRetryPolicy, RetryPolicy},
+    TransportError, TransportResult,
+};
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{fmt, marker::PhantomData, ops::RangeInclusive, time::Duration};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+
+/// The last header yielded by a [`HeaderFetcher`], from which an interrupted download can be
+/// resumed with [`HeaderFetcher::resume_from`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HeaderCheckpoint {
+    /// The number of the header.
+    pub number: BlockNumber,
+    /// The hash of the header.
+    pub hash: BlockHash,
+}
+
+impl HeaderCheckpoint {
+    /// Creates a new checkpoint.
+    pub const fn new(number: BlockNumber, hash: BlockHash) -> Self {
+        Self { number, hash }
+    }
+
+    /// Creates a checkpoint at the given header.
+    pub fn from_header<H: HeaderResponse>(header: &H) -> Self {
+        Self::new(header.number(), header.hash())
+    }
+
+    /// Returns an error if `header` is not the child of the checkpoint.
+    fn verify_child<H: HeaderResponse>(&self, header: &H) -> Result<(), HeaderFetchError> {
+        if header.number() != self.number + 1 || header.parent_hash() != self.hash {
+            return Err(HeaderFetchError::Unlinked {
+                number: header.number(),
+                parent_hash: header.parent_hash(),
+                expected: self.hash,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// An error returned by a [`HeaderFetcher`].
+#[derive(Debug, thiserror::Error)]
+pub enum HeaderFetchError {
+    /// The request for a batch of headers failed after exhausting all retries.
+    #[error(transparent)]
+    Transport(#[from] TransportError),
+    /// The node returned no header for the given block number.
+    #[error("header {0} not found")]
+    NotFound(BlockNumber),
+    /// A header does not link to the header before it, e.g. because the chain was reorged
+    /// during the download.
+    #[error("header {number} has parent hash {parent_hash}, expected {expected}")]
+    Unlinked {
+        /// The number of the header.
+        number: BlockNumber,
+        /// The parent hash of the header.
+        parent_hash: BlockHash,
+        /// The hash of the header before it.
+        expected: BlockHash,
+    },
+}
+
+/// Downloads a range of block headers in parallel batches.
+///
+/// The range is split into batches of [`batch_size`](Self::with_batch_size) headers, which are
+/// each fetched with a single JSON-RPC batch request, and up to
+/// [`concurrency`](Self::with_concurrency) batches are downloaded at the same time. Headers are
+/// yielded in ascending order, and each header is verified to link to the header before it.
+///
+/// Failed batches are retried according to the [retry policy](Self::with_retry_policy), with
+/// exponential backoff or the backoff hinted by the error. Once the retries are exhausted, or if
+/// a header is missing or unlinked, the error is yielded and the stream ends.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_provider::headers::{HeaderCheckpoint, HeaderFetcher};
+/// use futures::StreamExt;
+///
+/// # let saved: Option<HeaderCheckpoint> = None;
+/// let mut fetcher = HeaderFetcher::new(&provider, 0..=1_000_000).with_concurrency(8);
+/// if let Some(checkpoint) = saved {
+///     fetcher = fetcher.resume_from(checkpoint);
+/// }
+/// let headers = fetcher.into_stream();
+/// futures::pin_mut!(headers);
+/// while let Some(header) = headers.next().await {
+///     let header = header?;
+///     // Persist the checkpoint to resume from it later.
+///     let checkpoint = HeaderCheckpoint::from_header(&header);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct HeaderFetcher<P, N = Ethereum> {
+    provider: P,
+    range: RangeInclusive<BlockNumber>,
+    checkpoint: Option<HeaderCheckpoint>,
+    batch_size: usize,
+    concurrency: usize,
+    policy: Box<dyn RetryPolicy>,
+    max_retries: u32,
+    initial_backoff: Duration,
+    _network: PhantomData<N>,
+}
+
+impl<P, N> fmt::Debug for HeaderFetcher<P, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeaderFetcher")
+            .field("range", &self.range)
+            .field("checkpoint", &self.checkpoint)
+            .field("batch_size", &self.batch_size)
+            .field("concurrency", &self.concurrency)
+            .field("policy", &self.policy)
+            .field("max_retries", &self.max_retries)
+            .field("initial_backoff", &self.initial_backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P, N> HeaderFetcher<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new fetcher for the headers in the given range.
+    ///
+    /// By default, batches contain 100 headers, 4 batches are downloaded at the same time, and
+    /// requests that fail with a rate limit or another retryable error are retried up to 3 times,
+    /// with an initial backoff of 500ms.
+    pub fn new(provider: P, range: RangeInclusive<BlockNumber>) -> Self {
+        Self {
+            provider,
+            range,
+            checkpoint: None,
+            batch_size: 100,
+            concurrency: 4,
+            policy: Box::new(RateLimitRetryPolicy::default()),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            _network: PhantomData,
+        }
+    }
+
+    /// Resumes an interrupted download after the given checkpoint.
+    ///
+    /// The download starts at the header after the checkpoint, which must link to it.
+    pub const fn resume_from(mut self, checkpoint: HeaderCheckpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Sets the number of headers fetched in a single batch request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub const fn with_batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch size must be non-zero");
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Sets the maximum number of batches downloaded at the same time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `concurrency` is zero.
+    pub const fn with_concurrency(mut self, concurrency: usize) -> Self {
+        assert!(concurrency > 0, "concurrency must be non-zero");
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets the policy that decides which errors are retried.
+    pub fn with_retry_policy(mut self, policy: impl RetryPolicy + 'static) -> Self {
+        self.policy = Box::new(policy);
+        self
+    }
+
+    /// Sets the maximum number of retries of a single batch. Zero disables retries.
+    pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the backoff before the first retry of a batch, which doubles with each retry.
+    ///
+    /// Backoffs hinted by the error take precedence.
+    pub const fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Returns the block numbers of the batches to download.
+    fn batches(&self) -> impl Iterator<Item = RangeInclusive<BlockNumber>> {
+        let start = match self.checkpoint {
+            Some(checkpoint) => (*self.range.start()).max(checkpoint.number + 1),
+            None => *self.range.start(),
+        };
+        let end = *self.range.end();
+        let batch_size = self.batch_size as u64;
+        (start..=end)
+            .step_by(self.batch_size)
+            .map(move |first| first..=first.saturating_add(batch_size - 1).min(end))
+    }
+
+    /// Fetches the headers of a single batch, retrying failed requests.
+    async fn fetch(
+        &self,
+        numbers: RangeInclusive<BlockNumber>,
+    ) -> TransportResult<Vec<(BlockNumber, Option<N::HeaderResponse>)>> {
+        let mut retries = 0;
+        let headers = loop {
+            match self.fetch_once(numbers.clone()).await {
+                Ok(headers) => break headers,
+                Err(err) if retries < self.max_retries && self.policy.should_retry(&err) => {
+                    let backoff = self.policy.backoff_hint(&err).unwrap_or_else(|| {
+                        self.initial_backoff.saturating_mul(1 << retries.min(16))
+                    });
+                    retries += 1;
+                    debug!(%err, retries, ?backoff, ?numbers, "retrying header batch");
+                    sleep(backoff).await;
+                }
+                Err(err) => return Err(err),
+            }
+        };
+        Ok(numbers.zip(headers).collect())
+    }
+
+    async fn fetch_once(
+        &self,
+        numbers: RangeInclusive<BlockNumber>,
+    ) -> TransportResult<Vec<Option<N::HeaderResponse>>> {
+        let mut batch = BatchRequest::new(self.provider.client());
+        // Blocks without transactions deserialize into their headers.
+        let waiters = numbers
+            .map(|number| {
+                let params = (BlockNumberOrTag::Number(number), false);
+                batch.add_call::<_, Option<N::HeaderResponse>>("eth_getBlockByNumber", &params)
+            })
+            .collect::<TransportResult<Vec<_>>>()?;
+        batch.send().await?;
+        futures::future::try_join_all(waiters).await
+    }
+
+    /// Returns a stream over the headers, in ascending order.
+    pub fn into_stream(self) -> impl Stream<Item = Result<N::HeaderResponse, HeaderFetchError>> {
+        stream! {
+            let mut parent = self.checkpoint;
+            let batches = futures::stream::iter(self.batches())
+                .map(|numbers| self.fetch(numbers))
+                .buffered(self.concurrency);
+            for await batch in batches {
+                let headers = match batch {
+                    Ok(headers) => headers,
+                    Err(err) => {
+                        yield Err(err.into());
+                        return;
+                    }
+                };
+                for (number, header) in headers {
+                    let Some(header) = header else {
+                        yield Err(HeaderFetchError::NotFound(number));
+                        return;
+                    };
+                    if let Some(Err(err)) = parent.map(|parent| parent.verify_child(&header)) {
+                        yield Err(err);
+                        return;
+                    }
+                    parent = Some(HeaderCheckpoint::from_header(&header));
+                    yield Ok(header);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "anvil-api"))]
+mod tests {
+    use super::*;
+    use crate::{ext::AnvilApi, ProviderBuilder};
+    use alloy_primitives::B256;
+
+    #[tokio::test]
+    async fn fetches_linked_headers() {
+        let provider = ProviderBuilder::new().on_anvil();
+        provider.anvil_mine(Some(25), None).await.unwrap();
+
+        let headers = HeaderFetcher::new(&provider, 0..=25)
+            .with_batch_size(4)
+            .with_concurrency(3)
+            .into_stream()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            headers.iter().map(|h| h.number).collect::<Vec<_>>(),
+            (0..=25).collect::<Vec<_>>()
+        );
+
+        // Resume after block 10.
+        let checkpoint = HeaderCheckpoint::from_header(&headers[10]);
+        let resumed = HeaderFetcher::new(&provider, 0..=25)
+            .resume_from(checkpoint)
+            .into_stream()
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(resumed.len(), 15);
+        assert_eq!(resumed[0].as_ref().unwrap().number, 11);
+
+        // A checkpoint of another chain does not link.
+        let checkpoint = HeaderCheckpoint::new(10, B256::repeat_byte(1));
+        let resumed = HeaderFetcher::new(&provider, 0..=25).resume_from(checkpoint).into_stream();
+        futures::pin_mut!(resumed);
+        let err = resumed.next().await.unwrap().unwrap_err();
+        assert!(matches!(err, HeaderFetchError::Unlinked { number: 11, .. }));
+        assert!(resumed.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn missing_headers() {
+        let provider = ProviderBuilder::new().on_anvil();
+        let headers = HeaderFetcher::new(&provider, 0..=2).into_stream().collect::<Vec<_>>().await;
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers[0].as_ref().unwrap().number, 0);
+        assert!(matches!(headers[1], Err(HeaderFetchError::NotFound(1))));
+    }
+}
@@ -1,8 +1,7 @@
 //! Block heartbeat and pending transaction watcher.
 
-use crate::{Provider, RootProvider};
+use crate::{NotFound, Provider, RootProvider};
 use alloy_consensus::BlockHeader;
-use alloy_json_rpc::RpcError;
 use alloy_network::{BlockResponse, Network};
 use alloy_primitives::{
     map::{B256HashMap, B256HashSet},
@@ -10,10 +9,12 @@ use alloy_primitives::{
 };
 use alloy_transport::{utils::Spawnable, TransportError};
 use futures::{stream::StreamExt, FutureExt, Stream};
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, VecDeque},
     fmt,
     future::Future,
+    sync::Arc,
     time::Duration,
 };
 use tokio::{
@@ -235,7 +236,7 @@ impl<N: Network> PendingTransactionBuilder<N> {
             }
 
             if confirmed {
-                return Err(RpcError::NullResp.into());
+                return Err(TransportError::from(NotFound::Receipt(hash)).into());
             }
         }
     }
@@ -245,8 +246,12 @@ impl<N: Network> PendingTransactionBuilder<N> {
 ///
 /// This type can be used to create a [`PendingTransactionBuilder`], but in general it is only used
 /// internally.
+///
+/// The configuration is serializable so that it can be persisted, e.g. by a
+/// [`PendingTransactionStore`], and used to resume watching after a restart.
 #[must_use = "this type does nothing unless you call `with_provider`"]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 #[doc(alias = "PendingTxConfig", alias = "TxPendingConfig")]
 pub struct PendingTransactionConfig {
     /// The transaction hash to watch for.
@@ -335,6 +340,53 @@ pub enum WatchTxError {
     /// Transaction was not confirmed after configured timeout.
     #[error("transaction was not confirmed within the timeout")]
     Timeout,
+
+    /// Watching the transaction was cancelled.
+    #[error("watching the transaction was cancelled")]
+    Cancelled,
+}
+
+/// Persistence hooks for the pending transaction watcher.
+///
+/// The heartbeat calls [`on_watch`](Self::on_watch) whenever it starts watching a transaction and
+/// [`on_finish`](Self::on_finish) once the watcher completes, times out, or is cancelled. An
+/// implementation can persist the [`PendingTransactionConfig`]s of the transactions that are
+/// still being watched, so that a restarted process can resume watching them using
+/// [`RootProvider::resume_pending_transactions`].
+///
+/// Hooks are called from the heartbeat task and should not block.
+pub trait PendingTransactionStore: fmt::Debug + Send + Sync + 'static {
+    /// Called when the heartbeat starts watching a transaction.
+    fn on_watch(&self, config: &PendingTransactionConfig);
+
+    /// Called when the heartbeat stops watching a transaction.
+    fn on_finish(&self, tx_hash: &TxHash, result: &Result<(), WatchTxError>);
+}
+
+/// A snapshot of a transaction being watched by the heartbeat.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct WatchedTransaction {
+    /// The watching configuration.
+    pub config: PendingTransactionConfig,
+    /// The time elapsed since the transaction was registered with the heartbeat.
+    pub age: Duration,
+    /// The block at which the transaction was included, if known.
+    pub received_at_block: Option<u64>,
+    /// The latest block that was checked for this transaction, if any.
+    pub last_checked_block: Option<u64>,
+}
+
+impl WatchedTransaction {
+    /// Returns the transaction hash.
+    pub const fn tx_hash(&self) -> &TxHash {
+        self.config.tx_hash()
+    }
+
+    /// Returns `true` if the transaction was included and is waiting for confirmations.
+    pub const fn is_included(&self) -> bool {
+        self.received_at_block.is_some()
+    }
 }
 
 #[doc(alias = "TransactionWatcher")]
@@ -343,6 +395,12 @@ struct TxWatcher {
     /// The block at which the transaction was received. To be filled once known.
     /// Invariant: any confirmed transaction in `Heart` has this value set.
     received_at_block: Option<u64>,
+    /// The latest block checked for this transaction.
+    last_checked_block: Option<u64>,
+    /// The time at which the watcher was registered.
+    registered_at: Instant,
+    /// The persistence hooks, if any.
+    store: Option<Arc<dyn PendingTransactionStore>>,
     tx: oneshot::Sender<Result<(), WatchTxError>>,
 }
 
@@ -350,8 +408,31 @@ impl TxWatcher {
     /// Notify the waiter.
     fn notify(self, result: Result<(), WatchTxError>) {
         debug!(tx=%self.config.tx_hash, "notifying");
+        if let Some(store) = &self.store {
+            store.on_finish(&self.config.tx_hash, &result);
+        }
         let _ = self.tx.send(result);
     }
+
+    /// Take a snapshot of this watcher.
+    fn snapshot(&self, now: Instant) -> WatchedTransaction {
+        WatchedTransaction {
+            config: self.config.clone(),
+            age: now.saturating_duration_since(self.registered_at),
+            received_at_block: self.received_at_block,
+            last_checked_block: self.last_checked_block,
+        }
+    }
+}
+
+/// Instructions for the heartbeat task.
+enum HeartbeatInstruction {
+    /// Start watching a transaction.
+    Watch(TxWatcher),
+    /// List the transactions being watched.
+    List(oneshot::Sender<Vec<WatchedTransaction>>),
+    /// Stop watching a transaction, replying whether it was being watched.
+    Cancel(TxHash, oneshot::Sender<bool>),
 }
 
 /// Represents a transaction that is yet to be confirmed a specified number of times.
@@ -407,7 +488,7 @@ impl Future for PendingTransaction {
 /// A handle to the heartbeat task.
 #[derive(Clone, Debug)]
 pub(crate) struct HeartbeatHandle<N: Network> {
-    tx: mpsc::Sender<TxWatcher>,
+    tx: mpsc::Sender<HeartbeatInstruction>,
     latest: watch::Receiver<Option<N::BlockResponse>>,
 }
 
@@ -418,13 +499,40 @@ impl<N: Network> HeartbeatHandle<N> {
         &self,
         config: PendingTransactionConfig,
         received_at_block: Option<u64>,
+        store: Option<Arc<dyn PendingTransactionStore>>,
     ) -> Result<PendingTransaction, PendingTransactionConfig> {
+        let Ok(permit) = self.tx.reserve().await else { return Err(config) };
         let (tx, rx) = oneshot::channel();
         let tx_hash = config.tx_hash;
-        match self.tx.send(TxWatcher { config, received_at_block, tx }).await {
-            Ok(()) => Ok(PendingTransaction { tx_hash, rx }),
-            Err(e) => Err(e.0.config),
-        }
+        permit.send(HeartbeatInstruction::Watch(TxWatcher {
+            config,
+            received_at_block,
+            last_checked_block: None,
+            registered_at: Instant::now(),
+            store,
+            tx,
+        }));
+        Ok(PendingTransaction { tx_hash, rx })
+    }
+
+    /// Returns a snapshot of all transactions being watched.
+    pub(crate) async fn watched_txs(
+        &self,
+    ) -> Result<Vec<WatchedTransaction>, PendingTransactionError> {
+        let (tx, rx) = oneshot::channel();
+        // If the heartbeat is gone, the reply sender is dropped with the instruction.
+        let _ = self.tx.send(HeartbeatInstruction::List(tx)).await;
+        Ok(rx.await?)
+    }
+
+    /// Stops watching a transaction, notifying its waiter with [`WatchTxError::Cancelled`].
+    ///
+    /// Returns `true` if the transaction was being watched.
+    pub(crate) async fn cancel_tx(&self, tx_hash: TxHash) -> Result<bool, PendingTransactionError> {
+        let (tx, rx) = oneshot::channel();
+        // If the heartbeat is gone, the reply sender is dropped with the instruction.
+        let _ = self.tx.send(HeartbeatInstruction::Cancel(tx_hash, tx)).await;
+        Ok(rx.await?)
     }
 
     /// Returns a watcher that always sees the latest block.
@@ -520,12 +628,55 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
         }
     }
 
+    /// Handle an instruction from a [`HeartbeatHandle`].
+    fn handle_ix(&mut self, ix: HeartbeatInstruction) {
+        match ix {
+            HeartbeatInstruction::Watch(to_watch) => self.handle_watch_ix(to_watch),
+            HeartbeatInstruction::List(tx) => {
+                let _ = tx.send(self.watched_txs());
+            }
+            HeartbeatInstruction::Cancel(tx_hash, tx) => {
+                let _ = tx.send(self.cancel(tx_hash));
+            }
+        }
+    }
+
+    /// Returns a snapshot of all transactions being watched.
+    fn watched_txs(&self) -> Vec<WatchedTransaction> {
+        let now = Instant::now();
+        self.unconfirmed
+            .values()
+            .chain(self.waiting_confs.values().flatten())
+            .map(|watcher| watcher.snapshot(now))
+            .collect()
+    }
+
+    /// Stop watching a transaction. Returns `true` if the transaction was being watched.
+    fn cancel(&mut self, tx_hash: TxHash) -> bool {
+        let watcher = self.unconfirmed.remove(&tx_hash).or_else(|| {
+            self.waiting_confs.values_mut().find_map(|waiters| {
+                let idx = waiters.iter().position(|w| w.config.tx_hash == tx_hash)?;
+                Some(waiters.remove(idx))
+            })
+        });
+        self.waiting_confs.retain(|_, waiters| !waiters.is_empty());
+        self.reap_at.retain(|_, hash| *hash != tx_hash);
+
+        let Some(watcher) = watcher else { return false };
+        debug!(tx=%tx_hash, "cancelled");
+        watcher.notify(Err(WatchTxError::Cancelled));
+        true
+    }
+
     /// Handle a watch instruction by adding it to the watch list, and
     /// potentially adding it to our `reap_at` list.
     fn handle_watch_ix(&mut self, to_watch: TxWatcher) {
         // Start watching for the transaction.
         debug!(tx=%to_watch.config.tx_hash, "watching");
         trace!(?to_watch.config, ?to_watch.received_at_block);
+        if let Some(store) = &to_watch.store {
+            store.on_watch(&to_watch.config);
+        }
         if let Some(received_at_block) = to_watch.received_at_block {
             // Transaction is already confirmed, we just need to wait for the required
             // confirmations.
@@ -598,6 +749,13 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
         }
         self.past_blocks.push_back((block_height, block.transactions().hashes().collect()));
 
+        // Every watcher is checked against this block.
+        for watcher in
+            self.unconfirmed.values_mut().chain(self.waiting_confs.values_mut().flatten())
+        {
+            watcher.last_checked_block = Some(block_height);
+        }
+
         // Check if we are watching for any of the transactions in this block.
         let to_check: Vec<_> = block
             .transactions()
@@ -663,7 +821,7 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
     async fn into_future(
         mut self,
         latest: watch::Sender<Option<N::BlockResponse>>,
-        mut ixns: mpsc::Receiver<TxWatcher>,
+        mut ixns: mpsc::Receiver<HeartbeatInstruction>,
     ) {
         'shutdown: loop {
             {
@@ -677,7 +835,7 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
 
                     // Watch for new transactions.
                     ix_opt = ixns.recv() => match ix_opt {
-                        Some(to_watch) => self.handle_watch_ix(to_watch),
+                        Some(ix) => self.handle_ix(ix),
                         None => break 'shutdown, // ix channel is closed
                     },
 
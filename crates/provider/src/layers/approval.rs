@@ -0,0 +1,457 @@
+use crate::{PendingTransactionBuilder, Provider, ProviderLayer, RootProvider, SendableTx};
+use alloy_dyn_abi::DynSolValue;
+use alloy_json_abi::{Function, JsonAbi};
+use alloy_json_rpc::RpcError;
+use alloy_network::{
+    eip2718::{Decodable2718, Encodable2718},
+    Network, NetworkWallet, TransactionBuilder,
+};
+use alloy_pretty::Abis;
+use alloy_primitives::{Address, ChainId, Selector, TxKind, U256};
+use alloy_transport::{TransportError, TransportErrorKind, TransportResult};
+use std::{fmt, future::Future, marker::PhantomData, sync::Arc};
+
+/// A normalized summary of a state-changing transaction, passed to an [`ApprovalHook`].
+///
+/// Fields the transaction does not set are `None`. The sender of a signed transaction is only
+/// known if it can be recovered from the signature.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct TransactionSummary {
+    /// The sender.
+    pub from: Option<Address>,
+    /// The recipient, or [`TxKind::Create`] for a contract deployment.
+    pub to: Option<TxKind>,
+    /// The function selector, i.e. the first four bytes of the calldata.
+    pub selector: Option<Selector>,
+    /// The decoded call, if the ABI of the recipient or a global ABI matches the selector.
+    pub call: Option<DecodedCall>,
+    /// The value transferred.
+    pub value: U256,
+    /// The nonce.
+    pub nonce: Option<u64>,
+    /// The chain id.
+    pub chain_id: Option<ChainId>,
+    /// The gas limit.
+    pub gas_limit: Option<u64>,
+    /// The gas price of a legacy or EIP-2930 transaction.
+    pub gas_price: Option<u128>,
+    /// The max fee per gas of a dynamic fee transaction.
+    pub max_fee_per_gas: Option<u128>,
+    /// The max priority fee per gas of a dynamic fee transaction.
+    pub max_priority_fee_per_gas: Option<u128>,
+}
+
+impl TransactionSummary {
+    /// Returns the maximum amount of wei the transaction may spend on fees, if the gas limit and
+    /// price are known.
+    pub fn max_fee(&self) -> Option<U256> {
+        let gas_price = self.max_fee_per_gas.or(self.gas_price)?;
+        Some(U256::from(self.gas_limit?) * U256::from(gas_price))
+    }
+}
+
+/// A call decoded with a known ABI.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedCall {
+    /// The called function.
+    pub function: Function,
+    /// The decoded arguments.
+    pub args: Vec<DynSolValue>,
+}
+
+/// The decision of an [`ApprovalHook`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Approval {
+    /// The transaction may be sent.
+    Approved,
+    /// The transaction must not be sent, for the given reason.
+    Rejected(String),
+}
+
+/// An async hook that approves or rejects state-changing transactions, e.g. by prompting the user
+/// or evaluating a policy.
+///
+/// Implemented for closures taking a [`TransactionSummary`] and returning a future of
+/// [`Approval`].
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait ApprovalHook: Send + Sync {
+    /// Decides whether the summarized transaction may be sent.
+    async fn approve(&self, summary: &TransactionSummary) -> Approval;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<F, Fut> ApprovalHook for F
+where
+    F: Fn(TransactionSummary) -> Fut + Send + Sync,
+    Fut: Future<Output = Approval> + Send,
+{
+    async fn approve(&self, summary: &TransactionSummary) -> Approval {
+        self(summary.clone()).await
+    }
+}
+
+/// Error returned when an [`ApprovalHook`] rejects a transaction.
+///
+/// Converts into a [`TransportError`] when returned by the [`ApprovalProvider`], use
+/// [`TransactionRejected::from_transport_error`] to recover it.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[error("transaction rejected: {reason}")]
+pub struct TransactionRejected {
+    /// The reason given by the hook.
+    pub reason: String,
+    /// The summary of the rejected transaction.
+    pub summary: TransactionSummary,
+}
+
+impl TransactionRejected {
+    /// Returns the [`TransactionRejected`] error contained in the transport error, if any.
+    pub fn from_transport_error(err: &TransportError) -> Option<&Self> {
+        match err {
+            RpcError::Transport(TransportErrorKind::Custom(err)) => err.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<TransactionRejected> for TransportError {
+    fn from(err: TransactionRejected) -> Self {
+        TransportErrorKind::custom(err)
+    }
+}
+
+/// A layer that passes every state-changing transaction through an [`ApprovalHook`] before it is
+/// sent.
+///
+/// The [`ApprovalProvider`] intercepts [`send_transaction`], [`send_tx_envelope`] and
+/// [`send_raw_transaction`]. Transactions signed locally by a wallet filler reach the layer as
+/// signed envelopes, so to approve them before they are signed, wrap the wallet with
+/// [`wallet`](Self::wallet) instead. Using both approves such transactions twice.
+///
+/// ABIs registered with [`with_abi`](Self::with_abi) and [`with_global_abi`](Self::with_global_abi)
+/// are used to decode the calldata in the [`TransactionSummary`].
+///
+/// [`send_transaction`]: Provider::send_transaction
+/// [`send_tx_envelope`]: Provider::send_tx_envelope
+/// [`send_raw_transaction`]: Provider::send_raw_transaction
+#[derive(Clone)]
+pub struct ApprovalLayer {
+    hook: Arc<dyn ApprovalHook>,
+    abis: Arc<Abis>,
+}
+
+impl fmt::Debug for ApprovalLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApprovalLayer").field("abis", &self.abis).finish_non_exhaustive()
+    }
+}
+
+impl ApprovalLayer {
+    /// Creates a new layer with the given hook.
+    pub fn new(hook: impl ApprovalHook + 'static) -> Self {
+        Self { hook: Arc::new(hook), abis: Default::default() }
+    }
+
+    /// Registers the ABI of the contract at `address`.
+    pub fn with_abi(mut self, address: Address, abi: JsonAbi) -> Self {
+        Arc::make_mut(&mut self.abis).insert(address, abi);
+        self
+    }
+
+    /// Registers an ABI used to decode calls to any contract, e.g. a token standard.
+    pub fn with_global_abi(mut self, abi: JsonAbi) -> Self {
+        Arc::make_mut(&mut self.abis).push_global(abi);
+        self
+    }
+
+    /// Wraps the wallet so that every transaction it signs is approved first.
+    pub fn wallet<W>(&self, wallet: W) -> ApprovalWallet<W> {
+        ApprovalWallet { inner: wallet, layer: self.clone() }
+    }
+
+    /// Summarizes the transaction request.
+    pub fn summarize<N: Network>(&self, tx: &N::TransactionRequest) -> TransactionSummary {
+        let input = tx.input().map(|input| &input[..]).unwrap_or_default();
+        let selector = input.get(..4).and_then(|selector| Selector::try_from(selector).ok());
+        let call = self
+            .abis
+            .decode_call(tx.to(), input)
+            .map(|(function, args)| DecodedCall { function: function.clone(), args });
+        TransactionSummary {
+            from: tx.from(),
+            to: tx.kind(),
+            selector,
+            call,
+            value: tx.value().unwrap_or_default(),
+            nonce: tx.nonce(),
+            chain_id: tx.chain_id(),
+            gas_limit: tx.gas_limit(),
+            gas_price: tx.gas_price(),
+            max_fee_per_gas: tx.max_fee_per_gas(),
+            max_priority_fee_per_gas: tx.max_priority_fee_per_gas(),
+        }
+    }
+
+    /// Passes the summary to the hook, returning an error if it rejects the transaction.
+    pub async fn approve(&self, summary: TransactionSummary) -> Result<(), TransactionRejected> {
+        match self.hook.approve(&summary).await {
+            Approval::Approved => Ok(()),
+            Approval::Rejected(reason) => Err(TransactionRejected { reason, summary }),
+        }
+    }
+
+    /// Summarizes the transaction request and passes it to the hook.
+    pub async fn approve_request<N: Network>(
+        &self,
+        tx: &N::TransactionRequest,
+    ) -> Result<(), TransactionRejected> {
+        self.approve(self.summarize::<N>(tx)).await
+    }
+}
+
+impl<P, N> ProviderLayer<P, N> for ApprovalLayer
+where
+    P: Provider<N>,
+    N: Network,
+{
+    type Provider = ApprovalProvider<P, N>;
+
+    fn layer(&self, inner: P) -> Self::Provider {
+        ApprovalProvider { inner, layer: self.clone(), _pd: PhantomData }
+    }
+}
+
+/// A provider that approves state-changing transactions before sending them, see
+/// [`ApprovalLayer`].
+#[derive(Clone, Debug)]
+pub struct ApprovalProvider<P, N> {
+    inner: P,
+    layer: ApprovalLayer,
+    _pd: PhantomData<N>,
+}
+
+impl<P, N> ApprovalProvider<P, N> {
+    /// Returns the approval layer of the provider.
+    pub const fn layer(&self) -> &ApprovalLayer {
+        &self.layer
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<P, N> Provider<N> for ApprovalProvider<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    #[inline(always)]
+    fn root(&self) -> &RootProvider<N> {
+        self.inner.root()
+    }
+
+    async fn send_raw_transaction(
+        &self,
+        encoded_tx: &[u8],
+    ) -> TransportResult<PendingTransactionBuilder<N>> {
+        let tx = N::TxEnvelope::decode_2718(&mut &encoded_tx[..]).map_err(|_| {
+            TransportError::local_usage_str("raw transaction could not be decoded for approval")
+        })?;
+        self.layer.approve_request::<N>(&tx.into()).await?;
+        self.inner.send_raw_transaction(encoded_tx).await
+    }
+
+    async fn send_transaction_internal(
+        &self,
+        tx: SendableTx<N>,
+    ) -> TransportResult<PendingTransactionBuilder<N>> {
+        match &tx {
+            SendableTx::Builder(request) => self.layer.approve_request::<N>(request).await?,
+            SendableTx::Envelope(envelope) => {
+                // envelopes are not `Clone`, so the request is built from a decoded copy
+                let copy = N::TxEnvelope::decode_2718(&mut envelope.encoded_2718().as_slice())
+                    .map_err(|_| {
+                        TransportError::local_usage_str(
+                            "transaction envelope could not be decoded for approval",
+                        )
+                    })?;
+                self.layer.approve_request::<N>(&copy.into()).await?
+            }
+        }
+        self.inner.send_transaction_internal(tx).await
+    }
+}
+
+/// A [`NetworkWallet`] that approves every transaction before signing it, see
+/// [`ApprovalLayer::wallet`].
+#[derive(Clone, Debug)]
+pub struct ApprovalWallet<W> {
+    inner: W,
+    layer: ApprovalLayer,
+}
+
+impl<W> ApprovalWallet<W> {
+    /// Returns the wrapped wallet.
+    pub const fn inner(&self) -> &W {
+        &self.inner
+    }
+}
+
+impl<W, N> NetworkWallet<N> for ApprovalWallet<W>
+where
+    W: NetworkWallet<N>,
+    N: Network,
+    N::UnsignedTx: Clone + Send,
+{
+    fn default_signer_address(&self) -> Address {
+        self.inner.default_signer_address()
+    }
+
+    fn has_signer_for(&self, address: &Address) -> bool {
+        self.inner.has_signer_for(address)
+    }
+
+    fn signer_addresses(&self) -> impl Iterator<Item = Address> {
+        self.inner.signer_addresses()
+    }
+
+    async fn sign_transaction_from(
+        &self,
+        sender: Address,
+        tx: N::UnsignedTx,
+    ) -> alloy_signer::Result<N::TxEnvelope> {
+        // the summary is built from a request converted from a copy, the original is signed
+        let request =
+            <N::TransactionRequest as From<N::UnsignedTx>>::from(tx.clone()).with_from(sender);
+        self.layer.approve_request::<N>(&request).await.map_err(alloy_signer::Error::other)?;
+        self.inner.sign_transaction_from(sender, tx).await
+    }
+
+    async fn sign_request(
+        &self,
+        request: N::TransactionRequest,
+    ) -> alloy_signer::Result<N::TxEnvelope> {
+        let sender = request.from().unwrap_or_else(|| self.inner.default_signer_address());
+        let summary =
+            TransactionSummary { from: Some(sender), ..self.layer.summarize::<N>(&request) };
+        self.layer.approve(summary).await.map_err(alloy_signer::Error::other)?;
+        self.inner.sign_request(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use alloy_consensus::{TxEip1559, TypedTransaction};
+    use alloy_dyn_abi::JsonAbiExt;
+    use alloy_network::{Ethereum, EthereumWallet};
+    use alloy_node_bindings::Anvil;
+    use alloy_primitives::{address, B256};
+    use alloy_rpc_types_eth::TransactionRequest;
+    use alloy_signer_local::PrivateKeySigner;
+    use std::sync::Mutex;
+
+    const ERC20: &str = include_str!("../../../pretty/test_data/erc20.json");
+
+    #[test]
+    fn summarizes_requests() {
+        let abi: JsonAbi = serde_json::from_str(ERC20).unwrap();
+        let layer =
+            ApprovalLayer::new(|_| async { Approval::Approved }).with_global_abi(abi.clone());
+        let to = address!("00000000000000000000000000000000000000aa");
+        let input = abi.function("transfer").unwrap()[0]
+            .abi_encode_input(&[DynSolValue::Address(to), DynSolValue::Uint(U256::from(5), 256)])
+            .unwrap();
+        let tx = TransactionRequest::default()
+            .with_to(to)
+            .with_input(input)
+            .with_value(U256::from(1))
+            .with_gas_limit(21_000)
+            .with_max_fee_per_gas(10);
+
+        let summary = layer.summarize::<Ethereum>(&tx);
+        assert_eq!(summary.to, Some(TxKind::Call(to)));
+        assert_eq!(summary.selector, Some(Selector::from([0xa9, 0x05, 0x9c, 0xbb])));
+        assert_eq!(summary.value, U256::from(1));
+        assert_eq!(summary.max_fee(), Some(U256::from(210_000)));
+        let call = summary.call.unwrap();
+        assert_eq!(call.function.name, "transfer");
+        assert_eq!(call.args[1], DynSolValue::Uint(U256::from(5), 256));
+    }
+
+    #[tokio::test]
+    async fn wallet_signs_original_transaction() {
+        let signer = PrivateKeySigner::from_bytes(&B256::repeat_byte(1)).unwrap();
+        let sender = signer.address();
+        let inner = EthereumWallet::from(signer);
+        let wallet = ApprovalLayer::new(move |summary: TransactionSummary| async move {
+            assert_eq!(summary.from, Some(sender));
+            assert_eq!(summary.nonce, Some(3));
+            Approval::Approved
+        })
+        .wallet(inner.clone());
+
+        let tx = TypedTransaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            nonce: 3,
+            gas_limit: 21_000,
+            max_fee_per_gas: 10,
+            to: TxKind::Call(Address::with_last_byte(1)),
+            value: U256::from(1),
+            ..Default::default()
+        });
+        let signed = NetworkWallet::<Ethereum>::sign_transaction_from(&wallet, sender, tx.clone())
+            .await
+            .unwrap();
+        let expected =
+            NetworkWallet::<Ethereum>::sign_transaction_from(&inner, sender, tx).await.unwrap();
+        assert_eq!(signed, expected);
+    }
+
+    #[tokio::test]
+    async fn approves_provider_and_wallet_sends() {
+        let anvil = Anvil::new().spawn();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let hook = {
+            let seen = seen.clone();
+            move |summary: TransactionSummary| {
+                let seen = seen.clone();
+                async move {
+                    let approved = summary.value < U256::from(100);
+                    seen.lock().unwrap().push(summary);
+                    if approved {
+                        Approval::Approved
+                    } else {
+                        Approval::Rejected("value too high".into())
+                    }
+                }
+            }
+        };
+        let layer = ApprovalLayer::new(hook);
+
+        let provider = ProviderBuilder::new().layer(layer.clone()).on_http(anvil.endpoint_url());
+        let from = anvil.addresses()[0];
+        let to = anvil.addresses()[1];
+        let tx = TransactionRequest::default().with_from(from).with_to(to);
+
+        provider.send_transaction(tx.clone().with_value(U256::from(1))).await.unwrap();
+        let err =
+            provider.send_transaction(tx.clone().with_value(U256::from(100))).await.unwrap_err();
+        let rejected = TransactionRejected::from_transport_error(&err).unwrap();
+        assert_eq!(rejected.reason, "value too high");
+        assert_eq!(rejected.summary.from, Some(from));
+
+        let signer: PrivateKeySigner = anvil.keys()[0].clone().into();
+        let wallet = layer.wallet(EthereumWallet::from(signer));
+        let provider = ProviderBuilder::new().wallet(wallet).on_http(anvil.endpoint_url());
+        let tx = TransactionRequest::default().with_to(to).with_value(U256::from(200));
+        assert!(provider.send_transaction(tx).await.is_err());
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 3);
+        assert_eq!(seen[2].from, Some(from));
+        assert_eq!(seen[2].value, U256::from(200));
+    }
+}
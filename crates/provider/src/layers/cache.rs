@@ -3,16 +3,25 @@ use alloy_eips::BlockId;
 use alloy_json_rpc::{RpcError, RpcObject, RpcSend};
 use alloy_network::Network;
 use alloy_primitives::{
-    keccak256, Address, BlockHash, Bytes, StorageKey, StorageValue, TxHash, B256, U256, U64,
+    keccak256, map::HashMap, Address, BlockHash, Bytes, StorageKey, StorageValue, TxHash, B256,
+    U256, U64,
 };
+use alloy_rpc_client::NoParams;
 use alloy_rpc_types_eth::{
     BlockNumberOrTag, BlockTransactionsKind, EIP1186AccountProofResponse, Filter, Log,
 };
 use alloy_transport::{TransportErrorKind, TransportResult};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use schnellru::{ByLength, LruMap};
 use serde::{Deserialize, Serialize};
-use std::{io::BufReader, marker::PhantomData, path::PathBuf, sync::Arc};
+use std::{
+    future::Future,
+    io::BufReader,
+    marker::PhantomData,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 /// A provider layer that caches RPC responses and serves them on subsequent requests.
 ///
@@ -21,6 +30,12 @@ use std::{io::BufReader, marker::PhantomData, path::PathBuf, sync::Arc};
 ///
 /// One can load the cache from the file system by calling `load_cache` and save the cache to the
 /// file system by calling `save_cache`.
+///
+/// Only idempotent requests are cached, such as `eth_chainId`, blocks requested by hash or number,
+/// and transactions and receipts once they are found. Responses are cached until they are evicted
+/// from the LRU cache, unless a time to live is set for their method with
+/// [`with_ttl`](Self::with_ttl). Concurrent identical requests are deduplicated, so that only one
+/// of them is sent while the others wait for its response.
 #[derive(Debug, Clone)]
 pub struct CacheLayer {
     /// In-memory LRU cache, mapping requests to responses.
@@ -34,6 +49,13 @@ impl CacheLayer {
         Self { cache: SharedCache::new(max_items) }
     }
 
+    /// Sets the time to live of the cached responses to the given method, e.g.
+    /// `eth_getBlockByNumber`.
+    pub fn with_ttl(mut self, method: &'static str, ttl: Duration) -> Self {
+        self.cache = self.cache.with_ttl(method, ttl);
+        self
+    }
+
     /// Returns the maximum number of items that can be stored in the cache, set at initialization.
     pub const fn max_items(&self) -> u32 {
         self.cache.max_items()
@@ -108,7 +130,7 @@ macro_rules! rpc_call_with_block {
             // Insert into cache.
             let json_str = serde_json::to_string(&res).map_err(TransportErrorKind::custom)?;
             let hash = $req.params_hash()?;
-            let _ = cache.put(hash, json_str);
+            cache.put_response($req.method(), hash, json_str);
 
             Ok(res)
         }))
@@ -150,6 +172,16 @@ where
         self.inner.root()
     }
 
+    fn get_chain_id(&self) -> ProviderCall<NoParams, U64, u64> {
+        let req = RequestType::new("eth_chainId", ());
+        let fetch = self.inner.get_chain_id();
+        let cache = self.cache.clone();
+        ProviderCall::BoxedFuture(Box::pin(async move {
+            let chain_id = cache_get_or_fetch(&cache, req, async { fetch.await.map(Some) }).await?;
+            Ok(chain_id.unwrap_or_default())
+        }))
+    }
+
     async fn get_block_by_hash(
         &self,
         hash: BlockHash,
@@ -170,6 +202,11 @@ where
         number: BlockNumberOrTag,
         kind: BlockTransactionsKind,
     ) -> TransportResult<Option<N::BlockResponse>> {
+        // Tags such as `latest` refer to different blocks over time.
+        if !number.is_number() {
+            return self.inner.get_block_by_number(number, kind).await;
+        }
+
         let full = match kind {
             BlockTransactionsKind::Full => true,
             BlockTransactionsKind::Hashes => false,
@@ -213,7 +250,7 @@ where
 
             if !redirect {
                 let hash = req.params_hash()?;
-                let _ = cache.put(hash, json_str);
+                cache.put_response(req.method(), hash, json_str);
             }
 
             Ok(result)
@@ -245,7 +282,7 @@ where
         let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
 
         let hash = req.params_hash()?;
-        let _ = self.cache.put(hash, json_str);
+        self.cache.put_response(req.method(), hash, json_str);
 
         Ok(result)
     }
@@ -282,55 +319,27 @@ where
         hash: TxHash,
     ) -> ProviderCall<(TxHash,), Option<N::TransactionResponse>> {
         let req = RequestType::new("eth_getTransactionByHash", (hash,));
-
-        let params_hash = req.params_hash().ok();
-
-        if let Some(hash) = params_hash {
-            if let Ok(Some(cached)) = self.cache.get_deserialized(&hash) {
-                return ProviderCall::BoxedFuture(Box::pin(async move { Ok(cached) }));
-            }
-        }
         let client = self.inner.weak_client();
         let cache = self.cache.clone();
         ProviderCall::BoxedFuture(Box::pin(async move {
             let client = client
                 .upgrade()
                 .ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
-            let result = client.request(req.method(), req.params()).await?;
-
-            let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
-            let hash = req.params_hash()?;
-            let _ = cache.put(hash, json_str);
-
-            Ok(result)
+            let fetch = client.request(req.method(), req.params());
+            cache_get_or_fetch(&cache, req, fetch).await
         }))
     }
 
     fn get_raw_transaction_by_hash(&self, hash: TxHash) -> ProviderCall<(TxHash,), Option<Bytes>> {
         let req = RequestType::new("eth_getRawTransactionByHash", (hash,));
-
-        let params_hash = req.params_hash().ok();
-
-        if let Some(hash) = params_hash {
-            if let Ok(Some(cached)) = self.cache.get_deserialized(&hash) {
-                return ProviderCall::BoxedFuture(Box::pin(async move { Ok(cached) }));
-            }
-        }
-
         let client = self.inner.weak_client();
         let cache = self.cache.clone();
         ProviderCall::BoxedFuture(Box::pin(async move {
             let client = client
                 .upgrade()
                 .ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
-
-            let result = client.request(req.method(), req.params()).await?;
-
-            let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
-            let hash = req.params_hash()?;
-            let _ = cache.put(hash, json_str);
-
-            Ok(result)
+            let fetch = client.request(req.method(), req.params());
+            cache_get_or_fetch(&cache, req, fetch).await
         }))
     }
 
@@ -349,29 +358,15 @@ where
         hash: TxHash,
     ) -> ProviderCall<(TxHash,), Option<N::ReceiptResponse>> {
         let req = RequestType::new("eth_getTransactionReceipt", (hash,));
-
-        let params_hash = req.params_hash().ok();
-
-        if let Some(hash) = params_hash {
-            if let Ok(Some(cached)) = self.cache.get_deserialized(&hash) {
-                return ProviderCall::BoxedFuture(Box::pin(async move { Ok(cached) }));
-            }
-        }
-
         let client = self.inner.weak_client();
         let cache = self.cache.clone();
         ProviderCall::BoxedFuture(Box::pin(async move {
             let client = client
                 .upgrade()
                 .ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
-
-            let result = client.request(req.method(), req.params()).await?;
-
-            let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
-            let hash = req.params_hash()?;
-            let _ = cache.put(hash, json_str);
-
-            Ok(result)
+            // Receipts are only cached once the transaction is included.
+            let fetch = client.request(req.method(), req.params());
+            cache_get_or_fetch(&cache, req, fetch).await
         }))
     }
 }
@@ -430,18 +425,46 @@ struct FsCacheEntry {
     /// Serialized response to the request from which the hash was computed.
     value: String,
 }
+
+/// An entry of the in-memory cache.
+#[derive(Debug)]
+struct CacheEntry {
+    /// Serialized response to the request.
+    value: String,
+    /// The time at which the entry expires, or `None` if it does not expire.
+    expires_at: Option<Instant>,
+}
+
 /// Shareable cache.
 #[derive(Debug, Clone)]
 pub struct SharedCache {
-    inner: Arc<RwLock<LruMap<B256, String>>>,
+    inner: Arc<RwLock<LruMap<B256, CacheEntry>>>,
     max_items: u32,
+    /// The time to live of the responses to each method.
+    ttls: Arc<HashMap<&'static str, Duration>>,
+    /// Locks of the keys with requests in flight, used to deduplicate concurrent requests.
+    in_flight: Arc<Mutex<HashMap<B256, Arc<tokio::sync::Mutex<()>>>>>,
 }
 
 impl SharedCache {
     /// Instantiate a new shared cache.
     pub fn new(max_items: u32) -> Self {
         let inner = Arc::new(RwLock::new(LruMap::new(ByLength::new(max_items))));
-        Self { inner, max_items }
+        Self { inner, max_items, ttls: Default::default(), in_flight: Default::default() }
+    }
+
+    /// Sets the time to live of the cached responses to the given method.
+    ///
+    /// Responses to methods without a time to live do not expire, but may still be evicted once
+    /// the cache is full.
+    pub fn with_ttl(mut self, method: &'static str, ttl: Duration) -> Self {
+        Arc::make_mut(&mut self.ttls).insert(method, ttl);
+        self
+    }
+
+    /// Returns the time to live of the cached responses to the given method, if any.
+    pub fn ttl(&self, method: &str) -> Option<Duration> {
+        self.ttls.get(method).copied()
     }
 
     /// Maximum number of items that can be stored in the cache.
@@ -450,14 +473,29 @@ impl SharedCache {
     }
 
     /// Puts a value into the cache, and returns the old value if it existed.
+    ///
+    /// The value does not expire.
     pub fn put(&self, key: B256, value: String) -> TransportResult<bool> {
-        Ok(self.inner.write().insert(key, value))
+        Ok(self.inner.write().insert(key, CacheEntry { value, expires_at: None }))
+    }
+
+    /// Puts the response to a request of the given method into the cache, expiring after the
+    /// time to live of the method.
+    fn put_response(&self, method: &str, key: B256, value: String) {
+        let expires_at = self.ttl(method).map(|ttl| Instant::now() + ttl);
+        self.inner.write().insert(key, CacheEntry { value, expires_at });
     }
 
-    /// Gets a value from the cache, if it exists.
+    /// Gets a value from the cache, if it exists and has not expired.
     pub fn get(&self, key: &B256) -> Option<String> {
         // Need to acquire a write guard to change the order of keys in LRU cache.
-        self.inner.write().get(key).cloned()
+        let mut inner = self.inner.write();
+        let entry = inner.get(key)?;
+        if entry.expires_at.is_some_and(|expires_at| expires_at <= Instant::now()) {
+            inner.remove(key);
+            return None;
+        }
+        Some(entry.value.clone())
     }
 
     /// Get deserialized value from the cache.
@@ -473,12 +511,15 @@ impl SharedCache {
     /// Saves the cache to a file specified by the path.
     /// If the files does not exist, it creates one.
     /// If the file exists, it overwrites it.
+    ///
+    /// Values that expire are not saved.
     pub fn save_cache(&self, path: PathBuf) -> TransportResult<()> {
         let entries: Vec<FsCacheEntry> = {
             self.inner
                 .read()
                 .iter()
-                .map(|(key, value)| FsCacheEntry { key: *key, value: value.clone() })
+                .filter(|(_, entry)| entry.expires_at.is_none())
+                .map(|(key, entry)| FsCacheEntry { key: *key, value: entry.value.clone() })
                 .collect()
         };
         let file = std::fs::File::create(path).map_err(TransportErrorKind::custom)?;
@@ -498,37 +539,81 @@ impl SharedCache {
             serde_json::from_reader(file).map_err(TransportErrorKind::custom)?;
         let mut cache = self.inner.write();
         for entry in entries {
-            cache.insert(entry.key, entry.value);
+            cache.insert(entry.key, CacheEntry { value: entry.value, expires_at: None });
         }
 
         Ok(())
     }
+
+    /// Returns the cached response to a request of the given method, or fetches and caches it if
+    /// it is not `None`.
+    ///
+    /// Concurrent calls for the same key are deduplicated: while a response is fetched, other
+    /// calls wait for it and are served from the cache.
+    async fn get_or_fetch<Resp: RpcObject>(
+        &self,
+        method: &str,
+        key: B256,
+        fetch: impl Future<Output = TransportResult<Option<Resp>>>,
+    ) -> TransportResult<Option<Resp>> {
+        if let Some(cached) = self.get_deserialized(&key)? {
+            return Ok(Some(cached));
+        }
+
+        let in_flight = InFlight::new(self, key);
+        let _lock = in_flight.lock.lock().await;
+        // The response may have been fetched while waiting for the lock.
+        if let Some(cached) = self.get_deserialized(&key)? {
+            return Ok(Some(cached));
+        }
+
+        let result = fetch.await?;
+        if let Some(ref data) = result {
+            let json_str = serde_json::to_string(data).map_err(TransportErrorKind::custom)?;
+            self.put_response(method, key, json_str);
+        }
+        Ok(result)
+    }
+}
+
+/// The lock of a key with a request in flight, removed from the cache once it is no longer used.
+struct InFlight<'a> {
+    cache: &'a SharedCache,
+    key: B256,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl<'a> InFlight<'a> {
+    fn new(cache: &'a SharedCache, key: B256) -> Self {
+        let lock = cache.in_flight.lock().entry(key).or_default().clone();
+        Self { cache, key, lock }
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self.cache.in_flight.lock();
+        // The lock is only referenced by the map and this request.
+        if Arc::strong_count(&self.lock) == 2 {
+            in_flight.remove(&self.key);
+        }
+    }
 }
 
 /// Attempts to fetch the response from the cache by using the hash of the
 /// request params.
 ///
 /// In case of a cache miss, fetches from the RPC and saves the response to the
-/// cache.
+/// cache, unless it is `None`.
 ///
 /// This helps overriding [`Provider`] methods that return [`TransportResult<T>`].
 async fn cache_get_or_fetch<Params: RpcSend, Resp: RpcObject>(
     cache: &SharedCache,
     req: RequestType<Params>,
-    fetch_fn: impl std::future::Future<Output = TransportResult<Option<Resp>>>,
+    fetch_fn: impl Future<Output = TransportResult<Option<Resp>>>,
 ) -> TransportResult<Option<Resp>> {
     let hash = req.params_hash()?;
-    if let Some(cached) = cache.get_deserialized(&hash)? {
-        return Ok(Some(cached));
-    }
-
-    let result = fetch_fn.await?;
-    if let Some(ref data) = result {
-        let json_str = serde_json::to_string(data).map_err(TransportErrorKind::custom)?;
-        let _ = cache.put(hash, json_str)?;
-    }
-
-    Ok(result)
+    cache.get_or_fetch(req.method(), hash, fetch_fn).await
 }
 
 #[cfg(test)]
@@ -539,6 +624,44 @@ mod tests {
     use alloy_node_bindings::{utils::run_with_tempdir, Anvil};
     use alloy_primitives::{bytes, hex, Bytes, FixedBytes};
     use alloy_rpc_types_eth::{BlockId, TransactionRequest};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_ttl() {
+        let cache = SharedCache::new(10).with_ttl("eth_blockNumber", Duration::from_millis(20));
+        cache.put_response("eth_blockNumber", B256::with_last_byte(1), "\"0x1\"".to_string());
+        cache.put_response("eth_chainId", B256::with_last_byte(2), "\"0x1\"".to_string());
+        assert!(cache.get(&B256::with_last_byte(1)).is_some());
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(cache.get(&B256::with_last_byte(1)), None);
+        assert!(cache.get(&B256::with_last_byte(2)).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_deduplicate_requests() {
+        let cache = SharedCache::new(10);
+        let fetches = AtomicUsize::new(0);
+        let fetch = || async {
+            fetches.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Ok(Some(1u64))
+        };
+        let key = B256::with_last_byte(1);
+        let (a, b) = tokio::join!(
+            cache.get_or_fetch("eth_chainId", key, fetch()),
+            cache.get_or_fetch("eth_chainId", key, fetch())
+        );
+        assert_eq!((a.unwrap(), b.unwrap()), (Some(1), Some(1)));
+        assert_eq!(fetches.load(Ordering::Relaxed), 1);
+        assert!(cache.in_flight.lock().is_empty());
+
+        // `None` is not cached
+        let missing = B256::with_last_byte(2);
+        let none = cache.get_or_fetch::<u64>("eth_chainId", missing, async { Ok(None) }).await;
+        assert_eq!(none.unwrap(), None);
+        assert_eq!(cache.get(&missing), None);
+    }
 
     #[tokio::test]
     async fn test_get_block() {
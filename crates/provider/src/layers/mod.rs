@@ -7,6 +7,14 @@ mod anvil;
 #[cfg(any(test, feature = "anvil-node"))]
 pub use anvil::{AnvilLayer, AnvilProvider};
 
+#[cfg(feature = "approval")]
+mod approval;
+#[cfg(feature = "approval")]
+pub use approval::{
+    Approval, ApprovalHook, ApprovalLayer, ApprovalProvider, ApprovalWallet, DecodedCall,
+    TransactionRejected, TransactionSummary,
+};
+
 mod chain;
 pub use chain::ChainLayer;
 
@@ -14,3 +22,8 @@ pub use chain::ChainLayer;
 mod cache;
 #[cfg(not(target_arch = "wasm32"))]
 pub use cache::{CacheLayer, CacheProvider, SharedCache};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod state_cache;
+#[cfg(not(target_arch = "wasm32"))]
+pub use state_cache::{StateCache, StateCacheLayer, StateCacheProvider};
@@ -0,0 +1,406 @@
+use crate::{ParamsWithBlock, Provider, ProviderCall, ProviderLayer, RootProvider, RpcWithBlock};
+use alloy_eips::{BlockId, RpcBlockHash};
+use alloy_json_rpc::{RpcError, RpcObject, RpcSend};
+use alloy_network::Network;
+use alloy_primitives::{
+    keccak256, map::HashMap, Address, BlockHash, Bytes, StorageValue, B256, U256,
+};
+use alloy_rpc_client::WeakClient;
+use alloy_rpc_types_eth::BlockNumberOrTag;
+use alloy_transport::{TransportErrorKind, TransportResult};
+use parking_lot::Mutex;
+use schnellru::{ByLength, LruMap};
+use serde::Serialize;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A provider layer that caches the state of blocks in memory, i.e. balances, code, storage and
+/// block receipts, for read-heavy applications that repeatedly query the same state within a
+/// block.
+///
+/// Responses to queries at a block hash never change, and are cached in an LRU cache of up to
+/// `max_items` entries. Responses to queries at the `latest` block are cached until the next
+/// block: if the client supports pubsub, the layer subscribes to new heads and invalidates them
+/// on each new head. Otherwise, new heads can be reported with [`StateCache::set_head`], and
+/// `latest` queries are not cached before the first head is reported. Queries at block numbers
+/// and other tags are not cached, since the blocks they refer to change with reorgs.
+///
+/// Unlike the [`CacheLayer`](super::CacheLayer), the cache is not persisted.
+#[derive(Clone, Debug)]
+pub struct StateCacheLayer {
+    cache: StateCache,
+}
+
+impl StateCacheLayer {
+    /// Creates a new state cache layer storing up to `max_items` responses to queries at block
+    /// hashes.
+    pub fn new(max_items: u32) -> Self {
+        Self { cache: StateCache::new(max_items) }
+    }
+
+    /// Returns the shared cache.
+    pub fn cache(&self) -> StateCache {
+        self.cache.clone()
+    }
+}
+
+impl<P, N> ProviderLayer<P, N> for StateCacheLayer
+where
+    P: Provider<N>,
+    N: Network,
+{
+    type Provider = StateCacheProvider<P, N>;
+
+    fn layer(&self, inner: P) -> Self::Provider {
+        #[cfg(feature = "pubsub")]
+        if inner.client().pubsub_frontend().is_some() {
+            self.cache.track_heads(inner.root().clone());
+        }
+        StateCacheProvider::new(inner, self.cache())
+    }
+}
+
+/// A provider that serves state queries from a [`StateCache`], see [`StateCacheLayer`].
+#[derive(Clone, Debug)]
+pub struct StateCacheProvider<P, N> {
+    inner: P,
+    cache: StateCache,
+    _pd: PhantomData<N>,
+}
+
+impl<P, N> StateCacheProvider<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new state cache provider.
+    pub const fn new(inner: P, cache: StateCache) -> Self {
+        Self { inner, cache, _pd: PhantomData }
+    }
+
+    /// Returns the shared cache.
+    pub fn cache(&self) -> StateCache {
+        self.cache.clone()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<P, N> Provider<N> for StateCacheProvider<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    #[inline(always)]
+    fn root(&self) -> &RootProvider<N> {
+        self.inner.root()
+    }
+
+    fn get_balance(&self, address: Address) -> RpcWithBlock<Address, U256, U256> {
+        let client = self.inner.weak_client();
+        let cache = self.cache.clone();
+        RpcWithBlock::new_provider(move |block_id| {
+            cached_call_with_block(&cache, &client, "eth_getBalance", address, block_id)
+        })
+    }
+
+    fn get_block_receipts(
+        &self,
+        block: BlockId,
+    ) -> ProviderCall<(BlockId,), Option<Vec<N::ReceiptResponse>>> {
+        let client = self.inner.weak_client();
+        let cache = self.cache.clone();
+        ProviderCall::BoxedFuture(Box::pin(async move {
+            let slot = cache.slot("eth_getBlockReceipts", &(), block)?;
+            if let Some(cached) = slot.and_then(|slot| cache.get_deserialized(slot)) {
+                return Ok(Some(cached));
+            }
+            let client = client
+                .upgrade()
+                .ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
+            let receipts: Option<Vec<N::ReceiptResponse>> =
+                client.request("eth_getBlockReceipts", (block,)).await?;
+            // Receipts are only cached once the block is found.
+            if let (Some(slot), Some(receipts)) = (slot, &receipts) {
+                cache.put(slot, receipts)?;
+            }
+            Ok(receipts)
+        }))
+    }
+
+    fn get_code_at(&self, address: Address) -> RpcWithBlock<Address, Bytes> {
+        let client = self.inner.weak_client();
+        let cache = self.cache.clone();
+        RpcWithBlock::new_provider(move |block_id| {
+            cached_call_with_block(&cache, &client, "eth_getCode", address, block_id)
+        })
+    }
+
+    fn get_storage_at(
+        &self,
+        address: Address,
+        key: U256,
+    ) -> RpcWithBlock<(Address, U256), StorageValue> {
+        let client = self.inner.weak_client();
+        let cache = self.cache.clone();
+        RpcWithBlock::new_provider(move |block_id| {
+            cached_call_with_block(&cache, &client, "eth_getStorageAt", (address, key), block_id)
+        })
+    }
+}
+
+/// Serves a request that takes a block parameter from the cache, or sends it and caches the
+/// response.
+fn cached_call_with_block<Params, Resp>(
+    cache: &StateCache,
+    client: &WeakClient,
+    method: &'static str,
+    params: Params,
+    block_id: BlockId,
+) -> ProviderCall<ParamsWithBlock<Params>, Resp>
+where
+    Params: RpcSend + 'static,
+    Resp: RpcObject,
+{
+    let cache = cache.clone();
+    let client = client.clone();
+    ProviderCall::BoxedFuture(Box::pin(async move {
+        let slot = cache.slot(method, &params, block_id)?;
+        if let Some(cached) = slot.and_then(|slot| cache.get_deserialized(slot)) {
+            return Ok(cached);
+        }
+        let client =
+            client.upgrade().ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
+        let res: Resp = client
+            .request(method, params)
+            .map_params(|params| ParamsWithBlock { params, block_id })
+            .await?;
+        if let Some(slot) = slot {
+            cache.put(slot, &res)?;
+        }
+        Ok(res)
+    }))
+}
+
+/// The location of a response in the [`StateCache`].
+#[derive(Clone, Copy, Debug)]
+enum Slot {
+    /// A response to a query at a block hash.
+    Hash(B256),
+    /// A response to a query at the `latest` block, made while `head` was the latest block.
+    Latest { key: B256, head: BlockHash },
+}
+
+/// The responses to queries at the `latest` block.
+#[derive(Debug, Default)]
+struct LatestState {
+    /// The hash of the latest block, or `None` if it is unknown.
+    head: Option<BlockHash>,
+    entries: HashMap<B256, String>,
+}
+
+/// Shareable cache of the [`StateCacheLayer`].
+#[derive(Clone, Debug)]
+pub struct StateCache {
+    by_hash: Arc<Mutex<LruMap<B256, String>>>,
+    latest: Arc<Mutex<LatestState>>,
+    max_items: u32,
+}
+
+impl StateCache {
+    /// Creates a new state cache storing up to `max_items` responses to queries at block hashes.
+    pub fn new(max_items: u32) -> Self {
+        Self {
+            by_hash: Arc::new(Mutex::new(LruMap::new(ByLength::new(max_items)))),
+            latest: Arc::default(),
+            max_items,
+        }
+    }
+
+    /// Returns the maximum number of responses to queries at block hashes.
+    pub const fn max_items(&self) -> u32 {
+        self.max_items
+    }
+
+    /// Returns the number of cached responses.
+    pub fn len(&self) -> usize {
+        self.by_hash.lock().len() + self.latest.lock().entries.len()
+    }
+
+    /// Returns `true` if no responses are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the hash of the latest block, if known.
+    pub fn head(&self) -> Option<BlockHash> {
+        self.latest.lock().head
+    }
+
+    /// Sets the hash of the latest block, invalidating the responses to queries at the `latest`
+    /// block if it changed.
+    ///
+    /// This is called on each new head if the client supports pubsub.
+    pub fn set_head(&self, head: BlockHash) {
+        let mut latest = self.latest.lock();
+        if latest.head != Some(head) {
+            latest.head = Some(head);
+            latest.entries.clear();
+        }
+    }
+
+    /// Forgets the latest block, invalidating the responses to queries at the `latest` block and
+    /// disabling their caching until the next [`set_head`](Self::set_head).
+    pub fn clear_head(&self) {
+        let mut latest = self.latest.lock();
+        latest.head = None;
+        latest.entries.clear();
+    }
+
+    /// Removes all cached responses.
+    pub fn clear(&self) {
+        self.by_hash.lock().clear();
+        self.latest.lock().entries.clear();
+    }
+
+    /// Returns the location of the response to the request in the cache, or `None` if the
+    /// response is not cached.
+    fn slot(
+        &self,
+        method: &str,
+        params: &impl Serialize,
+        block_id: BlockId,
+    ) -> TransportResult<Option<Slot>> {
+        let params = serde_json::to_string(params).map_err(RpcError::ser_err)?;
+        let slot = match block_id {
+            BlockId::Hash(RpcBlockHash { block_hash, require_canonical: None | Some(false) }) => {
+                Some(Slot::Hash(keccak256(format!("{method}{params}{block_hash}"))))
+            }
+            BlockId::Number(BlockNumberOrTag::Latest) => self
+                .head()
+                .map(|head| Slot::Latest { key: keccak256(format!("{method}{params}")), head }),
+            _ => None,
+        };
+        Ok(slot)
+    }
+
+    fn get_deserialized<T: RpcObject>(&self, slot: Slot) -> Option<T> {
+        let value = match slot {
+            Slot::Hash(key) => self.by_hash.lock().get(&key).cloned(),
+            Slot::Latest { key, head } => {
+                let latest = self.latest.lock();
+                latest.entries.get(&key).filter(|_| latest.head == Some(head)).cloned()
+            }
+        }?;
+        serde_json::from_str(&value).ok()
+    }
+
+    fn put<T: RpcObject>(&self, slot: Slot, value: &T) -> TransportResult<()> {
+        let value = serde_json::to_string(value).map_err(TransportErrorKind::custom)?;
+        match slot {
+            Slot::Hash(key) => {
+                self.by_hash.lock().insert(key, value);
+            }
+            Slot::Latest { key, head } => {
+                // The response may be of a previous block if a new head arrived in the meantime.
+                let mut latest = self.latest.lock();
+                if latest.head == Some(head) {
+                    latest.entries.insert(key, value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Subscribes to new heads in the background, updating the head of the cache until the
+    /// subscription ends.
+    #[cfg(feature = "pubsub")]
+    fn track_heads<N: Network>(&self, root: RootProvider<N>) {
+        use alloy_network_primitives::HeaderResponse;
+        use alloy_transport::utils::Spawnable;
+        use futures::StreamExt;
+
+        let cache = self.clone();
+        let fut = async move {
+            let sub = match root.subscribe_blocks().await {
+                Ok(sub) => sub,
+                Err(err) => {
+                    debug!(%err, "failed to subscribe to new heads, not caching latest state");
+                    return;
+                }
+            };
+            // Only the subscription is kept, so that the task ends once the client is dropped.
+            drop(root);
+            let mut heads = sub.into_stream();
+            while let Some(header) = heads.next().await {
+                cache.set_head(header.hash());
+            }
+            cache.clear_head();
+        };
+        fut.spawn_task();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use alloy_network::TransactionBuilder;
+    use alloy_primitives::address;
+    use alloy_rpc_types_eth::{BlockTransactionsKind, TransactionRequest};
+
+    #[test]
+    fn slots() {
+        let cache = StateCache::new(10);
+        let hash = B256::with_last_byte(1);
+        assert!(matches!(cache.slot("eth_getCode", &(), BlockId::hash(hash)), Ok(Some(_))));
+        assert!(matches!(cache.slot("eth_getCode", &(), BlockId::hash_canonical(hash)), Ok(None)));
+        assert!(matches!(cache.slot("eth_getCode", &(), BlockId::number(1)), Ok(None)));
+        assert!(matches!(cache.slot("eth_getCode", &(), BlockId::latest()), Ok(None)));
+
+        cache.set_head(hash);
+        let slot = cache.slot("eth_getCode", &(), BlockId::latest()).unwrap().unwrap();
+        cache.put(slot, &1u64).unwrap();
+        assert_eq!(cache.get_deserialized::<u64>(slot), Some(1));
+
+        // a new head invalidates the latest state
+        cache.set_head(B256::with_last_byte(2));
+        assert_eq!(cache.get_deserialized::<u64>(slot), None);
+        cache.put(slot, &1u64).unwrap();
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn latest_state() {
+        let layer = StateCacheLayer::new(100);
+        let cache = layer.cache();
+        let provider = ProviderBuilder::new().layer(layer).on_anvil_with_wallet();
+        let recipient = address!("000000000000000000000000000000000000dEaD");
+
+        // without a known head, latest state is not cached
+        assert_eq!(provider.get_balance(recipient).await.unwrap(), U256::ZERO);
+        assert!(cache.is_empty());
+
+        let head = provider
+            .get_block(BlockId::latest(), BlockTransactionsKind::Hashes)
+            .await
+            .unwrap()
+            .unwrap()
+            .header
+            .hash;
+        cache.set_head(head);
+        assert_eq!(provider.get_balance(recipient).await.unwrap(), U256::ZERO);
+        assert_eq!(cache.len(), 1);
+
+        let tx = TransactionRequest::default().with_to(recipient).with_value(U256::from(1));
+        let receipt = provider.send_transaction(tx).await.unwrap().get_receipt().await.unwrap();
+        let block_hash = receipt.block_hash.unwrap();
+
+        // the cached latest state is served until the head is updated
+        assert_eq!(provider.get_balance(recipient).await.unwrap(), U256::ZERO);
+        let balance = provider.get_balance(recipient).block_id(BlockId::hash(block_hash));
+        assert_eq!(balance.await.unwrap(), U256::from(1));
+        cache.set_head(block_hash);
+        assert_eq!(provider.get_balance(recipient).await.unwrap(), U256::from(1));
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -34,22 +34,49 @@ pub use builder::{Identity, ProviderBuilder, ProviderLayer, Stack};
 
 mod blocks;
 
+pub mod bridge;
+
+pub mod broadcast;
+
 pub mod ext;
 
 pub mod fillers;
 
+pub mod headers;
+
+pub mod pool;
+
 mod heart;
 pub use heart::{
     PendingTransaction, PendingTransactionBuilder, PendingTransactionConfig,
-    PendingTransactionError, WatchTxError,
+    PendingTransactionError, PendingTransactionStore, WatchTxError, WatchedTransaction,
 };
 
 pub mod layers;
 
+pub mod logs;
+
+pub mod mempool;
+
+pub mod paginate;
+
+#[cfg(all(feature = "otterscan-api", feature = "trace-api"))]
+mod scanner;
+#[cfg(all(feature = "otterscan-api", feature = "trace-api"))]
+pub use scanner::{AddressActivity, AddressScanner, ScanStrategy};
+
+#[cfg(feature = "simulation")]
+pub mod simulation;
+
+pub mod stream;
+
+pub mod timeseries;
+
 mod provider;
 pub use provider::{
-    builder, Caller, EthCall, EthCallParams, FilterPollerBuilder, ParamsWithBlock, Provider,
-    ProviderCall, RootProvider, RpcWithBlock, SendableTx, WalletProvider,
+    builder, Caller, EthCall, EthCallParams, FilterPollerBuilder, NotFound, ParamsWithBlock,
+    Provider, ProviderCall, RootProvider, RpcWithBlock, SendTransactionBuilder, SendableTx,
+    WalletProvider,
 };
 
 pub mod utils;
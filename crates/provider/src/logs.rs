@@ -0,0 +1,459 @@
+//! Helpers for `eth_getLogs` queries.
+//!
+//! Providers limit the block range or the number of results of `eth_getLogs` queries.
+//! [`get_logs_split`] splits the block range of queries rejected by these limits until they
+//! succeed.
+//!
+//! Indexers usually trust the logs returned by a node, but buggy or load-balanced providers have
+//! been known to return logs out of order, to return the same log twice across chunked queries, or
+//! to mix logs of different forks. A [`LogValidator`] checks the responses of one or more
+//! `eth_getLogs` queries for these inconsistencies, reporting each as a [`LogIssue`].
+
+use crate::Provider;
+use alloy_consensus::BlockHeader;
+use alloy_json_rpc::RpcError;
+use alloy_network::Network;
+use alloy_network_primitives::BlockResponse;
+use alloy_primitives::{
+    map::{HashMap, HashSet},
+    BlockHash, BlockNumber,
+};
+use alloy_rpc_types_eth::{
+    BlockNumberOrTag, BlockTransactionsKind, Filter, FilterBlockOption, Log,
+};
+use alloy_transport::{TransportError, TransportErrorKind, TransportResult};
+use std::{fmt, future::Future};
+
+/// Substrings of the error messages returned by providers that reject `eth_getLogs` queries over
+/// too many blocks or with too many results.
+const RANGE_LIMIT_MESSAGES: &[&str] = &[
+    "query returned more than",
+    "block range",
+    "range is too large",
+    "range too large",
+    "max range",
+    "is limited to",
+    "too many blocks",
+    "too many results",
+    "response size",
+    "limit exceeded",
+    "query timeout exceeded",
+];
+
+/// Returns `true` if the error was returned by a provider that rejected an `eth_getLogs` query
+/// because its block range or its number of results exceeds a limit, e.g. "query returned more
+/// than 10000 results".
+pub fn is_range_limit_error(err: &TransportError) -> bool {
+    err.as_error_resp().is_some_and(|payload| {
+        let message = payload.message.to_lowercase();
+        RANGE_LIMIT_MESSAGES.iter().any(|pattern| message.contains(pattern))
+    })
+}
+
+/// Fetches the logs matching the filter, splitting its block range in halves whenever the
+/// provider rejects a query as too large, until all queries succeed. The logs of the split
+/// queries are merged in order.
+///
+/// Block tags are only resolved to block numbers if the range is split. Queries at a block hash
+/// or of a single block cannot be split, so their errors are returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_primitives::address;
+/// use alloy_provider::logs::get_logs_split;
+/// use alloy_rpc_types_eth::Filter;
+///
+/// let filter = Filter::new()
+///     .address(address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"))
+///     .from_block(6_000_000);
+/// let logs = get_logs_split(&provider, &filter).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_logs_split<P, N>(provider: &P, filter: &Filter) -> TransportResult<Vec<Log>>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    let err = match provider.get_logs(filter).await {
+        Err(err) if is_range_limit_error(&err) => err,
+        res => return res,
+    };
+    let FilterBlockOption::Range { from_block, to_block } = filter.block_option else {
+        return Err(err);
+    };
+    let from = resolve_block(provider, from_block).await?;
+    let to = resolve_block(provider, to_block).await?;
+    if from >= to {
+        return Err(err);
+    }
+
+    debug!(from, to, %err, "eth_getLogs range too large, splitting");
+    bisect(halves(from, to), |start, end| {
+        let filter = filter.clone().from_block(start).to_block(end);
+        async move { provider.get_logs(&filter).await }
+    })
+    .await
+}
+
+/// Returns the number of the block.
+async fn resolve_block<P, N>(provider: &P, block: Option<BlockNumberOrTag>) -> TransportResult<u64>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    match block.unwrap_or_default() {
+        BlockNumberOrTag::Number(number) => Ok(number),
+        BlockNumberOrTag::Earliest => Ok(0),
+        BlockNumberOrTag::Latest | BlockNumberOrTag::Pending => provider.get_block_number().await,
+        tag => {
+            let block = provider.require_block(tag.into(), BlockTransactionsKind::Hashes).await?;
+            Ok(block.header().number())
+        }
+    }
+}
+
+/// Splits the inclusive range of at least two blocks in halves.
+const fn halves(from: u64, to: u64) -> [(u64, u64); 2] {
+    let mid = from + (to - from) / 2;
+    [(from, mid), (mid + 1, to)]
+}
+
+/// Fetches the logs of the ranges in order, splitting ranges rejected as too large in halves.
+async fn bisect<F, Fut>(
+    ranges: impl IntoIterator<Item = (u64, u64)>,
+    mut fetch: F,
+) -> TransportResult<Vec<Log>>
+where
+    F: FnMut(u64, u64) -> Fut,
+    Fut: Future<Output = TransportResult<Vec<Log>>>,
+{
+    // The ranges left to fetch, with the next range last.
+    let mut ranges: Vec<_> = ranges.into_iter().collect();
+    ranges.reverse();
+
+    let mut logs = Vec::new();
+    while let Some((start, end)) = ranges.pop() {
+        match fetch(start, end).await {
+            Ok(chunk) => logs.extend(chunk),
+            Err(err) if start < end && is_range_limit_error(&err) => {
+                trace!(start, end, "eth_getLogs range too large, splitting");
+                let [first, second] = halves(start, end);
+                ranges.extend([second, first]);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(logs)
+}
+
+/// The position of a log in the chain, ordered by block number and then log index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LogPosition {
+    /// The number of the block of the log.
+    pub block_number: BlockNumber,
+    /// The index of the log in the block.
+    pub log_index: u64,
+}
+
+impl LogPosition {
+    /// Returns the position of the log, or `None` if the log is pending, i.e. its block number or
+    /// log index is not set.
+    pub const fn of(log: &Log) -> Option<Self> {
+        match (log.block_number, log.log_index) {
+            (Some(block_number), Some(log_index)) => Some(Self { block_number, log_index }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LogPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "log {} of block {}", self.log_index, self.block_number)
+    }
+}
+
+/// An inconsistency in `eth_getLogs` responses, found by a [`LogValidator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum LogIssue {
+    /// The log is not ordered after the previous log.
+    #[error("{current} was returned after {previous}")]
+    OutOfOrder {
+        /// The position of the previous log.
+        previous: LogPosition,
+        /// The position of the log.
+        current: LogPosition,
+    },
+    /// A log at the same position was already returned.
+    #[error("{0} was returned more than once")]
+    Duplicate(LogPosition),
+    /// The log has a different block hash than a previous log of the same block, e.g. because
+    /// the provider mixed logs of different forks.
+    #[error("block {block_number} has hash {actual}, but a previous log had hash {expected}")]
+    InconsistentBlockHash {
+        /// The number of the block.
+        block_number: BlockNumber,
+        /// The block hash of the previous logs of the block.
+        expected: BlockHash,
+        /// The block hash of the log.
+        actual: BlockHash,
+    },
+}
+
+/// An error returned by a strict [`LogValidator`] when an `eth_getLogs` response is inconsistent.
+///
+/// This converts into a [`TransportError`]. Use [`LogIntegrityError::from_transport_error`] to
+/// recover it.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("inconsistent logs: {}", .issues[0])]
+pub struct LogIntegrityError {
+    /// The issues found, in the order of the logs. Never empty.
+    pub issues: Vec<LogIssue>,
+}
+
+impl LogIntegrityError {
+    /// Returns the [`LogIntegrityError`] contained in the transport error, if any.
+    pub fn from_transport_error(err: &TransportError) -> Option<&Self> {
+        match err {
+            RpcError::Transport(TransportErrorKind::Custom(err)) => err.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<LogIntegrityError> for TransportError {
+    fn from(err: LogIntegrityError) -> Self {
+        TransportErrorKind::custom(err)
+    }
+}
+
+/// Validates the integrity of `eth_getLogs` responses.
+///
+/// The validator checks that logs are sorted by block number and log index, that no log is
+/// returned twice, and that all logs of a block have the same block hash. Its state is kept
+/// across responses, so the chunks of a query split over block ranges can be validated one after
+/// another, in ascending order of their ranges. Pending logs without a position are not checked.
+///
+/// Issues are logged as warnings. A [strict](Self::with_strict) validator additionally fails with
+/// a [`LogIntegrityError`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_provider::logs::LogValidator;
+/// use alloy_rpc_types_eth::Filter;
+///
+/// let mut validator = LogValidator::new().with_strict(true);
+/// for start in (20_000_000..20_010_000).step_by(1_000) {
+///     let filter = Filter::new().from_block(start).to_block(start + 999);
+///     let logs = validator.get_logs(&provider, &filter).await?;
+///     println!("{} logs", logs.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LogValidator {
+    strict: bool,
+    last: Option<LogPosition>,
+    seen: HashSet<LogPosition>,
+    block_hashes: HashMap<BlockNumber, BlockHash>,
+}
+
+impl LogValidator {
+    /// Creates a new validator that logs issues as warnings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the validator fails with a [`LogIntegrityError`] if issues are found.
+    pub const fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns `true` if the validator fails if issues are found.
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Checks the logs of a response against each other and the previously checked responses,
+    /// returning the issues found, in the order of the logs.
+    pub fn check(&mut self, logs: &[Log]) -> Vec<LogIssue> {
+        let mut issues = Vec::new();
+        for log in logs {
+            let Some(position) = LogPosition::of(log) else { continue };
+
+            if !self.seen.insert(position) {
+                issues.push(LogIssue::Duplicate(position));
+            } else if let Some(previous) = self.last.filter(|previous| *previous > position) {
+                issues.push(LogIssue::OutOfOrder { previous, current: position });
+            }
+            self.last = self.last.max(Some(position));
+
+            if let Some(actual) = log.block_hash {
+                let expected = *self.block_hashes.entry(position.block_number).or_insert(actual);
+                if expected != actual {
+                    issues.push(LogIssue::InconsistentBlockHash {
+                        block_number: position.block_number,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+        issues
+    }
+
+    /// Checks the logs of a response, logging the issues found as warnings.
+    ///
+    /// Fails with a [`LogIntegrityError`] if the validator is [strict](Self::with_strict) and
+    /// issues were found.
+    pub fn validate(&mut self, logs: &[Log]) -> Result<(), LogIntegrityError> {
+        let issues = self.check(logs);
+        for issue in &issues {
+            warn!(%issue, "inconsistent eth_getLogs response");
+        }
+        if self.strict && !issues.is_empty() {
+            return Err(LogIntegrityError { issues });
+        }
+        Ok(())
+    }
+
+    /// Fetches the logs matching the filter and [validates](Self::validate) them.
+    pub async fn get_logs<P, N>(
+        &mut self,
+        provider: &P,
+        filter: &Filter,
+    ) -> TransportResult<Vec<Log>>
+    where
+        P: Provider<N>,
+        N: Network,
+    {
+        let logs = provider.get_logs(filter).await?;
+        self.validate(&logs)?;
+        Ok(logs)
+    }
+
+    /// Clears the state of the validator, e.g. to validate an unrelated query.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.seen.clear();
+        self.block_hashes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use alloy_primitives::B256;
+
+    fn error_resp(message: &'static str) -> TransportError {
+        TransportError::ErrorResp(ErrorPayload {
+            code: -32005,
+            message: message.into(),
+            data: None,
+        })
+    }
+
+    #[test]
+    fn range_limit_errors() {
+        assert!(is_range_limit_error(&error_resp("query returned more than 10000 results")));
+        assert!(is_range_limit_error(&error_resp(
+            "Log response size exceeded. You can make eth_getLogs requests with up to a 2K block \
+             range and no limit on the response size"
+        )));
+        assert!(is_range_limit_error(&error_resp("block range is too wide")));
+        assert!(!is_range_limit_error(&error_resp("invalid params")));
+        assert!(!is_range_limit_error(&TransportErrorKind::backend_gone()));
+    }
+
+    #[tokio::test]
+    async fn bisect_splits_ranges() {
+        let mut requests = Vec::new();
+        // ranges of more than 3 blocks are rejected, each block has one log
+        let logs = bisect([(0, 9)], |start, end| {
+            requests.push((start, end));
+            async move {
+                if end - start >= 3 {
+                    return Err(error_resp("query returned more than 10000 results"));
+                }
+                Ok((start..=end).map(|number| log(number, 0, 0)).collect())
+            }
+        })
+        .await
+        .unwrap();
+
+        let blocks: Vec<_> = logs.iter().map(|log| log.block_number.unwrap()).collect();
+        assert_eq!(blocks, (0..=9).collect::<Vec<_>>());
+        assert_eq!(requests, [(0, 9), (0, 4), (0, 2), (3, 4), (5, 9), (5, 7), (8, 9)]);
+
+        // other errors are returned
+        let err = bisect([(0, 9)], |_, _| async { Err(error_resp("invalid params")) }).await;
+        assert_eq!(err.unwrap_err().as_error_resp().unwrap().message, "invalid params");
+    }
+
+    fn log(block_number: u64, log_index: u64, block_hash: u8) -> Log {
+        Log {
+            block_number: Some(block_number),
+            log_index: Some(log_index),
+            block_hash: Some(B256::with_last_byte(block_hash)),
+            ..Default::default()
+        }
+    }
+
+    fn position(block_number: u64, log_index: u64) -> LogPosition {
+        LogPosition { block_number, log_index }
+    }
+
+    #[test]
+    fn consistent() {
+        let mut validator = LogValidator::new().with_strict(true);
+        validator.validate(&[log(1, 0, 1), log(1, 3, 1), log(2, 0, 2)]).unwrap();
+        // the next chunk continues after the previous one, pending logs are skipped
+        validator.validate(&[log(3, 0, 3), Log::default()]).unwrap();
+    }
+
+    #[test]
+    fn out_of_order_and_duplicates() {
+        let mut validator = LogValidator::new();
+        let issues = validator.check(&[log(1, 1, 1), log(1, 0, 1), log(1, 1, 1)]);
+        assert_eq!(
+            issues,
+            [
+                LogIssue::OutOfOrder { previous: position(1, 1), current: position(1, 0) },
+                LogIssue::Duplicate(position(1, 1)),
+            ]
+        );
+
+        // duplicates are found across responses
+        let issues = validator.check(&[log(1, 0, 1), log(2, 0, 2)]);
+        assert_eq!(issues, [LogIssue::Duplicate(position(1, 0))]);
+
+        validator.reset();
+        assert!(validator.check(&[log(1, 0, 1)]).is_empty());
+    }
+
+    #[test]
+    fn inconsistent_block_hash() {
+        let mut validator = LogValidator::new().with_strict(true);
+        validator.validate(&[log(1, 0, 1)]).unwrap();
+        let err = validator.validate(&[log(1, 1, 2)]).unwrap_err();
+        assert_eq!(
+            err.issues,
+            [LogIssue::InconsistentBlockHash {
+                block_number: 1,
+                expected: B256::with_last_byte(1),
+                actual: B256::with_last_byte(2),
+            }]
+        );
+
+        let transport_err = TransportError::from(err.clone());
+        assert_eq!(LogIntegrityError::from_transport_error(&transport_err), Some(&err));
+    }
+}
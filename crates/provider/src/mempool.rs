@@ -0,0 +1,272 @@
+//! Client-side filtering of pending transactions.
+
+use alloy_consensus::Transaction;
+use alloy_network_primitives::TransactionResponse;
+use alloy_primitives::{map::AddressHashSet, Address, Bytes, U256};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "pubsub")]
+use futures::Stream;
+#[cfg(feature = "pubsub")]
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+/// A client-side filter for pending transactions.
+///
+/// All configured criteria must match. An empty filter matches every transaction.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, U256};
+/// use alloy_provider::mempool::PendingTransactionFilter;
+///
+/// // Transfers of USDC with a value of at least 1 wei.
+/// let filter = PendingTransactionFilter::new()
+///     .to([address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")])
+///     .selector_prefix([0xa9, 0x05, 0x9c, 0xbb])
+///     .min_value(U256::from(1));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PendingTransactionFilter {
+    from: Option<AddressHashSet>,
+    to: Option<AddressHashSet>,
+    input_prefixes: Vec<Bytes>,
+    min_value: Option<U256>,
+    min_max_fee_per_gas: Option<u128>,
+}
+
+impl PendingTransactionFilter {
+    /// Creates a filter that matches every transaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match transactions sent from one of the addresses.
+    pub fn from(mut self, addresses: impl IntoIterator<Item = Address>) -> Self {
+        self.from.get_or_insert_with(Default::default).extend(addresses);
+        self
+    }
+
+    /// Only match calls to one of the addresses. Contract creations never match.
+    pub fn to(mut self, addresses: impl IntoIterator<Item = Address>) -> Self {
+        self.to.get_or_insert_with(Default::default).extend(addresses);
+        self
+    }
+
+    /// Only match transactions whose input starts with one of the configured prefixes, e.g. a
+    /// function selector.
+    pub fn selector_prefix(mut self, prefix: impl Into<Bytes>) -> Self {
+        self.input_prefixes.push(prefix.into());
+        self
+    }
+
+    /// Only match transactions transferring at least the given value.
+    pub const fn min_value(mut self, value: U256) -> Self {
+        self.min_value = Some(value);
+        self
+    }
+
+    /// Only match transactions with at least the given max fee per gas, or gas price for legacy
+    /// transactions.
+    pub const fn min_max_fee_per_gas(mut self, fee: u128) -> Self {
+        self.min_max_fee_per_gas = Some(fee);
+        self
+    }
+
+    /// Returns `true` if the transaction matches the filter.
+    pub fn matches<T: TransactionResponse>(&self, tx: &T) -> bool {
+        if let Some(from) = &self.from {
+            if !from.contains(&TransactionResponse::from(tx)) {
+                return false;
+            }
+        }
+        if let Some(to) = &self.to {
+            if !tx.to().is_some_and(|addr| to.contains(&addr)) {
+                return false;
+            }
+        }
+        if !self.input_prefixes.is_empty()
+            && !self.input_prefixes.iter().any(|prefix| tx.input().starts_with(prefix))
+        {
+            return false;
+        }
+        if self.min_value.is_some_and(|min| tx.value() < min) {
+            return false;
+        }
+        if self.min_max_fee_per_gas.is_some_and(|min| Transaction::max_fee_per_gas(tx) < min) {
+            return false;
+        }
+        true
+    }
+}
+
+/// Counters of a [`FilteredPendingTransactions`] stream.
+#[derive(Debug, Default)]
+pub struct FilterStats {
+    received: AtomicU64,
+    filtered: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl FilterStats {
+    /// Returns the number of transactions received from the subscription.
+    pub fn received(&self) -> u64 {
+        self.received.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of transactions that did not match the filter.
+    pub fn filtered(&self) -> u64 {
+        self.filtered.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of notifications that were dropped, either because they could not be
+    /// deserialized or because the stream lagged behind the subscription.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(all(feature = "pubsub", not(target_arch = "wasm32")))]
+type BoxedStream<T> = Pin<Box<dyn Stream<Item = T> + Send>>;
+
+#[cfg(all(feature = "pubsub", target_arch = "wasm32"))]
+type BoxedStream<T> = Pin<Box<dyn Stream<Item = T>>>;
+
+/// A stream of pending transactions matching a [`PendingTransactionFilter`].
+///
+/// Notifications that cannot be deserialized are counted and skipped instead of ending the
+/// stream. See [`Provider::subscribe_filtered_pending_transactions`].
+///
+/// [`Provider::subscribe_filtered_pending_transactions`]: crate::Provider::subscribe_filtered_pending_transactions
+#[cfg(feature = "pubsub")]
+#[must_use = "streams do nothing unless polled"]
+pub struct FilteredPendingTransactions<T> {
+    inner: BoxedStream<T>,
+    stats: Arc<FilterStats>,
+}
+
+#[cfg(feature = "pubsub")]
+impl<T> std::fmt::Debug for FilteredPendingTransactions<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilteredPendingTransactions").field("stats", &self.stats).finish()
+    }
+}
+
+#[cfg(feature = "pubsub")]
+impl<T> FilteredPendingTransactions<T>
+where
+    T: TransactionResponse + serde::de::DeserializeOwned + Send + 'static,
+{
+    /// Applies the filter to a subscription of full pending transactions.
+    pub fn new(
+        subscription: alloy_pubsub::Subscription<T>,
+        filter: PendingTransactionFilter,
+    ) -> Self {
+        use alloy_json_rpc::{deserialize_reported, DeserContext};
+        use tokio::sync::broadcast::error::RecvError;
+
+        let stats = Arc::<FilterStats>::default();
+        let counters = stats.clone();
+        let mut raw = subscription.into_raw();
+        let inner = Box::pin(async_stream::stream! {
+            loop {
+                let value = match raw.recv().await {
+                    Ok(value) => value,
+                    Err(RecvError::Lagged(skipped)) => {
+                        debug!(skipped, "pending transaction stream lagged");
+                        counters.dropped.fetch_add(skipped, Ordering::Relaxed);
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                counters.received.fetch_add(1, Ordering::Relaxed);
+                match deserialize_reported::<T>(DeserContext::Subscription, value.get()) {
+                    Ok(tx) if filter.matches(&tx) => yield tx,
+                    Ok(_) => {
+                        counters.filtered.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(err) => {
+                        debug!(%err, "failed deserializing pending transaction");
+                        counters.dropped.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        });
+        Self { inner, stats }
+    }
+}
+
+#[cfg(feature = "pubsub")]
+impl<T> FilteredPendingTransactions<T> {
+    /// Returns the counters of the stream, which can be kept after the stream is consumed.
+    pub fn stats(&self) -> Arc<FilterStats> {
+        self.stats.clone()
+    }
+}
+
+#[cfg(feature = "pubsub")]
+impl<T> Stream for FilteredPendingTransactions<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Signed, TxEip1559, TxEnvelope};
+    use alloy_primitives::{address, bytes, PrimitiveSignature as Signature, TxKind, B256};
+    use alloy_rpc_types_eth::Transaction as RpcTransaction;
+
+    fn tx(from: Address, to: TxKind, input: Bytes, value: u64) -> RpcTransaction {
+        let tx = TxEip1559 {
+            to,
+            input,
+            value: U256::from(value),
+            max_fee_per_gas: 10,
+            ..Default::default()
+        };
+        let signed = Signed::new_unchecked(tx, Signature::test_signature(), B256::ZERO);
+        RpcTransaction {
+            inner: TxEnvelope::Eip1559(signed),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+            from,
+        }
+    }
+
+    #[test]
+    fn matches_all_criteria() {
+        let alice = address!("00000000000000000000000000000000000000a1");
+        let bob = address!("00000000000000000000000000000000000000b0");
+        let transfer = bytes!("a9059cbb0000");
+        let call = tx(alice, TxKind::Call(bob), transfer.clone(), 5);
+
+        assert!(PendingTransactionFilter::new().matches(&call));
+        assert!(PendingTransactionFilter::new().from([alice]).to([bob]).matches(&call));
+        assert!(!PendingTransactionFilter::new().from([bob]).matches(&call));
+        assert!(!PendingTransactionFilter::new().to([alice]).matches(&call));
+        assert!(PendingTransactionFilter::new()
+            .selector_prefix(bytes!("deadbeef"))
+            .selector_prefix(bytes!("a9059cbb"))
+            .matches(&call));
+        assert!(!PendingTransactionFilter::new()
+            .selector_prefix(bytes!("deadbeef"))
+            .matches(&call));
+        assert!(PendingTransactionFilter::new().min_value(U256::from(5)).matches(&call));
+        assert!(!PendingTransactionFilter::new().min_value(U256::from(6)).matches(&call));
+        assert!(PendingTransactionFilter::new().min_max_fee_per_gas(10).matches(&call));
+        assert!(!PendingTransactionFilter::new().min_max_fee_per_gas(11).matches(&call));
+
+        let create = tx(alice, TxKind::Create, transfer, 5);
+        assert!(!PendingTransactionFilter::new().to([bob]).matches(&create));
+    }
+}
@@ -0,0 +1,374 @@
+//! Streams over paginated APIs.
+//!
+//! Many APIs return their results in pages, either with an explicit cursor or over block windows,
+//! e.g. `ots_searchTransactionsBefore`, relay data APIs and the beacon API. [`Paginated`] turns a
+//! function that fetches a single page into a [`Stream`] over all items, retrying failed requests
+//! and pacing requests to stay within rate limits.
+
+use alloy_transport::{
+    layers::{RateLimitRetryPolicy, RetryPolicy},
+    TransportResult,
+};
+use async_stream::stream;
+use futures::Stream;
+use std::{fmt, future::Future, time::Duration};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+
+/// A page of results, with the cursor of the next page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Page<T, C> {
+    /// The items of the page.
+    pub items: Vec<T>,
+    /// The cursor of the next page, or `None` if this is the last page.
+    pub next: Option<C>,
+}
+
+impl<T, C> Page<T, C> {
+    /// Creates a new page.
+    pub const fn new(items: Vec<T>, next: Option<C>) -> Self {
+        Self { items, next }
+    }
+
+    /// Creates the last page.
+    pub const fn last(items: Vec<T>) -> Self {
+        Self { items, next: None }
+    }
+
+    /// Returns `true` if there are no more pages after this one.
+    pub const fn is_last(&self) -> bool {
+        self.next.is_none()
+    }
+}
+
+/// A paginated request.
+///
+/// Pages are fetched lazily by calling the fetch function with the cursor of each page, starting
+/// with the initial cursor, until a page without a [next cursor](Page::next) is returned.
+///
+/// Failed requests are retried according to the [retry policy](Self::with_retry_policy), with
+/// exponential backoff or the backoff hinted by the error. Once the retries are exhausted, the
+/// error is yielded and the stream ends.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_provider::paginate::{Page, Paginated};
+/// use futures::StreamExt;
+/// use std::time::Duration;
+///
+/// // Fetch block hashes in windows of 100 blocks.
+/// let latest = provider.get_block_number().await?;
+/// let provider = &provider;
+/// let hashes = Paginated::new(0u64, |start| async move {
+///     let end = (start + 100).min(latest + 1);
+///     let mut hashes = Vec::new();
+///     for number in start..end {
+///         let block = provider.get_block_by_number(number.into(), false.into()).await?;
+///         hashes.extend(block.map(|block| block.header.hash));
+///     }
+///     Ok(Page::new(hashes, (end <= latest).then_some(end)))
+/// })
+/// .with_interval(Duration::from_millis(100))
+/// .into_stream();
+/// futures::pin_mut!(hashes);
+/// while let Some(hash) = hashes.next().await {
+///     println!("{}", hash?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginated<C, F> {
+    cursor: C,
+    fetch: F,
+    policy: Box<dyn RetryPolicy>,
+    max_retries: u32,
+    initial_backoff: Duration,
+    interval: Duration,
+}
+
+impl<C: fmt::Debug, F> fmt::Debug for Paginated<C, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Paginated")
+            .field("cursor", &self.cursor)
+            .field("policy", &self.policy)
+            .field("max_retries", &self.max_retries)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C, F, Fut, T> Paginated<C, F>
+where
+    C: Clone,
+    F: FnMut(C) -> Fut,
+    Fut: Future<Output = TransportResult<Page<T, C>>>,
+{
+    /// Creates a new paginated request starting at the given cursor.
+    ///
+    /// By default, requests that fail with a rate limit or another retryable error are retried up
+    /// to 3 times, with an initial backoff of 500ms.
+    pub fn new(cursor: C, fetch: F) -> Self {
+        Self {
+            cursor,
+            fetch,
+            policy: Box::new(RateLimitRetryPolicy::default()),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            interval: Duration::ZERO,
+        }
+    }
+
+    /// Sets the policy that decides which errors are retried.
+    pub fn with_retry_policy(mut self, policy: impl RetryPolicy + 'static) -> Self {
+        self.policy = Box::new(policy);
+        self
+    }
+
+    /// Sets the maximum number of retries of a single page. Zero disables retries.
+    pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the backoff before the first retry of a page, which doubles with each retry.
+    ///
+    /// Backoffs hinted by the error take precedence.
+    pub const fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the minimum delay between fetching two pages. Defaults to zero.
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns a stream over the pages.
+    pub fn into_pages(self) -> impl Stream<Item = TransportResult<Page<T, C>>> {
+        let Self { cursor, mut fetch, policy, max_retries, initial_backoff, interval } = self;
+        stream! {
+            let mut cursor = Some(cursor);
+            let mut first = true;
+            while let Some(current) = cursor.take() {
+                if !first && !interval.is_zero() {
+                    sleep(interval).await;
+                }
+                first = false;
+
+                let mut retries = 0;
+                let page = loop {
+                    match fetch(current.clone()).await {
+                        Ok(page) => break page,
+                        Err(err) if retries < max_retries && policy.should_retry(&err) => {
+                            let backoff = policy.backoff_hint(&err).unwrap_or_else(|| {
+                                initial_backoff.saturating_mul(1 << retries.min(16))
+                            });
+                            retries += 1;
+                            debug!(%err, retries, ?backoff, "retrying page");
+                            sleep(backoff).await;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                };
+                cursor = page.next.clone();
+                yield Ok(page);
+            }
+        }
+    }
+
+    /// Returns a stream over the items of all pages.
+    pub fn into_stream(self) -> impl Stream<Item = TransportResult<T>> {
+        let pages = self.into_pages();
+        stream! {
+            for await page in pages {
+                match page {
+                    Ok(page) => {
+                        for item in page.items {
+                            yield Ok(item);
+                        }
+                    }
+                    Err(err) => yield Err(err),
+                }
+            }
+        }
+    }
+}
+
+/// A boxed future resolving to a page, for fetch functions whose future type cannot be named.
+#[cfg(not(target_arch = "wasm32"))]
+pub type PageFuture<'a, T, C> =
+    std::pin::Pin<Box<dyn Future<Output = TransportResult<Page<T, C>>> + Send + 'a>>;
+
+/// A boxed future resolving to a page, for fetch functions whose future type cannot be named.
+#[cfg(target_arch = "wasm32")]
+pub type PageFuture<'a, T, C> =
+    std::pin::Pin<Box<dyn Future<Output = TransportResult<Page<T, C>>> + 'a>>;
+
+#[cfg(feature = "otterscan-api")]
+pub use otterscan::{ots_transactions_after, ots_transactions_before};
+
+#[cfg(feature = "otterscan-api")]
+mod otterscan {
+    use super::{Page, PageFuture, Paginated};
+    use crate::{ext::OtterscanApi, Provider};
+    use alloy_network::Network;
+    use alloy_primitives::Address;
+    use alloy_rpc_types_trace::otterscan::{OtsTransactionReceipt, TransactionsWithReceipts};
+
+    /// The block of the first page of an Otterscan search, which starts at the latest block when
+    /// searching backwards and at genesis when searching forwards.
+    const START: u64 = 0;
+
+    type OtsPageFuture<'a, N> =
+        PageFuture<'a, (<N as Network>::TransactionResponse, OtsTransactionReceipt), u64>;
+
+    /// Pages through the transactions sent from or to `address`, from the latest block backwards,
+    /// using `ots_searchTransactionsBefore`.
+    pub fn ots_transactions_before<'a, N, P>(
+        provider: &'a P,
+        address: Address,
+        page_size: usize,
+    ) -> Paginated<u64, impl FnMut(u64) -> OtsPageFuture<'a, N> + 'a>
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        Paginated::new(START, move |block| -> OtsPageFuture<'a, N> {
+            Box::pin(async move {
+                let page =
+                    provider.ots_search_transactions_before(address, block, page_size).await?;
+                // Pages contain whole blocks in descending order, and the block is exclusive.
+                Ok(into_page(page.last_page, page, |blocks| blocks.min()))
+            })
+        })
+    }
+
+    /// Pages through the transactions sent from or to `address`, from genesis forwards, using
+    /// `ots_searchTransactionsAfter`.
+    ///
+    /// Note that the transactions of each page are in descending order.
+    pub fn ots_transactions_after<'a, N, P>(
+        provider: &'a P,
+        address: Address,
+        page_size: usize,
+    ) -> Paginated<u64, impl FnMut(u64) -> OtsPageFuture<'a, N> + 'a>
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        Paginated::new(START, move |block| -> OtsPageFuture<'a, N> {
+            Box::pin(async move {
+                let page =
+                    provider.ots_search_transactions_after(address, block, page_size).await?;
+                // The newest transactions are on the first page.
+                Ok(into_page(page.first_page, page, |blocks| blocks.max()))
+            })
+        })
+    }
+
+    /// Converts a page of search results, computing the next cursor from the block numbers of the
+    /// receipts unless the search is `done`.
+    fn into_page<T>(
+        done: bool,
+        page: TransactionsWithReceipts<T>,
+        next: impl FnOnce(&mut dyn Iterator<Item = u64>) -> Option<u64>,
+    ) -> Page<(T, OtsTransactionReceipt), u64> {
+        let next = if done || page.txs.is_empty() {
+            None
+        } else {
+            next(&mut page.receipts.iter().filter_map(|r| r.receipt.block_number))
+        };
+        let items = page.txs.into_iter().zip(page.receipts).collect();
+        Page::new(items, next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use alloy_transport::{RpcError, TransportErrorKind};
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn rate_limited() -> RpcError<TransportErrorKind> {
+        RpcError::ErrorResp(ErrorPayload {
+            code: 429,
+            message: "Too Many Requests".into(),
+            data: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn streams_items_across_pages() {
+        let items: Vec<_> = Paginated::new(0u32, |start| async move {
+            let next = (start < 6).then_some(start + 3);
+            Ok(Page::new((start..start + 3).collect(), next))
+        })
+        .into_stream()
+        .collect()
+        .await;
+        let items: Vec<u32> = items.into_iter().collect::<Result<_, _>>().unwrap();
+        assert_eq!(items, (0..9).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn retries_rate_limited_pages() {
+        let calls = AtomicU32::new(0);
+        let pages: Vec<_> = Paginated::new((), |()| {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if call < 2 {
+                    Err(rate_limited())
+                } else {
+                    Ok(Page::last(vec![call]))
+                }
+            }
+        })
+        .with_initial_backoff(Duration::from_millis(1))
+        .into_pages()
+        .collect()
+        .await;
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].as_ref().unwrap().items, vec![2]);
+
+        let calls = AtomicU32::new(0);
+        let pages: Vec<_> = Paginated::new((), |()| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err::<Page<(), ()>, _>(rate_limited()) }
+        })
+        .with_max_retries(1)
+        .with_initial_backoff(Duration::from_millis(1))
+        .into_pages()
+        .collect()
+        .await;
+        assert_eq!(pages.len(), 1);
+        assert!(pages[0].is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_other_errors() {
+        let calls = AtomicU32::new(0);
+        let pages: Vec<_> = Paginated::new((), |()| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err::<Page<(), ()>, _>(TransportErrorKind::custom_str("bad request")) }
+        })
+        .into_pages()
+        .collect()
+        .await;
+        assert!(pages[0].is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
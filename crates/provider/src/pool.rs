@@ -0,0 +1,519 @@
+//! A pool of providers with health checks, warm-up and graceful shutdown.
+
+use crate::Provider;
+use alloy_network::{Ethereum, Network};
+use alloy_transport::utils::Spawnable;
+use futures::future::join_all;
+use std::{
+    future::IntoFuture,
+    marker::PhantomData,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::{watch, Notify};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::{sleep, timeout};
+
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::{sleep, timeout};
+
+/// Errors of a [`ProviderPool`].
+#[derive(Debug, thiserror::Error)]
+pub enum PoolError {
+    /// The pool has been shut down.
+    #[error("provider pool is shut down")]
+    ShutDown,
+    /// None of the providers of the pool is healthy.
+    #[error("no healthy provider in pool")]
+    NoHealthyProvider,
+    /// Fewer providers than required passed the warm-up.
+    #[error("only {healthy} of the required {required} providers are healthy")]
+    InsufficientHealthy {
+        /// The number of healthy providers.
+        healthy: usize,
+        /// The required number of healthy providers.
+        required: usize,
+    },
+    /// The providers of the pool are connected to different chains.
+    #[error("providers report different chain ids: {0:?}")]
+    ChainIdMismatch(Vec<u64>),
+    /// In-flight requests did not finish within the drain timeout.
+    #[error("timed out draining {0} in-flight requests")]
+    DrainTimeout(usize),
+}
+
+/// Builder for a [`ProviderPool`].
+#[derive(Debug)]
+pub struct ProviderPoolBuilder<P> {
+    providers: Vec<P>,
+    health_check_timeout: Duration,
+    failure_threshold: u32,
+    min_healthy: usize,
+}
+
+impl<P> Default for ProviderPoolBuilder<P> {
+    fn default() -> Self {
+        Self {
+            providers: Vec::new(),
+            health_check_timeout: Duration::from_secs(5),
+            failure_threshold: 3,
+            min_healthy: 1,
+        }
+    }
+}
+
+impl<P> ProviderPoolBuilder<P> {
+    /// Adds a provider to the pool.
+    pub fn provider(mut self, provider: P) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// Adds multiple providers to the pool.
+    pub fn providers(mut self, providers: impl IntoIterator<Item = P>) -> Self {
+        self.providers.extend(providers);
+        self
+    }
+
+    /// Sets the timeout of a single health check request. Defaults to 5 seconds.
+    pub const fn health_check_timeout(mut self, timeout: Duration) -> Self {
+        self.health_check_timeout = timeout;
+        self
+    }
+
+    /// Sets the number of consecutive failed health checks after which a provider is considered
+    /// unhealthy. Defaults to 3.
+    pub const fn failure_threshold(mut self, threshold: u32) -> Self {
+        self.failure_threshold = threshold;
+        self
+    }
+
+    /// Sets the number of providers that must pass [`ProviderPool::warm_up`]. Defaults to 1.
+    pub const fn min_healthy(mut self, min_healthy: usize) -> Self {
+        self.min_healthy = min_healthy;
+        self
+    }
+
+    /// Builds the pool. All providers are considered healthy until checked.
+    pub fn build<N>(self) -> ProviderPool<P, N> {
+        let (shutdown, _) = watch::channel(false);
+        ProviderPool {
+            inner: Arc::new(PoolInner {
+                members: self
+                    .providers
+                    .into_iter()
+                    .map(|provider| Member {
+                        provider,
+                        healthy: AtomicBool::new(true),
+                        failures: AtomicU32::new(0),
+                        in_flight: AtomicUsize::new(0),
+                        #[cfg(feature = "pubsub")]
+                        subscriptions: Default::default(),
+                    })
+                    .collect(),
+                health_check_timeout: self.health_check_timeout,
+                failure_threshold: self.failure_threshold.max(1),
+                min_healthy: self.min_healthy,
+                next: AtomicUsize::new(0),
+                in_flight: AtomicUsize::new(0),
+                shut_down: AtomicBool::new(false),
+                drained: Notify::new(),
+                shutdown,
+            }),
+            _network: PhantomData,
+        }
+    }
+}
+
+/// A pool of providers for long-running services.
+///
+/// The pool hands out [`PooledProvider`] handles to the least loaded healthy provider and tracks
+/// them as in-flight work. Providers are checked with [`warm_up`](Self::warm_up) before serving
+/// and periodically with [`spawn_health_checks`](Self::spawn_health_checks).
+/// [`shutdown`](Self::shutdown) stops handing out providers, waits for all handles to be
+/// returned and cancels the subscriptions registered with
+/// [`PooledProvider::track_subscription`].
+///
+/// The pool is cheap to clone, all clones share the same state.
+///
+/// # Example
+///
+/// ```no_run
+/// use alloy_provider::{pool::ProviderPool, Provider, RootProvider};
+/// use std::time::Duration;
+///
+/// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+/// let pool: ProviderPool<_> = ProviderPool::builder()
+///     .provider(RootProvider::new_http("http://localhost:8545".parse()?))
+///     .provider(RootProvider::new_http("http://localhost:8546".parse()?))
+///     .build();
+/// pool.warm_up().await?;
+/// pool.spawn_health_checks(Duration::from_secs(10));
+///
+/// let provider = pool.checkout()?;
+/// let block_number = provider.get_block_number().await?;
+/// drop(provider);
+///
+/// pool.shutdown(Duration::from_secs(30)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ProviderPool<P, N = Ethereum> {
+    inner: Arc<PoolInner<P>>,
+    _network: PhantomData<fn() -> N>,
+}
+
+impl<P, N> Clone for ProviderPool<P, N> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), _network: PhantomData }
+    }
+}
+
+#[derive(Debug)]
+struct PoolInner<P> {
+    members: Vec<Member<P>>,
+    health_check_timeout: Duration,
+    failure_threshold: u32,
+    min_healthy: usize,
+    next: AtomicUsize,
+    in_flight: AtomicUsize,
+    shut_down: AtomicBool,
+    drained: Notify,
+    shutdown: watch::Sender<bool>,
+}
+
+#[derive(Debug)]
+struct Member<P> {
+    provider: P,
+    healthy: AtomicBool,
+    failures: AtomicU32,
+    in_flight: AtomicUsize,
+    #[cfg(feature = "pubsub")]
+    subscriptions: std::sync::Mutex<Vec<alloy_primitives::B256>>,
+}
+
+impl<P> Member<P> {
+    fn record(&self, success: bool, failure_threshold: u32) {
+        if success {
+            self.failures.store(0, Ordering::Relaxed);
+            self.healthy.store(true, Ordering::Relaxed);
+        } else if self.failures.fetch_add(1, Ordering::Relaxed) + 1 >= failure_threshold {
+            self.healthy.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+impl ProviderPool<(), Ethereum> {
+    /// Returns a builder for a pool.
+    pub fn builder<P>() -> ProviderPoolBuilder<P> {
+        ProviderPoolBuilder::default()
+    }
+}
+
+impl<P, N> ProviderPool<P, N> {
+    /// Returns the number of providers in the pool.
+    pub fn len(&self) -> usize {
+        self.inner.members.len()
+    }
+
+    /// Returns whether the pool has no providers.
+    pub fn is_empty(&self) -> bool {
+        self.inner.members.is_empty()
+    }
+
+    /// Returns the number of healthy providers.
+    pub fn healthy_count(&self) -> usize {
+        self.inner.members.iter().filter(|member| member.healthy.load(Ordering::Relaxed)).count()
+    }
+
+    /// Returns the number of checked out handles.
+    pub fn in_flight(&self) -> usize {
+        self.inner.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether [`shutdown`](Self::shutdown) has been called.
+    pub fn is_shut_down(&self) -> bool {
+        self.inner.shut_down.load(Ordering::SeqCst)
+    }
+
+    /// Returns the providers of the pool together with their health.
+    pub fn providers(&self) -> impl Iterator<Item = (&P, bool)> + '_ {
+        self.inner
+            .members
+            .iter()
+            .map(|member| (&member.provider, member.healthy.load(Ordering::Relaxed)))
+    }
+
+    /// Checks out the least loaded healthy provider.
+    ///
+    /// The returned handle counts as in-flight work until it is dropped.
+    pub fn checkout(&self) -> Result<PooledProvider<P, N>, PoolError> {
+        // count the handle before checking the flag, so that `shutdown` waits for it
+        self.inner.in_flight.fetch_add(1, Ordering::SeqCst);
+        if self.is_shut_down() {
+            self.release();
+            return Err(PoolError::ShutDown);
+        }
+
+        let len = self.len();
+        let start = self.inner.next.fetch_add(1, Ordering::Relaxed);
+        let Some(index) = (start..start + len)
+            .map(|i| i % len)
+            .filter(|&i| self.inner.members[i].healthy.load(Ordering::Relaxed))
+            .min_by_key(|&i| self.inner.members[i].in_flight.load(Ordering::Relaxed))
+        else {
+            self.release();
+            return Err(PoolError::NoHealthyProvider);
+        };
+
+        self.inner.members[index].in_flight.fetch_add(1, Ordering::Relaxed);
+        Ok(PooledProvider { pool: self.clone(), index })
+    }
+
+    fn release(&self) {
+        if self.inner.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 && self.is_shut_down() {
+            self.inner.drained.notify_waiters();
+        }
+    }
+
+    async fn drain(&self) {
+        loop {
+            let drained = self.inner.drained.notified();
+            if self.in_flight() == 0 {
+                return;
+            }
+            drained.await;
+        }
+    }
+}
+
+impl<P, N> ProviderPool<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Checks the health of all providers by requesting the latest block number.
+    ///
+    /// Providers that fail the configured number of consecutive checks are skipped by
+    /// [`checkout`](Self::checkout) until they pass a check again. Returns the number of healthy
+    /// providers.
+    pub async fn check_health(&self) -> usize {
+        let inner = &self.inner;
+        join_all(inner.members.iter().map(|member| async move {
+            let result = timeout(
+                inner.health_check_timeout,
+                member.provider.get_block_number().into_future(),
+            )
+            .await;
+            match &result {
+                Ok(Ok(_)) => {}
+                Ok(Err(err)) => debug!(%err, "provider health check failed"),
+                Err(_) => debug!("provider health check timed out"),
+            }
+            member.record(matches!(result, Ok(Ok(_))), inner.failure_threshold);
+        }))
+        .await;
+        self.healthy_count()
+    }
+
+    /// Checks that the providers are reachable and connected to the same chain.
+    ///
+    /// Unreachable providers are marked unhealthy immediately. Fails if fewer providers than
+    /// configured with [`ProviderPoolBuilder::min_healthy`] are reachable.
+    pub async fn warm_up(&self) -> Result<(), PoolError> {
+        let inner = &self.inner;
+        let chain_ids = join_all(inner.members.iter().map(|member| async move {
+            let result =
+                timeout(inner.health_check_timeout, member.provider.get_chain_id().into_future())
+                    .await
+                    .ok()
+                    .and_then(Result::ok);
+            member.failures.store(0, Ordering::Relaxed);
+            member.healthy.store(result.is_some(), Ordering::Relaxed);
+            result
+        }))
+        .await;
+
+        let mut chain_ids = chain_ids.into_iter().flatten().collect::<Vec<_>>();
+        chain_ids.sort_unstable();
+        chain_ids.dedup();
+        if chain_ids.len() > 1 {
+            return Err(PoolError::ChainIdMismatch(chain_ids));
+        }
+
+        let healthy = self.healthy_count();
+        let required = inner.min_healthy.max(1);
+        if healthy < required {
+            return Err(PoolError::InsufficientHealthy { healthy, required });
+        }
+        Ok(())
+    }
+
+    /// Spawns a task running [`check_health`](Self::check_health) at the given interval until the
+    /// pool is shut down.
+    pub fn spawn_health_checks(&self, interval: Duration)
+    where
+        P: 'static,
+    {
+        let pool = self.clone();
+        let mut shutdown = self.inner.shutdown.subscribe();
+        let fut = async move {
+            loop {
+                tokio::select! {
+                    _ = sleep(interval) => {}
+                    _ = shutdown.wait_for(|shut_down| *shut_down) => break,
+                }
+                let healthy = pool.check_health().await;
+                trace!(healthy, total = pool.len(), "provider pool health check");
+            }
+        };
+        fut.spawn_task();
+    }
+
+    /// Shuts down the pool.
+    ///
+    /// New checkouts fail with [`PoolError::ShutDown`], health checks stop and the pool waits up
+    /// to `drain_timeout` for all checked out handles to be dropped. Afterwards all tracked
+    /// subscriptions are cancelled.
+    pub async fn shutdown(&self, drain_timeout: Duration) -> Result<(), PoolError> {
+        self.inner.shut_down.store(true, Ordering::SeqCst);
+        self.inner.shutdown.send_replace(true);
+
+        let drained = timeout(drain_timeout, self.drain()).await;
+
+        #[cfg(feature = "pubsub")]
+        for member in &self.inner.members {
+            let subscriptions = std::mem::take(&mut *member.subscriptions.lock().unwrap());
+            for id in subscriptions {
+                if let Err(err) = member.provider.unsubscribe(id).await {
+                    debug!(%id, %err, "failed to cancel subscription");
+                }
+            }
+        }
+
+        drained.map_err(|_| PoolError::DrainTimeout(self.in_flight()))
+    }
+}
+
+/// A provider checked out from a [`ProviderPool`].
+///
+/// Dereferences to the provider. The handle counts as in-flight work of the pool until dropped.
+#[derive(Debug)]
+pub struct PooledProvider<P, N = Ethereum> {
+    pool: ProviderPool<P, N>,
+    index: usize,
+}
+
+impl<P, N> PooledProvider<P, N> {
+    /// Returns the index of the provider in the pool.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Marks the provider as failed, e.g. after a request error, counting towards the failure
+    /// threshold of the pool.
+    pub fn report_failure(&self) {
+        self.member().record(false, self.pool.inner.failure_threshold);
+    }
+
+    /// Registers a subscription to be cancelled when the pool shuts down.
+    #[cfg(feature = "pubsub")]
+    pub fn track_subscription(&self, id: alloy_primitives::B256) {
+        self.member().subscriptions.lock().unwrap().push(id);
+    }
+
+    fn member(&self) -> &Member<P> {
+        &self.pool.inner.members[self.index]
+    }
+}
+
+impl<P, N> Deref for PooledProvider<P, N> {
+    type Target = P;
+
+    fn deref(&self) -> &Self::Target {
+        &self.member().provider
+    }
+}
+
+impl<P, N> Drop for PooledProvider<P, N> {
+    fn drop(&mut self) {
+        self.member().in_flight.fetch_sub(1, Ordering::Relaxed);
+        self.pool.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RootProvider;
+    use alloy_node_bindings::Anvil;
+
+    #[tokio::test]
+    async fn checkout_and_shutdown() {
+        let anvil = Anvil::new().spawn();
+        let pool: ProviderPool<_> = ProviderPool::builder()
+            .provider(RootProvider::new_http(anvil.endpoint_url()))
+            .provider(RootProvider::new_http(anvil.endpoint_url()))
+            .build();
+        pool.warm_up().await.unwrap();
+
+        let a = pool.checkout().unwrap();
+        let b = pool.checkout().unwrap();
+        assert_ne!(a.index(), b.index());
+        assert_eq!(a.get_chain_id().await.unwrap(), anvil.chain_id());
+        assert_eq!(pool.in_flight(), 2);
+        drop(a);
+
+        let shutdown = tokio::spawn({
+            let pool = pool.clone();
+            async move { pool.shutdown(Duration::from_secs(10)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(matches!(pool.checkout(), Err(PoolError::ShutDown)));
+        assert!(!shutdown.is_finished());
+
+        drop(b);
+        shutdown.await.unwrap().unwrap();
+        assert_eq!(pool.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn skips_unhealthy() {
+        let anvil = Anvil::new().spawn();
+        let pool: ProviderPool<_> = ProviderPool::builder()
+            .provider(RootProvider::new_http("http://127.0.0.1:1".parse().unwrap()))
+            .provider(RootProvider::new_http(anvil.endpoint_url()))
+            .failure_threshold(1)
+            .build();
+        pool.warm_up().await.unwrap();
+        assert_eq!(pool.healthy_count(), 1);
+        assert_eq!(pool.check_health().await, 1);
+
+        let handles = (0..3).map(|_| pool.checkout().unwrap()).collect::<Vec<_>>();
+        assert!(handles.iter().all(|handle| handle.index() == 1));
+
+        handles[0].report_failure();
+        assert!(matches!(pool.checkout(), Err(PoolError::NoHealthyProvider)));
+        assert!(matches!(
+            pool.shutdown(Duration::from_millis(10)).await,
+            Err(PoolError::DrainTimeout(3))
+        ));
+    }
+
+    #[tokio::test]
+    async fn warm_up_requires_healthy() {
+        let pool: ProviderPool<_> = ProviderPool::builder()
+            .provider(RootProvider::new_http("http://127.0.0.1:1".parse().unwrap()))
+            .build();
+        assert!(matches!(
+            pool.warm_up().await,
+            Err(PoolError::InsufficientHealthy { healthy: 0, required: 1 })
+        ));
+    }
+}
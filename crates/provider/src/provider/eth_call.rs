@@ -1,7 +1,7 @@
 use alloy_eips::BlockId;
 use alloy_json_rpc::RpcRecv;
 use alloy_network::Network;
-use alloy_rpc_types_eth::state::StateOverride;
+use alloy_rpc_types_eth::{state::StateOverride, BlockOverrides};
 use alloy_transport::TransportResult;
 use futures::FutureExt;
 use serde::ser::SerializeSeq;
@@ -15,6 +15,7 @@ pub struct EthCallParams<'req, N: Network> {
     data: Cow<'req, N::TransactionRequest>,
     block: Option<BlockId>,
     overrides: Option<Cow<'req, StateOverride>>,
+    block_overrides: Option<Cow<'req, BlockOverrides>>,
 }
 
 impl<'req, N> EthCallParams<'req, N>
@@ -23,7 +24,7 @@ where
 {
     /// Instantiates a new `EthCallParams` with the given data (transaction).
     pub const fn new(data: &'req N::TransactionRequest) -> Self {
-        Self { data: Cow::Borrowed(data), block: None, overrides: None }
+        Self { data: Cow::Borrowed(data), block: None, overrides: None, block_overrides: None }
     }
 
     /// Sets the block to use for this call.
@@ -38,11 +39,22 @@ where
         self
     }
 
+    /// Sets the block overrides for this call.
+    pub fn with_block_overrides(mut self, block_overrides: &'req BlockOverrides) -> Self {
+        self.block_overrides = Some(Cow::Borrowed(block_overrides));
+        self
+    }
+
     /// Returns a reference to the state overrides if set.
     pub fn overrides(&self) -> Option<&StateOverride> {
         self.overrides.as_deref()
     }
 
+    /// Returns a reference to the block overrides if set.
+    pub fn block_overrides(&self) -> Option<&BlockOverrides> {
+        self.block_overrides.as_deref()
+    }
+
     /// Returns a reference to the transaction data.
     pub fn data(&self) -> &N::TransactionRequest {
         &self.data
@@ -59,18 +71,30 @@ where
             data: Cow::Owned(self.data.into_owned()),
             block: self.block,
             overrides: self.overrides.map(|o| Cow::Owned(o.into_owned())),
+            block_overrides: self.block_overrides.map(|o| Cow::Owned(o.into_owned())),
         }
     }
 }
 
 impl<N: Network> serde::Serialize for EthCallParams<'_, N> {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let len = if self.overrides().is_some() { 3 } else { 2 };
+        let len = if self.block_overrides().is_some() {
+            4
+        } else if self.overrides().is_some() {
+            3
+        } else {
+            2
+        };
 
         let mut seq = serializer.serialize_seq(Some(len))?;
         seq.serialize_element(&self.data())?;
 
-        if let Some(overrides) = self.overrides() {
+        if let Some(block_overrides) = self.block_overrides() {
+            // The state overrides are serialized as `null` if not set.
+            seq.serialize_element(&self.block().unwrap_or_default())?;
+            seq.serialize_element(&self.overrides())?;
+            seq.serialize_element(block_overrides)?;
+        } else if let Some(overrides) = self.overrides() {
             seq.serialize_element(&self.block().unwrap_or_default())?;
             seq.serialize_element(overrides)?;
         } else if let Some(block) = self.block() {
@@ -303,6 +327,17 @@ where
         self
     }
 
+    /// Set the block overrides for this call, e.g. to override the timestamp or number of the
+    /// block the call is executed in.
+    ///
+    /// # Note
+    ///
+    /// Not all client implementations support block overrides for `eth_call`.
+    pub fn block_overrides(mut self, block_overrides: &'req BlockOverrides) -> Self {
+        self.params.block_overrides = Some(Cow::Borrowed(block_overrides));
+        self
+    }
+
     /// Set the block to use for this call.
     pub const fn block(mut self, block: BlockId) -> Self {
         self.params.block = Some(block);
@@ -403,5 +438,27 @@ mod test {
             serde_json::to_string(&params).unwrap(),
             r#"[{"from":"0x0000000000000000000000000000000000000001","to":"0x0000000000000000000000000000000000000002","maxFeePerGas":"0x4a817c800","maxPriorityFeePerGas":"0x3b9aca00","gas":"0x5208","value":"0x64","nonce":"0x0","chainId":"0x1"},"0x1"]"#
         );
+
+        // Expected: [data, (default), null, block overrides]
+        let block_overrides = BlockOverrides { time: Some(1), ..Default::default() };
+        let params: EthCallParams<'_, Ethereum> =
+            EthCallParams::new(&data).with_block_overrides(&block_overrides);
+
+        assert_eq!(params.block_overrides(), Some(&block_overrides));
+        assert_eq!(
+            serde_json::to_string(&params).unwrap(),
+            r#"[{"from":"0x0000000000000000000000000000000000000001","to":"0x0000000000000000000000000000000000000002","maxFeePerGas":"0x4a817c800","maxPriorityFeePerGas":"0x3b9aca00","gas":"0x5208","value":"0x64","nonce":"0x0","chainId":"0x1"},"latest",null,{"time":"0x1"}]"#
+        );
+
+        // Expected: [data, block, overrides, block overrides]
+        let params: EthCallParams<'_, Ethereum> = EthCallParams::new(&data)
+            .with_block(block)
+            .with_overrides(&overrides)
+            .with_block_overrides(&block_overrides);
+
+        assert_eq!(
+            serde_json::to_string(&params).unwrap(),
+            r#"[{"from":"0x0000000000000000000000000000000000000001","to":"0x0000000000000000000000000000000000000002","maxFeePerGas":"0x4a817c800","maxPriorityFeePerGas":"0x3b9aca00","gas":"0x5208","value":"0x64","nonce":"0x0","chainId":"0x1"},"0x1",{},{"time":"0x1"}]"#
+        );
     }
 }
@@ -1,12 +1,18 @@
 mod eth_call;
 pub use eth_call::{EthCall, EthCallParams};
 
+mod not_found;
+pub use not_found::NotFound;
+
 mod prov_call;
 pub use prov_call::ProviderCall;
 
 mod root;
 pub use root::{builder, RootProvider};
 
+mod send_builder;
+pub use send_builder::SendTransactionBuilder;
+
 mod sendable;
 pub use sendable::SendableTx;
 
@@ -0,0 +1,74 @@
+use alloy_json_rpc::RpcError;
+use alloy_primitives::TxHash;
+use alloy_rpc_types_eth::BlockId;
+use alloy_transport::{TransportError, TransportErrorKind};
+
+/// An object the node returned `null` for, identified by the query that requested it.
+///
+/// This is returned by the `require_*` methods of the [`Provider`](crate::Provider), and converts
+/// into a [`TransportError`]. Use [`NotFound::from_transport_error`] to recover it.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum NotFound {
+    /// The block was not found.
+    #[error("block {0} not found")]
+    Block(BlockId),
+    /// The receipts of the block were not found.
+    #[error("receipts of block {0} not found")]
+    BlockReceipts(BlockId),
+    /// The transaction was not found.
+    #[error("transaction {0} not found")]
+    Transaction(TxHash),
+    /// The transaction at the index of the block was not found.
+    #[error("transaction at index {index} of block {block} not found")]
+    TransactionByBlockAndIndex {
+        /// The block that was queried.
+        block: BlockId,
+        /// The index that was queried.
+        index: usize,
+    },
+    /// The receipt of the transaction was not found, i.e. the transaction is unknown or not yet
+    /// included in a block.
+    #[error("receipt of transaction {0} not found, it is unknown or still pending")]
+    Receipt(TxHash),
+    /// The uncle at the index of the block was not found.
+    #[error("uncle at index {index} of block {block} not found")]
+    Uncle {
+        /// The block that was queried.
+        block: BlockId,
+        /// The index that was queried.
+        index: u64,
+    },
+}
+
+impl NotFound {
+    /// Returns the [`NotFound`] error contained in the transport error, if any.
+    pub fn from_transport_error(err: &TransportError) -> Option<&Self> {
+        match err {
+            RpcError::Transport(TransportErrorKind::Custom(err)) => err.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<NotFound> for TransportError {
+    fn from(err: NotFound) -> Self {
+        TransportErrorKind::custom(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_eth::BlockNumberOrTag;
+
+    #[test]
+    fn transport_error_roundtrip() {
+        let not_found = NotFound::Uncle { block: BlockNumberOrTag::Number(16).into(), index: 1 };
+        let err = TransportError::from(not_found.clone());
+        assert_eq!(err.to_string(), "uncle at index 1 of block 0x10 not found");
+        assert_eq!(NotFound::from_transport_error(&err), Some(&not_found));
+
+        assert_eq!(NotFound::from_transport_error(&RpcError::NullResp), None);
+    }
+}
@@ -1,15 +1,17 @@
 use crate::{
     blocks::NewBlocks,
     heart::{Heartbeat, HeartbeatHandle},
-    Identity, ProviderBuilder,
+    Identity, PendingTransaction, PendingTransactionConfig, PendingTransactionError,
+    PendingTransactionStore, Provider, ProviderBuilder, WatchedTransaction,
 };
 use alloy_network::{Ethereum, Network};
+use alloy_primitives::TxHash;
 use alloy_rpc_client::{BuiltInConnectionString, ClientBuilder, ClientRef, RpcClient, WeakClient};
 use alloy_transport::{TransportConnect, TransportError};
 use std::{
     fmt,
     marker::PhantomData,
-    sync::{Arc, OnceLock},
+    sync::{Arc, OnceLock, RwLock},
 };
 
 #[cfg(feature = "pubsub")]
@@ -103,6 +105,33 @@ impl<N: Network> RootProvider<N> {
         self.pubsub_frontend()?.unsubscribe(id)
     }
 
+    /// Shuts down the pubsub connection gracefully, e.g. before a deploy.
+    ///
+    /// Unsubscribes from all active subscriptions, waits up to `timeout` for in-flight requests
+    /// to complete and closes the connection, returning a report of what was cancelled.
+    ///
+    /// See [`PubSubFrontend::shutdown`].
+    #[cfg(feature = "pubsub")]
+    pub async fn shutdown(
+        &self,
+        timeout: std::time::Duration,
+    ) -> alloy_transport::TransportResult<alloy_pubsub::ShutdownReport> {
+        self.pubsub_frontend()?.shutdown(timeout).await
+    }
+
+    /// Subscribes to the lifecycle events of the pubsub connection, e.g. to
+    /// display its health or pause work while it is reconnecting.
+    ///
+    /// See [`PubSubFrontend::connection_events`].
+    #[cfg(feature = "pubsub")]
+    pub fn connection_events(
+        &self,
+    ) -> alloy_transport::TransportResult<
+        tokio::sync::broadcast::Receiver<alloy_pubsub::ConnectionEvent>,
+    > {
+        self.pubsub_frontend().map(PubSubFrontend::connection_events)
+    }
+
     #[cfg(feature = "pubsub")]
     pub(crate) fn pubsub_frontend(&self) -> alloy_transport::TransportResult<&PubSubFrontend> {
         self.inner
@@ -111,6 +140,52 @@ impl<N: Network> RootProvider<N> {
             .ok_or_else(alloy_transport::TransportErrorKind::pubsub_unavailable)
     }
 
+    /// Sets the persistence hooks for pending transactions watched by this provider.
+    ///
+    /// The hooks apply to transactions registered after this call. See
+    /// [`PendingTransactionStore`] for more details.
+    pub fn set_pending_transaction_store(&self, store: impl PendingTransactionStore) {
+        *self.inner.pending_tx_store.write().unwrap() = Some(Arc::new(store));
+    }
+
+    /// Returns a snapshot of the pending transactions currently watched by the heartbeat.
+    pub async fn watched_transactions(
+        &self,
+    ) -> Result<Vec<WatchedTransaction>, PendingTransactionError> {
+        self.get_heart().watched_txs().await
+    }
+
+    /// Stops watching a pending transaction. Its waiter resolves with
+    /// [`WatchTxError::Cancelled`](crate::WatchTxError::Cancelled).
+    ///
+    /// Returns `true` if the transaction was being watched.
+    pub async fn cancel_pending_transaction(
+        &self,
+        tx_hash: TxHash,
+    ) -> Result<bool, PendingTransactionError> {
+        self.get_heart().cancel_tx(tx_hash).await
+    }
+
+    /// Resumes watching previously persisted pending transactions, e.g. after a restart.
+    ///
+    /// Transactions that were already confirmed in the meantime resolve immediately. Note that
+    /// timeouts start over from the moment a transaction is resumed.
+    pub async fn resume_pending_transactions(
+        &self,
+        configs: impl IntoIterator<Item = PendingTransactionConfig>,
+    ) -> Vec<Result<PendingTransaction, PendingTransactionError>> {
+        let mut pending = Vec::new();
+        for config in configs {
+            pending.push(self.watch_pending_transaction(config).await);
+        }
+        pending
+    }
+
+    #[inline]
+    pub(crate) fn pending_tx_store(&self) -> Option<Arc<dyn PendingTransactionStore>> {
+        self.inner.pending_tx_store.read().unwrap().clone()
+    }
+
     #[inline]
     pub(crate) fn get_heart(&self) -> &HeartbeatHandle<N> {
         self.inner.heart.get_or_init(|| {
@@ -126,18 +201,29 @@ impl<N: Network> RootProvider<N> {
 pub(crate) struct RootProviderInner<N: Network = Ethereum> {
     client: RpcClient,
     heart: OnceLock<HeartbeatHandle<N>>,
+    pending_tx_store: RwLock<Option<Arc<dyn PendingTransactionStore>>>,
     _network: PhantomData<N>,
 }
 
 impl<N: Network> Clone for RootProviderInner<N> {
     fn clone(&self) -> Self {
-        Self { client: self.client.clone(), heart: self.heart.clone(), _network: PhantomData }
+        Self {
+            client: self.client.clone(),
+            heart: self.heart.clone(),
+            pending_tx_store: RwLock::new(self.pending_tx_store.read().unwrap().clone()),
+            _network: PhantomData,
+        }
     }
 }
 
 impl<N: Network> RootProviderInner<N> {
     pub(crate) fn new(client: RpcClient) -> Self {
-        Self { client, heart: Default::default(), _network: PhantomData }
+        Self {
+            client,
+            heart: Default::default(),
+            pending_tx_store: Default::default(),
+            _network: PhantomData,
+        }
     }
 
     pub(crate) fn weak_client(&self) -> WeakClient {
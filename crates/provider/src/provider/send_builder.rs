@@ -0,0 +1,164 @@
+use crate::{EthCall, PendingTransactionBuilder, Provider};
+use alloy_eips::eip2930::AccessList;
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{Address, Bytes, ChainId, U256, U64};
+use alloy_transport::TransportResult;
+use std::marker::PhantomData;
+
+/// A fluent builder for sending a transaction, created by
+/// [`Provider::transaction_builder`].
+///
+/// Fields that are not set are populated by the fillers of the provider when sending, e.g. the
+/// nonce, gas limit and fees with the recommended fillers, and the transaction is signed by the
+/// wallet of the provider, if any.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+/// use alloy_primitives::{address, utils::parse_ether};
+///
+/// let receipt = provider
+///     .tx()
+///     .to(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"))
+///     .value(parse_ether("0.1")?)
+///     .send()
+///     .await?
+///     .get_receipt()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+#[must_use = "transaction builders do nothing unless sent"]
+pub struct SendTransactionBuilder<'a, P, N: Network> {
+    provider: &'a P,
+    request: N::TransactionRequest,
+    _network: PhantomData<N>,
+}
+
+impl<'a, P, N> SendTransactionBuilder<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new builder sending an empty transaction request with the given provider.
+    pub fn new(provider: &'a P) -> Self {
+        Self::with_request(provider, Default::default())
+    }
+
+    /// Creates a new builder sending the given transaction request with the given provider.
+    pub const fn with_request(provider: &'a P, request: N::TransactionRequest) -> Self {
+        Self { provider, request, _network: PhantomData }
+    }
+
+    /// Sets the recipient of the transaction.
+    pub fn to(mut self, to: Address) -> Self {
+        self.request.set_to(to);
+        self
+    }
+
+    /// Sets the sender of the transaction, which must be a signer of the provider's wallet or an
+    /// account managed by the node.
+    pub fn from(mut self, from: Address) -> Self {
+        self.request.set_from(from);
+        self
+    }
+
+    /// Sets the value transferred by the transaction, in wei.
+    pub fn value(mut self, value: U256) -> Self {
+        self.request.set_value(value);
+        self
+    }
+
+    /// Sets the input data of the transaction.
+    pub fn input(mut self, input: impl Into<Bytes>) -> Self {
+        self.request.set_input(input);
+        self
+    }
+
+    /// Makes the transaction a contract deployment with the given bytecode.
+    pub fn deploy(mut self, code: impl Into<Bytes>) -> Self {
+        self.request.set_deploy_code(code);
+        self
+    }
+
+    /// Sets the nonce of the transaction.
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.request.set_nonce(nonce);
+        self
+    }
+
+    /// Sets the chain ID of the transaction.
+    pub fn chain_id(mut self, chain_id: ChainId) -> Self {
+        self.request.set_chain_id(chain_id);
+        self
+    }
+
+    /// Sets the gas limit of the transaction.
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.request.set_gas_limit(gas_limit);
+        self
+    }
+
+    /// Sets the legacy gas price of the transaction.
+    pub fn gas_price(mut self, gas_price: u128) -> Self {
+        self.request.set_gas_price(gas_price);
+        self
+    }
+
+    /// Sets the EIP-1559 max fee per gas of the transaction.
+    pub fn max_fee_per_gas(mut self, max_fee_per_gas: u128) -> Self {
+        self.request.set_max_fee_per_gas(max_fee_per_gas);
+        self
+    }
+
+    /// Sets the EIP-1559 max priority fee per gas of the transaction.
+    pub fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: u128) -> Self {
+        self.request.set_max_priority_fee_per_gas(max_priority_fee_per_gas);
+        self
+    }
+
+    /// Sets the access list of the transaction.
+    pub fn access_list(mut self, access_list: AccessList) -> Self {
+        self.request.set_access_list(access_list);
+        self
+    }
+
+    /// Applies a function to the transaction request, e.g. to set fields not covered by this
+    /// builder.
+    pub fn map_request(
+        mut self,
+        f: impl FnOnce(N::TransactionRequest) -> N::TransactionRequest,
+    ) -> Self {
+        self.request = f(self.request);
+        self
+    }
+
+    /// Returns a reference to the transaction request.
+    pub const fn request(&self) -> &N::TransactionRequest {
+        &self.request
+    }
+
+    /// Consumes the builder, returning the transaction request.
+    pub fn into_request(self) -> N::TransactionRequest {
+        self.request
+    }
+
+    /// Executes the transaction with `eth_call` without sending it, see [`Provider::call`].
+    pub fn call(&self) -> EthCall<'_, N, Bytes> {
+        self.provider.call(&self.request)
+    }
+
+    /// Estimates the gas used by the transaction, see [`Provider::estimate_gas`].
+    pub fn estimate_gas(&self) -> EthCall<'_, N, U64, u64> {
+        self.provider.estimate_gas(&self.request)
+    }
+
+    /// Fills, signs and broadcasts the transaction, see [`Provider::send_transaction`].
+    ///
+    /// Returns a [`PendingTransactionBuilder`] to await the receipt or confirmations.
+    pub async fn send(self) -> TransportResult<PendingTransactionBuilder<N>> {
+        self.provider.send_transaction(self.request).await
+    }
+}
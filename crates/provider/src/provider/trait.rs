@@ -3,29 +3,35 @@
 #![allow(unknown_lints, elided_named_lifetimes)]
 
 use crate::{
+    fillers::AccessListEstimate,
     heart::PendingTransactionError,
-    utils::{self, Eip1559Estimation, EstimatorFunction},
-    EthCall, Identity, PendingTransaction, PendingTransactionBuilder, PendingTransactionConfig,
-    ProviderBuilder, ProviderCall, RootProvider, RpcWithBlock, SendableTx,
+    utils::{self, Eip1559Estimation, EstimatorFunction, PriorityFeeEstimator, PriorityFeeSource},
+    EthCall, Identity, NotFound, PendingTransaction, PendingTransactionBuilder,
+    PendingTransactionConfig, ProviderBuilder, ProviderCall, RootProvider, RpcWithBlock,
+    SendTransactionBuilder, SendableTx,
 };
 use alloy_consensus::BlockHeader;
+use alloy_dyn_abi::eip712::TypedData;
 use alloy_eips::eip2718::Encodable2718;
 use alloy_json_rpc::{RpcError, RpcRecv, RpcSend};
-use alloy_network::{Ethereum, Network};
+use alloy_network::{Ethereum, Network, TransactionBuilder};
 use alloy_network_primitives::{BlockResponse, BlockTransactionsKind, ReceiptResponse};
 use alloy_primitives::{
-    hex, Address, BlockHash, BlockNumber, Bytes, StorageKey, StorageValue, TxHash, B256, U128,
-    U256, U64,
+    hex, map::HashMap, Address, BlockHash, BlockNumber, Bytes, PrimitiveSignature as Signature,
+    StorageKey, StorageValue, TxHash, B256, U128, U256, U64,
 };
-use alloy_rpc_client::{ClientRef, NoParams, PollerBuilder, WeakClient};
+use alloy_rpc_client::{BatchRequest, ClientRef, NoParams, PollerBuilder, WeakClient};
 use alloy_rpc_types_eth::{
     simulate::{SimulatePayload, SimulatedBlock},
     AccessListResult, BlockId, BlockNumberOrTag, EIP1186AccountProofResponse, FeeHistory, Filter,
     FilterChanges, Index, Log, SyncStatus,
 };
-use alloy_transport::TransportResult;
+use alloy_transport::{TransportErrorKind, TransportResult};
 use serde_json::value::RawValue;
-use std::borrow::Cow;
+use std::{borrow::Cow, future::IntoFuture};
+
+#[cfg(feature = "pubsub")]
+use alloy_rpc_types_eth::pubsub::SubscriptionParams;
 
 /// A task that polls the provider with `eth_getFilterChanges`, returning a list of `R`.
 ///
@@ -104,6 +110,36 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         self.client().request_noparams("eth_accounts").into()
     }
 
+    /// Signs a message with an account managed by the node, using `personal_sign`.
+    ///
+    /// The node hashes the message with the [EIP-191] prefix before signing it, like local signers
+    /// do. The account must be unlocked on the node, see [`get_accounts`](Self::get_accounts).
+    ///
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    async fn sign_message(&self, address: Address, message: &[u8]) -> TransportResult<Signature> {
+        let message = Bytes::copy_from_slice(message);
+        let signature: Bytes = self.client().request("personal_sign", (message, address)).await?;
+        parse_signature(&signature)
+    }
+
+    /// Signs [EIP-712] typed data with an account managed by the node, using
+    /// `eth_signTypedData_v4`.
+    ///
+    /// The signature can be verified against the
+    /// [`eip712_signing_hash`](TypedData::eip712_signing_hash) of the typed data. The account
+    /// must be unlocked on the node, see [`get_accounts`](Self::get_accounts).
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    async fn sign_typed_data(
+        &self,
+        address: Address,
+        typed_data: &TypedData,
+    ) -> TransportResult<Signature> {
+        let signature: Bytes =
+            self.client().request("eth_signTypedData_v4", (address, typed_data)).await?;
+        parse_signature(&signature)
+    }
+
     /// Returns the base fee per blob gas (blob gas price) in wei.
     fn get_blob_base_fee(&self) -> ProviderCall<NoParams, U128, u128> {
         self.client()
@@ -182,6 +218,37 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         self.client().request("eth_createAccessList", request).into()
     }
 
+    /// Creates an [EIP-2930] access list for the transaction and estimates its gas with and
+    /// without it.
+    ///
+    /// Returns an error if the node failed to generate the access list. See
+    /// [`AccessListEstimate::should_include`] to decide whether to attach the access list.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    async fn estimate_access_list(
+        &self,
+        tx: &N::TransactionRequest,
+    ) -> TransportResult<AccessListEstimate> {
+        let result = self.create_access_list(tx).await?;
+        if let Some(error) = result.error {
+            return Err(RpcError::local_usage_str(&format!(
+                "failed to create access list: {error}"
+            )));
+        }
+
+        let with_access_list = tx.clone().with_access_list(result.access_list.clone());
+        let (gas_without_access_list, gas_with_access_list) = futures::try_join!(
+            self.estimate_gas(tx).into_future(),
+            self.estimate_gas(&with_access_list).into_future()
+        )?;
+
+        Ok(AccessListEstimate {
+            access_list: result.access_list,
+            gas_with_access_list,
+            gas_without_access_list,
+        })
+    }
+
     /// Create an [`EthCall`] future to estimate the gas required for a
     /// transaction.
     ///
@@ -225,7 +292,7 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
                 // empty response, fetch basefee from latest block directly
                 self.get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Hashes)
                     .await?
-                    .ok_or(RpcError::NullResp)?
+                    .ok_or(NotFound::Block(BlockNumberOrTag::Latest.into()))?
                     .header()
                     .as_ref()
                     .base_fee_per_gas()
@@ -240,6 +307,52 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         ))
     }
 
+    /// Estimates the `maxPriorityFeePerGas` using the given [`PriorityFeeEstimator`] stack.
+    ///
+    /// Each source is tried in order until one yields a non-zero value, which is then clamped
+    /// between the estimator's floor and ceiling. If all sources fail, the floor is returned.
+    ///
+    /// See [`PriorityFeeEstimator::for_chain`] for per-chain defaults.
+    async fn estimate_max_priority_fee_per_gas(
+        &self,
+        estimator: &PriorityFeeEstimator,
+    ) -> TransportResult<u128> {
+        for source in estimator.sources() {
+            let estimate = match *source {
+                PriorityFeeSource::MaxPriorityFeePerGas => {
+                    match self.get_max_priority_fee_per_gas().await {
+                        Ok(fee) => Some(fee),
+                        Err(err) => {
+                            debug!(%err, "eth_maxPriorityFeePerGas failed, falling back");
+                            None
+                        }
+                    }
+                }
+                PriorityFeeSource::FeeHistory { blocks, percentile } => {
+                    match self
+                        .get_fee_history(blocks, BlockNumberOrTag::Latest, &[percentile])
+                        .await
+                    {
+                        Ok(history) => PriorityFeeEstimator::fee_history_estimate(
+                            &history.reward.unwrap_or_default(),
+                        ),
+                        Err(err) => {
+                            debug!(%err, "eth_feeHistory failed, falling back");
+                            None
+                        }
+                    }
+                }
+                PriorityFeeSource::Static(fee) => Some(fee),
+            };
+
+            if let Some(fee) = estimate.filter(|fee| *fee > 0) {
+                return Ok(estimator.clamp(fee));
+            }
+        }
+
+        Ok(estimator.floor())
+    }
+
     /// Returns a collection of historical gas information [FeeHistory] which
     /// can be used to calculate the EIP1559 fields `maxFeePerGas` and `maxPriorityFeePerGas`.
     /// `block_count` can range from 1 to 1024 blocks in a single request.
@@ -341,6 +454,18 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         Ok(block)
     }
 
+    /// Gets a block by either its hash, tag, or number, with full transactions or only hashes.
+    ///
+    /// Unlike [`get_block`](Self::get_block), returns a [`NotFound`] error if the block does not
+    /// exist.
+    async fn require_block(
+        &self,
+        block: BlockId,
+        kind: BlockTransactionsKind,
+    ) -> TransportResult<N::BlockResponse> {
+        self.get_block(block, kind).await?.ok_or_else(|| NotFound::Block(block).into())
+    }
+
     /// Returns the number of transactions in a block from a block matching the given block hash.
     async fn get_block_transaction_count_by_hash(
         &self,
@@ -371,6 +496,17 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         self.client().request("eth_getBlockReceipts", (block,)).into()
     }
 
+    /// Gets the selected block [BlockId] receipts.
+    ///
+    /// Unlike [`get_block_receipts`](Self::get_block_receipts), returns a [`NotFound`] error if the
+    /// block does not exist.
+    async fn require_block_receipts(
+        &self,
+        block: BlockId,
+    ) -> TransportResult<Vec<N::ReceiptResponse>> {
+        self.get_block_receipts(block).await?.ok_or_else(|| NotFound::BlockReceipts(block).into())
+    }
+
     /// Gets the bytecode located at the corresponding [Address].
     fn get_code_at(&self, address: Address) -> RpcWithBlock<Address, Bytes> {
         self.client().request("eth_getCode", address).into()
@@ -563,6 +699,43 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         self.client().request("eth_getStorageAt", (address, key)).into()
     }
 
+    /// Gets many storage values, possibly of many accounts, at the given block.
+    ///
+    /// The `eth_getStorageAt` requests are sent in JSON-RPC batches of at most `batch_size`
+    /// requests each, which is considerably faster than awaiting each slot individually. Values
+    /// are keyed by `(address, slot)`; duplicate slots are only requested once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    async fn get_storage_batch(
+        &self,
+        slots: &[(Address, U256)],
+        block: BlockId,
+        batch_size: usize,
+    ) -> TransportResult<HashMap<(Address, U256), StorageValue>> {
+        assert!(batch_size > 0, "batch size must be non-zero");
+        let mut unique = slots.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+
+        let mut values = HashMap::with_capacity_and_hasher(unique.len(), Default::default());
+        for chunk in unique.chunks(batch_size) {
+            let mut batch = BatchRequest::new(self.client());
+            let waiters = chunk
+                .iter()
+                .map(|&(address, slot)| {
+                    batch.add_call::<_, StorageValue>("eth_getStorageAt", &(address, slot, block))
+                })
+                .collect::<TransportResult<Vec<_>>>()?;
+            batch.send().await?;
+            for (key, value) in chunk.iter().zip(futures::future::try_join_all(waiters).await?) {
+                values.insert(*key, value);
+            }
+        }
+        Ok(values)
+    }
+
     /// Gets a transaction by its [TxHash].
     fn get_transaction_by_hash(
         &self,
@@ -571,6 +744,17 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         self.client().request("eth_getTransactionByHash", (hash,)).into()
     }
 
+    /// Gets a transaction by its [TxHash].
+    ///
+    /// Unlike [`get_transaction_by_hash`](Self::get_transaction_by_hash), returns a [`NotFound`]
+    /// error if the transaction does not exist.
+    async fn require_transaction_by_hash(
+        &self,
+        hash: TxHash,
+    ) -> TransportResult<N::TransactionResponse> {
+        self.get_transaction_by_hash(hash).await?.ok_or_else(|| NotFound::Transaction(hash).into())
+    }
+
     /// Gets a transaction by block hash and transaction index position.
     fn get_transaction_by_block_hash_and_index(
         &self,
@@ -604,6 +788,30 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
             .into()
     }
 
+    /// Gets a transaction by block and transaction index position.
+    ///
+    /// Unlike [`get_transaction_by_block_hash_and_index`] and
+    /// [`get_transaction_by_block_number_and_index`], returns a [`NotFound`] error if the block or
+    /// the transaction does not exist.
+    ///
+    /// [`get_transaction_by_block_hash_and_index`]: Self::get_transaction_by_block_hash_and_index
+    /// [`get_transaction_by_block_number_and_index`]: Self::get_transaction_by_block_number_and_index
+    async fn require_transaction_by_block_and_index(
+        &self,
+        block: BlockId,
+        index: usize,
+    ) -> TransportResult<N::TransactionResponse> {
+        let tx = match block {
+            BlockId::Hash(hash) => {
+                self.get_transaction_by_block_hash_and_index(hash.block_hash, index).await?
+            }
+            BlockId::Number(number) => {
+                self.get_transaction_by_block_number_and_index(number, index).await?
+            }
+        };
+        tx.ok_or_else(|| NotFound::TransactionByBlockAndIndex { block, index }.into())
+    }
+
     /// Gets a raw transaction by block number and transaction index position.
     fn get_raw_transaction_by_block_number_and_index(
         &self,
@@ -648,6 +856,17 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         self.client().request("eth_getTransactionReceipt", (hash,)).into()
     }
 
+    /// Gets a transaction receipt by its [TxHash].
+    ///
+    /// Unlike [`get_transaction_receipt`](Self::get_transaction_receipt), returns a [`NotFound`]
+    /// error if the transaction does not exist or is still pending.
+    async fn require_transaction_receipt(
+        &self,
+        hash: TxHash,
+    ) -> TransportResult<N::ReceiptResponse> {
+        self.get_transaction_receipt(hash).await?.ok_or_else(|| NotFound::Receipt(hash).into())
+    }
+
     /// Gets an uncle block through the tag [BlockId] and index [u64].
     async fn get_uncle(&self, tag: BlockId, idx: u64) -> TransportResult<Option<N::BlockResponse>> {
         let idx = U64::from(idx);
@@ -663,20 +882,29 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         }
     }
 
+    /// Gets an uncle block through the tag [BlockId] and index [u64].
+    ///
+    /// Unlike [`get_uncle`](Self::get_uncle), returns a [`NotFound`] error if the block or the
+    /// uncle does not exist.
+    async fn require_uncle(&self, tag: BlockId, idx: u64) -> TransportResult<N::BlockResponse> {
+        self.get_uncle(tag, idx)
+            .await?
+            .ok_or_else(|| NotFound::Uncle { block: tag, index: idx }.into())
+    }
+
     /// Gets the number of uncles for the block specified by the tag [BlockId].
+    ///
+    /// Returns a [`NotFound`] error if the block does not exist.
     async fn get_uncle_count(&self, tag: BlockId) -> TransportResult<u64> {
-        match tag {
-            BlockId::Hash(hash) => self
-                .client()
-                .request("eth_getUncleCountByBlockHash", (hash.block_hash,))
-                .await
-                .map(|count: U64| count.to::<u64>()),
-            BlockId::Number(number) => self
-                .client()
-                .request("eth_getUncleCountByBlockNumber", (number,))
-                .await
-                .map(|count: U64| count.to::<u64>()),
-        }
+        let count: Option<U64> = match tag {
+            BlockId::Hash(hash) => {
+                self.client().request("eth_getUncleCountByBlockHash", (hash.block_hash,)).await?
+            }
+            BlockId::Number(number) => {
+                self.client().request("eth_getUncleCountByBlockNumber", (number,)).await?
+            }
+        };
+        count.map(|count| count.to::<u64>()).ok_or_else(|| NotFound::Block(tag).into())
     }
 
     /// Returns a suggestion for the current `maxPriorityFeePerGas` in wei.
@@ -759,6 +987,48 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         self.send_transaction_internal(SendableTx::Builder(tx)).await
     }
 
+    /// Starts building a transaction to send, with a fluent API.
+    ///
+    /// Fields that are not set are populated by the fillers of the provider when sending, and the
+    /// transaction is signed by its wallet, if any. See [`SendTransactionBuilder`] for more
+    /// details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(provider: impl alloy_provider::Provider, data: alloy_primitives::Bytes) -> Result<(), Box<dyn std::error::Error>> {
+    /// use alloy_primitives::{address, utils::parse_ether};
+    ///
+    /// let pending = provider
+    ///     .transaction_builder()
+    ///     .to(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"))
+    ///     .value(parse_ether("0.1")?)
+    ///     .input(data)
+    ///     .send()
+    ///     .await?;
+    /// let tx_hash = pending.watch().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[auto_impl(keep_default_for(&, &mut, Rc, Arc, Box))]
+    fn transaction_builder(&self) -> SendTransactionBuilder<'_, Self, N>
+    where
+        Self: Sized,
+    {
+        SendTransactionBuilder::new(self)
+    }
+
+    /// Starts building a transaction to send, with a fluent API.
+    ///
+    /// Shorthand for [`transaction_builder`](Self::transaction_builder).
+    #[auto_impl(keep_default_for(&, &mut, Rc, Arc, Box))]
+    fn tx(&self) -> SendTransactionBuilder<'_, Self, N>
+    where
+        Self: Sized,
+    {
+        self.transaction_builder()
+    }
+
     /// Broadcasts a transaction envelope to the network.
     ///
     /// Returns a [`PendingTransactionBuilder`] which can be used to configure
@@ -829,7 +1099,7 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         &self,
     ) -> TransportResult<alloy_pubsub::Subscription<N::HeaderResponse>> {
         self.root().pubsub_frontend()?;
-        let id = self.client().request("eth_subscribe", ("newHeads",)).await?;
+        let id = self.client().request("eth_subscribe", SubscriptionParams::NewHeads).await?;
         self.root().get_subscription(id).await
     }
 
@@ -863,7 +1133,10 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         &self,
     ) -> TransportResult<alloy_pubsub::Subscription<B256>> {
         self.root().pubsub_frontend()?;
-        let id = self.client().request("eth_subscribe", ("newPendingTransactions",)).await?;
+        let id = self
+            .client()
+            .request("eth_subscribe", SubscriptionParams::pending_transactions())
+            .await?;
         self.root().get_subscription(id).await
     }
 
@@ -902,10 +1175,56 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         &self,
     ) -> TransportResult<alloy_pubsub::Subscription<N::TransactionResponse>> {
         self.root().pubsub_frontend()?;
-        let id = self.client().request("eth_subscribe", ("newPendingTransactions", true)).await?;
+        let id = self
+            .client()
+            .request("eth_subscribe", SubscriptionParams::full_pending_transactions())
+            .await?;
         self.root().get_subscription(id).await
     }
 
+    /// Subscribe to a stream of full pending transactions matching the given filter.
+    ///
+    /// The filter is applied client-side. Notifications that fail to deserialize are skipped
+    /// rather than ending the stream, and are counted in the stream's
+    /// [`stats`](crate::mempool::FilteredPendingTransactions::stats) together with the filtered
+    /// transactions.
+    ///
+    /// # Errors
+    ///
+    /// This method is only available on `pubsub` clients, such as WebSockets or IPC, and will
+    /// return a [`PubsubUnavailable`](alloy_transport::TransportErrorKind::PubsubUnavailable)
+    /// transport error if the client does not support it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+    /// use alloy_primitives::address;
+    /// use alloy_provider::mempool::PendingTransactionFilter;
+    /// use futures::StreamExt;
+    ///
+    /// let filter = PendingTransactionFilter::new()
+    ///     .to([address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")])
+    ///     .selector_prefix([0xa9, 0x05, 0x9c, 0xbb]);
+    /// let stream = provider.subscribe_filtered_pending_transactions(filter).await?;
+    /// let stats = stream.stats();
+    /// let mut stream = stream.take(5);
+    /// while let Some(tx) = stream.next().await {
+    ///    println!("{tx:#?}");
+    /// }
+    /// println!("filtered: {}, dropped: {}", stats.filtered(), stats.dropped());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "pubsub")]
+    async fn subscribe_filtered_pending_transactions(
+        &self,
+        filter: crate::mempool::PendingTransactionFilter,
+    ) -> TransportResult<crate::mempool::FilteredPendingTransactions<N::TransactionResponse>> {
+        let sub = self.subscribe_full_pending_transactions().await?;
+        Ok(crate::mempool::FilteredPendingTransactions::new(sub, filter))
+    }
+
     /// Subscribe to a stream of logs matching given filter.
     ///
     /// # Errors
@@ -942,11 +1261,18 @@ pub trait Provider<N: Network = Ethereum>: Send + Sync {
         filter: &Filter,
     ) -> TransportResult<alloy_pubsub::Subscription<Log>> {
         self.root().pubsub_frontend()?;
-        let id = self.client().request("eth_subscribe", ("logs", filter)).await?;
+        let id = self
+            .client()
+            .request("eth_subscribe", SubscriptionParams::logs(filter.clone()))
+            .await?;
         self.root().get_subscription(id).await
     }
 
     /// Subscribe to an RPC event.
+    ///
+    /// The params are sent as the params of the `eth_subscribe` request. Use
+    /// [`SubscriptionParams`] for the standard subscription kinds, or
+    /// [`SubscriptionParams::Custom`] for kinds added by other networks.
     #[cfg(feature = "pubsub")]
     #[auto_impl(keep_default_for(&, &mut, Rc, Arc, Box))]
     async fn subscribe<P, R>(&self, params: P) -> TransportResult<alloy_pubsub::Subscription<R>>
@@ -1098,18 +1424,28 @@ impl<N: Network> Provider<N> for RootProvider<N> {
             };
 
         self.get_heart()
-            .watch_tx(config, block_number)
+            .watch_tx(config, block_number, self.pending_tx_store())
             .await
             .map_err(|_| PendingTransactionError::FailedToRegister)
     }
 }
 
+/// Parses a signature returned by a signing method of the node.
+fn parse_signature(signature: &[u8]) -> TransportResult<Signature> {
+    Signature::try_from(signature).map_err(TransportErrorKind::custom)
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{io::Read, str::FromStr, time::Duration};
+    use std::{
+        io::Read,
+        str::FromStr,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
 
     use super::*;
-    use crate::{builder, ProviderBuilder, WalletProvider};
+    use crate::{builder, PendingTransactionStore, ProviderBuilder, WalletProvider, WatchTxError};
     use alloy_consensus::Transaction;
     use alloy_network::{AnyNetwork, EthereumWallet, TransactionBuilder};
     use alloy_node_bindings::Anvil;
@@ -1362,6 +1698,22 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "ws")]
+    #[tokio::test]
+    #[cfg_attr(windows, ignore)]
+    async fn shutdown_ws() {
+        let anvil = Anvil::new().spawn();
+        let ws = alloy_rpc_client::WsConnect::new(anvil.ws_endpoint());
+        let client = alloy_rpc_client::RpcClient::connect_pubsub(ws).await.unwrap();
+        let provider = RootProvider::<Ethereum>::new(client);
+
+        let _sub = provider.subscribe_blocks().await.unwrap();
+        let report = provider.shutdown(std::time::Duration::from_secs(5)).await.unwrap();
+        assert_eq!(report.unsubscribed, 1);
+        assert!(report.is_clean());
+        assert!(provider.get_block_number().await.is_err());
+    }
+
     #[tokio::test]
     #[cfg(feature = "ws")]
     async fn subscribe_blocks_ws_remote() {
@@ -1428,6 +1780,68 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    #[tokio::test]
+    async fn test_transaction_builder() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let to = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+
+        let builder = provider.tx().to(to).value(U256::from(100));
+        assert_eq!(builder.estimate_gas().await.unwrap(), 21000);
+        assert_eq!(builder.request().value, Some(U256::from(100)));
+
+        let receipt = builder.send().await.unwrap().get_receipt().await.unwrap();
+        assert!(receipt.status());
+        assert_eq!(receipt.to, Some(to));
+        assert_eq!(provider.get_balance(to).await.unwrap(), U256::from(100));
+    }
+
+    #[tokio::test]
+    async fn test_sign_with_node_account() {
+        let provider = ProviderBuilder::new().on_anvil();
+        let account = provider.get_accounts().await.unwrap()[0];
+
+        let message = b"hello alloy";
+        let signature = provider.sign_message(account, message).await.unwrap();
+        assert_eq!(signature.recover_address_from_msg(message).unwrap(), account);
+
+        let typed_data: TypedData = serde_json::from_value(serde_json::json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "chainId", "type": "uint256" }
+                ],
+                "Mail": [
+                    { "name": "to", "type": "address" },
+                    { "name": "contents", "type": "string" }
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": { "name": "Ether Mail", "chainId": 31337 },
+            "message": {
+                "to": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB",
+                "contents": "Hello, Bob!"
+            }
+        }))
+        .unwrap();
+        let signature = provider.sign_typed_data(account, &typed_data).await.unwrap();
+        let hash = typed_data.eip712_signing_hash().unwrap();
+        assert_eq!(signature.recover_address_from_prehash(&hash).unwrap(), account);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_access_list() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let tx = TransactionRequest::default()
+            .with_from(provider.default_signer_address())
+            .with_to(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"))
+            .with_value(U256::from(100));
+
+        let estimate = provider.estimate_access_list(&tx).await.unwrap();
+        assert_eq!(estimate.gas_without_access_list, 21000);
+        assert!(!estimate.should_include(0));
+        assert_eq!(estimate.gas_limit(0), 21000);
+    }
+
     #[tokio::test]
     async fn test_watch_confirmed_tx() {
         let provider = ProviderBuilder::new().on_anvil_with_wallet();
@@ -1475,6 +1889,70 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    #[derive(Clone, Debug, Default)]
+    struct MemoryStore(Arc<Mutex<Vec<PendingTransactionConfig>>>);
+
+    impl PendingTransactionStore for MemoryStore {
+        fn on_watch(&self, config: &PendingTransactionConfig) {
+            self.0.lock().unwrap().push(config.clone());
+        }
+
+        fn on_finish(&self, tx_hash: &TxHash, _result: &Result<(), WatchTxError>) {
+            self.0.lock().unwrap().retain(|config| config.tx_hash() != tx_hash);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_and_cancel_watched_txs() {
+        let provider = ProviderBuilder::new().on_anvil();
+        let hash = B256::repeat_byte(0x11);
+        let config = PendingTransactionConfig::new(hash);
+        let pending = provider.watch_pending_transaction(config.clone()).await.unwrap();
+
+        let watched = provider.root().watched_transactions().await.unwrap();
+        assert_eq!(watched.len(), 1);
+        assert_eq!(watched[0].config, config);
+        assert!(!watched[0].is_included());
+
+        assert!(provider.root().cancel_pending_transaction(hash).await.unwrap());
+        assert!(matches!(
+            pending.await,
+            Err(PendingTransactionError::TxWatcher(WatchTxError::Cancelled))
+        ));
+        assert!(provider.root().watched_transactions().await.unwrap().is_empty());
+        assert!(!provider.root().cancel_pending_transaction(hash).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_resume_pending_transactions() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let store = MemoryStore::default();
+        provider.root().set_pending_transaction_store(store.clone());
+
+        let tx = TransactionRequest::default()
+            .with_from(provider.default_signer_address())
+            .with_to(Address::with_last_byte(1))
+            .with_value(U256::from(1));
+        let confirmed = provider.send_transaction(tx).await.unwrap().watch().await.unwrap();
+        assert!(store.0.lock().unwrap().is_empty());
+
+        // Persisted configs survive a restart in serialized form.
+        let unknown = B256::repeat_byte(0x22);
+        let configs =
+            [PendingTransactionConfig::new(confirmed), PendingTransactionConfig::new(unknown)];
+        let configs: Vec<PendingTransactionConfig> =
+            serde_json::from_str(&serde_json::to_string(&configs).unwrap()).unwrap();
+
+        let mut resumed = provider.root().resume_pending_transactions(configs).await.into_iter();
+        assert_eq!(resumed.next().unwrap().unwrap().await.unwrap(), confirmed);
+        let pending = resumed.next().unwrap().unwrap();
+        assert_eq!(*store.0.lock().unwrap(), [PendingTransactionConfig::new(unknown)]);
+
+        provider.root().cancel_pending_transaction(unknown).await.unwrap();
+        assert!(pending.await.is_err());
+        assert!(store.0.lock().unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn gets_block_number() {
         let provider = ProviderBuilder::new().on_anvil();
@@ -1559,6 +2037,22 @@ mod tests {
         assert_eq!(block.header.number, num);
     }
 
+    #[tokio::test]
+    async fn require_returns_not_found() {
+        let provider = ProviderBuilder::new().on_anvil();
+
+        let block = BlockId::number(100);
+        let err = provider.require_block(block, BlockTransactionsKind::Hashes).await.unwrap_err();
+        assert_eq!(NotFound::from_transport_error(&err), Some(&NotFound::Block(block)));
+
+        let hash = B256::repeat_byte(1);
+        let err = provider.require_transaction_receipt(hash).await.unwrap_err();
+        assert_eq!(NotFound::from_transport_error(&err), Some(&NotFound::Receipt(hash)));
+
+        let block = provider.require_block(BlockId::latest(), BlockTransactionsKind::Hashes).await;
+        assert_eq!(block.unwrap().header.number, 0);
+    }
+
     #[tokio::test]
     async fn gets_block_by_number() {
         let provider = ProviderBuilder::new().on_anvil();
@@ -1611,6 +2105,17 @@ mod tests {
         assert_eq!(storage, U256::ZERO);
     }
 
+    #[tokio::test]
+    async fn gets_storage_batch() {
+        let provider = ProviderBuilder::new().on_anvil();
+        let a = Address::with_last_byte(16);
+        let b = Address::with_last_byte(17);
+        let slots = [(a, U256::ZERO), (a, U256::from(1)), (b, U256::ZERO), (a, U256::ZERO)];
+        let values = provider.get_storage_batch(&slots, BlockId::latest(), 2).await.unwrap();
+        assert_eq!(values.len(), 3);
+        assert!(slots.iter().all(|slot| values[slot] == U256::ZERO));
+    }
+
     #[tokio::test]
     async fn gets_transaction_by_hash_not_found() {
         let provider = ProviderBuilder::new().on_anvil();
@@ -0,0 +1,373 @@
+//! Scanning the transaction history of an address.
+
+use crate::{
+    ext::{OtterscanApi, TraceApi},
+    Provider,
+};
+use alloy_consensus::Transaction;
+use alloy_network::{Ethereum, Network};
+use alloy_network_primitives::{BlockResponse, TransactionResponse};
+use alloy_primitives::{
+    map::{HashMap, HashSet},
+    Address, TxHash, U256,
+};
+use alloy_rpc_client::CancellationToken;
+use alloy_rpc_types_eth::{BlockNumberOrTag, BlockTransactionsKind};
+use alloy_rpc_types_trace::filter::TraceFilter;
+use alloy_transport::TransportResult;
+use futures::{stream, Stream, TryStreamExt};
+use std::{future::IntoFuture, marker::PhantomData, ops::RangeInclusive};
+
+/// The strategy used by an [`AddressScanner`] to find the activity of an address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScanStrategy {
+    /// Page through the Otterscan `ots_searchTransactionsAfter` index.
+    ///
+    /// Finds all transactions sent from or to the address, including internal calls.
+    Otterscan,
+    /// Query `trace_filter` over chunks of the block range.
+    ///
+    /// Finds all transactions sent from or to the address, including internal calls, as well as
+    /// block rewards.
+    TraceFilter,
+    /// Bisect the block range over the nonce and balance of the address, then search the
+    /// transactions of each block where either changed.
+    ///
+    /// Only requires standard `eth_` methods, but only finds activity that changes the nonce or
+    /// balance of the address, and misses changes that cancel out within a bisected range.
+    StateBisect,
+}
+
+/// A reference to activity of an address, found by an [`AddressScanner`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AddressActivity {
+    /// The number of the block of the activity.
+    pub block_number: u64,
+    /// The hash of the transaction, or `None` if the activity is not attributable to a single
+    /// transaction, e.g. a block reward or a withdrawal.
+    pub transaction_hash: Option<TxHash>,
+    /// The strategy that found the activity.
+    pub strategy: ScanStrategy,
+}
+
+/// Finds the transactions involving an address across a block range.
+///
+/// Unless a strategy is set with [`with_strategy`](Self::with_strategy), the best strategy
+/// available on the node is detected when scanning, see [`detect_strategy`](Self::detect_strategy).
+#[derive(Clone, Debug)]
+pub struct AddressScanner<P, N = Ethereum> {
+    provider: P,
+    address: Address,
+    strategy: Option<ScanStrategy>,
+    page_size: usize,
+    trace_block_span: u64,
+    cancel: Option<CancellationToken>,
+    _network: PhantomData<fn() -> N>,
+}
+
+impl<P, N> AddressScanner<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// The default number of transactions requested per Otterscan page.
+    pub const DEFAULT_PAGE_SIZE: usize = 25;
+
+    /// The default number of blocks covered by each `trace_filter` request.
+    pub const DEFAULT_TRACE_BLOCK_SPAN: u64 = 10_000;
+
+    /// Creates a new scanner for the given address.
+    pub const fn new(provider: P, address: Address) -> Self {
+        Self {
+            provider,
+            address,
+            strategy: None,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            trace_block_span: Self::DEFAULT_TRACE_BLOCK_SPAN,
+            cancel: None,
+            _network: PhantomData,
+        }
+    }
+
+    /// Sets the strategy to use instead of detecting it.
+    pub const fn with_strategy(mut self, strategy: ScanStrategy) -> Self {
+        self.strategy = Some(strategy);
+        self
+    }
+
+    /// Sets the number of transactions requested per Otterscan page.
+    pub const fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the number of blocks covered by each `trace_filter` request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `span` is zero.
+    pub const fn with_trace_block_span(mut self, span: u64) -> Self {
+        assert!(span > 0, "trace block span must be non-zero");
+        self.trace_block_span = span;
+        self
+    }
+
+    /// Sets a cancellation token that ends the scan when cancelled.
+    ///
+    /// Cancellation is checked before each request, so the scan stream ends after the current
+    /// page instead of erroring.
+    pub fn with_cancellation_token(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Detects the best strategy available on the node.
+    ///
+    /// Prefers [`ScanStrategy::Otterscan`], then [`ScanStrategy::TraceFilter`], and falls back to
+    /// [`ScanStrategy::StateBisect`].
+    pub async fn detect_strategy(&self) -> TransportResult<ScanStrategy> {
+        if OtterscanApi::<N>::ots_is_supported(&self.provider).await? {
+            return Ok(ScanStrategy::Otterscan);
+        }
+        let filter = TraceFilter::default()
+            .from_block(0)
+            .to_block(0)
+            .from_address(vec![self.address])
+            .to_address(vec![self.address]);
+        match TraceApi::<N>::trace_filter(&self.provider, &filter).await {
+            Ok(_) => Ok(ScanStrategy::TraceFilter),
+            Err(err) if crate::ext::is_method_not_found(&err) => Ok(ScanStrategy::StateBisect),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns a stream of the activity of the address in the given block range, in ascending
+    /// block order.
+    pub fn scan(
+        &self,
+        blocks: RangeInclusive<u64>,
+    ) -> impl Stream<Item = TransportResult<AddressActivity>> + '_ {
+        let (start, end) = blocks.into_inner();
+        let state = match self.strategy {
+            Some(strategy) => ScanState::new(strategy, start, end),
+            None => ScanState::Detect,
+        };
+        stream::try_unfold(state, move |mut state| async move {
+            if self.cancel.as_ref().is_some_and(CancellationToken::is_cancelled) {
+                return Ok(None);
+            }
+            let page = self.next_page(&mut state, start, end).await?;
+            Ok(page.map(|page| (page, state)))
+        })
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Returns the next page of activity, or `None` once the range is exhausted.
+    async fn next_page(
+        &self,
+        state: &mut ScanState,
+        start: u64,
+        end: u64,
+    ) -> TransportResult<Option<Vec<AddressActivity>>> {
+        loop {
+            match state {
+                ScanState::Detect => {
+                    *state = ScanState::new(self.detect_strategy().await?, start, end);
+                }
+                ScanState::Otterscan { cursor } => {
+                    if *cursor >= end {
+                        return Ok(None);
+                    }
+                    let result = OtterscanApi::<N>::ots_search_transactions_after(
+                        &self.provider,
+                        self.address,
+                        *cursor,
+                        self.page_size,
+                    )
+                    .await?;
+                    let mut page = result
+                        .txs
+                        .iter()
+                        .filter_map(|tx| {
+                            let block_number = tx.block_number()?;
+                            Some(AddressActivity {
+                                block_number,
+                                transaction_hash: Some(tx.tx_hash()),
+                                strategy: ScanStrategy::Otterscan,
+                            })
+                        })
+                        .filter(|activity| activity.block_number > *cursor)
+                        .collect::<Vec<_>>();
+                    let Some(last) = page.iter().map(|activity| activity.block_number).max() else {
+                        *state = ScanState::Done;
+                        continue;
+                    };
+                    *cursor = last;
+                    // pages are ordered newest first
+                    page.reverse();
+                    page.sort_by_key(|activity| activity.block_number);
+                    page.retain(|activity| activity.block_number <= end);
+                    return Ok(Some(page));
+                }
+                ScanState::TraceFilter { next } => {
+                    if *next > end {
+                        return Ok(None);
+                    }
+                    let to = next.saturating_add(self.trace_block_span - 1).min(end);
+                    let filter = TraceFilter::default()
+                        .from_block(*next)
+                        .to_block(to)
+                        .from_address(vec![self.address])
+                        .to_address(vec![self.address]);
+                    let traces = TraceApi::<N>::trace_filter(&self.provider, &filter).await?;
+                    *next = to.saturating_add(1);
+                    if to == u64::MAX {
+                        *state = ScanState::Done;
+                    }
+
+                    let mut seen = HashSet::default();
+                    let page = traces
+                        .into_iter()
+                        .filter_map(|trace| {
+                            let activity = AddressActivity {
+                                block_number: trace.block_number?,
+                                transaction_hash: trace.transaction_hash,
+                                strategy: ScanStrategy::TraceFilter,
+                            };
+                            seen.insert(activity).then_some(activity)
+                        })
+                        .collect();
+                    return Ok(Some(page));
+                }
+                ScanState::StateBisect { ranges, states } => {
+                    let Some((lo, hi)) = ranges.pop() else {
+                        return Ok(None);
+                    };
+                    let before = match lo.checked_sub(1) {
+                        Some(parent) => self.account_state(states, parent).await?,
+                        None => (0, U256::ZERO),
+                    };
+                    if before == self.account_state(states, hi).await? {
+                        continue;
+                    }
+                    if lo == hi {
+                        return self.scan_block(lo).await.map(Some);
+                    }
+                    let mid = lo + (hi - lo) / 2;
+                    ranges.push((mid + 1, hi));
+                    ranges.push((lo, mid));
+                }
+                ScanState::Done => return Ok(None),
+            }
+        }
+    }
+
+    /// Returns the nonce and balance of the address at the given block.
+    async fn account_state(
+        &self,
+        states: &mut HashMap<u64, (u64, U256)>,
+        block: u64,
+    ) -> TransportResult<(u64, U256)> {
+        if let Some(state) = states.get(&block) {
+            return Ok(*state);
+        }
+        let state = futures::try_join!(
+            self.provider.get_transaction_count(self.address).number(block).into_future(),
+            self.provider.get_balance(self.address).number(block).into_future(),
+        )?;
+        states.insert(block, state);
+        Ok(state)
+    }
+
+    /// Returns the transactions of the block sent from or to the address.
+    async fn scan_block(&self, number: u64) -> TransportResult<Vec<AddressActivity>> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(number), BlockTransactionsKind::Full)
+            .await?;
+        let mut page = block
+            .iter()
+            .flat_map(|block| block.transactions().txns())
+            .filter(|&tx| {
+                TransactionResponse::from(tx) == self.address || tx.to() == Some(self.address)
+            })
+            .map(|tx| AddressActivity {
+                block_number: number,
+                transaction_hash: Some(tx.tx_hash()),
+                strategy: ScanStrategy::StateBisect,
+            })
+            .collect::<Vec<_>>();
+        if page.is_empty() {
+            // e.g. an internal transfer or a withdrawal
+            page.push(AddressActivity {
+                block_number: number,
+                transaction_hash: None,
+                strategy: ScanStrategy::StateBisect,
+            });
+        }
+        Ok(page)
+    }
+}
+
+#[derive(Debug)]
+enum ScanState {
+    Detect,
+    Otterscan { cursor: u64 },
+    TraceFilter { next: u64 },
+    StateBisect { ranges: Vec<(u64, u64)>, states: HashMap<u64, (u64, U256)> },
+    Done,
+}
+
+impl ScanState {
+    fn new(strategy: ScanStrategy, start: u64, end: u64) -> Self {
+        if start > end {
+            return Self::Done;
+        }
+        match strategy {
+            ScanStrategy::Otterscan => Self::Otterscan { cursor: start.saturating_sub(1) },
+            ScanStrategy::TraceFilter => Self::TraceFilter { next: start },
+            ScanStrategy::StateBisect => {
+                Self::StateBisect { ranges: vec![(start, end)], states: HashMap::default() }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use alloy_network::TransactionBuilder;
+    use alloy_rpc_types_eth::TransactionRequest;
+
+    #[tokio::test]
+    async fn scan_strategies_agree() {
+        let provider = ProviderBuilder::new().on_anvil_with_wallet();
+        let to = Address::with_last_byte(0x42);
+
+        let mut hashes = Vec::new();
+        for _ in 0..3 {
+            let tx = TransactionRequest::default().with_to(to).with_value(U256::from(1));
+            let receipt = provider.send_transaction(tx).await.unwrap().get_receipt().await.unwrap();
+            hashes.push(Some(receipt.transaction_hash));
+        }
+        let latest = provider.get_block_number().await.unwrap();
+
+        for strategy in
+            [ScanStrategy::Otterscan, ScanStrategy::TraceFilter, ScanStrategy::StateBisect]
+        {
+            let scanner: AddressScanner<_> = AddressScanner::new(&provider, to)
+                .with_strategy(strategy)
+                .with_page_size(1)
+                .with_trace_block_span(2);
+            let found = scanner
+                .scan(0..=latest)
+                .map_ok(|activity| activity.transaction_hash)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+            assert_eq!(found, hashes, "{strategy:?}");
+        }
+    }
+}
@@ -0,0 +1,457 @@
+//! Transaction simulation across node APIs.
+//!
+//! A [`SimulationBackend`] simulates a transaction and normalizes the outcome into a
+//! [`SimulationResult`], so that applications can switch between simulation providers without
+//! changing how results are handled. The following backends are available:
+//!
+//! - [`EthSimulateBackend`], using `eth_simulateV1`.
+//! - [`DebugTraceBackend`], using `debug_traceCall` with the call and prestate tracers. Requires
+//!   the `debug-api` feature.
+//! - [`TenderlyBackend`], using `tenderly_simulateTransaction`. Requires the `tenderly-api`
+//!   feature.
+
+use crate::Provider;
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Bytes, Log};
+use alloy_rpc_types_eth::{
+    simulate::{SimBlock, SimulatePayload},
+    state::EvmOverrides,
+    BlockId, TransactionRequest,
+};
+use alloy_rpc_types_tenderly::{AssetChange, BalanceChange, StateChange};
+use alloy_transport::{TransportErrorKind, TransportResult};
+use std::marker::PhantomData;
+
+#[cfg(feature = "debug-api")]
+use alloy_rpc_types_trace::geth::{CallFrame, DiffMode};
+
+/// The normalized outcome of a simulated transaction.
+///
+/// Fields that a backend cannot provide are `None`, see the documentation of each backend.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulationResult {
+    /// Whether the transaction succeeded.
+    pub success: bool,
+    /// The gas used by the transaction.
+    pub gas_used: u64,
+    /// The return data of the transaction, or its revert data if it reverted.
+    pub output: Bytes,
+    /// The error of the transaction, preferring decoded revert reasons, if it failed.
+    pub error: Option<String>,
+    /// The logs emitted by the transaction, empty if it failed.
+    pub logs: Vec<Log>,
+    /// The asset changes caused by the transaction.
+    pub asset_changes: Option<Vec<AssetChange>>,
+    /// The balance changes caused by the transaction, in US dollars.
+    pub balance_changes: Option<Vec<BalanceChange>>,
+    /// The state changes caused by the transaction.
+    pub state_changes: Option<Vec<StateChange>>,
+}
+
+impl From<alloy_rpc_types_tenderly::SimulationResult> for SimulationResult {
+    fn from(result: alloy_rpc_types_tenderly::SimulationResult) -> Self {
+        let error = result.error().map(str::to_string);
+        let output = result.trace.first().and_then(|call| call.output.clone()).unwrap_or_default();
+        Self {
+            success: result.status,
+            gas_used: result.gas_used,
+            output,
+            error,
+            logs: result.logs.into_iter().map(|log| log.raw).collect(),
+            asset_changes: Some(result.asset_changes),
+            balance_changes: Some(result.balance_changes),
+            state_changes: Some(result.state_changes),
+        }
+    }
+}
+
+/// A node API that simulates transactions.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait SimulationBackend: Send + Sync {
+    /// Simulates the transaction on top of the given block, after applying the overrides.
+    async fn simulate(
+        &self,
+        tx: TransactionRequest,
+        block: BlockId,
+        overrides: EvmOverrides,
+    ) -> TransportResult<SimulationResult>;
+}
+
+/// A [`SimulationBackend`] using `eth_simulateV1`.
+///
+/// Provides the status, gas, output and logs of the transaction, but no asset, balance or state
+/// changes.
+#[derive(Clone, Debug)]
+pub struct EthSimulateBackend<P, N = Ethereum> {
+    provider: P,
+    _network: PhantomData<N>,
+}
+
+impl<P, N> EthSimulateBackend<P, N> {
+    /// Creates a new backend simulating through the given provider.
+    pub const fn new(provider: P) -> Self {
+        Self { provider, _network: PhantomData }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<P, N> SimulationBackend for EthSimulateBackend<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    async fn simulate(
+        &self,
+        tx: TransactionRequest,
+        block: BlockId,
+        overrides: EvmOverrides,
+    ) -> TransportResult<SimulationResult> {
+        let payload = SimulatePayload::default()
+            .extend(SimBlock::default().with_evm_overrides(overrides).call(tx));
+        let blocks = self.provider.simulate(&payload).block_id(block).await?;
+        let call = blocks
+            .into_iter()
+            .next()
+            .and_then(|block| block.calls.into_iter().next())
+            .ok_or_else(|| TransportErrorKind::custom_str("eth_simulateV1 returned no call"))?;
+        Ok(SimulationResult {
+            success: call.status,
+            gas_used: call.gas_used,
+            output: call.return_data,
+            error: call.error.map(|error| error.message),
+            logs: call.logs.into_iter().map(|log| log.inner).collect(),
+            ..Default::default()
+        })
+    }
+}
+
+/// A [`SimulationBackend`] using `debug_traceCall`.
+///
+/// Runs the call tracer and the prestate tracer in diff mode concurrently. Provides the status,
+/// gas, output, logs and state changes of the transaction, but no asset or balance changes.
+#[cfg(feature = "debug-api")]
+#[derive(Clone, Debug)]
+pub struct DebugTraceBackend<P, N = Ethereum> {
+    provider: P,
+    _network: PhantomData<N>,
+}
+
+#[cfg(feature = "debug-api")]
+impl<P, N> DebugTraceBackend<P, N> {
+    /// Creates a new backend simulating through the given provider.
+    pub const fn new(provider: P) -> Self {
+        Self { provider, _network: PhantomData }
+    }
+}
+
+#[cfg(feature = "debug-api")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<P, N> SimulationBackend for DebugTraceBackend<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    async fn simulate(
+        &self,
+        tx: TransactionRequest,
+        block: BlockId,
+        overrides: EvmOverrides,
+    ) -> TransportResult<SimulationResult> {
+        use crate::ext::DebugApi;
+        use alloy_rpc_types_trace::geth::{
+            CallConfig, GethDebugTracingCallOptions, GethDebugTracingOptions, PreStateConfig,
+            PreStateFrame,
+        };
+
+        let call_options = GethDebugTracingCallOptions::new(GethDebugTracingOptions::call_tracer(
+            CallConfig::default().with_log(),
+        ))
+        .with_evm_overrides(overrides.clone());
+        let prestate_options = GethDebugTracingCallOptions::new(
+            GethDebugTracingOptions::prestate_tracer(PreStateConfig {
+                diff_mode: Some(true),
+                disable_code: Some(true),
+                ..Default::default()
+            }),
+        )
+        .with_evm_overrides(overrides);
+        let (call, prestate) = futures::try_join!(
+            self.provider.debug_trace_call(tx.clone(), block, call_options),
+            self.provider.debug_trace_call(tx, block, prestate_options),
+        )?;
+
+        let call = call.try_into_call_frame().map_err(TransportErrorKind::custom)?;
+        let PreStateFrame::Diff(diff) =
+            prestate.try_into_pre_state_frame().map_err(TransportErrorKind::custom)?
+        else {
+            return Err(TransportErrorKind::custom_str("prestate tracer returned no diff"));
+        };
+
+        Ok(SimulationResult {
+            success: call.error.is_none(),
+            gas_used: call.gas_used.saturating_to(),
+            logs: call_frame_logs(&call),
+            state_changes: Some(diff_state_changes(&diff)),
+            error: call.revert_reason.or(call.error),
+            output: call.output.unwrap_or_default(),
+            ..Default::default()
+        })
+    }
+}
+
+/// A [`SimulationBackend`] using `tenderly_simulateTransaction`.
+///
+/// Provides all fields of the [`SimulationResult`], including asset and balance changes.
+#[cfg(feature = "tenderly-api")]
+#[derive(Clone, Debug)]
+pub struct TenderlyBackend<P, N = Ethereum> {
+    provider: P,
+    _network: PhantomData<N>,
+}
+
+#[cfg(feature = "tenderly-api")]
+impl<P, N> TenderlyBackend<P, N> {
+    /// Creates a new backend simulating through the given provider, which must be connected to a
+    /// Tenderly node.
+    pub const fn new(provider: P) -> Self {
+        Self { provider, _network: PhantomData }
+    }
+}
+
+#[cfg(feature = "tenderly-api")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<P, N> SimulationBackend for TenderlyBackend<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    async fn simulate(
+        &self,
+        tx: TransactionRequest,
+        block: BlockId,
+        overrides: EvmOverrides,
+    ) -> TransportResult<SimulationResult> {
+        use crate::ext::TenderlyApi;
+
+        let request = alloy_rpc_types_tenderly::SimulationRequest::new(tx)
+            .at_block(block)
+            .with_evm_overrides(overrides);
+        self.provider.tenderly_simulate_transaction(request).await.map(Into::into)
+    }
+}
+
+/// Returns the logs of the call frame and its successful subcalls, in emission order.
+#[cfg(feature = "debug-api")]
+fn call_frame_logs(root: &CallFrame) -> Vec<Log> {
+    let mut logs = Vec::new();
+    // the frame, the number of subcalls already visited and the number of logs already emitted
+    let mut stack = vec![(root, 0, 0)];
+    while let Some((frame, call, mut log)) = stack.pop() {
+        if frame.error.is_some() {
+            continue;
+        }
+        // the position of a log is the number of subcalls made before it was emitted
+        while let Some(entry) = frame.logs.get(log) {
+            if entry.position.is_some_and(|position| position as usize > call) {
+                break;
+            }
+            logs.push(Log::new_unchecked(
+                entry.address.unwrap_or_default(),
+                entry.topics.clone().unwrap_or_default(),
+                entry.data.clone().unwrap_or_default(),
+            ));
+            log += 1;
+        }
+        if let Some(subcall) = frame.calls.get(call) {
+            stack.push((frame, call + 1, log));
+            stack.push((subcall, 0, 0));
+        }
+    }
+    logs
+}
+
+/// Returns the state changes of a prestate tracer diff.
+///
+/// Accounts only present before the transaction were destroyed, and are reported as reset.
+#[cfg(feature = "debug-api")]
+fn diff_state_changes(diff: &DiffMode) -> Vec<StateChange> {
+    use alloy_primitives::{B256, U256};
+    use alloy_rpc_types_tenderly::{StorageSlotChange, ValueChange};
+
+    let default = Default::default();
+    let addresses =
+        diff.pre.keys().chain(diff.post.keys().filter(|address| !diff.pre.contains_key(*address)));
+    addresses
+        .map(|&address| {
+            let pre = diff.pre.get(&address).unwrap_or(&default);
+            let post = diff.post.get(&address);
+            let destroyed = post.is_none();
+            let post = post.unwrap_or(&default);
+
+            let nonce = (post.nonce.is_some() || destroyed)
+                .then(|| ValueChange {
+                    previous_value: U256::from(pre.nonce.unwrap_or_default()),
+                    new_value: U256::from(post.nonce.unwrap_or_default()),
+                })
+                .filter(ValueChange::is_changed);
+            let balance = (post.balance.is_some() || destroyed)
+                .then(|| ValueChange {
+                    previous_value: pre.balance.unwrap_or_default(),
+                    new_value: post.balance.unwrap_or_default(),
+                })
+                .filter(ValueChange::is_changed);
+
+            let mut storage = post
+                .storage
+                .iter()
+                .map(|(&slot, &new_value)| StorageSlotChange {
+                    slot,
+                    previous_value: pre.storage.get(&slot).copied().unwrap_or_default(),
+                    new_value,
+                })
+                .collect::<Vec<_>>();
+            // slots cleared by the transaction are omitted from the post state
+            storage.extend(
+                pre.storage.iter().filter(|(slot, _)| !post.storage.contains_key(*slot)).map(
+                    |(&slot, &previous_value)| StorageSlotChange {
+                        slot,
+                        previous_value,
+                        new_value: B256::ZERO,
+                    },
+                ),
+            );
+            storage.retain(|change| change.previous_value != change.new_value);
+            storage.sort_by_key(|change| change.slot);
+
+            StateChange { address, nonce, balance, storage }
+        })
+        .filter(|change| {
+            change.nonce.is_some() || change.balance.is_some() || !change.storage.is_empty()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+
+    #[test]
+    fn from_tenderly() {
+        let result: alloy_rpc_types_tenderly::SimulationResult =
+            serde_json::from_value(serde_json::json!({
+                "status": false,
+                "gasUsed": "0x5208",
+                "logs": null,
+                "trace": [{
+                    "type": "CALL",
+                    "from": Address::ZERO,
+                    "gas": "0x10000",
+                    "gasUsed": "0x5208",
+                    "output": "0x08c379a0",
+                    "error": "execution reverted",
+                    "errorReason": "insufficient balance"
+                }],
+                "assetChanges": [],
+                "balanceChanges": [],
+                "stateChanges": []
+            }))
+            .unwrap();
+        let result = SimulationResult::from(result);
+        assert!(!result.success);
+        assert_eq!(result.gas_used, 21_000);
+        assert_eq!(result.output, Bytes::from_static(&[0x08, 0xc3, 0x79, 0xa0]));
+        assert_eq!(result.error.as_deref(), Some("insufficient balance"));
+        assert_eq!(result.asset_changes, Some(vec![]));
+    }
+
+    #[cfg(feature = "debug-api")]
+    #[test]
+    fn call_frame_logs_in_emission_order() {
+        use alloy_rpc_types_trace::geth::CallLogFrame;
+
+        let log = |address: Address, position: u64| CallLogFrame {
+            address: Some(address),
+            position: Some(position),
+            ..Default::default()
+        };
+        let (a, b, c) =
+            (Address::with_last_byte(1), Address::with_last_byte(2), Address::with_last_byte(3));
+        let frame = CallFrame {
+            logs: vec![log(a, 0), log(a, 1), log(a, 2)],
+            calls: vec![
+                CallFrame { logs: vec![log(b, 0)], ..Default::default() },
+                CallFrame {
+                    logs: vec![log(c, 0)],
+                    error: Some("execution reverted".to_string()),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let emitters = call_frame_logs(&frame).iter().map(|log| log.address).collect::<Vec<_>>();
+        assert_eq!(emitters, vec![a, b, a, a]);
+
+        let failed = CallFrame { error: Some("out of gas".to_string()), ..frame };
+        assert!(call_frame_logs(&failed).is_empty());
+    }
+
+    #[cfg(feature = "debug-api")]
+    #[test]
+    fn diff_to_state_changes() {
+        use alloy_primitives::{address, b256, B256, U256};
+        use alloy_rpc_types_trace::geth::AccountState;
+
+        let sender = address!("0000000000000000000000000000000000000001");
+        let token = address!("0000000000000000000000000000000000000002");
+        let destroyed = address!("0000000000000000000000000000000000000003");
+        let created = address!("0000000000000000000000000000000000000004");
+        let (slot0, slot1) = (B256::ZERO, B256::with_last_byte(1));
+        let one = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        let mut diff = DiffMode::default();
+        diff.pre.insert(
+            sender,
+            AccountState { balance: Some(U256::from(10)), nonce: Some(1), ..Default::default() },
+        );
+        diff.post.insert(
+            sender,
+            AccountState { balance: Some(U256::from(7)), nonce: Some(2), ..Default::default() },
+        );
+        diff.pre.insert(
+            token,
+            AccountState { storage: [(slot0, one), (slot1, one)].into(), ..Default::default() },
+        );
+        diff.post.insert(
+            token,
+            AccountState {
+                storage: [(slot0, B256::with_last_byte(2))].into(),
+                ..Default::default()
+            },
+        );
+        diff.pre
+            .insert(destroyed, AccountState { balance: Some(U256::from(5)), ..Default::default() });
+        diff.post.insert(created, AccountState { nonce: Some(1), ..Default::default() });
+
+        let changes = diff_state_changes(&diff);
+        assert_eq!(changes.len(), 4);
+        assert_eq!(changes[0].address, sender);
+        assert_eq!(changes[0].nonce.unwrap().new_value, U256::from(2));
+        assert_eq!(changes[0].balance.unwrap().previous_value, U256::from(10));
+
+        assert_eq!(changes[1].address, token);
+        assert_eq!(changes[1].nonce, None);
+        assert_eq!(changes[1].storage.len(), 2);
+        assert_eq!(changes[1].storage[0].new_value, B256::with_last_byte(2));
+        assert_eq!(changes[1].storage[1].new_value, B256::ZERO);
+
+        assert_eq!(changes[2].address, destroyed);
+        assert_eq!(changes[2].balance.unwrap().new_value, U256::ZERO);
+        assert_eq!(changes[3].address, created);
+        assert_eq!(changes[3].nonce.unwrap().previous_value, U256::ZERO);
+    }
+}
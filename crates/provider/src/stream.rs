@@ -0,0 +1,322 @@
+//! Block-aligned combinators for streams of chain data.
+//!
+//! Subscriptions and pollers yield blocks, headers and logs one at a time, while event-driven
+//! applications usually act once per block. [`BlockStreamExt`] adds combinators to any [`Stream`]
+//! of [`BlockItem`]s, such as subscription streams, which group, collapse or hold back items
+//! according to the block they belong to.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+//! use alloy_provider::stream::BlockStreamExt;
+//! use alloy_rpc_types_eth::Filter;
+//! use futures::{stream, StreamExt};
+//!
+//! let logs = provider.watch_logs(&Filter::new()).await?.into_stream().flat_map(stream::iter);
+//! let mut batches = logs.batch_by_block();
+//! while let Some(logs) = batches.next().await {
+//!     println!("block {:?}: {} logs", logs[0].block_number, logs.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{BlockHash, BlockNumber};
+use alloy_rpc_types_eth::{Block, Header, Log};
+use futures::{ready, Stream};
+use pin_project::pin_project;
+use std::{
+    collections::VecDeque,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// An item of chain data that belongs to a block.
+pub trait BlockItem {
+    /// Returns the number of the block the item belongs to, `None` if it is pending.
+    fn block_number(&self) -> Option<BlockNumber>;
+
+    /// Returns the hash of the block the item belongs to, if known.
+    fn block_hash(&self) -> Option<BlockHash>;
+}
+
+impl<H: BlockHeader> BlockItem for Header<H> {
+    fn block_number(&self) -> Option<BlockNumber> {
+        Some(self.inner.number())
+    }
+
+    fn block_hash(&self) -> Option<BlockHash> {
+        Some(self.hash)
+    }
+}
+
+impl<T, H: BlockItem> BlockItem for Block<T, H> {
+    fn block_number(&self) -> Option<BlockNumber> {
+        self.header.block_number()
+    }
+
+    fn block_hash(&self) -> Option<BlockHash> {
+        self.header.block_hash()
+    }
+}
+
+impl<T> BlockItem for Log<T> {
+    fn block_number(&self) -> Option<BlockNumber> {
+        self.block_number
+    }
+
+    fn block_hash(&self) -> Option<BlockHash> {
+        self.block_hash
+    }
+}
+
+/// Extension trait adding block-aligned combinators to streams of [`BlockItem`]s.
+///
+/// All combinators assume that the items of a block are yielded consecutively, as is the case
+/// for subscriptions and pollers.
+pub trait BlockStreamExt: Stream + Sized
+where
+    Self::Item: BlockItem,
+{
+    /// Collapses consecutive items of the same block number into the last one.
+    ///
+    /// An item is yielded once an item of another block arrives or the stream ends, so the
+    /// stream lags one block behind. This is useful to act once per block, and only on the
+    /// latest header if the tip was reorged before the next block arrived.
+    fn debounce_per_block(self) -> DebouncePerBlock<Self> {
+        DebouncePerBlock { stream: self, pending: None, done: false }
+    }
+
+    /// Groups consecutive items of the same block into batches.
+    ///
+    /// Items belong to the same block if both their block number and hash match, so that logs
+    /// removed by a reorg are not batched with the logs replacing them. A batch is yielded once
+    /// an item of another block arrives or the stream ends.
+    fn batch_by_block(self) -> BatchByBlock<Self> {
+        BatchByBlock { stream: self, batch: Vec::new(), done: false }
+    }
+
+    /// Holds back items until their block is finalized.
+    ///
+    /// `finalized` yields the number of the latest finalized block, e.g. by fetching the
+    /// `finalized` block for each new head. Items are yielded in order once their block number
+    /// is at most the latest finalized block number. Items without a block number are yielded
+    /// as soon as all items before them are.
+    ///
+    /// The stream ends when this stream ends and all items were yielded, or when `finalized`
+    /// ends, dropping the items that were not finalized yet.
+    fn until_finalized<F>(self, finalized: F) -> UntilFinalized<Self, F>
+    where
+        F: Stream<Item = BlockNumber>,
+    {
+        UntilFinalized {
+            stream: self,
+            finalized,
+            latest: None,
+            buffer: VecDeque::new(),
+            stream_done: false,
+            finalized_done: false,
+        }
+    }
+}
+
+impl<S> BlockStreamExt for S
+where
+    S: Stream,
+    S::Item: BlockItem,
+{
+}
+
+/// Stream for [`BlockStreamExt::debounce_per_block`].
+#[pin_project]
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct DebouncePerBlock<S: Stream> {
+    #[pin]
+    stream: S,
+    pending: Option<S::Item>,
+    done: bool,
+}
+
+impl<S> Stream for DebouncePerBlock<S>
+where
+    S: Stream,
+    S::Item: BlockItem,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        while !*this.done {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let number = item.block_number();
+                    if let Some(previous) = this.pending.replace(item) {
+                        if previous.block_number() != number {
+                            return Poll::Ready(Some(previous));
+                        }
+                    }
+                }
+                None => *this.done = true,
+            }
+        }
+        Poll::Ready(this.pending.take())
+    }
+}
+
+/// Stream for [`BlockStreamExt::batch_by_block`].
+#[pin_project]
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct BatchByBlock<S: Stream> {
+    #[pin]
+    stream: S,
+    batch: Vec<S::Item>,
+    done: bool,
+}
+
+impl<S> Stream for BatchByBlock<S>
+where
+    S: Stream,
+    S::Item: BlockItem,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        while !*this.done {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let same_block = this.batch.first().map_or(true, |first| {
+                        first.block_number() == item.block_number()
+                            && first.block_hash() == item.block_hash()
+                    });
+                    if !same_block {
+                        return Poll::Ready(Some(mem::replace(this.batch, vec![item])));
+                    }
+                    this.batch.push(item);
+                }
+                None => *this.done = true,
+            }
+        }
+        Poll::Ready((!this.batch.is_empty()).then(|| mem::take(this.batch)))
+    }
+}
+
+/// Stream for [`BlockStreamExt::until_finalized`].
+#[pin_project]
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct UntilFinalized<S: Stream, F> {
+    #[pin]
+    stream: S,
+    #[pin]
+    finalized: F,
+    latest: Option<BlockNumber>,
+    buffer: VecDeque<S::Item>,
+    stream_done: bool,
+    finalized_done: bool,
+}
+
+impl<S, F> Stream for UntilFinalized<S, F>
+where
+    S: Stream,
+    S::Item: BlockItem,
+    F: Stream<Item = BlockNumber>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while !*this.finalized_done {
+            match this.finalized.as_mut().poll_next(cx) {
+                Poll::Ready(Some(number)) => {
+                    *this.latest = Some(this.latest.map_or(number, |latest| latest.max(number)));
+                }
+                Poll::Ready(None) => *this.finalized_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        while !*this.stream_done {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => this.buffer.push_back(item),
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        let latest = *this.latest;
+        let is_finalized = |item: &S::Item| {
+            item.block_number().map_or(true, |number| latest.is_some_and(|latest| number <= latest))
+        };
+        match this.buffer.front() {
+            Some(item) if is_finalized(item) => Poll::Ready(this.buffer.pop_front()),
+            Some(_) if *this.finalized_done => Poll::Ready(None),
+            None if *this.stream_done => Poll::Ready(None),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use futures::{stream, StreamExt};
+
+    fn log(number: u64, hash: u8) -> Log {
+        Log {
+            block_number: Some(number),
+            block_hash: Some(B256::repeat_byte(hash)),
+            ..Default::default()
+        }
+    }
+
+    fn numbers(logs: &[Log]) -> Vec<u64> {
+        logs.iter().map(|log| log.block_number.unwrap()).collect()
+    }
+
+    #[tokio::test]
+    async fn debounce_per_block() {
+        let logs = vec![log(1, 1), log(1, 2), log(2, 1), log(3, 1), log(3, 3)];
+        let debounced = stream::iter(logs).debounce_per_block().collect::<Vec<_>>().await;
+        assert_eq!(debounced, vec![log(1, 2), log(2, 1), log(3, 3)]);
+
+        let empty = stream::iter(Vec::<Log>::new()).debounce_per_block();
+        assert!(empty.collect::<Vec<_>>().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn batch_by_block() {
+        let logs = vec![log(1, 1), log(1, 1), log(2, 1), log(2, 2), log(2, 2), log(3, 1)];
+        let batches = stream::iter(logs).batch_by_block().collect::<Vec<_>>().await;
+        assert_eq!(
+            batches.iter().map(|batch| numbers(batch)).collect::<Vec<_>>(),
+            vec![vec![1, 1], vec![2], vec![2, 2], vec![3]]
+        );
+        assert_eq!(batches[2][0].block_hash, Some(B256::repeat_byte(2)));
+    }
+
+    #[tokio::test]
+    async fn until_finalized() {
+        let logs = vec![log(1, 1), Log::default(), log(2, 1), log(3, 1)];
+        let finalized = stream::iter(vec![1, 2]);
+        let released = stream::iter(logs).until_finalized(finalized).collect::<Vec<_>>().await;
+        assert_eq!(released, vec![log(1, 1), Log::default(), log(2, 1)]);
+
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut released = stream::iter(vec![log(1, 1), log(2, 1)]).until_finalized(rx);
+        assert!(futures::poll!(released.next()).is_pending());
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(released.next().await, Some(log(1, 1)));
+        assert!(futures::poll!(released.next()).is_pending());
+        tx.unbounded_send(2).unwrap();
+        drop(tx);
+        assert_eq!(released.collect::<Vec<_>>().await, vec![log(2, 1)]);
+    }
+}
@@ -0,0 +1,276 @@
+//! Time series of chain data.
+//!
+//! Chain data is indexed by block, while analytics usually need values aligned to wall-clock
+//! time. [`BlockTimestamps`] joins streams of per-block values with the timestamps of their
+//! blocks, yielding [`Point`]s, and [`TimeSeriesExt::resample`] aligns points to fixed intervals.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+//! use alloy_primitives::address;
+//! use alloy_provider::timeseries::{BlockTimestamps, TimeSeriesExt};
+//! use futures::{stream, StreamExt, TryStreamExt};
+//!
+//! let address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+//! let mut balances = Vec::new();
+//! for block in (20_000_000..20_001_000).step_by(10) {
+//!     balances.push((block, provider.get_balance(address).number(block).await?));
+//! }
+//!
+//! // hourly balances
+//! let timestamps = BlockTimestamps::new(&provider);
+//! let points: Vec<_> = timestamps.points(stream::iter(balances)).try_collect().await?;
+//! let hourly: Vec<_> = stream::iter(points).resample(3600).collect().await;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Provider;
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_network::{Ethereum, Network};
+use alloy_network_primitives::BlockResponse;
+use alloy_primitives::BlockNumber;
+use alloy_rpc_types_eth::BlockTransactionsKind;
+use alloy_transport::TransportResult;
+use futures::{ready, Stream, StreamExt};
+use pin_project::pin_project;
+use schnellru::{ByLength, LruMap};
+use std::{
+    collections::VecDeque,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+};
+
+/// A value at a point in time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point<T> {
+    /// The number of the block the value was observed at.
+    pub block_number: BlockNumber,
+    /// The timestamp of the point, in seconds since the Unix epoch.
+    ///
+    /// This is the timestamp of the block, or the interval boundary for
+    /// [resampled](TimeSeriesExt::resample) points.
+    pub timestamp: u64,
+    /// The value.
+    pub value: T,
+}
+
+/// Looks up the timestamps of blocks, caching them in an LRU cache.
+///
+/// The cache is shared between clones.
+#[derive(Clone, Debug)]
+pub struct BlockTimestamps<P, N = Ethereum> {
+    provider: P,
+    cache: Arc<Mutex<LruMap<BlockNumber, u64>>>,
+    concurrency: usize,
+    _network: PhantomData<fn() -> N>,
+}
+
+impl<P, N> BlockTimestamps<P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// The default number of cached timestamps.
+    pub const DEFAULT_CAPACITY: u32 = 10_000;
+
+    /// The default number of concurrent header lookups of [`points`](Self::points).
+    pub const DEFAULT_CONCURRENCY: usize = 8;
+
+    /// Creates a new timestamp lookup.
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider,
+            cache: Arc::new(Mutex::new(LruMap::new(ByLength::new(Self::DEFAULT_CAPACITY)))),
+            concurrency: Self::DEFAULT_CONCURRENCY,
+            _network: PhantomData,
+        }
+    }
+
+    /// Sets the number of cached timestamps, clearing the cache.
+    pub fn with_capacity(mut self, capacity: u32) -> Self {
+        self.cache = Arc::new(Mutex::new(LruMap::new(ByLength::new(capacity))));
+        self
+    }
+
+    /// Sets the number of concurrent header lookups of [`points`](Self::points).
+    pub const fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Returns the timestamp of the block, in seconds since the Unix epoch.
+    pub async fn timestamp(&self, block: BlockNumber) -> TransportResult<u64> {
+        if let Some(timestamp) = self.lock().get(&block) {
+            return Ok(*timestamp);
+        }
+        let timestamp = self
+            .provider
+            .require_block(BlockId::number(block), BlockTransactionsKind::Hashes)
+            .await?
+            .header()
+            .timestamp();
+        self.lock().insert(block, timestamp);
+        Ok(timestamp)
+    }
+
+    /// Joins a stream of block numbers and values with the timestamps of the blocks.
+    ///
+    /// Points are yielded in the order of the stream, with up to
+    /// [`with_concurrency`](Self::with_concurrency) timestamps looked up concurrently.
+    pub fn points<'a, S, T>(
+        &'a self,
+        stream: S,
+    ) -> impl Stream<Item = TransportResult<Point<T>>> + 'a
+    where
+        S: Stream<Item = (BlockNumber, T)> + 'a,
+        T: 'a,
+    {
+        stream
+            .map(move |(block_number, value)| async move {
+                let timestamp = self.timestamp(block_number).await?;
+                Ok(Point { block_number, timestamp, value })
+            })
+            .buffered(self.concurrency.max(1))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruMap<BlockNumber, u64>> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Extension trait adding time series combinators to streams of [`Point`]s.
+pub trait TimeSeriesExt<T>: Stream<Item = Point<T>> + Sized {
+    /// Resamples the points to a fixed interval, in seconds.
+    ///
+    /// Yields a point at every multiple of the interval from the timestamp of the first point to
+    /// the timestamp of the last point, with the value of the latest point at or before it, i.e.
+    /// the value observed at that time. The points must be ordered by timestamp. An interval of
+    /// `0` is treated as `1`.
+    fn resample(self, interval: u64) -> Resample<Self, T>
+    where
+        T: Clone,
+    {
+        Resample {
+            stream: self,
+            interval: interval.max(1),
+            next: 0,
+            last: None,
+            buffer: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+impl<S, T> TimeSeriesExt<T> for S where S: Stream<Item = Point<T>> {}
+
+/// Stream for [`TimeSeriesExt::resample`].
+#[pin_project]
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Resample<S, T> {
+    #[pin]
+    stream: S,
+    interval: u64,
+    /// The next interval boundary to yield.
+    next: u64,
+    last: Option<Point<T>>,
+    buffer: VecDeque<Point<T>>,
+    done: bool,
+}
+
+impl<S, T> Stream for Resample<S, T>
+where
+    S: Stream<Item = Point<T>>,
+    T: Clone,
+{
+    type Item = Point<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(point) = this.buffer.pop_front() {
+                return Poll::Ready(Some(point));
+            }
+            if *this.done {
+                return Poll::Ready(None);
+            }
+
+            let Some(point) = ready!(this.stream.as_mut().poll_next(cx)) else {
+                *this.done = true;
+                // the boundary at the timestamp of the last point takes its value
+                if let Some(last) = this.last.take().filter(|last| last.timestamp == *this.next) {
+                    this.buffer.push_back(Point { timestamp: *this.next, ..last });
+                }
+                continue;
+            };
+
+            match this.last.take() {
+                Some(last) => {
+                    while *this.next < point.timestamp {
+                        this.buffer.push_back(Point {
+                            timestamp: *this.next,
+                            block_number: last.block_number,
+                            value: last.value.clone(),
+                        });
+                        *this.next = this.next.saturating_add(*this.interval);
+                    }
+                }
+                None => {
+                    *this.next = point.timestamp.div_ceil(*this.interval) * *this.interval;
+                }
+            }
+            *this.last = Some(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use futures::{stream, TryStreamExt};
+
+    fn point(block_number: u64, timestamp: u64) -> Point<u64> {
+        Point { block_number, timestamp, value: block_number * 10 }
+    }
+
+    #[tokio::test]
+    async fn resample() {
+        let points = [point(1, 105), point(2, 125), point(3, 130), point(4, 131)];
+        let resampled: Vec<_> = stream::iter(points).resample(10).collect().await;
+        assert_eq!(
+            resampled,
+            [
+                Point { block_number: 1, timestamp: 110, value: 10 },
+                Point { block_number: 1, timestamp: 120, value: 10 },
+                Point { block_number: 3, timestamp: 130, value: 30 },
+            ]
+        );
+
+        let resampled: Vec<_> = stream::iter([point(1, 100)]).resample(10).collect().await;
+        assert_eq!(resampled, [point(1, 100)]);
+    }
+
+    #[tokio::test]
+    async fn points() {
+        let provider = ProviderBuilder::new().on_anvil();
+        let genesis = provider.get_block(0.into(), BlockTransactionsKind::Hashes).await.unwrap();
+        let timestamp = genesis.unwrap().header.timestamp;
+
+        let timestamps = BlockTimestamps::new(&provider);
+        let points: Vec<_> =
+            timestamps.points(stream::iter([(0, "a"), (0, "b")])).try_collect().await.unwrap();
+        assert_eq!(
+            points,
+            [
+                Point { block_number: 0, timestamp, value: "a" },
+                Point { block_number: 0, timestamp, value: "b" },
+            ]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Provider-related utilities.
 
+use alloy_chains::{Chain, NamedChain};
 use alloy_primitives::{U128, U64};
 
 use crate::{
@@ -62,6 +63,130 @@ pub fn eip1559_default_estimator(
     }
 }
 
+/// A source for estimating `maxPriorityFeePerGas`, used in a [`PriorityFeeEstimator`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PriorityFeeSource {
+    /// Query the node via `eth_maxPriorityFeePerGas`.
+    MaxPriorityFeePerGas,
+    /// Compute the median of the given reward percentile over the last `blocks` blocks, as
+    /// returned by `eth_feeHistory`. Blocks without rewards (e.g. empty blocks) are ignored.
+    FeeHistory {
+        /// The number of past blocks to consider.
+        blocks: u64,
+        /// The reward percentile to request.
+        percentile: f64,
+    },
+    /// A static priority fee.
+    Static(u128),
+}
+
+/// A configurable `maxPriorityFeePerGas` estimation stack.
+///
+/// The [sources](PriorityFeeSource) are tried in order, falling back to the next one if a source
+/// fails or yields no usable (non-zero) value. The resulting estimate is then clamped between the
+/// configured floor and ceiling. If every source fails, the floor is used.
+///
+/// Per-chain defaults are available via [`PriorityFeeEstimator::for_chain`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriorityFeeEstimator {
+    sources: Vec<PriorityFeeSource>,
+    floor: u128,
+    ceiling: Option<u128>,
+}
+
+impl Default for PriorityFeeEstimator {
+    fn default() -> Self {
+        Self {
+            sources: vec![
+                PriorityFeeSource::MaxPriorityFeePerGas,
+                PriorityFeeSource::FeeHistory {
+                    blocks: EIP1559_FEE_ESTIMATION_PAST_BLOCKS,
+                    percentile: EIP1559_FEE_ESTIMATION_REWARD_PERCENTILE,
+                },
+            ],
+            floor: EIP1559_MIN_PRIORITY_FEE,
+            ceiling: None,
+        }
+    }
+}
+
+impl PriorityFeeEstimator {
+    /// Creates a new estimator with the given sources, no ceiling, and a floor of
+    /// [`EIP1559_MIN_PRIORITY_FEE`].
+    pub fn new(sources: impl IntoIterator<Item = PriorityFeeSource>) -> Self {
+        Self { sources: sources.into_iter().collect(), ..Default::default() }
+    }
+
+    /// Returns the default estimator for the given chain.
+    ///
+    /// - OP-stack chains use a lower floor, as most blocks are far from full.
+    /// - Arbitrum chains ignore the priority fee entirely, so it is always zero.
+    /// - Polygon chains enforce a minimum priority fee of 30 gwei.
+    pub fn for_chain(chain: Chain) -> Self {
+        use NamedChain as C;
+
+        let Some(named) = chain.named() else { return Self::default() };
+        match named {
+            C::Arbitrum | C::ArbitrumNova | C::ArbitrumSepolia => {
+                Self::new([PriorityFeeSource::Static(0)]).with_floor(0).with_ceiling(Some(0))
+            }
+            C::Optimism | C::OptimismSepolia | C::Base | C::BaseSepolia => {
+                Self::default().with_floor(1_000)
+            }
+            C::Polygon | C::PolygonAmoy => Self::default().with_floor(30_000_000_000),
+            _ => Self::default(),
+        }
+    }
+
+    /// Returns the sources, in the order they are tried.
+    pub fn sources(&self) -> &[PriorityFeeSource] {
+        &self.sources
+    }
+
+    /// Appends a source to try after the existing ones.
+    pub fn with_source(mut self, source: PriorityFeeSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Returns the floor.
+    pub const fn floor(&self) -> u128 {
+        self.floor
+    }
+
+    /// Sets the floor.
+    pub const fn with_floor(mut self, floor: u128) -> Self {
+        self.floor = floor;
+        self
+    }
+
+    /// Returns the ceiling, if any.
+    pub const fn ceiling(&self) -> Option<u128> {
+        self.ceiling
+    }
+
+    /// Sets the ceiling.
+    pub const fn with_ceiling(mut self, ceiling: Option<u128>) -> Self {
+        self.ceiling = ceiling;
+        self
+    }
+
+    /// Clamps the given priority fee between the floor and the ceiling.
+    pub fn clamp(&self, priority_fee: u128) -> u128 {
+        let priority_fee = priority_fee.max(self.floor);
+        self.ceiling.map_or(priority_fee, |ceiling| priority_fee.min(ceiling))
+    }
+
+    /// Computes the fee history source estimate from the given rewards. Returns `None` if no block
+    /// had a non-zero reward.
+    pub fn fee_history_estimate(rewards: &[Vec<u128>]) -> Option<u128> {
+        rewards
+            .iter()
+            .any(|r| r.first().is_some_and(|r| *r > 0))
+            .then(|| estimate_priority_fee(rewards))
+    }
+}
+
 /// Convert `U128` to `u128`.
 pub(crate) fn convert_u128(r: U128) -> u128 {
     r.to::<u128>()
@@ -105,6 +230,24 @@ mod tests {
         assert_eq!(super::estimate_priority_fee(&[]), EIP1559_MIN_PRIORITY_FEE);
     }
 
+    #[test]
+    fn test_priority_fee_estimator() {
+        let estimator = PriorityFeeEstimator::default().with_floor(10).with_ceiling(Some(100));
+        assert_eq!(estimator.clamp(1), 10);
+        assert_eq!(estimator.clamp(50), 50);
+        assert_eq!(estimator.clamp(1_000), 100);
+
+        assert_eq!(PriorityFeeEstimator::fee_history_estimate(&[vec![0], vec![0]]), None);
+        assert_eq!(PriorityFeeEstimator::fee_history_estimate(&[vec![0], vec![7]]), Some(7));
+
+        let arbitrum = PriorityFeeEstimator::for_chain(NamedChain::Arbitrum.into());
+        assert_eq!(arbitrum.clamp(1_000), 0);
+        let polygon = PriorityFeeEstimator::for_chain(NamedChain::Polygon.into());
+        assert_eq!(polygon.clamp(1), 30_000_000_000);
+        let mainnet = PriorityFeeEstimator::for_chain(NamedChain::Mainnet.into());
+        assert_eq!(mainnet, PriorityFeeEstimator::default());
+    }
+
     #[test]
     fn test_eip1559_default_estimator() {
         let base_fee_per_gas = 1_000_000_000_u128;
@@ -0,0 +1,264 @@
+use crate::Subscription;
+use alloy_transport::utils::Spawnable;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+
+/// What a subscription bridge does when it falls behind the subscription and
+/// notifications are dropped.
+///
+/// Bridges forward notifications as fast as their destination channel accepts
+/// them. If the destination applies backpressure for too long, the underlying
+/// subscription channel overflows and the oldest notifications are lost.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LagPolicy {
+    /// Log a warning and continue with the oldest notification still
+    /// available.
+    #[default]
+    Skip,
+    /// Stop forwarding, closing the destination channel.
+    Stop,
+}
+
+/// A handle to a task forwarding a [`Subscription`] into a channel.
+///
+/// Dropping the handle detaches the task, which then runs until the
+/// subscription or the destination channel is closed. Use
+/// [`BridgeHandle::stop`] to stop forwarding early.
+#[derive(Debug)]
+pub struct BridgeHandle {
+    shutdown: oneshot::Sender<()>,
+}
+
+impl BridgeHandle {
+    /// Returns `true` if the forwarding task has finished.
+    pub fn is_finished(&self) -> bool {
+        self.shutdown.is_closed()
+    }
+
+    /// Stops the forwarding task. Notifications that were already forwarded
+    /// remain in the destination channel.
+    pub fn stop(self) {
+        let _ = self.shutdown.send(());
+    }
+}
+
+impl<T> Subscription<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    /// Forwards all notifications into the given [`broadcast::Sender`].
+    ///
+    /// Notifications are forwarded even while the sender has no receivers, so
+    /// consumers may [`subscribe`] at any time.
+    ///
+    /// [`subscribe`]: broadcast::Sender::subscribe
+    pub fn forward_to_broadcast(self, tx: broadcast::Sender<T>, policy: LagPolicy) -> BridgeHandle {
+        self.spawn_bridge(policy, move |item| {
+            let _ = tx.send(item);
+            async { true }
+        })
+    }
+
+    /// Forwards all notifications into the given [`mpsc::Sender`], stopping
+    /// when the receiver is dropped.
+    pub fn forward_to_mpsc(self, tx: mpsc::Sender<T>, policy: LagPolicy) -> BridgeHandle {
+        self.spawn_bridge(policy, move |item| {
+            let tx = tx.clone();
+            async move { tx.send(item).await.is_ok() }
+        })
+    }
+
+    /// Forwards all notifications into the given [`watch::Sender`], stopping
+    /// when all receivers are dropped.
+    pub fn forward_to_watch(self, tx: watch::Sender<T>, policy: LagPolicy) -> BridgeHandle
+    where
+        T: Sync,
+    {
+        self.spawn_bridge(policy, move |item| {
+            let sent = tx.send(item).is_ok();
+            async move { sent }
+        })
+    }
+
+    /// Creates a broadcast channel with the given capacity and forwards all
+    /// notifications into it.
+    ///
+    /// Returns the sender, which can be used to create any number of
+    /// receivers, and the handle of the forwarding task.
+    pub fn into_broadcast(
+        self,
+        capacity: usize,
+        policy: LagPolicy,
+    ) -> (broadcast::Sender<T>, BridgeHandle)
+    where
+        T: Clone,
+    {
+        let (tx, _) = broadcast::channel(capacity);
+        let handle = self.forward_to_broadcast(tx.clone(), policy);
+        (tx, handle)
+    }
+
+    /// Creates an mpsc channel with the given capacity and forwards all
+    /// notifications into it.
+    pub fn into_mpsc(
+        self,
+        capacity: usize,
+        policy: LagPolicy,
+    ) -> (mpsc::Receiver<T>, BridgeHandle) {
+        let (tx, rx) = mpsc::channel(capacity);
+        (rx, self.forward_to_mpsc(tx, policy))
+    }
+
+    /// Creates a watch channel holding `initial` and updates it with every
+    /// notification, so consumers only observe the latest one.
+    pub fn into_watch(self, initial: T, policy: LagPolicy) -> (watch::Receiver<T>, BridgeHandle)
+    where
+        T: Sync,
+    {
+        let (tx, rx) = watch::channel(initial);
+        (rx, self.forward_to_watch(tx, policy))
+    }
+
+    /// Spawns a task that receives notifications and passes them to `send`
+    /// until it returns `false`, the subscription closes, or the handle is
+    /// stopped.
+    fn spawn_bridge<F, Fut>(mut self, policy: LagPolicy, mut send: F) -> BridgeHandle
+    where
+        F: FnMut(T) -> Fut + Send + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        let (shutdown, mut shutdown_rx) = oneshot::channel();
+        let id = *self.local_id();
+
+        let fut = async move {
+            let mut detached = false;
+            loop {
+                let item = tokio::select! {
+                    biased;
+                    res = &mut shutdown_rx, if !detached => {
+                        if res.is_ok() {
+                            debug!(%id, "subscription bridge stopped");
+                            break;
+                        }
+                        detached = true;
+                        continue;
+                    }
+                    item = self.recv() => item,
+                };
+
+                match item {
+                    Ok(item) => {
+                        if !send(item).await {
+                            debug!(%id, "subscription bridge destination closed");
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => match policy {
+                        LagPolicy::Skip => {
+                            warn!(%id, skipped, "subscription bridge lagged");
+                        }
+                        LagPolicy::Stop => {
+                            warn!(%id, skipped, "subscription bridge lagged, stopping");
+                            break;
+                        }
+                    },
+                    Err(broadcast::error::RecvError::Closed) => {
+                        debug!(%id, "subscription closed, stopping bridge");
+                        break;
+                    }
+                }
+            }
+        };
+        fut.spawn_task();
+
+        BridgeHandle { shutdown }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RawSubscription;
+    use alloy_primitives::B256;
+    use alloy_transport::utils::to_json_raw_value;
+    use serde_json::value::RawValue;
+
+    fn subscription(capacity: usize) -> (broadcast::Sender<Box<RawValue>>, Subscription<u64>) {
+        let (tx, rx) = broadcast::channel(capacity);
+        (tx, RawSubscription { rx, local_id: B256::ZERO }.into())
+    }
+
+    fn notify(tx: &broadcast::Sender<Box<RawValue>>, item: u64) {
+        tx.send(to_json_raw_value(&item).unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn forwards_into_channels() {
+        let (sub_tx, sub) = subscription(16);
+        let (tx, handle) = sub.into_broadcast(16, LagPolicy::Skip);
+        let mut rx = tx.subscribe();
+        notify(&sub_tx, 1);
+        notify(&sub_tx, 2);
+        assert_eq!(rx.recv().await.unwrap(), 1);
+        assert_eq!(rx.recv().await.unwrap(), 2);
+        drop((tx, sub_tx));
+        assert!(rx.recv().await.is_err());
+        assert!(handle.is_finished());
+
+        let (sub_tx, sub) = subscription(16);
+        let (mut rx, _handle) = sub.into_mpsc(16, LagPolicy::Skip);
+        notify(&sub_tx, 1);
+        notify(&sub_tx, 2);
+        assert_eq!(rx.recv().await, Some(1));
+        assert_eq!(rx.recv().await, Some(2));
+        drop(sub_tx);
+        assert_eq!(rx.recv().await, None);
+
+        let (sub_tx, sub) = subscription(16);
+        let (mut rx, _handle) = sub.into_watch(0, LagPolicy::Skip);
+        notify(&sub_tx, 1);
+        rx.changed().await.unwrap();
+        assert_eq!(*rx.borrow_and_update(), 1);
+        drop(sub_tx);
+        assert!(rx.changed().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn lag_policies() {
+        // the subscription overflows before the bridge receives anything
+        let (sub_tx, sub) = subscription(2);
+        (1..=4).for_each(|item| notify(&sub_tx, item));
+        let (mut rx, _handle) = sub.into_mpsc(16, LagPolicy::Skip);
+        assert_eq!(rx.recv().await, Some(3));
+        assert_eq!(rx.recv().await, Some(4));
+        notify(&sub_tx, 5);
+        assert_eq!(rx.recv().await, Some(5));
+
+        let (sub_tx, sub) = subscription(2);
+        (1..=4).for_each(|item| notify(&sub_tx, item));
+        let (mut rx, handle) = sub.into_mpsc(16, LagPolicy::Stop);
+        assert_eq!(rx.recv().await, None);
+        assert!(handle.is_finished());
+    }
+
+    #[tokio::test]
+    async fn stop_bridge() {
+        let (sub_tx, sub) = subscription(16);
+        let (mut rx, handle) = sub.into_mpsc(16, LagPolicy::Skip);
+        notify(&sub_tx, 1);
+        assert_eq!(rx.recv().await, Some(1));
+        assert!(!handle.is_finished());
+
+        handle.stop();
+        notify(&sub_tx, 2);
+        assert_eq!(rx.recv().await, None);
+
+        // dropping the handle detaches the task instead
+        let (sub_tx, sub) = subscription(16);
+        let (mut rx, handle) = sub.into_mpsc(16, LagPolicy::Skip);
+        drop(handle);
+        notify(&sub_tx, 1);
+        assert_eq!(rx.recv().await, Some(1));
+    }
+}
@@ -1,5 +1,6 @@
-use crate::{handle::ConnectionHandle, service::PubSubService, PubSubFrontend};
+use crate::{handle::ConnectionHandle, service::PubSubService, PubSubFrontend, ReconnectPolicy};
 use alloy_transport::{impl_future, TransportResult};
+use std::time::Duration;
 
 /// Configuration objects that contain connection details for a backend.
 ///
@@ -26,6 +27,37 @@ pub trait PubSubConnect: Sized + Send + Sync + 'static {
         self.connect()
     }
 
+    /// The policy for reconnecting when the backend fails.
+    ///
+    /// Each attempt calls [`PubSubConnect::try_reconnect`]. Defaults to
+    /// [`ReconnectPolicy::default`], which attempts to reconnect once without
+    /// retrying.
+    fn reconnect_policy(&self) -> ReconnectPolicy {
+        ReconnectPolicy::default()
+    }
+
+    /// The maximum number of connections that subscriptions may be sharded
+    /// across.
+    ///
+    /// When the server rejects a subscription because the connection reached
+    /// its subscription limit, the service opens an additional connection via
+    /// [`PubSubConnect::connect`] and retries the subscription there, up to
+    /// this many connections in total. Defaults to `1`, which disables
+    /// sharding.
+    fn max_shards(&self) -> usize {
+        1
+    }
+
+    /// The response latency above which the connection is considered degraded.
+    ///
+    /// The service emits [`ConnectionEvent::Degraded`] when a response takes
+    /// longer than this. Defaults to 10 seconds.
+    ///
+    /// [`ConnectionEvent::Degraded`]: crate::ConnectionEvent::Degraded
+    fn degraded_latency(&self) -> Duration {
+        Duration::from_secs(10)
+    }
+
     /// Convert the configuration object into a service with a running backend.
     fn into_service(self) -> impl_future!(<Output = TransportResult<PubSubFrontend>>) {
         PubSubService::connect(self)
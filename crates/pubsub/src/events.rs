@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+/// The number of connection events buffered for each receiver.
+pub(crate) const EVENT_CHANNEL_SIZE: usize = 16;
+
+/// A lifecycle event of a pubsub connection.
+///
+/// Events are broadcast by the pubsub service, see [`PubSubFrontend::connection_events`]. When
+/// the backend fails, the service emits [`Reconnecting`], followed by [`Connected`] and
+/// [`Resubscribed`] once the new connection is up. Failed attempts are retried according to
+/// the [`ReconnectPolicy`] of the connector, emitting [`Reconnecting`] for each attempt, and
+/// [`Dropped`] is emitted once all attempts failed.
+///
+/// [`PubSubFrontend::connection_events`]: crate::PubSubFrontend::connection_events
+/// [`ReconnectPolicy`]: crate::ReconnectPolicy
+/// [`Reconnecting`]: Self::Reconnecting
+/// [`Connected`]: Self::Connected
+/// [`Resubscribed`]: Self::Resubscribed
+/// [`Dropped`]: Self::Dropped
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConnectionEvent {
+    /// The connection was re-established, or recovered after being degraded.
+    Connected,
+    /// A response took longer than [`PubSubConnect::degraded_latency`]. Contains the latency of
+    /// the response.
+    ///
+    /// Emitted once until the connection recovers, which is signaled by [`Connected`].
+    ///
+    /// [`PubSubConnect::degraded_latency`]: crate::PubSubConnect::degraded_latency
+    /// [`Connected`]: Self::Connected
+    Degraded(Duration),
+    /// The backend failed and the service is reconnecting. Contains the number of consecutive
+    /// reconnection attempts without receiving a message in between, starting at 1.
+    Reconnecting(u32),
+    /// Active subscriptions were re-issued on the new connection. Contains the number of
+    /// subscriptions.
+    Resubscribed(usize),
+    /// The service failed to reconnect and shut down. Contains the reason.
+    Dropped(String),
+}
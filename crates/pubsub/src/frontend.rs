@@ -1,5 +1,7 @@
-use crate::{ix::PubSubInstruction, managers::InFlight, RawSubscription};
-use alloy_json_rpc::{RequestPacket, Response, ResponsePacket, SerializedRequest};
+use crate::{
+    ix::PubSubInstruction, managers::InFlight, ConnectionEvent, RawSubscription, ShutdownReport,
+};
+use alloy_json_rpc::{Id, RequestPacket, Response, ResponsePacket, SerializedRequest};
 use alloy_primitives::B256;
 use alloy_transport::{TransportError, TransportErrorKind, TransportFut, TransportResult};
 use futures::{future::try_join_all, FutureExt, TryFutureExt};
@@ -7,8 +9,9 @@ use std::{
     future::Future,
     sync::atomic::{AtomicUsize, Ordering},
     task::{Context, Poll},
+    time::Duration,
 };
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{broadcast, mpsc, oneshot};
 
 /// A `PubSubFrontend` is [`Transport`] composed of a channel to a running
 /// PubSub service.
@@ -17,6 +20,9 @@ use tokio::sync::{mpsc, oneshot};
 #[derive(Debug)]
 pub struct PubSubFrontend {
     tx: mpsc::UnboundedSender<PubSubInstruction>,
+    /// The sender of the service's connection events, used to subscribe to
+    /// them.
+    events: broadcast::Sender<ConnectionEvent>,
     /// The number of items to buffer in new subscription channels. Defaults to
     /// 16. See [`tokio::sync::broadcast::channel`] for a description.
     channel_size: AtomicUsize,
@@ -25,14 +31,35 @@ pub struct PubSubFrontend {
 impl Clone for PubSubFrontend {
     fn clone(&self) -> Self {
         let channel_size = self.channel_size.load(Ordering::Relaxed);
-        Self { tx: self.tx.clone(), channel_size: AtomicUsize::new(channel_size) }
+        Self {
+            tx: self.tx.clone(),
+            events: self.events.clone(),
+            channel_size: AtomicUsize::new(channel_size),
+        }
     }
 }
 
 impl PubSubFrontend {
     /// Create a new frontend.
-    pub(crate) const fn new(tx: mpsc::UnboundedSender<PubSubInstruction>) -> Self {
-        Self { tx, channel_size: AtomicUsize::new(16) }
+    pub(crate) const fn new(
+        tx: mpsc::UnboundedSender<PubSubInstruction>,
+        events: broadcast::Sender<ConnectionEvent>,
+    ) -> Self {
+        Self { tx, events, channel_size: AtomicUsize::new(16) }
+    }
+
+    /// Forward an instruction to the service.
+    pub(crate) fn forward(&self, ix: PubSubInstruction) -> TransportResult<()> {
+        self.tx.send(ix).map_err(|_| TransportErrorKind::backend_gone())
+    }
+
+    /// Subscribe to the lifecycle events of the connection, including those of
+    /// its shard connections.
+    ///
+    /// Only events emitted after this call are received. The receiver can be
+    /// turned into a stream with `tokio_stream::wrappers::BroadcastStream`.
+    pub fn connection_events(&self) -> broadcast::Receiver<ConnectionEvent> {
+        self.events.subscribe()
     }
 
     /// Get the subscription ID for a local ID.
@@ -57,7 +84,32 @@ impl PubSubFrontend {
             .map_err(|_| TransportErrorKind::backend_gone())
     }
 
+    /// Shut down the service gracefully.
+    ///
+    /// Unsubscribes from all active subscriptions, waits up to `timeout` for
+    /// in-flight requests to complete, and then closes the backend. Requests
+    /// that did not complete in time are cancelled, and their waiters receive
+    /// an error. The service stops afterwards, so this frontend and all of its
+    /// clones become unusable.
+    ///
+    /// The shutdown starts when this method is called, even if the returned
+    /// future is never polled.
+    pub fn shutdown(
+        &self,
+        timeout: Duration,
+    ) -> impl Future<Output = TransportResult<ShutdownReport>> + Send + 'static {
+        let (tx, rx) = oneshot::channel();
+        let sent = self.forward(PubSubInstruction::Shutdown(timeout, tx));
+        async move {
+            sent?;
+            rx.await.map_err(|_| TransportErrorKind::backend_gone())
+        }
+    }
+
     /// Send a request.
+    ///
+    /// Dropping the returned future before the response is received cancels
+    /// the request, removing it from the service's in-flight requests.
     pub fn send(
         &self,
         req: SerializedRequest,
@@ -66,10 +118,15 @@ impl PubSubFrontend {
         let channel_size = self.channel_size.load(Ordering::Relaxed);
 
         async move {
+            let id = req.id().clone();
             let (in_flight, rx) = InFlight::new(req, channel_size);
             tx.send(PubSubInstruction::Request(in_flight))
                 .map_err(|_| TransportErrorKind::backend_gone())?;
-            rx.await.map_err(|_| TransportErrorKind::backend_gone())?
+
+            let guard = CancelGuard { tx, id: Some(id) };
+            let resp = rx.await.map_err(|_| TransportErrorKind::backend_gone());
+            guard.disarm();
+            resp?
         }
     }
 
@@ -103,6 +160,27 @@ impl PubSubFrontend {
     }
 }
 
+/// Cancels an in-flight request when dropped before the response is received.
+struct CancelGuard {
+    tx: mpsc::UnboundedSender<PubSubInstruction>,
+    id: Option<Id>,
+}
+
+impl CancelGuard {
+    /// Disarm the guard once the request is complete.
+    fn disarm(mut self) {
+        self.id = None;
+    }
+}
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        if let Some(id) = self.id.take() {
+            let _ = self.tx.send(PubSubInstruction::Cancel(id));
+        }
+    }
+}
+
 impl tower::Service<RequestPacket> for PubSubFrontend {
     type Response = ResponsePacket;
     type Error = TransportError;
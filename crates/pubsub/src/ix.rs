@@ -1,6 +1,7 @@
-use crate::{managers::InFlight, RawSubscription};
+use crate::{managers::InFlight, RawSubscription, ShutdownReport};
+use alloy_json_rpc::Id;
 use alloy_primitives::B256;
-use std::fmt;
+use std::{fmt, time::Duration};
 use tokio::sync::oneshot;
 
 /// Instructions for the pubsub service.
@@ -11,6 +12,11 @@ pub(crate) enum PubSubInstruction {
     GetSub(B256, oneshot::Sender<RawSubscription>),
     /// Unsubscribe from a subscription.
     Unsubscribe(B256),
+    /// Cancel an in-flight request whose waiter was dropped.
+    Cancel(Id),
+    /// Unsubscribe from all subscriptions, drain in-flight requests for up to
+    /// the given duration, and shut down.
+    Shutdown(Duration, oneshot::Sender<ShutdownReport>),
 }
 
 impl fmt::Debug for PubSubInstruction {
@@ -19,6 +25,8 @@ impl fmt::Debug for PubSubInstruction {
             Self::Request(arg0) => f.debug_tuple("Request").field(arg0).finish(),
             Self::GetSub(arg0, _) => f.debug_tuple("GetSub").field(arg0).finish(),
             Self::Unsubscribe(arg0) => f.debug_tuple("Unsubscribe").field(arg0).finish(),
+            Self::Cancel(arg0) => f.debug_tuple("Cancel").field(arg0).finish(),
+            Self::Shutdown(arg0, _) => f.debug_tuple("Shutdown").field(arg0).finish(),
         }
     }
 }
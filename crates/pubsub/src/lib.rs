@@ -9,9 +9,15 @@
 #[macro_use]
 extern crate tracing;
 
+mod bridge;
+pub use bridge::{BridgeHandle, LagPolicy};
+
 mod connect;
 pub use connect::PubSubConnect;
 
+mod events;
+pub use events::ConnectionEvent;
+
 mod frontend;
 pub use frontend::PubSubFrontend;
 
@@ -22,8 +28,14 @@ pub use handle::{ConnectionHandle, ConnectionInterface};
 
 mod managers;
 
+mod reconnect;
+pub use reconnect::ReconnectPolicy;
+
 mod service;
 
+mod shutdown;
+pub use shutdown::ShutdownReport;
+
 mod sub;
 pub use sub::{
     RawSubscription, SubAnyStream, SubResultStream, Subscription, SubscriptionItem,
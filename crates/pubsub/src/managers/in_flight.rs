@@ -3,6 +3,12 @@ use alloy_transport::{TransportError, TransportResult};
 use std::fmt;
 use tokio::sync::oneshot;
 
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::std::Instant;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
 /// An in-flight JSON-RPC request.
 ///
 /// This struct contains the request that was sent, as well as a channel to
@@ -16,6 +22,9 @@ pub(crate) struct InFlight {
 
     /// The channel to send the response on.
     pub(crate) tx: oneshot::Sender<TransportResult<Response>>,
+
+    /// When the request was created, used to measure the response latency.
+    pub(crate) sent_at: Instant,
 }
 
 impl fmt::Debug for InFlight {
@@ -36,7 +45,7 @@ impl InFlight {
     ) -> (Self, oneshot::Receiver<TransportResult<Response>>) {
         let (tx, rx) = oneshot::channel();
 
-        (Self { request, channel_size, tx }, rx)
+        (Self { request, channel_size, tx, sent_at: Instant::now() }, rx)
     }
 
     /// Check if the request is a subscription.
@@ -1,5 +1,5 @@
 use crate::managers::InFlight;
-use alloy_json_rpc::{Id, Response, SubId};
+use alloy_json_rpc::Id;
 use alloy_primitives::map::HashMap;
 
 /// Manages in-flight requests.
@@ -14,25 +14,33 @@ impl RequestManager {
         self.reqs.len()
     }
 
+    /// Check if there are no in-flight requests.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.reqs.is_empty()
+    }
+
     /// Get an iterator over the in-flight requests.
     pub(crate) fn iter(&self) -> impl Iterator<Item = (&Id, &InFlight)> {
         self.reqs.iter()
     }
 
+    /// Get an in-flight request by its ID.
+    pub(crate) fn get(&self, id: &Id) -> Option<&InFlight> {
+        self.reqs.get(id)
+    }
+
+    /// Remove all in-flight requests, without fulfilling them.
+    pub(crate) fn drain(&mut self) -> impl Iterator<Item = InFlight> + '_ {
+        self.reqs.drain().map(|(_, in_flight)| in_flight)
+    }
+
     /// Insert a new in-flight request.
     pub(crate) fn insert(&mut self, in_flight: InFlight) {
         self.reqs.insert(in_flight.request.id().clone(), in_flight);
     }
 
-    /// Handle a response by sending the payload to the waiter.
-    ///
-    /// If the request created a new subscription, this function returns the
-    /// subscription ID and the in-flight request for conversion to an
-    /// `ActiveSubscription`.
-    pub(crate) fn handle_response(&mut self, resp: Response) -> Option<(SubId, InFlight)> {
-        if let Some(in_flight) = self.reqs.remove(&resp.id) {
-            return in_flight.fulfill(resp);
-        }
-        None
+    /// Remove an in-flight request by its ID, without fulfilling it.
+    pub(crate) fn remove(&mut self, id: &Id) -> Option<InFlight> {
+        self.reqs.remove(id)
     }
 }
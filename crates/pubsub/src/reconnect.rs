@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+/// The policy for re-establishing a failed backend connection, see
+/// [`PubSubConnect::reconnect_policy`].
+///
+/// When the backend fails, the service attempts to reconnect immediately. If
+/// that fails, it retries up to [`max_retries`] times, waiting an
+/// exponentially increasing backoff between attempts. Once a connection is
+/// re-established, pending requests are re-issued and active subscriptions
+/// are re-started, so that [`Subscription`] streams keep yielding items. If
+/// all attempts fail, the service shuts down.
+///
+/// The service keeps handling instructions while waiting between attempts:
+/// new requests are sent once the connection is re-established, and shutting
+/// down stops the retries.
+///
+/// By default, failed reconnection attempts are not retried.
+///
+/// [`PubSubConnect::reconnect_policy`]: crate::PubSubConnect::reconnect_policy
+/// [`max_retries`]: Self::max_retries
+/// [`Subscription`]: crate::Subscription
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// The maximum number of retries after the first reconnection attempt
+    /// failed.
+    pub max_retries: u32,
+    /// The backoff before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum backoff between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    /// Attempts to reconnect once, without retries, see
+    /// [`no_retries`](Self::no_retries).
+    fn default() -> Self {
+        Self::no_retries()
+    }
+}
+
+impl ReconnectPolicy {
+    /// The default backoff before the first retry.
+    const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+    /// The default maximum backoff between retries.
+    const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+    /// Creates a new policy with the given maximum number of retries and
+    /// initial backoff.
+    pub const fn new(max_retries: u32, initial_backoff: Duration) -> Self {
+        Self { max_retries, initial_backoff, max_backoff: Self::DEFAULT_MAX_BACKOFF }
+    }
+
+    /// Creates a policy that attempts to reconnect only once, without retries.
+    ///
+    /// Retries enabled with [`with_max_retries`](Self::with_max_retries)
+    /// start with a backoff of 500ms, doubling it up to 30s.
+    pub const fn no_retries() -> Self {
+        Self::new(0, Self::DEFAULT_INITIAL_BACKOFF)
+    }
+
+    /// Sets the maximum number of retries after the first reconnection
+    /// attempt failed.
+    pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the maximum backoff between retries.
+    pub const fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Returns the backoff before the given retry, starting at 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32.checked_shl(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff() {
+        let policy = ReconnectPolicy::new(10, Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(2), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(7), Duration::from_secs(30));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(30));
+
+        let policy = policy.with_max_backoff(Duration::from_millis(800));
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(2), Duration::from_millis(800));
+    }
+
+    #[test]
+    fn no_retries_by_default() {
+        let policy = ReconnectPolicy::default();
+        assert_eq!(policy, ReconnectPolicy::no_retries());
+        assert_eq!(policy.max_retries, 0);
+        assert_eq!(policy.with_max_retries(3).backoff(1), Duration::from_millis(500));
+    }
+}
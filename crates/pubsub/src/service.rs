@@ -1,17 +1,30 @@
 use crate::{
+    events::EVENT_CHANNEL_SIZE,
     handle::ConnectionHandle,
     ix::PubSubInstruction,
     managers::{InFlight, RequestManager, SubscriptionManager},
-    PubSubConnect, PubSubFrontend, RawSubscription,
+    ConnectionEvent, PubSubConnect, PubSubFrontend, RawSubscription, ReconnectPolicy,
+    ShutdownReport,
 };
-use alloy_json_rpc::{Id, PubSubItem, Request, Response, ResponsePayload, SubId};
-use alloy_primitives::B256;
+use alloy_json_rpc::{ErrorPayload, Id, PubSubItem, Request, Response, ResponsePayload, SubId};
+use alloy_primitives::{map::B256HashSet, B256};
 use alloy_transport::{
     utils::{to_json_raw_value, Spawnable},
     TransportErrorKind, TransportResult,
 };
 use serde_json::value::RawValue;
-use tokio::sync::{mpsc, oneshot};
+use std::{ops::ControlFlow, sync::Arc, time::Duration};
+use tokio::sync::{broadcast, mpsc, oneshot};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+
+/// A request to shut down the service, with the drain timeout and the channel
+/// for the report.
+type ShutdownRequest = (Duration, oneshot::Sender<ShutdownReport>);
 
 /// The service contains the backend handle, a subscription manager, and the
 /// configuration details required to reconnect.
@@ -21,7 +34,7 @@ pub(crate) struct PubSubService<T> {
     pub(crate) handle: ConnectionHandle,
 
     /// The configuration details required to reconnect.
-    pub(crate) connector: T,
+    pub(crate) connector: Arc<T>,
 
     /// The inbound requests.
     pub(crate) reqs: mpsc::UnboundedReceiver<PubSubInstruction>,
@@ -31,23 +44,96 @@ pub(crate) struct PubSubService<T> {
 
     /// The request manager.
     pub(crate) in_flights: RequestManager,
+
+    /// The service running on the next shard connection, if any. Subscriptions
+    /// rejected by this connection's subscription limit are delegated to it.
+    pub(crate) shard: Option<PubSubFrontend>,
+
+    /// The result of connecting the next shard, while it is being connected.
+    pub(crate) shard_connecting: Option<oneshot::Receiver<TransportResult<PubSubFrontend>>>,
+
+    /// The subscriptions waiting for the next shard to connect.
+    pub(crate) shard_pending: Vec<InFlight>,
+
+    /// The local IDs of subscriptions that were delegated to the next shard.
+    pub(crate) sharded_subs: B256HashSet,
+
+    /// The number of additional shard connections that may still be opened.
+    pub(crate) shards_remaining: usize,
+
+    /// The sender of connection lifecycle events, shared with the shards.
+    pub(crate) events: broadcast::Sender<ConnectionEvent>,
+
+    /// The response latency above which the connection is degraded.
+    pub(crate) degraded_latency: Duration,
+
+    /// Whether the connection is currently degraded.
+    pub(crate) degraded: bool,
+
+    /// The number of consecutive reconnection attempts without receiving a
+    /// message from the backend.
+    pub(crate) reconnect_attempts: u32,
+
+    /// The policy for retrying failed reconnection attempts.
+    pub(crate) reconnect_policy: ReconnectPolicy,
 }
 
 impl<T: PubSubConnect> PubSubService<T> {
     /// Create a new service from a connector.
     pub(crate) async fn connect(connector: T) -> TransportResult<PubSubFrontend> {
+        let shards_remaining = connector.max_shards().saturating_sub(1);
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_SIZE);
+        Self::connect_shard(Arc::new(connector), shards_remaining, events).await
+    }
+
+    /// Create a new service from a shared connector, allowing it to open up to
+    /// `shards_remaining` additional connections.
+    async fn connect_shard(
+        connector: Arc<T>,
+        shards_remaining: usize,
+        events: broadcast::Sender<ConnectionEvent>,
+    ) -> TransportResult<PubSubFrontend> {
         let handle = connector.connect().await?;
 
         let (tx, reqs) = mpsc::unbounded_channel();
         let this = Self {
             handle,
+            degraded_latency: connector.degraded_latency(),
+            reconnect_policy: connector.reconnect_policy(),
             connector,
             reqs,
             subs: SubscriptionManager::default(),
             in_flights: Default::default(),
+            shard: None,
+            shard_connecting: None,
+            shard_pending: Vec::new(),
+            sharded_subs: Default::default(),
+            shards_remaining,
+            events: events.clone(),
+            degraded: false,
+            reconnect_attempts: 0,
         };
         this.spawn();
-        Ok(PubSubFrontend::new(tx))
+        Ok(PubSubFrontend::new(tx, events))
+    }
+
+    /// Broadcast a connection event. Events are dropped if nobody listens.
+    fn emit(&self, event: ConnectionEvent) {
+        let _ = self.events.send(event);
+    }
+
+    /// Record the latency of a response, emitting an event when the
+    /// connection becomes degraded or recovers.
+    fn observe_latency(&mut self, latency: Duration) {
+        let degraded = latency > self.degraded_latency;
+        if degraded != self.degraded {
+            self.degraded = degraded;
+            self.emit(if degraded {
+                ConnectionEvent::Degraded(latency)
+            } else {
+                ConnectionEvent::Connected
+            });
+        }
     }
 
     /// Reconnect by dropping the backend and creating a new one.
@@ -62,8 +148,14 @@ impl<T: PubSubConnect> PubSubService<T> {
     async fn reconnect(&mut self) -> TransportResult<()> {
         info!("Reconnecting pubsub service backend.");
 
+        self.reconnect_attempts += 1;
+        self.emit(ConnectionEvent::Reconnecting(self.reconnect_attempts));
+
         let mut old_handle = self.get_new_backend().await?;
 
+        self.degraded = false;
+        self.emit(ConnectionEvent::Connected);
+
         debug!("Draining old backend to_handle");
 
         // Drain the old backend
@@ -98,17 +190,124 @@ impl<T: PubSubConnect> PubSubService<T> {
             let msg = req.into_serialized();
             self.handle.to_socket.send(msg).map_err(|_| TransportErrorKind::backend_gone())?;
         }
+        self.emit(ConnectionEvent::Resubscribed(self.subs.len()));
 
         Ok(())
     }
 
+    /// Reconnect the backend, retrying failed attempts with backoff according
+    /// to the reconnect policy.
+    ///
+    /// Instructions are handled while waiting between attempts. Breaks if the
+    /// service is shut down in the meantime, with the shutdown request if any.
+    async fn reconnect_with_retries(
+        &mut self,
+    ) -> TransportResult<ControlFlow<Option<ShutdownRequest>>> {
+        let mut retries = 0;
+        loop {
+            let err = match self.reconnect().await {
+                Ok(()) => return Ok(ControlFlow::Continue(())),
+                Err(err) => err,
+            };
+            if retries >= self.reconnect_policy.max_retries {
+                return Err(err);
+            }
+            retries += 1;
+            let backoff = self.reconnect_policy.backoff(retries);
+            warn!(%err, retries, ?backoff, "Pubsub service reconnection failed, retrying.");
+            if let ControlFlow::Break(shutdown) = self.wait_disconnected(backoff).await {
+                return Ok(ControlFlow::Break(shutdown));
+            }
+        }
+    }
+
+    /// Handle instructions for `duration` while the backend is disconnected.
+    ///
+    /// Breaks if a shutdown is requested or the request channel is closed.
+    async fn wait_disconnected(
+        &mut self,
+        duration: Duration,
+    ) -> ControlFlow<Option<ShutdownRequest>> {
+        let deadline = sleep(duration);
+        tokio::pin!(deadline);
+        loop {
+            tokio::select! {
+                biased;
+
+                ix_opt = self.reqs.recv() => match ix_opt {
+                    Some(PubSubInstruction::Shutdown(timeout, tx)) => {
+                        return ControlFlow::Break(Some((timeout, tx)))
+                    }
+                    Some(ix) => self.service_ix_disconnected(ix),
+                    None => {
+                        info!("Pubsub service request channel closed. Shutting down.");
+                        return ControlFlow::Break(None)
+                    }
+                },
+
+                _ = &mut deadline => return ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    /// Service an instruction while the backend is disconnected.
+    ///
+    /// Requests are only recorded as in-flight, so that they are sent when
+    /// the connection is re-established, and unsubscribed subscriptions are
+    /// not re-started.
+    fn service_ix_disconnected(&mut self, ix: PubSubInstruction) {
+        trace!(?ix, "servicing instruction while disconnected");
+        match ix {
+            PubSubInstruction::Request(in_flight) => {
+                if let Some(in_flight) = self.route_to_shard(in_flight) {
+                    self.in_flights.insert(in_flight);
+                }
+            }
+            PubSubInstruction::Unsubscribe(local_id) => {
+                if !self.unsubscribe_sharded(local_id) {
+                    self.subs.remove_sub(local_id);
+                }
+            }
+            PubSubInstruction::GetSub(local_id, tx) => self.service_get_sub(local_id, tx),
+            PubSubInstruction::Cancel(id) => self.service_cancel(id),
+            // Handled by `wait_disconnected`.
+            PubSubInstruction::Shutdown(..) => {}
+        }
+    }
+
     /// Dispatch a request to the socket.
     fn dispatch_request(&self, brv: Box<RawValue>) -> TransportResult<()> {
         self.handle.to_socket.send(brv).map(drop).map_err(|_| TransportErrorKind::backend_gone())
     }
 
+    /// Route subscriptions already living on another shard there, so that the
+    /// same params always map to the same connection. If the shard is still
+    /// connecting, the subscription is queued until it is connected.
+    ///
+    /// Returns the request if it is served by this connection.
+    fn route_to_shard(&mut self, in_flight: InFlight) -> Option<InFlight> {
+        if !in_flight.is_subscription()
+            || !self.sharded_subs.contains(&in_flight.request().params_hash())
+        {
+            return Some(in_flight);
+        }
+        match &self.shard {
+            Some(shard) => {
+                let _ = shard.forward(PubSubInstruction::Request(in_flight));
+                None
+            }
+            None if self.shard_connecting.is_some() => {
+                self.shard_pending.push(in_flight);
+                None
+            }
+            None => Some(in_flight),
+        }
+    }
+
     /// Service a request.
     fn service_request(&mut self, in_flight: InFlight) -> TransportResult<()> {
+        let Some(in_flight) = self.route_to_shard(in_flight) else { return Ok(()) };
+
         let brv = in_flight.request();
 
         self.dispatch_request(brv.serialized().to_owned())?;
@@ -124,13 +323,21 @@ impl<T: PubSubConnect> PubSubService<T> {
     /// `tx` is dropped. This notifies the waiter that the subscription does
     /// not exist.
     fn service_get_sub(&self, local_id: B256, tx: oneshot::Sender<RawSubscription>) {
-        if let Some(rx) = self.subs.get_subscription(local_id) {
+        if self.sharded_subs.contains(&local_id) {
+            if let Some(shard) = &self.shard {
+                let _ = shard.forward(PubSubInstruction::GetSub(local_id, tx));
+            }
+        } else if let Some(rx) = self.subs.get_subscription(local_id) {
             let _ = tx.send(rx);
         }
     }
 
     /// Service an unsubscribe instruction.
     fn service_unsubscribe(&mut self, local_id: B256) -> TransportResult<()> {
+        if self.unsubscribe_sharded(local_id) {
+            return Ok(());
+        }
+
         if let Some(server_id) = self.subs.server_id_for(&local_id) {
             let req = Request::new("eth_unsubscribe", Id::None, [server_id]);
             let brv = req.serialize().expect("no ser error").take_request();
@@ -141,6 +348,35 @@ impl<T: PubSubConnect> PubSubService<T> {
         Ok(())
     }
 
+    /// Unsubscribe from a subscription delegated to the next shard, returning
+    /// whether it was delegated.
+    fn unsubscribe_sharded(&mut self, local_id: B256) -> bool {
+        if !self.sharded_subs.remove(&local_id) {
+            return false;
+        }
+        if let Some(shard) = &self.shard {
+            // The shard going away means the subscription is gone as well.
+            let _ = shard.unsubscribe(local_id);
+        }
+        true
+    }
+
+    /// Service a cancel instruction, dropping the in-flight request so that it
+    /// is neither re-issued on reconnection nor fulfilled.
+    ///
+    /// Requests unknown to this connection may have been delegated to the next
+    /// shard, so the instruction is forwarded there.
+    fn service_cancel(&mut self, id: Id) {
+        if self.in_flights.remove(&id).is_some() {
+            return;
+        }
+        if let Some(pos) = self.shard_pending.iter().position(|p| p.request().id() == &id) {
+            self.shard_pending.swap_remove(pos);
+        } else if let Some(shard) = &self.shard {
+            let _ = shard.forward(PubSubInstruction::Cancel(id));
+        }
+    }
+
     /// Service an instruction
     fn service_ix(&mut self, ix: PubSubInstruction) -> TransportResult<()> {
         trace!(?ix, "servicing instruction");
@@ -151,16 +387,35 @@ impl<T: PubSubConnect> PubSubService<T> {
                 Ok(())
             }
             PubSubInstruction::Unsubscribe(alias) => self.service_unsubscribe(alias),
+            PubSubInstruction::Cancel(id) => {
+                self.service_cancel(id);
+                Ok(())
+            }
+            // Shutdowns are asynchronous and handled by the service loop.
+            PubSubInstruction::Shutdown(..) => Ok(()),
         }
     }
 
     /// Handle an item from the backend.
     fn handle_item(&mut self, item: PubSubItem) -> TransportResult<()> {
         match item {
-            PubSubItem::Response(resp) => match self.in_flights.handle_response(resp) {
-                Some((server_id, in_flight)) => self.handle_sub_response(in_flight, server_id),
-                None => Ok(()),
-            },
+            PubSubItem::Response(resp) => {
+                let Some(in_flight) = self.in_flights.remove(&resp.id) else { return Ok(()) };
+                self.observe_latency(in_flight.sent_at.elapsed());
+
+                if in_flight.is_subscription()
+                    && self.shards_remaining > 0
+                    && resp.payload.as_error().is_some_and(ErrorPayload::is_subscription_limit_err)
+                {
+                    self.shard_subscription(in_flight);
+                    return Ok(());
+                }
+
+                match in_flight.fulfill(resp) {
+                    Some((server_id, in_flight)) => self.handle_sub_response(in_flight, server_id),
+                    None => Ok(()),
+                }
+            }
             PubSubItem::Notification(notification) => {
                 self.subs.notify(notification);
                 Ok(())
@@ -168,6 +423,66 @@ impl<T: PubSubConnect> PubSubService<T> {
         }
     }
 
+    /// Delegate a subscription rejected by the subscription limit of this
+    /// connection to the next shard.
+    ///
+    /// If the shard is not connected yet, it is connected in the background
+    /// and the subscription is queued until the connection completes, see
+    /// [`Self::handle_shard_connected`]. The shard replies to the original
+    /// waiter directly.
+    fn shard_subscription(&mut self, in_flight: InFlight) {
+        self.sharded_subs.insert(in_flight.request().params_hash());
+        if let Some(shard) = &self.shard {
+            // If the shard is gone, the waiter is dropped and notified.
+            let _ = shard.forward(PubSubInstruction::Request(in_flight));
+            return;
+        }
+
+        self.shard_pending.push(in_flight);
+        if self.shard_connecting.is_none() {
+            debug!(
+                shards_remaining = self.shards_remaining,
+                "Subscription limit reached, opening new shard connection"
+            );
+            let (tx, rx) = oneshot::channel();
+            let shard = Self::connect_shard(
+                self.connector.clone(),
+                self.shards_remaining - 1,
+                self.events.clone(),
+            );
+            async move {
+                let _ = tx.send(shard.await);
+            }
+            .spawn_task();
+            self.shard_connecting = Some(rx);
+        }
+    }
+
+    /// Forward the subscriptions queued while the next shard was connecting.
+    ///
+    /// If connecting the shard failed, the waiters receive the connection
+    /// error, and the next rejected subscription tries to connect again.
+    fn handle_shard_connected(&mut self, shard: TransportResult<PubSubFrontend>) {
+        self.shard_connecting = None;
+        let pending = std::mem::take(&mut self.shard_pending);
+        match shard {
+            Ok(shard) => {
+                for in_flight in pending {
+                    let _ = shard.forward(PubSubInstruction::Request(in_flight));
+                }
+                self.shard = Some(shard);
+            }
+            Err(err) => {
+                warn!(%err, "Failed to open shard connection.");
+                for in_flight in pending {
+                    self.sharded_subs.remove(&in_flight.request().params_hash());
+                    let _ =
+                        in_flight.tx.send(Err(TransportErrorKind::custom_str(&err.to_string())));
+                }
+            }
+        }
+    }
+
     /// Rewrite the subscription id and insert into the subscriptions manager
     fn handle_sub_response(
         &mut self,
@@ -190,10 +505,125 @@ impl<T: PubSubConnect> PubSubService<T> {
         Ok(())
     }
 
+    /// Unsubscribe from all active subscriptions, returning their number.
+    ///
+    /// The `eth_unsubscribe` requests are tracked as in-flight requests, so
+    /// that draining waits for the server to process them.
+    fn unsubscribe_all(&mut self) -> usize {
+        let local_ids: Vec<B256> = self.subs.iter().map(|(local_id, _)| *local_id).collect();
+        for local_id in &local_ids {
+            if let Some(server_id) = self.subs.server_id_for(local_id) {
+                let id = Id::String(format!("unsubscribe-{local_id}"));
+                let req = Request::new("eth_unsubscribe", id, [server_id]);
+                let (in_flight, _) = InFlight::new(req.serialize().expect("no ser error"), 0);
+                if self.dispatch_request(in_flight.request().serialized().to_owned()).is_ok() {
+                    self.in_flights.insert(in_flight);
+                }
+            }
+            self.subs.remove_sub(*local_id);
+        }
+        local_ids.len()
+    }
+
+    /// Shut down the service gracefully.
+    ///
+    /// Unsubscribes from all active subscriptions, waits up to `timeout` for
+    /// in-flight requests to complete, and then closes the backend. Requests
+    /// that are still in flight, or that are sent while draining, are
+    /// cancelled. Shards shut down concurrently, and their reports are merged
+    /// into the returned one.
+    async fn shutdown(mut self, timeout: Duration) -> ShutdownReport {
+        info!(?timeout, "Shutting down pubsub service.");
+
+        let shard = self.shard.take().map(|shard| shard.shutdown(timeout));
+        self.sharded_subs.clear();
+        // Subscriptions rejected while draining must not open new shards, and a
+        // shard that is still connecting shuts down once its frontend is dropped.
+        self.shards_remaining = 0;
+        self.shard_connecting = None;
+
+        let mut report = ShutdownReport::default();
+        report
+            .cancelled
+            .extend(self.shard_pending.drain(..).map(|in_flight| in_flight.request().id().clone()));
+        report.unsubscribed += self.unsubscribe_all();
+
+        let deadline = sleep(timeout);
+        tokio::pin!(deadline);
+        let mut reqs_open = true;
+        while !self.in_flights.is_empty() {
+            tokio::select! {
+                biased;
+
+                item_opt = self.handle.from_socket.recv() => {
+                    let Some(item) = item_opt else {
+                        report.backend_failed = true;
+                        break
+                    };
+                    if let PubSubItem::Response(resp) = &item {
+                        if self.in_flights.get(&resp.id).is_some_and(|in_flight| !is_unsubscribe(in_flight)) {
+                            report.completed += 1;
+                        }
+                    }
+                    if self.handle_item(item).is_err() {
+                        report.backend_failed = true;
+                        break
+                    }
+                }
+
+                _ = &mut self.handle.error => {
+                    report.backend_failed = true;
+                    break
+                }
+
+                ix_opt = self.reqs.recv(), if reqs_open => match ix_opt {
+                    Some(PubSubInstruction::Request(in_flight)) => {
+                        // Dropping the request notifies the waiter.
+                        report.cancelled.push(in_flight.request().id().clone());
+                    }
+                    Some(PubSubInstruction::Unsubscribe(local_id)) => {
+                        let _ = self.service_unsubscribe(local_id);
+                    }
+                    Some(PubSubInstruction::Cancel(id)) => self.service_cancel(id),
+                    // Dropping the sender notifies the waiter.
+                    Some(PubSubInstruction::GetSub(..) | PubSubInstruction::Shutdown(..)) => {}
+                    None => reqs_open = false,
+                },
+
+                _ = &mut deadline => break,
+            }
+        }
+
+        // Subscriptions created while draining are dropped as well.
+        report.unsubscribed += self.unsubscribe_all();
+        report.cancelled.extend(
+            self.in_flights
+                .drain()
+                .filter(|in_flight| !is_unsubscribe(in_flight))
+                .map(|in_flight| in_flight.request().id().clone()),
+        );
+        self.handle.shutdown();
+
+        if let Some(shard) = shard {
+            match shard.await {
+                Ok(shard_report) => report.merge(shard_report),
+                Err(err) => debug!(%err, "pubsub shard already shut down"),
+            }
+        }
+
+        info!(
+            unsubscribed = report.unsubscribed,
+            completed = report.completed,
+            cancelled = report.cancelled.len(),
+            "Pubsub service shut down."
+        );
+        report
+    }
+
     /// Spawn the service.
     pub(crate) fn spawn(mut self) {
         let fut = async move {
-            let result: TransportResult<()> = loop {
+            let result: TransportResult<Option<ShutdownRequest>> = loop {
                 // We bias the loop so that we always handle new messages before
                 // reconnecting, and always reconnect before dispatching new
                 // requests.
@@ -202,38 +632,289 @@ impl<T: PubSubConnect> PubSubService<T> {
 
                     item_opt = self.handle.from_socket.recv() => {
                         if let Some(item) = item_opt {
+                            self.reconnect_attempts = 0;
                             if let Err(e) = self.handle_item(item) {
                                 break Err(e)
                             }
-                        } else if let Err(e) = self.reconnect().await {
-                            break Err(e)
+                        } else {
+                            match self.reconnect_with_retries().await {
+                                Ok(ControlFlow::Continue(())) => {}
+                                Ok(ControlFlow::Break(shutdown)) => break Ok(shutdown),
+                                Err(e) => break Err(e),
+                            }
                         }
                     }
 
                     _ = &mut self.handle.error => {
                         error!("Pubsub service backend error.");
-                        if let Err(e) = self.reconnect().await {
-                            break Err(e)
+                        match self.reconnect_with_retries().await {
+                            Ok(ControlFlow::Continue(())) => {}
+                            Ok(ControlFlow::Break(shutdown)) => break Ok(shutdown),
+                            Err(e) => break Err(e),
                         }
                     }
 
+                    shard = shard_connected(&mut self.shard_connecting), if self.shard_connecting.is_some() => {
+                        self.handle_shard_connected(shard);
+                    }
+
                     req_opt = self.reqs.recv() => {
-                        if let Some(req) = req_opt {
-                            if let Err(e) = self.service_ix(req) {
-                                break Err(e)
+                        match req_opt {
+                            Some(PubSubInstruction::Shutdown(timeout, tx)) => {
+                                break Ok(Some((timeout, tx)))
+                            }
+                            Some(req) => {
+                                if let Err(e) = self.service_ix(req) {
+                                    break Err(e)
+                                }
+                            }
+                            None => {
+                                info!("Pubsub service request channel closed. Shutting down.");
+                                break Ok(None)
                             }
-                        } else {
-                            info!("Pubsub service request channel closed. Shutting down.");
-                           break Ok(())
                         }
                     }
                 }
             };
 
-            if let Err(err) = result {
-                error!(%err, "pubsub service reconnection error");
+            match result {
+                Ok(Some((timeout, tx))) => {
+                    let report = self.shutdown(timeout).await;
+                    let _ = tx.send(report);
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    error!(%err, "pubsub service reconnection error");
+                    self.emit(ConnectionEvent::Dropped(err.to_string()));
+                }
             }
         };
         fut.spawn_task();
     }
 }
+
+/// Waits for the next shard to connect. Never completes if no shard is
+/// connecting.
+async fn shard_connected(
+    connecting: &mut Option<oneshot::Receiver<TransportResult<PubSubFrontend>>>,
+) -> TransportResult<PubSubFrontend> {
+    match connecting {
+        Some(rx) => rx.await.unwrap_or_else(|_| Err(TransportErrorKind::backend_gone())),
+        None => std::future::pending().await,
+    }
+}
+
+/// Returns whether the request is an `eth_unsubscribe` issued by the service.
+fn is_unsubscribe(in_flight: &InFlight) -> bool {
+    in_flight.request().method() == "eth_unsubscribe"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionInterface;
+    use alloy_json_rpc::SerializedRequest;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::time::Instant;
+
+    /// A connector whose backends are driven by the test.
+    #[derive(Debug)]
+    struct MockConnect {
+        /// The interfaces of connected backends.
+        backends: mpsc::UnboundedSender<ConnectionInterface>,
+        /// The number of upcoming connection attempts that fail.
+        failures: Arc<AtomicUsize>,
+        policy: ReconnectPolicy,
+        shards: usize,
+    }
+
+    impl MockConnect {
+        fn new(
+            policy: ReconnectPolicy,
+        ) -> (Self, mpsc::UnboundedReceiver<ConnectionInterface>, Arc<AtomicUsize>) {
+            let (backends, rx) = mpsc::unbounded_channel();
+            let failures = Arc::new(AtomicUsize::new(0));
+            (Self { backends, failures: failures.clone(), policy, shards: 1 }, rx, failures)
+        }
+    }
+
+    impl PubSubConnect for MockConnect {
+        fn is_local(&self) -> bool {
+            true
+        }
+
+        async fn connect(&self) -> TransportResult<ConnectionHandle> {
+            if self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                return Err(TransportErrorKind::custom_str("connection refused"));
+            }
+            let (handle, interface) = ConnectionHandle::new();
+            let _ = self.backends.send(interface);
+            Ok(handle)
+        }
+
+        fn reconnect_policy(&self) -> ReconnectPolicy {
+            self.policy
+        }
+
+        fn max_shards(&self) -> usize {
+            self.shards
+        }
+
+        fn degraded_latency(&self) -> Duration {
+            DEGRADED_LATENCY
+        }
+    }
+
+    /// The degraded latency of the mock connector. Latencies are measured with
+    /// the system clock, which is not paused in tests.
+    const DEGRADED_LATENCY: Duration = Duration::from_millis(50);
+
+    fn request(id: u64) -> SerializedRequest {
+        Request::new("eth_blockNumber", Id::Number(id), ()).serialize().unwrap()
+    }
+
+    fn subscribe(id: u64) -> SerializedRequest {
+        Request::new("eth_subscribe", Id::Number(id), ["newHeads"]).serialize().unwrap()
+    }
+
+    fn respond(backend: &ConnectionInterface, id: u64) {
+        let payload = ResponsePayload::Success(to_json_raw_value(&"0x1").unwrap());
+        backend
+            .send_to_frontend(PubSubItem::Response(Response { id: Id::Number(id), payload }))
+            .unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_reconnection_with_backoff() {
+        let policy = ReconnectPolicy::new(3, Duration::from_secs(1));
+        let (connect, mut backends, failures) = MockConnect::new(policy);
+        let frontend = connect.into_service().await.unwrap();
+        let mut events = frontend.connection_events();
+
+        failures.store(2, Ordering::SeqCst);
+        let start = Instant::now();
+        backends.recv().await.unwrap().close_with_error();
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Reconnecting(1));
+
+        // requests sent while waiting to reconnect are sent on the new connection
+        let response = tokio::spawn(frontend.send(request(1)));
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Reconnecting(2));
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Reconnecting(3));
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Connected);
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Resubscribed(0));
+        assert!(start.elapsed() >= Duration::from_secs(3));
+
+        let mut backend = backends.recv().await.unwrap();
+        let sent = backend.recv_from_frontend().await.unwrap();
+        assert_eq!(sent.get(), request(1).serialized().get());
+        respond(&backend, 1);
+        assert!(response.await.unwrap().unwrap().payload.is_success());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shutdown_while_reconnecting() {
+        let policy = ReconnectPolicy::new(10, Duration::from_secs(60));
+        let (connect, mut backends, failures) = MockConnect::new(policy);
+        let frontend = connect.into_service().await.unwrap();
+        let mut events = frontend.connection_events();
+
+        failures.store(usize::MAX, Ordering::SeqCst);
+        let start = Instant::now();
+        backends.recv().await.unwrap().close_with_error();
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Reconnecting(1));
+
+        let report = frontend.shutdown(Duration::from_secs(1)).await.unwrap();
+        assert!(report.cancelled.is_empty());
+        assert!(start.elapsed() < Duration::from_secs(60));
+        assert!(frontend.send(request(1)).await.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn no_retries_by_default() {
+        let (connect, mut backends, failures) = MockConnect::new(ReconnectPolicy::default());
+        let frontend = connect.into_service().await.unwrap();
+        let mut events = frontend.connection_events();
+
+        failures.store(1, Ordering::SeqCst);
+        backends.recv().await.unwrap().close_with_error();
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Reconnecting(1));
+        assert!(matches!(events.recv().await.unwrap(), ConnectionEvent::Dropped(_)));
+        assert!(frontend.send(request(1)).await.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn connection_events_in_order() {
+        let (connect, mut backends, failures) = MockConnect::new(ReconnectPolicy::default());
+        let frontend = connect.into_service().await.unwrap();
+        let mut events = frontend.connection_events();
+
+        let mut backend = backends.recv().await.unwrap();
+        let response = tokio::spawn(frontend.send(subscribe(1)));
+        backend.recv_from_frontend().await.unwrap();
+        respond(&backend, 1);
+        assert!(response.await.unwrap().unwrap().payload.is_success());
+
+        // the subscription is re-issued on the new connection
+        backend.close_with_error();
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Reconnecting(1));
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Connected);
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Resubscribed(1));
+        let mut backend = backends.recv().await.unwrap();
+        let sent = backend.recv_from_frontend().await.unwrap();
+        assert_eq!(sent.get(), subscribe(1).serialized().get());
+        respond(&backend, 1);
+
+        // a slow response degrades the connection
+        let response = tokio::spawn(frontend.send(request(2)));
+        backend.recv_from_frontend().await.unwrap();
+        std::thread::sleep(DEGRADED_LATENCY * 2);
+        respond(&backend, 2);
+        assert!(response.await.unwrap().unwrap().payload.is_success());
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            ConnectionEvent::Degraded(latency) if latency > DEGRADED_LATENCY
+        ));
+
+        // the connection is dropped once reconnecting fails
+        failures.store(1, Ordering::SeqCst);
+        backend.close_with_error();
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Reconnecting(1));
+        assert!(matches!(events.recv().await.unwrap(), ConnectionEvent::Dropped(_)));
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shards_subscriptions_on_limit_error() {
+        let (mut connect, mut backends, _) = MockConnect::new(ReconnectPolicy::default());
+        connect.shards = 2;
+        let frontend = connect.into_service().await.unwrap();
+        let mut first = backends.recv().await.unwrap();
+
+        // the subscription rejected by the first connection is sent on a new shard
+        let subscription = tokio::spawn(frontend.send(subscribe(1)));
+        first.recv_from_frontend().await.unwrap();
+        let payload = ResponsePayload::Failure(ErrorPayload::server_error(
+            -32005,
+            "limit exceeded: subscriptions",
+        ));
+        first
+            .send_to_frontend(PubSubItem::Response(Response { id: Id::Number(1), payload }))
+            .unwrap();
+        let mut second = backends.recv().await.unwrap();
+        let sent = second.recv_from_frontend().await.unwrap();
+        assert_eq!(sent.get(), subscribe(1).serialized().get());
+
+        // the first connection keeps serving requests
+        let response = tokio::spawn(frontend.send(request(2)));
+        first.recv_from_frontend().await.unwrap();
+        respond(&first, 2);
+        assert!(response.await.unwrap().unwrap().payload.is_success());
+
+        respond(&second, 1);
+        assert!(subscription.await.unwrap().unwrap().payload.is_success());
+    }
+}
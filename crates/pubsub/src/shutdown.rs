@@ -0,0 +1,36 @@
+use alloy_json_rpc::Id;
+
+/// The outcome of a graceful shutdown of the pubsub service, see
+/// [`PubSubFrontend::shutdown`].
+///
+/// Counts include the shard connections of the service.
+///
+/// [`PubSubFrontend::shutdown`]: crate::PubSubFrontend::shutdown
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ShutdownReport {
+    /// The number of active subscriptions that were unsubscribed.
+    pub unsubscribed: usize,
+    /// The number of in-flight requests that completed while draining.
+    pub completed: usize,
+    /// The IDs of the requests that were cancelled, because they did not
+    /// complete before the timeout or were sent after the shutdown started.
+    pub cancelled: Vec<Id>,
+    /// Whether draining stopped early because the backend failed.
+    pub backend_failed: bool,
+}
+
+impl ShutdownReport {
+    /// Returns `true` if all in-flight requests completed.
+    pub fn is_clean(&self) -> bool {
+        self.cancelled.is_empty() && !self.backend_failed
+    }
+
+    /// Merge the report of a shard connection into this one.
+    pub(crate) fn merge(&mut self, other: Self) {
+        self.unsubscribed += other.unsubscribed;
+        self.completed += other.completed;
+        self.cancelled.extend(other.cancelled);
+        self.backend_failed |= other.backend_failed;
+    }
+}
@@ -1,3 +1,4 @@
+use alloy_json_rpc::{deserialize_reported, DeserContext};
 use alloy_primitives::B256;
 use futures::{ready, Stream, StreamExt};
 use serde::de::DeserializeOwned;
@@ -99,7 +100,7 @@ pub enum SubscriptionItem<T> {
 
 impl<T: DeserializeOwned> From<Box<RawValue>> for SubscriptionItem<T> {
     fn from(value: Box<RawValue>) -> Self {
-        serde_json::from_str(value.get()).map_or_else(
+        deserialize_reported(DeserContext::Subscription, value.get()).map_or_else(
             |_| {
                 trace!(value = value.get(), "Received unexpected value in subscription.");
                 Self::Other(value)
@@ -295,7 +296,9 @@ impl<T: DeserializeOwned> Subscription<T> {
     pub fn blocking_recv_result(
         &mut self,
     ) -> Result<Result<T, serde_json::Error>, broadcast::error::RecvError> {
-        self.inner.blocking_recv().map(|value| serde_json::from_str(value.get()))
+        self.inner
+            .blocking_recv()
+            .map(|value| deserialize_reported(DeserContext::Subscription, value.get()))
     }
 
     /// Wrapper for [`recv`]. Await an item from the channel, deserializing the
@@ -305,7 +308,10 @@ impl<T: DeserializeOwned> Subscription<T> {
     pub async fn recv_result(
         &mut self,
     ) -> Result<Result<T, serde_json::Error>, broadcast::error::RecvError> {
-        self.inner.recv().await.map(|value| serde_json::from_str(value.get()))
+        self.inner
+            .recv()
+            .await
+            .map(|value| deserialize_reported(DeserContext::Subscription, value.get()))
     }
 
     /// Wrapper for [`try_recv`]. Attempt to receive a message from the channel
@@ -315,7 +321,9 @@ impl<T: DeserializeOwned> Subscription<T> {
     pub fn try_recv_result(
         &mut self,
     ) -> Result<Result<T, serde_json::Error>, broadcast::error::TryRecvError> {
-        self.inner.try_recv().map(|value| serde_json::from_str(value.get()))
+        self.inner
+            .try_recv()
+            .map(|value| deserialize_reported(DeserContext::Subscription, value.get()))
     }
 }
 
@@ -382,14 +390,16 @@ impl<T: DeserializeOwned> Stream for SubscriptionStream<T> {
     ) -> task::Poll<Option<Self::Item>> {
         loop {
             match ready!(self.inner.poll_next_unpin(cx)) {
-                Some(Ok(value)) => match serde_json::from_str(value.get()) {
-                    Ok(item) => return task::Poll::Ready(Some(item)),
-                    Err(err) => {
-                        debug!(value = ?value.get(), %err, %self.id, "failed deserializing subscription item");
-                        error!(%err, %self.id, "failed deserializing subscription item");
-                        continue;
+                Some(Ok(value)) => {
+                    match deserialize_reported(DeserContext::Subscription, value.get()) {
+                        Ok(item) => return task::Poll::Ready(Some(item)),
+                        Err(err) => {
+                            debug!(value = ?value.get(), %err, %self.id, "failed deserializing subscription item");
+                            error!(%err, %self.id, "failed deserializing subscription item");
+                            continue;
+                        }
                     }
-                },
+                }
                 Some(Err(err @ BroadcastStreamRecvError::Lagged(_))) => {
                     // This is OK.
                     debug!(%err, %self.id, "stream lagged");
@@ -429,7 +439,10 @@ impl<T: DeserializeOwned> Stream for SubResultStream<T> {
         loop {
             match ready!(self.inner.poll_next_unpin(cx)) {
                 Some(Ok(value)) => {
-                    return task::Poll::Ready(Some(serde_json::from_str(value.get())))
+                    return task::Poll::Ready(Some(deserialize_reported(
+                        DeserContext::Subscription,
+                        value.get(),
+                    )))
                 }
                 Some(Err(err @ BroadcastStreamRecvError::Lagged(_))) => {
                     // This is OK.
@@ -42,6 +42,8 @@ pub struct BatchRequest<'a> {
 }
 
 /// Awaits a single response for a request that has been included in a batch.
+///
+/// Dropping the waiter before the batch is sent removes its request from the batch.
 #[must_use = "A Waiter does nothing unless the corresponding BatchRequest is sent via `send_batch` and `.await`, AND the Waiter is awaited."]
 #[pin_project]
 #[derive(Debug)]
@@ -191,6 +193,16 @@ impl BatchFuture {
             return Poll::Ready(Err(e));
         }
 
+        // Requests whose waiter has been dropped are not sent.
+        channels.retain(|_, tx| !tx.is_closed());
+        if let RequestPacket::Batch(batch) = requests {
+            batch.retain(|req| channels.contains_key(req.id()));
+        }
+        if channels.is_empty() {
+            self.set(Self::Complete);
+            return Poll::Ready(Ok(()));
+        }
+
         // We only have mut refs, and we want ownership, so we just replace with 0-capacity
         // collections.
         let channels = std::mem::take(channels);
@@ -1,6 +1,6 @@
 use alloy_json_rpc::{
-    transform_response, try_deserialize_ok, Request, RequestPacket, ResponsePacket, RpcRecv,
-    RpcResult, RpcSend,
+    transform_response, try_deserialize_ok, Request, RequestPacket, RequestPriority,
+    ResponsePacket, RpcRecv, RpcResult, RpcSend,
 };
 use alloy_transport::{BoxTransport, IntoBoxTransport, RpcFut, TransportError, TransportResult};
 use core::panic;
@@ -220,6 +220,25 @@ where
         self.request_mut().meta.set_subscription_status(status);
     }
 
+    /// Set the priority of the request, see [`RequestPriority`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after the request has been sent.
+    pub fn set_priority(&mut self, priority: RequestPriority) {
+        self.request_mut().meta.set_priority(priority);
+    }
+
+    /// Returns the request with the given priority, see [`RequestPriority`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after the request has been sent.
+    pub fn with_priority(mut self, priority: RequestPriority) -> Self {
+        self.set_priority(priority);
+        self
+    }
+
     /// Get a mutable reference to the params of the request.
     ///
     /// This is useful for modifying the params after the request has been
@@ -25,7 +25,10 @@ mod client;
 pub use client::{ClientRef, NoParams, RpcClient, RpcClientInner, WeakClient};
 
 mod poller;
-pub use poller::{PollChannel, PollerBuilder};
+pub use poller::{AdaptiveInterval, PollChannel, PollerBuilder};
+
+#[doc(no_inline)]
+pub use tokio_util::sync::CancellationToken;
 
 #[cfg(feature = "ws")]
 pub use alloy_transport_ws::WsConnect;
@@ -1,24 +1,29 @@
 use crate::WeakClient;
-use alloy_json_rpc::{RpcError, RpcRecv, RpcSend};
+use alloy_json_rpc::{deserialize_reported, DeserContext, RpcError, RpcRecv, RpcSend};
 use alloy_transport::utils::Spawnable;
-use futures::{Stream, StreamExt};
+use futures::{
+    future::{select, Either},
+    Stream, StreamExt,
+};
 use serde::Serialize;
 use serde_json::value::RawValue;
 use std::{
     borrow::Cow,
     marker::PhantomData,
     ops::{Deref, DerefMut},
+    pin::pin,
     time::Duration,
 };
 use tokio::sync::broadcast;
 use tokio_stream::wrappers::BroadcastStream;
+use tokio_util::sync::CancellationToken;
 use tracing::Instrument;
 
 #[cfg(target_arch = "wasm32")]
-use wasmtimer::tokio::sleep;
+use wasmtimer::{std::Instant, tokio::sleep};
 
 #[cfg(not(target_arch = "wasm32"))]
-use tokio::time::sleep;
+use {std::time::Instant, tokio::time::sleep};
 
 /// The number of retries for polling a request.
 const MAX_RETRIES: usize = 3;
@@ -31,7 +36,8 @@ const MAX_RETRIES: usize = 3;
 ///
 /// The builder is consumed using the [`spawn`](Self::spawn) method, which returns a channel to
 /// receive the responses. The task will continue to poll until either the client or the channel is
-/// dropped.
+/// dropped, or until the [`CancellationToken`] set with
+/// [`with_cancellation_token`](Self::with_cancellation_token) is cancelled.
 ///
 /// The channel can be converted into a stream using the [`into_stream`](PollChannel::into_stream)
 /// method.
@@ -75,6 +81,8 @@ pub struct PollerBuilder<Params, Resp> {
     channel_size: usize,
     poll_interval: Duration,
     limit: usize,
+    adaptive: Option<AdaptiveInterval>,
+    cancel: Option<CancellationToken>,
 
     _pd: PhantomData<fn() -> Resp>,
 }
@@ -95,6 +103,8 @@ where
             channel_size: 16,
             poll_interval,
             limit: usize::MAX,
+            adaptive: None,
+            cancel: None,
             _pd: PhantomData,
         }
     }
@@ -147,17 +157,78 @@ where
         self
     }
 
+    /// Returns the adaptive interval configuration, if any.
+    pub const fn adaptive_interval(&self) -> Option<&AdaptiveInterval> {
+        self.adaptive.as_ref()
+    }
+
+    /// Sets the adaptive interval configuration.
+    ///
+    /// When set, the duration between polls is no longer fixed: it backs off while the responses
+    /// are unchanged, tightens when activity is detected, and aligns polls to the expected block
+    /// time. See [`AdaptiveInterval`] for more details.
+    pub fn set_adaptive_interval(&mut self, adaptive: Option<AdaptiveInterval>) {
+        self.adaptive = adaptive;
+    }
+
+    /// Sets the adaptive interval configuration.
+    ///
+    /// See [`set_adaptive_interval`](Self::set_adaptive_interval) for more details.
+    pub fn with_adaptive_interval(mut self, adaptive: AdaptiveInterval) -> Self {
+        self.set_adaptive_interval(Some(adaptive));
+        self
+    }
+
+    /// Enables adaptive polling, using the current poll interval as the expected block time.
+    ///
+    /// The interval ranges between a quarter and four times the current poll interval.
+    pub fn with_adaptive_polling(self) -> Self {
+        let adaptive = AdaptiveInterval::new(self.poll_interval / 4, self.poll_interval * 4)
+            .with_block_time(self.poll_interval);
+        self.with_adaptive_interval(adaptive)
+    }
+
+    /// Returns the cancellation token of the poller task, if any.
+    pub const fn cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancel.as_ref()
+    }
+
+    /// Sets a cancellation token that stops the poller task when cancelled.
+    ///
+    /// Any in-flight request is dropped, and the channel is closed.
+    pub fn set_cancellation_token(&mut self, cancel: Option<CancellationToken>) {
+        self.cancel = cancel;
+    }
+
+    /// Sets a cancellation token that stops the poller task when cancelled.
+    ///
+    /// See [`set_cancellation_token`](Self::set_cancellation_token) for more details.
+    pub fn with_cancellation_token(mut self, cancel: CancellationToken) -> Self {
+        self.set_cancellation_token(Some(cancel));
+        self
+    }
+
     /// Starts the poller in a new Tokio task, returning a channel to receive the responses on.
     pub fn spawn(self) -> PollChannel<Resp> {
         let (tx, rx) = broadcast::channel(self.channel_size);
         let span = debug_span!("poller", method = %self.method);
-        self.into_future(tx).instrument(span).spawn_task();
+        let cancel = self.cancel.clone();
+        let fut = self.into_future(tx);
+        let fut = async move {
+            let Some(cancel) = cancel else { return fut.await };
+            let result = select(pin!(cancel.cancelled()), pin!(fut)).await;
+            if let Either::Left(_) = result {
+                debug!("poller cancelled");
+            }
+        };
+        fut.instrument(span).spawn_task();
         rx.into()
     }
 
-    async fn into_future(self, tx: broadcast::Sender<Resp>) {
+    async fn into_future(mut self, tx: broadcast::Sender<Resp>) {
         let mut params = ParamsOnce::Typed(self.params);
         let mut retries = MAX_RETRIES;
+        let mut previous: Option<Box<RawValue>> = None;
         'outer: for _ in 0..self.limit {
             let Some(client) = self.client.upgrade() else {
                 debug!("client dropped");
@@ -175,12 +246,25 @@ where
 
             loop {
                 trace!("polling");
-                match client.request(self.method.clone(), params).await {
-                    Ok(resp) => {
+                match client.request::<_, Box<RawValue>>(self.method.clone(), params).await {
+                    Ok(raw) => {
+                        let resp =
+                            match deserialize_reported::<Resp>(DeserContext::Response, raw.get()) {
+                                Ok(resp) => resp,
+                                Err(err) => {
+                                    error!(%err, "failed to deserialize poll response");
+                                    break 'outer;
+                                }
+                            };
                         if tx.send(resp).is_err() {
                             debug!("channel closed");
                             break 'outer;
                         }
+                        if let Some(adaptive) = &mut self.adaptive {
+                            let active = is_activity(previous.as_deref(), &raw);
+                            adaptive.observe(active, Instant::now());
+                            previous = Some(raw);
+                        }
                     }
                     Err(RpcError::Transport(err)) if retries > 0 && err.recoverable() => {
                         debug!(%err, "failed to poll, retrying");
@@ -195,8 +279,12 @@ where
                 break;
             }
 
-            trace!(duration=?self.poll_interval, "sleeping");
-            sleep(self.poll_interval).await;
+            let duration = self
+                .adaptive
+                .as_ref()
+                .map_or(self.poll_interval, |adaptive| adaptive.next_interval(Instant::now()));
+            trace!(?duration, "sleeping");
+            sleep(duration).await;
         }
     }
 
@@ -210,6 +298,118 @@ where
     }
 }
 
+/// Returns `true` if the raw poll response indicates activity.
+///
+/// A response is considered active if it differs from the previous response and is not an empty
+/// array, which is what filter polling returns when nothing happened.
+fn is_activity(previous: Option<&RawValue>, current: &RawValue) -> bool {
+    let current = current.get().trim();
+    let is_empty = current
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .is_some_and(|s| s.trim().is_empty());
+    !is_empty && previous.map_or(true, |previous| previous.get().trim() != current)
+}
+
+/// Adaptive poll interval configuration and state.
+///
+/// The interval starts at the minimum, is multiplied by the backoff factor each time a poll
+/// returns an unchanged response, up to the maximum, and is reset as soon as activity is detected.
+///
+/// The expected block time is learned from the time between observed activity, i.e. from new
+/// headers when polling `eth_blockNumber` or block filters. Once known, the next poll after
+/// activity is scheduled for when the next block is expected, instead of immediately.
+#[derive(Clone, Debug)]
+pub struct AdaptiveInterval {
+    min: Duration,
+    max: Duration,
+    backoff: f64,
+    block_time: Option<Duration>,
+    current: Duration,
+    last_activity: Option<Instant>,
+}
+
+impl AdaptiveInterval {
+    /// The default backoff factor applied when responses are unchanged.
+    pub const DEFAULT_BACKOFF: f64 = 1.5;
+
+    /// The weight of a new observation in the moving average of the block time.
+    const BLOCK_TIME_WEIGHT: f64 = 0.25;
+
+    /// Creates a new adaptive interval ranging between `min` and `max`.
+    pub fn new(min: Duration, max: Duration) -> Self {
+        let max = max.max(min);
+        Self {
+            min,
+            max,
+            backoff: Self::DEFAULT_BACKOFF,
+            block_time: None,
+            current: min,
+            last_activity: None,
+        }
+    }
+
+    /// Sets the backoff factor applied when responses are unchanged.
+    ///
+    /// Factors below `1.0` are treated as `1.0`, i.e. no backoff.
+    pub fn with_backoff(mut self, backoff: f64) -> Self {
+        self.backoff = backoff.max(1.0);
+        self
+    }
+
+    /// Sets the initial expected block time. It is refined as activity is observed.
+    pub fn with_block_time(mut self, block_time: Duration) -> Self {
+        self.block_time = Some(block_time.clamp(self.min, self.max));
+        self
+    }
+
+    /// Returns the minimum interval.
+    pub const fn min(&self) -> Duration {
+        self.min
+    }
+
+    /// Returns the maximum interval.
+    pub const fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Returns the current expected block time, if known.
+    pub const fn block_time(&self) -> Option<Duration> {
+        self.block_time
+    }
+
+    /// Records the outcome of a poll made at `now`.
+    pub fn observe(&mut self, active: bool, now: Instant) {
+        if !active {
+            self.current = self.current.mul_f64(self.backoff).min(self.max);
+            return;
+        }
+
+        if let Some(last) = self.last_activity {
+            let observed = now.saturating_duration_since(last).clamp(self.min, self.max);
+            let block_time = self.block_time.map_or(observed, |block_time| {
+                block_time.mul_f64(1.0 - Self::BLOCK_TIME_WEIGHT)
+                    + observed.mul_f64(Self::BLOCK_TIME_WEIGHT)
+            });
+            self.block_time = Some(block_time);
+        }
+        self.last_activity = Some(now);
+        self.current = self.min;
+    }
+
+    /// Returns the duration to wait before the next poll, starting at `now`.
+    pub fn next_interval(&self, now: Instant) -> Duration {
+        match (self.block_time, self.last_activity) {
+            // Right after activity, wait until the next block is expected.
+            (Some(block_time), Some(last)) if self.current == self.min => {
+                let elapsed = now.saturating_duration_since(last);
+                block_time.saturating_sub(elapsed).max(self.min)
+            }
+            _ => self.current,
+        }
+    }
+}
+
 /// A channel yielding responses from a poller task.
 ///
 /// This stream is backed by a coroutine, and will continue to produce responses
@@ -299,3 +499,61 @@ fn _assert_unpin() {
     fn _assert<T: Unpin>() {}
     _assert::<PollChannel<()>>();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(s: &str) -> Box<RawValue> {
+        RawValue::from_string(s.to_string()).unwrap()
+    }
+
+    #[test]
+    fn detects_activity() {
+        assert!(!is_activity(None, &raw("[]")));
+        assert!(!is_activity(Some(&raw("[\"0x1\"]")), &raw("[ ]")));
+        assert!(is_activity(None, &raw("[\"0x1\"]")));
+        assert!(is_activity(Some(&raw("\"0x1\"")), &raw("\"0x2\"")));
+        assert!(!is_activity(Some(&raw("\"0x2\"")), &raw("\"0x2\"")));
+    }
+
+    #[test]
+    fn adaptive_backoff_and_reset() {
+        let min = Duration::from_secs(1);
+        let max = Duration::from_secs(4);
+        let mut adaptive = AdaptiveInterval::new(min, max).with_backoff(2.0);
+        let now = Instant::now();
+
+        assert_eq!(adaptive.next_interval(now), min);
+        adaptive.observe(false, now);
+        assert_eq!(adaptive.next_interval(now), Duration::from_secs(2));
+        adaptive.observe(false, now);
+        adaptive.observe(false, now);
+        assert_eq!(adaptive.next_interval(now), max);
+
+        adaptive.observe(true, now);
+        assert_eq!(adaptive.next_interval(now), min);
+    }
+
+    #[test]
+    fn adaptive_learns_block_time() {
+        let min = Duration::from_secs(1);
+        let max = Duration::from_secs(30);
+        let mut adaptive = AdaptiveInterval::new(min, max);
+        let start = Instant::now();
+
+        adaptive.observe(true, start);
+        assert_eq!(adaptive.block_time(), None);
+
+        adaptive.observe(true, start + Duration::from_secs(12));
+        assert_eq!(adaptive.block_time(), Some(Duration::from_secs(12)));
+
+        // Right after activity, the next poll is aligned to the next expected block.
+        let now = start + Duration::from_secs(14);
+        assert_eq!(adaptive.next_interval(now), Duration::from_secs(10));
+
+        // Past the expected block, poll at the minimum interval.
+        let now = start + Duration::from_secs(30);
+        assert_eq!(adaptive.next_interval(now), min);
+    }
+}
@@ -13,3 +13,21 @@ async fn it_makes_a_request() {
     let res = timeout.await.unwrap().unwrap();
     assert_eq!(res.to::<u64>(), 0);
 }
+
+#[tokio::test]
+async fn it_skips_dropped_batch_waiters() {
+    let anvil = Anvil::new().spawn();
+    let url = anvil.endpoint();
+    let client = ClientBuilder::default().http(url.parse().unwrap());
+
+    let mut batch = client.new_batch();
+    let chain_id = batch.add_call::<_, U64>("eth_chainId", &()).unwrap();
+    let block_number = batch.add_call::<_, U64>("eth_blockNumber", &()).unwrap();
+    drop(block_number);
+    batch.send().await.unwrap();
+    assert_eq!(chain_id.await.unwrap().to::<u64>(), anvil.chain_id());
+
+    let mut batch = client.new_batch();
+    drop(batch.add_call::<_, U64>("eth_blockNumber", &()).unwrap());
+    batch.send().await.unwrap();
+}
@@ -0,0 +1,472 @@
+//! Types for the builder API used between proposers (e.g. MEV-Boost) and block builders or relays.
+//!
+//! See also <https://ethereum.github.io/builder-specs/>
+
+use crate::{
+    constants::DOMAIN_APPLICATION_BUILDER,
+    relay::{ValidatorRegistration, ValidatorRegistrationMessage},
+    requests::ExecutionRequestsV4,
+    BlsPublicKey, BlsSignature,
+};
+use alloy_eips::eip4844::Bytes48;
+use alloy_primitives::{Address, Bloom, Bytes, B256, U256};
+use alloy_rpc_types_engine::{BlobsBundleV1, ExecutionPayloadV3};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use sha2::{Digest, Sha256};
+
+/// Path of the POST `registerValidator` endpoint.
+pub const REGISTER_VALIDATORS_PATH: &str = "/eth/v1/builder/validators";
+
+/// Path of the POST `submitBlindedBlock` endpoint, which returns the unblinded payload.
+pub const SUBMIT_BLINDED_BLOCK_PATH: &str = "/eth/v1/builder/blinded_blocks";
+
+/// Path of the GET `status` endpoint.
+pub const STATUS_PATH: &str = "/eth/v1/builder/status";
+
+/// The header carrying the fork name of SSZ encoded requests and responses.
+pub const CONSENSUS_VERSION_HEADER: &str = "Eth-Consensus-Version";
+
+/// A signed validator registration, the request body item of [`REGISTER_VALIDATORS_PATH`].
+pub type SignedValidatorRegistrationV1 = ValidatorRegistration;
+
+/// The encoding of builder API request and response bodies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BuilderApiEncoding {
+    /// JSON encoding.
+    #[default]
+    Json,
+    /// SSZ encoding, with the fork name in the [`CONSENSUS_VERSION_HEADER`].
+    Ssz,
+}
+
+impl BuilderApiEncoding {
+    /// Returns the content type of the encoding.
+    pub const fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Ssz => "application/octet-stream",
+        }
+    }
+
+    /// Returns the encoding of the given content type, ignoring any parameters.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+        if mime.eq_ignore_ascii_case("application/json") {
+            Some(Self::Json)
+        } else if mime.eq_ignore_ascii_case("application/octet-stream") {
+            Some(Self::Ssz)
+        } else {
+            None
+        }
+    }
+}
+
+/// Request of the GET `/eth/v1/builder/header/{slot}/{parent_hash}/{pubkey}` endpoint.
+///
+/// See also <https://ethereum.github.io/builder-specs/#/Builder/getHeader>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GetHeaderRequest {
+    /// The slot to request the header for.
+    pub slot: u64,
+    /// The hash of the parent execution block.
+    pub parent_hash: B256,
+    /// The public key of the proposer.
+    pub pubkey: BlsPublicKey,
+}
+
+impl GetHeaderRequest {
+    /// Creates a new request.
+    pub const fn new(slot: u64, parent_hash: B256, pubkey: BlsPublicKey) -> Self {
+        Self { slot, parent_hash, pubkey }
+    }
+
+    /// Returns the path of the request.
+    pub fn path(&self) -> String {
+        format!("/eth/v1/builder/header/{}/{}/{}", self.slot, self.parent_hash, self.pubkey)
+    }
+}
+
+/// The header of a Deneb or Electra execution payload.
+#[serde_as]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+pub struct ExecutionPayloadHeaderV3 {
+    /// The parent hash of the execution payload.
+    pub parent_hash: B256,
+    /// The fee recipient address of the execution payload.
+    pub fee_recipient: Address,
+    /// The state root of the execution payload.
+    pub state_root: B256,
+    /// The receipts root of the execution payload.
+    pub receipts_root: B256,
+    /// The logs bloom filter of the execution payload.
+    pub logs_bloom: Bloom,
+    /// The previous Randao value of the execution payload.
+    pub prev_randao: B256,
+    /// The block number of the execution payload.
+    #[serde_as(as = "DisplayFromStr")]
+    pub block_number: u64,
+    /// The gas limit of the execution payload.
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_limit: u64,
+    /// The gas used by the execution payload.
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_used: u64,
+    /// The timestamp of the execution payload.
+    #[serde_as(as = "DisplayFromStr")]
+    pub timestamp: u64,
+    /// The extra data of the execution payload.
+    pub extra_data: Bytes,
+    /// The base fee per gas of the execution payload.
+    #[serde_as(as = "DisplayFromStr")]
+    pub base_fee_per_gas: U256,
+    /// The block hash of the execution payload.
+    pub block_hash: B256,
+    /// The root of the transactions of the execution payload.
+    pub transactions_root: B256,
+    /// The root of the withdrawals of the execution payload.
+    pub withdrawals_root: B256,
+    /// The blob gas used by the execution payload.
+    #[serde_as(as = "DisplayFromStr")]
+    pub blob_gas_used: u64,
+    /// The excess blob gas of the execution payload.
+    #[serde_as(as = "DisplayFromStr")]
+    pub excess_blob_gas: u64,
+}
+
+/// A bid of a builder for a Deneb block.
+#[serde_as]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+pub struct BuilderBidV3 {
+    /// The header of the offered execution payload.
+    pub header: ExecutionPayloadHeaderV3,
+    /// The KZG commitments of the blobs of the offered payload.
+    pub blob_kzg_commitments: Vec<Bytes48>,
+    /// The value paid to the proposer, in wei.
+    #[serde_as(as = "DisplayFromStr")]
+    pub value: U256,
+    /// The public key of the builder.
+    pub pubkey: BlsPublicKey,
+}
+
+/// A bid of a builder for an Electra block.
+#[serde_as]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+pub struct BuilderBidV4 {
+    /// The header of the offered execution payload.
+    pub header: ExecutionPayloadHeaderV3,
+    /// The KZG commitments of the blobs of the offered payload.
+    pub blob_kzg_commitments: Vec<Bytes48>,
+    /// The execution requests of the offered payload.
+    pub execution_requests: ExecutionRequestsV4,
+    /// The value paid to the proposer, in wei.
+    #[serde_as(as = "DisplayFromStr")]
+    pub value: U256,
+    /// The public key of the builder.
+    pub pubkey: BlsPublicKey,
+}
+
+/// A signed [`BuilderBidV3`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+pub struct SignedBuilderBidV3 {
+    /// The bid.
+    pub message: BuilderBidV3,
+    /// The signature of the builder over the bid.
+    pub signature: BlsSignature,
+}
+
+/// A signed [`BuilderBidV4`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+pub struct SignedBuilderBidV4 {
+    /// The bid.
+    pub message: BuilderBidV4,
+    /// The signature of the builder over the bid.
+    pub signature: BlsSignature,
+}
+
+/// Response of the GET `/eth/v1/builder/header/{slot}/{parent_hash}/{pubkey}` endpoint.
+///
+/// See also <https://ethereum.github.io/builder-specs/#/Builder/getHeader>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum VersionedSignedBuilderBid {
+    /// A Deneb bid.
+    Deneb(SignedBuilderBidV3),
+    /// An Electra bid.
+    Electra(SignedBuilderBidV4),
+}
+
+impl VersionedSignedBuilderBid {
+    /// Returns the fork name of the bid.
+    pub const fn version(&self) -> &'static str {
+        match self {
+            Self::Deneb(_) => "deneb",
+            Self::Electra(_) => "electra",
+        }
+    }
+
+    /// Returns the value of the bid.
+    pub const fn value(&self) -> U256 {
+        match self {
+            Self::Deneb(bid) => bid.message.value,
+            Self::Electra(bid) => bid.message.value,
+        }
+    }
+
+    /// Returns the header of the offered execution payload.
+    pub const fn header(&self) -> &ExecutionPayloadHeaderV3 {
+        match self {
+            Self::Deneb(bid) => &bid.message.header,
+            Self::Electra(bid) => &bid.message.header,
+        }
+    }
+
+    /// Returns the public key of the builder.
+    pub const fn pubkey(&self) -> &BlsPublicKey {
+        match self {
+            Self::Deneb(bid) => &bid.message.pubkey,
+            Self::Electra(bid) => &bid.message.pubkey,
+        }
+    }
+
+    /// Returns the signature of the builder.
+    pub const fn signature(&self) -> &BlsSignature {
+        match self {
+            Self::Deneb(bid) => &bid.signature,
+            Self::Electra(bid) => &bid.signature,
+        }
+    }
+
+    /// Decodes an SSZ encoded bid of the fork named in the [`CONSENSUS_VERSION_HEADER`].
+    #[cfg(feature = "ssz")]
+    pub fn from_ssz_bytes(version: &str, bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+        use ssz::Decode;
+
+        match version.to_ascii_lowercase().as_str() {
+            "deneb" => SignedBuilderBidV3::from_ssz_bytes(bytes).map(Self::Deneb),
+            "electra" => SignedBuilderBidV4::from_ssz_bytes(bytes).map(Self::Electra),
+            _ => Err(ssz::DecodeError::BytesInvalid(format!("unsupported fork {version}"))),
+        }
+    }
+
+    /// Returns the SSZ encoding of the bid, without the version.
+    #[cfg(feature = "ssz")]
+    pub fn as_ssz_bytes(&self) -> Vec<u8> {
+        use ssz::Encode;
+
+        match self {
+            Self::Deneb(bid) => bid.as_ssz_bytes(),
+            Self::Electra(bid) => bid.as_ssz_bytes(),
+        }
+    }
+}
+
+/// The unblinded execution payload together with its blobs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+pub struct ExecutionPayloadAndBlobsBundle {
+    /// The execution payload.
+    #[serde(with = "crate::payload::beacon_payload_v3")]
+    pub execution_payload: ExecutionPayloadV3,
+    /// The blobs of the execution payload.
+    pub blobs_bundle: BlobsBundleV1,
+}
+
+/// Response of the POST [`SUBMIT_BLINDED_BLOCK_PATH`] endpoint.
+///
+/// See also <https://ethereum.github.io/builder-specs/#/Builder/submitBlindedBlock>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum VersionedExecutionPayloadAndBlobsBundle {
+    /// A Deneb payload.
+    Deneb(ExecutionPayloadAndBlobsBundle),
+    /// An Electra payload.
+    Electra(ExecutionPayloadAndBlobsBundle),
+}
+
+impl VersionedExecutionPayloadAndBlobsBundle {
+    /// Returns the fork name of the payload.
+    pub const fn version(&self) -> &'static str {
+        match self {
+            Self::Deneb(_) => "deneb",
+            Self::Electra(_) => "electra",
+        }
+    }
+
+    /// Returns the payload and its blobs.
+    pub const fn payload(&self) -> &ExecutionPayloadAndBlobsBundle {
+        match self {
+            Self::Deneb(payload) | Self::Electra(payload) => payload,
+        }
+    }
+
+    /// Consumes the type and returns the payload and its blobs.
+    pub fn into_payload(self) -> ExecutionPayloadAndBlobsBundle {
+        match self {
+            Self::Deneb(payload) | Self::Electra(payload) => payload,
+        }
+    }
+
+    /// Decodes an SSZ encoded payload of the fork named in the [`CONSENSUS_VERSION_HEADER`].
+    #[cfg(feature = "ssz")]
+    pub fn from_ssz_bytes(version: &str, bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+        use ssz::Decode;
+
+        let payload = ExecutionPayloadAndBlobsBundle::from_ssz_bytes(bytes)?;
+        match version.to_ascii_lowercase().as_str() {
+            "deneb" => Ok(Self::Deneb(payload)),
+            "electra" => Ok(Self::Electra(payload)),
+            _ => Err(ssz::DecodeError::BytesInvalid(format!("unsupported fork {version}"))),
+        }
+    }
+
+    /// Returns the SSZ encoding of the payload, without the version.
+    #[cfg(feature = "ssz")]
+    pub fn as_ssz_bytes(&self) -> Vec<u8> {
+        ssz::Encode::as_ssz_bytes(self.payload())
+    }
+}
+
+/// Computes the domain of builder API signatures.
+///
+/// Builder signatures are always bound to the genesis fork version and a zero genesis validators
+/// root, see <https://github.com/ethereum/builder-specs/blob/main/specs/bellatrix/builder.md#signing>
+pub fn compute_builder_domain(genesis_fork_version: [u8; 4]) -> B256 {
+    // hash_tree_root(ForkData { current_version, genesis_validators_root: 0 })
+    let mut version = [0u8; 32];
+    version[..4].copy_from_slice(&genesis_fork_version);
+    let fork_data_root = hash_pair(&version, &[0u8; 32]);
+
+    let mut domain = B256::ZERO;
+    domain[..4].copy_from_slice(&DOMAIN_APPLICATION_BUILDER);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain
+}
+
+/// Computes the signing root of an object, which is the message signed with BLS.
+///
+/// This is the `hash_tree_root` of the `SigningData` container.
+pub fn compute_signing_root(object_root: B256, domain: B256) -> B256 {
+    hash_pair(object_root.as_slice(), domain.as_slice()).into()
+}
+
+impl ValidatorRegistrationMessage {
+    /// Returns the SSZ `hash_tree_root` of the registration.
+    pub fn tree_hash_root(&self) -> B256 {
+        let mut fee_recipient = [0u8; 32];
+        fee_recipient[..20].copy_from_slice(self.fee_recipient.as_slice());
+        let mut gas_limit = [0u8; 32];
+        gas_limit[..8].copy_from_slice(&self.gas_limit.to_le_bytes());
+        let mut timestamp = [0u8; 32];
+        timestamp[..8].copy_from_slice(&self.timestamp.to_le_bytes());
+        let mut pubkey = [0u8; 64];
+        pubkey[..48].copy_from_slice(self.pubkey.as_slice());
+        let pubkey = Sha256::digest(pubkey);
+
+        hash_pair(&hash_pair(&fee_recipient, &gas_limit), &hash_pair(&timestamp, &pubkey)).into()
+    }
+
+    /// Returns the signing root of the registration for the given genesis fork version.
+    pub fn signing_root(&self, genesis_fork_version: [u8; 4]) -> B256 {
+        compute_signing_root(self.tree_hash_root(), compute_builder_domain(genesis_fork_version))
+    }
+}
+
+fn hash_pair(left: &[u8], right: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::GENESIS_FORK_VERSION_MAINNET;
+    use alloy_primitives::{b256, fixed_bytes};
+    use similar_asserts::assert_eq;
+
+    #[test]
+    fn builder_domain() {
+        assert_eq!(
+            compute_builder_domain(GENESIS_FORK_VERSION_MAINNET),
+            b256!("00000001f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a9")
+        );
+    }
+
+    #[test]
+    fn registration_signing_root() {
+        let registration = ValidatorRegistrationMessage {
+            fee_recipient: Address::repeat_byte(0x33),
+            gas_limit: 30_000_000,
+            timestamp: 1_700_000_000,
+            pubkey: BlsPublicKey::repeat_byte(0xaa),
+        };
+        assert_eq!(
+            registration.tree_hash_root(),
+            b256!("ecb4a84c6f7d150afabb52ebfa62d78ed54af038351547749baef477da2381f3")
+        );
+        assert_eq!(
+            registration.signing_root(GENESIS_FORK_VERSION_MAINNET),
+            b256!("7bc0aaa17ddeb24eb36fe7c70eb61f22b2007f5b5e48883b0de36b119caa9b79")
+        );
+    }
+
+    #[test]
+    fn get_header_path() {
+        let request = GetHeaderRequest::new(
+            7,
+            B256::repeat_byte(0x11),
+            fixed_bytes!("93247f2209abcacf57b75a51dafae777f9dd38bc7053d1af526f220a7489a6d3a2753e5f3e8b1cfe39b56f43611df74a"),
+        );
+        assert_eq!(
+            request.path(),
+            "/eth/v1/builder/header/7/0x1111111111111111111111111111111111111111111111111111111111111111/0x93247f2209abcacf57b75a51dafae777f9dd38bc7053d1af526f220a7489a6d3a2753e5f3e8b1cfe39b56f43611df74a"
+        );
+    }
+
+    #[test]
+    fn encoding_content_type() {
+        assert_eq!(
+            BuilderApiEncoding::from_content_type("application/json; charset=utf-8"),
+            Some(BuilderApiEncoding::Json)
+        );
+        assert_eq!(
+            BuilderApiEncoding::from_content_type(BuilderApiEncoding::Ssz.content_type()),
+            Some(BuilderApiEncoding::Ssz)
+        );
+        assert_eq!(BuilderApiEncoding::from_content_type("text/plain"), None);
+    }
+
+    #[test]
+    fn serde_get_header_response() {
+        let s = r#"{"version":"deneb","data":{"message":{"header":{"parent_hash":"0xcf8e0d4e9587369b2301d0790347320302cc0943d5a1884560367e8208d920f2","fee_recipient":"0xabcf8e0d4e9587369b2301d0790347320302cc09","state_root":"0xcf8e0d4e9587369b2301d0790347320302cc0943d5a1884560367e8208d920f2","receipts_root":"0xcf8e0d4e9587369b2301d0790347320302cc0943d5a1884560367e8208d920f2","logs_bloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","prev_randao":"0xcf8e0d4e9587369b2301d0790347320302cc0943d5a1884560367e8208d920f2","block_number":"1","gas_limit":"1","gas_used":"1","timestamp":"1","extra_data":"0xcf8e0d4e9587369b2301d0790347320302cc0943d5a1884560367e8208d920f2","base_fee_per_gas":"1","block_hash":"0xcf8e0d4e9587369b2301d0790347320302cc0943d5a1884560367e8208d920f2","transactions_root":"0xcf8e0d4e9587369b2301d0790347320302cc0943d5a1884560367e8208d920f2","withdrawals_root":"0xcf8e0d4e9587369b2301d0790347320302cc0943d5a1884560367e8208d920f2","blob_gas_used":"131072","excess_blob_gas":"0"},"blob_kzg_commitments":["0xa94170080872584e54a1cf092d845703b13907f2e6b3b1c0ad573b910530499e3bcd48c6378846b80d2bfa58c81cf3d5"],"value":"1","pubkey":"0x93247f2209abcacf57b75a51dafae777f9dd38bc7053d1af526f220a7489a6d3a2753e5f3e8b1cfe39b56f43611df74a"},"signature":"0x1b66ac1fb663c9bc59509846d6ec05345bd908eda73e670af888da41af171505cc411d61252fb6cb3fa0017b679f8bb2305b26a285fa2737f175668d0dff91cc1b66ac1fb663c9bc59509846d6ec05345bd908eda73e670af888da41af171505"}}"#;
+        let resp: VersionedSignedBuilderBid = serde_json::from_str(s).unwrap();
+        assert_eq!(resp.version(), "deneb");
+        assert_eq!(resp.value(), U256::from(1));
+        assert_eq!(resp.header().blob_gas_used, 131072);
+        let json: serde_json::Value = serde_json::from_str(s).unwrap();
+        assert_eq!(json, serde_json::to_value(resp).unwrap());
+    }
+
+    #[cfg(feature = "ssz")]
+    #[test]
+    fn ssz_roundtrip_bid() {
+        let bid = VersionedSignedBuilderBid::Electra(SignedBuilderBidV4 {
+            message: BuilderBidV4 {
+                blob_kzg_commitments: vec![Bytes48::repeat_byte(0x01)],
+                value: U256::from(42),
+                ..Default::default()
+            },
+            signature: BlsSignature::repeat_byte(0x02),
+        });
+        let bytes = bid.as_ssz_bytes();
+        assert_eq!(VersionedSignedBuilderBid::from_ssz_bytes("electra", &bytes).unwrap(), bid);
+        assert!(VersionedSignedBuilderBid::from_ssz_bytes("bellatrix", &bytes).is_err());
+    }
+}
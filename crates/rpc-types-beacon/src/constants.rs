@@ -15,3 +15,11 @@ pub const BLS_SECRET_KEY_BYTES_LEN: usize = 32;
 
 /// The number of bytes in a BLS12-381 signature.
 pub const BLS_SIGNATURE_BYTES_LEN: usize = 96;
+
+/// The domain type of builder API signatures, e.g. validator registrations and builder bids.
+///
+/// See <https://github.com/ethereum/builder-specs/blob/main/specs/bellatrix/builder.md#domain-types>
+pub const DOMAIN_APPLICATION_BUILDER: [u8; 4] = [0x00, 0x00, 0x00, 0x01];
+
+/// The genesis fork version of Ethereum mainnet.
+pub const GENESIS_FORK_VERSION_MAINNET: [u8; 4] = [0x00, 0x00, 0x00, 0x00];
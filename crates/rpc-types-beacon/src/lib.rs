@@ -9,6 +9,9 @@
 use alloy_primitives::FixedBytes;
 use constants::{BLS_PUBLIC_KEY_BYTES_LEN, BLS_SIGNATURE_BYTES_LEN};
 
+/// Types and functions related to the builder API.
+pub mod builder;
+
 /// Constants used in the Beacon API.
 pub mod constants;
 
@@ -18,6 +21,9 @@ pub mod events;
 /// Types and functions related to the beacon block header.
 pub mod header;
 
+/// Types for the light client endpoints.
+pub mod light_client;
+
 /// Types and functions related to the beacon block payload.
 pub mod payload;
 
@@ -0,0 +1,237 @@
+//! Types for the light client endpoints of the beacon API.
+//!
+//! See also <https://github.com/ethereum/consensus-specs/blob/dev/specs/deneb/light-client/sync-protocol.md>
+
+use crate::{header::BeaconBlockHeader, BlsPublicKey, BlsSignature};
+use alloy_primitives::{Address, Bloom, Bytes, B256, U256};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Versioned response of the light client endpoints, e.g.
+/// GET `/eth/v1/beacon/light_client/bootstrap/{block_root}`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientResponse<T> {
+    /// The fork the data belongs to, e.g. `deneb`.
+    pub version: String,
+    /// The response data.
+    pub data: T,
+}
+
+/// The execution payload header as embedded in a [`LightClientHeader`].
+///
+/// The blob gas fields are only present since Deneb.
+#[serde_as]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientExecutionHeader {
+    /// The parent hash of the execution block.
+    pub parent_hash: B256,
+    /// The fee recipient of the execution block.
+    pub fee_recipient: Address,
+    /// The state root of the execution block.
+    pub state_root: B256,
+    /// The receipts root of the execution block.
+    pub receipts_root: B256,
+    /// The logs bloom of the execution block.
+    pub logs_bloom: Bloom,
+    /// The previous randao value of the execution block.
+    pub prev_randao: B256,
+    /// The number of the execution block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub block_number: u64,
+    /// The gas limit of the execution block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_limit: u64,
+    /// The gas used by the execution block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_used: u64,
+    /// The timestamp of the execution block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub timestamp: u64,
+    /// The extra data of the execution block.
+    pub extra_data: Bytes,
+    /// The base fee per gas of the execution block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub base_fee_per_gas: U256,
+    /// The hash of the execution block.
+    pub block_hash: B256,
+    /// The root of the transactions list.
+    pub transactions_root: B256,
+    /// The root of the withdrawals list.
+    pub withdrawals_root: B256,
+    /// The blob gas used by the execution block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub blob_gas_used: Option<u64>,
+    /// The excess blob gas of the execution block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub excess_blob_gas: Option<u64>,
+}
+
+/// A beacon block header together with the execution payload header it commits to.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientHeader {
+    /// The beacon block header.
+    pub beacon: BeaconBlockHeader,
+    /// The execution payload header.
+    pub execution: LightClientExecutionHeader,
+    /// The merkle branch of the execution payload header in the beacon block body.
+    pub execution_branch: Vec<B256>,
+}
+
+/// The sync committee of a sync committee period.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncCommittee {
+    /// The public keys of the committee members.
+    pub pubkeys: Vec<BlsPublicKey>,
+    /// The aggregate of all public keys.
+    pub aggregate_pubkey: BlsPublicKey,
+}
+
+/// The participation bits and aggregate signature of a sync committee.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientSyncAggregate {
+    /// The bitvector of participating committee members.
+    pub sync_committee_bits: Bytes,
+    /// The aggregate signature of the participating members.
+    pub sync_committee_signature: BlsSignature,
+}
+
+/// Response data of GET `/eth/v1/beacon/light_client/bootstrap/{block_root}`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientBootstrap {
+    /// The header of the trusted block.
+    pub header: LightClientHeader,
+    /// The sync committee of the period of the trusted block.
+    pub current_sync_committee: SyncCommittee,
+    /// The merkle branch of the current sync committee in the beacon state.
+    pub current_sync_committee_branch: Vec<B256>,
+}
+
+/// A single update of GET `/eth/v1/beacon/light_client/updates`.
+#[serde_as]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientUpdate {
+    /// The header attested to by the sync committee.
+    pub attested_header: LightClientHeader,
+    /// The sync committee of the next period.
+    pub next_sync_committee: SyncCommittee,
+    /// The merkle branch of the next sync committee in the attested beacon state.
+    pub next_sync_committee_branch: Vec<B256>,
+    /// The finalized header of the attested beacon state.
+    pub finalized_header: LightClientHeader,
+    /// The merkle branch of the finalized checkpoint in the attested beacon state.
+    pub finality_branch: Vec<B256>,
+    /// The sync committee participation and signature.
+    pub sync_aggregate: LightClientSyncAggregate,
+    /// The slot at which the sync aggregate was included.
+    #[serde_as(as = "DisplayFromStr")]
+    pub signature_slot: u64,
+}
+
+/// Response data of GET `/eth/v1/beacon/light_client/finality_update`.
+#[serde_as]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientFinalityUpdate {
+    /// The header attested to by the sync committee.
+    pub attested_header: LightClientHeader,
+    /// The finalized header of the attested beacon state.
+    pub finalized_header: LightClientHeader,
+    /// The merkle branch of the finalized checkpoint in the attested beacon state.
+    pub finality_branch: Vec<B256>,
+    /// The sync committee participation and signature.
+    pub sync_aggregate: LightClientSyncAggregate,
+    /// The slot at which the sync aggregate was included.
+    #[serde_as(as = "DisplayFromStr")]
+    pub signature_slot: u64,
+}
+
+/// Response data of GET `/eth/v1/beacon/light_client/optimistic_update`.
+#[serde_as]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientOptimisticUpdate {
+    /// The header attested to by the sync committee.
+    pub attested_header: LightClientHeader,
+    /// The sync committee participation and signature.
+    pub sync_aggregate: LightClientSyncAggregate,
+    /// The slot at which the sync aggregate was included.
+    #[serde_as(as = "DisplayFromStr")]
+    pub signature_slot: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_light_client_header() {
+        let s = r#"{
+            "beacon": {
+                "slot": "8626176",
+                "proposer_index": "1234",
+                "parent_root": "0x7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b",
+                "state_root": "0x1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e6d",
+                "body_root": "0x6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e"
+            },
+            "execution": {
+                "parent_hash": "0x7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b",
+                "fee_recipient": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+                "state_root": "0x1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e6d",
+                "receipts_root": "0x6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e",
+                "logs_bloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                "prev_randao": "0x3e6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c",
+                "block_number": "19000000",
+                "gas_limit": "30000000",
+                "gas_used": "12000000",
+                "timestamp": "1705473371",
+                "extra_data": "0x6265617665726275696c642e6f7267",
+                "base_fee_per_gas": "23000000000",
+                "block_hash": "0x5c3e6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e2",
+                "transactions_root": "0xe25c3e6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5",
+                "withdrawals_root": "0x8e5e25c3e6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f",
+                "blob_gas_used": "262144",
+                "excess_blob_gas": "0"
+            },
+            "execution_branch": [
+                "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "0x0000000000000000000000000000000000000000000000000000000000000002",
+                "0x0000000000000000000000000000000000000000000000000000000000000003",
+                "0x0000000000000000000000000000000000000000000000000000000000000004"
+            ]
+        }"#;
+        let header: LightClientHeader = serde_json::from_str(s).unwrap();
+        assert_eq!(header.beacon.slot, 8626176);
+        assert_eq!(header.execution.block_number, 19000000);
+        assert_eq!(header.execution.base_fee_per_gas, U256::from(23000000000u64));
+        assert_eq!(header.execution.blob_gas_used, Some(262144));
+        assert_eq!(header.execution_branch.len(), 4);
+
+        let json = serde_json::to_value(&header).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(s).unwrap();
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn serde_capella_execution_header() {
+        let s = r#"{
+            "parent_hash": "0x7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b",
+            "fee_recipient": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+            "state_root": "0x1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e6d",
+            "receipts_root": "0x6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c3e",
+            "logs_bloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "prev_randao": "0x3e6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e25c",
+            "block_number": "17034870",
+            "gas_limit": "30000000",
+            "gas_used": "12000000",
+            "timestamp": "1681338479",
+            "extra_data": "0x",
+            "base_fee_per_gas": "23000000000",
+            "block_hash": "0x5c3e6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5e2",
+            "transactions_root": "0xe25c3e6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f8e5",
+            "withdrawals_root": "0x8e5e25c3e6d1b7a6b3c1c0b1e3a9c3f8e5e25c3e6d1b1a7a6b3c1c0b1e3a9c3f"
+        }"#;
+        let header: LightClientExecutionHeader = serde_json::from_str(s).unwrap();
+        assert_eq!(header.blob_gas_used, None);
+        assert_eq!(header.excess_blob_gas, None);
+    }
+}
@@ -26,6 +26,7 @@ pub struct Validator {
 
 /// Details of a validator registration.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
 pub struct ValidatorRegistration {
     /// The registration message.
     pub message: ValidatorRegistrationMessage,
@@ -36,6 +37,7 @@ pub struct ValidatorRegistration {
 /// Represents the message of a validator registration.
 #[serde_as]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
 pub struct ValidatorRegistrationMessage {
     /// The fee recipient's address.
     pub fee_recipient: Address,
@@ -1,6 +1,7 @@
 //! Types for the `debug` API.
 
-use alloy_primitives::{map::B256HashMap, Bytes};
+use alloy_primitives::{keccak256, map::B256HashMap, Bytes, B256};
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
 use serde::{Deserialize, Serialize};
 
 /// Represents the execution witness of a block. Contains an optional map of state preimages.
@@ -23,3 +24,119 @@ pub struct ExecutionWitness {
     /// `keccak(address|slot) => address|slot`
     pub keys: B256HashMap<Bytes>,
 }
+
+impl ExecutionWitness {
+    fn as_rlp(&self) -> RlpExecutionWitness {
+        let sorted = |map: &B256HashMap<Bytes>| {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_unstable_by_key(|(hash, _)| *hash);
+            entries.into_iter().map(|(_, preimage)| preimage.clone()).collect()
+        };
+        RlpExecutionWitness {
+            state: sorted(&self.state),
+            codes: sorted(&self.codes),
+            keys: sorted(&self.keys),
+        }
+    }
+}
+
+/// RLP encoding of an [`ExecutionWitness`], which only includes the preimages sorted by hash.
+#[derive(RlpEncodable, RlpDecodable)]
+struct RlpExecutionWitness {
+    state: Vec<Bytes>,
+    codes: Vec<Bytes>,
+    keys: Vec<Bytes>,
+}
+
+impl Encodable for ExecutionWitness {
+    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.as_rlp().encode(out)
+    }
+
+    fn length(&self) -> usize {
+        self.as_rlp().length()
+    }
+}
+
+impl Decodable for ExecutionWitness {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let RlpExecutionWitness { state, codes, keys } = RlpExecutionWitness::decode(buf)?;
+        let by_hash = |preimages: Vec<Bytes>| {
+            preimages.into_iter().map(|preimage| (keccak256(&preimage), preimage)).collect()
+        };
+        Ok(Self { state: by_hash(state), codes: by_hash(codes), keys: by_hash(keys) })
+    }
+}
+
+/// The result of a `debug_storageRangeAt` call: a page of the storage of an account.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageRangeResult {
+    /// The storage entries of the page, keyed by the hashed storage slot.
+    pub storage: B256HashMap<StorageRangeEntry>,
+    /// The hashed slot to start the next page at, or `None` if this is the last page.
+    pub next_key: Option<B256>,
+}
+
+impl StorageRangeResult {
+    /// Returns whether this is the last page of the storage range.
+    pub const fn is_complete(&self) -> bool {
+        self.next_key.is_none()
+    }
+}
+
+/// A storage entry of a [`StorageRangeResult`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageRangeEntry {
+    /// The unhashed storage slot, if its preimage is known to the node.
+    pub key: Option<B256>,
+    /// The storage value.
+    pub value: B256,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn execution_witness_rlp_roundtrip() {
+        let node = Bytes::from_static(&[0xc1, 0x80]);
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let mut witness = ExecutionWitness::default();
+        witness.state.insert(keccak256(&node), node);
+        witness.codes.insert(keccak256(&code), code);
+
+        let encoded = alloy_rlp::encode(&witness);
+        assert_eq!(encoded.len(), witness.length());
+        assert_eq!(alloy_rlp::decode_exact::<ExecutionWitness>(&encoded).unwrap(), witness);
+    }
+
+    #[test]
+    fn deserialize_storage_range() {
+        let s = r#"{
+            "storage": {
+                "0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563": {
+                    "key": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                    "value": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                },
+                "0xb10e2d527612073b26eecdfd717e6a320cf44b4afac2b0732d9fcbe2b7fa0cf6": {
+                    "key": null,
+                    "value": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                }
+            },
+            "nextKey": "0x405787fa12a823e0f2b7631cc41b3ba8828b3321ca811111fa75cd3aa3bb5ace"
+        }"#;
+        let range: StorageRangeResult = serde_json::from_str(s).unwrap();
+        assert_eq!(range.storage.len(), 2);
+        let entry = range.storage
+            [&b256!("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563")];
+        assert_eq!(entry.key, Some(B256::ZERO));
+        assert_eq!(entry.value, B256::with_last_byte(1));
+        assert!(!range.is_complete());
+
+        let roundtrip: StorageRangeResult =
+            serde_json::from_str(&serde_json::to_string(&range).unwrap()).unwrap();
+        assert_eq!(roundtrip, range);
+    }
+}
@@ -8,3 +8,6 @@
 
 mod debug;
 pub use debug::*;
+
+mod witness;
+pub use witness::*;
@@ -0,0 +1,253 @@
+//! Execution witness formats used for stateless execution.
+
+use crate::ExecutionWitness;
+use alloy_consensus::Header;
+use alloy_primitives::{keccak256, Bytes, FixedBytes, B256};
+use alloy_rlp::{RlpDecodable, RlpEncodable};
+use serde::{Deserialize, Serialize};
+
+/// The execution witness of a block in the format returned by geth's `debug_executionWitness`.
+///
+/// Unlike [`ExecutionWitness`], trie nodes and codes are plain lists instead of maps keyed by their
+/// hash, and the witness carries the ancestor headers required by `BLOCKHASH`.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+pub struct StatelessWitness {
+    /// The ancestor headers of the block in reverse order, starting with the parent.
+    pub headers: Vec<Header>,
+    /// The contract codes accessed during the execution of the block.
+    pub codes: Vec<Bytes>,
+    /// The trie nodes accessed during the execution of the block, including during state root
+    /// recomputation.
+    pub state: Vec<Bytes>,
+}
+
+impl StatelessWitness {
+    /// Returns the header of the parent block, if included.
+    pub fn parent_header(&self) -> Option<&Header> {
+        self.headers.first()
+    }
+
+    /// Returns the state root the witness is rooted at, i.e. the state root of the parent block.
+    pub fn pre_state_root(&self) -> Option<B256> {
+        self.parent_header().map(|header| header.state_root)
+    }
+}
+
+impl From<StatelessWitness> for ExecutionWitness {
+    fn from(witness: StatelessWitness) -> Self {
+        Self {
+            state: witness.state.into_iter().map(|node| (keccak256(&node), node)).collect(),
+            codes: witness.codes.into_iter().map(|code| (keccak256(&code), code)).collect(),
+            keys: Default::default(),
+        }
+    }
+}
+
+/// The execution witness of a block in the Verkle format of [EIP-6800].
+///
+/// [EIP-6800]: https://eips.ethereum.org/EIPS/eip-6800
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerkleExecutionWitness {
+    /// The pre- and post-state values of all accessed leaves, grouped by stem.
+    pub state_diff: Vec<StemStateDiff>,
+    /// The proof of the pre-state values.
+    pub verkle_proof: VerkleProof,
+}
+
+/// The accessed leaves of a stem in a [`VerkleExecutionWitness`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StemStateDiff {
+    /// The stem, i.e. the first 31 bytes of the tree keys.
+    pub stem: FixedBytes<31>,
+    /// The accessed leaves of the stem.
+    pub suffix_diffs: Vec<SuffixStateDiff>,
+}
+
+/// An accessed leaf in a [`StemStateDiff`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuffixStateDiff {
+    /// The last byte of the tree key.
+    pub suffix: u8,
+    /// The value before the execution of the block, `None` if absent.
+    pub current_value: Option<B256>,
+    /// The value after the execution of the block, `None` if unchanged.
+    pub new_value: Option<B256>,
+}
+
+/// The multiproof of the pre-state values of a [`VerkleExecutionWitness`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerkleProof {
+    /// The stems proven absent through extension nodes of other stems.
+    pub other_stems: Vec<FixedBytes<31>>,
+    /// The depth and extension status of every proven stem.
+    pub depth_extension_present: Bytes,
+    /// The commitments along the paths of the proven stems.
+    pub commitments_by_path: Vec<B256>,
+    /// The commitment of the aggregated polynomial.
+    pub d: B256,
+    /// The inner product argument proof.
+    pub ipa_proof: IpaProof,
+}
+
+/// The inner product argument proof of a [`VerkleProof`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpaProof {
+    /// The left commitments of every round.
+    pub cl: Vec<B256>,
+    /// The right commitments of every round.
+    pub cr: Vec<B256>,
+    /// The final evaluation.
+    pub final_evaluation: B256,
+}
+
+/// The number of code bytes in a chunk of [EIP-6800] code chunking.
+///
+/// [EIP-6800]: https://eips.ethereum.org/EIPS/eip-6800
+pub const CODE_CHUNK_SIZE: usize = 31;
+
+/// Splits contract code into the 32 byte chunks stored in the Verkle tree.
+///
+/// Every chunk consists of the number of leading bytes that are `PUSH` data, followed by 31 bytes
+/// of code. The last chunk is padded with zeros.
+///
+/// See also <https://eips.ethereum.org/EIPS/eip-6800#code>
+pub fn chunkify_code(code: &[u8]) -> Vec<B256> {
+    const PUSH1: u8 = 0x60;
+    const PUSH32: u8 = 0x7f;
+
+    let mut pushdata = vec![0u8; code.len().next_multiple_of(CODE_CHUNK_SIZE)];
+    let mut pos = 0;
+    while pos < code.len() {
+        let len = match code[pos] {
+            op @ PUSH1..=PUSH32 => (op - PUSH1 + 1) as usize,
+            _ => 0,
+        };
+        pos += 1;
+        for i in 0..len {
+            if let Some(remaining) = pushdata.get_mut(pos + i) {
+                *remaining = (len - i) as u8;
+            }
+        }
+        pos += len;
+    }
+
+    pushdata
+        .chunks(CODE_CHUNK_SIZE)
+        .enumerate()
+        .map(|(i, pushdata)| {
+            let start = i * CODE_CHUNK_SIZE;
+            let end = code.len().min(start + CODE_CHUNK_SIZE);
+            let mut chunk = B256::ZERO;
+            chunk[0] = pushdata[0].min(CODE_CHUNK_SIZE as u8);
+            chunk[1..1 + end - start].copy_from_slice(&code[start..end]);
+            chunk
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn stateless_witness_rlp_roundtrip() {
+        let witness = StatelessWitness {
+            headers: vec![Header { number: 1, ..Default::default() }],
+            codes: vec![hex!("6000").into()],
+            state: vec![hex!("c0").into(), hex!("c180").into()],
+        };
+        let encoded = alloy_rlp::encode(&witness);
+        let decoded: StatelessWitness = alloy_rlp::decode_exact(&encoded).unwrap();
+        assert_eq!(decoded, witness);
+
+        let json = serde_json::to_string(&witness).unwrap();
+        assert_eq!(serde_json::from_str::<StatelessWitness>(&json).unwrap(), witness);
+    }
+
+    #[test]
+    fn stateless_witness_to_execution_witness() {
+        let node = Bytes::from_static(&[0xc1, 0x80]);
+        let witness = StatelessWitness {
+            headers: vec![Header { state_root: B256::repeat_byte(1), ..Default::default() }],
+            codes: vec![],
+            state: vec![node.clone()],
+        };
+        assert_eq!(witness.pre_state_root(), Some(B256::repeat_byte(1)));
+
+        let witness = ExecutionWitness::from(witness);
+        assert_eq!(witness.state[&keccak256(&node)], node);
+    }
+
+    #[test]
+    fn deserialize_verkle_witness() {
+        let s = r#"{
+            "stateDiff": [
+                {
+                    "stem": "0x5b5fdfedd6a0e932da408ac7d772a36513d1eee9b9926e52620c43a433aad7",
+                    "suffixDiffs": [
+                        {
+                            "suffix": 0,
+                            "currentValue": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                            "newValue": null
+                        },
+                        {
+                            "suffix": 1,
+                            "currentValue": null,
+                            "newValue": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    ]
+                }
+            ],
+            "verkleProof": {
+                "otherStems": [],
+                "depthExtensionPresent": "0x0a",
+                "commitmentsByPath": [],
+                "d": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "ipaProof": {
+                    "cl": [],
+                    "cr": [],
+                    "finalEvaluation": "0x0000000000000000000000000000000000000000000000000000000000000000"
+                }
+            }
+        }"#;
+        let witness: VerkleExecutionWitness = serde_json::from_str(s).unwrap();
+        let diffs = &witness.state_diff[0].suffix_diffs;
+        assert_eq!(diffs[0].current_value, Some(B256::ZERO));
+        assert_eq!(diffs[1].new_value, Some(B256::with_last_byte(1)));
+        assert_eq!(witness.verkle_proof.depth_extension_present, Bytes::from_static(&[0x0a]));
+    }
+
+    #[test]
+    fn chunkify() {
+        assert!(chunkify_code(&[]).is_empty());
+
+        // PUSH4 at the end of the first chunk
+        let mut code = vec![0u8; 30];
+        code.push(0x63);
+        code.extend([0xaa; 4]);
+        let chunks = chunkify_code(&code);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0][0], 0);
+        assert_eq!(chunks[0][31], 0x63);
+        assert_eq!(chunks[1][0], 4);
+        assert_eq!(chunks[1][1..5], [0xaa; 4]);
+        assert_eq!(chunks[1][5..], [0; 27]);
+
+        // PUSH32 spanning three chunks
+        let mut code = vec![0u8; 30];
+        code.push(0x7f);
+        code.extend([0x11; 32]);
+        let chunks = chunkify_code(&code);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[1][0], 31);
+        assert_eq!(chunks[2][0], 1);
+    }
+}
@@ -53,6 +53,9 @@ pub enum PayloadError {
     /// requests present in pre-prague payload.
     #[display("requests present in pre-prague payload")]
     PrePragueBlockRequests,
+    /// requests missing in post-prague payload.
+    #[display("requests missing in post-prague payload")]
+    PostPragueBlockWithoutRequests,
     /// Invalid payload block hash.
     #[display("block hash mismatch: want {consensus}, got {execution}")]
     BlockHash {
@@ -1,6 +1,6 @@
 //! Payload types.
 
-use crate::{ExecutionPayloadSidecar, PayloadError};
+use crate::{ExecutionPayloadSidecar, ForkchoiceUpdateVersion, PayloadError};
 use alloc::{
     string::{String, ToString},
     vec::Vec,
@@ -10,7 +10,7 @@ use alloy_consensus::{
     EMPTY_OMMER_ROOT_HASH,
 };
 use alloy_eips::{
-    eip2718::{Decodable2718, Encodable2718},
+    eip2718::{Decodable2718, Encodable2718, Typed2718},
     eip4844::BlobTransactionSidecar,
     eip4895::{Withdrawal, Withdrawals},
     eip7685::Requests,
@@ -877,6 +877,92 @@ impl ExecutionPayload {
         }
     }
 
+    /// Converts [`alloy_consensus::Block`] to an [`ExecutionPayload`] of the given engine API
+    /// version, and also returns the [`ExecutionPayloadSidecar`] extracted from the block.
+    ///
+    /// Unlike [`ExecutionPayload::from_block_unchecked`], this checks that the block has exactly
+    /// the fields of the version: withdrawals from V2, blob gas fields and the parent beacon block
+    /// root from V3, and the requests hash from V4. Blob transactions are rejected before V3 and
+    /// EIP-7702 transactions before V4.
+    pub fn try_from_block_versioned<T>(
+        block_hash: B256,
+        block: &Block<T>,
+        version: ForkchoiceUpdateVersion,
+    ) -> Result<(Self, ExecutionPayloadSidecar), PayloadError>
+    where
+        T: Encodable2718 + Transaction,
+    {
+        validate_block_fields(block, version)?;
+
+        let execution_payload = match version {
+            ForkchoiceUpdateVersion::V1 => {
+                Self::V1(ExecutionPayloadV1::from_block_unchecked(block_hash, block))
+            }
+            ForkchoiceUpdateVersion::V2 => {
+                Self::V2(ExecutionPayloadV2::from_block_unchecked(block_hash, block))
+            }
+            ForkchoiceUpdateVersion::V3 | ForkchoiceUpdateVersion::V4 => {
+                Self::V3(ExecutionPayloadV3::from_block_unchecked(block_hash, block))
+            }
+        };
+
+        Ok((execution_payload, ExecutionPayloadSidecar::from_block(block)))
+    }
+
+    /// Tries to create a new block from the given payload and sidecar, received with the given
+    /// engine API version.
+    ///
+    /// In addition to [`ExecutionPayload::try_into_block_with_sidecar`], this validates that:
+    /// - the payload and sidecar match the version, see
+    ///   [`ExecutionPayload::try_from_block_versioned`]
+    /// - the block hash matches the hash of the converted block
+    /// - the blob versioned hashes of the sidecar match the blob transactions of the block
+    pub fn try_into_block_versioned<T>(
+        self,
+        sidecar: &ExecutionPayloadSidecar,
+        version: ForkchoiceUpdateVersion,
+    ) -> Result<Block<T>, PayloadError>
+    where
+        T: Decodable2718 + Transaction,
+    {
+        use ForkchoiceUpdateVersion::*;
+
+        match (&self, version) {
+            (Self::V1(_), V1) | (Self::V2(_), V2) | (Self::V3(_), V3 | V4) => {}
+            (Self::V1(_), _) => return Err(PayloadError::PostShanghaiBlockWithoutWithdrawals),
+            (Self::V2(_), V1) => return Err(PayloadError::PreShanghaiBlockWithWithdrawals),
+            (Self::V2(_), _) => return Err(PayloadError::PostCancunWithoutCancunFields),
+            (Self::V3(_), _) => return Err(PayloadError::PreCancunWithCancunFields),
+        }
+        match (sidecar.cancun().is_some(), version >= V3) {
+            (true, false) => return Err(PayloadError::PreCancunWithCancunFields),
+            (false, true) => return Err(PayloadError::PostCancunWithoutCancunFields),
+            _ => {}
+        }
+        match (sidecar.prague().is_some(), version >= V4) {
+            (true, false) => return Err(PayloadError::PrePragueBlockRequests),
+            (false, true) => return Err(PayloadError::PostPragueBlockWithoutRequests),
+            _ => {}
+        }
+
+        let block_hash = self.block_hash();
+        let block = self.try_into_block_with_sidecar::<T>(sidecar)?;
+        validate_block_fields(&block, version)?;
+
+        let execution = block.header.hash_slow();
+        if execution != block_hash {
+            return Err(PayloadError::BlockHash { execution, consensus: block_hash });
+        }
+
+        if let Some(versioned_hashes) = sidecar.versioned_hashes() {
+            if !block.body.blob_versioned_hashes_iter().eq(versioned_hashes) {
+                return Err(PayloadError::InvalidVersionedHashes);
+            }
+        }
+
+        Ok(block)
+    }
+
     /// Returns a reference to the V1 payload.
     pub const fn as_v1(&self) -> &ExecutionPayloadV1 {
         match self {
@@ -977,6 +1063,61 @@ impl ExecutionPayload {
     }
 }
 
+/// Checks that the block has exactly the fields and transaction types of the engine API version.
+fn validate_block_fields<T: Typed2718>(
+    block: &Block<T>,
+    version: ForkchoiceUpdateVersion,
+) -> Result<(), PayloadError> {
+    use ForkchoiceUpdateVersion::*;
+
+    let header = &block.header;
+    if version == V1 {
+        if block.body.withdrawals.is_some() {
+            return Err(PayloadError::PreShanghaiBlockWithWithdrawals);
+        }
+    } else if block.body.withdrawals.is_none() {
+        return Err(PayloadError::PostShanghaiBlockWithoutWithdrawals);
+    }
+
+    if version < V3 {
+        if header.blob_gas_used.is_some() {
+            return Err(PayloadError::PreCancunBlockWithBlobGasUsed);
+        }
+        if header.excess_blob_gas.is_some() {
+            return Err(PayloadError::PreCancunBlockWithExcessBlobGas);
+        }
+        if header.parent_beacon_block_root.is_some() {
+            return Err(PayloadError::PreCancunWithCancunFields);
+        }
+        if block.body.has_eip4844_transactions() {
+            return Err(PayloadError::PreCancunBlockWithBlobTransactions);
+        }
+    } else {
+        if header.blob_gas_used.is_none() {
+            return Err(PayloadError::PostCancunBlockWithoutBlobGasUsed);
+        }
+        if header.excess_blob_gas.is_none() {
+            return Err(PayloadError::PostCancunBlockWithoutExcessBlobGas);
+        }
+        if header.parent_beacon_block_root.is_none() {
+            return Err(PayloadError::PostCancunWithoutCancunFields);
+        }
+    }
+
+    if version < V4 {
+        if header.requests_hash.is_some() {
+            return Err(PayloadError::PrePragueBlockRequests);
+        }
+        if block.body.has_eip7702_transactions() {
+            return Err(PayloadError::PrePragueBlockWithEip7702Transactions);
+        }
+    } else if header.requests_hash.is_none() {
+        return Err(PayloadError::PostPragueBlockWithoutRequests);
+    }
+
+    Ok(())
+}
+
 impl From<ExecutionPayloadV1> for ExecutionPayload {
     fn from(payload: ExecutionPayloadV1) -> Self {
         Self::V1(payload)
@@ -1085,6 +1226,117 @@ pub struct PayloadAttributes {
     pub parent_beacon_block_root: Option<B256>,
 }
 
+impl PayloadAttributes {
+    /// Creates attributes for `engine_forkchoiceUpdatedV1`.
+    pub const fn v1(timestamp: u64, prev_randao: B256, suggested_fee_recipient: Address) -> Self {
+        Self {
+            timestamp,
+            prev_randao,
+            suggested_fee_recipient,
+            withdrawals: None,
+            parent_beacon_block_root: None,
+        }
+    }
+
+    /// Creates attributes for `engine_forkchoiceUpdatedV2`.
+    pub const fn v2(
+        timestamp: u64,
+        prev_randao: B256,
+        suggested_fee_recipient: Address,
+        withdrawals: Vec<Withdrawal>,
+    ) -> Self {
+        Self {
+            timestamp,
+            prev_randao,
+            suggested_fee_recipient,
+            withdrawals: Some(withdrawals),
+            parent_beacon_block_root: None,
+        }
+    }
+
+    /// Creates attributes for `engine_forkchoiceUpdatedV3`.
+    pub const fn v3(
+        timestamp: u64,
+        prev_randao: B256,
+        suggested_fee_recipient: Address,
+        withdrawals: Vec<Withdrawal>,
+        parent_beacon_block_root: B256,
+    ) -> Self {
+        Self {
+            timestamp,
+            prev_randao,
+            suggested_fee_recipient,
+            withdrawals: Some(withdrawals),
+            parent_beacon_block_root: Some(parent_beacon_block_root),
+        }
+    }
+
+    /// Sets the withdrawals, which are required from V2.
+    pub fn with_withdrawals(mut self, withdrawals: Vec<Withdrawal>) -> Self {
+        self.withdrawals = Some(withdrawals);
+        self
+    }
+
+    /// Sets the parent beacon block root, which is required from V3.
+    pub const fn with_parent_beacon_block_root(mut self, parent_beacon_block_root: B256) -> Self {
+        self.parent_beacon_block_root = Some(parent_beacon_block_root);
+        self
+    }
+
+    /// Returns the lowest `engine_forkchoiceUpdated` version that accepts these attributes.
+    pub const fn version(&self) -> ForkchoiceUpdateVersion {
+        if self.parent_beacon_block_root.is_some() {
+            ForkchoiceUpdateVersion::V3
+        } else if self.withdrawals.is_some() {
+            ForkchoiceUpdateVersion::V2
+        } else {
+            ForkchoiceUpdateVersion::V1
+        }
+    }
+
+    /// Computes the [`PayloadId`] an execution client assigns to a payload built on top of
+    /// `parent_hash` with these attributes, using the version of [`PayloadAttributes::version`].
+    ///
+    /// See [`PayloadAttributes::payload_id_with_version`].
+    pub fn payload_id(&self, parent_hash: &B256) -> PayloadId {
+        self.payload_id_with_version(parent_hash, self.version())
+    }
+
+    /// Computes the [`PayloadId`] an execution client assigns to a payload built on top of
+    /// `parent_hash` with these attributes, received with the given `engine_forkchoiceUpdated`
+    /// version.
+    ///
+    /// This matches geth: the first 8 bytes of the SHA-256 hash of the parent hash, timestamp,
+    /// prev randao, fee recipient, RLP encoded withdrawals and parent beacon block root, with the
+    /// first byte replaced by the version.
+    ///
+    /// See <https://github.com/ethereum/go-ethereum/blob/master/miner/payload_building.go>
+    pub fn payload_id_with_version(
+        &self,
+        parent_hash: &B256,
+        version: ForkchoiceUpdateVersion,
+    ) -> PayloadId {
+        use sha2::Digest;
+
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(parent_hash);
+        hasher.update(self.timestamp.to_be_bytes());
+        hasher.update(self.prev_randao);
+        hasher.update(self.suggested_fee_recipient);
+        let mut buf = Vec::new();
+        alloy_rlp::encode_list(self.withdrawals.as_deref().unwrap_or_default(), &mut buf);
+        hasher.update(buf);
+        if let Some(parent_beacon_block_root) = &self.parent_beacon_block_root {
+            hasher.update(parent_beacon_block_root);
+        }
+
+        let mut id = [0u8; 8];
+        id.copy_from_slice(&hasher.finalize()[..8]);
+        id[0] = version as u8;
+        PayloadId::new(id)
+    }
+}
+
 /// This structure contains the result of processing a payload or fork choice update.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
@@ -1249,8 +1501,108 @@ mod tests {
     use super::*;
     use crate::PayloadValidationError;
     use alloc::vec;
+    use alloy_primitives::hex;
     use similar_asserts::assert_eq;
 
+    #[test]
+    fn payload_id() {
+        let parent = B256::repeat_byte(0x11);
+        let attributes = PayloadAttributes::v1(
+            1_700_000_000,
+            B256::repeat_byte(0x22),
+            Address::repeat_byte(0x33),
+        );
+        assert_eq!(attributes.version(), ForkchoiceUpdateVersion::V1);
+        assert_eq!(attributes.payload_id(&parent), PayloadId::new(hex!("010f846a7ea7b1aa")));
+
+        let withdrawal = Withdrawal {
+            index: 1,
+            validator_index: 2,
+            address: Address::repeat_byte(0x55),
+            amount: 3,
+        };
+        let attributes = attributes
+            .with_withdrawals(vec![withdrawal])
+            .with_parent_beacon_block_root(B256::repeat_byte(0x44));
+        assert_eq!(attributes.version(), ForkchoiceUpdateVersion::V3);
+        assert_eq!(attributes.payload_id(&parent), PayloadId::new(hex!("0312fe99a599ed5c")));
+    }
+
+    #[test]
+    fn versioned_block_conversion() {
+        use alloy_consensus::TxEnvelope;
+
+        let header = Header {
+            base_fee_per_gas: Some(7),
+            withdrawals_root: Some(alloy_consensus::proofs::calculate_withdrawals_root(&[])),
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(B256::repeat_byte(1)),
+            ..Default::default()
+        };
+        let block = Block::<TxEnvelope> {
+            header,
+            body: BlockBody {
+                transactions: vec![],
+                ommers: vec![],
+                withdrawals: Some(Withdrawals::default()),
+            },
+        };
+        let block_hash = block.header.hash_slow();
+
+        assert!(matches!(
+            ExecutionPayload::try_from_block_versioned(
+                block_hash,
+                &block,
+                ForkchoiceUpdateVersion::V2
+            ),
+            Err(PayloadError::PreCancunBlockWithBlobGasUsed)
+        ));
+        assert!(matches!(
+            ExecutionPayload::try_from_block_versioned(
+                block_hash,
+                &block,
+                ForkchoiceUpdateVersion::V4
+            ),
+            Err(PayloadError::PostPragueBlockWithoutRequests)
+        ));
+
+        let (payload, sidecar) = ExecutionPayload::try_from_block_versioned(
+            block_hash,
+            &block,
+            ForkchoiceUpdateVersion::V3,
+        )
+        .unwrap();
+        assert!(matches!(payload, ExecutionPayload::V3(_)));
+
+        let converted: Block<TxEnvelope> = payload
+            .clone()
+            .try_into_block_versioned(&sidecar, ForkchoiceUpdateVersion::V3)
+            .unwrap();
+        assert_eq!(converted, block);
+
+        assert!(matches!(
+            payload
+                .clone()
+                .try_into_block_versioned::<TxEnvelope>(&sidecar, ForkchoiceUpdateVersion::V2),
+            Err(PayloadError::PreCancunWithCancunFields)
+        ));
+        assert!(matches!(
+            payload.clone().try_into_block_versioned::<TxEnvelope>(
+                &ExecutionPayloadSidecar::none(),
+                ForkchoiceUpdateVersion::V3
+            ),
+            Err(PayloadError::PostCancunWithoutCancunFields)
+        ));
+
+        let mut tampered = payload;
+        tampered.as_v1_mut().gas_limit += 1;
+        assert!(matches!(
+            tampered.try_into_block_versioned::<TxEnvelope>(&sidecar, ForkchoiceUpdateVersion::V3),
+            Err(PayloadError::BlockHash { .. })
+        ));
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde_payload_status() {
@@ -0,0 +1,36 @@
+#![allow(missing_docs)]
+
+use alloy_rpc_types_eth::{Block, TransactionReceipt};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const BLOCK_HASHES: &str =
+    include_str!("../testdata/golden/block/mainnet_cancun_without_uncles.json");
+const BLOCK_FULL: &str = include_str!("../testdata/tenderly.sepolia.json");
+const RECEIPT: &str = include_str!("../testdata/golden/receipt/mainnet_eip1559.json");
+
+fn deserialize(c: &mut Criterion) {
+    let mut g = c.benchmark_group("deserialize");
+    g.bench_function("block_hashes", |b| {
+        b.iter(|| serde_json::from_str::<Block>(black_box(BLOCK_HASHES)).unwrap())
+    });
+    g.bench_function("block_full", |b| {
+        b.iter(|| serde_json::from_str::<Block>(black_box(BLOCK_FULL)).unwrap())
+    });
+    g.bench_function("receipt", |b| {
+        b.iter(|| serde_json::from_str::<TransactionReceipt>(black_box(RECEIPT)).unwrap())
+    });
+    g.finish();
+}
+
+fn serialize(c: &mut Criterion) {
+    let block: Block = serde_json::from_str(BLOCK_FULL).unwrap();
+    let receipt: TransactionReceipt = serde_json::from_str(RECEIPT).unwrap();
+
+    let mut g = c.benchmark_group("serialize");
+    g.bench_function("block_full", |b| b.iter(|| serde_json::to_vec(black_box(&block)).unwrap()));
+    g.bench_function("receipt", |b| b.iter(|| serde_json::to_vec(black_box(&receipt)).unwrap()));
+    g.finish();
+}
+
+criterion_group!(benches, deserialize, serialize);
+criterion_main!(benches);
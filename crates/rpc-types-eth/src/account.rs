@@ -19,12 +19,16 @@ pub struct AccountInfo {
 #[cfg(feature = "serde")]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EIP1186StorageProof {
     /// Storage key.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::storage_key"))]
     pub key: alloy_serde::storage::JsonStorageKey,
     /// Value that the key holds
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity"))]
     pub value: U256,
     /// proof for the pair
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::bytes_vec"))]
     pub proof: Vec<Bytes>,
 }
 
@@ -45,19 +49,26 @@ impl EIP1186StorageProof {
 #[cfg(feature = "serde")]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EIP1186AccountProofResponse {
     /// The account address.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::address"))]
     pub address: Address,
     /// The account balance.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity"))]
     pub balance: U256,
     /// The hash of the code of the account.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::hash"))]
     pub code_hash: B256,
     /// The account nonce.
     #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity"))]
     pub nonce: u64,
     /// The hash of the storage of the account.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::hash"))]
     pub storage_hash: B256,
     /// The account proof.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::bytes_vec"))]
     pub account_proof: Vec<Bytes>,
     /// The storage proof.
     pub storage_proof: Vec<EIP1186StorageProof>,
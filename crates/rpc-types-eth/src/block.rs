@@ -253,6 +253,20 @@ impl<H> Header<H> {
         self.inner
     }
 
+    /// Consumes the type and returns the wrapped consensus header, sealed with the hash supplied
+    /// by the node.
+    ///
+    /// Unlike [`Sealable::seal_slow`], this does not recompute the hash.
+    pub fn into_sealed(self) -> Sealed<H> {
+        Sealed::new_unchecked(self.inner, self.hash)
+    }
+
+    /// Returns a reference to the wrapped consensus header, sealed with the hash supplied by the
+    /// node.
+    pub const fn as_sealed(&self) -> Sealed<&H> {
+        Sealed::new_unchecked(&self.inner, self.hash)
+    }
+
     /// Create a new [`Header`] from a sealed consensus header and additional fields.
     pub fn from_consensus(
         header: Sealed<H>,
@@ -276,6 +290,12 @@ impl<H> Header<H> {
     }
 }
 
+impl<H> From<Header<H>> for Sealed<H> {
+    fn from(header: Header<H>) -> Self {
+        header.into_sealed()
+    }
+}
+
 impl<H> Deref for Header<H> {
     type Target = H;
 
@@ -509,6 +529,79 @@ pub struct BlockOverrides {
     pub block_hash: Option<BTreeMap<u64, B256>>,
 }
 
+impl BlockOverrides {
+    /// Returns `true` if no overrides are set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Sets the block number override.
+    pub const fn with_number(mut self, number: U256) -> Self {
+        self.number = Some(number);
+        self
+    }
+
+    /// Sets the difficulty override.
+    pub const fn with_difficulty(mut self, difficulty: U256) -> Self {
+        self.difficulty = Some(difficulty);
+        self
+    }
+
+    /// Sets the timestamp override.
+    pub const fn with_time(mut self, time: u64) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Sets the gas limit override.
+    pub const fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Sets the coinbase override.
+    pub const fn with_coinbase(mut self, coinbase: Address) -> Self {
+        self.coinbase = Some(coinbase);
+        self
+    }
+
+    /// Sets the prevrandao override.
+    pub const fn with_random(mut self, random: B256) -> Self {
+        self.random = Some(random);
+        self
+    }
+
+    /// Sets the base fee override.
+    pub const fn with_base_fee(mut self, base_fee: U256) -> Self {
+        self.base_fee = Some(base_fee);
+        self
+    }
+
+    /// Overrides the hash returned by `BLOCKHASH` for the given block number.
+    pub fn append_block_hash(mut self, number: u64, hash: B256) -> Self {
+        self.block_hash.get_or_insert_with(Default::default).insert(number, hash);
+        self
+    }
+
+    /// Merges `other` into this instance.
+    ///
+    /// Fields set in `other` take precedence, block hash overrides are combined.
+    pub fn merge(&mut self, other: Self) {
+        let Self { number, difficulty, time, gas_limit, coinbase, random, base_fee, block_hash } =
+            other;
+        self.number = number.or(self.number);
+        self.difficulty = difficulty.or(self.difficulty);
+        self.time = time.or(self.time);
+        self.gas_limit = gas_limit.or(self.gas_limit);
+        self.coinbase = coinbase.or(self.coinbase);
+        self.random = random.or(self.random);
+        self.base_fee = base_fee.or(self.base_fee);
+        if let Some(hashes) = block_hash {
+            self.block_hash.get_or_insert_with(Default::default).extend(hashes);
+        }
+    }
+}
+
 impl<T: TransactionResponse, H> BlockResponse for Block<T, H> {
     type Header = H;
     type Transaction = T;
@@ -524,6 +617,10 @@ impl<T: TransactionResponse, H> BlockResponse for Block<T, H> {
     fn transactions_mut(&mut self) -> &mut BlockTransactions<Self::Transaction> {
         &mut self.transactions
     }
+
+    fn withdrawals(&self) -> Option<&Withdrawals> {
+        self.withdrawals.as_ref()
+    }
 }
 
 /// Bad block representation.
@@ -548,6 +645,21 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn merge_block_overrides() {
+        let mut overrides = BlockOverrides::default()
+            .with_time(1)
+            .with_gas_limit(2)
+            .append_block_hash(1, B256::ZERO);
+        assert!(!overrides.is_empty());
+        overrides.merge(
+            BlockOverrides::default().with_time(3).append_block_hash(2, B256::with_last_byte(1)),
+        );
+        assert_eq!(overrides.time, Some(3));
+        assert_eq!(overrides.gas_limit, Some(2));
+        assert_eq!(overrides.block_hash.unwrap().len(), 2);
+    }
+
     #[test]
     fn arbitrary_header() {
         let mut bytes = [0u8; 1024];
@@ -976,6 +1088,11 @@ mod tests {
 
         // Ensure the roundtrip conversion is correct
         assert_eq!(rpc_header, roundtrip_rpc_header);
+
+        // Sealing uses the hash of the RPC header instead of recomputing it
+        assert_eq!(rpc_header.as_sealed().seal(), B256::with_last_byte(1));
+        let sealed: Sealed<alloy_consensus::Header> = rpc_header.clone().into();
+        assert_eq!(sealed.into_parts(), (rpc_header.inner, B256::with_last_byte(1)));
     }
 
     #[test]
@@ -1092,4 +1209,14 @@ mod tests {
         let s = include_str!("../testdata/tenderly.sepolia.json");
         let _block: Block = serde_json::from_str(s).unwrap();
     }
+
+    #[test]
+    fn block_response_withdrawals() {
+        let block = Block::<Transaction>::default();
+        assert!(BlockResponse::withdrawals(&block).is_none());
+
+        let block = block.with_withdrawals(Some(Withdrawals::default()));
+        assert_eq!(BlockResponse::withdrawals(&block), Some(&Withdrawals::default()));
+        assert!(BlockResponse::transactions(&block).is_hashes());
+    }
 }
@@ -29,6 +29,7 @@ impl Ord for TxGasAndReward {
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeeHistory {
     /// An array of block base fees per gas.
     /// This includes the next block after the newest of the returned range,
@@ -42,10 +43,12 @@ pub struct FeeHistory {
         feature = "serde",
         serde(default, skip_serializing_if = "Vec::is_empty", with = "alloy_serde::quantity::vec")
     )]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity_vec"))]
     pub base_fee_per_gas: Vec<u128>,
     /// An array of block gas used ratios. These are calculated as the ratio
     /// of `gasUsed` and `gasLimit`.
     #[cfg_attr(feature = "serde", serde(deserialize_with = "alloy_serde::null_as_default"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<f64>"))]
     pub gas_used_ratio: Vec<f64>,
     /// An array of block base fees per blob gas. This includes the next block after the newest
     /// of the returned range, because this value can be derived from the newest block. Zeroes
@@ -54,6 +57,7 @@ pub struct FeeHistory {
         feature = "serde",
         serde(default, skip_serializing_if = "Vec::is_empty", with = "alloy_serde::quantity::vec")
     )]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity_vec"))]
     pub base_fee_per_blob_gas: Vec<u128>,
     /// An array of block blob gas used ratios. These are calculated as the ratio of gasUsed and
     /// gasLimit.
@@ -61,6 +65,7 @@ pub struct FeeHistory {
     pub blob_gas_used_ratio: Vec<f64>,
     /// Lowest number block of the returned range.
     #[cfg_attr(feature = "serde", serde(default, with = "alloy_serde::quantity"))]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity"))]
     pub oldest_block: u64,
     /// An (optional) array of effective priority fee per gas data points from a single
     /// block. All zeroes are returned if the block is empty.
@@ -72,6 +77,10 @@ pub struct FeeHistory {
             with = "alloy_serde::quantity::u128_vec_vec_opt"
         )
     )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::schema::quantity_vec_vec_opt")
+    )]
     pub reward: Option<Vec<Vec<u128>>>,
 }
 
@@ -0,0 +1,170 @@
+//! Corpus of real-world node responses for testing the serde stability of RPC types.
+//!
+//! The fixtures live in `testdata/golden/<kind>/<name>.json`, where the name starts with the
+//! client or network the response was captured from. Every fixture is checked against the types
+//! of this crate in CI, and the corpus is exposed with the `test-utils` feature so that downstream
+//! crates can check their own types against the same payloads.
+
+use alloc::string::{String, ToString};
+use core::fmt::Debug;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A captured node response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fixture {
+    /// The kind of payload, e.g. `block`.
+    pub kind: &'static str,
+    /// The name of the fixture, starting with the client or network it was captured from.
+    pub name: &'static str,
+    /// The raw JSON payload.
+    pub json: &'static str,
+}
+
+macro_rules! fixtures {
+    ($kind:literal: $($name:literal),* $(,)?) => {
+        &[$(Fixture {
+            kind: $kind,
+            name: $name,
+            json: include_str!(concat!("../testdata/golden/", $kind, "/", $name, ".json")),
+        }),*]
+    };
+}
+
+/// Responses of `eth_getBlockByNumber` and `eth_getBlockByHash`.
+pub const BLOCKS: &[Fixture] =
+    fixtures!("block": "mainnet_cancun_without_uncles", "mainnet_pow_hashes");
+
+/// Headers of `newHeads` subscriptions.
+pub const HEADERS: &[Fixture] =
+    fixtures!("header": "mainnet_pow_subscription", "nethermind_pow_subscription");
+
+/// Responses of `eth_getTransactionByHash`.
+pub const TRANSACTIONS: &[Fixture] = fixtures!(
+    "transaction": "anvil_eip1559",
+    "devnet_eip4844",
+    "devnet_eip7702",
+    "devnet_legacy_eip155",
+    "mainnet_eip1559",
+    "mainnet_legacy",
+);
+
+/// Responses of `eth_getTransactionReceipt`.
+pub const RECEIPTS: &[Fixture] = fixtures!("receipt": "mainnet_eip1559", "mainnet_pre_eip658");
+
+/// Logs as returned by `eth_getLogs`.
+pub const LOGS: &[Fixture] = fixtures!("log": "mainnet_erc20_approval", "mainnet_pre_byzantium");
+
+/// Error returned when a [`Fixture`] does not survive a serde roundtrip.
+#[derive(Debug, thiserror::Error)]
+pub enum GoldenError {
+    /// The fixture could not be deserialized.
+    #[error("failed to deserialize {kind}/{name}: {source}")]
+    Deserialize {
+        /// The kind of the fixture.
+        kind: &'static str,
+        /// The name of the fixture.
+        name: &'static str,
+        /// The deserialization error.
+        source: serde_json::Error,
+    },
+    /// The deserialized value could not be serialized again.
+    #[error("failed to serialize {kind}/{name}: {source}")]
+    Serialize {
+        /// The kind of the fixture.
+        kind: &'static str,
+        /// The name of the fixture.
+        name: &'static str,
+        /// The serialization error.
+        source: serde_json::Error,
+    },
+    /// The serialized value deserialized into a different value.
+    #[error("{kind}/{name} changed in a serde roundtrip, reserialized as {reserialized}")]
+    Mismatch {
+        /// The kind of the fixture.
+        kind: &'static str,
+        /// The name of the fixture.
+        name: &'static str,
+        /// The serialized value.
+        reserialized: String,
+    },
+}
+
+/// Deserializes the fixture into `T` and checks that serializing and deserializing it again
+/// yields the same value.
+///
+/// Returns the deserialized value for further assertions.
+pub fn check_roundtrip<T>(fixture: &Fixture) -> Result<T, GoldenError>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let Fixture { kind, name, json } = *fixture;
+    let value: T = serde_json::from_str(json).map_err(|source| GoldenError::Deserialize {
+        kind,
+        name,
+        source,
+    })?;
+    let reserialized = serde_json::to_string(&value).map_err(|source| GoldenError::Serialize {
+        kind,
+        name,
+        source,
+    })?;
+    match serde_json::from_str::<T>(&reserialized) {
+        Ok(roundtripped) if roundtripped == value => Ok(value),
+        _ => Err(GoldenError::Mismatch { kind, name, reserialized }),
+    }
+}
+
+/// Checks all fixtures with [`check_roundtrip`] and returns the errors of those that failed.
+pub fn check_all<T>(fixtures: &[Fixture]) -> Result<(), String>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let errors = fixtures
+        .iter()
+        .filter_map(|fixture| check_roundtrip::<T>(fixture).err())
+        .map(|err| err.to_string())
+        .collect::<alloc::vec::Vec<_>>();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Block, Header, Log, Transaction, TransactionReceipt};
+
+    #[test]
+    fn blocks() {
+        check_all::<Block>(BLOCKS).unwrap();
+    }
+
+    #[test]
+    fn headers() {
+        check_all::<Header>(HEADERS).unwrap();
+    }
+
+    #[test]
+    fn transactions() {
+        check_all::<Transaction>(TRANSACTIONS).unwrap();
+    }
+
+    #[test]
+    fn receipts() {
+        check_all::<TransactionReceipt>(RECEIPTS).unwrap();
+    }
+
+    #[test]
+    fn logs() {
+        check_all::<Log>(LOGS).unwrap();
+    }
+
+    #[test]
+    fn reports_failures() {
+        let fixture = Fixture { kind: "log", name: "broken", json: "{}" };
+        let err = check_roundtrip::<Log>(&fixture).unwrap_err();
+        assert!(err.to_string().starts_with("failed to deserialize log/broken: "));
+    }
+}
@@ -34,6 +34,9 @@ pub use fee::{FeeHistory, TxGasAndReward};
 mod filter;
 pub use filter::*;
 
+#[cfg(all(feature = "serde", any(test, feature = "test-utils")))]
+pub mod golden;
+
 mod index;
 pub use index::Index;
 
@@ -43,6 +46,12 @@ pub use log::*;
 #[cfg(feature = "serde")]
 pub mod pubsub;
 
+#[cfg(feature = "schemars")]
+pub mod schema;
+
+#[cfg(feature = "schemars")]
+pub mod typescript;
+
 mod raw_log;
 pub use raw_log::{logs_bloom, Log as RawLog};
 
@@ -180,6 +180,178 @@ impl<'a> serde::Deserialize<'a> for Params {
     }
 }
 
+/// Typed parameters of an `eth_subscribe` request.
+///
+/// Serializes to the params array of the request, e.g. `["logs", {...}]`. Networks that support
+/// additional subscription kinds can use [`SubscriptionParams::Custom`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubscriptionParams {
+    /// Subscribe to new block headers. See [`SubscriptionKind::NewHeads`].
+    NewHeads,
+    /// Subscribe to logs matching the filter. See [`SubscriptionKind::Logs`].
+    Logs(Box<Filter>),
+    /// Subscribe to pending transactions. See [`SubscriptionKind::NewPendingTransactions`].
+    NewPendingTransactions {
+        /// Whether to receive full transactions instead of hashes.
+        full: bool,
+    },
+    /// Subscribe to the syncing status. See [`SubscriptionKind::Syncing`].
+    Syncing,
+    /// A subscription kind that is not part of the Ethereum JSON-RPC API.
+    #[cfg(feature = "serde")]
+    Custom {
+        /// The name of the subscription kind.
+        kind: alloc::string::String,
+        /// The parameters following the kind, if any.
+        params: Option<serde_json::Value>,
+    },
+}
+
+impl SubscriptionParams {
+    /// Subscribe to logs matching the filter.
+    pub fn logs(filter: Filter) -> Self {
+        Self::Logs(Box::new(filter))
+    }
+
+    /// Subscribe to pending transaction hashes.
+    pub const fn pending_transactions() -> Self {
+        Self::NewPendingTransactions { full: false }
+    }
+
+    /// Subscribe to full pending transactions.
+    pub const fn full_pending_transactions() -> Self {
+        Self::NewPendingTransactions { full: true }
+    }
+
+    /// Subscribe to a custom subscription kind.
+    #[cfg(feature = "serde")]
+    pub fn custom(
+        kind: impl Into<alloc::string::String>,
+        params: Option<serde_json::Value>,
+    ) -> Self {
+        Self::Custom { kind: kind.into(), params }
+    }
+
+    /// Returns the standard subscription kind, or `None` for custom kinds.
+    pub const fn kind(&self) -> Option<SubscriptionKind> {
+        match self {
+            Self::NewHeads => Some(SubscriptionKind::NewHeads),
+            Self::Logs(_) => Some(SubscriptionKind::Logs),
+            Self::NewPendingTransactions { .. } => Some(SubscriptionKind::NewPendingTransactions),
+            Self::Syncing => Some(SubscriptionKind::Syncing),
+            #[cfg(feature = "serde")]
+            Self::Custom { .. } => None,
+        }
+    }
+
+    /// Returns the name of the subscription kind, as sent in the request.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::NewHeads => "newHeads",
+            Self::Logs(_) => "logs",
+            Self::NewPendingTransactions { .. } => "newPendingTransactions",
+            Self::Syncing => "syncing",
+            #[cfg(feature = "serde")]
+            Self::Custom { kind, .. } => kind,
+        }
+    }
+
+    /// Splits a standard subscription into its kind and [`Params`], or returns `None` for custom
+    /// kinds.
+    pub fn into_kind_and_params(self) -> Option<(SubscriptionKind, Params)> {
+        let kind = self.kind()?;
+        let params = match self {
+            Self::Logs(filter) => Params::Logs(filter),
+            // `false` is not sent, as some nodes reject it.
+            Self::NewPendingTransactions { full: true } => Params::Bool(true),
+            _ => Params::None,
+        };
+        Some((kind, params))
+    }
+}
+
+impl From<SubscriptionKind> for SubscriptionParams {
+    fn from(kind: SubscriptionKind) -> Self {
+        match kind {
+            SubscriptionKind::NewHeads => Self::NewHeads,
+            SubscriptionKind::Logs => Self::Logs(Box::default()),
+            SubscriptionKind::NewPendingTransactions => Self::pending_transactions(),
+            SubscriptionKind::Syncing => Self::Syncing,
+        }
+    }
+}
+
+impl From<Filter> for SubscriptionParams {
+    fn from(filter: Filter) -> Self {
+        Self::logs(filter)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SubscriptionParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element(self.name())?;
+        match self {
+            Self::Logs(filter) => seq.serialize_element(filter)?,
+            // `false` is not sent, as some nodes reject it.
+            Self::NewPendingTransactions { full: true } => seq.serialize_element(&true)?,
+            Self::Custom { params: Some(params), .. } => seq.serialize_element(params)?,
+            _ => {}
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SubscriptionParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let (kind, params): (alloc::string::String, Option<serde_json::Value>) =
+            match serde_json::Value::deserialize(deserializer)? {
+                serde_json::Value::Array(values) if (1..=2).contains(&values.len()) => {
+                    let mut values = values.into_iter();
+                    let kind = serde_json::from_value(values.next().expect("len checked"))
+                        .map_err(D::Error::custom)?;
+                    (kind, values.next())
+                }
+                _ => return Err(D::Error::custom("expected [kind] or [kind, params]")),
+            };
+
+        let Ok(kind) = serde_json::from_value::<SubscriptionKind>(kind.clone().into()) else {
+            return Ok(Self::Custom { kind, params });
+        };
+        let params = match params {
+            Some(params) => serde_json::from_value(params).map_err(D::Error::custom)?,
+            None => Params::None,
+        };
+        Ok(match (kind, params) {
+            (SubscriptionKind::NewHeads, Params::None) => Self::NewHeads,
+            (SubscriptionKind::Logs, Params::None) => Self::Logs(Box::default()),
+            (SubscriptionKind::Logs, Params::Logs(filter)) => Self::Logs(filter),
+            (SubscriptionKind::NewPendingTransactions, Params::None) => {
+                Self::pending_transactions()
+            }
+            (SubscriptionKind::NewPendingTransactions, Params::Bool(full)) => {
+                Self::NewPendingTransactions { full }
+            }
+            (SubscriptionKind::Syncing, Params::None) => Self::Syncing,
+            (kind, _) => {
+                return Err(D::Error::custom(format!("invalid params for {kind:?} subscription")))
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,4 +445,41 @@ mod tests {
         let expected = serde_json::to_string(&filter).unwrap();
         assert_eq!(serialized, expected);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn subscription_params_serde() {
+        let cases = [
+            (SubscriptionParams::NewHeads, r#"["newHeads"]"#),
+            (SubscriptionParams::pending_transactions(), r#"["newPendingTransactions"]"#),
+            (SubscriptionParams::full_pending_transactions(), r#"["newPendingTransactions",true]"#),
+            (SubscriptionParams::Syncing, r#"["syncing"]"#),
+            (
+                SubscriptionParams::custom("newFlashblocks", Some(serde_json::json!({"a": 1}))),
+                r#"["newFlashblocks",{"a":1}]"#,
+            ),
+        ];
+        for (params, json) in cases {
+            assert_eq!(serde_json::to_string(&params).unwrap(), json);
+            assert_eq!(serde_json::from_str::<SubscriptionParams>(json).unwrap(), params);
+        }
+
+        let filter = Filter::new().from_block(1);
+        let params = SubscriptionParams::from(filter.clone());
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json, serde_json::json!(["logs", filter]));
+        assert_eq!(serde_json::from_value::<SubscriptionParams>(json).unwrap(), params);
+
+        assert!(serde_json::from_str::<SubscriptionParams>(r#"["newHeads",true]"#).is_err());
+        assert!(serde_json::from_str::<SubscriptionParams>("[]").is_err());
+    }
+
+    #[test]
+    fn subscription_params_kind() {
+        let (kind, params) =
+            SubscriptionParams::full_pending_transactions().into_kind_and_params().unwrap();
+        assert_eq!(kind, SubscriptionKind::NewPendingTransactions);
+        assert_eq!(params, Params::Bool(true));
+        assert_eq!(SubscriptionParams::from(SubscriptionKind::Syncing).name(), "syncing");
+    }
 }
@@ -0,0 +1,366 @@
+//! [JSON Schema](https://json-schema.org) helpers for the Ethereum JSON-RPC encodings.
+//!
+//! The primitive types do not implement [`JsonSchema`], so fields of these types are annotated
+//! with `#[schemars(schema_with = "...")]` using the functions in this module. They can be used
+//! the same way in downstream types.
+//!
+//! [`JsonSchema`]: schemars::JsonSchema
+
+use crate::{Block, Header, Log, TransactionReceipt};
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec, vec::Vec};
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{
+        ArrayValidation, InstanceType, Metadata, ObjectValidation, Schema, SchemaObject,
+        StringValidation, SubschemaValidation,
+    },
+    JsonSchema,
+};
+
+fn string(description: &str, pattern: &str) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.into()),
+            ..Default::default()
+        })),
+        string: Some(Box::new(StringValidation {
+            pattern: Some(pattern.into()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn array(items: Schema) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::Array.into()),
+        array: Some(Box::new(ArrayValidation { items: Some(items.into()), ..Default::default() })),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn nullable(schema: Schema) -> Schema {
+    let null =
+        SchemaObject { instance_type: Some(InstanceType::Null.into()), ..Default::default() };
+    any_of(vec![schema, null.into()])
+}
+
+fn literals(values: &[&str]) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(values.iter().map(|v| serde_json::Value::from(*v)).collect()),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn any_of(schemas: Vec<Schema>) -> Schema {
+    SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+            any_of: Some(schemas),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Builder for the schema of a JSON object, for types that cannot derive [`JsonSchema`].
+#[derive(Debug, Default)]
+pub struct ObjectSchema {
+    description: Option<String>,
+    object: ObjectValidation,
+}
+
+impl ObjectSchema {
+    /// Creates an empty object schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the description of the object.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds a required property.
+    pub fn required(mut self, name: &str, schema: Schema) -> Self {
+        self.object.required.insert(name.to_owned());
+        self.optional(name, schema)
+    }
+
+    /// Adds an optional property.
+    pub fn optional(mut self, name: &str, schema: Schema) -> Self {
+        self.object.properties.insert(name.to_owned(), schema);
+        self
+    }
+
+    /// Builds the schema.
+    pub fn build(self) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            metadata: self.description.map(|description| {
+                Box::new(Metadata { description: Some(description), ..Default::default() })
+            }),
+            object: Some(Box::new(self.object)),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// Schema of a hex encoded quantity, e.g. `0x1a`.
+pub fn quantity(_: &mut SchemaGenerator) -> Schema {
+    string("hex encoded unsigned integer", "^0x(0|[1-9a-fA-F][0-9a-fA-F]*)$")
+}
+
+/// Schema of an optional hex encoded quantity.
+pub fn quantity_opt(gen: &mut SchemaGenerator) -> Schema {
+    nullable(quantity(gen))
+}
+
+/// Schema of a list of hex encoded quantities.
+pub fn quantity_vec(gen: &mut SchemaGenerator) -> Schema {
+    array(quantity(gen))
+}
+
+/// Schema of an optional list of lists of hex encoded quantities.
+pub fn quantity_vec_vec_opt(gen: &mut SchemaGenerator) -> Schema {
+    nullable(array(quantity_vec(gen)))
+}
+
+/// Schema of hex encoded bytes, e.g. `0xdeadbeef`.
+pub fn bytes(_: &mut SchemaGenerator) -> Schema {
+    string("hex encoded bytes", "^0x([0-9a-fA-F]{2})*$")
+}
+
+/// Schema of a list of hex encoded bytes.
+pub fn bytes_vec(gen: &mut SchemaGenerator) -> Schema {
+    array(bytes(gen))
+}
+
+/// Schema of a hex encoded 20 byte address.
+pub fn address(_: &mut SchemaGenerator) -> Schema {
+    string("hex encoded address", "^0x[0-9a-fA-F]{40}$")
+}
+
+/// Schema of an optional hex encoded bytes.
+pub fn bytes_opt(gen: &mut SchemaGenerator) -> Schema {
+    nullable(bytes(gen))
+}
+
+/// Schema of an optional hex encoded address.
+pub fn address_opt(gen: &mut SchemaGenerator) -> Schema {
+    nullable(address(gen))
+}
+
+/// Schema of a hex encoded 32 byte hash.
+pub fn hash(_: &mut SchemaGenerator) -> Schema {
+    string("32 byte hex encoded hash", "^0x[0-9a-fA-F]{64}$")
+}
+
+/// Schema of an optional hex encoded 32 byte hash.
+pub fn hash_opt(gen: &mut SchemaGenerator) -> Schema {
+    nullable(hash(gen))
+}
+
+/// Schema of a list of hex encoded 32 byte hashes.
+pub fn hash_vec(gen: &mut SchemaGenerator) -> Schema {
+    array(hash(gen))
+}
+
+/// Schema of an optional list of hex encoded 32 byte hashes.
+pub fn hash_vec_opt(gen: &mut SchemaGenerator) -> Schema {
+    nullable(hash_vec(gen))
+}
+
+/// Schema of a hex encoded 256 byte bloom filter.
+pub fn bloom(_: &mut SchemaGenerator) -> Schema {
+    string("hex encoded bloom filter", "^0x[0-9a-fA-F]{512}$")
+}
+
+/// Schema of a hex encoded storage key, either a 32 byte hash or a quantity.
+pub fn storage_key(_: &mut SchemaGenerator) -> Schema {
+    string("hex encoded storage key", "^0x[0-9a-fA-F]{1,64}$")
+}
+
+/// Schema of the `false` literal, used by `eth_syncing` when the node is not syncing.
+pub fn r#false(_: &mut SchemaGenerator) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::Boolean.into()),
+        const_value: Some(serde_json::Value::Bool(false)),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn consensus_header(gen: &mut SchemaGenerator) -> ObjectSchema {
+    ObjectSchema::new()
+        .required("parentHash", hash(gen))
+        .required("sha3Uncles", hash(gen))
+        .required("miner", address(gen))
+        .required("stateRoot", hash(gen))
+        .required("transactionsRoot", hash(gen))
+        .required("receiptsRoot", hash(gen))
+        .required("logsBloom", bloom(gen))
+        .required("difficulty", quantity(gen))
+        .required("number", quantity(gen))
+        .required("gasLimit", quantity(gen))
+        .required("gasUsed", quantity(gen))
+        .required("timestamp", quantity(gen))
+        .required("extraData", bytes(gen))
+        .required("mixHash", hash(gen))
+        .required("nonce", string("hex encoded 8 byte nonce", "^0x[0-9a-fA-F]{16}$"))
+        .optional("baseFeePerGas", quantity(gen))
+        .optional("withdrawalsRoot", hash(gen))
+        .optional("blobGasUsed", quantity(gen))
+        .optional("excessBlobGas", quantity(gen))
+        .optional("parentBeaconBlockRoot", hash(gen))
+        .optional("requestsHash", hash(gen))
+        .optional("totalDifficulty", quantity(gen))
+        .optional("size", quantity(gen))
+}
+
+// The types below wrap consensus types which do not implement `JsonSchema`, so their schemas are
+// written by hand.
+
+impl JsonSchema for Header {
+    fn schema_name() -> String {
+        "Header".into()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        consensus_header(gen).description("Block header").required("hash", hash(gen)).build()
+    }
+}
+
+impl JsonSchema for Block {
+    fn schema_name() -> String {
+        "Block".into()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let withdrawal = ObjectSchema::new()
+            .required("index", quantity(gen))
+            .required("validatorIndex", quantity(gen))
+            .required("address", address(gen))
+            .required("amount", quantity(gen))
+            .build();
+        let transaction = ObjectSchema::new().description("Full transaction object").build();
+        consensus_header(gen)
+            .description("Block with either transaction hashes or full transactions")
+            .required("hash", hash(gen))
+            .required("uncles", hash_vec(gen))
+            .required("transactions", any_of(vec![hash_vec(gen), array(transaction)]))
+            .optional("withdrawals", array(withdrawal))
+            .build()
+    }
+}
+
+impl JsonSchema for Log {
+    fn schema_name() -> String {
+        "Log".into()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        ObjectSchema::new()
+            .description("Log emitted by a transaction")
+            .required("address", address(gen))
+            .required("topics", hash_vec(gen))
+            .required("data", bytes(gen))
+            .optional("blockHash", hash_opt(gen))
+            .optional("blockNumber", quantity_opt(gen))
+            .optional("blockTimestamp", quantity(gen))
+            .optional("transactionHash", hash_opt(gen))
+            .optional("transactionIndex", quantity_opt(gen))
+            .optional("logIndex", quantity_opt(gen))
+            .optional("removed", bool::json_schema(gen))
+            .build()
+    }
+}
+
+impl JsonSchema for TransactionReceipt {
+    fn schema_name() -> String {
+        "TransactionReceipt".into()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        ObjectSchema::new()
+            .description("Transaction receipt")
+            .required("type", literals(&["0x0", "0x1", "0x2", "0x3", "0x4"]))
+            .optional("status", literals(&["0x0", "0x1"]))
+            .optional("root", hash(gen))
+            .required("cumulativeGasUsed", quantity(gen))
+            .required("logs", array(gen.subschema_for::<Log>()))
+            .required("logsBloom", bloom(gen))
+            .required("transactionHash", hash(gen))
+            .optional("transactionIndex", quantity_opt(gen))
+            .optional("blockHash", hash_opt(gen))
+            .optional("blockNumber", quantity_opt(gen))
+            .required("gasUsed", quantity(gen))
+            .required("effectiveGasPrice", quantity(gen))
+            .optional("blobGasUsed", quantity(gen))
+            .optional("blobGasPrice", quantity(gen))
+            .required("from", address(gen))
+            .required("to", address_opt(gen))
+            .required("contractAddress", address_opt(gen))
+            .build()
+    }
+}
+
+/// Returns the schema of `T` as a JSON value, inlining all subschemas.
+pub fn to_json_schema<T: schemars::JsonSchema>() -> serde_json::Value {
+    let settings = schemars::gen::SchemaSettings::draft07().with(|s| s.inline_subschemas = true);
+    let schema = settings.into_generator().into_root_schema_for::<T>();
+    serde_json::to_value(schema).expect("schemas are valid JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EIP1186AccountProofResponse, FeeHistory, SyncStatus};
+
+    #[test]
+    fn fee_history_schema() {
+        let schema = to_json_schema::<FeeHistory>();
+        let props = &schema["properties"];
+        assert_eq!(props["oldestBlock"]["pattern"], "^0x(0|[1-9a-fA-F][0-9a-fA-F]*)$");
+        assert_eq!(props["baseFeePerGas"]["type"], "array");
+        assert_eq!(props["gasUsedRatio"]["items"]["type"], "number");
+        assert_eq!(props["reward"]["anyOf"][1]["type"], "null");
+    }
+
+    #[test]
+    fn proof_schema() {
+        let schema = to_json_schema::<EIP1186AccountProofResponse>();
+        let props = &schema["properties"];
+        assert_eq!(props["address"]["pattern"], "^0x[0-9a-fA-F]{40}$");
+        assert_eq!(
+            props["storageProof"]["items"]["properties"]["key"]["pattern"],
+            "^0x[0-9a-fA-F]{1,64}$"
+        );
+    }
+
+    #[test]
+    fn receipt_schema() {
+        let schema = to_json_schema::<TransactionReceipt>();
+        let props = &schema["properties"];
+        assert_eq!(props["logs"]["items"]["properties"]["topics"]["type"], "array");
+        assert_eq!(props["to"]["anyOf"][1]["type"], "null");
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&"transactionHash".into()));
+        assert!(!required.contains(&"blobGasUsed".into()));
+    }
+
+    #[test]
+    fn sync_status_schema() {
+        let schema = to_json_schema::<SyncStatus>();
+        let variants = schema["anyOf"].as_array().unwrap();
+        assert_eq!(variants[0]["const"], false);
+        assert_eq!(variants[1]["properties"]["currentBlock"]["type"], "string");
+    }
+}
@@ -1,6 +1,9 @@
 //! 'eth_simulateV1' Request / Response types: <https://github.com/ethereum/execution-apis/pull/484>
 
-use crate::{state::StateOverride, Block, BlockOverrides, Log, TransactionRequest};
+use crate::{
+    state::{EvmOverrides, StateOverride},
+    Block, BlockOverrides, Log, TransactionRequest,
+};
 use alloc::{string::String, vec::Vec};
 use alloy_primitives::Bytes;
 
@@ -38,6 +41,13 @@ impl SimBlock {
         self
     }
 
+    /// Sets the state and block overrides from the given [`EvmOverrides`].
+    pub fn with_evm_overrides(mut self, overrides: EvmOverrides) -> Self {
+        self.state_overrides = overrides.state;
+        self.block_overrides = overrides.block.map(|block| *block);
+        self
+    }
+
     /// Adds a call to the block.
     pub fn call(mut self, call: TransactionRequest) -> Self {
         self.calls.push(call);
@@ -10,6 +10,118 @@ use alloy_primitives::{
 /// A set of account overrides
 pub type StateOverride = AddressHashMap<AccountOverride>;
 
+/// Error returned when validating state overrides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum StateOverrideError {
+    /// Both `state` and `stateDiff` are set for the same account.
+    #[error("account {0} has both state and stateDiff overrides")]
+    StateAndStateDiff(Address),
+}
+
+/// Validates every account override in the given [`StateOverride`].
+///
+/// See [`AccountOverride::validate`].
+pub fn validate_state_override(overrides: &StateOverride) -> Result<(), StateOverrideError> {
+    overrides.iter().try_for_each(|(address, account)| account.validate(*address))
+}
+
+/// Merges `other` into `overrides`, see [`AccountOverride::merge`].
+pub fn merge_state_override(overrides: &mut StateOverride, other: StateOverride) {
+    for (address, account) in other {
+        overrides.entry(address).or_default().merge(account);
+    }
+}
+
+/// A builder for [`StateOverride`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{Address, B256, U256};
+/// use alloy_rpc_types_eth::state::{AccountOverride, StateOverridesBuilder};
+///
+/// let overrides = StateOverridesBuilder::default()
+///     .with_balance(Address::ZERO, U256::from(1))
+///     .append(
+///         Address::with_last_byte(1),
+///         AccountOverride::default().with_storage_diff(B256::ZERO, B256::with_last_byte(1)),
+///     )
+///     .build()
+///     .unwrap();
+/// assert_eq!(overrides.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateOverridesBuilder {
+    overrides: StateOverride,
+}
+
+impl StateOverridesBuilder {
+    /// Creates a new builder with the given overrides.
+    pub const fn new(overrides: StateOverride) -> Self {
+        Self { overrides }
+    }
+
+    /// Merges the override for the given address into the existing overrides.
+    pub fn append(mut self, address: Address, account: AccountOverride) -> Self {
+        self.overrides.entry(address).or_default().merge(account);
+        self
+    }
+
+    /// Merges all given overrides into the existing overrides.
+    pub fn extend(mut self, overrides: StateOverride) -> Self {
+        merge_state_override(&mut self.overrides, overrides);
+        self
+    }
+
+    /// Modifies the override for the given address in place.
+    pub fn account(
+        mut self,
+        address: Address,
+        f: impl FnOnce(AccountOverride) -> AccountOverride,
+    ) -> Self {
+        let account = self.overrides.remove(&address).unwrap_or_default();
+        self.overrides.insert(address, f(account));
+        self
+    }
+
+    /// Overrides the balance of the given address.
+    pub fn with_balance(self, address: Address, balance: U256) -> Self {
+        self.account(address, |account| account.with_balance(balance))
+    }
+
+    /// Overrides the nonce of the given address.
+    pub fn with_nonce(self, address: Address, nonce: u64) -> Self {
+        self.account(address, |account| account.with_nonce(nonce))
+    }
+
+    /// Overrides the code of the given address.
+    pub fn with_code(self, address: Address, code: impl Into<Bytes>) -> Self {
+        self.account(address, |account| account.with_code(code))
+    }
+
+    /// Overrides a single storage slot of the given address.
+    pub fn with_storage_diff(self, address: Address, slot: B256, value: B256) -> Self {
+        self.account(address, |account| account.with_storage_diff(slot, value))
+    }
+
+    /// Returns the overrides without validating them.
+    pub fn build_unchecked(self) -> StateOverride {
+        self.overrides
+    }
+
+    /// Validates and returns the overrides.
+    pub fn build(self) -> Result<StateOverride, StateOverrideError> {
+        validate_state_override(&self.overrides)?;
+        Ok(self.overrides)
+    }
+}
+
+impl From<StateOverridesBuilder> for StateOverride {
+    fn from(builder: StateOverridesBuilder) -> Self {
+        builder.overrides
+    }
+}
+
 /// Custom account override used in call
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -53,6 +165,108 @@ pub struct AccountOverride {
     pub move_precompile_to: Option<Address>,
 }
 
+impl AccountOverride {
+    /// Sets the balance override.
+    pub const fn with_balance(mut self, balance: U256) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+
+    /// Sets the nonce override.
+    pub const fn with_nonce(mut self, nonce: u64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Sets the code override.
+    pub fn with_code(mut self, code: impl Into<Bytes>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Replaces the entire storage of the account.
+    ///
+    /// This clears any previously set `stateDiff`, since the two are mutually exclusive.
+    pub fn with_state(mut self, state: impl IntoIterator<Item = (B256, B256)>) -> Self {
+        self.state = Some(state.into_iter().collect());
+        self.state_diff = None;
+        self
+    }
+
+    /// Overrides the given storage slots, keeping the rest of the storage.
+    ///
+    /// If the entire storage is replaced with [`with_state`](Self::with_state), the slots are
+    /// written into the replacement storage instead.
+    pub fn with_state_diff(mut self, diff: impl IntoIterator<Item = (B256, B256)>) -> Self {
+        self.state_diff_mut().extend(diff);
+        self
+    }
+
+    /// Overrides a single storage slot, keeping the rest of the storage.
+    ///
+    /// See [`with_state_diff`](Self::with_state_diff).
+    pub fn with_storage_diff(mut self, slot: B256, value: B256) -> Self {
+        self.state_diff_mut().insert(slot, value);
+        self
+    }
+
+    /// Sets the address to move the precompile of this account to.
+    pub const fn with_move_precompile_to(mut self, address: Address) -> Self {
+        self.move_precompile_to = Some(address);
+        self
+    }
+
+    /// Returns the map that individual storage slot overrides are written to.
+    fn state_diff_mut(&mut self) -> &mut B256HashMap<B256> {
+        match &mut self.state {
+            Some(state) => state,
+            None => self.state_diff.get_or_insert_with(Default::default),
+        }
+    }
+
+    /// Merges `other` into this override.
+    ///
+    /// Fields set in `other` take precedence. A full `state` in `other` replaces all storage
+    /// overrides of `self`, while a `stateDiff` in `other` is applied on top of the existing
+    /// storage overrides, i.e. into the full `state` of `self` if it has one. Merging is
+    /// therefore not commutative: a diff merged after a full state is folded into it, while a
+    /// full state merged after a diff discards it.
+    ///
+    /// If `other` sets both `state` and `stateDiff`, both are kept as they are, so that the
+    /// conflict is reported by [`validate`](Self::validate).
+    pub fn merge(&mut self, other: Self) {
+        let Self { balance, nonce, code, state, state_diff, move_precompile_to } = other;
+        let conflicting = state.is_some() && state_diff.is_some();
+        self.balance = balance.or(self.balance);
+        self.nonce = nonce.or(self.nonce);
+        if code.is_some() {
+            self.code = code;
+        }
+        if state.is_some() {
+            self.state = state;
+            self.state_diff = None;
+        }
+        if let Some(diff) = state_diff {
+            if conflicting {
+                self.state_diff = Some(diff);
+            } else {
+                self.state_diff_mut().extend(diff);
+            }
+        }
+        self.move_precompile_to = move_precompile_to.or(self.move_precompile_to);
+    }
+
+    /// Checks that the override is well formed.
+    ///
+    /// `state` and `stateDiff` are mutually exclusive and rejected by nodes if both are set.
+    pub const fn validate(&self, address: Address) -> Result<(), StateOverrideError> {
+        if self.state.is_some() && self.state_diff.is_some() {
+            return Err(StateOverrideError::StateAndStateDiff(address));
+        }
+        Ok(())
+    }
+}
+
 /// Helper type that bundles various overrides for EVM Execution.
 ///
 /// By `Default`, no overrides are included.
@@ -103,14 +317,139 @@ impl EvmOverrides {
         self.block = Some(block);
         self
     }
+
+    /// Merges `other` into this instance.
+    ///
+    /// State overrides are merged with [`merge_state_override`] and block overrides with
+    /// [`BlockOverrides::merge`].
+    pub fn merge(&mut self, other: Self) {
+        if let Some(state) = other.state {
+            match &mut self.state {
+                Some(existing) => merge_state_override(existing, state),
+                None => self.state = Some(state),
+            }
+        }
+        if let Some(block) = other.block {
+            match &mut self.block {
+                Some(existing) => existing.merge(*block),
+                None => self.block = Some(block),
+            }
+        }
+    }
+
+    /// Validates the state overrides, see [`validate_state_override`].
+    pub fn validate(&self) -> Result<(), StateOverrideError> {
+        self.state.as_ref().map_or(Ok(()), validate_state_override)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::address;
+    use alloy_primitives::{address, b256};
     use similar_asserts::assert_eq;
 
+    #[test]
+    fn account_override_builder() {
+        let slot = B256::with_last_byte(1);
+        let acc = AccountOverride::default()
+            .with_balance(U256::from(1))
+            .with_nonce(2)
+            .with_code(Bytes::from_static(&[0x60]))
+            .with_storage_diff(slot, B256::with_last_byte(2));
+        assert_eq!(acc.balance, Some(U256::from(1)));
+        assert_eq!(acc.nonce, Some(2));
+        assert_eq!(acc.state_diff.unwrap()[&slot], B256::with_last_byte(2));
+        assert!(acc.state.is_none());
+    }
+
+    #[test]
+    fn storage_diff_writes_into_full_state() {
+        let slot = B256::with_last_byte(1);
+        let acc = AccountOverride::default()
+            .with_storage_diff(B256::ZERO, B256::ZERO)
+            .with_state([(B256::ZERO, B256::with_last_byte(1))])
+            .with_storage_diff(slot, B256::with_last_byte(2));
+        assert!(acc.state_diff.is_none());
+        let state = acc.state.as_ref().unwrap();
+        assert_eq!(state.len(), 2);
+        assert_eq!(state[&slot], B256::with_last_byte(2));
+        acc.validate(Address::ZERO).unwrap();
+    }
+
+    #[test]
+    fn merge_account_overrides() {
+        let slot0 = B256::ZERO;
+        let slot1 = B256::with_last_byte(1);
+        let mut acc = AccountOverride::default()
+            .with_balance(U256::from(1))
+            .with_nonce(1)
+            .with_storage_diff(slot0, slot1);
+        acc.merge(
+            AccountOverride::default().with_balance(U256::from(2)).with_storage_diff(slot1, slot1),
+        );
+        assert_eq!(acc.balance, Some(U256::from(2)));
+        assert_eq!(acc.nonce, Some(1));
+        assert_eq!(acc.state_diff.as_ref().unwrap().len(), 2);
+
+        acc.merge(AccountOverride::default().with_state([(slot1, slot0)]));
+        assert!(acc.state_diff.is_none());
+        assert_eq!(acc.state.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn validate_state_and_state_diff() {
+        let address = address!("1234567890123456789012345678901234567890");
+        let acc = AccountOverride {
+            state: Some(Default::default()),
+            state_diff: Some(Default::default()),
+            ..Default::default()
+        };
+        assert_eq!(acc.validate(address), Err(StateOverrideError::StateAndStateDiff(address)));
+
+        let err =
+            StateOverridesBuilder::default().append(address, acc.clone()).build().unwrap_err();
+        assert_eq!(err, StateOverrideError::StateAndStateDiff(address));
+
+        let overrides = EvmOverrides::state(Some(StateOverride::from_iter([(address, acc)])));
+        assert!(overrides.validate().is_err());
+    }
+
+    #[test]
+    fn state_overrides_builder() {
+        let a = address!("1234567890123456789012345678901234567890");
+        let b = address!("0000000000000000000000000000000000000124");
+        let slot = b256!("ede27e4e7f3676edbf125879f17a896d6507958df3d57bda6219f1880cae8a41");
+        let overrides = StateOverridesBuilder::default()
+            .with_balance(a, U256::from(1))
+            .with_nonce(a, 3)
+            .with_storage_diff(b, slot, B256::with_last_byte(1))
+            .with_code(b, Bytes::from_static(&[0x60]))
+            .build()
+            .unwrap();
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides[&a].nonce, Some(3));
+        assert_eq!(overrides[&b].state_diff.as_ref().unwrap()[&slot], B256::with_last_byte(1));
+        assert!(overrides[&b].code.is_some());
+    }
+
+    #[test]
+    fn merge_evm_overrides() {
+        let address = Address::with_last_byte(1);
+        let mut overrides = EvmOverrides::state(Some(
+            StateOverridesBuilder::default().with_balance(address, U256::from(1)).build_unchecked(),
+        ));
+        overrides.merge(
+            EvmOverrides::default()
+                .with_state(StateOverridesBuilder::default().with_nonce(address, 1).into())
+                .with_block(Box::new(BlockOverrides::default().with_time(1))),
+        );
+        let state = overrides.state.as_ref().unwrap();
+        assert_eq!(state[&address].balance, Some(U256::from(1)));
+        assert_eq!(state[&address].nonce, Some(1));
+        assert_eq!(overrides.block.unwrap().time, Some(1));
+    }
+
     #[test]
     fn test_default_account_override() {
         let acc_override = AccountOverride::default();
@@ -5,16 +5,22 @@ use alloy_primitives::{B512, U256};
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SyncInfo {
     /// Starting block
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity"))]
     pub starting_block: U256,
     /// Current block
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity"))]
     pub current_block: U256,
     /// Highest block seen so far
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity"))]
     pub highest_block: U256,
     /// Warp sync snapshot chunks total.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity_opt"))]
     pub warp_chunks_amount: Option<U256>,
     /// Warp sync snapshot chunks processed.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity_opt"))]
     pub warp_chunks_processed: Option<U256>,
     /// The details of the sync stages as an hashmap
     /// where the key is the name of the stage and the value is the block number.
@@ -26,12 +32,14 @@ pub struct SyncInfo {
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Stage {
     /// The name of the sync stage.
     #[cfg_attr(feature = "serde", serde(alias = "stage_name"))]
     pub name: String,
     /// Indicates the progress of the sync stage.
     #[cfg_attr(feature = "serde", serde(alias = "block_number", with = "alloy_serde::quantity"))]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "crate::schema::quantity"))]
     pub block: u64,
 }
 
@@ -144,6 +152,24 @@ impl<'de> serde::Deserialize<'de> for SyncStatus {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SyncStatus {
+    fn schema_name() -> String {
+        "SyncStatus".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                any_of: Some(vec![crate::schema::r#false(gen), gen.subschema_for::<SyncInfo>()]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for SyncStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
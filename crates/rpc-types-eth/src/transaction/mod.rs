@@ -1,8 +1,8 @@
 //! RPC types for transactions
 
 use alloy_consensus::{
-    Signed, TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant, TxEip7702, TxEnvelope, TxLegacy,
-    Typed2718,
+    transaction::Recovered, Signed, TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant, TxEip7702,
+    TxEnvelope, TxLegacy, Typed2718,
 };
 use alloy_eips::{eip2718::Encodable2718, eip7702::SignedAuthorization};
 use alloy_network_primitives::TransactionResponse;
@@ -23,7 +23,7 @@ mod receipt;
 pub use receipt::TransactionReceipt;
 
 pub mod request;
-pub use request::{TransactionInput, TransactionRequest};
+pub use request::{MissingTransactionFields, TransactionInput, TransactionRequest};
 
 pub use alloy_consensus::{
     Receipt, ReceiptEnvelope, ReceiptWithBloom, Transaction as TransactionTrait,
@@ -99,6 +99,43 @@ where
     }
 }
 
+impl<T> Transaction<T>
+where
+    T: TransactionTrait,
+{
+    /// Creates an RPC transaction from a consensus transaction, its sender, and the metadata of
+    /// its inclusion, if any.
+    ///
+    /// The effective gas price is computed from the base fee in the [`TransactionInfo`]. If the
+    /// base fee is unknown, e.g. for pending transactions, this falls back to the max fee per gas.
+    pub fn from_transaction(inner: T, from: Address, info: TransactionInfo) -> Self {
+        let base_fee = info.base_fee.map(|base_fee| base_fee as u64);
+        Self {
+            effective_gas_price: Some(inner.effective_gas_price(base_fee)),
+            inner,
+            block_hash: info.block_hash,
+            block_number: info.block_number,
+            transaction_index: info.index,
+            from,
+        }
+    }
+
+    /// Creates an RPC transaction from a recovered consensus transaction and the metadata of its
+    /// inclusion, if any.
+    ///
+    /// See [`Transaction::from_transaction`] for more details.
+    pub fn from_recovered(tx: Recovered<T>, info: TransactionInfo) -> Self {
+        let (inner, from) = tx.into_parts();
+        Self::from_transaction(inner, from, info)
+    }
+
+    /// Converts this RPC transaction into the consensus transaction along with its sender,
+    /// discarding the inclusion metadata.
+    pub fn into_recovered(self) -> Recovered<T> {
+        Recovered::new_unchecked(self.inner, self.from)
+    }
+}
+
 impl<T> Transaction<T>
 where
     T: Into<TransactionRequest>,
@@ -452,6 +489,20 @@ mod tests {
         assert_eq!(legacy_tx.effective_gas_price, Some(0x23237dee));
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn rpc_consensus_roundtrip() {
+        let rpc_tx = r#"{"hash":"0x018b2331d461a4aeedf6a1f9cc37463377578244e6a35216057a8370714e798f","nonce":"0x1","blockHash":"0x6e4e53d1de650d5a5ebed19b38321db369ef1dc357904284ecf4d89b8834969c","blockNumber":"0x2","transactionIndex":"0x0","from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","value":"0x0","gasPrice":"0x3a29f0f8","gas":"0x1c9c380","maxFeePerGas":"0xba43b7400","maxPriorityFeePerGas":"0x5f5e100","input":"0xd09de08a","r":"0xd309309a59a49021281cb6bb41d164c96eab4e50f0c1bd24c03ca336e7bc2bb7","s":"0x28a7f089143d0a1355ebeb2a1b9f0e5ad9eca4303021c1400d61bc23c9ac5319","v":"0x0","yParity":"0x0","chainId":"0x7a69","accessList":[],"type":"0x2"}"#;
+        let tx = serde_json::from_str::<Transaction>(rpc_tx).unwrap();
+
+        let info = tx.info().with_base_fee(0x3a29f0f8 - 0x5f5e100);
+        let recovered = tx.clone().into_recovered();
+        assert_eq!(recovered.signer(), tx.from);
+
+        let rebuilt = Transaction::from_recovered(recovered, info);
+        assert_eq!(rebuilt, tx);
+    }
+
     // <https://github.com/alloy-rs/alloy/issues/1643>
     #[test]
     fn deserialize_7702_v() {
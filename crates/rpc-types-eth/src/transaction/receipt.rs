@@ -1,5 +1,5 @@
 use crate::Log;
-use alloy_consensus::{ReceiptEnvelope, TxReceipt, TxType};
+use alloy_consensus::{BloomMismatch, Eip658Value, ReceiptEnvelope, TxReceipt, TxType};
 use alloy_network_primitives::ReceiptResponse;
 use alloy_primitives::{Address, BlockHash, TxHash, B256};
 
@@ -101,6 +101,13 @@ impl TransactionReceipt {
         }
         Some(self.from.create(nonce))
     }
+
+    /// Verifies that the `logsBloom` of the receipt matches its logs.
+    ///
+    /// See [`ReceiptEnvelope::verify_bloom`].
+    pub fn verify_bloom(&self) -> Result<(), BloomMismatch> {
+        self.inner.verify_bloom()
+    }
 }
 
 impl<T> TransactionReceipt<T> {
@@ -182,13 +189,21 @@ impl<T: TxReceipt<Log = Log>> ReceiptResponse for TransactionReceipt<T> {
     fn state_root(&self) -> Option<B256> {
         self.inner.status_or_post_state().as_post_state()
     }
+
+    fn logs(&self) -> impl Iterator<Item = &alloy_primitives::Log> {
+        self.inner.logs().iter().map(|log| &log.inner)
+    }
+
+    fn status_or_post_state(&self) -> Eip658Value {
+        self.inner.status_or_post_state()
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::TransactionReceipt;
-    use alloy_consensus::{Eip658Value, Receipt, ReceiptWithBloom};
+    use alloy_consensus::{Receipt, ReceiptWithBloom};
     use alloy_primitives::{address, b256, bloom, Bloom};
     use arbitrary::Arbitrary;
     use rand::Rng;
@@ -289,5 +304,59 @@ mod test {
             receipt.transaction_hash,
             b256!("ea1093d492a1dcb1bef708f771a99a96ff05dcab81ca76c31940300177fcf49f")
         );
+        assert_eq!(
+            ReceiptResponse::status_or_post_state(&receipt),
+            Eip658Value::PostState(b256!(
+                "284d35bf53b82ef480ab4208527325477439c64fb90ef518450f05ee151c8e10"
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn receipt_response_accessors() {
+        let receipt_json = r#"{
+            "blobGasPrice": "0x1",
+            "blobGasUsed": "0x20000",
+            "blockHash": "0x71f8d8a5e1a8b1b6dce8e6e7f4e3c5a0b0e2e2b1c0d9d8c7b6a5a4a3a2a1a0a9",
+            "blockNumber": "0x1",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0x5208",
+            "effectiveGasPrice": "0x3b9aca00",
+            "from": "0x39fa8c5f2793459d6622857e7d9fbb4bd91766d3",
+            "gasUsed": "0x5208",
+            "logs": [
+                {
+                    "address": "0xc083e9947cf02b8ffc7d3090ae9aea72df98fd47",
+                    "topics": [],
+                    "data": "0x",
+                    "blockHash": "0x71f8d8a5e1a8b1b6dce8e6e7f4e3c5a0b0e2e2b1c0d9d8c7b6a5a4a3a2a1a0a9",
+                    "blockNumber": "0x1",
+                    "transactionHash": "0xea1093d492a1dcb1bef708f771a99a96ff05dcab81ca76c31940300177fcf49f",
+                    "transactionIndex": "0x0",
+                    "logIndex": "0x0",
+                    "removed": false
+                }
+            ],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "revertReason": "0x08c379a0",
+            "status": "0x0",
+            "to": "0xc083e9947cf02b8ffc7d3090ae9aea72df98fd47",
+            "transactionHash": "0xea1093d492a1dcb1bef708f771a99a96ff05dcab81ca76c31940300177fcf49f",
+            "transactionIndex": "0x0",
+            "type": "0x3"
+        }"#;
+
+        let receipt: alloy_serde::WithOtherFields<TransactionReceipt> =
+            serde_json::from_str(receipt_json).unwrap();
+
+        assert!(!receipt.status());
+        assert_eq!(receipt.status_or_post_state(), Eip658Value::Eip658(false));
+        assert_eq!(receipt.logs().count(), 1);
+        assert_eq!(receipt.execution_fee(), 21_000 * 1_000_000_000);
+        assert_eq!(receipt.blob_fee(), Some(0x20000));
+        assert_eq!(receipt.total_fee(), 21_000 * 1_000_000_000 + 0x20000);
+        assert_eq!(receipt.revert_reason(), Some(alloy_primitives::bytes!("08c379a0")));
+        assert_eq!(receipt.inner.revert_reason(), None);
     }
 }
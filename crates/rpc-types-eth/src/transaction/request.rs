@@ -11,6 +11,7 @@ use alloy_primitives::{Address, Bytes, ChainId, TxKind, B256, U256};
 use core::hash::Hash;
 
 use alloc::{
+    boxed::Box,
     string::{String, ToString},
     vec,
     vec::Vec,
@@ -706,6 +707,22 @@ impl TransactionRequest {
     }
 }
 
+impl TryFrom<TransactionRequest> for TypedTransaction {
+    type Error = MissingTransactionFields;
+
+    /// Builds a _complete_ [`TypedTransaction`] of the request's
+    /// [preferred type](TransactionRequest::preferred_type), failing with the list of missing
+    /// fields otherwise.
+    fn try_from(request: TransactionRequest) -> Result<Self, Self::Error> {
+        match request.missing_keys() {
+            Ok(_) => Ok(request.build_typed_tx().expect("checked")),
+            Err((tx_type, missing)) => {
+                Err(MissingTransactionFields { tx_type, missing, request: Box::new(request) })
+            }
+        }
+    }
+}
+
 impl TransactionBuilder4844 for TransactionRequest {
     fn max_fee_per_blob_gas(&self) -> Option<u128> {
         self.max_fee_per_blob_gas
@@ -1113,6 +1130,19 @@ pub struct BuildTransactionErr<T = TransactionRequest> {
     pub error: String,
 }
 
+/// Error returned when a [`TransactionRequest`] is missing fields required to build a complete
+/// transaction of its preferred type.
+#[derive(Debug, thiserror::Error)]
+#[error("missing fields to build {tx_type} transaction: {}", missing.join(", "))]
+pub struct MissingTransactionFields {
+    /// The transaction type the request would be built as.
+    pub tx_type: TxType,
+    /// The names of the missing fields.
+    pub missing: Vec<&'static str>,
+    /// The transaction request that failed to build.
+    pub request: Box<TransactionRequest>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1244,6 +1274,29 @@ mod tests {
         assert!(req.sidecar.is_none()); // Sidecar won't be deserialized.
     }
 
+    #[test]
+    fn try_into_typed_tx_lists_missing_fields() {
+        let request = TransactionRequest {
+            to: Some(TxKind::Call(Address::repeat_byte(0xDE))),
+            max_fee_per_gas: Some(2),
+            nonce: Some(1),
+            ..Default::default()
+        };
+        let err = TypedTransaction::try_from(request.clone()).unwrap_err();
+        assert_eq!(err.tx_type, TxType::Eip1559);
+        assert_eq!(err.missing, vec!["gas_limit", "max_priority_fee_per_gas"]);
+        assert_eq!(
+            err.to_string(),
+            "missing fields to build EIP-1559 transaction: gas_limit, max_priority_fee_per_gas"
+        );
+        assert_eq!(*err.request, request);
+
+        let request =
+            TransactionRequest { gas: Some(21_000), max_priority_fee_per_gas: Some(1), ..request };
+        let tx = TypedTransaction::try_from(request).unwrap();
+        assert_matches!(tx, TypedTransaction::Eip1559(TxEip1559 { gas_limit: 21_000, .. }));
+    }
+
     #[test]
     fn build_consensus_tx_works() {
         // Legacy
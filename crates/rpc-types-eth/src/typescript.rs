@@ -0,0 +1,247 @@
+//! Generation of TypeScript type definitions from the [JSON Schemas](crate::schema) of RPC types.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_rpc_types_eth::{typescript::TypeScriptGenerator, Block, TransactionReceipt};
+//!
+//! let definitions =
+//!     TypeScriptGenerator::new().add::<Block>().add::<TransactionReceipt>().generate();
+//! assert!(definitions.contains("export interface TransactionReceipt {"));
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::{InstanceType, Schema, SchemaObject, SingleOrVec},
+    JsonSchema,
+};
+
+/// Generates TypeScript declarations for types implementing [`JsonSchema`].
+///
+/// Every added type is emitted together with all the types it references. Object types become
+/// interfaces, other types become type aliases. Hex encoded values are typed as template literal
+/// types starting with `0x`.
+#[derive(Debug)]
+pub struct TypeScriptGenerator {
+    gen: SchemaGenerator,
+    aliases: Vec<(String, Schema)>,
+}
+
+impl Default for TypeScriptGenerator {
+    fn default() -> Self {
+        Self { gen: SchemaSettings::draft07().into_generator(), aliases: Vec::new() }
+    }
+}
+
+impl TypeScriptGenerator {
+    /// Creates a new generator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `T` and all types it references to the output.
+    pub fn add<T: JsonSchema>(&mut self) -> &mut Self {
+        let schema = self.gen.subschema_for::<T>();
+        if !matches!(&schema, Schema::Object(obj) if obj.is_ref()) {
+            self.aliases.push((T::schema_name(), schema));
+        }
+        self
+    }
+
+    /// Returns the TypeScript declarations of all added types.
+    pub fn generate(&self) -> String {
+        let mut out = String::new();
+        let definitions =
+            self.gen.definitions().iter().map(|(name, schema)| (name.as_str(), schema));
+        let aliases = self.aliases.iter().map(|(name, schema)| (name.as_str(), schema));
+        for (i, (name, schema)) in definitions.chain(aliases).enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            write_declaration(&mut out, name, schema);
+        }
+        out
+    }
+}
+
+fn write_declaration(out: &mut String, name: &str, schema: &Schema) {
+    let name = ident(name);
+    let Schema::Object(obj) = schema else {
+        let _ = writeln!(out, "export type {name} = {};", ts_type(schema));
+        return;
+    };
+    if let Some(description) = obj.metadata.as_ref().and_then(|m| m.description.as_deref()) {
+        write_doc(out, description, "");
+    }
+    let is_interface = obj.subschemas.is_none()
+        && obj.instance_type == Some(InstanceType::Object.into())
+        && obj.object.as_ref().is_some_and(|o| !o.properties.is_empty());
+    if is_interface {
+        let _ = writeln!(out, "export interface {name} {{");
+        write_properties(out, obj, "  ");
+        out.push_str("}\n");
+    } else {
+        let _ = writeln!(out, "export type {name} = {};", ts_type(schema));
+    }
+}
+
+fn write_properties(out: &mut String, obj: &SchemaObject, indent: &str) {
+    let Some(object) = &obj.object else { return };
+    for (key, schema) in &object.properties {
+        if let Schema::Object(SchemaObject { metadata: Some(metadata), .. }) = schema {
+            if let Some(description) = &metadata.description {
+                write_doc(out, description, indent);
+            }
+        }
+        let optional = if object.required.contains(key) { "" } else { "?" };
+        let _ = writeln!(out, "{indent}{}{optional}: {};", property_key(key), ts_type(schema));
+    }
+}
+
+fn write_doc(out: &mut String, doc: &str, indent: &str) {
+    let mut lines = doc.lines();
+    match (lines.next(), lines.next()) {
+        (Some(line), None) => {
+            let _ = writeln!(out, "{indent}/** {line} */");
+        }
+        _ => {
+            let _ = writeln!(out, "{indent}/**");
+            for line in doc.lines() {
+                let _ = writeln!(out, "{}", format!("{indent} * {line}").trim_end());
+            }
+            let _ = writeln!(out, "{indent} */");
+        }
+    }
+}
+
+fn ts_type(schema: &Schema) -> String {
+    let obj = match schema {
+        Schema::Bool(true) => return "unknown".into(),
+        Schema::Bool(false) => return "never".into(),
+        Schema::Object(obj) => obj,
+    };
+    if let Some(reference) = &obj.reference {
+        return ident(reference.rsplit('/').next().unwrap_or(reference));
+    }
+    if let Some(value) = &obj.const_value {
+        return value.to_string();
+    }
+    if let Some(values) = &obj.enum_values {
+        return union(values.iter().map(ToString::to_string));
+    }
+    if let Some(subschemas) = &obj.subschemas {
+        if let Some(schemas) = subschemas.any_of.as_ref().or(subschemas.one_of.as_ref()) {
+            return union(schemas.iter().map(ts_type));
+        }
+        if let Some(schemas) = &subschemas.all_of {
+            return schemas
+                .iter()
+                .map(|s| format!("({})", ts_type(s)))
+                .collect::<Vec<_>>()
+                .join(" & ");
+        }
+    }
+    match &obj.instance_type {
+        None => "unknown".into(),
+        Some(SingleOrVec::Single(ty)) => instance_type(obj, ty),
+        Some(SingleOrVec::Vec(types)) => union(types.iter().map(|ty| instance_type(obj, ty))),
+    }
+}
+
+fn instance_type(obj: &SchemaObject, ty: &InstanceType) -> String {
+    match ty {
+        InstanceType::Null => "null".into(),
+        InstanceType::Boolean => "boolean".into(),
+        InstanceType::Number | InstanceType::Integer => "number".into(),
+        InstanceType::String => {
+            let pattern = obj.string.as_ref().and_then(|s| s.pattern.as_deref());
+            if pattern.is_some_and(|p| p.starts_with("^0x")) {
+                "`0x${string}`".into()
+            } else {
+                "string".into()
+            }
+        }
+        InstanceType::Array => match obj.array.as_ref().and_then(|a| a.items.as_ref()) {
+            Some(SingleOrVec::Single(item)) => format!("Array<{}>", ts_type(item)),
+            Some(SingleOrVec::Vec(items)) => {
+                format!("[{}]", items.iter().map(ts_type).collect::<Vec<_>>().join(", "))
+            }
+            None => "Array<unknown>".into(),
+        },
+        InstanceType::Object => match &obj.object {
+            Some(object) if !object.properties.is_empty() => {
+                let mut out = String::from("{\n");
+                write_properties(&mut out, obj, "  ");
+                out.push('}');
+                out
+            }
+            Some(object) => match &object.additional_properties {
+                Some(value) => format!("Record<string, {}>", ts_type(value)),
+                None => "Record<string, unknown>".into(),
+            },
+            None => "Record<string, unknown>".into(),
+        },
+    }
+}
+
+fn union(types: impl Iterator<Item = String>) -> String {
+    let mut variants = Vec::<String>::new();
+    for ty in types {
+        if !variants.contains(&ty) {
+            variants.push(ty);
+        }
+    }
+    variants.join(" | ")
+}
+
+fn ident(name: &str) -> String {
+    name.chars().map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' }).collect()
+}
+
+fn property_key(key: &str) -> String {
+    let is_ident = key.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_ident {
+        key.into()
+    } else {
+        serde_json::Value::from(key).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FeeHistory, SyncStatus, TransactionReceipt};
+
+    #[test]
+    fn generates_declarations() {
+        let ts = TypeScriptGenerator::new()
+            .add::<FeeHistory>()
+            .add::<SyncStatus>()
+            .add::<TransactionReceipt>()
+            .generate();
+
+        assert!(ts.contains("export interface FeeHistory {\n"));
+        assert!(ts.contains("  oldestBlock: `0x${string}`;\n"));
+        assert!(ts.contains("  reward?: Array<Array<`0x${string}`>> | null;\n"));
+        assert!(ts.contains("  gasUsedRatio: Array<number>;\n"));
+        assert!(ts.contains("export type SyncStatus = false | SyncInfo;\n"));
+        assert!(ts.contains("export interface SyncInfo {\n"));
+        assert!(ts.contains("  stages?: Array<Stage> | null;\n"));
+        assert!(ts.contains("  logs: Array<Log>;\n"));
+        assert!(ts.contains("  type: \"0x0\" | \"0x1\" | \"0x2\" | \"0x3\" | \"0x4\";\n"));
+        assert!(ts.contains("  to: `0x${string}` | null;\n"));
+    }
+
+    #[test]
+    fn quotes_keys() {
+        assert_eq!(property_key("gasUsed"), "gasUsed");
+        assert_eq!(property_key("0x"), "\"0x\"");
+        assert_eq!(property_key("a-b"), "\"a-b\"");
+    }
+}
@@ -229,20 +229,41 @@ pub struct CancelPrivateTransactionRequest {
 /// <https://docs.flashbots.net/flashbots-auction/searchers/advanced/rpc-endpoint#eth_sendbundle>
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EthSendBundle {
     /// A list of hex-encoded signed transactions
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::bytes_vec")
+    )]
     pub txs: Vec<Bytes>,
     /// hex-encoded block number for which this bundle is valid
     #[serde(with = "alloy_serde::quantity")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::quantity")
+    )]
     pub block_number: u64,
     /// unix timestamp when this bundle becomes active
     #[serde(default, with = "alloy_serde::quantity::opt", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::quantity_opt")
+    )]
     pub min_timestamp: Option<u64>,
     /// unix timestamp how long this bundle stays valid
     #[serde(default, with = "alloy_serde::quantity::opt", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::quantity_opt")
+    )]
     pub max_timestamp: Option<u64>,
     /// list of hashes of possibly reverting txs
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::hash_vec")
+    )]
     pub reverting_tx_hashes: Vec<B256>,
     /// UUID that can be used to cancel/replace this bundle
     #[serde(default, rename = "replacementUuid", skip_serializing_if = "Option::is_none")]
@@ -283,8 +304,10 @@ impl EthSendBundle {
 /// Response from the matchmaker after sending a bundle.
 #[derive(Deserialize, Debug, Serialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EthBundleHash {
     /// Hash of the bundle bodies.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "alloy_rpc_types_eth::schema::hash"))]
     pub bundle_hash: B256,
 }
 
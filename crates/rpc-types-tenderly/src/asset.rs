@@ -0,0 +1,138 @@
+//! Asset and balance changes of simulated transactions.
+
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+/// The token standard of an asset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AssetStandard {
+    /// The native currency of the chain.
+    NativeCurrency,
+    /// An [ERC-20](https://eips.ethereum.org/EIPS/eip-20) fungible token.
+    #[serde(rename = "ERC20")]
+    Erc20,
+    /// An [ERC-721](https://eips.ethereum.org/EIPS/eip-721) non-fungible token.
+    #[serde(rename = "ERC721")]
+    Erc721,
+    /// An [ERC-1155](https://eips.ethereum.org/EIPS/eip-1155) multi token.
+    #[serde(rename = "ERC1155")]
+    Erc1155,
+    /// Any other standard.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kind of an [`AssetChange`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AssetChangeType {
+    /// A transfer between two accounts.
+    Transfer,
+    /// Assets minted to an account.
+    Mint,
+    /// Assets burned from an account.
+    Burn,
+    /// Any other kind of change.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Information on the asset of an [`AssetChange`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetInfo {
+    /// The token standard of the asset.
+    pub standard: AssetStandard,
+    /// The kind of the asset, e.g. `Native` or `Fungible`.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The contract of the asset, `None` for the native currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_address: Option<Address>,
+    /// The symbol of the asset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// The name of the asset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The URL of the logo of the asset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+    /// The decimals of the asset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u8>,
+    /// The price of a unit of the asset in US dollars, as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dollar_value: Option<String>,
+}
+
+impl AssetInfo {
+    /// Creates the information of the native currency.
+    pub const fn native() -> Self {
+        Self {
+            standard: AssetStandard::NativeCurrency,
+            kind: None,
+            contract_address: None,
+            symbol: None,
+            name: None,
+            logo: None,
+            decimals: None,
+            dollar_value: None,
+        }
+    }
+
+    /// Creates the information of a token of the given standard.
+    pub const fn token(standard: AssetStandard, contract_address: Address) -> Self {
+        Self {
+            standard,
+            kind: None,
+            contract_address: Some(contract_address),
+            symbol: None,
+            name: None,
+            logo: None,
+            decimals: None,
+            dollar_value: None,
+        }
+    }
+}
+
+/// A change of the holdings of an asset, such as a transfer, mint or burn.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetChange {
+    /// The asset.
+    pub asset_info: AssetInfo,
+    /// The kind of the change.
+    #[serde(rename = "type")]
+    pub kind: AssetChangeType,
+    /// The sender, `None` for mints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<Address>,
+    /// The recipient, `None` for burns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// The amount in the smallest unit of the asset, `1` for ERC-721 tokens.
+    pub raw_amount: U256,
+    /// The amount scaled by the decimals of the asset, as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// The id of the token, for ERC-721 and ERC-1155 assets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<U256>,
+    /// The value of the amount in US dollars, as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dollar_value: Option<String>,
+}
+
+/// The change of the total holdings of an account, in US dollars.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChange {
+    /// The account.
+    pub address: Address,
+    /// The net change in US dollars, as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dollar_value: Option<String>,
+    /// The indices of the [`AssetChange`]s involving the account.
+    #[serde(default)]
+    pub transfers: Vec<usize>,
+}
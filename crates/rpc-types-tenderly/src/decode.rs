@@ -0,0 +1,30 @@
+//! Decoded calldata, errors and logs.
+
+use serde::{Deserialize, Serialize};
+
+/// A decoded function argument, error argument or event parameter.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedArgument {
+    /// The name of the parameter, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The Solidity type of the parameter.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The decoded value.
+    pub value: serde_json::Value,
+    /// Whether the event parameter is indexed, `None` for arguments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed: Option<bool>,
+}
+
+/// Calldata or revert data decoded by `tenderly_decodeInput` or `tenderly_decodeError`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedCall {
+    /// The name of the function or error.
+    pub name: String,
+    /// The decoded arguments.
+    #[serde(default)]
+    pub decoded_arguments: Vec<DecodedArgument>,
+}
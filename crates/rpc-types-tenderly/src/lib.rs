@@ -0,0 +1,22 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/alloy.jpg",
+    html_favicon_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/favicon.ico"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod asset;
+pub use asset::*;
+
+mod decode;
+pub use decode::*;
+
+mod query;
+pub use query::*;
+
+mod simulation;
+pub use simulation::*;
+
+mod state;
+pub use state::*;
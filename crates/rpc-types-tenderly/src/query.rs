@@ -0,0 +1,98 @@
+//! Historical queries of `tenderly_getTransactionsRange` and `tenderly_getStorageChanges`.
+
+use alloy_primitives::{Address, Selector, TxHash, B256};
+use alloy_rpc_types_eth::BlockNumberOrTag;
+use serde::{Deserialize, Serialize};
+
+/// The filter of `tenderly_getTransactionsRange`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsRangeRequest {
+    /// Only include transactions sent by this account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<Address>,
+    /// Only include transactions sent to this account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// The first block of the range.
+    pub from_block: BlockNumberOrTag,
+    /// The last block of the range, inclusive.
+    pub to_block: BlockNumberOrTag,
+    /// Only include transactions calling the function with this selector.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method_id: Option<Selector>,
+}
+
+impl TransactionsRangeRequest {
+    /// Creates a filter matching all transactions in the given block range.
+    pub const fn new(from_block: BlockNumberOrTag, to_block: BlockNumberOrTag) -> Self {
+        Self { from: None, to: None, from_block, to_block, method_id: None }
+    }
+
+    /// Only includes transactions sent by the given account.
+    pub const fn with_from(mut self, from: Address) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Only includes transactions sent to the given account.
+    pub const fn with_to(mut self, to: Address) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Only includes transactions calling the function with the given selector.
+    pub const fn with_method_id(mut self, method_id: Selector) -> Self {
+        self.method_id = Some(method_id);
+        self
+    }
+}
+
+/// The filter of `tenderly_getStorageChanges`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageChangesRequest {
+    /// The account whose storage changes are returned.
+    pub address: Address,
+    /// Only include changes of this slot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot: Option<B256>,
+    /// The first block of the range.
+    pub from_block: BlockNumberOrTag,
+    /// The last block of the range, inclusive.
+    pub to_block: BlockNumberOrTag,
+}
+
+impl StorageChangesRequest {
+    /// Creates a filter matching all storage changes of the account in the given block range.
+    pub const fn new(
+        address: Address,
+        from_block: BlockNumberOrTag,
+        to_block: BlockNumberOrTag,
+    ) -> Self {
+        Self { address, slot: None, from_block, to_block }
+    }
+
+    /// Only includes changes of the given slot.
+    pub const fn with_slot(mut self, slot: B256) -> Self {
+        self.slot = Some(slot);
+        self
+    }
+}
+
+/// A change of a storage slot by a historical transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalStorageChange {
+    /// The number of the block of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: u64,
+    /// The hash of the transaction.
+    pub transaction_hash: TxHash,
+    /// The storage slot.
+    pub slot: B256,
+    /// The value before the transaction.
+    pub previous_value: B256,
+    /// The value after the transaction.
+    pub new_value: B256,
+}
@@ -0,0 +1,438 @@
+//! Requests and results of `tenderly_simulateTransaction`, `tenderly_simulateBundle` and
+//! `tenderly_estimateGas`.
+
+use crate::{AssetChange, BalanceChange, DecodedArgument, StateChange};
+use alloy_primitives::{Address, Bloom, Bytes, Log, U256};
+use alloy_rpc_types_eth::{
+    state::{EvmOverrides, StateOverride},
+    BlockId, BlockOverrides, TransactionRequest,
+};
+use serde::{ser::SerializeSeq, Deserialize, Serialize, Serializer};
+
+/// The parameters of `tenderly_simulateTransaction`.
+///
+/// Serialized as the positional parameters of the method. The transaction is simulated on top of
+/// the latest block unless another block is set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulationRequest<T = TransactionRequest> {
+    /// The transaction to simulate.
+    pub transaction: T,
+    /// The block on top of which the transaction is simulated.
+    pub block: BlockId,
+    /// The state overrides applied before the simulation.
+    pub state_overrides: Option<StateOverride>,
+    /// The block overrides applied before the simulation.
+    pub block_overrides: Option<Box<BlockOverrides>>,
+}
+
+impl<T> SimulationRequest<T> {
+    /// Creates a request simulating the transaction on top of the latest block.
+    pub fn new(transaction: T) -> Self {
+        Self {
+            transaction,
+            block: BlockId::default(),
+            state_overrides: None,
+            block_overrides: None,
+        }
+    }
+
+    /// Sets the block on top of which the transaction is simulated.
+    pub const fn at_block(mut self, block: BlockId) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the state overrides.
+    pub fn with_state_overrides(mut self, state_overrides: StateOverride) -> Self {
+        self.state_overrides = Some(state_overrides);
+        self
+    }
+
+    /// Sets the block overrides.
+    pub fn with_block_overrides(mut self, block_overrides: BlockOverrides) -> Self {
+        self.block_overrides = Some(Box::new(block_overrides));
+        self
+    }
+
+    /// Sets the state and block overrides from the given [`EvmOverrides`].
+    pub fn with_evm_overrides(mut self, overrides: EvmOverrides) -> Self {
+        self.state_overrides = overrides.state;
+        self.block_overrides = overrides.block;
+        self
+    }
+}
+
+impl<T> From<T> for SimulationRequest<T> {
+    fn from(transaction: T) -> Self {
+        Self::new(transaction)
+    }
+}
+
+impl<T: Serialize> Serialize for SimulationRequest<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_params(
+            serializer,
+            &self.transaction,
+            &self.block,
+            self.state_overrides.as_ref(),
+            self.block_overrides.as_deref(),
+        )
+    }
+}
+
+/// The parameters of `tenderly_simulateBundle`.
+///
+/// The transactions are simulated in order, each on top of the state left by the previous ones.
+/// Serialized as the positional parameters of the method.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BundleSimulationRequest<T = TransactionRequest> {
+    /// The transactions to simulate.
+    pub transactions: Vec<T>,
+    /// The block on top of which the transactions are simulated.
+    pub block: BlockId,
+    /// The state overrides applied before the simulation.
+    pub state_overrides: Option<StateOverride>,
+    /// The block overrides applied before the simulation.
+    pub block_overrides: Option<Box<BlockOverrides>>,
+}
+
+impl<T> BundleSimulationRequest<T> {
+    /// Creates a request simulating the transactions on top of the latest block.
+    pub fn new(transactions: Vec<T>) -> Self {
+        Self {
+            transactions,
+            block: BlockId::default(),
+            state_overrides: None,
+            block_overrides: None,
+        }
+    }
+
+    /// Appends a transaction to the bundle.
+    pub fn push(mut self, transaction: T) -> Self {
+        self.transactions.push(transaction);
+        self
+    }
+
+    /// Sets the block on top of which the transactions are simulated.
+    pub const fn at_block(mut self, block: BlockId) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the state overrides.
+    pub fn with_state_overrides(mut self, state_overrides: StateOverride) -> Self {
+        self.state_overrides = Some(state_overrides);
+        self
+    }
+
+    /// Sets the block overrides.
+    pub fn with_block_overrides(mut self, block_overrides: BlockOverrides) -> Self {
+        self.block_overrides = Some(Box::new(block_overrides));
+        self
+    }
+
+    /// Sets the state and block overrides from the given [`EvmOverrides`].
+    pub fn with_evm_overrides(mut self, overrides: EvmOverrides) -> Self {
+        self.state_overrides = overrides.state;
+        self.block_overrides = overrides.block;
+        self
+    }
+}
+
+impl<T> From<Vec<T>> for BundleSimulationRequest<T> {
+    fn from(transactions: Vec<T>) -> Self {
+        Self::new(transactions)
+    }
+}
+
+impl<T> FromIterator<T> for BundleSimulationRequest<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<T: Serialize> Serialize for BundleSimulationRequest<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_params(
+            serializer,
+            &self.transactions,
+            &self.block,
+            self.state_overrides.as_ref(),
+            self.block_overrides.as_deref(),
+        )
+    }
+}
+
+/// Serializes the positional parameters of a simulation, omitting trailing unset overrides.
+fn serialize_params<S: Serializer>(
+    serializer: S,
+    transactions: &impl Serialize,
+    block: &BlockId,
+    state_overrides: Option<&StateOverride>,
+    block_overrides: Option<&BlockOverrides>,
+) -> Result<S::Ok, S::Error> {
+    let len = if block_overrides.is_some() {
+        4
+    } else if state_overrides.is_some() {
+        3
+    } else {
+        2
+    };
+    let mut seq = serializer.serialize_seq(Some(len))?;
+    seq.serialize_element(transactions)?;
+    seq.serialize_element(block)?;
+    if len > 2 {
+        seq.serialize_element(&state_overrides)?;
+    }
+    if let Some(block_overrides) = block_overrides {
+        seq.serialize_element(block_overrides)?;
+    }
+    seq.end()
+}
+
+/// A log emitted by a simulated transaction, decoded if the ABI of the emitter is known.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulatedLog {
+    /// The name of the event, if decoded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the event is anonymous.
+    #[serde(default)]
+    pub anonymous: bool,
+    /// The decoded parameters of the event.
+    #[serde(default, deserialize_with = "alloy_serde::null_as_default")]
+    pub inputs: Vec<DecodedArgument>,
+    /// The raw log.
+    pub raw: Log,
+}
+
+/// A call frame of a simulated transaction, in the flat format of parity traces.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedCall {
+    /// The kind of the call, e.g. `CALL`, `DELEGATECALL` or `CREATE`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The caller.
+    pub from: Address,
+    /// The callee, or the created contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// The gas provided to the call.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas: u64,
+    /// The gas used by the call.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// The value transferred by the call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    /// The input of the call.
+    #[serde(default)]
+    pub input: Bytes,
+    /// The output of the call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<Bytes>,
+    /// The name of the called function, if decoded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// The decoded arguments of the call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoded_input: Option<Vec<DecodedArgument>>,
+    /// The decoded return values of the call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoded_output: Option<Vec<DecodedArgument>>,
+    /// The error of the call, if it failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The decoded revert reason of the call, if it reverted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_reason: Option<String>,
+    /// The number of calls made by the call.
+    #[serde(default)]
+    pub subtraces: usize,
+    /// The position of the call in the call tree.
+    #[serde(default)]
+    pub trace_address: Vec<usize>,
+}
+
+/// The result of a simulated or traced transaction.
+///
+/// Returned by `tenderly_simulateTransaction`, `tenderly_simulateBundle` and
+/// `tenderly_traceTransaction`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationResult {
+    /// Whether the transaction succeeded.
+    pub status: bool,
+    /// The gas used by the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// The gas used by the block up to and including the transaction.
+    #[serde(default, with = "alloy_serde::quantity::opt", skip_serializing_if = "Option::is_none")]
+    pub cumulative_gas_used: Option<u64>,
+    /// The number of the block the transaction was simulated in.
+    #[serde(default, with = "alloy_serde::quantity::opt", skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<u64>,
+    /// The type of the transaction.
+    #[serde(
+        default,
+        rename = "type",
+        with = "alloy_serde::quantity::opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub transaction_type: Option<u8>,
+    /// The bloom filter of the logs of the transaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logs_bloom: Option<Bloom>,
+    /// The logs emitted by the transaction.
+    #[serde(default, deserialize_with = "alloy_serde::null_as_default")]
+    pub logs: Vec<SimulatedLog>,
+    /// The call frames of the transaction, in pre-order.
+    #[serde(default, deserialize_with = "alloy_serde::null_as_default")]
+    pub trace: Vec<SimulatedCall>,
+    /// The asset changes caused by the transaction.
+    #[serde(default, deserialize_with = "alloy_serde::null_as_default")]
+    pub asset_changes: Vec<AssetChange>,
+    /// The balance changes caused by the transaction, in US dollars.
+    #[serde(default, deserialize_with = "alloy_serde::null_as_default")]
+    pub balance_changes: Vec<BalanceChange>,
+    /// The state changes caused by the transaction.
+    #[serde(default, deserialize_with = "alloy_serde::null_as_default")]
+    pub state_changes: Vec<StateChange>,
+}
+
+impl SimulationResult {
+    /// Returns the first error of the call frames, preferring decoded revert reasons.
+    pub fn error(&self) -> Option<&str> {
+        self.trace.iter().find_map(|call| call.error_reason.as_deref().or(call.error.as_deref()))
+    }
+}
+
+/// The result of `tenderly_estimateGas`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimate {
+    /// The gas limit required by the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas: u64,
+    /// The gas used by the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssetChangeType, AssetStandard};
+    use alloy_primitives::{address, U256};
+    use alloy_rpc_types_eth::state::AccountOverride;
+    use similar_asserts::assert_eq;
+
+    #[test]
+    fn serialize_request() {
+        let tx = TransactionRequest::default().to(Address::ZERO);
+        let request = SimulationRequest::new(tx.clone()).at_block(BlockId::number(1));
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!([{ "to": Address::ZERO }, "0x1"])
+        );
+
+        let request = request.with_block_overrides(BlockOverrides::default());
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!([{ "to": Address::ZERO }, "0x1", null, {}])
+        );
+
+        let mut state = StateOverride::default();
+        state.insert(Address::ZERO, AccountOverride::default().with_nonce(1));
+        let bundle = BundleSimulationRequest::from(vec![tx.clone(), tx])
+            .with_evm_overrides(EvmOverrides::state(Some(state.clone())));
+        assert_eq!(
+            serde_json::to_value(&bundle).unwrap(),
+            serde_json::json!([
+                [{ "to": Address::ZERO }, { "to": Address::ZERO }],
+                "latest",
+                state
+            ])
+        );
+    }
+
+    #[test]
+    fn deserialize_result() {
+        let s = r#"{
+            "status": false,
+            "gasUsed": "0x5208",
+            "cumulativeGasUsed": "0x5208",
+            "blockNumber": "0x1312d00",
+            "type": "0x2",
+            "logs": [{
+                "name": "Transfer",
+                "anonymous": false,
+                "inputs": [
+                    { "name": "from", "type": "address", "value": "0x0000000000000000000000000000000000000001", "indexed": true },
+                    { "name": "value", "type": "uint256", "value": "1000", "indexed": false }
+                ],
+                "raw": {
+                    "address": "0x6b175474e89094c44da98b954eedeac495271d0f",
+                    "topics": ["0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"],
+                    "data": "0x"
+                }
+            }],
+            "trace": [{
+                "type": "CALL",
+                "from": "0x0000000000000000000000000000000000000001",
+                "to": "0x6b175474e89094c44da98b954eedeac495271d0f",
+                "gas": "0x10000",
+                "gasUsed": "0x5208",
+                "value": "0x0",
+                "input": "0xa9059cbb",
+                "method": "transfer",
+                "error": "execution reverted",
+                "errorReason": "Dai/insufficient-balance",
+                "subtraces": 0,
+                "traceAddress": []
+            }],
+            "assetChanges": [{
+                "assetInfo": {
+                    "standard": "ERC20",
+                    "type": "Fungible",
+                    "contractAddress": "0x6b175474e89094c44da98b954eedeac495271d0f",
+                    "symbol": "dai",
+                    "decimals": 18
+                },
+                "type": "Transfer",
+                "from": "0x0000000000000000000000000000000000000001",
+                "to": "0x0000000000000000000000000000000000000002",
+                "rawAmount": "0x3e8",
+                "amount": "0.000000000000001"
+            }],
+            "balanceChanges": null,
+            "stateChanges": [{
+                "address": "0x0000000000000000000000000000000000000001",
+                "nonce": { "previousValue": "0x1", "newValue": "0x2" }
+            }]
+        }"#;
+        let result: SimulationResult = serde_json::from_str(s).unwrap();
+        assert!(!result.status);
+        assert_eq!(result.gas_used, 21_000);
+        assert_eq!(result.transaction_type, Some(2));
+        assert_eq!(result.logs[0].inputs[1].value, serde_json::json!("1000"));
+        assert_eq!(result.error(), Some("Dai/insufficient-balance"));
+
+        let change = &result.asset_changes[0];
+        assert_eq!(change.asset_info.standard, AssetStandard::Erc20);
+        assert_eq!(change.kind, AssetChangeType::Transfer);
+        assert_eq!(change.raw_amount, U256::from(1000));
+        assert!(result.balance_changes.is_empty());
+        assert!(result.state_changes[0].nonce.unwrap().is_changed());
+        assert_eq!(
+            result.state_changes[0].address,
+            address!("0000000000000000000000000000000000000001")
+        );
+
+        let roundtrip: SimulationResult =
+            serde_json::from_value(serde_json::to_value(&result).unwrap()).unwrap();
+        assert_eq!(roundtrip, result);
+    }
+}
@@ -0,0 +1,50 @@
+//! State changes of simulated transactions.
+
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+
+/// The value of a field before and after a transaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueChange<T> {
+    /// The value before the transaction.
+    pub previous_value: T,
+    /// The value after the transaction.
+    pub new_value: T,
+}
+
+impl<T: PartialEq> ValueChange<T> {
+    /// Returns `true` if the value changed.
+    pub fn is_changed(&self) -> bool {
+        self.previous_value != self.new_value
+    }
+}
+
+/// The change of a storage slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageSlotChange {
+    /// The storage slot.
+    pub slot: B256,
+    /// The value before the transaction.
+    pub previous_value: B256,
+    /// The value after the transaction.
+    pub new_value: B256,
+}
+
+/// The changes of the state of an account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateChange {
+    /// The account.
+    pub address: Address,
+    /// The change of the nonce, if it changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<ValueChange<U256>>,
+    /// The change of the balance, if it changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<ValueChange<U256>>,
+    /// The changed storage slots.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageSlotChange>,
+}
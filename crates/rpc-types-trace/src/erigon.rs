@@ -0,0 +1,56 @@
+//! Erigon specific types for RPC requests.
+//!
+//! <https://github.com/erigontech/erigon/blob/main/docs/readthedocs/source/rpc/index.rst>
+
+use serde::{Deserialize, Serialize};
+
+/// Options for the erigon `getLatestLogs` RPC method.
+///
+/// The node walks blocks backwards from the latest block, collecting matching logs until either
+/// limit is reached.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatestLogsOptions {
+    /// The maximum number of logs to return.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_count: Option<u64>,
+    /// The maximum number of blocks to search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_count: Option<u64>,
+    /// Whether to match topics regardless of their position.
+    #[serde(default)]
+    pub ignore_topics_order: bool,
+}
+
+impl LatestLogsOptions {
+    /// Limits the number of returned logs.
+    pub const fn with_log_count(mut self, log_count: u64) -> Self {
+        self.log_count = Some(log_count);
+        self
+    }
+
+    /// Limits the number of searched blocks.
+    pub const fn with_block_count(mut self, block_count: u64) -> Self {
+        self.block_count = Some(block_count);
+        self
+    }
+
+    /// Matches topics regardless of their position.
+    pub const fn with_ignore_topics_order(mut self, ignore_topics_order: bool) -> Self {
+        self.ignore_topics_order = ignore_topics_order;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_latest_logs_options() {
+        let options = LatestLogsOptions::default().with_log_count(10);
+        let s = serde_json::to_string(&options).unwrap();
+        assert_eq!(s, r#"{"logCount":10,"ignoreTopicsOrder":false}"#);
+        assert_eq!(serde_json::from_str::<LatestLogsOptions>(&s).unwrap(), options);
+    }
+}
@@ -8,22 +8,44 @@ use serde::{Deserialize, Serialize};
 ///
 /// <https://github.com/ethereum/go-ethereum/blob/91cb6f863a965481e51d5d9c0e5ccd54796fd967/eth/tracers/native/call.go#L44>
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CallFrame {
     /// The address of that initiated the call.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::address")
+    )]
     pub from: Address,
     /// How much gas was left before the call.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::quantity")
+    )]
     pub gas: U256,
     /// How much gas was used by the call.
     #[serde(default, rename = "gasUsed")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::quantity")
+    )]
     pub gas_used: U256,
     /// The address of the contract that was called.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::address_opt")
+    )]
     pub to: Option<Address>,
     /// Calldata input.
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "alloy_rpc_types_eth::schema::bytes"))]
     pub input: Bytes,
     /// Output of the call, if any.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::bytes_opt")
+    )]
     pub output: Option<Bytes>,
     /// Error message, if any.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -39,6 +61,10 @@ pub struct CallFrame {
     pub logs: Vec<CallLogFrame>,
     /// Value transferred.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::quantity_opt")
+    )]
     pub value: Option<U256>,
     /// The type of the call.
     #[serde(rename = "type")]
@@ -47,18 +73,35 @@ pub struct CallFrame {
 
 /// Represents a recorded log that is emitted during a trace call.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CallLogFrame {
     /// The address of the contract that was called.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::address_opt")
+    )]
     pub address: Option<Address>,
     /// The topics of the log.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::hash_vec_opt")
+    )]
     pub topics: Option<Vec<B256>>,
     /// The data of the log.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::bytes_opt")
+    )]
     pub data: Option<Bytes>,
     /// The position of the log relative to subcalls within the same trace.
     #[serde(default, with = "alloy_serde::quantity::opt", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "alloy_rpc_types_eth::schema::quantity_opt")
+    )]
     pub position: Option<u64>,
 }
 
@@ -157,4 +200,17 @@ mod tests {
         let _trace: CallFrame = serde_json::from_str(ONLY_TOP_CALL).unwrap();
         let _trace: CallFrame = serde_json::from_str(WITH_LOG).unwrap();
     }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn call_frame_typescript() {
+        let ts = alloy_rpc_types_eth::typescript::TypeScriptGenerator::new()
+            .add::<CallFrame>()
+            .generate();
+        assert!(ts.contains("export interface CallFrame {\n"));
+        assert!(ts.contains("  calls?: Array<CallFrame>;\n"));
+        assert!(ts.contains("  logs?: Array<CallLogFrame>;\n"));
+        assert!(ts.contains("  type: string;\n"));
+        assert!(ts.contains("  topics?: Array<`0x${string}`> | null;\n"));
+    }
 }
@@ -5,7 +5,10 @@ use crate::geth::{
     mux::{MuxConfig, MuxFrame},
 };
 use alloy_primitives::{Bytes, B256, U256};
-use alloy_rpc_types_eth::{state::StateOverride, BlockOverrides};
+use alloy_rpc_types_eth::{
+    state::{EvmOverrides, StateOverride},
+    BlockOverrides,
+};
 use serde::{de::DeserializeOwned, ser::SerializeMap, Deserialize, Serialize, Serializer};
 use std::{collections::BTreeMap, time::Duration};
 // re-exports
@@ -685,6 +688,13 @@ impl GethDebugTracingCallOptions {
         self
     }
 
+    /// Sets the state and block overrides from the given [`EvmOverrides`].
+    pub fn with_evm_overrides(mut self, overrides: EvmOverrides) -> Self {
+        self.state_overrides = overrides.state;
+        self.block_overrides = overrides.block.map(|block| *block);
+        self
+    }
+
     /// Sets the tracing options
     pub fn with_tracing_options(mut self, options: GethDebugTracingOptions) -> Self {
         self.tracing_options = options;
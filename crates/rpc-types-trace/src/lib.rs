@@ -16,9 +16,12 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 pub mod common;
+pub mod erigon;
 pub mod filter;
 pub mod geth;
 pub mod opcode;
 pub mod otterscan;
 pub mod parity;
+pub mod profile;
 pub mod tracerequest;
+pub mod tree;
@@ -0,0 +1,241 @@
+//! Gas profiling of transactions based on their [`CallTree`].
+
+use crate::tree::{CallKind, CallNode, CallTree};
+use alloy_primitives::{map::HashMap, Address, Selector};
+use std::fmt::Write;
+
+/// The gas used by a single call frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameGas {
+    /// The index of the frame in the [`CallTree`].
+    pub idx: usize,
+    /// The index of the parent frame, `None` for the root frame.
+    pub parent: Option<usize>,
+    /// The kind of the call.
+    pub kind: CallKind,
+    /// The contract executing the frame, `None` if unknown, e.g. for a failed creation.
+    pub address: Option<Address>,
+    /// The function selector the frame was called with, if any.
+    pub selector: Option<Selector>,
+    /// The gas used by the frame, including its children.
+    pub gas_used: u64,
+    /// The gas used by the frame itself, excluding its children.
+    pub self_gas_used: u64,
+}
+
+impl FrameGas {
+    fn new(tree: &CallTree, node: &CallNode) -> Self {
+        let selector = (!node.kind.is_create() && node.input.len() >= 4)
+            .then(|| Selector::from_slice(&node.input[..4]));
+        Self {
+            idx: node.idx,
+            parent: node.parent,
+            kind: node.kind,
+            address: node.to,
+            selector,
+            gas_used: node.gas_used,
+            self_gas_used: tree.self_gas_used(node.idx),
+        }
+    }
+
+    /// Returns the label of the frame in the folded stack output of [`GasProfile::folded`].
+    ///
+    /// The label is the address of the contract, followed by the selector for calls with calldata
+    /// and prefixed with the kind for creations and selfdestructs.
+    pub fn label(&self) -> String {
+        let address = self.address.map_or_else(|| "unknown".to_string(), |a| a.to_string());
+        match (self.kind, self.selector) {
+            (kind, _) if kind.is_create() => {
+                format!("{}:{address}", format!("{kind:?}").to_uppercase())
+            }
+            (CallKind::Selfdestruct, _) => format!("SELFDESTRUCT:{address}"),
+            (_, Some(selector)) => format!("{address}:{selector}"),
+            (_, None) => address,
+        }
+    }
+}
+
+/// The gas used by a contract across all frames executing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContractGas {
+    /// The address of the contract.
+    pub address: Address,
+    /// The number of frames executing the contract.
+    pub calls: usize,
+    /// The gas used by all frames executing the contract, excluding their children.
+    pub self_gas_used: u64,
+}
+
+/// The gas used by a function of a contract across all frames calling it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SelectorGas {
+    /// The address of the contract.
+    pub address: Address,
+    /// The function selector.
+    pub selector: Selector,
+    /// The number of frames calling the function.
+    pub calls: usize,
+    /// The gas used by all frames calling the function, excluding their children.
+    pub self_gas_used: u64,
+}
+
+/// A report of where gas is spent in a transaction.
+///
+/// Gas is attributed to the contract executing each frame. Aggregates per contract and selector
+/// only count the gas a frame used itself, so that nested and recursive calls are not counted
+/// twice and the aggregates add up to the gas used by the root frame.
+///
+/// Note that the gas used by the root frame does not include the intrinsic gas of the
+/// transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasProfile {
+    /// The gas used by the root frame.
+    pub gas_used: u64,
+    /// All frames in pre-order.
+    pub frames: Vec<FrameGas>,
+    /// The gas used per contract, sorted by descending gas.
+    pub contracts: Vec<ContractGas>,
+    /// The gas used per contract function, sorted by descending gas.
+    pub selectors: Vec<SelectorGas>,
+}
+
+impl GasProfile {
+    /// Profiles the calls of the given tree.
+    pub fn new(tree: &CallTree) -> Self {
+        let frames = tree.iter().map(|node| FrameGas::new(tree, node)).collect::<Vec<_>>();
+
+        let mut contracts = HashMap::<Address, ContractGas>::default();
+        let mut selectors = HashMap::<(Address, Selector), SelectorGas>::default();
+        for frame in &frames {
+            let Some(address) = frame.address else { continue };
+            let contract = contracts.entry(address).or_insert(ContractGas {
+                address,
+                calls: 0,
+                self_gas_used: 0,
+            });
+            contract.calls += 1;
+            contract.self_gas_used += frame.self_gas_used;
+
+            if let Some(selector) = frame.selector {
+                let function = selectors.entry((address, selector)).or_insert(SelectorGas {
+                    address,
+                    selector,
+                    calls: 0,
+                    self_gas_used: 0,
+                });
+                function.calls += 1;
+                function.self_gas_used += frame.self_gas_used;
+            }
+        }
+
+        let mut contracts = contracts.into_values().collect::<Vec<_>>();
+        contracts
+            .sort_by(|a, b| b.self_gas_used.cmp(&a.self_gas_used).then(a.address.cmp(&b.address)));
+        let mut selectors = selectors.into_values().collect::<Vec<_>>();
+        selectors.sort_by(|a, b| {
+            b.self_gas_used
+                .cmp(&a.self_gas_used)
+                .then(a.address.cmp(&b.address))
+                .then(a.selector.cmp(&b.selector))
+        });
+
+        Self { gas_used: tree.root().gas_used, frames, contracts, selectors }
+    }
+
+    /// Returns the profile in the folded stack format, one line per frame, which can be rendered
+    /// by flamegraph tools such as `inferno` or `flamegraph.pl`.
+    ///
+    /// Each line contains the labels of the frame and its ancestors, see [`FrameGas::label`],
+    /// followed by the gas used by the frame itself. Frames that used no gas themselves are
+    /// omitted.
+    pub fn folded(&self) -> String {
+        let mut stacks = Vec::<String>::with_capacity(self.frames.len());
+        let mut out = String::new();
+        for frame in &self.frames {
+            let label = frame.label();
+            let stack = match frame.parent {
+                Some(parent) => format!("{};{label}", stacks[parent]),
+                None => label,
+            };
+            if frame.self_gas_used > 0 {
+                let _ = writeln!(out, "{stack} {}", frame.self_gas_used);
+            }
+            stacks.push(stack);
+        }
+        out
+    }
+}
+
+impl From<&CallTree> for GasProfile {
+    fn from(tree: &CallTree) -> Self {
+        Self::new(tree)
+    }
+}
+
+impl CallTree {
+    /// Returns the [`GasProfile`] of the calls in the tree.
+    pub fn gas_profile(&self) -> GasProfile {
+        GasProfile::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geth::CallFrame;
+    use alloy_primitives::{address, hex, U256};
+
+    fn frame(to: Address, input: &[u8], gas_used: u64, calls: Vec<CallFrame>) -> CallFrame {
+        CallFrame {
+            to: Some(to),
+            input: input.to_vec().into(),
+            gas_used: U256::from(gas_used),
+            calls,
+            typ: "CALL".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn profile() {
+        let router = address!("0000000000000000000000000000000000000001");
+        let token = address!("0000000000000000000000000000000000000002");
+        let transfer = hex!("a9059cbb");
+        let tree = CallTree::from(frame(
+            router,
+            &hex!("12345678"),
+            50_000,
+            vec![
+                frame(token, &transfer, 10_000, vec![]),
+                frame(token, &transfer, 12_000, vec![]),
+                frame(router, &[], 3_000, vec![]),
+            ],
+        ));
+        let profile = tree.gas_profile();
+
+        assert_eq!(profile.gas_used, 50_000);
+        assert_eq!(profile.frames[0].self_gas_used, 25_000);
+        assert_eq!(profile.frames[0].selector, Some(Selector::from(hex!("12345678"))));
+        assert_eq!(profile.frames[3].selector, None);
+        assert_eq!(
+            profile.contracts,
+            vec![
+                ContractGas { address: router, calls: 2, self_gas_used: 28_000 },
+                ContractGas { address: token, calls: 2, self_gas_used: 22_000 },
+            ]
+        );
+        assert_eq!(
+            profile.contracts.iter().map(|c| c.self_gas_used).sum::<u64>(),
+            profile.gas_used
+        );
+        assert_eq!(profile.selectors[1].selector, Selector::from(transfer));
+        assert_eq!(profile.selectors[1].calls, 2);
+        assert_eq!(profile.selectors[1].self_gas_used, 22_000);
+
+        let folded = profile.folded();
+        let mut lines = folded.lines();
+        assert_eq!(lines.next(), Some(&*format!("{router}:0x12345678 25000")));
+        assert_eq!(lines.next(), Some(&*format!("{router}:0x12345678;{token}:0xa9059cbb 10000")));
+        assert_eq!(lines.nth(1), Some(&*format!("{router}:0x12345678;{router} 3000")));
+    }
+}
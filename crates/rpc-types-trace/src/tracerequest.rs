@@ -3,7 +3,9 @@
 use crate::parity::TraceType;
 use alloy_primitives::map::HashSet;
 use alloy_rpc_types_eth::{
-    request::TransactionRequest, state::StateOverride, BlockId, BlockOverrides,
+    request::TransactionRequest,
+    state::{EvmOverrides, StateOverride},
+    BlockId, BlockOverrides,
 };
 use serde::{Deserialize, Serialize};
 
@@ -55,6 +57,13 @@ impl TraceCallRequest {
         self
     }
 
+    /// Sets the state and block overrides from the given [`EvmOverrides`].
+    pub fn with_evm_overrides(mut self, overrides: EvmOverrides) -> Self {
+        self.state_overrides = overrides.state;
+        self.block_overrides = overrides.block;
+        self
+    }
+
     /// Inserts a single trace type.
     pub fn with_trace_type(mut self, trace_type: TraceType) -> Self {
         self.trace_types.insert(trace_type);
@@ -0,0 +1,554 @@
+//! Client-agnostic call trees built from parity `trace_` results or geth `callTracer` frames.
+
+use crate::{
+    geth::CallFrame,
+    parity::{
+        Action, CallType, CreationMethod, LocalizedTransactionTrace, TraceOutput, TransactionTrace,
+    },
+};
+use alloy_primitives::{map::HashMap, Address, Bytes, U256};
+use std::collections::BTreeMap;
+
+/// Error returned when flat parity traces do not form a call tree.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CallTreeError {
+    /// No trace has an empty trace address.
+    #[error("missing root trace")]
+    MissingRoot,
+    /// More than one trace has the same trace address.
+    #[error("duplicate trace address {0:?}")]
+    Duplicate(Vec<usize>),
+    /// The parent of a trace is missing.
+    #[error("missing parent of trace address {0:?}")]
+    MissingParent(Vec<usize>),
+    /// A trace is nested deeper than [`MAX_CALL_DEPTH`].
+    #[error("trace address of length {0} exceeds the maximum call depth")]
+    DepthLimitExceeded(usize),
+}
+
+/// The maximum depth of a call tree, as limited by the EVM call depth.
+pub const MAX_CALL_DEPTH: usize = 1024;
+
+/// The kind of a [`CallNode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CallKind {
+    /// A `CALL`.
+    Call,
+    /// A `STATICCALL`.
+    StaticCall,
+    /// A `DELEGATECALL`.
+    DelegateCall,
+    /// A `CALLCODE`.
+    CallCode,
+    /// An `AUTHCALL`.
+    AuthCall,
+    /// A `CREATE`.
+    Create,
+    /// A `CREATE2`.
+    Create2,
+    /// An `EOFCREATE`.
+    EofCreate,
+    /// A `SELFDESTRUCT`.
+    Selfdestruct,
+    /// A block reward.
+    Reward,
+}
+
+impl CallKind {
+    /// Parses the `type` of a geth [`CallFrame`], returning `None` for unknown types.
+    pub fn from_geth_type(typ: &str) -> Option<Self> {
+        Some(match typ.to_ascii_uppercase().as_str() {
+            "CALL" => Self::Call,
+            "STATICCALL" => Self::StaticCall,
+            "DELEGATECALL" => Self::DelegateCall,
+            "CALLCODE" => Self::CallCode,
+            "AUTHCALL" => Self::AuthCall,
+            "CREATE" => Self::Create,
+            "CREATE2" => Self::Create2,
+            "EOFCREATE" => Self::EofCreate,
+            "SELFDESTRUCT" => Self::Selfdestruct,
+            _ => return None,
+        })
+    }
+
+    /// Returns `true` if this is a contract creation.
+    pub const fn is_create(&self) -> bool {
+        matches!(self, Self::Create | Self::Create2 | Self::EofCreate)
+    }
+}
+
+impl From<CallType> for CallKind {
+    fn from(call_type: CallType) -> Self {
+        match call_type {
+            CallType::None | CallType::Call => Self::Call,
+            CallType::CallCode => Self::CallCode,
+            CallType::DelegateCall => Self::DelegateCall,
+            CallType::StaticCall => Self::StaticCall,
+            CallType::AuthCall => Self::AuthCall,
+        }
+    }
+}
+
+impl From<CreationMethod> for CallKind {
+    fn from(method: CreationMethod) -> Self {
+        match method {
+            CreationMethod::None | CreationMethod::Create => Self::Create,
+            CreationMethod::Create2 => Self::Create2,
+            CreationMethod::EofCreate => Self::EofCreate,
+        }
+    }
+}
+
+/// A single call in a [`CallTree`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallNode {
+    /// The index of this node in the tree.
+    pub idx: usize,
+    /// The index of the parent node, `None` for the root.
+    pub parent: Option<usize>,
+    /// The indices of the child nodes, in execution order.
+    pub children: Vec<usize>,
+    /// The location of the call in the tree, see [`TransactionTrace::trace_address`].
+    pub trace_address: Vec<usize>,
+    /// The kind of the call.
+    pub kind: CallKind,
+    /// The caller, the destroyed contract of a selfdestruct or the author of a reward.
+    pub from: Address,
+    /// The callee, the created contract or the beneficiary of a selfdestruct.
+    pub to: Option<Address>,
+    /// The value transferred by the call.
+    pub value: U256,
+    /// The gas available to the call.
+    pub gas: u64,
+    /// The gas used by the call, including its children.
+    pub gas_used: u64,
+    /// The calldata or init code.
+    pub input: Bytes,
+    /// The returned data or the deployed code.
+    pub output: Bytes,
+    /// The error message if the call failed.
+    pub error: Option<String>,
+}
+
+impl CallNode {
+    /// Returns `true` if this is the root call.
+    pub const fn is_root(&self) -> bool {
+        self.parent.is_none()
+    }
+
+    /// Returns the depth of the call, `0` for the root call.
+    pub fn depth(&self) -> usize {
+        self.trace_address.len()
+    }
+
+    /// Returns `true` if the call failed.
+    pub const fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    fn from_parity(idx: usize, parent: Option<usize>, trace: TransactionTrace) -> Self {
+        let TransactionTrace { action, error, result, trace_address, .. } = trace;
+        let (gas_used, output, created) = match result {
+            Some(TraceOutput::Call(out)) => (out.gas_used, out.output, None),
+            Some(TraceOutput::Create(out)) => (out.gas_used, out.code, Some(out.address)),
+            None => (0, Bytes::new(), None),
+        };
+        let (kind, from, to, value, gas, input) = match action {
+            Action::Call(call) => {
+                (call.call_type.into(), call.from, Some(call.to), call.value, call.gas, call.input)
+            }
+            Action::Create(create) => (
+                create.creation_method.into(),
+                create.from,
+                created,
+                create.value,
+                create.gas,
+                create.init,
+            ),
+            Action::Selfdestruct(action) => (
+                CallKind::Selfdestruct,
+                action.address,
+                Some(action.refund_address),
+                action.balance,
+                0,
+                Bytes::new(),
+            ),
+            Action::Reward(reward) => {
+                (CallKind::Reward, reward.author, None, reward.value, 0, Bytes::new())
+            }
+        };
+        Self {
+            idx,
+            parent,
+            children: Vec::new(),
+            trace_address,
+            kind,
+            from,
+            to,
+            value,
+            gas,
+            gas_used,
+            input,
+            output,
+            error,
+        }
+    }
+}
+
+/// The calls of a transaction as a tree.
+///
+/// The nodes are stored in pre-order, the root being the first node. Trees can be built from the
+/// flat traces returned by parity style `trace_` endpoints with [`CallTree::from_parity_traces`],
+/// or from the nested frames of geth's `callTracer` with [`CallTree::from_call_frame`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallTree {
+    nodes: Vec<CallNode>,
+}
+
+impl CallTree {
+    /// Builds the tree of a single transaction from its flat parity traces, in any order.
+    pub fn from_parity_traces(
+        traces: impl IntoIterator<Item = TransactionTrace>,
+    ) -> Result<Self, CallTreeError> {
+        let mut traces = traces.into_iter().collect::<Vec<_>>();
+        // lexicographic order of trace addresses is the pre-order of the tree
+        traces.sort_by(|a, b| a.trace_address.cmp(&b.trace_address));
+
+        let mut nodes = Vec::<CallNode>::with_capacity(traces.len());
+        let mut indices = HashMap::<Vec<usize>, usize>::default();
+        for trace in traces {
+            if trace.trace_address.len() > MAX_CALL_DEPTH {
+                return Err(CallTreeError::DepthLimitExceeded(trace.trace_address.len()));
+            }
+            let idx = nodes.len();
+            let parent = match trace.trace_address.split_last() {
+                None => None,
+                Some(_) if idx == 0 => return Err(CallTreeError::MissingRoot),
+                Some((_, parent)) => match indices.get(parent) {
+                    Some(&parent) => Some(parent),
+                    None => return Err(CallTreeError::MissingParent(trace.trace_address)),
+                },
+            };
+            if indices.insert(trace.trace_address.clone(), idx).is_some() {
+                return Err(CallTreeError::Duplicate(trace.trace_address));
+            }
+            if let Some(parent) = parent {
+                nodes[parent].children.push(idx);
+            }
+            nodes.push(CallNode::from_parity(idx, parent, trace));
+        }
+
+        if nodes.is_empty() {
+            return Err(CallTreeError::MissingRoot);
+        }
+        Ok(Self { nodes })
+    }
+
+    /// Builds the trees of all transactions of a block from the flat traces returned by
+    /// `trace_block` or `trace_filter`.
+    ///
+    /// Returns the trees ordered by transaction position. Traces without a transaction position,
+    /// such as block rewards, are skipped.
+    pub fn from_block_traces(
+        traces: impl IntoIterator<Item = LocalizedTransactionTrace>,
+    ) -> Result<Vec<(u64, Self)>, CallTreeError> {
+        let mut transactions = BTreeMap::<u64, Vec<TransactionTrace>>::new();
+        for trace in traces {
+            if let Some(position) = trace.transaction_position {
+                transactions.entry(position).or_default().push(trace.trace);
+            }
+        }
+        transactions
+            .into_iter()
+            .map(|(position, traces)| Ok((position, Self::from_parity_traces(traces)?)))
+            .collect()
+    }
+
+    /// Builds the tree from the root frame returned by geth's `callTracer`.
+    ///
+    /// Frames with an unknown type are treated as calls. The frames are traversed iteratively, so
+    /// arbitrarily deep frames from untrusted endpoints cannot overflow the stack.
+    pub fn from_call_frame(frame: CallFrame) -> Self {
+        let mut nodes = Vec::<CallNode>::new();
+        let mut stack: Vec<(CallFrame, Option<usize>, Vec<usize>)> =
+            vec![(frame, None, Vec::new())];
+        while let Some((frame, parent, trace_address)) = stack.pop() {
+            let CallFrame {
+                from, gas, gas_used, to, input, output, error, calls, value, typ, ..
+            } = frame;
+            let idx = nodes.len();
+            if let Some(parent) = parent {
+                nodes[parent].children.push(idx);
+            }
+            // push in reverse to pop the children in execution order
+            for (i, call) in calls.into_iter().enumerate().rev() {
+                let mut child_address = trace_address.clone();
+                child_address.push(i);
+                stack.push((call, Some(idx), child_address));
+            }
+            nodes.push(CallNode {
+                idx,
+                parent,
+                children: Vec::new(),
+                trace_address,
+                kind: CallKind::from_geth_type(&typ).unwrap_or(CallKind::Call),
+                from,
+                to,
+                value: value.unwrap_or_default(),
+                gas: gas.saturating_to(),
+                gas_used: gas_used.saturating_to(),
+                input,
+                output: output.unwrap_or_default(),
+                error,
+            });
+        }
+        Self { nodes }
+    }
+
+    /// Returns the root call.
+    pub fn root(&self) -> &CallNode {
+        &self.nodes[0]
+    }
+
+    /// Returns the node at the given index.
+    pub fn get(&self, idx: usize) -> Option<&CallNode> {
+        self.nodes.get(idx)
+    }
+
+    /// Returns the node with the given trace address.
+    pub fn get_by_trace_address(&self, trace_address: &[usize]) -> Option<&CallNode> {
+        trace_address.iter().try_fold(self.root(), |node, &i| self.get(*node.children.get(i)?))
+    }
+
+    /// Returns all nodes in pre-order.
+    pub fn nodes(&self) -> &[CallNode] {
+        &self.nodes
+    }
+
+    /// Returns the number of calls in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the tree has no calls. Trees always contain at least the root call.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns an iterator over all nodes in pre-order.
+    pub fn iter(&self) -> std::slice::Iter<'_, CallNode> {
+        self.nodes.iter()
+    }
+
+    /// Returns an iterator over the children of the node.
+    pub fn children(&self, idx: usize) -> impl Iterator<Item = &CallNode> + '_ {
+        self.nodes[idx].children.iter().map(|&child| &self.nodes[child])
+    }
+
+    /// Returns an iterator over the ancestors of the node, starting with its parent.
+    pub fn ancestors(&self, idx: usize) -> impl Iterator<Item = &CallNode> + '_ {
+        std::iter::successors(self.nodes[idx].parent, |&idx| self.nodes[idx].parent)
+            .map(|idx| &self.nodes[idx])
+    }
+
+    /// Returns an iterator over the node and all its descendants in pre-order.
+    pub fn subtree(&self, idx: usize) -> impl Iterator<Item = &CallNode> + '_ {
+        // nodes are stored in pre-order, so a subtree is a contiguous range
+        let depth = self.nodes[idx].depth();
+        let end = self.nodes[idx + 1..]
+            .iter()
+            .position(|node| node.depth() <= depth)
+            .map_or(self.nodes.len(), |offset| idx + 1 + offset);
+        self.nodes[idx..end].iter()
+    }
+
+    /// Returns the total value transferred by the node and all its descendants.
+    pub fn subtree_value(&self, idx: usize) -> U256 {
+        self.subtree(idx).fold(U256::ZERO, |acc, node| acc.saturating_add(node.value))
+    }
+
+    /// Returns the gas used by the node itself, excluding the gas used by its children.
+    pub fn self_gas_used(&self, idx: usize) -> u64 {
+        let children = self.children(idx).map(|child| child.gas_used).sum::<u64>();
+        self.nodes[idx].gas_used.saturating_sub(children)
+    }
+
+    /// Returns the nodes that failed.
+    pub fn errors(&self) -> impl Iterator<Item = &CallNode> + '_ {
+        self.iter().filter(|node| node.is_error())
+    }
+}
+
+impl From<CallFrame> for CallTree {
+    fn from(frame: CallFrame) -> Self {
+        Self::from_call_frame(frame)
+    }
+}
+
+impl<'a> IntoIterator for &'a CallTree {
+    type Item = &'a CallNode;
+    type IntoIter = std::slice::Iter<'a, CallNode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parity::{CallAction, CallOutput};
+    use alloy_primitives::address;
+
+    fn call(trace_address: Vec<usize>, value: u64, gas_used: u64) -> TransactionTrace {
+        TransactionTrace {
+            action: Action::Call(CallAction {
+                from: Address::with_last_byte(trace_address.len() as u8),
+                call_type: CallType::Call,
+                gas: 100_000,
+                input: Bytes::new(),
+                to: Address::with_last_byte(trace_address.len() as u8 + 1),
+                value: U256::from(value),
+            }),
+            error: None,
+            result: Some(TraceOutput::Call(CallOutput { gas_used, output: Bytes::new() })),
+            subtraces: 0,
+            trace_address,
+        }
+    }
+
+    #[test]
+    fn parity_tree() {
+        let traces = vec![
+            call(vec![1], 3, 1_000),
+            call(vec![], 1, 10_000),
+            call(vec![0, 0], 4, 500),
+            call(vec![0], 2, 2_000),
+        ];
+        let tree = CallTree::from_parity_traces(traces).unwrap();
+
+        assert_eq!(tree.len(), 4);
+        assert!(tree.root().is_root());
+        assert_eq!(
+            tree.iter().map(|node| node.trace_address.clone()).collect::<Vec<_>>(),
+            vec![vec![], vec![0], vec![0, 0], vec![1]]
+        );
+        assert_eq!(tree.root().children, vec![1, 3]);
+        assert_eq!(tree.get_by_trace_address(&[0, 0]).unwrap().idx, 2);
+        assert!(tree.get_by_trace_address(&[2]).is_none());
+        assert_eq!(tree.ancestors(2).map(|node| node.idx).collect::<Vec<_>>(), vec![1, 0]);
+        assert_eq!(tree.subtree(1).count(), 2);
+        assert_eq!(tree.subtree_value(0), U256::from(10));
+        assert_eq!(tree.subtree_value(1), U256::from(6));
+        assert_eq!(tree.self_gas_used(0), 7_000);
+        assert_eq!(tree.self_gas_used(1), 1_500);
+    }
+
+    #[test]
+    fn parity_tree_errors() {
+        assert_eq!(CallTree::from_parity_traces(vec![]), Err(CallTreeError::MissingRoot));
+        assert_eq!(
+            CallTree::from_parity_traces(vec![call(vec![0], 0, 0)]),
+            Err(CallTreeError::MissingRoot)
+        );
+        assert_eq!(
+            CallTree::from_parity_traces(vec![call(vec![], 0, 0), call(vec![1, 0], 0, 0)]),
+            Err(CallTreeError::MissingParent(vec![1, 0]))
+        );
+        assert_eq!(
+            CallTree::from_parity_traces(vec![call(vec![], 0, 0), call(vec![], 0, 0)]),
+            Err(CallTreeError::Duplicate(vec![]))
+        );
+    }
+
+    #[test]
+    fn parity_tree_depth_limit() {
+        let traces = vec![call(vec![], 0, 0), call(vec![0; MAX_CALL_DEPTH + 1], 0, 0)];
+        assert_eq!(
+            CallTree::from_parity_traces(traces),
+            Err(CallTreeError::DepthLimitExceeded(MAX_CALL_DEPTH + 1))
+        );
+    }
+
+    #[test]
+    fn deep_geth_tree() {
+        let mut frame = CallFrame::default();
+        for _ in 0..3_000 {
+            frame = CallFrame { calls: vec![frame], ..Default::default() };
+        }
+        let tree = CallTree::from_call_frame(frame);
+        assert_eq!(tree.len(), 3_001);
+        assert_eq!(tree.get(3_000).unwrap().depth(), 3_000);
+    }
+
+    #[test]
+    fn block_trees() {
+        let localized = |position: Option<u64>, trace| LocalizedTransactionTrace {
+            trace,
+            block_hash: None,
+            block_number: None,
+            transaction_hash: None,
+            transaction_position: position,
+        };
+        let traces = vec![
+            localized(Some(1), call(vec![], 0, 0)),
+            localized(Some(0), call(vec![], 0, 0)),
+            localized(Some(0), call(vec![0], 0, 0)),
+            localized(None, call(vec![], 0, 0)),
+        ];
+        let trees = CallTree::from_block_traces(traces).unwrap();
+        assert_eq!(trees.len(), 2);
+        assert_eq!((trees[0].0, trees[0].1.len()), (0, 2));
+        assert_eq!((trees[1].0, trees[1].1.len()), (1, 1));
+    }
+
+    #[test]
+    fn geth_tree() {
+        let frame: CallFrame = serde_json::from_str(
+            r#"{
+                "from": "0x0000000000000000000000000000000000000001",
+                "gas": "0x2710",
+                "gasUsed": "0x1388",
+                "to": "0x0000000000000000000000000000000000000002",
+                "input": "0x",
+                "value": "0x1",
+                "type": "CALL",
+                "calls": [
+                    {
+                        "from": "0x0000000000000000000000000000000000000002",
+                        "gas": "0x3e8",
+                        "gasUsed": "0x64",
+                        "to": "0x0000000000000000000000000000000000000003",
+                        "input": "0x",
+                        "type": "STATICCALL"
+                    },
+                    {
+                        "from": "0x0000000000000000000000000000000000000002",
+                        "gas": "0x3e8",
+                        "gasUsed": "0xc8",
+                        "to": "0x0000000000000000000000000000000000000004",
+                        "input": "0x",
+                        "value": "0x2",
+                        "error": "execution reverted",
+                        "type": "CREATE2"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let tree = CallTree::from(frame);
+
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.root().kind, CallKind::Call);
+        assert_eq!(tree.get(1).unwrap().kind, CallKind::StaticCall);
+        assert_eq!(tree.get(2).unwrap().trace_address, vec![1]);
+        assert!(tree.get(2).unwrap().kind.is_create());
+        assert_eq!(
+            tree.get(2).unwrap().to,
+            Some(address!("0000000000000000000000000000000000000004"))
+        );
+        assert_eq!(tree.subtree_value(0), U256::from(3));
+        assert_eq!(tree.self_gas_used(0), 4_700);
+        assert_eq!(tree.errors().map(|node| node.idx).collect::<Vec<_>>(), vec![2]);
+    }
+}
@@ -34,6 +34,9 @@ pub use eth::*;
 #[cfg(feature = "mev")]
 pub use alloy_rpc_types_mev as mev;
 
+#[cfg(feature = "tenderly")]
+pub use alloy_rpc_types_tenderly as tenderly;
+
 #[cfg(feature = "trace")]
 pub use alloy_rpc_types_trace as trace;
 
@@ -25,6 +25,8 @@ pub use self::optional::*;
 
 pub mod quantity;
 
+pub mod strict;
+
 /// Storage related helpers.
 pub mod storage;
 pub use storage::JsonStorageKey;
@@ -0,0 +1,190 @@
+//! Strict deserialization, rejecting unknown fields.
+//!
+//! RPC types are deserialized leniently by default: fields that are not part of the type are
+//! silently dropped, so that nodes can extend their responses without breaking clients. This
+//! module provides an opt-in strict mode, which is useful for conformance testing against nodes
+//! and for catching drift between the types and the node's responses early.
+//!
+//! Unlike `#[serde(deny_unknown_fields)]`, strict mode works with any type implementing both
+//! [`Deserialize`] and [`Serialize`], including types with flattened fields. The value is
+//! deserialized, serialized again, and every field of the input that is missing from the
+//! serialized value is reported as unknown. As a consequence:
+//! - fields with a `null` value are never reported, as optional fields are commonly skipped when
+//!   serializing `None`;
+//! - fields captured by [`OtherFields`](crate::OtherFields) are considered known;
+//! - fields deserialized from an alias of their serialized name are reported as unknown.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_serde::strict::{self, StrictError};
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct Account {
+//!     nonce: u64,
+//! }
+//!
+//! let account: Account = strict::from_str(r#"{"nonce":1}"#).unwrap();
+//! assert_eq!(account.nonce, 1);
+//!
+//! let err = strict::from_str::<Account>(r#"{"nonce":1,"balance":"0x0"}"#).unwrap_err();
+//! assert!(matches!(err, StrictError::UnknownFields(fields) if fields == ["/balance"]));
+//! ```
+
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
+use core::fmt;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// An error returned by strict deserialization.
+#[derive(Debug)]
+pub enum StrictError {
+    /// The value could not be deserialized or serialized.
+    Json(serde_json::Error),
+    /// The value contains fields that are not part of the type, as [JSON pointers].
+    ///
+    /// [JSON pointers]: https://datatracker.ietf.org/doc/html/rfc6901
+    UnknownFields(Vec<String>),
+}
+
+impl fmt::Display for StrictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(err) => err.fmt(f),
+            Self::UnknownFields(fields) => write!(f, "unknown fields: {}", fields.join(", ")),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrictError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(err) => Some(err),
+            Self::UnknownFields(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for StrictError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+/// Deserializes a `T` from a JSON string, rejecting unknown fields.
+///
+/// See the [module documentation](self) for details.
+pub fn from_str<T>(s: &str) -> Result<T, StrictError>
+where
+    T: DeserializeOwned + Serialize,
+{
+    from_value(serde_json::from_str(s)?)
+}
+
+/// Deserializes a `T` from a JSON value, rejecting unknown fields.
+///
+/// See the [module documentation](self) for details.
+pub fn from_value<T>(value: Value) -> Result<T, StrictError>
+where
+    T: DeserializeOwned + Serialize,
+{
+    let parsed = T::deserialize(&value)?;
+    let fields = unknown_fields(&value, &serde_json::to_value(&parsed)?);
+    if fields.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(StrictError::UnknownFields(fields))
+    }
+}
+
+/// Returns the [JSON pointers] of all non-null fields of `input` that are missing from
+/// `serialized`.
+///
+/// Objects are compared recursively, and arrays element-wise.
+///
+/// [JSON pointers]: https://datatracker.ietf.org/doc/html/rfc6901
+pub fn unknown_fields(input: &Value, serialized: &Value) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut stack = vec![(String::new(), input, serialized)];
+    while let Some((pointer, input, serialized)) = stack.pop() {
+        match (input, serialized) {
+            (Value::Object(input), Value::Object(serialized)) => {
+                for (key, value) in input {
+                    let pointer = format!("{pointer}/{}", escape(key));
+                    match serialized.get(key) {
+                        Some(serialized) => stack.push((pointer, value, serialized)),
+                        None if !value.is_null() => fields.push(pointer),
+                        None => {}
+                    }
+                }
+            }
+            (Value::Array(input), Value::Array(serialized)) => {
+                for (i, (input, serialized)) in input.iter().zip(serialized).enumerate() {
+                    stack.push((format!("{pointer}/{i}"), input, serialized));
+                }
+            }
+            _ => {}
+        }
+    }
+    fields.sort_unstable();
+    fields
+}
+
+fn escape(key: &str) -> String {
+    if key.contains(['~', '/']) {
+        key.replace('~', "~0").replace('/', "~1")
+    } else {
+        key.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithOtherFields;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Inner {
+        value: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        extra: Option<u64>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        inner: Inner,
+        items: Vec<Inner>,
+    }
+
+    #[test]
+    fn accepts_known_fields() {
+        let outer: Outer =
+            from_str(r#"{"inner":{"value":1,"extra":null},"items":[{"value":2,"extra":3}]}"#)
+                .unwrap();
+        assert_eq!(outer.items[0], Inner { value: 2, extra: Some(3) });
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        let err = from_str::<Outer>(
+            r#"{"inner":{"value":1,"a/b":0},"items":[{"value":2},{"value":3,"x":[]}],"y":{}}"#,
+        )
+        .unwrap_err();
+        let StrictError::UnknownFields(fields) = err else { unreachable!() };
+        assert_eq!(fields, ["/inner/a~1b", "/items/1/x", "/y"]);
+        assert!(StrictError::UnknownFields(fields).to_string().starts_with("unknown fields: "));
+
+        assert!(matches!(from_str::<Outer>(r#"{"inner":{}}"#), Err(StrictError::Json(_))));
+    }
+
+    #[test]
+    fn other_fields_are_known() {
+        let value = serde_json::json!({ "value": 1, "custom": "0x1" });
+        assert!(from_value::<Inner>(value.clone()).is_err());
+        let inner = from_value::<WithOtherFields<Inner>>(value).unwrap();
+        assert_eq!(inner.other["custom"], "0x1");
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{Http, HttpConnect};
 use alloy_json_rpc::{RequestPacket, ResponsePacket};
 use alloy_transport::{
-    utils::guess_local_url, BoxTransport, TransportConnect, TransportError, TransportErrorKind,
-    TransportFut, TransportResult,
+    utils::guess_local_url, BoxTransport, HttpError, TransportConnect, TransportError,
+    TransportErrorKind, TransportFut, TransportResult,
 };
 use http_body_util::{BodyExt, Full};
 use hyper::{
@@ -107,6 +107,11 @@ where
         let resp = service.call(req).await.map_err(TransportErrorKind::custom)?;
 
         let status = resp.status();
+        let retry_after = resp
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(HttpError::parse_retry_after);
 
         debug!(%status, "received response from server");
 
@@ -119,9 +124,10 @@ where
         trace!(body = %String::from_utf8_lossy(&body), "response body");
 
         if status != hyper::StatusCode::OK {
-            return Err(TransportErrorKind::http_error(
+            return Err(TransportErrorKind::http_error_with_retry_after(
                 status.as_u16(),
                 String::from_utf8_lossy(&body).into_owned(),
+                retry_after,
             ));
         }
 
@@ -1,8 +1,8 @@
 use crate::{Http, HttpConnect};
 use alloy_json_rpc::{RequestPacket, ResponsePacket};
 use alloy_transport::{
-    utils::guess_local_url, BoxTransport, TransportConnect, TransportError, TransportErrorKind,
-    TransportFut, TransportResult,
+    utils::guess_local_url, BoxTransport, HttpError, TransportConnect, TransportError,
+    TransportErrorKind, TransportFut, TransportResult,
 };
 use std::task;
 use tower::Service;
@@ -43,6 +43,11 @@ impl Http<Client> {
             .await
             .map_err(TransportErrorKind::custom)?;
         let status = resp.status();
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(HttpError::parse_retry_after);
 
         debug!(%status, "received response from server");
 
@@ -55,9 +60,10 @@ impl Http<Client> {
         trace!(body = %String::from_utf8_lossy(&body), "response body");
 
         if status != reqwest::StatusCode::OK {
-            return Err(TransportErrorKind::http_error(
+            return Err(TransportErrorKind::http_error_with_retry_after(
                 status.as_u16(),
                 String::from_utf8_lossy(&body).into_owned(),
+                retry_after,
             ));
         }
 
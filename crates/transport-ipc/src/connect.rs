@@ -13,6 +13,8 @@ pub(crate) fn to_name(path: &std::ffi::OsStr) -> io::Result<ls::Name<'_>> {
 #[derive(Clone, Debug)]
 pub struct IpcConnect<T> {
     inner: T,
+    read_buffer_capacity: usize,
+    max_message_size: usize,
 }
 
 impl<T> IpcConnect<T> {
@@ -22,7 +24,38 @@ impl<T> IpcConnect<T> {
     where
         Self: alloy_pubsub::PubSubConnect,
     {
-        Self { inner }
+        Self::from_inner(inner)
+    }
+
+    const fn from_inner(inner: T) -> Self {
+        Self {
+            inner,
+            read_buffer_capacity: crate::CAPACITY,
+            max_message_size: crate::DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+
+    /// Sets the initial capacity of the read buffer. The buffer grows as needed to hold larger
+    /// messages, up to the maximum message size.
+    ///
+    /// Defaults to 4 KiB.
+    pub const fn with_read_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.read_buffer_capacity = capacity;
+        self
+    }
+
+    /// Sets the maximum size of a message received from the socket, in bytes. The connection is
+    /// closed with an error if a message exceeds this size.
+    ///
+    /// Defaults to 256 MiB, which accommodates large responses such as block traces.
+    pub const fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Returns the maximum size of a message received from the socket, in bytes.
+    pub const fn max_message_size(&self) -> usize {
+        self.max_message_size
     }
 }
 
@@ -30,7 +63,7 @@ macro_rules! impl_connect {
     ($target:ty => | $inner:ident | $map:expr) => {
         impl From<$target> for IpcConnect<$target> {
             fn from(inner: $target) -> Self {
-                Self { inner }
+                Self::from_inner(inner)
             }
         }
 
@@ -51,7 +84,7 @@ macro_rules! impl_connect {
                 let $inner = &self.inner;
                 let inner = $map;
                 let name = to_name(inner).map_err(alloy_transport::TransportErrorKind::custom)?;
-                crate::IpcBackend::connect(name)
+                crate::IpcBackend::connect(name, self.read_buffer_capacity, self.max_message_size)
                     .await
                     .map_err(alloy_transport::TransportErrorKind::custom)
             }
@@ -38,18 +38,24 @@ struct IpcBackend {
 
 impl IpcBackend {
     /// Connect to a local socket. Either a unix socket or a windows named pipe.
-    async fn connect(name: Name<'_>) -> Result<alloy_pubsub::ConnectionHandle> {
+    async fn connect(
+        name: Name<'_>,
+        read_buffer_capacity: usize,
+        max_message_size: usize,
+    ) -> Result<alloy_pubsub::ConnectionHandle> {
         let stream = LocalSocketStream::connect(name).await?;
         let (handle, interface) = alloy_pubsub::ConnectionHandle::new();
         let backend = Self { stream, interface };
-        backend.spawn();
+        backend.spawn(read_buffer_capacity, max_message_size);
         Ok(handle)
     }
 
-    fn spawn(mut self) {
+    fn spawn(mut self, read_buffer_capacity: usize, max_message_size: usize) {
         let fut = async move {
             let (read, mut writer) = self.stream.split();
-            let mut read = ReadJsonStream::new(read).fuse();
+            let mut read = ReadJsonStream::with_capacity(read, read_buffer_capacity)
+                .with_max_message_size(max_message_size)
+                .fuse();
 
             let err = loop {
                 select! {
@@ -99,6 +105,9 @@ impl IpcBackend {
 /// Default capacity for the IPC buffer.
 const CAPACITY: usize = 4096;
 
+/// Default maximum size of a message read from the IPC socket.
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 256 * 1024 * 1024;
+
 /// A stream of JSON-RPC items, read from an [`AsyncRead`] stream.
 #[derive(Debug)]
 #[pin_project::pin_project]
@@ -110,6 +119,8 @@ pub struct ReadJsonStream<T, Item = alloy_json_rpc::PubSubItem> {
     buf: BytesMut,
     /// Whether the buffer has been drained.
     drained: bool,
+    /// The maximum size of a single JSON value in the buffer.
+    max_message_size: usize,
 
     /// PhantomData marking the item type this stream will yield.
     _pd: std::marker::PhantomData<Item>,
@@ -117,13 +128,29 @@ pub struct ReadJsonStream<T, Item = alloy_json_rpc::PubSubItem> {
 
 impl<T: AsyncRead, U> ReadJsonStream<T, U> {
     fn new(reader: T) -> Self {
+        Self::with_capacity(reader, CAPACITY)
+    }
+
+    /// Creates a new stream with the given initial buffer capacity. The buffer grows as needed
+    /// to hold larger values, up to the maximum message size.
+    pub fn with_capacity(reader: T, capacity: usize) -> Self {
         Self {
             reader,
-            buf: BytesMut::with_capacity(CAPACITY),
+            buf: BytesMut::with_capacity(capacity),
             drained: true,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
             _pd: core::marker::PhantomData,
         }
     }
+
+    /// Sets the maximum size of a single JSON value, in bytes. The stream ends with an error if a
+    /// value exceeds this size.
+    ///
+    /// Defaults to 256 MiB.
+    pub const fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
 }
 
 impl<T: AsyncRead, U> From<T> for ReadJsonStream<T, U> {
@@ -148,29 +175,47 @@ where
             // try decoding from the buffer, but only if we have new data
             if !*this.drained {
                 debug!(buf_len = this.buf.len(), "Deserializing buffered IPC data");
-                let mut de = serde_json::Deserializer::from_slice(this.buf.as_ref()).into_iter();
-
-                let item = de.next();
-
-                // advance the buffer
-                this.buf.advance(de.byte_offset());
-
-                match item {
-                    Some(Ok(response)) => {
-                        return Ready(Some(response));
+                // find the end of the next JSON value first, so that a value that does not
+                // deserialize into `Item` can be skipped instead of stalling the stream
+                let mut de = serde_json::Deserializer::from_slice(this.buf.as_ref())
+                    .into_iter::<serde::de::IgnoredAny>();
+
+                match de.next() {
+                    Some(Ok(_)) => {
+                        let end = de.byte_offset();
+                        let item = serde_json::from_slice::<Item>(&this.buf[..end]);
+                        this.buf.advance(end);
+                        match item {
+                            Ok(response) => return Ready(Some(response)),
+                            Err(err) => {
+                                debug!(%err, "Skipping IPC item with unexpected contents");
+                                continue;
+                            }
+                        }
+                    }
+                    Some(Err(err)) if err.is_eof() => {
+                        if this.buf.len() > *this.max_message_size {
+                            error!(
+                                buf_len = this.buf.len(),
+                                max_message_size = *this.max_message_size,
+                                "IPC message exceeds the maximum message size"
+                            );
+                            return Ready(None);
+                        }
+                        trace!("partial object in IPC buffer");
+                        // nothing decoded
+                        *this.drained = true;
                     }
                     Some(Err(err)) => {
-                        if err.is_data() {
+                        if let Some(offset) = data_err_offset::<Item>(this.buf) {
+                            this.buf.advance(offset);
                             trace!(
                                 buffer = %String::from_utf8_lossy(this.buf.as_ref()),
                                 "IPC buffer contains invalid JSON data",
                             );
 
-                            // this happens if the deserializer is unable to decode a partial object
-                            *this.drained = true;
-                        } else if err.is_eof() {
-                            trace!("partial object in IPC buffer");
-                            // nothing decoded
+                            // this happens if the deserializer is unable to decode a partial
+                            // object
                             *this.drained = true;
                         } else {
                             error!(%err, "IPC response contained invalid JSON. Buffer contents will be logged at trace level");
@@ -183,7 +228,9 @@ where
                         }
                     }
                     None => {
-                        // nothing decoded
+                        // only whitespace left, nothing decoded
+                        let len = this.buf.len();
+                        this.buf.advance(len);
                         *this.drained = true;
                     }
                 }
@@ -212,6 +259,13 @@ where
     }
 }
 
+/// Returns the offset to advance the buffer by if decoding the next item from it fails with a
+/// data error, which the stream keeps reading past, as it did before skipping unexpected items.
+fn data_err_offset<Item: serde::de::DeserializeOwned>(buf: &[u8]) -> Option<usize> {
+    let mut de = serde_json::Deserializer::from_slice(buf).into_iter::<Item>();
+    matches!(de.next(), Some(Err(err)) if err.is_data()).then(|| de.byte_offset())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,4 +346,42 @@ mod tests {
         let obj = reader.next().await;
         assert!(obj.is_some());
     }
+
+    #[tokio::test]
+    async fn test_exceeds_max_message_size() {
+        let header = b"{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":\"0x";
+        let mock = tokio_test::io::Builder::new()
+            .read(header)
+            .read(vec![b'0'; 2 * CAPACITY].as_ref())
+            .build();
+
+        // the buffer fits the whole message, which exceeds the maximum size
+        let mut reader = ReadJsonStream::<_, PubSubItem>::with_capacity(mock, 4 * CAPACITY)
+            .with_max_message_size(CAPACITY);
+        assert!(reader.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_grows_beyond_capacity() {
+        let header = b"{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":\"0x";
+        let mock = tokio_test::io::Builder::new()
+            .read(header)
+            .read(vec![b'0'; 4 * CAPACITY].as_ref())
+            .read(b"\"}")
+            .build();
+
+        let mut reader = ReadJsonStream::<_, PubSubItem>::with_capacity(mock, 64);
+        assert!(matches!(reader.next().await, Some(PubSubItem::Response(_))));
+    }
+
+    #[tokio::test]
+    async fn test_skips_unexpected_item() {
+        let mock = tokio_test::io::Builder::new()
+            .read(br#"{"unexpected":true} {"jsonrpc":"2.0","id":1,"result":"0x1"}"#)
+            .build();
+
+        let mut reader = ReadJsonStream::<_, PubSubItem>::new(mock);
+        assert!(matches!(reader.next().await, Some(PubSubItem::Response(_))));
+        assert!(reader.next().await.is_none());
+    }
 }
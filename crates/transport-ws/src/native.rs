@@ -1,5 +1,5 @@
 use crate::WsBackend;
-use alloy_pubsub::PubSubConnect;
+use alloy_pubsub::{PubSubConnect, ReconnectPolicy};
 use alloy_transport::{utils::Spawnable, Authorization, TransportErrorKind, TransportResult};
 use futures::{SinkExt, StreamExt};
 use serde_json::value::RawValue;
@@ -20,6 +20,9 @@ type TungsteniteStream = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
 
 const KEEPALIVE: u64 = 10;
 
+/// The default maximum number of connections subscriptions are sharded across.
+const DEFAULT_MAX_SHARDS: usize = 1;
+
 /// Simple connection details for a websocket connection.
 #[derive(Clone, Debug)]
 pub struct WsConnect {
@@ -29,12 +32,24 @@ pub struct WsConnect {
     pub auth: Option<Authorization>,
     /// The websocket config.
     pub config: Option<WebSocketConfig>,
+    /// The maximum number of connections to open when the server rejects
+    /// subscriptions due to a per-connection subscription limit. `1` disables
+    /// sharding.
+    pub max_shards: usize,
+    /// The policy for reconnecting when the connection drops.
+    pub reconnect_policy: ReconnectPolicy,
 }
 
 impl WsConnect {
     /// Creates a new websocket connection configuration.
     pub fn new<S: Into<String>>(url: S) -> Self {
-        Self { url: url.into(), auth: None, config: None }
+        Self {
+            url: url.into(),
+            auth: None,
+            config: None,
+            max_shards: DEFAULT_MAX_SHARDS,
+            reconnect_policy: ReconnectPolicy::default(),
+        }
     }
 
     /// Sets the authorization header.
@@ -48,6 +63,30 @@ impl WsConnect {
         self.config = Some(config);
         self
     }
+
+    /// Sets the maximum number of connections that subscriptions may be
+    /// sharded across. Additional connections are only opened when the server
+    /// reports that its per-connection subscription limit was reached.
+    ///
+    /// Defaults to `1`, which disables sharding.
+    pub const fn with_max_shards(mut self, max_shards: usize) -> Self {
+        self.max_shards = max_shards;
+        self
+    }
+
+    /// Sets the policy for reconnecting when the connection drops. Active
+    /// subscriptions are re-established on the new connection.
+    pub const fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// Sets the maximum number of retries when reconnecting fails. Defaults
+    /// to no retries, see [`ReconnectPolicy`].
+    pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.reconnect_policy = self.reconnect_policy.with_max_retries(max_retries);
+        self
+    }
 }
 
 impl IntoClientRequest for WsConnect {
@@ -69,6 +108,14 @@ impl PubSubConnect for WsConnect {
         alloy_transport::utils::guess_local_url(&self.url)
     }
 
+    fn max_shards(&self) -> usize {
+        self.max_shards
+    }
+
+    fn reconnect_policy(&self) -> ReconnectPolicy {
+        self.reconnect_policy
+    }
+
     async fn connect(&self) -> TransportResult<alloy_pubsub::ConnectionHandle> {
         let request = self.clone().into_client_request();
         let req = request.map_err(TransportErrorKind::custom)?;
@@ -1,5 +1,5 @@
 use super::WsBackend;
-use alloy_pubsub::PubSubConnect;
+use alloy_pubsub::{PubSubConnect, ReconnectPolicy};
 use alloy_transport::{utils::Spawnable, TransportErrorKind, TransportResult};
 use futures::{
     sink::SinkExt,
@@ -13,12 +13,28 @@ use ws_stream_wasm::{WsErr, WsMessage, WsMeta, WsStream};
 pub struct WsConnect {
     /// The URL to connect to.
     pub url: String,
+    /// The policy for reconnecting when the connection drops.
+    pub reconnect_policy: ReconnectPolicy,
 }
 
 impl WsConnect {
     /// Creates a new websocket connection configuration.
     pub fn new<S: Into<String>>(url: S) -> Self {
-        Self { url: url.into() }
+        Self { url: url.into(), reconnect_policy: ReconnectPolicy::default() }
+    }
+
+    /// Sets the policy for reconnecting when the connection drops. Active
+    /// subscriptions are re-established on the new connection.
+    pub const fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// Sets the maximum number of retries when reconnecting fails. Defaults
+    /// to no retries, see [`ReconnectPolicy`].
+    pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.reconnect_policy = self.reconnect_policy.with_max_retries(max_retries);
+        self
     }
 }
 
@@ -27,6 +43,10 @@ impl PubSubConnect for WsConnect {
         alloy_transport::utils::guess_local_url(&self.url)
     }
 
+    fn reconnect_policy(&self) -> ReconnectPolicy {
+        self.reconnect_policy
+    }
+
     async fn connect(&self) -> TransportResult<alloy_pubsub::ConnectionHandle> {
         let socket =
             WsMeta::connect(&self.url, None).await.map_err(TransportErrorKind::custom)?.1.fuse();
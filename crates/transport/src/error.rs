@@ -1,7 +1,7 @@
 use alloy_json_rpc::{ErrorPayload, Id, RpcError, RpcResult};
 use serde::Deserialize;
 use serde_json::value::RawValue;
-use std::{error::Error as StdError, fmt::Debug};
+use std::{error::Error as StdError, fmt::Debug, time::Duration};
 use thiserror::Error;
 
 /// A transport error is an [`RpcError`] containing a [`TransportErrorKind`].
@@ -75,7 +75,17 @@ impl TransportErrorKind {
 
     /// Instantiate a new `TransportError::HttpError`.
     pub const fn http_error(status: u16, body: String) -> TransportError {
-        RpcError::Transport(Self::HttpError(HttpError { status, body }))
+        Self::http_error_with_retry_after(status, body, None)
+    }
+
+    /// Instantiate a new `TransportError::HttpError` with the backoff requested by the server,
+    /// e.g. in the `Retry-After` header.
+    pub const fn http_error_with_retry_after(
+        status: u16,
+        body: String,
+        retry_after: Option<Duration>,
+    ) -> TransportError {
+        RpcError::Transport(Self::HttpError(HttpError { status, body, retry_after }))
     }
 
     /// Analyzes the [TransportErrorKind] and decides if the request should be retried based on the
@@ -89,13 +99,38 @@ impl TransportErrorKind {
             }
             Self::Custom(err) => {
                 let msg = err.to_string();
-                msg.contains("429 Too Many Requests")
+                msg.contains("429 Too Many Requests") || is_connection_reset(&**err)
             }
             _ => false,
         }
     }
 }
 
+/// Returns whether the error was caused by the connection being reset or closed by the server,
+/// in which case the request can be retried on a new connection.
+fn is_connection_reset(err: &(dyn StdError + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<std::io::Error>() {
+            if matches!(
+                io_err.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+        // hyper reports connections closed while waiting for the response without an IO error
+        if err.to_string().contains("connection closed before message completed") {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
 /// Type for holding HTTP errors such as 429 rate limit error.
 #[derive(Debug, thiserror::Error)]
 #[error("HTTP error {status} with body: {body}")]
@@ -104,6 +139,8 @@ pub struct HttpError {
     pub status: u16,
     /// The HTTP response body.
     pub body: String,
+    /// The backoff requested by the server, e.g. in the `Retry-After` header.
+    pub retry_after: Option<Duration>,
 }
 
 impl HttpError {
@@ -117,6 +154,16 @@ impl HttpError {
     pub const fn is_temporarily_unavailable(&self) -> bool {
         self.status == 503
     }
+
+    /// Parses the value of a `Retry-After` header, in seconds.
+    ///
+    /// HTTP dates are not supported and return `None`.
+    pub fn parse_retry_after(value: &str) -> Option<Duration> {
+        let value = value.trim();
+        value.parse::<u64>().map(Duration::from_secs).ok().or_else(|| {
+            value.parse::<f64>().ok().and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        })
+    }
 }
 
 /// Extension trait to implement methods for [`RpcError<TransportErrorKind, E>`].
@@ -162,6 +209,9 @@ impl RpcErrorExt for RpcError<TransportErrorKind> {
     }
 
     fn backoff_hint(&self) -> Option<std::time::Duration> {
+        if let Self::Transport(TransportErrorKind::HttpError(err)) = self {
+            return err.retry_after;
+        }
         if let Self::ErrorResp(resp) = self {
             let data = resp.try_data_as::<serde_json::Value>();
             if let Some(Ok(data)) = data {
@@ -191,4 +241,25 @@ mod tests {
         let err = serde_json::from_str::<ErrorPayload>(err).unwrap();
         assert!(TransportError::ErrorResp(err).is_retryable());
     }
+
+    #[test]
+    fn test_retry_after() {
+        let err = TransportErrorKind::http_error_with_retry_after(
+            429,
+            String::new(),
+            HttpError::parse_retry_after("2"),
+        );
+        assert!(err.is_retryable());
+        assert_eq!(err.backoff_hint(), Some(Duration::from_secs(2)));
+        assert_eq!(HttpError::parse_retry_after(" 0.5 "), Some(Duration::from_millis(500)));
+        assert_eq!(HttpError::parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
+
+    #[test]
+    fn test_connection_reset() {
+        let reset = std::io::Error::from(std::io::ErrorKind::ConnectionReset);
+        assert!(TransportErrorKind::custom(reset).is_retryable());
+        let refused = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+        assert!(!TransportErrorKind::custom(refused).is_retryable());
+    }
 }
@@ -0,0 +1,327 @@
+use crate::{BoxTransport, TransportError, TransportErrorKind, TransportFut};
+use alloy_json_rpc::{ErrorPayload, RequestPacket, ResponsePacket, RpcError};
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+    time::Duration,
+};
+use tower::Service;
+use tracing::{debug, warn};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::{std::Instant, tokio::timeout};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::timeout;
+
+/// The health of an endpoint of a [`FallbackTransport`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EndpointHealth {
+    /// The number of consecutive failed requests.
+    pub consecutive_failures: u32,
+    /// Whether the endpoint is healthy. Unhealthy endpoints are only used when all healthy
+    /// endpoints failed, until their cooldown elapsed.
+    pub healthy: bool,
+}
+
+#[derive(Debug, Default)]
+struct EndpointState {
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+}
+
+impl EndpointState {
+    fn is_healthy(&self, now: Instant) -> bool {
+        self.unhealthy_until.map_or(true, |until| until <= now)
+    }
+}
+
+/// A transport that sends requests to an ordered list of endpoints, failing over to the next
+/// endpoint when a request fails.
+///
+/// Each request is sent to the first healthy endpoint, i.e. the primary endpoint while it is
+/// healthy. If the request fails with a transport error, times out, or is rejected with a
+/// rate limit error, it is sent to the next endpoint, and so on. When all endpoints fail, the
+/// last failure is returned.
+///
+/// An endpoint becomes unhealthy after [`with_failure_threshold`] consecutive failures, and is
+/// skipped for the [`with_cooldown`] duration, unless all healthy endpoints fail. Once the
+/// cooldown elapsed, the endpoint is tried again, so that requests are promoted back to the
+/// primary endpoint as soon as it recovers.
+///
+/// The transport can be passed to `ClientBuilder::transport` like any other transport. Its
+/// endpoints are [`BoxTransport`]s, so they may use different transport types and layers.
+///
+/// [`with_failure_threshold`]: Self::with_failure_threshold
+/// [`with_cooldown`]: Self::with_cooldown
+#[derive(Clone, Debug)]
+pub struct FallbackTransport {
+    transports: Arc<[BoxTransport]>,
+    state: Arc<Mutex<Vec<EndpointState>>>,
+    request_timeout: Duration,
+    failure_threshold: u32,
+    cooldown: Duration,
+}
+
+impl FallbackTransport {
+    /// Creates a new fallback transport over the given transports, in order of preference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no transport is given.
+    pub fn new<I>(transports: I) -> Self
+    where
+        I: IntoIterator<Item = BoxTransport>,
+    {
+        let transports: Arc<[BoxTransport]> = transports.into_iter().collect();
+        assert!(!transports.is_empty(), "fallback transport requires at least one transport");
+        let state = transports.iter().map(|_| EndpointState::default()).collect();
+        Self {
+            transports,
+            state: Arc::new(Mutex::new(state)),
+            request_timeout: Duration::from_secs(10),
+            failure_threshold: 3,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the timeout of a request to a single endpoint, after which the request fails over to
+    /// the next endpoint. Defaults to 10 seconds.
+    pub const fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Sets the number of consecutive failures after which an endpoint becomes unhealthy.
+    /// Defaults to 3.
+    pub const fn with_failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold;
+        self
+    }
+
+    /// Sets the duration for which an unhealthy endpoint is skipped. Defaults to 30 seconds.
+    pub const fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Returns the number of endpoints.
+    pub fn len(&self) -> usize {
+        self.transports.len()
+    }
+
+    /// Returns `true` if there are no endpoints, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.transports.is_empty()
+    }
+
+    /// Returns the health of the endpoints, in order.
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let now = Instant::now();
+        self.lock()
+            .iter()
+            .map(|state| EndpointHealth {
+                consecutive_failures: state.consecutive_failures,
+                healthy: state.is_healthy(now),
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<EndpointState>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the indices of the endpoints in the order they are tried: healthy endpoints
+    /// first, then unhealthy ones, each in order of preference.
+    fn endpoint_order(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let state = self.lock();
+        let (mut healthy, unhealthy): (Vec<_>, Vec<_>) =
+            (0..state.len()).partition(|&i| state[i].is_healthy(now));
+        healthy.extend(unhealthy);
+        healthy
+    }
+
+    fn record_success(&self, index: usize) {
+        let mut state = self.lock();
+        if state[index].unhealthy_until.take().is_some() {
+            debug!(index, "fallback endpoint recovered");
+        }
+        state[index].consecutive_failures = 0;
+    }
+
+    fn record_failure(&self, index: usize) {
+        let mut state = self.lock();
+        let endpoint = &mut state[index];
+        endpoint.consecutive_failures += 1;
+        if endpoint.consecutive_failures >= self.failure_threshold {
+            warn!(index, failures = endpoint.consecutive_failures, "fallback endpoint unhealthy");
+            endpoint.unhealthy_until = Some(Instant::now() + self.cooldown);
+        }
+    }
+
+    async fn call_endpoint(
+        &self,
+        index: usize,
+        request: RequestPacket,
+    ) -> Result<ResponsePacket, TransportError> {
+        let mut transport = self.transports[index].clone();
+        match timeout(self.request_timeout, transport.call(request)).await {
+            Ok(res) => res,
+            Err(_) => Err(TransportErrorKind::custom_str(&format!(
+                "request to fallback endpoint {index} timed out after {:?}",
+                self.request_timeout
+            ))),
+        }
+    }
+}
+
+/// Returns whether the result should be retried on the next endpoint.
+pub(crate) fn should_fail_over(res: &Result<ResponsePacket, TransportError>) -> bool {
+    match res {
+        Ok(response) => response.iter_errors().any(ErrorPayload::is_retry_err),
+        Err(RpcError::ErrorResp(err)) => err.is_retry_err(),
+        Err(RpcError::SerError(_)) => false,
+        Err(_) => true,
+    }
+}
+
+impl Service<RequestPacket> for FallbackTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Endpoints are polled when the request is sent.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let this = self.clone();
+        Box::pin(async move {
+            let order = this.endpoint_order();
+            let last = order.len() - 1;
+            for (attempt, index) in order.into_iter().enumerate() {
+                let res = this.call_endpoint(index, request.clone()).await;
+                if !should_fail_over(&res) {
+                    this.record_success(index);
+                    return res;
+                }
+                this.record_failure(index);
+                if attempt == last {
+                    return res;
+                }
+                match &res {
+                    Ok(_) => debug!(index, "fallback endpoint rate limited, failing over"),
+                    Err(err) => debug!(index, %err, "fallback endpoint failed, failing over"),
+                }
+            }
+            unreachable!("fallback transport has at least one endpoint")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::{Id, Request, Response, ResponsePayload};
+    use serde_json::value::RawValue;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// An endpoint that responds to every request, or fails while `failing` is set.
+    #[derive(Clone, Debug, Default)]
+    struct MockEndpoint {
+        failing: Arc<AtomicBool>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl MockEndpoint {
+        fn set_failing(&self, failing: bool) {
+            self.failing.store(failing, Ordering::Relaxed);
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::Relaxed)
+        }
+    }
+
+    impl Service<RequestPacket> for MockEndpoint {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: RequestPacket) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            let failing = self.failing.load(Ordering::Relaxed);
+            Box::pin(async move {
+                if failing {
+                    return Err(TransportErrorKind::backend_gone());
+                }
+                let result = RawValue::from_string("\"0x1\"".to_string()).unwrap();
+                Ok(ResponsePacket::Single(Response {
+                    id: Id::Number(1),
+                    payload: ResponsePayload::Success(result),
+                }))
+            })
+        }
+    }
+
+    fn request() -> RequestPacket {
+        Request::new("eth_blockNumber", Id::Number(1), ()).serialize().unwrap().into()
+    }
+
+    #[tokio::test]
+    async fn fails_over_and_promotes_primary() {
+        let primary = MockEndpoint::default();
+        let backup = MockEndpoint::default();
+        let mut transport = FallbackTransport::new([
+            BoxTransport::new(primary.clone()),
+            BoxTransport::new(backup.clone()),
+        ])
+        .with_failure_threshold(1)
+        .with_cooldown(Duration::from_millis(50));
+
+        primary.set_failing(true);
+        assert!(transport.call(request()).await.is_ok());
+        assert_eq!((primary.calls(), backup.calls()), (1, 1));
+        assert!(!transport.health()[0].healthy);
+
+        // the unhealthy primary is skipped
+        assert!(transport.call(request()).await.is_ok());
+        assert_eq!((primary.calls(), backup.calls()), (1, 2));
+
+        // the recovered primary is used again once the cooldown elapsed
+        primary.set_failing(false);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(transport.call(request()).await.is_ok());
+        assert_eq!((primary.calls(), backup.calls()), (2, 2));
+        assert_eq!(
+            transport.health()[0],
+            EndpointHealth { consecutive_failures: 0, healthy: true }
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_last_error() {
+        let primary = MockEndpoint::default();
+        let backup = MockEndpoint::default();
+        primary.set_failing(true);
+        backup.set_failing(true);
+        let mut transport = FallbackTransport::new([
+            BoxTransport::new(primary.clone()),
+            BoxTransport::new(backup.clone()),
+        ]);
+
+        let err = transport.call(request()).await.unwrap_err();
+        assert!(matches!(err, RpcError::Transport(TransportErrorKind::BackendGone)));
+        assert_eq!((primary.calls(), backup.calls()), (1, 1));
+        assert_eq!(transport.health()[1].consecutive_failures, 1);
+    }
+}
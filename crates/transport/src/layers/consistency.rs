@@ -0,0 +1,395 @@
+use crate::{utils::Spawnable, BoxTransport, IntoBoxTransport, TransportError, TransportFut};
+use alloy_json_rpc::{RequestPacket, ResponsePacket, ResponsePayload, SerializedRequest};
+use serde_json::{value::RawValue, Value};
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+use tracing::{debug, warn};
+
+/// The read-only methods that are mirrored by default.
+pub const DEFAULT_MIRRORED_METHODS: &[&str] = &[
+    "eth_blockNumber",
+    "eth_call",
+    "eth_chainId",
+    "eth_getBalance",
+    "eth_getBlockByHash",
+    "eth_getBlockByNumber",
+    "eth_getBlockReceipts",
+    "eth_getCode",
+    "eth_getLogs",
+    "eth_getProof",
+    "eth_getStorageAt",
+    "eth_getTransactionByHash",
+    "eth_getTransactionCount",
+    "eth_getTransactionReceipt",
+];
+
+/// Block tags whose meaning depends on the head of the endpoint.
+const HEAD_TAGS: &[&str] = &["latest", "pending", "safe", "finalized"];
+
+/// Methods with a trailing, optional block parameter, and their full number of parameters.
+const BLOCK_PARAM_ARITY: &[(&str, usize)] = &[
+    ("eth_call", 2),
+    ("eth_getBalance", 2),
+    ("eth_getCode", 2),
+    ("eth_getProof", 3),
+    ("eth_getStorageAt", 3),
+    ("eth_getTransactionCount", 2),
+];
+
+type DivergenceCallback = Arc<dyn Fn(&Divergence) + Send + Sync>;
+
+/// A response of the secondary endpoint that differs from the response of the primary endpoint.
+#[derive(Clone, Debug)]
+pub struct Divergence {
+    /// The method of the request.
+    pub method: String,
+    /// The parameters of the request.
+    pub params: Option<Box<RawValue>>,
+    /// The response payload of the primary endpoint.
+    pub primary: ResponsePayload,
+    /// The response payload of the secondary endpoint.
+    pub secondary: ResponsePayload,
+}
+
+/// Counters of a [`ConsistencyCheckLayer`], shared by all its services.
+#[derive(Debug, Default)]
+pub struct ConsistencyStats {
+    checked: AtomicU64,
+    diverged: AtomicU64,
+    failed: AtomicU64,
+}
+
+impl ConsistencyStats {
+    /// Returns the number of requests whose responses were compared.
+    pub fn checked(&self) -> u64 {
+        self.checked.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of requests whose responses diverged.
+    pub fn diverged(&self) -> u64 {
+        self.diverged.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of mirrored requests that failed on the secondary endpoint with a
+    /// transport error, and were not compared.
+    pub fn failed(&self) -> u64 {
+        self.failed.load(Ordering::Relaxed)
+    }
+}
+
+/// A Transport Layer that mirrors a fraction of read requests to a secondary endpoint, and
+/// reports responses that differ from the responses of the primary endpoint.
+///
+/// Responses are always served from the primary endpoint. The mirrored request is sent once the
+/// primary response arrived, and compared in the background.
+///
+/// Requests relative to the head of the chain, i.e. using a block tag such as `latest` or
+/// omitting the block parameter, may legitimately differ between endpoints and are not mirrored
+/// unless [`with_head_requests`](Self::with_head_requests) is set. `eth_blockNumber` is compared
+/// with a tolerance of [`with_block_tolerance`](Self::with_block_tolerance) blocks. Batch
+/// requests are not mirrored.
+#[derive(Clone)]
+pub struct ConsistencyCheckLayer {
+    secondary: BoxTransport,
+    sample_rate: f64,
+    methods: Arc<HashSet<String>>,
+    head_requests: bool,
+    block_tolerance: u64,
+    on_divergence: Option<DivergenceCallback>,
+    stats: Arc<ConsistencyStats>,
+}
+
+impl fmt::Debug for ConsistencyCheckLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConsistencyCheckLayer")
+            .field("sample_rate", &self.sample_rate)
+            .field("methods", &self.methods)
+            .field("head_requests", &self.head_requests)
+            .field("block_tolerance", &self.block_tolerance)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ConsistencyCheckLayer {
+    /// Creates a new layer mirroring all [default methods](DEFAULT_MIRRORED_METHODS) to the given
+    /// secondary transport.
+    pub fn new<T: IntoBoxTransport>(secondary: T) -> Self {
+        Self {
+            secondary: secondary.into_box_transport(),
+            sample_rate: 1.0,
+            methods: Arc::new(DEFAULT_MIRRORED_METHODS.iter().map(|m| m.to_string()).collect()),
+            head_requests: false,
+            block_tolerance: 2,
+            on_divergence: None,
+            stats: Default::default(),
+        }
+    }
+
+    /// Sets the fraction of eligible requests that are mirrored, between `0.0` and `1.0`.
+    ///
+    /// Requests are sampled deterministically, e.g. every fourth request for `0.25`.
+    pub fn with_sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = sample_rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the methods that are mirrored, replacing the default methods.
+    pub fn with_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.methods = Arc::new(methods.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets whether requests relative to the head of the chain are mirrored. Defaults to `false`.
+    pub const fn with_head_requests(mut self, head_requests: bool) -> Self {
+        self.head_requests = head_requests;
+        self
+    }
+
+    /// Sets the number of blocks by which the `eth_blockNumber` responses may differ. Defaults
+    /// to 2.
+    pub const fn with_block_tolerance(mut self, block_tolerance: u64) -> Self {
+        self.block_tolerance = block_tolerance;
+        self
+    }
+
+    /// Sets a callback invoked with every divergence.
+    ///
+    /// Divergences are also logged at `WARN` level and counted in the
+    /// [`stats`](Self::stats).
+    pub fn on_divergence<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Divergence) + Send + Sync + 'static,
+    {
+        self.on_divergence = Some(Arc::new(f));
+        self
+    }
+
+    /// Returns the counters of the layer.
+    pub fn stats(&self) -> Arc<ConsistencyStats> {
+        self.stats.clone()
+    }
+
+    /// Returns whether the request is eligible for mirroring, ignoring sampling.
+    fn is_eligible(&self, request: &SerializedRequest) -> bool {
+        self.methods.contains(request.method())
+            && (self.head_requests
+                || request.method() == "eth_blockNumber"
+                || !is_head_relative(request.method(), request.params()))
+    }
+}
+
+impl<S> Layer<S> for ConsistencyCheckLayer {
+    type Service = ConsistencyCheckService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ConsistencyCheckService { inner, config: self.clone(), counter: Default::default() }
+    }
+}
+
+/// A Tower Service used by the [`ConsistencyCheckLayer`] that mirrors requests to a secondary
+/// endpoint.
+#[derive(Clone, Debug)]
+pub struct ConsistencyCheckService<S> {
+    inner: S,
+    config: ConsistencyCheckLayer,
+    counter: Arc<AtomicU64>,
+}
+
+impl<S> ConsistencyCheckService<S> {
+    fn sample(&self) -> bool {
+        let n = self.counter.fetch_add(1, Ordering::Relaxed) as f64;
+        let rate = self.config.sample_rate;
+        ((n + 1.0) * rate).floor() > (n * rate).floor()
+    }
+}
+
+impl<S> Service<RequestPacket> for ConsistencyCheckService<S>
+where
+    S: Service<RequestPacket, Future = TransportFut<'static>, Error = TransportError>
+        + Send
+        + 'static
+        + Clone,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let mirrored = match &request {
+            RequestPacket::Single(single) if self.config.is_eligible(single) && self.sample() => {
+                Some(single.clone())
+            }
+            _ => None,
+        };
+        let response = self.inner.call(request);
+        let config = self.config.clone();
+        Box::pin(async move {
+            let response = response.await?;
+            if let (Some(mirrored), ResponsePacket::Single(primary)) = (mirrored, &response) {
+                let primary = primary.payload.clone();
+                mirror(config, mirrored, primary).spawn_task();
+            }
+            Ok(response)
+        })
+    }
+}
+
+async fn mirror(
+    config: ConsistencyCheckLayer,
+    request: SerializedRequest,
+    primary: ResponsePayload,
+) {
+    let method = request.method().to_string();
+    let params = request.params().map(ToOwned::to_owned);
+    let mut secondary = config.secondary.clone();
+    let secondary = match secondary.call(request.into()).await {
+        Ok(ResponsePacket::Single(response)) => response.payload,
+        Ok(ResponsePacket::Batch(_)) => return,
+        Err(err) => {
+            debug!(%method, %err, "mirrored request failed");
+            config.stats.failed.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+    };
+
+    config.stats.checked.fetch_add(1, Ordering::Relaxed);
+    if payloads_match(&method, &primary, &secondary, config.block_tolerance) {
+        return;
+    }
+    config.stats.diverged.fetch_add(1, Ordering::Relaxed);
+    warn!(%method, ?primary, ?secondary, "responses of redundant endpoints diverged");
+    if let Some(on_divergence) = &config.on_divergence {
+        on_divergence(&Divergence { method, params, primary, secondary });
+    }
+}
+
+/// Returns whether the response of a request depends on the head of the endpoint.
+fn is_head_relative(method: &str, params: Option<&RawValue>) -> bool {
+    let params = match params.map(|params| serde_json::from_str::<Value>(params.get())) {
+        Some(Ok(Value::Array(params))) => params,
+        Some(Ok(_)) | Some(Err(_)) => return false,
+        None => Vec::new(),
+    };
+    let is_tag = |value: &Value| value.as_str().is_some_and(|tag| HEAD_TAGS.contains(&tag));
+
+    if let Some(&(_, arity)) = BLOCK_PARAM_ARITY.iter().find(|(m, _)| *m == method) {
+        if params.len() < arity {
+            return true;
+        }
+    }
+    if method == "eth_getLogs" {
+        return params.first().and_then(Value::as_object).is_some_and(|filter| {
+            !filter.contains_key("blockHash")
+                && ["fromBlock", "toBlock"].iter().any(|key| filter.get(*key).map_or(true, is_tag))
+        });
+    }
+    params.iter().any(is_tag)
+}
+
+/// Returns whether the payloads of the primary and secondary endpoints match.
+fn payloads_match(
+    method: &str,
+    primary: &ResponsePayload,
+    secondary: &ResponsePayload,
+    block_tolerance: u64,
+) -> bool {
+    match (primary, secondary) {
+        (ResponsePayload::Success(a), ResponsePayload::Success(b)) => {
+            let (Ok(a), Ok(b)) =
+                (serde_json::from_str::<Value>(a.get()), serde_json::from_str::<Value>(b.get()))
+            else {
+                return a.get() == b.get();
+            };
+            if method == "eth_blockNumber" {
+                if let (Some(a), Some(b)) = (parse_quantity(&a), parse_quantity(&b)) {
+                    return a.abs_diff(b) <= block_tolerance;
+                }
+            }
+            a == b
+        }
+        (ResponsePayload::Failure(a), ResponsePayload::Failure(b)) => a.code == b.code,
+        _ => false,
+    }
+}
+
+fn parse_quantity(value: &Value) -> Option<u64> {
+    u64::from_str_radix(value.as_str()?.strip_prefix("0x")?, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+
+    fn raw(json: &str) -> Box<RawValue> {
+        RawValue::from_string(json.to_string()).unwrap()
+    }
+
+    #[test]
+    fn head_relative_requests() {
+        let head = |method, params: &str| is_head_relative(method, Some(&raw(params)));
+        assert!(head("eth_getBalance", r#"["0x0000000000000000000000000000000000000000"]"#));
+        assert!(head(
+            "eth_getBalance",
+            r#"["0x0000000000000000000000000000000000000000","latest"]"#
+        ));
+        assert!(!head(
+            "eth_getBalance",
+            r#"["0x0000000000000000000000000000000000000000","0x10"]"#
+        ));
+        assert!(head("eth_getBlockByNumber", r#"["finalized",false]"#));
+        assert!(!head("eth_getBlockByNumber", r#"["0x1",false]"#));
+        assert!(head("eth_getLogs", r#"[{"fromBlock":"0x1"}]"#));
+        assert!(!head("eth_getLogs", r#"[{"fromBlock":"0x1","toBlock":"0x2"}]"#));
+        assert!(!head("eth_getLogs", r#"[{"blockHash":"0x01"}]"#));
+        assert!(!head("eth_chainId", "[]"));
+        assert!(!is_head_relative("eth_chainId", None));
+    }
+
+    #[test]
+    fn compare_payloads() {
+        let ok = |json: &str| ResponsePayload::Success(raw(json));
+        assert!(payloads_match("eth_getBalance", &ok(r#""0x1""#), &ok(r#" "0x1" "#), 0));
+        assert!(!payloads_match("eth_getBalance", &ok(r#""0x1""#), &ok(r#""0x2""#), 0));
+        assert!(payloads_match(
+            "eth_getBlockByHash",
+            &ok(r#"{"a":1,"b":2}"#),
+            &ok(r#"{"b":2,"a":1}"#),
+            0
+        ));
+
+        assert!(payloads_match("eth_blockNumber", &ok(r#""0x10""#), &ok(r#""0x12""#), 2));
+        assert!(!payloads_match("eth_blockNumber", &ok(r#""0x10""#), &ok(r#""0x13""#), 2));
+
+        let err = ResponsePayload::Failure(ErrorPayload::internal_error());
+        assert!(payloads_match("eth_call", &err, &err.clone(), 0));
+        assert!(!payloads_match("eth_call", &ok("null"), &err, 0));
+    }
+
+    #[test]
+    fn sampling() {
+        let layer = ConsistencyCheckLayer::new(BoxTransport::new(tower::service_fn(
+            |_: RequestPacket| -> TransportFut<'static> { Box::pin(async { unreachable!() }) },
+        )));
+        let service = layer.with_sample_rate(0.25).layer(());
+        let sampled = (0..100).filter(|_| service.sample()).count();
+        assert_eq!(sampled, 25);
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{TransportError, TransportErrorKind, TransportFut};
+use alloy_json_rpc::{RequestPacket, ResponsePacket, SerializedRequest};
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// Method patterns of the namespaces that administer the node or its accounts, which should
+/// usually not be reachable when proxying untrusted input.
+pub const SENSITIVE_METHODS: &[&str] =
+    &["admin_*", "anvil_*", "debug_*", "engine_*", "evm_*", "hardhat_*", "miner_*", "personal_*"];
+
+/// An error returned by the [`MethodFirewallLayer`] when a request is blocked.
+///
+/// The error is returned as a [`TransportErrorKind::Custom`] error, and can be recovered by
+/// downcasting its source.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum MethodFirewallError {
+    /// The method does not match any pattern of the allowlist.
+    #[error("method {0} is not allowed")]
+    NotAllowed(String),
+    /// The method matches a pattern of the denylist.
+    #[error("method {0} is denied")]
+    Denied(String),
+}
+
+impl MethodFirewallError {
+    /// Returns the method of the blocked request.
+    pub fn method(&self) -> &str {
+        match self {
+            Self::NotAllowed(method) | Self::Denied(method) => method,
+        }
+    }
+}
+
+/// A pattern matching method names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum MethodPattern {
+    /// Matches the method with this exact name.
+    Exact(String),
+    /// Matches all methods starting with this prefix, e.g. a namespace such as `admin_`.
+    Prefix(String),
+}
+
+impl MethodPattern {
+    fn new(pattern: &str) -> Self {
+        match pattern.strip_suffix('*') {
+            Some(prefix) => Self::Prefix(prefix.to_string()),
+            None => Self::Exact(pattern.to_string()),
+        }
+    }
+
+    fn matches(&self, method: &str) -> bool {
+        match self {
+            Self::Exact(name) => name == method,
+            Self::Prefix(prefix) => method.starts_with(prefix.as_str()),
+        }
+    }
+}
+
+/// A Transport Layer that restricts which JSON-RPC methods may be issued.
+///
+/// Methods are matched against patterns, which are either exact method names such as
+/// `eth_call`, or prefixes ending with a `*` wildcard such as `admin_*` to match a whole
+/// namespace. A request is blocked if an [allowlist](Self::allow) is set and the method matches
+/// none of its patterns, or if the method matches a pattern of the [denylist](Self::deny).
+/// Blocked requests fail with a [`MethodFirewallError`] without being sent. A batch request is
+/// blocked as a whole if any of its requests is blocked.
+///
+/// This is useful when alloy is embedded in services that issue requests on behalf of users,
+/// e.g. to block the [`SENSITIVE_METHODS`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_transport::layers::{MethodFirewallLayer, SENSITIVE_METHODS};
+///
+/// // only allow the `eth` and `net` namespaces, except for signing with node accounts
+/// let firewall = MethodFirewallLayer::new().allow(["eth_*", "net_*"]).deny(["eth_sign*"]);
+///
+/// // allow everything except the sensitive namespaces
+/// let firewall = MethodFirewallLayer::new().deny(SENSITIVE_METHODS);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MethodFirewallLayer {
+    allowed: Option<Arc<Vec<MethodPattern>>>,
+    denied: Arc<Vec<MethodPattern>>,
+}
+
+impl MethodFirewallLayer {
+    /// Creates a new layer that allows all methods.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds patterns to the allowlist. Once an allowlist is set, only methods matching one of its
+    /// patterns are allowed.
+    pub fn allow<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let allowed = Arc::make_mut(self.allowed.get_or_insert_with(Default::default));
+        allowed.extend(patterns.into_iter().map(|pattern| MethodPattern::new(pattern.as_ref())));
+        self
+    }
+
+    /// Adds patterns to the denylist. Methods matching one of its patterns are blocked, even if
+    /// they are allowed by the allowlist.
+    pub fn deny<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let denied = Arc::make_mut(&mut self.denied);
+        denied.extend(patterns.into_iter().map(|pattern| MethodPattern::new(pattern.as_ref())));
+        self
+    }
+
+    /// Checks whether the method may be issued.
+    pub fn check(&self, method: &str) -> Result<(), MethodFirewallError> {
+        if self.denied.iter().any(|pattern| pattern.matches(method)) {
+            return Err(MethodFirewallError::Denied(method.to_string()));
+        }
+        match &self.allowed {
+            Some(allowed) if !allowed.iter().any(|pattern| pattern.matches(method)) => {
+                Err(MethodFirewallError::NotAllowed(method.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks whether all requests of the packet may be issued.
+    fn check_packet(&self, packet: &RequestPacket) -> Result<(), MethodFirewallError> {
+        let requests = match packet {
+            RequestPacket::Single(single) => std::slice::from_ref(single),
+            RequestPacket::Batch(batch) => batch.as_slice(),
+        };
+        requests.iter().map(SerializedRequest::method).try_for_each(|method| self.check(method))
+    }
+}
+
+impl<S> Layer<S> for MethodFirewallLayer {
+    type Service = MethodFirewallService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MethodFirewallService { inner, config: self.clone() }
+    }
+}
+
+/// A Tower Service used by the [`MethodFirewallLayer`] that blocks requests to restricted
+/// methods.
+#[derive(Clone, Debug)]
+pub struct MethodFirewallService<S> {
+    inner: S,
+    config: MethodFirewallLayer,
+}
+
+impl<S> Service<RequestPacket> for MethodFirewallService<S>
+where
+    S: Service<RequestPacket, Future = TransportFut<'static>, Error = TransportError>
+        + Send
+        + 'static
+        + Clone,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        if let Err(err) = self.config.check_packet(&request) {
+            return Box::pin(async move { Err(TransportErrorKind::custom(err)) });
+        }
+        self.inner.call(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::{Id, Request};
+
+    #[test]
+    fn allowlist() {
+        let firewall = MethodFirewallLayer::new().allow(["eth_*", "net_version"]);
+        assert_eq!(firewall.check("eth_call"), Ok(()));
+        assert_eq!(firewall.check("net_version"), Ok(()));
+        assert_eq!(
+            firewall.check("net_peerCount"),
+            Err(MethodFirewallError::NotAllowed("net_peerCount".to_string()))
+        );
+        assert_eq!(
+            firewall.check("admin_peers"),
+            Err(MethodFirewallError::NotAllowed("admin_peers".to_string()))
+        );
+    }
+
+    #[test]
+    fn denylist() {
+        let firewall = MethodFirewallLayer::new().deny(SENSITIVE_METHODS);
+        assert_eq!(firewall.check("eth_call"), Ok(()));
+        assert_eq!(
+            firewall.check("admin_addPeer"),
+            Err(MethodFirewallError::Denied("admin_addPeer".to_string()))
+        );
+
+        // the denylist takes precedence over the allowlist
+        let firewall = MethodFirewallLayer::new().allow(["*"]).deny(["eth_sign*"]);
+        assert_eq!(firewall.check("eth_chainId"), Ok(()));
+        assert_eq!(
+            firewall.check("eth_signTypedData_v4"),
+            Err(MethodFirewallError::Denied("eth_signTypedData_v4".to_string()))
+        );
+    }
+
+    #[test]
+    fn batch() {
+        let request = |method: &'static str, id| {
+            Request::new(method, Id::Number(id), ()).serialize().unwrap()
+        };
+        let firewall = MethodFirewallLayer::new().deny(["debug_*"]);
+        let allowed: RequestPacket = [request("eth_blockNumber", 1)].into_iter().collect();
+        assert_eq!(firewall.check_packet(&allowed), Ok(()));
+
+        let blocked: RequestPacket =
+            [request("eth_blockNumber", 1), request("debug_setHead", 2)].into_iter().collect();
+        let err = firewall.check_packet(&blocked).unwrap_err();
+        assert_eq!(err.method(), "debug_setHead");
+    }
+}
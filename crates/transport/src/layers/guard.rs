@@ -0,0 +1,346 @@
+use crate::{TransportError, TransportErrorKind, TransportFut};
+use alloy_json_rpc::{
+    Id, RequestPacket, Response, ResponsePacket, ResponsePayload, SerializedRequest,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// An error returned by the [`ResponseGuardLayer`] when a response exceeds one of its limits.
+///
+/// The error is returned as a [`TransportErrorKind::Custom`] error, and can be recovered by
+/// downcasting its source.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ResponseGuardError {
+    /// The response is larger than the maximum response size.
+    #[error("response of {size} bytes exceeds the maximum size of {max} bytes")]
+    TooLarge {
+        /// The size of the response, in bytes.
+        size: usize,
+        /// The maximum response size, in bytes.
+        max: usize,
+    },
+    /// The response is nested deeper than the maximum nesting depth.
+    #[error("response exceeds the maximum JSON nesting depth of {max}")]
+    TooDeep {
+        /// The maximum nesting depth.
+        max: usize,
+    },
+    /// The response contains an array with more elements than allowed for the method.
+    #[error("response to {method} contains an array exceeding the maximum length of {max}")]
+    ArrayTooLong {
+        /// The method of the request.
+        method: String,
+        /// The maximum array length for the method.
+        max: usize,
+    },
+}
+
+/// A Transport Layer that rejects responses exceeding configurable size and complexity limits.
+///
+/// This protects services connecting to untrusted endpoints, e.g. user-supplied RPC URLs, from
+/// responses crafted to exhaust memory or CPU when they are deserialized. The following limits
+/// are supported, none of which is set by default:
+/// - the size of the response payloads, see [`with_max_response_size`],
+/// - the JSON nesting depth of the response payloads, see [`with_max_depth`],
+/// - the number of elements of the arrays in the responses to given methods, see
+///   [`with_max_array_len`].
+///
+/// Responses are checked after they were received and parsed into raw JSON-RPC responses by the
+/// transport, but before they are deserialized. For batch requests, the size limit applies to the
+/// whole batch.
+///
+/// [`with_max_response_size`]: Self::with_max_response_size
+/// [`with_max_depth`]: Self::with_max_depth
+/// [`with_max_array_len`]: Self::with_max_array_len
+#[derive(Clone, Debug, Default)]
+pub struct ResponseGuardLayer {
+    max_response_size: Option<usize>,
+    max_depth: Option<usize>,
+    max_array_lens: Arc<HashMap<String, usize>>,
+}
+
+impl ResponseGuardLayer {
+    /// Creates a new layer without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of the response payloads, in bytes.
+    pub const fn with_max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = Some(max_response_size);
+        self
+    }
+
+    /// Sets the maximum JSON nesting depth of the response payloads.
+    ///
+    /// Scalars have a depth of 0, and each enclosing array or object adds 1.
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets the maximum number of elements of any array in the responses to the given method,
+    /// e.g. the logs returned by `eth_getLogs`.
+    pub fn with_max_array_len(mut self, method: impl Into<String>, max_len: usize) -> Self {
+        Arc::make_mut(&mut self.max_array_lens).insert(method.into(), max_len);
+        self
+    }
+
+    /// Checks the response packet against the limits.
+    fn check(
+        &self,
+        methods: &HashMap<Id, String>,
+        packet: &ResponsePacket,
+    ) -> Result<(), ResponseGuardError> {
+        if let Some(max) = self.max_response_size {
+            let size = responses(packet).iter().map(payload_size).sum();
+            if size > max {
+                return Err(ResponseGuardError::TooLarge { size, max });
+            }
+        }
+
+        if self.max_depth.is_none() && self.max_array_lens.is_empty() {
+            return Ok(());
+        }
+        for response in responses(packet) {
+            let method = methods.get(&response.id);
+            let max_array_len = method.and_then(|method| self.max_array_lens.get(method)).copied();
+            let payloads = match &response.payload {
+                ResponsePayload::Success(result) => [Some(result), None],
+                ResponsePayload::Failure(err) => [None, err.data.as_ref()],
+            };
+            for payload in payloads.into_iter().flatten() {
+                check_shape(payload.get(), self.max_depth, max_array_len).map_err(|violation| {
+                    match violation {
+                        Violation::Depth(max) => ResponseGuardError::TooDeep { max },
+                        Violation::ArrayLen(max) => ResponseGuardError::ArrayTooLong {
+                            method: method.cloned().unwrap_or_default(),
+                            max,
+                        },
+                    }
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S> Layer<S> for ResponseGuardLayer {
+    type Service = ResponseGuardService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResponseGuardService { inner, config: self.clone() }
+    }
+}
+
+/// A Tower Service used by the [`ResponseGuardLayer`] that rejects responses exceeding its
+/// limits.
+#[derive(Clone, Debug)]
+pub struct ResponseGuardService<S> {
+    inner: S,
+    config: ResponseGuardLayer,
+}
+
+impl<S> Service<RequestPacket> for ResponseGuardService<S>
+where
+    S: Service<RequestPacket, Future = TransportFut<'static>, Error = TransportError>
+        + Send
+        + 'static
+        + Clone,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        // Methods are only needed to look up array limits.
+        let methods: HashMap<Id, String> = if self.config.max_array_lens.is_empty() {
+            HashMap::new()
+        } else {
+            requests(&request)
+                .iter()
+                .filter(|req| self.config.max_array_lens.contains_key(req.method()))
+                .map(|req| (req.id().clone(), req.method().to_string()))
+                .collect()
+        };
+        let response = self.inner.call(request);
+        let config = self.config.clone();
+        Box::pin(async move {
+            let response = response.await?;
+            config.check(&methods, &response).map_err(TransportErrorKind::custom)?;
+            Ok(response)
+        })
+    }
+}
+
+/// Returns the requests of a packet.
+fn requests(packet: &RequestPacket) -> &[SerializedRequest] {
+    match packet {
+        RequestPacket::Single(single) => std::slice::from_ref(single),
+        RequestPacket::Batch(batch) => batch,
+    }
+}
+
+/// Returns the responses of a packet.
+fn responses(packet: &ResponsePacket) -> &[Response] {
+    match packet {
+        ResponsePacket::Single(single) => std::slice::from_ref(single),
+        ResponsePacket::Batch(batch) => batch,
+    }
+}
+
+/// Returns the size of the JSON payload of a response.
+fn payload_size(response: &Response) -> usize {
+    match &response.payload {
+        ResponsePayload::Success(result) => result.get().len(),
+        ResponsePayload::Failure(err) => {
+            err.message.len() + err.data.as_ref().map_or(0, |data| data.get().len())
+        }
+    }
+}
+
+/// A limit exceeded by a JSON value.
+#[derive(Debug, PartialEq, Eq)]
+enum Violation {
+    Depth(usize),
+    ArrayLen(usize),
+}
+
+/// Checks the nesting depth and array lengths of a valid JSON value without parsing it.
+fn check_shape(
+    json: &str,
+    max_depth: Option<usize>,
+    max_array_len: Option<usize>,
+) -> Result<(), Violation> {
+    // The number of elements of each enclosing container, `None` for objects.
+    let mut stack: Vec<Option<usize>> = Vec::new();
+    // Whether the current array was just opened, i.e. the next value is its first element.
+    let mut array_start = false;
+    let mut bytes = json.bytes();
+    while let Some(byte) = bytes.next() {
+        if byte.is_ascii_whitespace() {
+            continue;
+        }
+        if std::mem::take(&mut array_start) && byte != b']' {
+            if let Some(Some(len)) = stack.last_mut() {
+                *len = 1;
+            }
+        }
+        match byte {
+            b'"' => {
+                while let Some(byte) = bytes.next() {
+                    match byte {
+                        b'\\' => {
+                            bytes.next();
+                        }
+                        b'"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            b'[' | b'{' => {
+                stack.push((byte == b'[').then_some(0));
+                array_start = byte == b'[';
+                if let Some(max) = max_depth.filter(|max| stack.len() > *max) {
+                    return Err(Violation::Depth(max));
+                }
+            }
+            b']' | b'}' => {
+                stack.pop();
+            }
+            b',' => {
+                if let Some(Some(len)) = stack.last_mut() {
+                    *len += 1;
+                }
+            }
+            _ => {}
+        }
+        if let (Some(max), Some(Some(len))) = (max_array_len, stack.last()) {
+            if *len > max {
+                return Err(Violation::ArrayLen(max));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use serde_json::value::RawValue;
+
+    fn response(id: u64, json: &str) -> Response {
+        Response {
+            id: Id::Number(id),
+            payload: ResponsePayload::Success(RawValue::from_string(json.to_string()).unwrap()),
+        }
+    }
+
+    #[test]
+    fn shape() {
+        assert_eq!(check_shape(r#""0x1""#, Some(0), Some(0)), Ok(()));
+        assert_eq!(check_shape("[[1, 2], {}]", Some(2), Some(2)), Ok(()));
+        assert_eq!(check_shape("[[1, 2], {}]", Some(1), None), Err(Violation::Depth(1)));
+        assert_eq!(check_shape("[[1, 2, 3], {}]", None, Some(2)), Err(Violation::ArrayLen(2)));
+        assert_eq!(check_shape("[ ]", None, Some(0)), Ok(()));
+        assert_eq!(check_shape("[0]", None, Some(0)), Err(Violation::ArrayLen(0)));
+        // brackets and commas in strings are ignored
+        assert_eq!(check_shape(r#"["[[,", "\"[,"]"#, Some(1), Some(2)), Ok(()));
+        // commas in nested objects do not count towards the array length
+        assert_eq!(check_shape(r#"[{"a": 1, "b": 2, "c": 3}]"#, None, Some(1)), Ok(()));
+    }
+
+    #[test]
+    fn check_limits() {
+        let methods = HashMap::from([(Id::Number(1), "eth_getLogs".to_string())]);
+        let logs = ResponsePacket::Single(response(1, "[1, 2, 3]"));
+
+        let layer = ResponseGuardLayer::new();
+        assert_eq!(layer.check(&methods, &logs), Ok(()));
+
+        let layer = ResponseGuardLayer::new().with_max_response_size(8);
+        assert_eq!(
+            layer.check(&methods, &logs),
+            Err(ResponseGuardError::TooLarge { size: 9, max: 8 })
+        );
+
+        let layer = ResponseGuardLayer::new().with_max_depth(0);
+        assert_eq!(layer.check(&methods, &logs), Err(ResponseGuardError::TooDeep { max: 0 }));
+
+        let layer = ResponseGuardLayer::new().with_max_array_len("eth_getLogs", 2);
+        assert_eq!(
+            layer.check(&methods, &logs),
+            Err(ResponseGuardError::ArrayTooLong { method: "eth_getLogs".to_string(), max: 2 })
+        );
+        let other = ResponsePacket::Single(response(2, "[1, 2, 3]"));
+        assert_eq!(layer.check(&methods, &other), Ok(()));
+    }
+
+    #[test]
+    fn check_error_data() {
+        let data = RawValue::from_string("[[[]]]".to_string()).unwrap();
+        let packet = ResponsePacket::Single(Response {
+            id: Id::Number(1),
+            payload: ResponsePayload::Failure(ErrorPayload {
+                code: 3,
+                message: "execution reverted".into(),
+                data: Some(data),
+            }),
+        });
+        let layer = ResponseGuardLayer::new().with_max_depth(2);
+        assert_eq!(
+            layer.check(&HashMap::new(), &packet),
+            Err(ResponseGuardError::TooDeep { max: 2 })
+        );
+    }
+}
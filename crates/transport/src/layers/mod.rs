@@ -1,6 +1,23 @@
 //! Module for housing transport layers.
 
+mod consistency;
+mod firewall;
+mod guard;
+mod priority;
 mod retry;
 
+/// ConsistencyCheckLayer
+pub use consistency::{
+    ConsistencyCheckLayer, ConsistencyCheckService, ConsistencyStats, Divergence,
+    DEFAULT_MIRRORED_METHODS,
+};
+/// MethodFirewallLayer
+pub use firewall::{
+    MethodFirewallError, MethodFirewallLayer, MethodFirewallService, SENSITIVE_METHODS,
+};
+/// ResponseGuardLayer
+pub use guard::{ResponseGuardError, ResponseGuardLayer, ResponseGuardService};
+/// PriorityQueueLayer
+pub use priority::{PriorityQueueLayer, PriorityQueueService};
 /// RetryBackoffLayer
 pub use retry::{RateLimitRetryPolicy, RetryBackoffLayer, RetryBackoffService, RetryPolicy};
@@ -0,0 +1,312 @@
+use crate::{TransportError, TransportFut};
+use alloy_json_rpc::{RequestPacket, RequestPriority, ResponsePacket};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashSet},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, Waker},
+};
+use tower::{Layer, Service};
+
+/// A [`Layer`] that limits the number of in-flight requests and queues excess requests by their
+/// [`RequestPriority`].
+///
+/// Queued requests are dispatched highest priority first, and in order of arrival within a
+/// priority. Additional slots can be reserved for [`RequestPriority::Critical`] requests with
+/// [`with_critical_reserve`](Self::with_critical_reserve), so that critical requests are not
+/// delayed even if the regular slots are saturated by long-running requests.
+#[derive(Clone, Copy, Debug)]
+pub struct PriorityQueueLayer {
+    max_in_flight: usize,
+    critical_reserve: usize,
+}
+
+impl PriorityQueueLayer {
+    /// Creates a new layer allowing at most `max_in_flight` concurrent requests.
+    pub const fn new(max_in_flight: usize) -> Self {
+        Self { max_in_flight, critical_reserve: 0 }
+    }
+
+    /// Reserves `reserve` additional in-flight slots for critical requests.
+    pub const fn with_critical_reserve(mut self, reserve: usize) -> Self {
+        self.critical_reserve = reserve;
+        self
+    }
+}
+
+impl<S> Layer<S> for PriorityQueueLayer {
+    type Service = PriorityQueueService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PriorityQueueService {
+            inner,
+            queue: Arc::new(PriorityQueue {
+                max_in_flight: self.max_in_flight.max(1),
+                critical_reserve: self.critical_reserve,
+                state: Default::default(),
+            }),
+        }
+    }
+}
+
+/// A [`Service`] that queues requests by priority, see [`PriorityQueueLayer`].
+#[derive(Clone, Debug)]
+pub struct PriorityQueueService<S> {
+    inner: S,
+    queue: Arc<PriorityQueue>,
+}
+
+impl<S> PriorityQueueService<S> {
+    /// Returns the number of in-flight requests.
+    pub fn in_flight(&self) -> usize {
+        self.queue.lock().in_flight
+    }
+
+    /// Returns the number of queued requests.
+    pub fn queued(&self) -> usize {
+        self.queue.lock().waiting.len()
+    }
+}
+
+impl<S> Service<RequestPacket> for PriorityQueueService<S>
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        >
+        + Send
+        + 'static
+        + Clone,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let inner = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, inner);
+        let acquire = Acquire {
+            queue: self.queue.clone(),
+            priority: request.priority(),
+            ticket: None,
+            done: false,
+        };
+        Box::pin(async move {
+            let _permit = acquire.await;
+            inner.call(request).await
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    in_flight: usize,
+    next_ticket: u64,
+    waiting: BTreeMap<(Reverse<RequestPriority>, u64), Waker>,
+    granted: HashSet<u64>,
+}
+
+#[derive(Debug)]
+struct PriorityQueue {
+    max_in_flight: usize,
+    critical_reserve: usize,
+    state: Mutex<QueueState>,
+}
+
+impl PriorityQueue {
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    const fn limit(&self, priority: RequestPriority) -> usize {
+        match priority {
+            RequestPriority::Critical => self.max_in_flight + self.critical_reserve,
+            _ => self.max_in_flight,
+        }
+    }
+
+    /// Releases an in-flight slot and hands out free slots to the queued requests.
+    fn release(&self) {
+        let mut guard = self.lock();
+        let state = &mut *guard;
+        state.in_flight -= 1;
+        // limits only grow with the priority, so if the first waiter does not fit, none does
+        while let Some(entry) = state.waiting.first_entry() {
+            let (Reverse(priority), ticket) = *entry.key();
+            if state.in_flight >= self.limit(priority) {
+                break;
+            }
+            entry.remove().wake();
+            state.granted.insert(ticket);
+            state.in_flight += 1;
+        }
+    }
+}
+
+/// Future resolving once the request may be sent.
+struct Acquire {
+    queue: Arc<PriorityQueue>,
+    priority: RequestPriority,
+    ticket: Option<u64>,
+    done: bool,
+}
+
+impl Future for Acquire {
+    type Output = Permit;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let queue = this.queue.clone();
+        let mut state = queue.lock();
+
+        match this.ticket {
+            None => {
+                // do not overtake queued requests of the same or a higher priority
+                let overtakes = state
+                    .waiting
+                    .first_key_value()
+                    .map_or(true, |((Reverse(first), _), _)| this.priority > *first);
+                if overtakes && state.in_flight < queue.limit(this.priority) {
+                    state.in_flight += 1;
+                } else {
+                    let ticket = state.next_ticket;
+                    state.next_ticket += 1;
+                    state.waiting.insert((Reverse(this.priority), ticket), cx.waker().clone());
+                    this.ticket = Some(ticket);
+                    return Poll::Pending;
+                }
+            }
+            Some(ticket) => {
+                if !state.granted.remove(&ticket) {
+                    if let Some(waker) = state.waiting.get_mut(&(Reverse(this.priority), ticket)) {
+                        waker.clone_from(cx.waker());
+                    }
+                    return Poll::Pending;
+                }
+            }
+        }
+
+        this.done = true;
+        Poll::Ready(Permit { queue: this.queue.clone() })
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        let Some(ticket) = self.ticket.filter(|_| !self.done) else { return };
+        let mut state = self.queue.lock();
+        if state.waiting.remove(&(Reverse(self.priority), ticket)).is_none()
+            && state.granted.remove(&ticket)
+        {
+            // the slot was handed to this request after it was cancelled
+            drop(state);
+            self.queue.release();
+        }
+    }
+}
+
+/// An in-flight slot, released on drop.
+struct Permit {
+    queue: Arc<PriorityQueue>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.queue.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::Wake;
+
+    struct Flag(std::sync::atomic::AtomicBool);
+
+    impl Wake for Flag {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    fn queue(max_in_flight: usize, critical_reserve: usize) -> Arc<PriorityQueue> {
+        Arc::new(PriorityQueue { max_in_flight, critical_reserve, state: Default::default() })
+    }
+
+    fn acquire(queue: &Arc<PriorityQueue>, priority: RequestPriority) -> Pin<Box<Acquire>> {
+        Box::pin(Acquire { queue: queue.clone(), priority, ticket: None, done: false })
+    }
+
+    fn poll(acquire: &mut Pin<Box<Acquire>>) -> Option<Permit> {
+        let waker = Waker::from(Arc::new(Flag(Default::default())));
+        match acquire.as_mut().poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(permit) => Some(permit),
+            Poll::Pending => None,
+        }
+    }
+
+    #[test]
+    fn dispatches_by_priority() {
+        let queue = queue(1, 0);
+        let first = poll(&mut acquire(&queue, RequestPriority::Normal)).unwrap();
+
+        let mut background = acquire(&queue, RequestPriority::Background);
+        let mut normal = acquire(&queue, RequestPriority::Normal);
+        let mut critical = acquire(&queue, RequestPriority::Critical);
+        assert!(poll(&mut background).is_none());
+        assert!(poll(&mut normal).is_none());
+        assert!(poll(&mut critical).is_none());
+        assert_eq!(queue.lock().waiting.len(), 3);
+
+        drop(first);
+        assert!(poll(&mut background).is_none());
+        assert!(poll(&mut normal).is_none());
+        let permit = poll(&mut critical).unwrap();
+
+        drop(permit);
+        assert!(poll(&mut background).is_none());
+        let permit = poll(&mut normal).unwrap();
+
+        drop(permit);
+        let permit = poll(&mut background).unwrap();
+        drop(permit);
+        assert_eq!(queue.lock().in_flight, 0);
+    }
+
+    #[test]
+    fn critical_reserve() {
+        let queue = queue(1, 1);
+        let _normal = poll(&mut acquire(&queue, RequestPriority::Normal)).unwrap();
+        assert!(poll(&mut acquire(&queue, RequestPriority::Normal)).is_none());
+
+        let critical = poll(&mut acquire(&queue, RequestPriority::Critical)).unwrap();
+        assert!(poll(&mut acquire(&queue, RequestPriority::Critical)).is_none());
+        drop(critical);
+        assert_eq!(queue.lock().in_flight, 1);
+    }
+
+    #[test]
+    fn cancelled_requests_release_slots() {
+        let queue = queue(1, 0);
+        let first = poll(&mut acquire(&queue, RequestPriority::Normal)).unwrap();
+
+        let mut cancelled = acquire(&queue, RequestPriority::Normal);
+        let mut waiting = acquire(&queue, RequestPriority::Normal);
+        assert!(poll(&mut cancelled).is_none());
+        assert!(poll(&mut waiting).is_none());
+
+        // the slot is granted to the first waiter, which is dropped before it is polled again
+        drop(first);
+        drop(cancelled);
+        assert!(poll(&mut waiting).is_some());
+        assert_eq!(queue.lock().in_flight, 0);
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     error::{RpcErrorExt, TransportError, TransportErrorKind},
     TransportFut,
 };
-use alloy_json_rpc::{RequestPacket, ResponsePacket};
+use alloy_json_rpc::{RequestPacket, RequestPriority, ResponsePacket};
 use std::{
     sync::{
         atomic::{AtomicU32, Ordering},
@@ -23,6 +23,14 @@ use tokio::time::sleep;
 /// A Transport Layer that is responsible for retrying requests based on the
 /// error type. See [`TransportError`].
 ///
+/// With the default [RateLimitRetryPolicy], rate limit errors, HTTP 429 and 503 responses and
+/// connection resets are retried. The backoff between retries is the initial backoff, unless the
+/// error contains a backoff hint, e.g. the `Retry-After` header of an HTTP response. It can be
+/// made to grow exponentially with
+/// [`with_exponential_backoff`](RetryBackoffLayer::with_exponential_backoff), and randomized
+/// with [`with_jitter`](RetryBackoffLayer::with_jitter) to avoid retrying concurrent requests
+/// in lockstep.
+///
 /// TransportError: crate::error::TransportError
 #[derive(Debug, Clone)]
 pub struct RetryBackoffLayer<P: RetryPolicy = RateLimitRetryPolicy> {
@@ -34,6 +42,10 @@ pub struct RetryBackoffLayer<P: RetryPolicy = RateLimitRetryPolicy> {
     compute_units_per_second: u64,
     /// The [RetryPolicy] to use. Defaults to [RateLimitRetryPolicy]
     policy: P,
+    /// The maximum backoff if the backoff grows exponentially
+    max_backoff: Option<Duration>,
+    /// The fraction of the backoff that is randomized
+    jitter: f64,
 }
 
 impl RetryBackoffLayer {
@@ -43,12 +55,12 @@ impl RetryBackoffLayer {
         initial_backoff: u64,
         compute_units_per_second: u64,
     ) -> Self {
-        Self {
+        Self::new_with_policy(
             max_rate_limit_retries,
             initial_backoff,
             compute_units_per_second,
-            policy: RateLimitRetryPolicy,
-        }
+            RateLimitRetryPolicy,
+        )
     }
 }
 
@@ -60,7 +72,31 @@ impl<P: RetryPolicy> RetryBackoffLayer<P> {
         compute_units_per_second: u64,
         policy: P,
     ) -> Self {
-        Self { max_rate_limit_retries, initial_backoff, compute_units_per_second, policy }
+        Self {
+            max_rate_limit_retries,
+            initial_backoff,
+            compute_units_per_second,
+            policy,
+            max_backoff: None,
+            jitter: 0.0,
+        }
+    }
+
+    /// Doubles the backoff after every retry, starting at the initial backoff, up to
+    /// `max_backoff`.
+    ///
+    /// Backoff hints contained in the errors take precedence.
+    pub const fn with_exponential_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = Some(max_backoff);
+        self
+    }
+
+    /// Randomizes the backoff by adding up to the given fraction of it, between `0.0` and `1.0`.
+    ///
+    /// Defaults to `0.0`, i.e. no jitter.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
     }
 }
 
@@ -101,6 +137,8 @@ impl<S, P: RetryPolicy + Clone> Layer<S> for RetryBackoffLayer<P> {
             max_rate_limit_retries: self.max_rate_limit_retries,
             initial_backoff: self.initial_backoff,
             compute_units_per_second: self.compute_units_per_second,
+            max_backoff: self.max_backoff,
+            jitter: self.jitter,
             requests_enqueued: Arc::new(AtomicU32::new(0)),
         }
     }
@@ -120,6 +158,10 @@ pub struct RetryBackoffService<S, P: RetryPolicy = RateLimitRetryPolicy> {
     initial_backoff: u64,
     /// The number of compute units per second for this service
     compute_units_per_second: u64,
+    /// The maximum backoff if the backoff grows exponentially
+    max_backoff: Option<Duration>,
+    /// The fraction of the backoff that is randomized
+    jitter: f64,
     /// The number of requests currently enqueued
     requests_enqueued: Arc<AtomicU32>,
 }
@@ -128,6 +170,27 @@ impl<S, P: RetryPolicy> RetryBackoffService<S, P> {
     const fn initial_backoff(&self) -> Duration {
         Duration::from_millis(self.initial_backoff)
     }
+
+    /// Returns the backoff before the given retry, starting at 1, without jitter.
+    fn backoff(&self, retry: u32) -> Duration {
+        let initial = self.initial_backoff();
+        match self.max_backoff {
+            Some(max_backoff) => {
+                let factor = 1u32.checked_shl(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+                initial.saturating_mul(factor).min(max_backoff)
+            }
+            None => initial,
+        }
+    }
+
+    /// Adds a random jitter to the backoff.
+    fn with_jitter(&self, backoff: Duration) -> Duration {
+        if self.jitter > 0.0 {
+            backoff + backoff.mul_f64(self.jitter * random_fraction())
+        } else {
+            backoff
+        }
+    }
 }
 
 impl<S, P> Service<RequestPacket> for RetryBackoffService<S, P>
@@ -154,6 +217,7 @@ where
         let mut inner = std::mem::replace(&mut self.inner, inner);
         Box::pin(async move {
             let ahead_in_queue = this.requests_enqueued.fetch_add(1, Ordering::SeqCst) as u64;
+            let priority = request.priority();
             let mut rate_limit_retry_number: u32 = 0;
             loop {
                 let err;
@@ -187,7 +251,9 @@ where
                     // try to extract the requested backoff from the error or compute the next
                     // backoff based on retry count
                     let backoff_hint = this.policy.backoff_hint(&err);
-                    let next_backoff = backoff_hint.unwrap_or_else(|| this.initial_backoff());
+                    let next_backoff = this.with_jitter(
+                        backoff_hint.unwrap_or_else(|| this.backoff(rate_limit_retry_number)),
+                    );
 
                     // requests are usually weighted and can vary from 10 CU to several 100 CU,
                     // cheaper requests are more common some example alchemy
@@ -199,13 +265,20 @@ where
                     // (coming from forking mode) assuming here that storage request will be the
                     // driver for Rate limits we choose `17` as the average cost
                     // of any request
+                    //
+                    // critical requests do not wait for the budget of the queued requests
                     const AVG_COST: u64 = 17u64;
-                    let seconds_to_wait_for_compute_budget = compute_unit_offset_in_secs(
-                        AVG_COST,
-                        this.compute_units_per_second,
-                        current_queued_reqs,
-                        ahead_in_queue,
-                    );
+                    let seconds_to_wait_for_compute_budget =
+                        if priority == RequestPriority::Critical {
+                            0
+                        } else {
+                            compute_unit_offset_in_secs(
+                                AVG_COST,
+                                this.compute_units_per_second,
+                                current_queued_reqs,
+                                ahead_in_queue,
+                            )
+                        };
                     let total_backoff = next_backoff
                         + std::time::Duration::from_secs(seconds_to_wait_for_compute_budget);
 
@@ -227,6 +300,15 @@ where
     }
 }
 
+/// Returns a pseudo-random number in `[0, 1)`, which is sufficient to spread out retries.
+fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    // every `RandomState` is seeded with different keys
+    let hash = std::collections::hash_map::RandomState::new().build_hasher().finish();
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
 /// Calculates an offset in seconds by taking into account the number of currently queued requests,
 /// number of requests that were ahead in the queue when the request was first issued, the average
 /// cost a weighted request (heuristic), and the number of available compute units per seconds.
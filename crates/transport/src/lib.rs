@@ -15,6 +15,12 @@ pub use connect::TransportConnect;
 mod common;
 pub use common::Authorization;
 
+mod fallback;
+pub use fallback::{EndpointHealth, FallbackTransport};
+
+mod quorum;
+pub use quorum::{Quorum, QuorumError, QuorumTransport};
+
 mod error;
 #[doc(hidden)]
 pub use error::TransportErrorKind;
@@ -0,0 +1,354 @@
+use crate::{
+    fallback::should_fail_over, BoxTransport, TransportError, TransportErrorKind, TransportFut,
+};
+use alloy_json_rpc::{Id, RequestPacket, ResponsePacket, ResponsePayload};
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use serde_json::Value;
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tower::Service;
+use tracing::debug;
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::timeout;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::timeout;
+
+/// The agreement required by a [`QuorumTransport`] before a response is returned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Quorum {
+    /// Return the fastest response, without checking agreement.
+    Race,
+    /// More than half of the endpoints must return the same response.
+    #[default]
+    Majority,
+    /// All endpoints must return the same response.
+    All,
+    /// At least this many endpoints must return the same response.
+    AtLeast(usize),
+}
+
+impl Quorum {
+    /// Returns the number of agreeing responses required out of the given number of endpoints.
+    pub const fn required(&self, endpoints: usize) -> usize {
+        match self {
+            Self::Race => 1,
+            Self::Majority => endpoints / 2 + 1,
+            Self::All => endpoints,
+            Self::AtLeast(required) => {
+                if *required == 0 {
+                    1
+                } else {
+                    *required
+                }
+            }
+        }
+    }
+}
+
+/// An error returned by the [`QuorumTransport`] when too few endpoints agree on a response.
+///
+/// The error is returned as a [`TransportErrorKind::Custom`] error, and can be recovered by
+/// downcasting its source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("quorum of {required} not reached: at most {agreeing} responses agreed, {failed} failed")]
+#[non_exhaustive]
+pub struct QuorumError {
+    /// The number of agreeing responses required.
+    pub required: usize,
+    /// The largest number of responses that agreed.
+    pub agreeing: usize,
+    /// The number of endpoints that failed.
+    pub failed: usize,
+}
+
+/// A transport that sends each request to all of its endpoints concurrently, and returns a
+/// response once enough endpoints agree on it.
+///
+/// With [`Quorum::Race`], the fastest response is returned. Otherwise, responses are compared by
+/// their JSON value, and the first response returned by the [required](Quorum::required) number of
+/// endpoints is returned, without waiting for the remaining endpoints. This allows trust-minimized
+/// reads, where a single faulty or malicious endpoint cannot forge a response. Error responses
+/// agree if they have the same code and data.
+///
+/// Endpoints that fail with a transport error, time out, or are rate limited do not count towards
+/// the quorum. If the quorum can no longer be reached, the request fails with a [`QuorumError`],
+/// or with the last failure if no endpoint responded.
+///
+/// The transport can be passed to `ClientBuilder::transport` like any other transport. Its
+/// endpoints are [`BoxTransport`]s, so they may use different transport types and layers.
+#[derive(Clone, Debug)]
+pub struct QuorumTransport {
+    transports: Arc<[BoxTransport]>,
+    quorum: Quorum,
+    request_timeout: Duration,
+}
+
+impl QuorumTransport {
+    /// Creates a new quorum transport over the given transports, requiring a
+    /// [majority](Quorum::Majority) of them to agree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no transport is given.
+    pub fn new<I>(transports: I) -> Self
+    where
+        I: IntoIterator<Item = BoxTransport>,
+    {
+        let transports: Arc<[BoxTransport]> = transports.into_iter().collect();
+        assert!(!transports.is_empty(), "quorum transport requires at least one transport");
+        Self { transports, quorum: Quorum::default(), request_timeout: Duration::from_secs(10) }
+    }
+
+    /// Sets the required agreement. Defaults to [`Quorum::Majority`].
+    pub const fn with_quorum(mut self, quorum: Quorum) -> Self {
+        self.quorum = quorum;
+        self
+    }
+
+    /// Sets the timeout of a request to a single endpoint, after which the endpoint is considered
+    /// failed. Defaults to 10 seconds.
+    pub const fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Returns the required agreement.
+    pub const fn quorum(&self) -> Quorum {
+        self.quorum
+    }
+
+    /// Returns the number of endpoints.
+    pub fn len(&self) -> usize {
+        self.transports.len()
+    }
+
+    /// Returns `true` if there are no endpoints, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.transports.is_empty()
+    }
+
+    async fn call_endpoint(
+        &self,
+        index: usize,
+        request: RequestPacket,
+    ) -> (usize, Result<ResponsePacket, TransportError>) {
+        let mut transport = self.transports[index].clone();
+        let res = match timeout(self.request_timeout, transport.call(request)).await {
+            Ok(res) => res,
+            Err(_) => Err(TransportErrorKind::custom_str(&format!(
+                "request to quorum endpoint {index} timed out after {:?}",
+                self.request_timeout
+            ))),
+        };
+        (index, res)
+    }
+}
+
+/// The value of a response packet that is compared for agreement, ordered by request id.
+type ResponseKey = Vec<(Id, Result<Value, (i64, Option<Value>)>)>;
+
+/// Returns the value of the response packet that is compared for agreement.
+fn response_key(packet: &ResponsePacket) -> Option<ResponseKey> {
+    let responses = match packet {
+        ResponsePacket::Single(single) => std::slice::from_ref(single),
+        ResponsePacket::Batch(batch) => batch.as_slice(),
+    };
+    let mut key = responses
+        .iter()
+        .map(|response| {
+            let value = match &response.payload {
+                ResponsePayload::Success(result) => Ok(serde_json::from_str(result.get()).ok()?),
+                ResponsePayload::Failure(err) => Err((
+                    err.code,
+                    err.data.as_ref().and_then(|data| serde_json::from_str(data.get()).ok()),
+                )),
+            };
+            Some((response.id.clone(), value))
+        })
+        .collect::<Option<ResponseKey>>()?;
+    key.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Some(key)
+}
+
+impl Service<RequestPacket> for QuorumTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Endpoints are polled when the request is sent.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let this = self.clone();
+        Box::pin(async move {
+            let required = this.quorum.required(this.len());
+            let mut pending: FuturesUnordered<_> =
+                (0..this.len()).map(|index| this.call_endpoint(index, request.clone())).collect();
+
+            // The distinct responses received so far, with the number of endpoints returning each.
+            let mut responses: Vec<(ResponseKey, usize)> = Vec::new();
+            let mut agreeing = 0;
+            let mut failed = 0;
+            let mut last_err = None;
+            while let Some((index, res)) = pending.next().await {
+                if should_fail_over(&res) {
+                    debug!(index, "quorum endpoint failed");
+                    failed += 1;
+                    last_err = Some(res);
+                } else {
+                    let response = res?;
+                    if required == 1 {
+                        return Ok(response);
+                    }
+                    let Some(key) = response_key(&response) else {
+                        debug!(index, "quorum endpoint returned an unparsable response");
+                        failed += 1;
+                        continue;
+                    };
+                    let count = match responses.iter_mut().find(|(other, _)| *other == key) {
+                        Some((_, count)) => {
+                            *count += 1;
+                            *count
+                        }
+                        None => {
+                            responses.push((key, 1));
+                            1
+                        }
+                    };
+                    if count >= required {
+                        return Ok(response);
+                    }
+                    agreeing = agreeing.max(count);
+                }
+                if agreeing + pending.len() < required {
+                    break;
+                }
+            }
+
+            match last_err {
+                Some(res) if responses.is_empty() => res,
+                _ => Err(TransportErrorKind::custom(QuorumError { required, agreeing, failed })),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::{Request, Response, RpcError};
+    use serde_json::value::RawValue;
+
+    /// An endpoint that responds to every request with a fixed result after a delay, or fails if
+    /// it has no result.
+    #[derive(Clone, Debug)]
+    struct MockEndpoint {
+        result: Option<&'static str>,
+        delay: Duration,
+    }
+
+    impl MockEndpoint {
+        fn transport(result: Option<&'static str>, delay_ms: u64) -> BoxTransport {
+            BoxTransport::new(Self { result, delay: Duration::from_millis(delay_ms) })
+        }
+    }
+
+    impl Service<RequestPacket> for MockEndpoint {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: RequestPacket) -> Self::Future {
+            let this = self.clone();
+            Box::pin(async move {
+                tokio::time::sleep(this.delay).await;
+                let result = this.result.ok_or_else(TransportErrorKind::backend_gone)?;
+                Ok(ResponsePacket::Single(Response {
+                    id: Id::Number(1),
+                    payload: ResponsePayload::Success(
+                        RawValue::from_string(result.to_string()).unwrap(),
+                    ),
+                }))
+            })
+        }
+    }
+
+    fn request() -> RequestPacket {
+        Request::new("eth_blockNumber", Id::Number(1), ()).serialize().unwrap().into()
+    }
+
+    fn result(packet: ResponsePacket) -> String {
+        match packet {
+            ResponsePacket::Single(Response {
+                payload: ResponsePayload::Success(result), ..
+            }) => result.get().to_string(),
+            _ => panic!("unexpected response"),
+        }
+    }
+
+    #[test]
+    fn required() {
+        assert_eq!(Quorum::Race.required(3), 1);
+        assert_eq!(Quorum::Majority.required(3), 2);
+        assert_eq!(Quorum::Majority.required(4), 3);
+        assert_eq!(Quorum::All.required(3), 3);
+        assert_eq!(Quorum::AtLeast(0).required(3), 1);
+    }
+
+    #[tokio::test]
+    async fn race() {
+        let mut transport = QuorumTransport::new([
+            MockEndpoint::transport(Some("\"0x1\""), 50),
+            MockEndpoint::transport(None, 0),
+            MockEndpoint::transport(Some("\"0x2\""), 10),
+        ])
+        .with_quorum(Quorum::Race);
+        assert_eq!(result(transport.call(request()).await.unwrap()), "\"0x2\"");
+    }
+
+    #[tokio::test]
+    async fn majority() {
+        // the fastest response is outvoted, and whitespace does not affect agreement
+        let mut transport = QuorumTransport::new([
+            MockEndpoint::transport(Some(r#"{"a":1}"#), 0),
+            MockEndpoint::transport(Some(r#"{"a": 2}"#), 10),
+            MockEndpoint::transport(None, 0),
+            MockEndpoint::transport(Some(r#"{"a":2}"#), 20),
+        ])
+        .with_quorum(Quorum::AtLeast(2));
+        assert_eq!(result(transport.call(request()).await.unwrap()), r#"{"a":2}"#);
+    }
+
+    #[tokio::test]
+    async fn quorum_not_reached() {
+        let mut transport = QuorumTransport::new([
+            MockEndpoint::transport(Some("\"0x1\""), 0),
+            MockEndpoint::transport(Some("\"0x2\""), 0),
+            MockEndpoint::transport(None, 0),
+        ]);
+        let err = match transport.call(request()).await.unwrap_err() {
+            RpcError::Transport(TransportErrorKind::Custom(err)) => err,
+            err => panic!("unexpected error: {err}"),
+        };
+        assert_eq!(
+            err.downcast_ref::<QuorumError>(),
+            Some(&QuorumError { required: 2, agreeing: 1, failed: 1 })
+        );
+
+        // without any response, the last failure is returned
+        let mut transport = QuorumTransport::new([MockEndpoint::transport(None, 0)]);
+        let err = transport.call(request()).await.unwrap_err();
+        assert!(matches!(err, RpcError::Transport(TransportErrorKind::BackendGone)));
+    }
+}
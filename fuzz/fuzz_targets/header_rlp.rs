@@ -0,0 +1,15 @@
+#![no_main]
+
+use alloy_consensus::Header;
+use alloy_rlp::{Decodable, Encodable};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(header) = Header::decode(&mut &data[..]) {
+        let encoded = alloy_rlp::encode(&header);
+        assert_eq!(encoded.len(), header.length());
+        let decoded = Header::decode(&mut encoded.as_slice()).expect("re-decoding failed");
+        assert_eq!(decoded, header);
+        let _ = header.hash_slow();
+    }
+});
@@ -0,0 +1,40 @@
+#![no_main]
+
+use alloy_json_rpc::PubSubItem;
+use alloy_transport_ipc::ReadJsonStream;
+use futures::StreamExt;
+use libfuzzer_sys::fuzz_target;
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// A reader returning the data in chunks of a fixed size, to exercise partial reads.
+struct Chunked<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+impl AsyncRead for Chunked<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let len = self.chunk.min(self.data.len()).min(buf.remaining());
+        let (head, tail) = self.data.split_at(len);
+        buf.put_slice(head);
+        self.data = tail;
+        Poll::Ready(Ok(()))
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&chunk, data)) = data.split_first() else { return };
+    let reader = Chunked { data, chunk: chunk.max(1) as usize };
+    let stream = ReadJsonStream::<_, PubSubItem>::from(reader);
+    // The stream must terminate once the reader is exhausted.
+    futures::executor::block_on(stream.count());
+});
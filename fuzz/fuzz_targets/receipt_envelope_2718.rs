@@ -0,0 +1,15 @@
+#![no_main]
+
+use alloy_consensus::ReceiptEnvelope;
+use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(receipt) = ReceiptEnvelope::decode_2718(&mut &data[..]) {
+        let encoded = receipt.encoded_2718();
+        assert_eq!(encoded.len(), receipt.encode_2718_len());
+        let decoded =
+            ReceiptEnvelope::decode_2718(&mut encoded.as_slice()).expect("re-decoding failed");
+        assert_eq!(decoded, receipt);
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use alloy_consensus::TxEnvelope;
+use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(tx) = TxEnvelope::decode_2718(&mut &data[..]) {
+        // Everything that decodes must encode back into a decodable envelope.
+        let encoded = tx.encoded_2718();
+        assert_eq!(encoded.len(), tx.encode_2718_len());
+        let decoded = TxEnvelope::decode_2718(&mut encoded.as_slice()).expect("re-decoding failed");
+        assert_eq!(decoded.encoded_2718(), encoded);
+    }
+
+    let _ = TxEnvelope::network_decode(&mut &data[..]);
+});
@@ -0,0 +1,54 @@
+//! Generates seed corpora for the fuzz targets from arbitrary, well-formed values.
+//!
+//! Usage: `cargo run --bin generate-corpus [count]`
+
+use alloy_consensus::{Header, ReceiptEnvelope, TxEnvelope};
+use alloy_eips::eip2718::Encodable2718;
+use arbitrary::{Arbitrary, Unstructured};
+use rand::Rng;
+use std::{fs, path::Path};
+
+const IPC_SEEDS: &[&str] = &[
+    r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#,
+    r#"{"jsonrpc":"2.0","id":2,"error":{"code":-32000,"message":"execution reverted"}}"#,
+    r#"{"jsonrpc":"2.0","method":"eth_subscription","params":{"subscription":"0xcd0c3e8af590364c09d0fa6a1210faf5","result":{"number":"0x1"}}}"#,
+];
+
+fn main() {
+    let count = std::env::args().nth(1).map_or(256, |n| n.parse().expect("invalid count"));
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus");
+    let mut rng = rand::thread_rng();
+    let mut bytes = vec![0u8; 4096];
+
+    for i in 0..count {
+        rng.fill(bytes.as_mut_slice());
+        write(&root, "tx_envelope_roundtrip", i, &bytes);
+
+        let mut u = Unstructured::new(&bytes);
+        if let Ok(tx) = TxEnvelope::arbitrary(&mut u) {
+            write(&root, "tx_envelope_2718", i, &tx.encoded_2718());
+        }
+        if let Ok(receipt) = ReceiptEnvelope::arbitrary(&mut u) {
+            write(&root, "receipt_envelope_2718", i, &receipt.encoded_2718());
+        }
+        if let Ok(header) = Header::arbitrary(&mut u) {
+            write(&root, "header_rlp", i, &alloy_rlp::encode(&header));
+        }
+    }
+
+    // Seeds are prefixed with the chunk size of the reader.
+    for (i, seed) in IPC_SEEDS.iter().enumerate() {
+        let mut data = vec![7];
+        data.extend_from_slice(seed.as_bytes());
+        write(&root, "ipc_read_json_stream", i, &data);
+    }
+    let mut data = vec![u8::MAX];
+    data.extend(IPC_SEEDS.iter().flat_map(|seed| seed.bytes()));
+    write(&root, "ipc_read_json_stream", IPC_SEEDS.len(), &data);
+}
+
+fn write(root: &Path, target: &str, i: usize, data: &[u8]) {
+    let dir = root.join(target);
+    fs::create_dir_all(&dir).expect("failed to create corpus directory");
+    fs::write(dir.join(format!("seed-{i:05}")), data).expect("failed to write seed");
+}